@@ -41,11 +41,16 @@ import { getCurrentLocalDate } from "@/lib/utils"
 gql`
   mutation ExecuteManualTransaction($input: ManualTransactionExecuteInput!) {
     manualTransactionExecute(input: $input) {
-      transaction {
+      manualTransaction {
         id
-        ledgerTransactionId
+        manualTransactionId
+        status
         createdAt
         description
+        ledgerTransaction {
+          id
+          ledgerTransactionId
+        }
       }
     }
   }
@@ -109,11 +114,16 @@ export const ExecuteManualTransactionDialog: React.FC<ExecuteManualTransactionPr
           input: { ...formValues },
         },
         onCompleted: (data) => {
-          if (data?.manualTransactionExecute.transaction) {
+          const manualTransaction = data?.manualTransactionExecute.manualTransaction
+          if (manualTransaction) {
             toast.success(t("success"))
-            navigate(
-              `/ledger-transaction/${data.manualTransactionExecute.transaction.ledgerTransactionId}`,
-            )
+            if (manualTransaction.ledgerTransaction) {
+              navigate(
+                `/ledger-transaction/${manualTransaction.ledgerTransaction.ledgerTransactionId}`,
+              )
+            } else {
+              setOpenExecuteManualTransaction(false)
+            }
           } else {
             throw new Error(t("errored"))
           }
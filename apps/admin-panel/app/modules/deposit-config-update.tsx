@@ -33,6 +33,8 @@ gql`
         chartOfAccountBankDepositAccountsParentCode
         chartOfAccountFinancialInstitutionDepositAccountsParentCode
         chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+        chartOfAccountInterestExpenseParentCode
+        chartOfAccountInterestPayableParentCode
       }
     }
   }
@@ -52,6 +54,8 @@ const initialFormData: DepositModuleConfigureInput = {
   chartOfAccountBankDepositAccountsParentCode: "",
   chartOfAccountFinancialInstitutionDepositAccountsParentCode: "",
   chartOfAccountNonDomiciledIndividualDepositAccountsParentCode: "",
+  chartOfAccountInterestExpenseParentCode: "",
+  chartOfAccountInterestPayableParentCode: "",
 }
 
 const depositModuleCodes = {
@@ -62,6 +66,8 @@ const depositModuleCodes = {
   chartOfAccountBankDepositAccountsParentCode: "2110.01.0501",
   chartOfAccountFinancialInstitutionDepositAccountsParentCode: "2110.01.0601",
   chartOfAccountNonDomiciledIndividualDepositAccountsParentCode: "2110.01.0901",
+  chartOfAccountInterestExpenseParentCode: "7110.01.0100",
+  chartOfAccountInterestPayableParentCode: "2110.01.9901",
 }
 
 export const DepositConfigUpdateDialog: React.FC<DepositConfigUpdateDialogProps> = ({
@@ -93,7 +99,9 @@ export const DepositConfigUpdateDialog: React.FC<DepositConfigUpdateDialogProps>
       depositModuleConfig.chartOfAccountPrivateCompanyDepositAccountsParentCode &&
       depositModuleConfig.chartOfAccountBankDepositAccountsParentCode &&
       depositModuleConfig.chartOfAccountFinancialInstitutionDepositAccountsParentCode &&
-      depositModuleConfig.chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+      depositModuleConfig.chartOfAccountNonDomiciledCompanyDepositAccountsParentCode &&
+      depositModuleConfig.chartOfAccountInterestExpenseParentCode &&
+      depositModuleConfig.chartOfAccountInterestPayableParentCode
     ) {
       setFormData({
         chartOfAccountsOmnibusParentCode:
@@ -110,6 +118,10 @@ export const DepositConfigUpdateDialog: React.FC<DepositConfigUpdateDialogProps>
           depositModuleConfig.chartOfAccountFinancialInstitutionDepositAccountsParentCode,
         chartOfAccountNonDomiciledIndividualDepositAccountsParentCode:
           depositModuleConfig.chartOfAccountNonDomiciledCompanyDepositAccountsParentCode,
+        chartOfAccountInterestExpenseParentCode:
+          depositModuleConfig.chartOfAccountInterestExpenseParentCode,
+        chartOfAccountInterestPayableParentCode:
+          depositModuleConfig.chartOfAccountInterestPayableParentCode,
       })
     }
   }, [depositModuleConfig])
@@ -41,6 +41,8 @@ gql`
       chartOfAccountBankDepositAccountsParentCode
       chartOfAccountFinancialInstitutionDepositAccountsParentCode
       chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+      chartOfAccountInterestExpenseParentCode
+      chartOfAccountInterestPayableParentCode
     }
   }
 
@@ -1054,6 +1054,8 @@ export type DepositModuleConfig = {
   chartOfAccountsGovernmentEntityDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountsId?: Maybe<Scalars['UUID']['output']>;
   chartOfAccountsIndividualDepositAccountsParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountInterestExpenseParentCode?: Maybe<Scalars['String']['output']>;
+  chartOfAccountInterestPayableParentCode?: Maybe<Scalars['String']['output']>;
   chartOfAccountsOmnibusParentCode?: Maybe<Scalars['String']['output']>;
 };
 
@@ -1064,6 +1066,8 @@ export type DepositModuleConfigureInput = {
   chartOfAccountPrivateCompanyDepositAccountsParentCode: Scalars['String']['input'];
   chartOfAccountsGovernmentEntityDepositAccountsParentCode: Scalars['String']['input'];
   chartOfAccountsIndividualDepositAccountsParentCode: Scalars['String']['input'];
+  chartOfAccountInterestExpenseParentCode?: InputMaybe<Scalars['String']['input']>;
+  chartOfAccountInterestPayableParentCode?: InputMaybe<Scalars['String']['input']>;
   chartOfAccountsOmnibusParentCode: Scalars['String']['input'];
 };
 
@@ -1348,6 +1352,33 @@ export enum LoanAgreementStatus {
   Pending = 'PENDING'
 }
 
+export type ManualTransaction = {
+  __typename?: 'ManualTransaction';
+  approvalProcess?: Maybe<ApprovalProcess>;
+  createdAt: Scalars['Timestamp']['output'];
+  description: Scalars['String']['output'];
+  effective?: Maybe<Scalars['Date']['output']>;
+  entries: Array<ManualTransactionEntry>;
+  failureReason?: Maybe<Scalars['String']['output']>;
+  id: Scalars['ID']['output'];
+  ledgerTransaction?: Maybe<LedgerTransaction>;
+  manualTransactionId: Scalars['UUID']['output'];
+  reference: Scalars['String']['output'];
+  reversalOf?: Maybe<Scalars['UUID']['output']>;
+  reversalTransactionId?: Maybe<Scalars['UUID']['output']>;
+  reversed: Scalars['Boolean']['output'];
+  status: ManualTransactionStatus;
+};
+
+export type ManualTransactionEntry = {
+  __typename?: 'ManualTransactionEntry';
+  accountId: Scalars['UUID']['output'];
+  amount: Scalars['Decimal']['output'];
+  currency: Scalars['String']['output'];
+  description: Scalars['String']['output'];
+  direction: DebitOrCredit;
+};
+
 export type ManualTransactionEntryInput = {
   accountRef: Scalars['String']['input'];
   amount: Scalars['Decimal']['input'];
@@ -1365,9 +1396,16 @@ export type ManualTransactionExecuteInput = {
 
 export type ManualTransactionExecutePayload = {
   __typename?: 'ManualTransactionExecutePayload';
-  transaction: LedgerTransaction;
+  manualTransaction: ManualTransaction;
 };
 
+export enum ManualTransactionStatus {
+  Denied = 'DENIED',
+  Failed = 'FAILED',
+  PendingApproval = 'PENDING_APPROVAL',
+  Posted = 'POSTED'
+}
+
 export type Me = {
   __typename?: 'Me';
   user: User;
@@ -2927,7 +2965,7 @@ export type ExecuteManualTransactionMutationVariables = Exact<{
 }>;
 
 
-export type ExecuteManualTransactionMutation = { __typename?: 'Mutation', manualTransactionExecute: { __typename?: 'ManualTransactionExecutePayload', transaction: { __typename?: 'LedgerTransaction', id: string, ledgerTransactionId: string, createdAt: any, description?: string | null } } };
+export type ExecuteManualTransactionMutation = { __typename?: 'Mutation', manualTransactionExecute: { __typename?: 'ManualTransactionExecutePayload', manualTransaction: { __typename?: 'ManualTransaction', id: string, manualTransactionId: string, status: ManualTransactionStatus, createdAt: any, description: string, ledgerTransaction?: { __typename?: 'LedgerTransaction', id: string, ledgerTransactionId: string } | null } } };
 
 export type JournalEntriesQueryVariables = Exact<{
   first: Scalars['Int']['input'];
@@ -3018,12 +3056,12 @@ export type DepositModuleConfigureMutationVariables = Exact<{
 }>;
 
 
-export type DepositModuleConfigureMutation = { __typename?: 'Mutation', depositModuleConfigure: { __typename?: 'DepositModuleConfigurePayload', depositConfig: { __typename?: 'DepositModuleConfig', chartOfAccountsId?: string | null, chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null } } };
+export type DepositModuleConfigureMutation = { __typename?: 'Mutation', depositModuleConfigure: { __typename?: 'DepositModuleConfigurePayload', depositConfig: { __typename?: 'DepositModuleConfig', chartOfAccountsId?: string | null, chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null, chartOfAccountInterestExpenseParentCode?: string | null, chartOfAccountInterestPayableParentCode?: string | null } } };
 
 export type DepositConfigQueryVariables = Exact<{ [key: string]: never; }>;
 
 
-export type DepositConfigQuery = { __typename?: 'Query', depositConfig?: { __typename?: 'DepositModuleConfig', chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null } | null };
+export type DepositConfigQuery = { __typename?: 'Query', depositConfig?: { __typename?: 'DepositModuleConfig', chartOfAccountsOmnibusParentCode?: string | null, chartOfAccountsIndividualDepositAccountsParentCode?: string | null, chartOfAccountsGovernmentEntityDepositAccountsParentCode?: string | null, chartOfAccountPrivateCompanyDepositAccountsParentCode?: string | null, chartOfAccountBankDepositAccountsParentCode?: string | null, chartOfAccountFinancialInstitutionDepositAccountsParentCode?: string | null, chartOfAccountNonDomiciledCompanyDepositAccountsParentCode?: string | null, chartOfAccountInterestExpenseParentCode?: string | null, chartOfAccountInterestPayableParentCode?: string | null } | null };
 
 export type CreditConfigQueryVariables = Exact<{ [key: string]: never; }>;
 
@@ -6128,11 +6166,16 @@ export type DisbursalsQueryResult = Apollo.QueryResult<DisbursalsQuery, Disbursa
 export const ExecuteManualTransactionDocument = gql`
     mutation ExecuteManualTransaction($input: ManualTransactionExecuteInput!) {
   manualTransactionExecute(input: $input) {
-    transaction {
+    manualTransaction {
       id
-      ledgerTransactionId
+      manualTransactionId
+      status
       createdAt
       description
+      ledgerTransaction {
+        id
+        ledgerTransactionId
+      }
     }
   }
 }
@@ -6679,6 +6722,8 @@ export const DepositModuleConfigureDocument = gql`
       chartOfAccountBankDepositAccountsParentCode
       chartOfAccountFinancialInstitutionDepositAccountsParentCode
       chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+      chartOfAccountInterestExpenseParentCode
+      chartOfAccountInterestPayableParentCode
     }
   }
 }
@@ -6719,6 +6764,8 @@ export const DepositConfigDocument = gql`
     chartOfAccountBankDepositAccountsParentCode
     chartOfAccountFinancialInstitutionDepositAccountsParentCode
     chartOfAccountNonDomiciledCompanyDepositAccountsParentCode
+    chartOfAccountInterestExpenseParentCode
+    chartOfAccountInterestPayableParentCode
   }
 }
     `;
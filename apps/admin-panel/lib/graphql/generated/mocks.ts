@@ -1368,6 +1368,8 @@ export const mockDepositModuleConfig = (overrides?: Partial<DepositModuleConfig>
         chartOfAccountsGovernmentEntityDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsGovernmentEntityDepositAccountsParentCode') ? overrides.chartOfAccountsGovernmentEntityDepositAccountsParentCode! : faker.lorem.word(),
         chartOfAccountsId: overrides && overrides.hasOwnProperty('chartOfAccountsId') ? overrides.chartOfAccountsId! : generateMockValue.uuid(),
         chartOfAccountsIndividualDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsIndividualDepositAccountsParentCode') ? overrides.chartOfAccountsIndividualDepositAccountsParentCode! : faker.lorem.word(),
+        chartOfAccountInterestExpenseParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInterestExpenseParentCode') ? overrides.chartOfAccountInterestExpenseParentCode! : faker.lorem.word(),
+        chartOfAccountInterestPayableParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInterestPayableParentCode') ? overrides.chartOfAccountInterestPayableParentCode! : faker.lorem.word(),
         chartOfAccountsOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsOmnibusParentCode') ? overrides.chartOfAccountsOmnibusParentCode! : faker.lorem.word(),
     };
 };
@@ -1382,6 +1384,8 @@ export const mockDepositModuleConfigureInput = (overrides?: Partial<DepositModul
         chartOfAccountPrivateCompanyDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountPrivateCompanyDepositAccountsParentCode') ? overrides.chartOfAccountPrivateCompanyDepositAccountsParentCode! : faker.lorem.word(),
         chartOfAccountsGovernmentEntityDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsGovernmentEntityDepositAccountsParentCode') ? overrides.chartOfAccountsGovernmentEntityDepositAccountsParentCode! : faker.lorem.word(),
         chartOfAccountsIndividualDepositAccountsParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsIndividualDepositAccountsParentCode') ? overrides.chartOfAccountsIndividualDepositAccountsParentCode! : faker.lorem.word(),
+        chartOfAccountInterestExpenseParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInterestExpenseParentCode') ? overrides.chartOfAccountInterestExpenseParentCode! : faker.lorem.word(),
+        chartOfAccountInterestPayableParentCode: overrides && overrides.hasOwnProperty('chartOfAccountInterestPayableParentCode') ? overrides.chartOfAccountInterestPayableParentCode! : faker.lorem.word(),
         chartOfAccountsOmnibusParentCode: overrides && overrides.hasOwnProperty('chartOfAccountsOmnibusParentCode') ? overrides.chartOfAccountsOmnibusParentCode! : faker.lorem.word(),
     };
 };
@@ -0,0 +1,320 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{AccountingPeriodId, ManualTransactionId};
+
+use super::{error::AccountingPeriodError, primitives::*};
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "AccountingPeriodId")]
+pub enum AccountingPeriodEvent {
+    Initialized {
+        id: AccountingPeriodId,
+        period_type: AccountingPeriodType,
+        starts_on: NaiveDate,
+        ends_on: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    SoftClosed {
+        audit_info: AuditInfo,
+    },
+    Reopened {
+        audit_info: AuditInfo,
+    },
+    Closed {
+        closing_transaction_id: Option<ManualTransactionId>,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct AccountingPeriod {
+    pub id: AccountingPeriodId,
+    pub period_type: AccountingPeriodType,
+    pub starts_on: NaiveDate,
+    pub ends_on: NaiveDate,
+    #[builder(setter(strip_option), default)]
+    pub closing_transaction_id: Option<ManualTransactionId>,
+    events: EntityEvents<AccountingPeriodEvent>,
+}
+
+impl AccountingPeriod {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for accounting period")
+    }
+
+    pub fn status(&self) -> AccountingPeriodStatus {
+        self.events
+            .iter_all()
+            .last()
+            .map(|event| match event {
+                AccountingPeriodEvent::Initialized { .. }
+                | AccountingPeriodEvent::Reopened { .. } => AccountingPeriodStatus::Open,
+                AccountingPeriodEvent::SoftClosed { .. } => AccountingPeriodStatus::SoftClosed,
+                AccountingPeriodEvent::Closed { .. } => AccountingPeriodStatus::Closed,
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.status() == AccountingPeriodStatus::Closed
+    }
+
+    pub fn requires_closing_entry(&self) -> bool {
+        self.period_type == AccountingPeriodType::Annual
+    }
+
+    pub(super) fn ensure_closable(&self, today: NaiveDate) -> Result<(), AccountingPeriodError> {
+        if self.is_closed() {
+            return Err(AccountingPeriodError::AlreadyClosed(self.id));
+        }
+        if today <= self.ends_on {
+            return Err(AccountingPeriodError::PeriodNotEnded(self.id));
+        }
+        Ok(())
+    }
+
+    pub(super) fn soft_close(
+        &mut self,
+        today: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, AccountingPeriodError> {
+        if self.status() == AccountingPeriodStatus::SoftClosed {
+            return Ok(Idempotent::Ignored);
+        }
+        self.ensure_closable(today)?;
+
+        self.events
+            .push(AccountingPeriodEvent::SoftClosed { audit_info });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(super) fn reopen(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, AccountingPeriodError> {
+        match self.status() {
+            AccountingPeriodStatus::Open => return Ok(Idempotent::Ignored),
+            AccountingPeriodStatus::Closed => {
+                return Err(AccountingPeriodError::AlreadyClosed(self.id));
+            }
+            AccountingPeriodStatus::SoftClosed => (),
+        }
+
+        self.events
+            .push(AccountingPeriodEvent::Reopened { audit_info });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(super) fn close(
+        &mut self,
+        today: NaiveDate,
+        closing_transaction_id: Option<ManualTransactionId>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, AccountingPeriodError> {
+        if self.is_closed() {
+            return Ok(Idempotent::Ignored);
+        }
+        self.ensure_closable(today)?;
+
+        self.events.push(AccountingPeriodEvent::Closed {
+            closing_transaction_id,
+            audit_info,
+        });
+        self.closing_transaction_id = closing_transaction_id;
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<AccountingPeriodEvent> for AccountingPeriod {
+    fn try_from_events(events: EntityEvents<AccountingPeriodEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = AccountingPeriodBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                AccountingPeriodEvent::Initialized {
+                    id,
+                    period_type,
+                    starts_on,
+                    ends_on,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .period_type(*period_type)
+                        .starts_on(*starts_on)
+                        .ends_on(*ends_on)
+                }
+                AccountingPeriodEvent::Closed {
+                    closing_transaction_id: Some(closing_transaction_id),
+                    ..
+                } => builder = builder.closing_transaction_id(*closing_transaction_id),
+                _ => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate", error = "AccountingPeriodError"))]
+pub struct NewAccountingPeriod {
+    #[builder(setter(into))]
+    pub(super) id: AccountingPeriodId,
+    pub(super) period_type: AccountingPeriodType,
+    pub(super) starts_on: NaiveDate,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewAccountingPeriodBuilder {
+    fn validate(&self) -> Result<(), AccountingPeriodError> {
+        if let (Some(period_type), Some(starts_on)) = (self.period_type, self.starts_on)
+            && period_type.ends_on(starts_on).is_none()
+        {
+            return Err(AccountingPeriodError::InvalidPeriodStart(starts_on));
+        }
+        Ok(())
+    }
+}
+
+impl NewAccountingPeriod {
+    pub fn builder() -> NewAccountingPeriodBuilder {
+        NewAccountingPeriodBuilder::default()
+    }
+
+    pub(super) fn ends_on(&self) -> NaiveDate {
+        self.period_type
+            .ends_on(self.starts_on)
+            .expect("period start already validated")
+    }
+
+    pub(super) fn status(&self) -> AccountingPeriodStatus {
+        AccountingPeriodStatus::Open
+    }
+}
+
+impl IntoEvents<AccountingPeriodEvent> for NewAccountingPeriod {
+    fn into_events(self) -> EntityEvents<AccountingPeriodEvent> {
+        EntityEvents::init(
+            self.id,
+            [AccountingPeriodEvent::Initialized {
+                id: self.id,
+                period_type: self.period_type,
+                starts_on: self.starts_on,
+                ends_on: self.ends_on(),
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn january() -> AccountingPeriod {
+        let new_period = NewAccountingPeriod::builder()
+            .id(AccountingPeriodId::new())
+            .period_type(AccountingPeriodType::Monthly)
+            .starts_on(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        AccountingPeriod::try_from_events(new_period.into_events()).unwrap()
+    }
+
+    fn february_first() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()
+    }
+
+    #[test]
+    fn rejects_invalid_start() {
+        let res = NewAccountingPeriod::builder()
+            .id(AccountingPeriodId::new())
+            .period_type(AccountingPeriodType::Quarterly)
+            .starts_on(NaiveDate::from_ymd_opt(2024, 1, 2).unwrap())
+            .audit_info(dummy_audit_info())
+            .build();
+        assert!(matches!(
+            res,
+            Err(AccountingPeriodError::InvalidPeriodStart(_))
+        ));
+    }
+
+    #[test]
+    fn cannot_close_before_period_ends() {
+        let mut period = january();
+        let last_day = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert!(matches!(
+            period.soft_close(last_day, dummy_audit_info()),
+            Err(AccountingPeriodError::PeriodNotEnded(_))
+        ));
+        assert!(matches!(
+            period.close(last_day, None, dummy_audit_info()),
+            Err(AccountingPeriodError::PeriodNotEnded(_))
+        ));
+    }
+
+    #[test]
+    fn soft_close_and_reopen() {
+        let mut period = january();
+        assert!(
+            period
+                .soft_close(february_first(), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(period.status(), AccountingPeriodStatus::SoftClosed);
+        assert!(
+            period
+                .soft_close(february_first(), dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+
+        assert!(period.reopen(dummy_audit_info()).unwrap().did_execute());
+        assert_eq!(period.status(), AccountingPeriodStatus::Open);
+    }
+
+    #[test]
+    fn closed_period_cannot_be_reopened() {
+        let mut period = january();
+        assert!(
+            period
+                .close(february_first(), None, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(period.is_closed());
+        assert!(matches!(
+            period.reopen(dummy_audit_info()),
+            Err(AccountingPeriodError::AlreadyClosed(_))
+        ));
+        assert!(
+            period
+                .close(february_first(), None, dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+    }
+}
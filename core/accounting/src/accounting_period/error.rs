@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+use crate::primitives::AccountingPeriodId;
+
+#[derive(Error, Debug)]
+pub enum AccountingPeriodError {
+    #[error("AccountingPeriodError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("AccountingPeriodError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("AccountingPeriodError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("AccountingPeriodError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("AccountingPeriodError - CalaAccountSetError: {0}")]
+    AccountSetError(#[from] cala_ledger::account_set::error::AccountSetError),
+    #[error("AccountingPeriodError - CalaBalanceError: {0}")]
+    BalanceError(#[from] cala_ledger::balance::error::BalanceError),
+    #[error("AccountingPeriodError - ManualTransactionError: {0}")]
+    ManualTransactionError(#[from] crate::manual_transaction::error::ManualTransactionError),
+    #[error("AccountingPeriodError - InvalidPeriodStart: {0}")]
+    InvalidPeriodStart(chrono::NaiveDate),
+    #[error("AccountingPeriodError - PeriodNotEnded: {0}")]
+    PeriodNotEnded(AccountingPeriodId),
+    #[error("AccountingPeriodError - AlreadyClosed: {0}")]
+    AlreadyClosed(AccountingPeriodId),
+    #[error("AccountingPeriodError - RetainedEarningsAccountRequired: {0}")]
+    RetainedEarningsAccountRequired(AccountingPeriodId),
+    #[error("AccountingPeriodError - UninitializedField: {0}")]
+    UninitializedField(#[from] derive_builder::UninitializedFieldError),
+}
+
+es_entity::from_es_entity_error!(AccountingPeriodError);
+
+#[derive(Error, Debug)]
+pub enum AccountingPeriodGuardError {
+    #[error("AccountingPeriodGuardError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("AccountingPeriodGuardError - PostingInLockedPeriod: {0} falls in period {1}")]
+    PostingInLockedPeriod(chrono::NaiveDate, AccountingPeriodId),
+}
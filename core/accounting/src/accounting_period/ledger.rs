@@ -0,0 +1,100 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use cala_ledger::{
+    AccountSetId, CalaLedger, Currency, JournalId,
+    account_set::{AccountSetMemberId, AccountSetMembersByCreatedAtCursor},
+};
+
+use crate::primitives::CalaAccountId;
+
+use super::error::AccountingPeriodError;
+
+#[derive(Clone)]
+pub struct AccountingPeriodLedger {
+    cala: CalaLedger,
+    journal_id: JournalId,
+}
+
+impl AccountingPeriodLedger {
+    pub fn new(cala: &CalaLedger, journal_id: JournalId) -> Self {
+        Self {
+            cala: cala.clone(),
+            journal_id,
+        }
+    }
+
+    /// Net settled movement (credit minus debit) of every account below the
+    /// given sets within the period.
+    pub async fn net_settled_movements(
+        &self,
+        account_set_ids: &[AccountSetId],
+        currency: Currency,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<(CalaAccountId, Decimal)>, AccountingPeriodError> {
+        let mut account_ids = Vec::new();
+        for id in account_set_ids {
+            self.collect_member_accounts(*id, &mut account_ids).await?;
+        }
+        account_ids.sort();
+        account_ids.dedup();
+
+        let balance_ids = account_ids
+            .iter()
+            .map(|id| (self.journal_id, *id, currency))
+            .collect::<Vec<_>>();
+        let ranges = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&balance_ids, from, Some(until))
+            .await?;
+
+        let mut res = Vec::new();
+        for balance_id in balance_ids {
+            if let Some(range) = ranges.get(&balance_id) {
+                let settled = &range.period.details.settled;
+                let net = settled.cr_balance - settled.dr_balance;
+                if !net.is_zero() {
+                    res.push((balance_id.1, net));
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    fn collect_member_accounts<'a>(
+        &'a self,
+        id: AccountSetId,
+        account_ids: &'a mut Vec<CalaAccountId>,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), AccountingPeriodError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let mut after: Option<AccountSetMembersByCreatedAtCursor> = None;
+            loop {
+                let members = self
+                    .cala
+                    .account_sets()
+                    .list_members_by_created_at(
+                        id,
+                        es_entity::PaginatedQueryArgs { first: 100, after },
+                    )
+                    .await?;
+                for member in members.entities {
+                    match member.id {
+                        AccountSetMemberId::Account(account_id) => account_ids.push(account_id),
+                        AccountSetMemberId::AccountSet(set_id) => {
+                            self.collect_member_accounts(set_id, account_ids).await?
+                        }
+                    }
+                }
+                if !members.has_next_page {
+                    break;
+                }
+                after = members.end_cursor;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,402 @@
+mod entity;
+pub mod error;
+mod ledger;
+mod primitives;
+mod repo;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use std::collections::HashMap;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cala_ledger::{CalaLedger, JournalId};
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    manual_transaction::{ManualEntryInput, ManualTransactions},
+    primitives::{
+        AccountCode, AccountIdOrCode, AccountingPeriodId, CalaAccountSetId, CalaCurrency,
+        CoreAccountingAction, CoreAccountingObject, DebitOrCredit, ManualTransactionId,
+    },
+};
+
+pub use entity::AccountingPeriod;
+#[cfg(feature = "json-schema")]
+pub use entity::AccountingPeriodEvent;
+pub(super) use entity::*;
+use error::*;
+use ledger::AccountingPeriodLedger;
+pub use primitives::*;
+pub use repo::accounting_period_cursor::AccountingPeriodsByCreatedAtCursor;
+use repo::*;
+
+/// Accounts needed to post the closing entry of an annual period.
+#[derive(Debug, Clone)]
+pub struct ClosingEntryAccounts {
+    pub chart_ref: String,
+    pub profit_and_loss_account_set_ids: Vec<CalaAccountSetId>,
+    pub retained_earnings_code: AccountCode,
+}
+
+/// Rejects ledger postings whose effective date falls in a locked period.
+#[derive(Clone)]
+pub struct AccountingPeriodGuard {
+    repo: AccountingPeriodRepo,
+}
+
+impl AccountingPeriodGuard {
+    pub fn new(pool: &sqlx::PgPool) -> Self {
+        Self {
+            repo: AccountingPeriodRepo::new(pool),
+        }
+    }
+
+    /// Template postings from other modules keep flowing while a period is
+    /// soft-closed so that late accruals can still land.
+    pub async fn ensure_open_for_system_postings(
+        &self,
+        effective: NaiveDate,
+    ) -> Result<(), AccountingPeriodGuardError> {
+        self.ensure_not_in(effective, &[AccountingPeriodStatus::Closed])
+            .await
+    }
+
+    pub async fn ensure_open_for_manual_postings(
+        &self,
+        effective: NaiveDate,
+    ) -> Result<(), AccountingPeriodGuardError> {
+        self.ensure_not_in(
+            effective,
+            &[
+                AccountingPeriodStatus::SoftClosed,
+                AccountingPeriodStatus::Closed,
+            ],
+        )
+        .await
+    }
+
+    async fn ensure_not_in(
+        &self,
+        effective: NaiveDate,
+        statuses: &[AccountingPeriodStatus],
+    ) -> Result<(), AccountingPeriodGuardError> {
+        match self
+            .repo
+            .find_locking_period_id(effective, statuses)
+            .await?
+        {
+            Some(id) => Err(AccountingPeriodGuardError::PostingInLockedPeriod(
+                effective, id,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct AccountingPeriods<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    authz: Perms,
+    ledger: AccountingPeriodLedger,
+    manual_transactions: ManualTransactions<Perms, E>,
+    repo: AccountingPeriodRepo,
+}
+
+impl<Perms, E> Clone for AccountingPeriods<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            ledger: self.ledger.clone(),
+            manual_transactions: self.manual_transactions.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms, E> AccountingPeriods<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        manual_transactions: &ManualTransactions<Perms, E>,
+        cala: &CalaLedger,
+        journal_id: JournalId,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            ledger: AccountingPeriodLedger::new(cala, journal_id),
+            manual_transactions: manual_transactions.clone(),
+            repo: AccountingPeriodRepo::new(pool),
+        }
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.create", skip(self), err)]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        period_type: AccountingPeriodType,
+        starts_on: NaiveDate,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_periods(),
+                CoreAccountingAction::ACCOUNTING_PERIOD_CREATE,
+            )
+            .await?;
+
+        let new_period = NewAccountingPeriod::builder()
+            .id(AccountingPeriodId::new())
+            .period_type(period_type)
+            .starts_on(starts_on)
+            .audit_info(audit_info)
+            .build()?;
+
+        self.repo.create(new_period).await
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.find_by_id", skip(self), err)]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+    ) -> Result<Option<AccountingPeriod>, AccountingPeriodError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(period) => Ok(Some(period)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.list", skip(self), err)]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<AccountingPeriodsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<AccountingPeriod, AccountingPeriodsByCreatedAtCursor>,
+        AccountingPeriodError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_accounting_periods(),
+                CoreAccountingAction::ACCOUNTING_PERIOD_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.find_all", skip(self), err)]
+    pub async fn find_all<T: From<AccountingPeriod>>(
+        &self,
+        ids: &[AccountingPeriodId],
+    ) -> Result<HashMap<AccountingPeriodId, T>, AccountingPeriodError> {
+        self.repo.find_all(ids).await
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.soft_close", skip(self), err)]
+    pub async fn soft_close(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_CLOSE,
+            )
+            .await?;
+
+        let mut period = self.repo.find_by_id(id).await?;
+        if period
+            .soft_close(chrono::Utc::now().date_naive(), audit_info)?
+            .did_execute()
+        {
+            self.repo.update(&mut period).await?;
+        }
+        Ok(period)
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.reopen", skip(self), err)]
+    pub async fn reopen(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_REOPEN,
+            )
+            .await?;
+
+        let mut period = self.repo.find_by_id(id).await?;
+        if period.reopen(audit_info)?.did_execute() {
+            self.repo.update(&mut period).await?;
+        }
+        Ok(period)
+    }
+
+    #[instrument(name = "core_accounting.accounting_period.close", skip(self), err)]
+    pub async fn close(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<AccountingPeriodId> + std::fmt::Debug,
+        closing_accounts: Option<ClosingEntryAccounts>,
+    ) -> Result<AccountingPeriod, AccountingPeriodError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::accounting_period(id),
+                CoreAccountingAction::ACCOUNTING_PERIOD_CLOSE,
+            )
+            .await?;
+
+        let mut period = self.repo.find_by_id(id).await?;
+        if period.is_closed() {
+            return Ok(period);
+        }
+        let today = chrono::Utc::now().date_naive();
+        period.ensure_closable(today)?;
+
+        let closing_entry = if period.requires_closing_entry() {
+            let closing_accounts = closing_accounts
+                .ok_or(AccountingPeriodError::RetainedEarningsAccountRequired(id))?;
+            let entries = self.closing_entries(&period, &closing_accounts).await?;
+            (!entries.is_empty()).then_some((closing_accounts.chart_ref, entries))
+        } else {
+            None
+        };
+
+        let closing_transaction_id = closing_entry.as_ref().map(|_| ManualTransactionId::new());
+        if period
+            .close(today, closing_transaction_id, audit_info.clone())?
+            .was_ignored()
+        {
+            return Ok(period);
+        }
+
+        let mut db = self.repo.begin_op().await?;
+        self.repo.update_in_op(&mut db, &mut period).await?;
+
+        match (closing_transaction_id, closing_entry) {
+            (Some(tx_id), Some((chart_ref, entries))) => {
+                self.manual_transactions
+                    .execute_in_op(
+                        db,
+                        sub,
+                        &chart_ref,
+                        tx_id,
+                        Some(format!("period-close-{id}")),
+                        format!(
+                            "Closing entry for period {} - {}",
+                            period.starts_on, period.ends_on
+                        ),
+                        period.ends_on,
+                        entries,
+                        audit_info,
+                    )
+                    .await?;
+            }
+            _ => db.commit().await?,
+        }
+
+        Ok(period)
+    }
+
+    async fn closing_entries(
+        &self,
+        period: &AccountingPeriod,
+        closing_accounts: &ClosingEntryAccounts,
+    ) -> Result<Vec<ManualEntryInput>, AccountingPeriodError> {
+        let movements = self
+            .ledger
+            .net_settled_movements(
+                &closing_accounts.profit_and_loss_account_set_ids,
+                CalaCurrency::USD,
+                period.starts_on,
+                period.ends_on,
+            )
+            .await?;
+
+        let mut net_income = Decimal::ZERO;
+        let mut entries = Vec::with_capacity(movements.len() + 1);
+        for (account_id, net) in movements {
+            net_income += net;
+            entries.push(closing_entry_input(
+                AccountIdOrCode::Id(account_id.into()),
+                -net,
+                "Close profit and loss balance",
+            ));
+        }
+        if !net_income.is_zero() {
+            entries.push(closing_entry_input(
+                AccountIdOrCode::Code(closing_accounts.retained_earnings_code.clone()),
+                net_income,
+                "Transfer to retained earnings",
+            ));
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Positive amounts credit the account, negative amounts debit it.
+fn closing_entry_input(
+    account_id_or_code: AccountIdOrCode,
+    amount: Decimal,
+    description: &str,
+) -> ManualEntryInput {
+    let direction = if amount.is_sign_negative() {
+        DebitOrCredit::Debit
+    } else {
+        DebitOrCredit::Credit
+    };
+    ManualEntryInput::builder()
+        .account_id_or_code(account_id_or_code)
+        .amount(amount.abs())
+        .currency(CalaCurrency::USD)
+        .description(description)
+        .direction(direction)
+        .build()
+        .expect("Couldn't build closing entry")
+}
@@ -0,0 +1,81 @@
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum AccountingPeriodType {
+    Monthly,
+    Quarterly,
+    Annual,
+}
+
+impl AccountingPeriodType {
+    fn months(&self) -> u32 {
+        match self {
+            Self::Monthly => 1,
+            Self::Quarterly => 3,
+            Self::Annual => 12,
+        }
+    }
+
+    pub(super) fn ends_on(&self, starts_on: NaiveDate) -> Option<NaiveDate> {
+        if starts_on.day() != 1 {
+            return None;
+        }
+        starts_on
+            .checked_add_months(Months::new(self.months()))
+            .and_then(|next| next.pred_opt())
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum AccountingPeriodStatus {
+    #[default]
+    Open,
+    SoftClosed,
+    Closed,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ends_on_last_day_of_period() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(
+            AccountingPeriodType::Monthly.ends_on(start),
+            NaiveDate::from_ymd_opt(2024, 1, 31)
+        );
+        assert_eq!(
+            AccountingPeriodType::Quarterly.ends_on(start),
+            NaiveDate::from_ymd_opt(2024, 3, 31)
+        );
+        assert_eq!(
+            AccountingPeriodType::Annual.ends_on(start),
+            NaiveDate::from_ymd_opt(2024, 12, 31)
+        );
+    }
+
+    #[test]
+    fn period_must_start_on_first_of_month() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        assert_eq!(AccountingPeriodType::Monthly.ends_on(start), None);
+    }
+}
@@ -0,0 +1,131 @@
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::AccountingPeriodId;
+
+use super::{entity::*, error::*, primitives::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "AccountingPeriod",
+    err = "AccountingPeriodError",
+    columns(
+        period_type(ty = "AccountingPeriodType"),
+        starts_on(ty = "NaiveDate"),
+        ends_on(ty = "NaiveDate", create(accessor = "ends_on()")),
+        status(
+            ty = "AccountingPeriodStatus",
+            create(accessor = "status()"),
+            update(accessor = "status()")
+        )
+    ),
+    tbl_prefix = "core"
+)]
+pub struct AccountingPeriodRepo {
+    pool: PgPool,
+}
+
+impl Clone for AccountingPeriodRepo {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl AccountingPeriodRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    pub async fn find_locking_period_id(
+        &self,
+        effective: NaiveDate,
+        statuses: &[AccountingPeriodStatus],
+    ) -> Result<Option<AccountingPeriodId>, sqlx::Error> {
+        let statuses = statuses.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let row = sqlx::query!(
+            r#"SELECT id as "id: AccountingPeriodId"
+            FROM core_accounting_periods
+            WHERE starts_on <= $1 AND ends_on >= $1 AND status = ANY($2)
+            LIMIT 1"#,
+            effective,
+            &statuses[..]
+        )
+        .fetch_optional(self.pool())
+        .await?;
+        Ok(row.map(|r| r.id))
+    }
+}
+
+mod accounting_period_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::{AccountingPeriodStatus, AccountingPeriodType};
+
+    impl Type<Postgres> for AccountingPeriodType {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for AccountingPeriodType {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for AccountingPeriodType {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for AccountingPeriodType {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+
+    impl Type<Postgres> for AccountingPeriodStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for AccountingPeriodStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for AccountingPeriodStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for AccountingPeriodStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
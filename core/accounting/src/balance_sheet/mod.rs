@@ -12,7 +12,9 @@ use chrono::NaiveDate;
 use crate::{
     LedgerAccountId,
     chart_of_accounts::Chart,
-    primitives::{BalanceRange, CalaAccountSetId, CoreAccountingAction, CoreAccountingObject},
+    primitives::{
+        BalanceRange, CalaAccountSetId, CoreAccountingAction, CoreAccountingObject, CurrencyCode,
+    },
 };
 
 pub use chart_of_accounts_integration::{
@@ -239,3 +241,13 @@ pub struct BalanceSheet {
     pub btc_balance_range: Option<BalanceRange>,
     pub category_ids: Vec<LedgerAccountId>,
 }
+
+impl BalanceSheet {
+    /// Balance ranges for the currencies the ledger holds balances in.
+    pub fn balance_ranges(&self) -> Vec<(CurrencyCode, &BalanceRange)> {
+        crate::ledger_account::currency_balance_ranges(
+            self.usd_balance_range.as_ref(),
+            self.btc_balance_range.as_ref(),
+        )
+    }
+}
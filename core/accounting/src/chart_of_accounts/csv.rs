@@ -1,5 +1,5 @@
 use cala_ledger::DebitOrCredit;
-use csv::{ReaderBuilder, Trim};
+use csv::{ReaderBuilder, Trim, WriterBuilder};
 use std::io::Cursor;
 
 use crate::primitives::{
@@ -106,6 +106,54 @@ impl CsvParser {
     }
 }
 
+/// Writes account specs in the layout read by [`CsvParser`]: one column per code
+/// section, followed by the name and, for root accounts, the normal balance type.
+pub struct CsvExporter<'a> {
+    specs: Vec<&'a AccountSpec>,
+}
+impl<'a> CsvExporter<'a> {
+    pub fn new(specs: impl IntoIterator<Item = &'a AccountSpec>) -> Self {
+        let mut specs: Vec<_> = specs.into_iter().collect();
+        specs.sort_by(|a, b| a.code.cmp(&b.code));
+        Self { specs }
+    }
+
+    pub fn export(self) -> String {
+        let section_columns = self
+            .specs
+            .iter()
+            .map(|spec| spec.code.len_sections())
+            .max()
+            .unwrap_or_default();
+
+        let mut wtr = WriterBuilder::new()
+            .flexible(true)
+            .has_headers(false)
+            .from_writer(vec![]);
+        for spec in self.specs {
+            let mut record = (0..section_columns)
+                .map(|idx| {
+                    spec.code
+                        .section(idx)
+                        .map(|section| section.to_string())
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+            record.push(spec.name.to_string());
+            record.push(match (&spec.parent, spec.normal_balance_type) {
+                (Some(_), _) => String::new(),
+                (None, DebitOrCredit::Debit) => "Debit".to_string(),
+                (None, DebitOrCredit::Credit) => "Credit".to_string(),
+            });
+            wtr.write_record(&record)
+                .expect("Could not write record to in-memory buffer");
+        }
+
+        String::from_utf8(wtr.into_inner().expect("Could not flush in-memory buffer"))
+            .expect("CSV output is not valid UTF-8")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,4 +260,28 @@ mod tests {
         assert_eq!(Some(&specs[2].code), specs[4].parent.as_ref());
         assert_eq!(&specs[4].code.to_string(), "11.01.0102");
     }
+
+    #[test]
+    fn export_round_trips_through_parser() {
+        let data = r#"
+        1,,,Assets,Debit,
+        11,,,Current Assets,,
+            ,01,,Cash and Equivalents,,
+        ,,0101,"Operating Cash, Main",,
+        2,,,Liabilities,Credit,
+        "#;
+        let specs = CsvParser::new(data.to_string()).account_specs().unwrap();
+
+        let exported = CsvExporter::new(specs.iter()).export();
+        assert!(exported.starts_with("1,,,Assets,Debit\n"));
+
+        let reparsed = CsvParser::new(exported).account_specs().unwrap();
+        assert_eq!(reparsed.len(), specs.len());
+        for (original, reparsed) in specs.iter().zip(reparsed.iter()) {
+            assert_eq!(original.code, reparsed.code);
+            assert_eq!(original.parent, reparsed.parent);
+            assert_eq!(original.name, reparsed.name);
+            assert_eq!(original.normal_balance_type, reparsed.normal_balance_type);
+        }
+    }
 }
@@ -4,7 +4,7 @@ use derive_builder::Builder;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use audit::AuditInfo;
 
@@ -12,7 +12,7 @@ use es_entity::*;
 
 use crate::primitives::*;
 
-use super::{error::*, tree};
+use super::{error::*, import_diff::*, tree};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -35,6 +35,21 @@ pub enum ChartEvent {
         ledger_account_id: LedgerAccountId,
         audit_info: AuditInfo,
     },
+    NodeRenamed {
+        code: AccountCode,
+        name: AccountName,
+        audit_info: AuditInfo,
+    },
+    NodeDeactivated {
+        code: AccountCode,
+        audit_info: AuditInfo,
+    },
+    NodeMoved {
+        code: AccountCode,
+        from_parent: Option<AccountCode>,
+        to_parent: AccountCode,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -55,9 +70,16 @@ impl Chart {
         spec: &AccountSpec,
         journal_id: CalaJournalId,
         audit_info: AuditInfo,
-    ) -> Idempotent<NewChartAccountDetails> {
+    ) -> Result<Idempotent<NewChartAccountDetails>, ChartOfAccountsError> {
         if self.all_accounts.contains_key(&spec.code) {
-            return Idempotent::Ignored;
+            return Ok(Idempotent::Ignored);
+        }
+        if let Some(parent) = spec
+            .parent
+            .as_ref()
+            .filter(|parent| self.is_deactivated(parent))
+        {
+            return Err(ChartOfAccountsError::AccountDeactivated(parent.clone()));
         }
         let ledger_account_set_id = CalaAccountSetId::new();
         self.events.push(ChartEvent::NodeAdded {
@@ -80,6 +102,7 @@ impl Chart {
                 spec: spec.clone(),
                 account_set_id: ledger_account_set_id,
                 manual_transaction_account_id: None,
+                deactivated: false,
             },
         );
 
@@ -93,10 +116,10 @@ impl Chart {
             .build()
             .expect("Could not build new account set");
 
-        Idempotent::Executed(NewChartAccountDetails {
+        Ok(Idempotent::Executed(NewChartAccountDetails {
             parent_account_set_id,
             new_account_set,
-        })
+        }))
     }
 
     pub(super) fn create_child_node(
@@ -107,13 +130,13 @@ impl Chart {
         journal_id: CalaJournalId,
         audit_info: AuditInfo,
     ) -> Result<Idempotent<NewChartAccountDetails>, ChartOfAccountsError> {
-        let parent_normal_balance_type = self
-            .all_accounts
-            .get(&parent_code)
-            .map(|AccountDetails { spec, .. }| spec.normal_balance_type)
-            .ok_or(ChartOfAccountsError::ParentAccountNotFound(
-                parent_code.to_string(),
-            ))?;
+        let parent = self.all_accounts.get(&parent_code).ok_or(
+            ChartOfAccountsError::ParentAccountNotFound(parent_code.to_string()),
+        )?;
+        if parent.deactivated {
+            return Err(ChartOfAccountsError::AccountDeactivated(parent_code));
+        }
+        let parent_normal_balance_type = parent.spec.normal_balance_type;
 
         let spec = AccountSpec::try_new(
             Some(parent_code),
@@ -122,7 +145,208 @@ impl Chart {
             parent_normal_balance_type,
         )?;
 
-        Ok(self.create_node_without_verifying_parent(&spec, journal_id, audit_info))
+        self.create_node_without_verifying_parent(&spec, journal_id, audit_info)
+    }
+
+    pub(super) fn rename_node(
+        &mut self,
+        code: &AccountCode,
+        name: AccountName,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<CalaAccountSetId>, ChartOfAccountsError> {
+        let node = self.active_node_mut(code)?;
+        if node.spec.name == name {
+            return Ok(Idempotent::Ignored);
+        }
+        node.spec.name = name.clone();
+        let account_set_id = node.account_set_id;
+
+        self.events.push(ChartEvent::NodeRenamed {
+            code: code.clone(),
+            name,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(account_set_id))
+    }
+
+    /// Deactivated nodes stay in the ledger hierarchy so that historical balances
+    /// keep rolling up, but no longer accept postings or new children. The
+    /// node's manual transaction account is locked in the ledger alongside.
+    pub(super) fn deactivate_node(
+        &mut self,
+        code: &AccountCode,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<NodeDeactivation>, ChartOfAccountsError> {
+        let node = self
+            .all_accounts
+            .get(code)
+            .ok_or_else(|| ChartOfAccountsError::CodeNotFoundInChart(code.clone()))?;
+        if node.deactivated {
+            return Ok(Idempotent::Ignored);
+        }
+        if self
+            .all_accounts
+            .values()
+            .any(|child| child.spec.parent.as_ref() == Some(code) && !child.deactivated)
+        {
+            return Err(ChartOfAccountsError::NodeHasActiveChildren(code.clone()));
+        }
+
+        let child_account_set_ids = self.children(code);
+        let node = self.active_node_mut(code)?;
+        node.deactivated = true;
+        let deactivation = NodeDeactivation {
+            account_set_id: node.account_set_id,
+            child_account_set_ids,
+            manual_transaction_account_id: node.manual_transaction_account_id,
+        };
+        self.events.push(ChartEvent::NodeDeactivated {
+            code: code.clone(),
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(deactivation))
+    }
+
+    pub(super) fn move_node(
+        &mut self,
+        code: &AccountCode,
+        to_parent: AccountCode,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<NodeMove>, ChartOfAccountsError> {
+        let node = self
+            .all_accounts
+            .get(code)
+            .ok_or_else(|| ChartOfAccountsError::CodeNotFoundInChart(code.clone()))?;
+        if node.deactivated {
+            return Err(ChartOfAccountsError::AccountDeactivated(code.clone()));
+        }
+        if node.spec.parent.as_ref() == Some(&to_parent) {
+            return Ok(Idempotent::Ignored);
+        }
+        code.check_valid_parent(Some(to_parent.clone()))?;
+
+        let new_parent = self
+            .all_accounts
+            .get(&to_parent)
+            .ok_or_else(|| ChartOfAccountsError::ParentAccountNotFound(to_parent.to_string()))?;
+        if new_parent.deactivated {
+            return Err(ChartOfAccountsError::AccountDeactivated(to_parent));
+        }
+        if new_parent.spec.normal_balance_type != node.spec.normal_balance_type {
+            return Err(ChartOfAccountsError::NormalBalanceTypeMismatch(
+                code.clone(),
+                to_parent,
+            ));
+        }
+
+        let from_parent = node.spec.parent.clone();
+        let node_move = NodeMove {
+            account_set_id: node.account_set_id,
+            from_parent_account_set_id: from_parent
+                .as_ref()
+                .and_then(|parent| self.all_accounts.get(parent))
+                .map(|parent| parent.account_set_id),
+            to_parent_account_set_id: new_parent.account_set_id,
+        };
+
+        self.active_node_mut(code)?.spec.parent = Some(to_parent.clone());
+        self.events.push(ChartEvent::NodeMoved {
+            code: code.clone(),
+            from_parent,
+            to_parent,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(node_move))
+    }
+
+    fn active_node_mut(
+        &mut self,
+        code: &AccountCode,
+    ) -> Result<&mut AccountDetails, ChartOfAccountsError> {
+        let node = self
+            .all_accounts
+            .get_mut(code)
+            .ok_or_else(|| ChartOfAccountsError::CodeNotFoundInChart(code.clone()))?;
+        if node.deactivated {
+            return Err(ChartOfAccountsError::AccountDeactivated(code.clone()));
+        }
+        Ok(node)
+    }
+
+    pub fn is_deactivated(&self, code: &AccountCode) -> bool {
+        self.all_accounts
+            .get(code)
+            .is_some_and(|AccountDetails { deactivated, .. }| *deactivated)
+    }
+
+    /// Active account specs, in the order they are exported.
+    pub(super) fn active_account_specs(&self) -> Vec<&AccountSpec> {
+        let mut specs = self
+            .all_accounts
+            .values()
+            .filter(|AccountDetails { deactivated, .. }| !deactivated)
+            .map(|AccountDetails { spec, .. }| spec)
+            .collect::<Vec<_>>();
+        specs.sort_by(|a, b| a.code.cmp(&b.code));
+        specs
+    }
+
+    pub(super) fn import_diff(&self, specs: Vec<AccountSpec>) -> ChartImportDiff {
+        let mut diff = ChartImportDiff::default();
+        let mut seen = HashSet::new();
+        for spec in specs {
+            if !seen.insert(spec.code.clone()) {
+                diff.conflicting.push(ConflictingAccount {
+                    spec,
+                    conflict: ImportConflict::DuplicateCode,
+                });
+                continue;
+            }
+
+            let Some(current) = self.all_accounts.get(&spec.code) else {
+                if spec
+                    .parent
+                    .as_ref()
+                    .is_some_and(|parent| self.is_deactivated(parent))
+                {
+                    diff.conflicting.push(ConflictingAccount {
+                        spec,
+                        conflict: ImportConflict::ParentDeactivated,
+                    });
+                } else {
+                    diff.added.push(spec);
+                }
+                continue;
+            };
+
+            let conflict = if current.deactivated {
+                Some(ImportConflict::AccountDeactivated)
+            } else if current.spec.parent != spec.parent {
+                Some(ImportConflict::ParentMismatch {
+                    current: current.spec.parent.clone(),
+                })
+            } else if current.spec.normal_balance_type != spec.normal_balance_type {
+                Some(ImportConflict::NormalBalanceTypeMismatch {
+                    current: current.spec.normal_balance_type,
+                })
+            } else {
+                None
+            };
+
+            if let Some(conflict) = conflict {
+                diff.conflicting.push(ConflictingAccount { spec, conflict });
+            } else if current.spec.name != spec.name {
+                diff.changed.push(ChangedAccount {
+                    code: spec.code,
+                    current_name: current.spec.name.clone(),
+                    new_name: spec.name,
+                });
+            }
+        }
+        diff
     }
 
     pub(super) fn trial_balance_account_ids_from_new_accounts(
@@ -227,6 +451,9 @@ impl Chart {
         &self,
         code: &AccountCode,
     ) -> Result<(), ChartOfAccountsError> {
+        if self.is_deactivated(code) {
+            return Err(ChartOfAccountsError::AccountDeactivated(code.clone()));
+        }
         if !self.children::<CalaAccountSetId>(code).is_empty() {
             return Err(ChartOfAccountsError::NonLeafAccount(code.to_string()));
         };
@@ -339,6 +566,7 @@ impl TryFromEvents<ChartEvent> for Chart {
                             spec: spec.clone(),
                             account_set_id: *ledger_account_set_id,
                             manual_transaction_account_id: None,
+                            deactivated: false,
                         },
                     );
                 }
@@ -357,6 +585,23 @@ impl TryFromEvents<ChartEvent> for Chart {
                         *manual_transaction_account_id = Some(*ledger_account_id);
                     }
                 }
+                ChartEvent::NodeRenamed { code, name, .. } => {
+                    if let Some(AccountDetails { spec, .. }) = all_accounts.get_mut(code) {
+                        spec.name = name.clone();
+                    }
+                }
+                ChartEvent::NodeDeactivated { code, .. } => {
+                    if let Some(AccountDetails { deactivated, .. }) = all_accounts.get_mut(code) {
+                        *deactivated = true;
+                    }
+                }
+                ChartEvent::NodeMoved {
+                    code, to_parent, ..
+                } => {
+                    if let Some(AccountDetails { spec, .. }) = all_accounts.get_mut(code) {
+                        spec.parent = Some(to_parent.clone());
+                    }
+                }
             }
         }
         builder
@@ -408,6 +653,19 @@ struct AccountDetails {
     spec: AccountSpec,
     account_set_id: CalaAccountSetId,
     manual_transaction_account_id: Option<LedgerAccountId>,
+    deactivated: bool,
+}
+
+pub struct NodeDeactivation {
+    pub account_set_id: CalaAccountSetId,
+    pub child_account_set_ids: Vec<CalaAccountSetId>,
+    pub manual_transaction_account_id: Option<LedgerAccountId>,
+}
+
+pub struct NodeMove {
+    pub account_set_id: CalaAccountSetId,
+    pub from_parent_account_set_id: Option<CalaAccountSetId>,
+    pub to_parent_account_set_id: CalaAccountSetId,
 }
 
 pub struct NewChartAccountDetails {
@@ -461,6 +719,7 @@ mod test {
                 CalaJournalId::new(),
                 dummy_audit_info(),
             )
+            .unwrap()
             .expect("Already executed");
         let NewChartAccountDetails {
             new_account_set: level_2_new_account_set,
@@ -477,6 +736,7 @@ mod test {
                 CalaJournalId::new(),
                 dummy_audit_info(),
             )
+            .unwrap()
             .expect("Already executed");
         let NewChartAccountDetails {
             new_account_set: level_3_new_account_set,
@@ -493,6 +753,7 @@ mod test {
                 CalaJournalId::new(),
                 dummy_audit_info(),
             )
+            .unwrap()
             .expect("Already executed");
 
         (
@@ -535,17 +796,19 @@ mod test {
     fn unchecked_creates_node_if_parent_node_does_not_exist() {
         let (mut chart, _) = default_chart();
 
-        let res = chart.create_node_without_verifying_parent(
-            &AccountSpec::try_new(
-                Some(code("1.9")),
-                vec![section("1"), section("9"), section("1")],
-                "Cash".parse::<AccountName>().unwrap(),
-                DebitOrCredit::Debit,
+        let res = chart
+            .create_node_without_verifying_parent(
+                &AccountSpec::try_new(
+                    Some(code("1.9")),
+                    vec![section("1"), section("9"), section("1")],
+                    "Cash".parse::<AccountName>().unwrap(),
+                    DebitOrCredit::Debit,
+                )
+                .unwrap(),
+                CalaJournalId::new(),
+                dummy_audit_info(),
             )
-            .unwrap(),
-            CalaJournalId::new(),
-            dummy_audit_info(),
-        );
+            .unwrap();
         assert!(res.did_execute());
     }
 
@@ -583,6 +846,7 @@ mod test {
                 CalaJournalId::new(),
                 dummy_audit_info(),
             )
+            .unwrap()
             .expect("Already executed");
 
         let new_ids = chart
@@ -742,4 +1006,249 @@ mod test {
             chart.manual_transaction_account(AccountIdOrCode::Id(random_id), dummy_audit_info());
         assert!(matches!(res, Err(ChartOfAccountsError::NonLeafAccount(_))));
     }
+
+    #[test]
+    fn rename_node_updates_name() {
+        let (mut chart, (_, level_2_id, _)) = default_chart();
+
+        let res = chart
+            .rename_node(
+                &code("1.1"),
+                "Short-term Assets".parse().unwrap(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(res.unwrap(), level_2_id);
+        assert_eq!(
+            chart
+                .account_spec(&code("1.1"))
+                .unwrap()
+                .spec
+                .name
+                .to_string(),
+            "Short-term Assets"
+        );
+
+        let res = chart
+            .rename_node(
+                &code("1.1"),
+                "Short-term Assets".parse().unwrap(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert!(res.was_ignored());
+    }
+
+    #[test]
+    fn deactivate_node_requires_inactive_children() {
+        let (mut chart, _) = default_chart();
+
+        let res = chart.deactivate_node(&code("1.1"), dummy_audit_info());
+        assert!(matches!(
+            res,
+            Err(ChartOfAccountsError::NodeHasActiveChildren(_))
+        ));
+
+        assert!(
+            chart
+                .deactivate_node(&code("1.1.1"), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            chart
+                .deactivate_node(&code("1.1"), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(chart.is_deactivated(&code("1.1")));
+    }
+
+    #[test]
+    fn deactivated_node_blocks_manual_transactions_and_children() {
+        let (mut chart, _) = default_chart();
+        chart
+            .deactivate_node(&code("1.1.1"), dummy_audit_info())
+            .unwrap()
+            .expect("Already deactivated");
+
+        let res = chart
+            .manual_transaction_account(AccountIdOrCode::Code(code("1.1.1")), dummy_audit_info());
+        assert!(matches!(
+            res,
+            Err(ChartOfAccountsError::AccountDeactivated(_))
+        ));
+
+        let res = chart.create_child_node(
+            code("1.1.1"),
+            code("1.1.1.1"),
+            "Petty Cash".parse::<AccountName>().unwrap(),
+            CalaJournalId::new(),
+            dummy_audit_info(),
+        );
+        assert!(matches!(
+            res,
+            Err(ChartOfAccountsError::AccountDeactivated(_))
+        ));
+    }
+
+    #[test]
+    fn deactivation_survives_rehydration() {
+        let (mut chart, _) = default_chart();
+        chart
+            .deactivate_node(&code("1.1.1"), dummy_audit_info())
+            .unwrap()
+            .expect("Already deactivated");
+
+        let events = chart.events.iter_all().cloned().collect::<Vec<_>>();
+        let chart = chart_from(events);
+        assert!(chart.is_deactivated(&code("1.1.1")));
+    }
+
+    #[test]
+    fn move_node_to_valid_parent() {
+        let (mut chart, (level_1_id, level_2_id, level_3_id)) = default_chart();
+
+        let NodeMove {
+            account_set_id,
+            from_parent_account_set_id,
+            to_parent_account_set_id,
+        } = chart
+            .move_node(&code("1.1.1"), code("1"), dummy_audit_info())
+            .unwrap()
+            .unwrap();
+        assert_eq!(account_set_id, level_3_id);
+        assert_eq!(from_parent_account_set_id, Some(level_2_id));
+        assert_eq!(to_parent_account_set_id, level_1_id);
+        assert_eq!(chart.children::<CalaAccountSetId>(&code("1")).len(), 2);
+
+        let res = chart
+            .move_node(&code("1.1.1"), code("1"), dummy_audit_info())
+            .unwrap();
+        assert!(res.was_ignored());
+    }
+
+    #[test]
+    fn move_node_rejects_invalid_parent() {
+        let (mut chart, _) = default_chart();
+        chart
+            .create_node_without_verifying_parent(
+                &AccountSpec::try_new(
+                    None,
+                    vec![section("2")],
+                    "Liabilities".parse::<AccountName>().unwrap(),
+                    DebitOrCredit::Credit,
+                )
+                .unwrap(),
+                CalaJournalId::new(),
+                dummy_audit_info(),
+            )
+            .unwrap()
+            .expect("Already executed");
+
+        let res = chart.move_node(&code("1.1"), code("2"), dummy_audit_info());
+        assert!(matches!(res, Err(ChartOfAccountsError::AccountCode(_))));
+
+        chart
+            .deactivate_node(&code("1.1.1"), dummy_audit_info())
+            .unwrap()
+            .expect("Already deactivated");
+        let res = chart.move_node(&code("1.1.1"), code("1"), dummy_audit_info());
+        assert!(matches!(
+            res,
+            Err(ChartOfAccountsError::AccountDeactivated(_))
+        ));
+    }
+
+    #[test]
+    fn import_diff_reports_added_changed_and_conflicting() {
+        let (mut chart, _) = default_chart();
+        chart
+            .deactivate_node(&code("1.1.1"), dummy_audit_info())
+            .unwrap()
+            .expect("Already deactivated");
+
+        let data = r#"
+        1,,,Assets,Debit
+        1,1,,Short-term Assets
+        1,1,1,Cash
+        1,1,2,Bank
+        1,1,2,Bank
+        1,1,1,1,Petty Cash
+        2,,,Liabilities,Credit
+        "#;
+        let specs = super::super::CsvParser::new(data.to_string())
+            .account_specs()
+            .unwrap();
+        let diff = chart.import_diff(specs);
+
+        let added = diff
+            .added
+            .iter()
+            .map(|spec| spec.code.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(added, vec!["1.1.2", "2"]);
+
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].code, code("1.1"));
+        assert_eq!(diff.changed[0].new_name.to_string(), "Short-term Assets");
+
+        let conflicts = diff
+            .conflicting
+            .iter()
+            .map(|c| (c.spec.code.to_string(), c.conflict.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            conflicts,
+            vec![
+                ("1.1.1".to_string(), ImportConflict::AccountDeactivated),
+                ("1.1.2".to_string(), ImportConflict::DuplicateCode),
+                ("1.1.1.1".to_string(), ImportConflict::ParentDeactivated),
+            ]
+        );
+    }
+
+    #[test]
+    fn import_rejects_accounts_under_deactivated_parent() {
+        let (mut chart, _) = default_chart();
+        chart
+            .deactivate_node(&code("1.1.1"), dummy_audit_info())
+            .unwrap()
+            .expect("Already deactivated");
+
+        let data = r#"
+        1,,,Assets,Debit
+        1,1,,Current Assets
+        1,1,1,Cash
+        1,1,1,1,Petty Cash
+        "#;
+        let specs = super::super::CsvParser::new(data.to_string())
+            .account_specs()
+            .unwrap();
+        let res = specs.iter().try_for_each(|spec| {
+            chart
+                .create_node_without_verifying_parent(
+                    spec,
+                    CalaJournalId::new(),
+                    dummy_audit_info(),
+                )
+                .map(|_| ())
+        });
+        assert!(matches!(
+            res,
+            Err(ChartOfAccountsError::AccountDeactivated(parent)) if parent == code("1.1.1")
+        ));
+        assert!(!chart.all_accounts.contains_key(&code("1.1.1.1")));
+    }
+
+    #[test]
+    fn import_diff_is_empty_for_exported_chart() {
+        let (chart, _) = default_chart();
+
+        let exported = super::super::CsvExporter::new(chart.active_account_specs()).export();
+        let specs = super::super::CsvParser::new(exported)
+            .account_specs()
+            .unwrap();
+        assert!(chart.import_diff(specs).is_empty());
+    }
 }
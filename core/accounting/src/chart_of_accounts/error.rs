@@ -26,6 +26,17 @@ pub enum ChartOfAccountsError {
     NonLeafAccount(String),
     #[error("ChartOfAccountsError - ParentAccountNotFound: {0}")]
     ParentAccountNotFound(String),
+    #[error("ChartOfAccountsError - AccountDeactivated: {0}")]
+    AccountDeactivated(crate::primitives::AccountCode),
+    #[error("ChartOfAccountsError - NodeHasActiveChildren: {0}")]
+    NodeHasActiveChildren(crate::primitives::AccountCode),
+    #[error("ChartOfAccountsError - NodeHasLedgerAccounts: {0}")]
+    NodeHasLedgerAccounts(crate::primitives::AccountCode),
+    #[error("ChartOfAccountsError - NormalBalanceTypeMismatch: {0} cannot move under {1}")]
+    NormalBalanceTypeMismatch(
+        crate::primitives::AccountCode,
+        crate::primitives::AccountCode,
+    ),
 }
 
 es_entity::from_es_entity_error!(ChartOfAccountsError);
@@ -0,0 +1,41 @@
+use cala_ledger::DebitOrCredit;
+
+use crate::primitives::{AccountCode, AccountName, AccountSpec};
+
+/// Outcome of comparing a CSV import against the current chart without applying it.
+#[derive(Debug, Clone, Default)]
+pub struct ChartImportDiff {
+    pub added: Vec<AccountSpec>,
+    pub changed: Vec<ChangedAccount>,
+    pub conflicting: Vec<ConflictingAccount>,
+}
+
+impl ChartImportDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.conflicting.is_empty()
+    }
+}
+
+/// An existing account whose name differs in the import. Importing does not
+/// rename accounts, so these have to be applied with `rename_node`.
+#[derive(Debug, Clone)]
+pub struct ChangedAccount {
+    pub code: AccountCode,
+    pub current_name: AccountName,
+    pub new_name: AccountName,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConflictingAccount {
+    pub spec: AccountSpec,
+    pub conflict: ImportConflict,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportConflict {
+    ParentMismatch { current: Option<AccountCode> },
+    NormalBalanceTypeMismatch { current: DebitOrCredit },
+    AccountDeactivated,
+    ParentDeactivated,
+    DuplicateCode,
+}
@@ -1,6 +1,7 @@
 mod csv;
 mod entity;
 pub mod error;
+mod import_diff;
 mod repo;
 pub mod tree;
 
@@ -9,19 +10,24 @@ use tracing::instrument;
 use audit::AuditSvc;
 use authz::PermissionCheck;
 
-use cala_ledger::{CalaLedger, account::Account};
+use cala_ledger::{
+    CalaLedger, Status,
+    account::{Account, AccountUpdate},
+    account_set::{AccountSetMemberId, AccountSetUpdate},
+};
 
 use crate::primitives::{
     AccountCode, AccountIdOrCode, AccountName, AccountSpec, CalaAccountSetId, CalaJournalId,
     ChartId, CoreAccountingAction, CoreAccountingObject, LedgerAccountId,
 };
 
-pub(super) use csv::{CsvParseError, CsvParser};
+pub(super) use csv::{CsvExporter, CsvParseError, CsvParser};
 pub use entity::Chart;
 #[cfg(feature = "json-schema")]
 pub use entity::ChartEvent;
 pub(super) use entity::*;
 use error::*;
+pub use import_diff::*;
 pub(super) use repo::*;
 
 pub struct ChartOfAccounts<Perms>
@@ -140,7 +146,7 @@ where
                 &spec,
                 self.journal_id,
                 audit_info.clone(),
-            ) {
+            )? {
                 let account_set_id = new_account_set.id;
                 new_account_sets.push(new_account_set);
                 if let Some(parent) = parent_account_set_id {
@@ -179,6 +185,56 @@ where
         Ok((chart, Some(new_account_set_ids.clone())))
     }
 
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.import_from_csv_dry_run",
+        skip(self, data),
+        err
+    )]
+    pub async fn import_from_csv_dry_run(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+        data: impl AsRef<str>,
+    ) -> Result<ChartImportDiff, ChartOfAccountsError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_IMPORT_ACCOUNTS,
+            )
+            .await?;
+        let chart = self.repo.find_by_id(id).await?;
+
+        let data = data.as_ref().to_string();
+        let account_specs = CsvParser::new(data).account_specs()?;
+
+        Ok(chart.import_diff(account_specs))
+    }
+
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.export_to_csv",
+        skip(self),
+        err
+    )]
+    pub async fn export_to_csv(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+    ) -> Result<String, ChartOfAccountsError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_LIST,
+            )
+            .await?;
+        let chart = self.repo.find_by_id(id).await?;
+
+        Ok(CsvExporter::new(chart.active_account_specs()).export())
+    }
+
     #[instrument(
         name = "core_accounting.chart_of_accounts.add_root_node",
         skip(self,),
@@ -205,7 +261,11 @@ where
         let es_entity::Idempotent::Executed(NewChartAccountDetails {
             parent_account_set_id: _,
             new_account_set,
-        }) = chart.create_node_without_verifying_parent(&spec, self.journal_id, audit_info.clone())
+        }) = chart.create_node_without_verifying_parent(
+            &spec,
+            self.journal_id,
+            audit_info.clone(),
+        )?
         else {
             return Ok((chart, None));
         };
@@ -290,6 +350,187 @@ where
         Ok((chart, new_account_set_id))
     }
 
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.rename_node",
+        skip(self),
+        err
+    )]
+    pub async fn rename_node(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+        code: AccountCode,
+        name: AccountName,
+    ) -> Result<Chart, ChartOfAccountsError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_UPDATE,
+            )
+            .await?;
+        let mut chart = self.repo.find_by_id(id).await?;
+
+        let es_entity::Idempotent::Executed(account_set_id) =
+            chart.rename_node(&code, name.clone(), audit_info)?
+        else {
+            return Ok(chart);
+        };
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo.update_in_op(&mut op, &mut chart).await?;
+
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+        let mut account_set = self.cala.account_sets().find(account_set_id).await?;
+        let mut update = AccountSetUpdate::default();
+        update.name(name.to_string()).description(name.to_string());
+        account_set.update(update);
+        self.cala
+            .account_sets()
+            .persist_in_op(&mut op, &mut account_set)
+            .await?;
+
+        op.commit().await?;
+
+        Ok(chart)
+    }
+
+    #[instrument(
+        name = "core_accounting.chart_of_accounts.deactivate_node",
+        skip(self),
+        err
+    )]
+    pub async fn deactivate_node(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+        code: AccountCode,
+    ) -> Result<Chart, ChartOfAccountsError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_UPDATE,
+            )
+            .await?;
+        let mut chart = self.repo.find_by_id(id).await?;
+
+        let es_entity::Idempotent::Executed(NodeDeactivation {
+            account_set_id,
+            child_account_set_ids,
+            manual_transaction_account_id,
+        }) = chart.deactivate_node(&code, audit_info)?
+        else {
+            return Ok(chart);
+        };
+
+        // Module accounts attached through an integration config would keep
+        // accepting postings, so the integration has to be moved off first.
+        let mut after = None;
+        loop {
+            let members = self
+                .cala
+                .account_sets()
+                .list_members_by_created_at(
+                    account_set_id,
+                    es_entity::PaginatedQueryArgs { first: 100, after },
+                )
+                .await?;
+            if members.entities.iter().any(|member| match member.id {
+                AccountSetMemberId::Account(account_id) => {
+                    manual_transaction_account_id != Some(account_id.into())
+                }
+                AccountSetMemberId::AccountSet(set_id) => !child_account_set_ids.contains(&set_id),
+            }) {
+                return Err(ChartOfAccountsError::NodeHasLedgerAccounts(code));
+            }
+            if !members.has_next_page {
+                break;
+            }
+            after = members.end_cursor;
+        }
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo.update_in_op(&mut op, &mut chart).await?;
+
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+        if let Some(manual_transaction_account_id) = manual_transaction_account_id {
+            let mut account = self
+                .cala
+                .accounts()
+                .find(manual_transaction_account_id.into())
+                .await?;
+            let mut update = AccountUpdate::default();
+            update.status(Status::Locked);
+            account.update(update);
+            self.cala
+                .accounts()
+                .persist_in_op(&mut op, &mut account)
+                .await?;
+        }
+        op.commit().await?;
+
+        Ok(chart)
+    }
+
+    #[instrument(name = "core_accounting.chart_of_accounts.move_node", skip(self), err)]
+    pub async fn move_node(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ChartId> + std::fmt::Debug,
+        code: AccountCode,
+        new_parent_code: AccountCode,
+    ) -> Result<Chart, ChartOfAccountsError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::chart(id),
+                CoreAccountingAction::CHART_UPDATE,
+            )
+            .await?;
+        let mut chart = self.repo.find_by_id(id).await?;
+
+        let es_entity::Idempotent::Executed(NodeMove {
+            account_set_id,
+            from_parent_account_set_id,
+            to_parent_account_set_id,
+        }) = chart.move_node(&code, new_parent_code, audit_info)?
+        else {
+            return Ok(chart);
+        };
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo.update_in_op(&mut op, &mut chart).await?;
+
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+        if let Some(from_parent) = from_parent_account_set_id {
+            self.cala
+                .account_sets()
+                .remove_member_in_op(&mut op, from_parent, account_set_id)
+                .await?;
+        }
+        self.cala
+            .account_sets()
+            .add_member_in_op(&mut op, to_parent_account_set_id, account_set_id)
+            .await?;
+
+        op.commit().await?;
+
+        Ok(chart)
+    }
+
     #[instrument(name = "core_accounting.chart_of_accounts.find_by_id", skip(self), err)]
     pub async fn find_by_id(
         &self,
@@ -20,6 +20,7 @@ pub struct TreeNode {
     pub code: AccountCode,
     pub name: AccountName,
     pub parent: Option<AccountCode>,
+    pub deactivated: bool,
     pub children: Vec<TreeNode>,
 }
 
@@ -29,6 +30,7 @@ pub struct TreeNodeWithRef {
     code: AccountCode,
     name: AccountName,
     parent: Option<AccountCode>,
+    deactivated: bool,
     children: Vec<Rc<RefCell<TreeNodeWithRef>>>,
 }
 
@@ -39,6 +41,7 @@ impl TreeNodeWithRef {
             code: self.code,
             name: self.name,
             parent: self.parent,
+            deactivated: self.deactivated,
             children: self
                 .children
                 .into_iter()
@@ -57,6 +60,7 @@ impl TreeNodeWithRef {
 pub struct EntityNode {
     pub id: CalaAccountSetId,
     pub spec: AccountSpec,
+    pub deactivated: bool,
 }
 
 pub(super) fn project<'a>(events: impl DoubleEndedIterator<Item = &'a ChartEvent>) -> ChartTree {
@@ -81,7 +85,25 @@ pub(super) fn project<'a>(events: impl DoubleEndedIterator<Item = &'a ChartEvent
             } => entity_nodes.push(EntityNode {
                 id: *id,
                 spec: spec.clone(),
+                deactivated: false,
             }),
+            ChartEvent::NodeRenamed { code, name, .. } => {
+                if let Some(node) = entity_nodes.iter_mut().find(|n| &n.spec.code == code) {
+                    node.spec.name = name.clone();
+                }
+            }
+            ChartEvent::NodeDeactivated { code, .. } => {
+                if let Some(node) = entity_nodes.iter_mut().find(|n| &n.spec.code == code) {
+                    node.deactivated = true;
+                }
+            }
+            ChartEvent::NodeMoved {
+                code, to_parent, ..
+            } => {
+                if let Some(node) = entity_nodes.iter_mut().find(|n| &n.spec.code == code) {
+                    node.spec.parent = Some(to_parent.clone());
+                }
+            }
             _ => (),
         }
     }
@@ -97,6 +119,7 @@ pub(super) fn project<'a>(events: impl DoubleEndedIterator<Item = &'a ChartEvent
             code: node.spec.code.clone(),
             name: node.spec.name.clone(),
             parent: node.spec.parent.clone(),
+            deactivated: node.deactivated,
             children: vec![],
         }));
         if let Some(parent) = node.spec.parent {
@@ -185,6 +208,7 @@ mod tests {
                     CalaJournalId::new(),
                     dummy_audit_info(),
                 )
+                .unwrap()
                 .unwrap();
             chart
                 .create_node_without_verifying_parent(
@@ -197,6 +221,7 @@ mod tests {
                     CalaJournalId::new(),
                     dummy_audit_info(),
                 )
+                .unwrap()
                 .unwrap();
             chart
                 .create_node_without_verifying_parent(
@@ -211,6 +236,7 @@ mod tests {
                     CalaJournalId::new(),
                     dummy_audit_info(),
                 )
+                .unwrap()
                 .unwrap();
             chart
                 .create_node_without_verifying_parent(
@@ -230,6 +256,7 @@ mod tests {
                     CalaJournalId::new(),
                     dummy_audit_info(),
                 )
+                .unwrap()
                 .unwrap();
         }
         let tree = chart.chart();
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ComparativeStatementError {
+    #[error("ComparativeStatementError - LedgerAccountError: {0}")]
+    LedgerAccountError(#[from] crate::ledger_account::error::LedgerAccountError),
+    #[error("ComparativeStatementError - BalanceSheetError: {0}")]
+    BalanceSheetError(#[from] crate::balance_sheet::error::BalanceSheetError),
+    #[error("ComparativeStatementError - ProfitAndLossStatementError: {0}")]
+    ProfitAndLossStatementError(#[from] crate::profit_and_loss::error::ProfitAndLossStatementError),
+    #[error("ComparativeStatementError - NoPeriods")]
+    NoPeriods,
+    #[error("ComparativeStatementError - InvalidDateRange: {0} is after {1}")]
+    InvalidDateRange(chrono::NaiveDate, chrono::NaiveDate),
+}
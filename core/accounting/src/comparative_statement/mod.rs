@@ -0,0 +1,319 @@
+pub mod error;
+mod primitives;
+
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+
+use crate::{
+    balance_sheet::BalanceSheets,
+    ledger_account::{LedgerAccount, LedgerAccounts},
+    primitives::{
+        BalanceRange, CalaAccountSetId, CoreAccountingAction, CoreAccountingObject, CurrencyCode,
+        LedgerAccountId,
+    },
+    profit_and_loss::ProfitAndLossStatements,
+};
+
+use error::*;
+pub use primitives::*;
+
+pub struct ComparativeStatements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    balance_sheets: BalanceSheets<Perms>,
+    profit_and_loss: ProfitAndLossStatements<Perms>,
+    ledger_accounts: LedgerAccounts<Perms>,
+}
+
+impl<Perms> Clone for ComparativeStatements<Perms>
+where
+    Perms: PermissionCheck,
+{
+    fn clone(&self) -> Self {
+        Self {
+            balance_sheets: self.balance_sheets.clone(),
+            profit_and_loss: self.profit_and_loss.clone(),
+            ledger_accounts: self.ledger_accounts.clone(),
+        }
+    }
+}
+
+impl<Perms> ComparativeStatements<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub(crate) fn new(
+        balance_sheets: &BalanceSheets<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        ledger_accounts: &LedgerAccounts<Perms>,
+    ) -> Self {
+        Self {
+            balance_sheets: balance_sheets.clone(),
+            profit_and_loss: profit_and_loss.clone(),
+            ledger_accounts: ledger_accounts.clone(),
+        }
+    }
+
+    /// Compares closing balances at the end of each period.
+    #[instrument(
+        name = "core_accounting.comparative_statement.balance_sheet",
+        skip(self),
+        err
+    )]
+    pub async fn balance_sheet(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        periods: Vec<ComparativePeriod>,
+    ) -> Result<ComparativeStatement, ComparativeStatementError> {
+        validate_periods(&periods)?;
+
+        let mut snapshots = Vec::with_capacity(periods.len());
+        for period in periods.iter() {
+            let balance_sheet = self
+                .balance_sheets
+                .balance_sheet(sub, reference.clone(), period.from, Some(period.until))
+                .await?;
+            snapshots.push(StatementSnapshot {
+                balance_ranges: balance_sheet
+                    .balance_ranges()
+                    .into_iter()
+                    .map(|(currency, range)| (currency, range.clone()))
+                    .collect(),
+                id: balance_sheet.id,
+                name: balance_sheet.name,
+                category_ids: balance_sheet.category_ids,
+            });
+        }
+
+        self.compare(periods, snapshots, closing_balance).await
+    }
+
+    /// Compares the activity within each period.
+    #[instrument(
+        name = "core_accounting.comparative_statement.pl_statement",
+        skip(self),
+        err
+    )]
+    pub async fn pl_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        reference: String,
+        periods: Vec<ComparativePeriod>,
+    ) -> Result<ComparativeStatement, ComparativeStatementError> {
+        validate_periods(&periods)?;
+
+        let mut snapshots = Vec::with_capacity(periods.len());
+        for period in periods.iter() {
+            let pl_statement = self
+                .profit_and_loss
+                .pl_statement(sub, reference.clone(), period.from, Some(period.until))
+                .await?;
+            snapshots.push(StatementSnapshot {
+                balance_ranges: pl_statement
+                    .balance_ranges()
+                    .into_iter()
+                    .map(|(currency, range)| (currency, range.clone()))
+                    .collect(),
+                id: pl_statement.id,
+                name: pl_statement.name,
+                category_ids: pl_statement.category_ids,
+            });
+        }
+
+        self.compare(periods, snapshots, period_activity).await
+    }
+
+    async fn compare(
+        &self,
+        periods: Vec<ComparativePeriod>,
+        snapshots: Vec<StatementSnapshot>,
+        amount_of: fn(&BalanceRange) -> Decimal,
+    ) -> Result<ComparativeStatement, ComparativeStatementError> {
+        let mut accounts_by_period = Vec::with_capacity(periods.len());
+        for (period, snapshot) in periods.iter().zip(snapshots.iter()) {
+            accounts_by_period.push(self.categorized_accounts(snapshot, period).await?);
+        }
+
+        // The account structure is the same in every period, so the first one
+        // determines the line order.
+        let layout: Vec<(usize, &LedgerAccount)> = accounts_by_period[0]
+            .iter()
+            .map(|(depth, account)| (*depth, account))
+            .collect();
+        let lookups: Vec<HashMap<LedgerAccountId, &LedgerAccount>> = accounts_by_period
+            .iter()
+            .map(|accounts| {
+                accounts
+                    .iter()
+                    .map(|(_, account)| (account.id, account))
+                    .collect()
+            })
+            .collect();
+
+        let mut lines = Vec::new();
+        let mut totals = Vec::new();
+        for currency in currencies(&snapshots) {
+            let snapshot_ranges: Vec<_> = snapshots
+                .iter()
+                .map(|snapshot| snapshot.balance_range(&currency))
+                .collect();
+
+            for (depth, account) in layout.iter() {
+                let amounts = lookups
+                    .iter()
+                    .map(|lookup| {
+                        lookup
+                            .get(&account.id)
+                            .and_then(|account| account_balance_range(account, &currency))
+                            .map(amount_of)
+                            .unwrap_or_default()
+                    })
+                    .collect();
+                let line = ComparativeLine::new(
+                    account.id,
+                    account.code.clone(),
+                    account.name.clone(),
+                    *depth,
+                    currency.clone(),
+                    amounts,
+                );
+                if *depth == 0 || !line.is_zero() {
+                    lines.push(line);
+                }
+            }
+
+            let first = &snapshots[0];
+            totals.push(ComparativeLine::new(
+                first.id,
+                None,
+                first.name.clone(),
+                0,
+                currency,
+                snapshot_ranges
+                    .into_iter()
+                    .map(|range| range.map(amount_of).unwrap_or_default())
+                    .collect(),
+            ));
+        }
+
+        let first = snapshots.into_iter().next().expect("at least one period");
+        Ok(ComparativeStatement {
+            id: first.id,
+            name: first.name,
+            periods,
+            lines,
+            totals,
+        })
+    }
+
+    async fn categorized_accounts(
+        &self,
+        snapshot: &StatementSnapshot,
+        period: &ComparativePeriod,
+    ) -> Result<Vec<(usize, LedgerAccount)>, ComparativeStatementError> {
+        let mut categories = self
+            .ledger_accounts
+            .complete_children(
+                CalaAccountSetId::from(snapshot.id),
+                period.from,
+                period.until,
+                false,
+            )
+            .await?;
+        let mut accounts = Vec::new();
+        for category_id in snapshot.category_ids.iter() {
+            let Some(idx) = categories.iter().position(|c| c.id == *category_id) else {
+                continue;
+            };
+            let category = categories.swap_remove(idx);
+            let children = self
+                .ledger_accounts
+                .complete_children(
+                    CalaAccountSetId::from(category.id),
+                    period.from,
+                    period.until,
+                    false,
+                )
+                .await?;
+            accounts.push((0, category));
+            accounts.extend(children.into_iter().map(|child| (1, child)));
+        }
+        Ok(accounts)
+    }
+}
+
+struct StatementSnapshot {
+    id: LedgerAccountId,
+    name: String,
+    balance_ranges: Vec<(CurrencyCode, BalanceRange)>,
+    category_ids: Vec<LedgerAccountId>,
+}
+
+impl StatementSnapshot {
+    fn balance_range(&self, currency: &CurrencyCode) -> Option<&BalanceRange> {
+        self.balance_ranges
+            .iter()
+            .find(|(c, _)| c == currency)
+            .map(|(_, range)| range)
+    }
+}
+
+/// Every currency the ledger holds statement balances in for any period, in
+/// the order they are first reported.
+fn currencies(snapshots: &[StatementSnapshot]) -> Vec<CurrencyCode> {
+    let mut currencies: Vec<CurrencyCode> = Vec::new();
+    for (currency, _) in snapshots.iter().flat_map(|s| s.balance_ranges.iter()) {
+        if !currencies.contains(currency) {
+            currencies.push(currency.clone());
+        }
+    }
+    currencies
+}
+
+fn account_balance_range<'a>(
+    account: &'a LedgerAccount,
+    currency: &CurrencyCode,
+) -> Option<&'a BalanceRange> {
+    account
+        .balance_ranges()
+        .into_iter()
+        .find(|(c, _)| c == currency)
+        .map(|(_, range)| range)
+}
+
+fn closing_balance(range: &BalanceRange) -> Decimal {
+    range
+        .close
+        .as_ref()
+        .map(|balance| balance.settled())
+        .unwrap_or_default()
+}
+
+fn period_activity(range: &BalanceRange) -> Decimal {
+    range
+        .period_activity
+        .as_ref()
+        .map(|balance| balance.settled())
+        .unwrap_or_default()
+}
+
+fn validate_periods(periods: &[ComparativePeriod]) -> Result<(), ComparativeStatementError> {
+    if periods.is_empty() {
+        return Err(ComparativeStatementError::NoPeriods);
+    }
+    if let Some(period) = periods.iter().find(|period| period.from > period.until) {
+        return Err(ComparativeStatementError::InvalidDateRange(
+            period.from,
+            period.until,
+        ));
+    }
+    Ok(())
+}
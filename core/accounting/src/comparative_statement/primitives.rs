@@ -0,0 +1,198 @@
+use chrono::{Datelike, Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{AccountCode, CurrencyCode, LedgerAccountId};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ComparisonPreset {
+    /// Month to date against the same days of the prior month.
+    MonthOverMonth,
+    /// Year to date against the same days of the prior year.
+    YearToDateOverPriorYear,
+}
+
+impl ComparisonPreset {
+    /// Column sets for the preset, current period first.
+    pub fn periods(&self, as_of: NaiveDate) -> Vec<ComparativePeriod> {
+        match self {
+            Self::MonthOverMonth => {
+                let month_start = as_of.with_day(1).expect("first of month");
+                // Same number of days into the prior month, capped at its last day
+                let prior_until = as_of - Months::new(1);
+                vec![
+                    ComparativePeriod::new(month_start, as_of),
+                    ComparativePeriod::new(month_start - Months::new(1), prior_until),
+                ]
+            }
+            Self::YearToDateOverPriorYear => {
+                let year_start = as_of.with_ordinal(1).expect("first of year");
+                let prior_until = as_of - Months::new(12);
+                vec![
+                    ComparativePeriod::new(year_start, as_of),
+                    ComparativePeriod::new(year_start - Months::new(12), prior_until),
+                ]
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComparativePeriod {
+    pub from: NaiveDate,
+    pub until: NaiveDate,
+}
+
+impl ComparativePeriod {
+    pub fn new(from: NaiveDate, until: NaiveDate) -> Self {
+        Self { from, until }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Variance {
+    pub absolute: Decimal,
+    /// `None` when the compared amount is zero.
+    pub percent: Option<Decimal>,
+}
+
+impl Variance {
+    pub(super) fn between(current: Decimal, compared: Decimal) -> Self {
+        let absolute = current - compared;
+        let percent = if compared.is_zero() {
+            None
+        } else {
+            Some((absolute / compared.abs() * Decimal::ONE_HUNDRED).round_dp(2))
+        };
+        Self { absolute, percent }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ComparativeLine {
+    pub ledger_account_id: LedgerAccountId,
+    pub code: Option<AccountCode>,
+    pub name: String,
+    pub depth: usize,
+    pub currency: CurrencyCode,
+    /// One amount per period, in the order the periods were requested.
+    pub amounts: Vec<Decimal>,
+    /// Variance of the first period against each of the following periods.
+    pub variances: Vec<Variance>,
+}
+
+impl ComparativeLine {
+    pub(super) fn new(
+        ledger_account_id: LedgerAccountId,
+        code: Option<AccountCode>,
+        name: String,
+        depth: usize,
+        currency: CurrencyCode,
+        amounts: Vec<Decimal>,
+    ) -> Self {
+        let variances = match amounts.split_first() {
+            Some((current, compared)) => compared
+                .iter()
+                .map(|compared| Variance::between(*current, *compared))
+                .collect(),
+            None => Vec::new(),
+        };
+        Self {
+            ledger_account_id,
+            code,
+            name,
+            depth,
+            currency,
+            amounts,
+            variances,
+        }
+    }
+
+    pub(super) fn is_zero(&self) -> bool {
+        self.amounts.iter().all(|amount| amount.is_zero())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ComparativeStatement {
+    pub id: LedgerAccountId,
+    pub name: String,
+    pub periods: Vec<ComparativePeriod>,
+    /// Categories at depth 0, each followed by its accounts at depth 1, per currency.
+    pub lines: Vec<ComparativeLine>,
+    /// Statement totals, one per currency.
+    pub totals: Vec<ComparativeLine>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn month_over_month_periods() {
+        let periods = ComparisonPreset::MonthOverMonth.periods(date(2024, 3, 15));
+        assert_eq!(
+            periods,
+            vec![
+                ComparativePeriod::new(date(2024, 3, 1), date(2024, 3, 15)),
+                ComparativePeriod::new(date(2024, 2, 1), date(2024, 2, 15)),
+            ]
+        );
+    }
+
+    #[test]
+    fn month_over_month_periods_at_month_end() {
+        let periods = ComparisonPreset::MonthOverMonth.periods(date(2024, 3, 31));
+        assert_eq!(
+            periods,
+            vec![
+                ComparativePeriod::new(date(2024, 3, 1), date(2024, 3, 31)),
+                ComparativePeriod::new(date(2024, 2, 1), date(2024, 2, 29)),
+            ]
+        );
+    }
+
+    #[test]
+    fn year_to_date_periods() {
+        let periods = ComparisonPreset::YearToDateOverPriorYear.periods(date(2024, 2, 29));
+        assert_eq!(
+            periods,
+            vec![
+                ComparativePeriod::new(date(2024, 1, 1), date(2024, 2, 29)),
+                ComparativePeriod::new(date(2023, 1, 1), date(2023, 2, 28)),
+            ]
+        );
+    }
+
+    #[test]
+    fn variance_against_each_compared_period() {
+        let line = ComparativeLine::new(
+            LedgerAccountId::new(),
+            None,
+            "Revenue".to_string(),
+            0,
+            CurrencyCode::USD,
+            vec![Decimal::from(150), Decimal::from(-100), Decimal::ZERO],
+        );
+        assert_eq!(
+            line.variances,
+            vec![
+                Variance {
+                    absolute: Decimal::from(250),
+                    percent: Some(Decimal::from(250)),
+                },
+                Variance {
+                    absolute: Decimal::from(150),
+                    percent: None,
+                },
+            ]
+        );
+    }
+}
@@ -8,6 +8,10 @@ pub enum CoreAccountingError {
     ChartOfAccountsError(#[from] super::chart_of_accounts_error::ChartOfAccountsError),
     #[error("CoreAccountingError - LedgerAccount: {0}")]
     LedgerAccountError(#[from] super::ledger_account::error::LedgerAccountError),
+    #[error("CoreAccountingError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("CoreAccountingError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
     #[error("CoreAccountingError - ManualTransaction: {0}")]
     ManualTransactionError(#[from] super::manual_transaction::error::ManualTransactionError),
     #[error("CoreAccountingError - LedgerTransaction: {0}")]
@@ -18,4 +22,10 @@ pub enum CoreAccountingError {
     AccountingCsvExportError(#[from] super::csv::error::AccountingCsvExportError),
     #[error("CoreAccountingError - TrialBalanceError: {0}")]
     TrialBalance(#[from] super::trial_balance::error::TrialBalanceError),
+    #[error("CoreAccountingError - AccountingPeriodError: {0}")]
+    AccountingPeriodError(#[from] super::accounting_period::error::AccountingPeriodError),
+    #[error("CoreAccountingError - ProfitAndLossStatementError: {0}")]
+    ProfitAndLossStatementError(#[from] super::profit_and_loss::error::ProfitAndLossStatementError),
+    #[error("CoreAccountingError - ProfitAndLossConfigNotFound: {0}")]
+    ProfitAndLossConfigNotFound(String),
 }
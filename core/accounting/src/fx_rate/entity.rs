@@ -0,0 +1,197 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use rust_decimal::Decimal;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{CurrencyCode, FxRateId};
+
+use super::error::FxRateError;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "FxRateId")]
+pub enum FxRateEvent {
+    Initialized {
+        id: FxRateId,
+        currency: CurrencyCode,
+        rate_date: NaiveDate,
+        rate: Decimal,
+        audit_info: AuditInfo,
+    },
+    RateUpdated {
+        rate: Decimal,
+        audit_info: AuditInfo,
+    },
+}
+
+/// Units of the functional currency (USD) per one unit of `currency`,
+/// valid from `rate_date` until a later rate is recorded.
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct FxRate {
+    pub id: FxRateId,
+    pub currency: CurrencyCode,
+    pub rate_date: NaiveDate,
+    pub rate: Decimal,
+    events: EntityEvents<FxRateEvent>,
+}
+
+impl FxRate {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for fx rate")
+    }
+
+    pub(super) fn update_rate(
+        &mut self,
+        rate: Decimal,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, FxRateError> {
+        if rate <= Decimal::ZERO {
+            return Err(FxRateError::NonPositiveRate(rate));
+        }
+        if self.rate == rate {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events
+            .push(FxRateEvent::RateUpdated { rate, audit_info });
+        self.rate = rate;
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<FxRateEvent> for FxRate {
+    fn try_from_events(events: EntityEvents<FxRateEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = FxRateBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                FxRateEvent::Initialized {
+                    id,
+                    currency,
+                    rate_date,
+                    rate,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .currency(currency.clone())
+                        .rate_date(*rate_date)
+                        .rate(*rate)
+                }
+                FxRateEvent::RateUpdated { rate, .. } => builder = builder.rate(*rate),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate", error = "FxRateError"))]
+pub struct NewFxRate {
+    #[builder(setter(into))]
+    pub(super) id: FxRateId,
+    pub(super) currency: CurrencyCode,
+    pub(super) rate_date: NaiveDate,
+    pub(super) rate: Decimal,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewFxRateBuilder {
+    fn validate(&self) -> Result<(), FxRateError> {
+        if let Some(currency) = self.currency.as_ref()
+            && currency.is_usd()
+        {
+            return Err(FxRateError::RateForFunctionalCurrency(currency.clone()));
+        }
+        if let Some(rate) = self.rate
+            && rate <= Decimal::ZERO
+        {
+            return Err(FxRateError::NonPositiveRate(rate));
+        }
+        Ok(())
+    }
+}
+
+impl NewFxRate {
+    pub fn builder() -> NewFxRateBuilder {
+        NewFxRateBuilder::default()
+    }
+}
+
+impl IntoEvents<FxRateEvent> for NewFxRate {
+    fn into_events(self) -> EntityEvents<FxRateEvent> {
+        EntityEvents::init(
+            self.id,
+            [FxRateEvent::Initialized {
+                id: self.id,
+                currency: self.currency,
+                rate_date: self.rate_date,
+                rate: self.rate,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn new_rate(currency: &str, rate: Decimal) -> Result<NewFxRate, FxRateError> {
+        NewFxRate::builder()
+            .id(FxRateId::new())
+            .currency(currency.parse().unwrap())
+            .rate_date(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap())
+            .rate(rate)
+            .audit_info(dummy_audit_info())
+            .build()
+    }
+
+    #[test]
+    fn rejects_invalid_rates() {
+        assert!(matches!(
+            new_rate("EUR", dec!(0)),
+            Err(FxRateError::NonPositiveRate(_))
+        ));
+        assert!(matches!(
+            new_rate("USD", dec!(1)),
+            Err(FxRateError::RateForFunctionalCurrency(_))
+        ));
+    }
+
+    #[test]
+    fn update_rate_is_idempotent() {
+        let mut rate =
+            FxRate::try_from_events(new_rate("EUR", dec!(1.08)).unwrap().into_events()).unwrap();
+        assert!(
+            rate.update_rate(dec!(1.08), dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+        assert!(
+            rate.update_rate(dec!(1.09), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(rate.rate, dec!(1.09));
+    }
+}
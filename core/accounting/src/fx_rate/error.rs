@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use crate::primitives::CurrencyCode;
+
+#[derive(Error, Debug)]
+pub enum FxRateError {
+    #[error("FxRateError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("FxRateError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("FxRateError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("FxRateError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("FxRateError - NonPositiveRate: {0}")]
+    NonPositiveRate(rust_decimal::Decimal),
+    #[error("FxRateError - RateForFunctionalCurrency: {0}")]
+    RateForFunctionalCurrency(CurrencyCode),
+    #[error("FxRateError - UninitializedField: {0}")]
+    UninitializedField(#[from] derive_builder::UninitializedFieldError),
+}
+
+es_entity::from_es_entity_error!(FxRateError);
@@ -0,0 +1,129 @@
+mod entity;
+pub mod error;
+mod repo;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+
+use crate::primitives::{CoreAccountingAction, CoreAccountingObject, CurrencyCode, FxRateId};
+
+pub use entity::FxRate;
+#[cfg(feature = "json-schema")]
+pub use entity::FxRateEvent;
+pub(super) use entity::*;
+use error::*;
+pub use repo::fx_rate_cursor::*;
+use repo::*;
+
+#[derive(Clone)]
+pub struct FxRates<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    repo: FxRateRepo,
+}
+
+impl<Perms> FxRates<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(pool: &sqlx::PgPool, authz: &Perms) -> Self {
+        Self {
+            authz: authz.clone(),
+            repo: FxRateRepo::new(pool),
+        }
+    }
+
+    /// Records the rate for `currency` on `rate_date`, replacing any rate
+    /// previously recorded for that day.
+    #[instrument(name = "core_accounting.fx_rate.record", skip(self), err)]
+    pub async fn record(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        currency: CurrencyCode,
+        rate_date: NaiveDate,
+        rate: Decimal,
+    ) -> Result<FxRate, FxRateError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_fx_rates(),
+                CoreAccountingAction::FX_RATE_RECORD,
+            )
+            .await?;
+
+        if let Some(mut fx_rate) = self.repo.find_for_date(&currency, rate_date).await? {
+            if fx_rate.update_rate(rate, audit_info)?.did_execute() {
+                self.repo.update(&mut fx_rate).await?;
+            }
+            return Ok(fx_rate);
+        }
+
+        let new_rate = NewFxRate::builder()
+            .id(FxRateId::new())
+            .currency(currency)
+            .rate_date(rate_date)
+            .rate(rate)
+            .audit_info(audit_info)
+            .build()?;
+        self.repo.create(new_rate).await
+    }
+
+    #[instrument(name = "core_accounting.fx_rate.find_rate", skip(self), err)]
+    pub async fn find_rate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        currency: &CurrencyCode,
+        date: NaiveDate,
+    ) -> Result<Option<FxRate>, FxRateError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_fx_rates(),
+                CoreAccountingAction::FX_RATE_READ,
+            )
+            .await?;
+
+        self.repo.find_latest_on_or_before(currency, date).await
+    }
+
+    #[instrument(name = "core_accounting.fx_rate.list_for_currency", skip(self), err)]
+    pub async fn list_for_currency(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        currency: CurrencyCode,
+        query: es_entity::PaginatedQueryArgs<FxRatesByRateDateCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<FxRate, FxRatesByRateDateCursor>, FxRateError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_fx_rates(),
+                CoreAccountingAction::FX_RATE_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_for_currency_by_rate_date(currency, query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    pub(crate) async fn find_latest_on_or_before(
+        &self,
+        currency: &CurrencyCode,
+        date: NaiveDate,
+    ) -> Result<Option<FxRate>, FxRateError> {
+        self.repo.find_latest_on_or_before(currency, date).await
+    }
+
+    pub(crate) async fn currencies(&self) -> Result<Vec<CurrencyCode>, FxRateError> {
+        self.repo.list_currencies().await
+    }
+}
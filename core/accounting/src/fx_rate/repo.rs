@@ -0,0 +1,79 @@
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{CurrencyCode, FxRateId};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "FxRate",
+    err = "FxRateError",
+    columns(
+        currency(ty = "CurrencyCode", list_for),
+        rate_date(ty = "NaiveDate", list_by)
+    ),
+    tbl_prefix = "core"
+)]
+pub struct FxRateRepo {
+    pool: PgPool,
+}
+
+impl Clone for FxRateRepo {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl FxRateRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    pub async fn find_for_date(
+        &self,
+        currency: &CurrencyCode,
+        rate_date: NaiveDate,
+    ) -> Result<Option<FxRate>, FxRateError> {
+        es_query!(
+            tbl_prefix = "core",
+            r#"SELECT rate_date, id FROM core_fx_rates WHERE currency = $1 AND rate_date = $2"#,
+            currency as &CurrencyCode,
+            rate_date as NaiveDate
+        )
+        .fetch_optional(self.pool())
+        .await
+    }
+
+    /// The most recent rate recorded on or before `date`.
+    pub async fn find_latest_on_or_before(
+        &self,
+        currency: &CurrencyCode,
+        date: NaiveDate,
+    ) -> Result<Option<FxRate>, FxRateError> {
+        es_query!(
+            tbl_prefix = "core",
+            r#"SELECT rate_date, id FROM core_fx_rates
+            WHERE currency = $1 AND rate_date <= $2
+            ORDER BY rate_date DESC
+            LIMIT 1"#,
+            currency as &CurrencyCode,
+            date as NaiveDate
+        )
+        .fetch_optional(self.pool())
+        .await
+    }
+
+    pub async fn list_currencies(&self) -> Result<Vec<CurrencyCode>, FxRateError> {
+        let rows = sqlx::query!(
+            r#"SELECT DISTINCT currency as "currency: CurrencyCode" FROM core_fx_rates ORDER BY 1"#
+        )
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows.into_iter().map(|r| r.currency).collect())
+    }
+}
@@ -0,0 +1,134 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use rust_decimal::Decimal;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{FxRevaluationId, ManualTransactionId};
+
+use super::primitives::FxRevaluationLine;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "FxRevaluationId")]
+pub enum FxRevaluationEvent {
+    Initialized {
+        id: FxRevaluationId,
+        revaluation_date: NaiveDate,
+        previous_revaluation_date: Option<NaiveDate>,
+        lines: Vec<FxRevaluationLine>,
+        manual_transaction_id: Option<ManualTransactionId>,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct FxRevaluation {
+    pub id: FxRevaluationId,
+    pub revaluation_date: NaiveDate,
+    pub previous_revaluation_date: Option<NaiveDate>,
+    pub lines: Vec<FxRevaluationLine>,
+    pub manual_transaction_id: Option<ManualTransactionId>,
+    events: EntityEvents<FxRevaluationEvent>,
+}
+
+impl FxRevaluation {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for fx revaluation")
+    }
+
+    pub fn total_gain(&self) -> Decimal {
+        self.lines
+            .iter()
+            .map(|l| l.adjustment)
+            .filter(|a| a.is_sign_positive())
+            .sum()
+    }
+
+    pub fn total_loss(&self) -> Decimal {
+        -self
+            .lines
+            .iter()
+            .map(|l| l.adjustment)
+            .filter(|a| a.is_sign_negative())
+            .sum::<Decimal>()
+    }
+}
+
+impl TryFromEvents<FxRevaluationEvent> for FxRevaluation {
+    fn try_from_events(events: EntityEvents<FxRevaluationEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = FxRevaluationBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                FxRevaluationEvent::Initialized {
+                    id,
+                    revaluation_date,
+                    previous_revaluation_date,
+                    lines,
+                    manual_transaction_id,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .revaluation_date(*revaluation_date)
+                        .previous_revaluation_date(*previous_revaluation_date)
+                        .lines(lines.clone())
+                        .manual_transaction_id(*manual_transaction_id)
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewFxRevaluation {
+    #[builder(setter(into))]
+    pub(super) id: FxRevaluationId,
+    pub(super) revaluation_date: NaiveDate,
+    pub(super) previous_revaluation_date: Option<NaiveDate>,
+    pub(super) lines: Vec<FxRevaluationLine>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewFxRevaluation {
+    pub fn builder() -> NewFxRevaluationBuilder {
+        NewFxRevaluationBuilder::default()
+    }
+
+    pub(super) fn has_adjustments(&self) -> bool {
+        self.lines.iter().any(|l| !l.adjustment.is_zero())
+    }
+
+    /// The revaluation is posted as a manual transaction with the same id.
+    pub(super) fn manual_transaction_id(&self) -> Option<ManualTransactionId> {
+        self.has_adjustments()
+            .then(|| ManualTransactionId::from(uuid::Uuid::from(self.id)))
+    }
+}
+
+impl IntoEvents<FxRevaluationEvent> for NewFxRevaluation {
+    fn into_events(self) -> EntityEvents<FxRevaluationEvent> {
+        let manual_transaction_id = self.manual_transaction_id();
+        EntityEvents::init(
+            self.id,
+            [FxRevaluationEvent::Initialized {
+                id: self.id,
+                revaluation_date: self.revaluation_date,
+                previous_revaluation_date: self.previous_revaluation_date,
+                lines: self.lines,
+                manual_transaction_id,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+use crate::{
+    accounting_period, chart_of_accounts, fx_rate, manual_transaction, primitives::CurrencyCode,
+};
+
+#[derive(Error, Debug)]
+pub enum FxRevaluationError {
+    #[error("FxRevaluationError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("FxRevaluationError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("FxRevaluationError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("FxRevaluationError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("FxRevaluationError - CalaBalanceError: {0}")]
+    BalanceError(#[from] cala_ledger::balance::error::BalanceError),
+    #[error("FxRevaluationError - CalaAccountSetError: {0}")]
+    AccountSetError(#[from] cala_ledger::account_set::error::AccountSetError),
+    #[error("FxRevaluationError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
+    #[error("FxRevaluationError - FxRateError: {0}")]
+    FxRateError(#[from] fx_rate::error::FxRateError),
+    #[error("FxRevaluationError - ChartOfAccountsError: {0}")]
+    ChartOfAccountsError(#[from] chart_of_accounts::error::ChartOfAccountsError),
+    #[error("FxRevaluationError - ManualTransactionError: {0}")]
+    ManualTransactionError(#[from] manual_transaction::error::ManualTransactionError),
+    #[error("FxRevaluationError - AccountingPeriodGuardError: {0}")]
+    AccountingPeriodGuardError(#[from] accounting_period::error::AccountingPeriodGuardError),
+    #[error("FxRevaluationError - ChartOfAccountsNotFoundByReference: {0}")]
+    ChartOfAccountsNotFoundByReference(String),
+    #[error("FxRevaluationError - UnsupportedCurrency: {0}")]
+    UnsupportedCurrency(CurrencyCode),
+}
+
+es_entity::from_es_entity_error!(FxRevaluationError);
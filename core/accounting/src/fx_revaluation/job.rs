@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use chrono::{Datelike, Days, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::primitives::{CoreAccountingAction, CoreAccountingObject};
+
+use super::FxRevaluations;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FxRevaluationJobConfig<Perms, E> {
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> JobConfig for FxRevaluationJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = FxRevaluationInit<Perms, E>;
+}
+
+pub struct FxRevaluationInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fx_revaluations: FxRevaluations<Perms, E>,
+}
+
+impl<Perms, E> FxRevaluationInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(fx_revaluations: &FxRevaluations<Perms, E>) -> Self {
+        Self {
+            fx_revaluations: fx_revaluations.clone(),
+        }
+    }
+}
+
+const FX_REVALUATION_JOB: JobType = JobType::new("fx-revaluation");
+impl<Perms, E> JobInitializer for FxRevaluationInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        FX_REVALUATION_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(FxRevaluationJobRunner::<Perms, E> {
+            fx_revaluations: self.fx_revaluations.clone(),
+        }))
+    }
+}
+
+pub struct FxRevaluationJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fx_revaluations: FxRevaluations<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for FxRevaluationJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[instrument(
+        name = "core_accounting.job.fx-revaluation",
+        skip(self, _current_job),
+        fields(attempt)
+    )]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let today = Utc::now().date_naive();
+        let month_start = first_of_month(today);
+        let revaluation_date = month_start - Days::new(1);
+
+        self.fx_revaluations
+            .revalue_as_system(revaluation_date)
+            .await?;
+
+        let next_month_start = first_of_month(month_start + Days::new(31));
+        Ok(JobCompletion::RescheduleAt(Utc.from_utc_datetime(
+            &next_month_start.and_hms_opt(0, 0, 0).expect("valid time"),
+        )))
+    }
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).expect("first day of month always exists")
+}
@@ -0,0 +1,104 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use cala_ledger::{
+    CalaLedger, DebitOrCredit, JournalId,
+    account_set::{AccountSetUpdate, NewAccountSet, error::AccountSetError},
+};
+
+use crate::primitives::{CalaAccountSetId, CalaCurrency};
+
+use super::{error::FxRevaluationError, primitives::FxRevaluationConfig};
+
+const FX_REVALUATION_CONFIG_REFERENCE: &str = "fx-revaluation-config";
+
+#[derive(Clone)]
+pub struct FxRevaluationLedger {
+    cala: CalaLedger,
+    journal_id: JournalId,
+}
+
+impl FxRevaluationLedger {
+    pub fn new(cala: &CalaLedger, journal_id: JournalId) -> Self {
+        Self {
+            cala: cala.clone(),
+            journal_id,
+        }
+    }
+
+    /// Net settled debit balance of the account set in `currency` as of `date`.
+    pub async fn net_debit_balance(
+        &self,
+        account_set_id: CalaAccountSetId,
+        currency: CalaCurrency,
+        date: NaiveDate,
+    ) -> Result<Decimal, FxRevaluationError> {
+        let balance_id = (self.journal_id, account_set_id.into(), currency);
+        let ranges = self
+            .cala
+            .balances()
+            .effective()
+            .find_all_in_range(&[balance_id], date, Some(date))
+            .await?;
+
+        Ok(ranges
+            .get(&balance_id)
+            .map(|range| {
+                let settled = &range.close.details.settled;
+                settled.dr_balance - settled.cr_balance
+            })
+            .unwrap_or(Decimal::ZERO))
+    }
+
+    /// The config is kept in the metadata of a dedicated account set so that
+    /// every job run picks up the latest one.
+    pub async fn get_config(&self) -> Result<Option<FxRevaluationConfig>, FxRevaluationError> {
+        let account_set = match self
+            .cala
+            .account_sets()
+            .find_by_external_id(FX_REVALUATION_CONFIG_REFERENCE.to_string())
+            .await
+        {
+            Ok(account_set) => account_set,
+            Err(AccountSetError::CouldNotFindByExternalId(_)) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(account_set.values().metadata.as_ref().map(|meta| {
+            serde_json::from_value(meta.clone()).expect("Could not deserialize metadata")
+        }))
+    }
+
+    pub async fn set_config(&self, config: &FxRevaluationConfig) -> Result<(), FxRevaluationError> {
+        match self
+            .cala
+            .account_sets()
+            .find_by_external_id(FX_REVALUATION_CONFIG_REFERENCE.to_string())
+            .await
+        {
+            Ok(mut account_set) => {
+                let mut update = AccountSetUpdate::default();
+                update.metadata(config).expect("Could not update metadata");
+                account_set.update(update);
+                self.cala.account_sets().persist(&mut account_set).await?;
+            }
+            Err(AccountSetError::CouldNotFindByExternalId(_)) => {
+                let new_account_set = NewAccountSet::builder()
+                    .id(CalaAccountSetId::new())
+                    .journal_id(self.journal_id)
+                    .external_id(FX_REVALUATION_CONFIG_REFERENCE)
+                    .name(FX_REVALUATION_CONFIG_REFERENCE)
+                    .description(FX_REVALUATION_CONFIG_REFERENCE)
+                    .normal_balance_type(DebitOrCredit::Debit)
+                    .metadata(config)
+                    .expect("Could not add metadata")
+                    .build()
+                    .expect("Could not build new account set");
+                self.cala.account_sets().create(new_account_set).await?;
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,471 @@
+mod entity;
+pub mod error;
+mod job;
+mod ledger;
+mod primitives;
+mod repo;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use std::str::FromStr;
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use cala_ledger::{CalaLedger, JournalId};
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    Jobs,
+    accounting_period::AccountingPeriodGuard,
+    chart_of_accounts::ChartOfAccounts,
+    fx_rate::FxRates,
+    manual_transaction::{ManualEntryInput, ManualTransactions},
+    primitives::{
+        AccountIdOrCode, CalaAccountSetId, CalaCurrency, CoreAccountingAction,
+        CoreAccountingObject, CurrencyCode, DebitOrCredit, FxRevaluationId,
+    },
+};
+
+pub use entity::FxRevaluation;
+#[cfg(feature = "json-schema")]
+pub use entity::FxRevaluationEvent;
+pub(super) use entity::*;
+use error::*;
+use job::*;
+use ledger::FxRevaluationLedger;
+pub use primitives::*;
+pub use repo::fx_revaluation_cursor::FxRevaluationsByRevaluationDateCursor;
+use repo::*;
+
+pub struct FxRevaluations<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    authz: Perms,
+    chart_of_accounts: ChartOfAccounts<Perms>,
+    fx_rates: FxRates<Perms>,
+    jobs: Jobs,
+    ledger: FxRevaluationLedger,
+    manual_transactions: ManualTransactions<Perms, E>,
+    period_guard: AccountingPeriodGuard,
+    repo: FxRevaluationRepo,
+}
+
+impl<Perms, E> Clone for FxRevaluations<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            chart_of_accounts: self.chart_of_accounts.clone(),
+            fx_rates: self.fx_rates.clone(),
+            jobs: self.jobs.clone(),
+            ledger: self.ledger.clone(),
+            manual_transactions: self.manual_transactions.clone(),
+            period_guard: self.period_guard.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms, E> FxRevaluations<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        chart_of_accounts: &ChartOfAccounts<Perms>,
+        fx_rates: &FxRates<Perms>,
+        manual_transactions: &ManualTransactions<Perms, E>,
+        cala: &CalaLedger,
+        journal_id: JournalId,
+        jobs: &Jobs,
+    ) -> Self {
+        let fx_revaluations = Self {
+            authz: authz.clone(),
+            chart_of_accounts: chart_of_accounts.clone(),
+            fx_rates: fx_rates.clone(),
+            jobs: jobs.clone(),
+            ledger: FxRevaluationLedger::new(cala, journal_id),
+            manual_transactions: manual_transactions.clone(),
+            period_guard: AccountingPeriodGuard::new(pool),
+            repo: FxRevaluationRepo::new(pool),
+        };
+        jobs.add_initializer(FxRevaluationInit::new(&fx_revaluations));
+        fx_revaluations
+    }
+
+    /// Stores the config and spawns the job revaluing foreign currency
+    /// balances at every month end. The job reads the latest stored config on
+    /// each run.
+    #[instrument(
+        name = "core_accounting.fx_revaluation.schedule_monthly",
+        skip(self),
+        err
+    )]
+    pub async fn schedule_monthly(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        config: FxRevaluationConfig,
+    ) -> Result<(), FxRevaluationError> {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_fx_revaluations(),
+                CoreAccountingAction::FX_REVALUATION_CREATE,
+            )
+            .await?;
+
+        self.ledger.set_config(&config).await?;
+        self.jobs
+            .add_initializer_and_spawn_unique(
+                FxRevaluationInit::new(self),
+                FxRevaluationJobConfig::<Perms, E> {
+                    _phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[instrument(name = "core_accounting.fx_revaluation.revalue", skip(self), err)]
+    async fn revalue(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        config: &FxRevaluationConfig,
+        revaluation_date: NaiveDate,
+    ) -> Result<FxRevaluation, FxRevaluationError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_fx_revaluations(),
+                CoreAccountingAction::FX_REVALUATION_CREATE,
+            )
+            .await?;
+
+        if let Some(revaluation) = self
+            .repo
+            .maybe_find_by_revaluation_date(revaluation_date)
+            .await?
+        {
+            return Ok(revaluation);
+        }
+
+        let previous = self.repo.find_latest_before(revaluation_date).await?;
+        let previous_revaluation_date = previous.as_ref().map(|p| p.revaluation_date);
+        let lines = self
+            .revaluation_lines(config, revaluation_date, previous.as_ref())
+            .await?;
+
+        let new_revaluation = NewFxRevaluation::builder()
+            .id(FxRevaluationId::new())
+            .revaluation_date(revaluation_date)
+            .previous_revaluation_date(previous_revaluation_date)
+            .lines(lines)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("Could not build new fx revaluation");
+
+        let manual_transaction_id = new_revaluation.manual_transaction_id();
+        if manual_transaction_id.is_some() {
+            self.period_guard
+                .ensure_open_for_system_postings(revaluation_date)
+                .await?;
+        }
+
+        let mut db = self.repo.begin_op().await?;
+        let revaluation = self.repo.create_in_op(&mut db, new_revaluation).await?;
+
+        match manual_transaction_id {
+            Some(tx_id) => {
+                self.manual_transactions
+                    .execute_in_op(
+                        db,
+                        sub,
+                        &config.chart_of_accounts_ref,
+                        tx_id,
+                        Some(format!("fx-revaluation-{revaluation_date}")),
+                        format!("Foreign exchange revaluation as of {revaluation_date}"),
+                        revaluation_date,
+                        revaluation_entries(&revaluation, config),
+                        audit_info,
+                    )
+                    .await?;
+            }
+            None => db.commit().await?,
+        }
+
+        Ok(revaluation)
+    }
+
+    /// Returns `None` while no config has been stored.
+    pub(crate) async fn revalue_as_system(
+        &self,
+        revaluation_date: NaiveDate,
+    ) -> Result<Option<FxRevaluation>, FxRevaluationError> {
+        let Some(config) = self.ledger.get_config().await? else {
+            return Ok(None);
+        };
+        let revaluation = self
+            .revalue(
+                &<<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject as SystemSubject>::system(),
+                &config,
+                revaluation_date,
+            )
+            .await?;
+        Ok(Some(revaluation))
+    }
+
+    #[instrument(name = "core_accounting.fx_revaluation.find_by_id", skip(self), err)]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<FxRevaluationId> + std::fmt::Debug,
+    ) -> Result<Option<FxRevaluation>, FxRevaluationError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::fx_revaluation(id),
+                CoreAccountingAction::FX_REVALUATION_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(revaluation) => Ok(Some(revaluation)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[instrument(name = "core_accounting.fx_revaluation.list", skip(self), err)]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<FxRevaluationsByRevaluationDateCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<FxRevaluation, FxRevaluationsByRevaluationDateCursor>,
+        FxRevaluationError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_fx_revaluations(),
+                CoreAccountingAction::FX_REVALUATION_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_by_revaluation_date(query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    async fn revaluation_lines(
+        &self,
+        config: &FxRevaluationConfig,
+        revaluation_date: NaiveDate,
+        previous: Option<&FxRevaluation>,
+    ) -> Result<Vec<FxRevaluationLine>, FxRevaluationError> {
+        let chart = self
+            .chart_of_accounts
+            .find_by_reference(&config.chart_of_accounts_ref)
+            .await?
+            .ok_or_else(|| {
+                FxRevaluationError::ChartOfAccountsNotFoundByReference(
+                    config.chart_of_accounts_ref.clone(),
+                )
+            })?;
+
+        let mut lines = Vec::new();
+        for currency in self.fx_rates.currencies().await? {
+            let Some(rate) = self
+                .fx_rates
+                .find_latest_on_or_before(&currency, revaluation_date)
+                .await?
+            else {
+                continue;
+            };
+            let cala_currency = CalaCurrency::from_str(currency.as_str())
+                .map_err(|_| FxRevaluationError::UnsupportedCurrency(currency.clone()))?;
+            let daily_rates = match previous {
+                Some(previous) => {
+                    self.daily_rates(
+                        &currency,
+                        previous.revaluation_date,
+                        revaluation_date,
+                        rate.rate,
+                    )
+                    .await?
+                }
+                None => Vec::new(),
+            };
+
+            for code in config.revalued_account_codes.iter() {
+                let account_set_id = chart.account_set_id_from_code(code)?;
+                let balance = self
+                    .ledger
+                    .net_debit_balance(account_set_id, cala_currency, revaluation_date)
+                    .await?;
+                let carrying_value = match previous {
+                    Some(previous) => {
+                        let opening_value = previous
+                            .lines
+                            .iter()
+                            .find(|line| &line.account_code == code && line.currency == currency)
+                            .map(|line| line.revalued_value());
+                        Some(
+                            self.carrying_value(
+                                account_set_id,
+                                cala_currency,
+                                opening_value,
+                                &daily_rates,
+                            )
+                            .await?,
+                        )
+                    }
+                    None => None,
+                };
+                if balance.is_zero() && carrying_value.is_none_or(|value| value.is_zero()) {
+                    continue;
+                }
+                lines.push(FxRevaluationLine::new(
+                    code.clone(),
+                    currency.clone(),
+                    balance,
+                    rate.rate,
+                    carrying_value,
+                ));
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// The rate in effect on each day from `from` to `to` inclusive, falling
+    /// back to `closing_rate` for days before the first recorded rate.
+    async fn daily_rates(
+        &self,
+        currency: &CurrencyCode,
+        from: NaiveDate,
+        to: NaiveDate,
+        closing_rate: Decimal,
+    ) -> Result<Vec<(NaiveDate, Decimal)>, FxRevaluationError> {
+        let mut rates = Vec::new();
+        for day in from.iter_days().take_while(|day| *day <= to) {
+            let rate = self
+                .fx_rates
+                .find_latest_on_or_before(currency, day)
+                .await?
+                .map(|r| r.rate)
+                .unwrap_or(closing_rate);
+            rates.push((day, rate));
+        }
+        Ok(rates)
+    }
+
+    /// Starts from the value established by the previous revaluation (or the
+    /// opening balance at that day's rate when the account had no line) and
+    /// adds each day's movement at the rate in effect on that day.
+    async fn carrying_value(
+        &self,
+        account_set_id: CalaAccountSetId,
+        currency: CalaCurrency,
+        opening_value: Option<Decimal>,
+        daily_rates: &[(NaiveDate, Decimal)],
+    ) -> Result<Decimal, FxRevaluationError> {
+        let Some(((opening_date, opening_rate), movements)) = daily_rates.split_first() else {
+            return Ok(Decimal::ZERO);
+        };
+
+        let mut last_balance = self
+            .ledger
+            .net_debit_balance(account_set_id, currency, *opening_date)
+            .await?;
+        let mut carrying_value =
+            opening_value.unwrap_or_else(|| round_to_usd_minor_units(last_balance * opening_rate));
+        for (day, rate) in movements {
+            let balance = self
+                .ledger
+                .net_debit_balance(account_set_id, currency, *day)
+                .await?;
+            carrying_value += round_to_usd_minor_units((balance - last_balance) * rate);
+            last_balance = balance;
+        }
+
+        Ok(carrying_value)
+    }
+}
+
+/// Adjustments land in the manual account of each revalued node so that the
+/// balances of the underlying (e.g. customer) accounts are left untouched.
+fn revaluation_entries(
+    revaluation: &FxRevaluation,
+    config: &FxRevaluationConfig,
+) -> Vec<ManualEntryInput> {
+    let mut entries = revaluation
+        .lines
+        .iter()
+        .filter(|line| !line.adjustment.is_zero())
+        .map(|line| {
+            revaluation_entry_input(
+                AccountIdOrCode::Code(line.account_code.clone()),
+                line.adjustment,
+                format!("Revaluation of {} balance", line.currency),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let gain = revaluation.total_gain();
+    if !gain.is_zero() {
+        entries.push(revaluation_entry_input(
+            AccountIdOrCode::Code(config.unrealized_gain_account_code.clone()),
+            -gain,
+            "Unrealized foreign exchange gain".to_string(),
+        ));
+    }
+    let loss = revaluation.total_loss();
+    if !loss.is_zero() {
+        entries.push(revaluation_entry_input(
+            AccountIdOrCode::Code(config.unrealized_loss_account_code.clone()),
+            loss,
+            "Unrealized foreign exchange loss".to_string(),
+        ));
+    }
+
+    entries
+}
+
+/// Positive amounts debit the account, negative amounts credit it.
+fn revaluation_entry_input(
+    account_id_or_code: AccountIdOrCode,
+    amount: Decimal,
+    description: String,
+) -> ManualEntryInput {
+    let direction = if amount.is_sign_negative() {
+        DebitOrCredit::Credit
+    } else {
+        DebitOrCredit::Debit
+    };
+    ManualEntryInput::builder()
+        .account_id_or_code(account_id_or_code)
+        .amount(amount.abs())
+        .currency(CalaCurrency::USD)
+        .description(description)
+        .direction(direction)
+        .build()
+        .expect("Couldn't build revaluation entry")
+}
@@ -0,0 +1,127 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{AccountCode, CurrencyCode};
+
+/// Where monthly revaluations read foreign currency balances from and where
+/// the resulting unrealized gains and losses are posted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FxRevaluationConfig {
+    pub chart_of_accounts_ref: String,
+    pub revalued_account_codes: Vec<AccountCode>,
+    pub unrealized_gain_account_code: AccountCode,
+    pub unrealized_loss_account_code: AccountCode,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct FxRevaluationLine {
+    pub account_code: AccountCode,
+    pub currency: CurrencyCode,
+    /// Net debit balance in `currency` on the revaluation date.
+    pub balance: Decimal,
+    /// Closing rate on the revaluation date.
+    pub rate: Decimal,
+    /// USD value `balance` was carried at before this revaluation: the value
+    /// established by the previous revaluation plus movements since then,
+    /// translated at the rates in effect when they were booked.
+    pub carrying_value: Decimal,
+    /// `balance` at the closing rate less `carrying_value`.
+    /// Positive amounts are gains.
+    pub adjustment: Decimal,
+}
+
+impl FxRevaluationLine {
+    /// Without a carrying value the line only establishes a baseline at the
+    /// closing rate.
+    pub fn new(
+        account_code: AccountCode,
+        currency: CurrencyCode,
+        balance: Decimal,
+        rate: Decimal,
+        carrying_value: Option<Decimal>,
+    ) -> Self {
+        let closing_value = round_to_usd_minor_units(balance * rate);
+        let (carrying_value, adjustment) = match carrying_value {
+            Some(carrying_value) => (carrying_value, closing_value - carrying_value),
+            None => (closing_value, Decimal::ZERO),
+        };
+
+        Self {
+            account_code,
+            currency,
+            balance,
+            rate,
+            carrying_value,
+            adjustment,
+        }
+    }
+
+    /// USD value the balance is carried at after this revaluation.
+    pub fn revalued_value(&self) -> Decimal {
+        self.carrying_value + self.adjustment
+    }
+}
+
+pub(super) fn round_to_usd_minor_units(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(
+        CurrencyCode::USD.minor_units(),
+        RoundingStrategy::MidpointAwayFromZero,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn line(balance: Decimal, rate: Decimal, carrying_value: Option<Decimal>) -> FxRevaluationLine {
+        FxRevaluationLine::new(
+            "11.01.0101".parse().unwrap(),
+            "EUR".parse().unwrap(),
+            balance,
+            rate,
+            carrying_value,
+        )
+    }
+
+    #[test]
+    fn asset_gains_when_rate_rises() {
+        assert_eq!(
+            line(dec!(1000), dec!(1.10), Some(dec!(1080))).adjustment,
+            dec!(20)
+        );
+    }
+
+    #[test]
+    fn liability_loses_when_rate_rises() {
+        assert_eq!(
+            line(dec!(-1000), dec!(1.10), Some(dec!(-1080))).adjustment,
+            dec!(-20)
+        );
+    }
+
+    #[test]
+    fn rounds_to_cents() {
+        assert_eq!(
+            line(dec!(333.33), dec!(1.0815), Some(dec!(360))).adjustment,
+            dec!(0.50)
+        );
+    }
+
+    #[test]
+    fn movements_are_revalued_from_their_booking_rate() {
+        // 1000 carried at 1.08 and 500 deposited at 1.09, closing at 1.10
+        let line = line(dec!(1500), dec!(1.10), Some(dec!(1080) + dec!(545)));
+        assert_eq!(line.adjustment, dec!(25));
+        assert_eq!(line.revalued_value(), dec!(1650));
+    }
+
+    #[test]
+    fn first_revaluation_is_a_baseline() {
+        let line = line(dec!(1000), dec!(1.10), None);
+        assert!(line.adjustment.is_zero());
+        assert_eq!(line.carrying_value, dec!(1100));
+    }
+}
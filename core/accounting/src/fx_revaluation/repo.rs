@@ -0,0 +1,49 @@
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::FxRevaluationId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "FxRevaluation",
+    err = "FxRevaluationError",
+    columns(revaluation_date(ty = "NaiveDate", list_by)),
+    tbl_prefix = "core"
+)]
+pub struct FxRevaluationRepo {
+    pool: PgPool,
+}
+
+impl Clone for FxRevaluationRepo {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl FxRevaluationRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+
+    pub async fn find_latest_before(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Option<FxRevaluation>, FxRevaluationError> {
+        es_query!(
+            tbl_prefix = "core",
+            r#"SELECT revaluation_date, id FROM core_fx_revaluations
+            WHERE revaluation_date < $1
+            ORDER BY revaluation_date DESC
+            LIMIT 1"#,
+            date as NaiveDate
+        )
+        .fetch_optional(self.pool())
+        .await
+    }
+}
@@ -1,6 +1,6 @@
 use cala_ledger::entry::Entry;
 use cala_ledger::{Currency, DebitOrCredit, EntryId, Layer};
-use core_money::{Satoshis, UsdCents};
+use core_money::{CurrencyCode, Money, Satoshis, UsdCents};
 use serde::{Deserialize, Serialize};
 
 use super::error::JournalError;
@@ -19,10 +19,11 @@ pub struct JournalEntry {
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub enum JournalEntryAmount {
     Usd(UsdCents),
     Btc(Satoshis),
+    Money(Money),
 }
 
 impl TryFrom<Entry> for JournalEntry {
@@ -34,7 +35,8 @@ impl TryFrom<Entry> for JournalEntry {
         } else if entry.values().currency == Currency::BTC {
             JournalEntryAmount::Btc(Satoshis::try_from_btc(entry.values().units)?)
         } else {
-            return Err(JournalError::UnexpectedCurrency);
+            let currency: CurrencyCode = entry.values().currency.code().parse()?;
+            JournalEntryAmount::Money(Money::try_new(entry.values().units, currency)?)
         };
         Ok(Self {
             ledger_account_id: entry.values().account_id.into(),
@@ -55,6 +57,7 @@ impl JournalEntryAmount {
         match self {
             JournalEntryAmount::Usd(cents) => cents.to_usd().to_string(),
             JournalEntryAmount::Btc(sats) => sats.to_btc().to_string(),
+            JournalEntryAmount::Money(money) => money.amount().to_string(),
         }
     }
 
@@ -62,6 +65,7 @@ impl JournalEntryAmount {
         match self {
             JournalEntryAmount::Usd(_) => String::from("USD"),
             JournalEntryAmount::Btc(_) => String::from("BTC"),
+            JournalEntryAmount::Money(money) => money.currency().to_string(),
         }
     }
 }
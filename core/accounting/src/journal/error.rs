@@ -10,10 +10,10 @@ pub enum JournalError {
     CalaEntry(#[from] cala_ledger::entry::error::EntryError),
     #[error("JournalError - AuthorizationError: {0}")]
     AuthorizationError(#[from] authz::error::AuthorizationError),
-    #[error("JournalError - UnexpectedCurrency")]
-    UnexpectedCurrency,
     #[error("JournalError - ConversionError: {0}")]
     ConversionError(#[from] core_money::ConversionError),
+    #[error("JournalError - MoneyError: {0}")]
+    MoneyError(#[from] core_money::MoneyError),
     #[error("JournalError - ParseCurrencyError: {0}")]
     ParseCurrencyError(#[from] cala_ledger::ParseCurrencyError),
 }
@@ -8,6 +8,8 @@ pub enum LedgerAccountLedgerError {
     CalaLedger(#[from] cala_ledger::error::LedgerError),
     #[error("LedgerAccountLedgerError - CalaEntryError: {0}")]
     CalaEntry(#[from] cala_ledger::entry::error::EntryError),
+    #[error("LedgerAccountLedgerError - CalaTransactionError: {0}")]
+    CalaTransaction(#[from] cala_ledger::transaction::error::TransactionError),
     #[error("LedgerAccountLedgerError - CalaBalanceError: {0}")]
     CalaBalance(#[from] cala_ledger::balance::error::BalanceError),
     #[error("LedgerAccountLedgerError - CalaAccountSetError: {0}")]
@@ -2,13 +2,17 @@ pub mod error;
 
 use std::collections::HashMap;
 
+use chrono::NaiveDate;
+
 use cala_ledger::{
-    CalaLedger, Currency, JournalId,
+    CalaLedger, Currency, JournalId, TransactionId,
     account::Account,
     account_set::{
         AccountSet, AccountSetId, AccountSetMemberByExternalId, AccountSetMemberId,
         AccountSetMembersByExternalIdCursor,
     },
+    entry::EntriesByCreatedAtCursor,
+    transaction::Transaction,
 };
 
 use crate::{AccountCode, LedgerAccount, LedgerAccountId, journal_error::JournalError};
@@ -18,6 +22,7 @@ use super::{AccountBalances, BalanceRanges, LedgerAccountChildrenCursor};
 use error::*;
 
 const MAX_DEPTH_BETWEEN_LEAF_AND_COA_EDGE: usize = 2; // coa_edge -> internal_account -> leaf
+const ENTRIES_PAGE_SIZE: usize = 100;
 
 #[derive(Clone)]
 pub struct LedgerAccountLedger {
@@ -93,6 +98,75 @@ impl LedgerAccountLedger {
         })
     }
 
+    /// Entries on the account (or on the members of the account set) whose
+    /// transaction is effective between `from` and `until`, inclusive.
+    pub async fn ledger_account_entries_effective_between<T>(
+        &self,
+        ledger_account_id: LedgerAccountId,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<T>, LedgerAccountLedgerError>
+    where
+        T: TryFrom<cala_ledger::entry::Entry, Error = JournalError>,
+    {
+        let mut entries = Vec::new();
+        let mut after: Option<EntriesByCreatedAtCursor> = None;
+        let mut is_account_set = true;
+        loop {
+            let is_first_page = after.is_none();
+            let query = es_entity::PaginatedQueryArgs {
+                first: ENTRIES_PAGE_SIZE,
+                after: after.take(),
+            };
+            let ret = if is_account_set {
+                self.cala
+                    .entries()
+                    .list_for_account_set_id(
+                        ledger_account_id.into(),
+                        query,
+                        es_entity::ListDirection::Ascending,
+                    )
+                    .await?
+            } else {
+                self.cala
+                    .entries()
+                    .list_for_account_id(
+                        ledger_account_id.into(),
+                        query,
+                        es_entity::ListDirection::Ascending,
+                    )
+                    .await?
+            };
+            if is_account_set && is_first_page && ret.entities.is_empty() {
+                is_account_set = false;
+                continue;
+            }
+
+            let transaction_ids = ret
+                .entities
+                .iter()
+                .map(|entry| entry.values().transaction_id)
+                .collect::<Vec<_>>();
+            let transactions: HashMap<TransactionId, Transaction> =
+                self.cala.transactions().find_all(&transaction_ids).await?;
+            entries.extend(ret.entities.into_iter().filter(|entry| {
+                transactions
+                    .get(&entry.values().transaction_id)
+                    .is_some_and(|tx| (from..=until).contains(&tx.values().effective))
+            }));
+
+            match ret.end_cursor {
+                Some(cursor) if ret.has_next_page => after = Some(cursor),
+                _ => break,
+            }
+        }
+
+        Ok(entries
+            .into_iter()
+            .map(T::try_from)
+            .collect::<Result<Vec<T>, _>>()?)
+    }
+
     #[allow(clippy::type_complexity)]
     pub fn find_parent_with_account_code(
         &self,
@@ -99,6 +99,66 @@ where
         Ok(all_entries)
     }
 
+    #[instrument(
+        name = "core_accounting.ledger_account.history_effective_between",
+        skip(self),
+        err
+    )]
+    pub(crate) async fn history_effective_between(
+        &self,
+        id: impl Into<LedgerAccountId> + std::fmt::Debug,
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+    ) -> Result<Vec<JournalEntry>, LedgerAccountError> {
+        Ok(self
+            .ledger
+            .ledger_account_entries_effective_between(id.into(), from, until)
+            .await?)
+    }
+
+    #[instrument(
+        name = "core_accounting.ledger_account.complete_children",
+        skip(self),
+        err
+    )]
+    pub(crate) async fn complete_children(
+        &self,
+        id: cala_ledger::AccountSetId,
+        from: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+        filter_non_zero: bool,
+    ) -> Result<Vec<LedgerAccount>, LedgerAccountError> {
+        let mut all_children = Vec::new();
+        let mut cursor: Option<LedgerAccountChildrenCursor> = None;
+        let page_size = 100;
+
+        loop {
+            let query_args = es_entity::PaginatedQueryArgs {
+                first: page_size,
+                after: cursor,
+            };
+
+            let result = self
+                .ledger
+                .list_children(id, query_args, from, Some(until))
+                .await?;
+            all_children.extend(
+                result
+                    .entities
+                    .into_iter()
+                    .filter(|account| !filter_non_zero || account.has_non_zero_activity()),
+            );
+
+            if !result.has_next_page {
+                break;
+            }
+
+            cursor = result.end_cursor;
+        }
+
+        Ok(all_children)
+    }
+
     #[instrument(
         name = "core_accounting.ledger_account.find_by_id",
         skip(self, chart),
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use crate::{
     AccountCode, BalanceRange, CalaAccount, CalaAccountBalance, CalaAccountId, CalaAccountSet,
     CalaAccountSetId, CalaBalanceId, CalaBalanceRange, CalaCurrency, CalaJournalId, DebitOrCredit,
-    LedgerAccountId,
+    LedgerAccountId, primitives::CurrencyCode,
 };
 
 #[derive(Debug, Clone)]
@@ -23,6 +23,14 @@ pub struct LedgerAccount {
 }
 
 impl LedgerAccount {
+    /// Balance ranges for the currencies the ledger holds balances in.
+    pub fn balance_ranges(&self) -> Vec<(CurrencyCode, &BalanceRange)> {
+        currency_balance_ranges(
+            self.usd_balance_range.as_ref(),
+            self.btc_balance_range.as_ref(),
+        )
+    }
+
     pub(super) fn account_set_member_id(&self) -> cala_ledger::account_set::AccountSetMemberId {
         if self.is_leaf {
             CalaAccountId::from(self.id).into()
@@ -42,6 +50,19 @@ impl LedgerAccount {
     }
 }
 
+pub(crate) fn currency_balance_ranges<'a>(
+    usd_balance_range: Option<&'a BalanceRange>,
+    btc_balance_range: Option<&'a BalanceRange>,
+) -> Vec<(CurrencyCode, &'a BalanceRange)> {
+    [
+        (CurrencyCode::USD, usd_balance_range),
+        (CurrencyCode::BTC, btc_balance_range),
+    ]
+    .into_iter()
+    .filter_map(|(currency, range)| range.map(|range| (currency, range)))
+    .collect()
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct ByCurrency<B> {
     pub(super) usd: Option<B>,
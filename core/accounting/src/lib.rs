@@ -1,16 +1,24 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
+pub mod accounting_period;
 pub mod balance_sheet;
 pub mod chart_of_accounts;
+pub mod comparative_statement;
 pub mod csv;
 pub mod error;
+pub mod fx_rate;
+pub mod fx_revaluation;
 pub mod journal;
 pub mod ledger_account;
 pub mod ledger_transaction;
 pub mod manual_transaction;
 mod primitives;
+mod processes;
 pub mod profit_and_loss;
+pub mod reconciliation;
+pub mod recurring_journal_entry;
+pub mod statement_export;
 pub mod transaction_templates;
 pub mod trial_balance;
 
@@ -20,111 +28,239 @@ use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
 use document_storage::DocumentStorage;
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
 use job::Jobs;
 use manual_transaction::ManualTransactions;
+use outbox::{Outbox, OutboxEventMarker};
+use processes::approve_manual_transaction::{
+    ApproveManualTransaction, ManualTransactionApprovalInit, ManualTransactionApprovalJobConfig,
+};
 use tracing::instrument;
 
+pub use accounting_period::{
+    AccountingPeriod, AccountingPeriodGuard, AccountingPeriodStatus, AccountingPeriodType,
+    AccountingPeriods, AccountingPeriodsByCreatedAtCursor, ClosingEntryAccounts,
+};
 pub use balance_sheet::{BalanceSheet, BalanceSheets};
 pub use chart_of_accounts::{Chart, ChartOfAccounts, error as chart_of_accounts_error, tree};
+pub use comparative_statement::{
+    ComparativeLine, ComparativePeriod, ComparativeStatement, ComparativeStatements,
+    ComparisonPreset, Variance,
+};
 pub use csv::AccountingCsvExports;
 use error::CoreAccountingError;
+pub use fx_rate::{FxRate, FxRates, FxRatesByRateDateCursor};
+pub use fx_revaluation::{
+    FxRevaluation, FxRevaluationConfig, FxRevaluationLine, FxRevaluations,
+    FxRevaluationsByRevaluationDateCursor,
+};
 pub use journal::{Journal, error as journal_error};
 pub use ledger_account::{LedgerAccount, LedgerAccountChildrenCursor, LedgerAccounts};
 pub use ledger_transaction::{LedgerTransaction, LedgerTransactions};
-pub use manual_transaction::ManualEntryInput;
+pub use manual_transaction::{ManualEntryInput, ManualTransaction};
 pub use primitives::*;
+pub use processes::APPROVE_MANUAL_TRANSACTION_PROCESS;
 pub use profit_and_loss::{ProfitAndLossStatement, ProfitAndLossStatements};
+pub use reconciliation::{
+    Reconciliation, Reconciliations, ReconciliationsByCreatedAtCursor, StatementFormat,
+};
+pub use recurring_journal_entry::{
+    RecurrenceFrequency, RecurrenceSchedule, RecurringJournalEntries,
+    RecurringJournalEntriesByCreatedAtCursor, RecurringJournalEntry,
+};
+pub use statement_export::{StatementExportFormat, StatementExports, StatementType};
 pub use transaction_templates::TransactionTemplates;
 pub use trial_balance::{TrialBalanceRoot, TrialBalances};
 
 #[cfg(feature = "json-schema")]
 pub mod event_schema {
+    pub use crate::accounting_period::AccountingPeriodEvent;
     pub use crate::chart_of_accounts::ChartEvent;
+    pub use crate::fx_rate::FxRateEvent;
+    pub use crate::fx_revaluation::FxRevaluationEvent;
     pub use crate::manual_transaction::ManualTransactionEvent;
+    pub use crate::reconciliation::ReconciliationEvent;
+    pub use crate::recurring_journal_entry::RecurringJournalEntryEvent;
 }
 
-pub struct CoreAccounting<Perms>
+pub struct CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     authz: Perms,
+    accounting_periods: AccountingPeriods<Perms, E>,
     chart_of_accounts: ChartOfAccounts<Perms>,
+    fx_rates: FxRates<Perms>,
+    fx_revaluations: FxRevaluations<Perms, E>,
     journal: Journal<Perms>,
     ledger_accounts: LedgerAccounts<Perms>,
     ledger_transactions: LedgerTransactions<Perms>,
-    manual_transactions: ManualTransactions<Perms>,
+    manual_transactions: ManualTransactions<Perms, E>,
     profit_and_loss: ProfitAndLossStatements<Perms>,
+    reconciliations: Reconciliations<Perms>,
+    recurring_journal_entries: RecurringJournalEntries<Perms, E>,
     transaction_templates: TransactionTemplates<Perms>,
     balance_sheets: BalanceSheets<Perms>,
+    comparative_statements: ComparativeStatements<Perms>,
     csvs: AccountingCsvExports<Perms>,
+    statement_exports: StatementExports<Perms>,
     trial_balances: TrialBalances<Perms>,
 }
 
-impl<Perms> Clone for CoreAccounting<Perms>
+impl<Perms, E> Clone for CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     fn clone(&self) -> Self {
         Self {
             authz: self.authz.clone(),
+            accounting_periods: self.accounting_periods.clone(),
             chart_of_accounts: self.chart_of_accounts.clone(),
+            fx_rates: self.fx_rates.clone(),
+            fx_revaluations: self.fx_revaluations.clone(),
             journal: self.journal.clone(),
             ledger_accounts: self.ledger_accounts.clone(),
             manual_transactions: self.manual_transactions.clone(),
             ledger_transactions: self.ledger_transactions.clone(),
             profit_and_loss: self.profit_and_loss.clone(),
+            reconciliations: self.reconciliations.clone(),
+            recurring_journal_entries: self.recurring_journal_entries.clone(),
             transaction_templates: self.transaction_templates.clone(),
             balance_sheets: self.balance_sheets.clone(),
+            comparative_statements: self.comparative_statements.clone(),
             csvs: self.csvs.clone(),
+            statement_exports: self.statement_exports.clone(),
             trial_balances: self.trial_balances.clone(),
         }
     }
 }
 
-impl<Perms> CoreAccounting<Perms>
+impl<Perms, E> CoreAccounting<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
-    pub fn new(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn init(
         pool: &sqlx::PgPool,
         authz: &Perms,
+        outbox: &Outbox<E>,
+        governance: &Governance<Perms, E>,
         cala: &CalaLedger,
         journal_id: CalaJournalId,
         document_storage: DocumentStorage,
         jobs: &Jobs,
-    ) -> Self {
+    ) -> Result<Self, CoreAccountingError> {
         let chart_of_accounts = ChartOfAccounts::new(pool, authz, cala, journal_id);
         let journal = Journal::new(authz, cala, journal_id);
         let ledger_accounts = LedgerAccounts::new(authz, cala, journal_id);
-        let manual_transactions =
-            ManualTransactions::new(pool, authz, &chart_of_accounts, cala, journal_id);
+        let manual_transactions = ManualTransactions::new(
+            pool,
+            authz,
+            &chart_of_accounts,
+            governance,
+            cala,
+            journal_id,
+        );
+        let accounting_periods =
+            AccountingPeriods::new(pool, authz, &manual_transactions, cala, journal_id);
+        let fx_rates = FxRates::new(pool, authz);
+        let fx_revaluations = FxRevaluations::new(
+            pool,
+            authz,
+            &chart_of_accounts,
+            &fx_rates,
+            &manual_transactions,
+            cala,
+            journal_id,
+            jobs,
+        );
+        let recurring_journal_entries =
+            RecurringJournalEntries::new(pool, authz, &manual_transactions, jobs);
         let ledger_transactions = LedgerTransactions::new(authz, cala);
+        let reconciliations =
+            Reconciliations::new(pool, authz, &ledger_accounts, &ledger_transactions);
         let profit_and_loss = ProfitAndLossStatements::new(pool, authz, cala, journal_id);
         let transaction_templates = TransactionTemplates::new(authz, cala);
         let balance_sheets = BalanceSheets::new(pool, authz, cala, journal_id);
-        let csvs = AccountingCsvExports::new(authz, jobs, document_storage, &ledger_accounts);
+        let comparative_statements =
+            ComparativeStatements::new(&balance_sheets, &profit_and_loss, &ledger_accounts);
+        let csvs =
+            AccountingCsvExports::new(authz, jobs, document_storage.clone(), &ledger_accounts);
         let trial_balances = TrialBalances::new(pool, authz, cala, journal_id);
-        Self {
+        let statement_exports = StatementExports::new(
+            authz,
+            jobs,
+            cala,
+            document_storage,
+            &trial_balances,
+            &balance_sheets,
+            &profit_and_loss,
+            &ledger_accounts,
+        );
+
+        let approve_manual_transaction = ApproveManualTransaction::new(&manual_transactions);
+        jobs.add_initializer_and_spawn_unique(
+            ManualTransactionApprovalInit::new(outbox, &approve_manual_transaction),
+            ManualTransactionApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        match governance
+            .init_policy(APPROVE_MANUAL_TRANSACTION_PROCESS)
+            .await
+        {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
+        Ok(Self {
             authz: authz.clone(),
+            accounting_periods,
             chart_of_accounts,
+            fx_rates,
+            fx_revaluations,
             journal,
             ledger_accounts,
             ledger_transactions,
             manual_transactions,
             profit_and_loss,
+            reconciliations,
+            recurring_journal_entries,
             transaction_templates,
             balance_sheets,
+            comparative_statements,
             csvs,
+            statement_exports,
             trial_balances,
-        }
+        })
+    }
+
+    pub fn accounting_periods(&self) -> &AccountingPeriods<Perms, E> {
+        &self.accounting_periods
     }
 
     pub fn chart_of_accounts(&self) -> &ChartOfAccounts<Perms> {
         &self.chart_of_accounts
     }
 
+    pub fn fx_rates(&self) -> &FxRates<Perms> {
+        &self.fx_rates
+    }
+
+    pub fn fx_revaluations(&self) -> &FxRevaluations<Perms, E> {
+        &self.fx_revaluations
+    }
+
     pub fn journal(&self) -> &Journal<Perms> {
         &self.journal
     }
@@ -137,7 +273,7 @@ where
         &self.ledger_transactions
     }
 
-    pub fn manual_transactions(&self) -> &ManualTransactions<Perms> {
+    pub fn manual_transactions(&self) -> &ManualTransactions<Perms, E> {
         &self.manual_transactions
     }
 
@@ -145,10 +281,22 @@ where
         &self.profit_and_loss
     }
 
+    pub fn reconciliations(&self) -> &Reconciliations<Perms> {
+        &self.reconciliations
+    }
+
+    pub fn recurring_journal_entries(&self) -> &RecurringJournalEntries<Perms, E> {
+        &self.recurring_journal_entries
+    }
+
     pub fn csvs(&self) -> &AccountingCsvExports<Perms> {
         &self.csvs
     }
 
+    pub fn statement_exports(&self) -> &StatementExports<Perms> {
+        &self.statement_exports
+    }
+
     pub fn transaction_templates(&self) -> &TransactionTemplates<Perms> {
         &self.transaction_templates
     }
@@ -157,6 +305,10 @@ where
         &self.balance_sheets
     }
 
+    pub fn comparative_statements(&self) -> &ComparativeStatements<Perms> {
+        &self.comparative_statements
+    }
+
     pub fn trial_balances(&self) -> &TrialBalances<Perms> {
         &self.trial_balances
     }
@@ -255,8 +407,8 @@ where
         description: String,
         effective: Option<chrono::NaiveDate>,
         entries: Vec<ManualEntryInput>,
-    ) -> Result<LedgerTransaction, CoreAccountingError> {
-        let tx = self
+    ) -> Result<ManualTransaction, CoreAccountingError> {
+        Ok(self
             .manual_transactions
             .execute(
                 sub,
@@ -266,13 +418,77 @@ where
                 effective.unwrap_or_else(|| chrono::Utc::now().date_naive()),
                 entries,
             )
-            .await?;
+            .await?)
+    }
 
-        let ledger_tx_id = tx.ledger_transaction_id;
-        let mut txs = self.ledger_transactions.find_all(&[ledger_tx_id]).await?;
-        Ok(txs
-            .remove(&ledger_tx_id)
-            .expect("Could not find LedgerTransaction"))
+    #[instrument(name = "core_accounting.reverse_manual_transaction", skip(self), err)]
+    pub async fn reverse_manual_transaction(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: ManualTransactionId,
+        effective: Option<chrono::NaiveDate>,
+    ) -> Result<ManualTransaction, CoreAccountingError> {
+        Ok(self
+            .manual_transactions
+            .reverse(
+                sub,
+                id,
+                effective.unwrap_or_else(|| chrono::Utc::now().date_naive()),
+            )
+            .await?)
+    }
+
+    #[instrument(name = "core_accounting.close_accounting_period", skip(self), err)]
+    pub async fn close_accounting_period(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        pl_statement_ref: &str,
+        id: AccountingPeriodId,
+        retained_earnings_code: Option<AccountCode>,
+    ) -> Result<AccountingPeriod, CoreAccountingError> {
+        let closing_accounts = match retained_earnings_code {
+            Some(retained_earnings_code) => {
+                let chart = self
+                    .chart_of_accounts
+                    .find_by_reference(chart_ref)
+                    .await?
+                    .ok_or_else(move || {
+                        CoreAccountingError::ChartOfAccountsNotFoundByReference(
+                            chart_ref.to_string(),
+                        )
+                    })?;
+                let config = self
+                    .profit_and_loss
+                    .get_chart_of_accounts_integration_config(sub, pl_statement_ref.to_string())
+                    .await?
+                    .ok_or_else(|| {
+                        CoreAccountingError::ProfitAndLossConfigNotFound(
+                            pl_statement_ref.to_string(),
+                        )
+                    })?;
+                let profit_and_loss_account_set_ids = [
+                    &config.chart_of_accounts_revenue_code,
+                    &config.chart_of_accounts_cost_of_revenue_code,
+                    &config.chart_of_accounts_expenses_code,
+                ]
+                .into_iter()
+                .map(|code| chart.account_set_id_from_code(code))
+                .collect::<Result<Vec<_>, _>>()?;
+
+                Some(ClosingEntryAccounts {
+                    chart_ref: chart_ref.to_string(),
+                    profit_and_loss_account_set_ids,
+                    retained_earnings_code,
+                })
+            }
+            None => None,
+        };
+
+        Ok(self
+            .accounting_periods
+            .close(sub, id, closing_accounts)
+            .await?)
     }
 
     #[instrument(name = "core_accounting.import_csv", skip(self), err)]
@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use derive_builder::Builder;
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
@@ -5,9 +6,12 @@ use serde::{Deserialize, Serialize};
 
 use audit::AuditInfo;
 use es_entity::*;
+use governance::ApprovalProcessId;
 
 use crate::primitives::{CalaTxId, ManualTransactionId};
 
+use super::{error::ManualTransactionError, primitives::*};
+
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -18,6 +22,27 @@ pub enum ManualTransactionEvent {
         ledger_transaction_id: CalaTxId,
         description: String,
         reference: String,
+        #[serde(default)]
+        reversal_of: Option<ManualTransactionId>,
+        #[serde(default)]
+        approval_process_id: Option<ApprovalProcessId>,
+        #[serde(default)]
+        effective: Option<NaiveDate>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        entries: Vec<ManualTransactionEntry>,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+    Failed {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    Reversed {
+        reversal_transaction_id: ManualTransactionId,
         audit_info: AuditInfo,
     },
 }
@@ -29,6 +54,16 @@ pub struct ManualTransaction {
     pub reference: String,
     pub description: String,
     pub ledger_transaction_id: CalaTxId,
+    #[builder(setter(strip_option), default)]
+    pub reversal_of: Option<ManualTransactionId>,
+    #[builder(setter(strip_option), default)]
+    pub reversal_transaction_id: Option<ManualTransactionId>,
+    #[builder(setter(strip_option), default)]
+    pub approval_process_id: Option<ApprovalProcessId>,
+    #[builder(setter(strip_option), default)]
+    pub effective: Option<NaiveDate>,
+    #[builder(default)]
+    pub entries: Vec<ManualTransactionEntry>,
     events: EntityEvents<ManualTransactionEvent>,
 }
 
@@ -38,6 +73,99 @@ impl ManualTransaction {
             .entity_first_persisted_at()
             .expect("No events for deposit")
     }
+
+    pub fn status(&self) -> ManualTransactionStatus {
+        if self.approval_process_id.is_none() {
+            return ManualTransactionStatus::Posted;
+        }
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                ManualTransactionEvent::Failed { .. } => Some(ManualTransactionStatus::Failed),
+                ManualTransactionEvent::ApprovalProcessConcluded { approved: true, .. } => {
+                    Some(ManualTransactionStatus::Posted)
+                }
+                ManualTransactionEvent::ApprovalProcessConcluded {
+                    approved: false, ..
+                } => Some(ManualTransactionStatus::Denied),
+                _ => None,
+            })
+            .unwrap_or(ManualTransactionStatus::PendingApproval)
+    }
+
+    pub fn is_approval_process_concluded(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, ManualTransactionEvent::ApprovalProcessConcluded { .. }))
+    }
+
+    pub fn failure_reason(&self) -> Option<&str> {
+        self.events.iter_all().find_map(|event| match event {
+            ManualTransactionEvent::Failed { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.reversal_transaction_id.is_some()
+    }
+
+    pub(super) fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ManualTransactionEvent::ApprovalProcessConcluded { .. }
+        );
+        let approval_process_id = self
+            .approval_process_id
+            .expect("manual transaction without approval process");
+        self.events
+            .push(ManualTransactionEvent::ApprovalProcessConcluded {
+                approval_process_id,
+                approved,
+                audit_info,
+            });
+        Idempotent::Executed(())
+    }
+
+    /// Records that an approved transaction could not be posted.
+    pub(super) fn record_failed(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            ManualTransactionEvent::Failed { .. }
+        );
+        self.events
+            .push(ManualTransactionEvent::Failed { reason, audit_info });
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn reverse(
+        &mut self,
+        reversal_transaction_id: ManualTransactionId,
+        audit_info: AuditInfo,
+    ) -> Result<(), ManualTransactionError> {
+        if self.status() != ManualTransactionStatus::Posted {
+            return Err(ManualTransactionError::NotPosted(self.id));
+        }
+        if self.is_reversed() {
+            return Err(ManualTransactionError::AlreadyReversed(self.id));
+        }
+
+        self.events.push(ManualTransactionEvent::Reversed {
+            reversal_transaction_id,
+            audit_info,
+        });
+        self.reversal_transaction_id = Some(reversal_transaction_id);
+        Ok(())
+    }
 }
 
 impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
@@ -52,6 +180,10 @@ impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
                     reference,
                     description,
                     ledger_transaction_id,
+                    reversal_of,
+                    approval_process_id,
+                    effective,
+                    entries,
                     ..
                 } => {
                     builder = builder
@@ -59,7 +191,22 @@ impl TryFromEvents<ManualTransactionEvent> for ManualTransaction {
                         .reference(reference.clone())
                         .description(description.clone())
                         .ledger_transaction_id(*ledger_transaction_id)
+                        .entries(entries.clone());
+                    if let Some(reversal_of) = reversal_of {
+                        builder = builder.reversal_of(*reversal_of);
+                    }
+                    if let Some(approval_process_id) = approval_process_id {
+                        builder = builder.approval_process_id(*approval_process_id);
+                    }
+                    if let Some(effective) = effective {
+                        builder = builder.effective(*effective);
+                    }
                 }
+                ManualTransactionEvent::Reversed {
+                    reversal_transaction_id,
+                    ..
+                } => builder = builder.reversal_transaction_id(*reversal_transaction_id),
+                _ => (),
             }
         }
         builder.events(events).build()
@@ -73,6 +220,12 @@ pub struct NewManualTransaction {
     reference: Option<String>,
     pub(super) ledger_transaction_id: CalaTxId,
     description: String,
+    #[builder(setter(strip_option), default)]
+    reversal_of: Option<ManualTransactionId>,
+    #[builder(setter(strip_option, into), default)]
+    pub(super) approval_process_id: Option<ApprovalProcessId>,
+    effective: NaiveDate,
+    entries: Vec<ManualTransactionEntry>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
 }
@@ -89,6 +242,14 @@ impl NewManualTransaction {
             Some(reference) => reference.to_string(),
         }
     }
+
+    pub(super) fn status(&self) -> ManualTransactionStatus {
+        if self.approval_process_id.is_some() {
+            ManualTransactionStatus::PendingApproval
+        } else {
+            ManualTransactionStatus::Posted
+        }
+    }
 }
 
 impl IntoEvents<ManualTransactionEvent> for NewManualTransaction {
@@ -100,8 +261,94 @@ impl IntoEvents<ManualTransactionEvent> for NewManualTransaction {
                 id: self.id,
                 ledger_transaction_id: self.ledger_transaction_id,
                 description: self.description,
+                reversal_of: self.reversal_of,
+                approval_process_id: self.approval_process_id,
+                effective: Some(self.effective),
+                entries: self.entries,
                 audit_info: self.audit_info,
             }],
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn new_manual_transaction() -> NewManualTransactionBuilder {
+        let id = ManualTransactionId::new();
+        let mut builder = NewManualTransaction::builder();
+        builder
+            .id(id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description("description".to_string())
+            .reference(None)
+            .effective(chrono::Utc::now().date_naive())
+            .entries(vec![])
+            .audit_info(dummy_audit_info());
+        builder
+    }
+
+    fn manual_transaction() -> ManualTransaction {
+        let new_tx = new_manual_transaction().build().unwrap();
+        ManualTransaction::try_from_events(new_tx.into_events()).unwrap()
+    }
+
+    fn pending_manual_transaction() -> ManualTransaction {
+        let new_tx = new_manual_transaction()
+            .approval_process_id(ApprovalProcessId::new())
+            .build()
+            .unwrap();
+        ManualTransaction::try_from_events(new_tx.into_events()).unwrap()
+    }
+
+    #[test]
+    fn posted_once_approved() {
+        let mut tx = pending_manual_transaction();
+        assert_eq!(tx.status(), ManualTransactionStatus::PendingApproval);
+
+        assert!(
+            tx.approval_process_concluded(true, dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(tx.status(), ManualTransactionStatus::Posted);
+        assert!(
+            tx.approval_process_concluded(false, dummy_audit_info())
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn denied_transaction_cannot_be_reversed() {
+        let mut tx = pending_manual_transaction();
+        let _ = tx.approval_process_concluded(false, dummy_audit_info());
+        assert_eq!(tx.status(), ManualTransactionStatus::Denied);
+
+        assert!(matches!(
+            tx.reverse(ManualTransactionId::new(), dummy_audit_info()),
+            Err(ManualTransactionError::NotPosted(_))
+        ));
+    }
+
+    #[test]
+    fn can_only_be_reversed_once() {
+        let mut tx = manual_transaction();
+        let reversal_id = ManualTransactionId::new();
+        tx.reverse(reversal_id, dummy_audit_info()).unwrap();
+        assert_eq!(tx.reversal_transaction_id, Some(reversal_id));
+
+        assert!(matches!(
+            tx.reverse(ManualTransactionId::new(), dummy_audit_info()),
+            Err(ManualTransactionError::AlreadyReversed(_))
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::chart_of_accounts;
+use crate::{accounting_period, chart_of_accounts, primitives::ManualTransactionId};
 
 #[derive(Error, Debug)]
 pub enum ManualTransactionError {
@@ -22,6 +22,18 @@ pub enum ManualTransactionError {
     AuthorizationError(#[from] authz::error::AuthorizationError),
     #[error("ManualTransactionError - ChartOfAccounts: {0}")]
     ChartOfAccountsError(#[from] chart_of_accounts::error::ChartOfAccountsError),
+    #[error("ManualTransactionError - AccountingPeriodGuardError: {0}")]
+    AccountingPeriodGuardError(#[from] accounting_period::error::AccountingPeriodGuardError),
+    #[error("ManualTransactionError - CalaEntryError: {0}")]
+    EntryError(#[from] cala_ledger::entry::error::EntryError),
+    #[error("ManualTransactionError - AlreadyReversed: {0}")]
+    AlreadyReversed(ManualTransactionId),
+    #[error("ManualTransactionError - NotPosted: {0}")]
+    NotPosted(ManualTransactionId),
+    #[error("ManualTransactionError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("ManualTransactionError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
 }
 
 es_entity::from_es_entity_error!(ManualTransactionError);
@@ -1,10 +1,10 @@
 mod template;
 
-use cala_ledger::CalaLedger;
+use cala_ledger::{CalaLedger, DebitOrCredit};
 
 use crate::primitives::CalaTxId;
 
-use super::error::ManualTransactionError;
+use super::{error::ManualTransactionError, primitives::ManualTransactionEntry};
 
 use template::*;
 pub use template::{EntryParams, ManualTransactionParams};
@@ -40,4 +40,28 @@ impl ManualTransactionLedger {
 
         Ok(())
     }
+
+    /// Mirrors every entry of `tx_id` with the opposite direction.
+    pub async fn reversal_entries(
+        &self,
+        tx_id: CalaTxId,
+    ) -> Result<Vec<ManualTransactionEntry>, ManualTransactionError> {
+        let entries = self.cala.entries().list_for_transaction_id(tx_id).await?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| {
+                let values = entry.into_values();
+                ManualTransactionEntry {
+                    account_id: values.account_id,
+                    currency: values.currency,
+                    amount: values.units,
+                    description: values.description.unwrap_or_default(),
+                    direction: match values.direction {
+                        DebitOrCredit::Debit => DebitOrCredit::Credit,
+                        DebitOrCredit::Credit => DebitOrCredit::Debit,
+                    },
+                }
+            })
+            .collect())
+    }
 }
@@ -7,6 +7,8 @@ use cala_ledger::{
     *,
 };
 
+use crate::manual_transaction::ManualTransactionEntry;
+
 #[derive(Debug)]
 pub struct EntryParams {
     pub account_id: CalaAccountId,
@@ -16,6 +18,18 @@ pub struct EntryParams {
     pub direction: DebitOrCredit,
 }
 
+impl From<&ManualTransactionEntry> for EntryParams {
+    fn from(entry: &ManualTransactionEntry) -> Self {
+        Self {
+            account_id: entry.account_id,
+            currency: entry.currency,
+            amount: entry.amount,
+            description: entry.description.clone(),
+            direction: entry.direction,
+        }
+    }
+}
+
 impl EntryParams {
     pub fn populate_params(&self, params: &mut Params, n: usize) {
         params.insert(Self::account_id_param_name(n), self.account_id);
@@ -11,11 +11,17 @@ use std::collections::HashMap;
 use audit::AuditSvc;
 use authz::PermissionCheck;
 use cala_ledger::{CalaLedger, JournalId};
+use governance::{
+    ApprovalProcessStatus, Governance, GovernanceAction, GovernanceEvent, GovernanceObject,
+};
 use ledger::{EntryParams, ManualTransactionLedger, ManualTransactionParams};
+use outbox::OutboxEventMarker;
 
 use crate::{
+    accounting_period::{AccountingPeriodGuard, error::AccountingPeriodGuardError},
     chart_of_accounts::ChartOfAccounts,
     primitives::{CalaTxId, CoreAccountingAction, CoreAccountingObject, ManualTransactionId},
+    processes::APPROVE_MANUAL_TRANSACTION_PROCESS,
 };
 use error::*;
 
@@ -27,28 +33,52 @@ pub use primitives::*;
 pub use repo::manual_transaction_cursor::ManualTransactionsByCreatedAtCursor;
 use repo::*;
 
-#[derive(Clone)]
-pub struct ManualTransactions<Perms>
+pub struct ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     ledger: ManualTransactionLedger,
     authz: Perms,
     chart_of_accounts: ChartOfAccounts<Perms>,
+    governance: Governance<Perms, E>,
     journal_id: JournalId,
+    period_guard: AccountingPeriodGuard,
     repo: ManualTransactionRepo,
 }
 
-impl<Perms> ManualTransactions<Perms>
+impl<Perms, E> Clone for ManualTransactions<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ledger: self.ledger.clone(),
+            authz: self.authz.clone(),
+            chart_of_accounts: self.chart_of_accounts.clone(),
+            governance: self.governance.clone(),
+            journal_id: self.journal_id,
+            period_guard: self.period_guard.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ManualTransactions<Perms, E>
 where
     Perms: PermissionCheck,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
-    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
 {
     pub fn new(
         pool: &sqlx::PgPool,
         authz: &Perms,
         chart_of_accounts: &ChartOfAccounts<Perms>,
+        governance: &Governance<Perms, E>,
         cala: &CalaLedger,
         journal_id: JournalId,
     ) -> Self {
@@ -57,7 +87,9 @@ where
             ledger: ManualTransactionLedger::new(cala),
             chart_of_accounts: chart_of_accounts.clone(),
             authz: authz.clone(),
+            governance: governance.clone(),
             journal_id,
+            period_guard: AccountingPeriodGuard::new(pool),
             repo,
         }
     }
@@ -110,6 +142,36 @@ where
             .await
     }
 
+    #[instrument(
+        name = "core_accounting.manual_transaction.list_pending",
+        skip(self),
+        err
+    )]
+    pub async fn list_pending_manual_transactions(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<ManualTransactionsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<ManualTransaction, ManualTransactionsByCreatedAtCursor>,
+        ManualTransactionError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_manual_transactions(),
+                CoreAccountingAction::MANUAL_TRANSACTION_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_for_status_by_created_at(
+                ManualTransactionStatus::PendingApproval,
+                query,
+                es_entity::ListDirection::Descending,
+            )
+            .await
+    }
+
     #[instrument(name = "core_accounting.manual_transaction.find_all", skip(self), err)]
     pub async fn find_all<T: From<ManualTransaction>>(
         &self,
@@ -118,6 +180,13 @@ where
         self.repo.find_all(ids).await
     }
 
+    /// Records the transaction and starts its approval process. It is only posted
+    /// to the ledger once the process concludes approved.
+    #[instrument(
+        name = "core_accounting.manual_transaction.execute",
+        skip(self, entries),
+        err
+    )]
     pub async fn execute(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -136,22 +205,289 @@ where
             )
             .await?;
 
-        let ledger_tx_id = CalaTxId::new();
-        let manual_tx_id = ManualTransactionId::new();
+        let entries = self.resolve_entries(sub, chart_ref, entries).await?;
+        let db = self.repo.begin_op().await?;
+        self.request_approval_in_op(
+            db,
+            ManualTransactionId::new(),
+            reference,
+            description,
+            effective,
+            entries,
+            audit_info,
+        )
+        .await
+    }
 
+    /// Records a transaction whose entries were resolved up front and starts its
+    /// approval process, committing `db` together with it.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn request_approval_in_op(
+        &self,
+        db: es_entity::DbOp<'_>,
+        id: ManualTransactionId,
+        reference: Option<String>,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualTransactionEntry>,
+        audit_info: audit::AuditInfo,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let new_tx = NewManualTransaction::builder()
+            .id(id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description(description)
+            .reference(reference)
+            .approval_process_id(id)
+            .effective(effective)
+            .entries(entries)
+            .audit_info(audit_info)
+            .build()
+            .expect("Couldn't build new manual transaction");
+
+        self.create_pending_approval_in_op(db, new_tx, effective)
+            .await
+    }
+
+    async fn create_pending_approval_in_op(
+        &self,
+        mut db: es_entity::DbOp<'_>,
+        new_tx: NewManualTransaction,
+        effective: chrono::NaiveDate,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        self.period_guard
+            .ensure_open_for_manual_postings(effective)
+            .await?;
+
+        let id = new_tx.id;
+        let process = self
+            .governance
+            .start_process(
+                &mut db,
+                id,
+                id.to_string(),
+                APPROVE_MANUAL_TRANSACTION_PROCESS,
+            )
+            .await?;
+        let mut manual_transaction = self.repo.create_in_op(&mut db, new_tx).await?;
+
+        match process.status() {
+            ApprovalProcessStatus::InProgress => db.commit().await?,
+            status => {
+                self.conclude_approval_process_in_op(
+                    db,
+                    &mut manual_transaction,
+                    status == ApprovalProcessStatus::Approved,
+                )
+                .await?
+            }
+        }
+
+        Ok(manual_transaction)
+    }
+
+    pub(crate) async fn conclude_approval_process(
+        &self,
+        id: ManualTransactionId,
+        approved: bool,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let mut manual_transaction = self.repo.find_by_id(id).await?;
+        if manual_transaction.is_approval_process_concluded() {
+            return Ok(manual_transaction);
+        }
+
+        let db = self.repo.begin_op().await?;
+        self.conclude_approval_process_in_op(db, &mut manual_transaction, approved)
+            .await?;
+        Ok(manual_transaction)
+    }
+
+    async fn conclude_approval_process_in_op(
+        &self,
+        mut db: es_entity::DbOp<'_>,
+        manual_transaction: &mut ManualTransaction,
+        approved: bool,
+    ) -> Result<(), ManualTransactionError> {
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                &mut db,
+                CoreAccountingObject::manual_transaction(manual_transaction.id),
+                CoreAccountingAction::MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+
+        if manual_transaction
+            .approval_process_concluded(approved, audit_info.clone())
+            .was_ignored()
+        {
+            return Ok(());
+        }
+
+        if approved {
+            let effective = manual_transaction
+                .effective
+                .expect("manual transaction awaiting approval without effective date");
+            match self
+                .period_guard
+                .ensure_open_for_manual_postings(effective)
+                .await
+            {
+                Ok(()) => match self
+                    .reverse_original_in_op(&mut db, manual_transaction, audit_info.clone())
+                    .await
+                {
+                    Ok(()) => {
+                        self.repo.update_in_op(&mut db, manual_transaction).await?;
+                        let params = self.posting_params(manual_transaction);
+                        self.ledger
+                            .execute(db, manual_transaction.ledger_transaction_id, params)
+                            .await?;
+                        return Ok(());
+                    }
+                    Err(
+                        e @ (ManualTransactionError::NotPosted(_)
+                        | ManualTransactionError::AlreadyReversed(_)),
+                    ) => {
+                        let _ = manual_transaction.record_failed(e.to_string(), audit_info);
+                    }
+                    Err(e) => return Err(e),
+                },
+                Err(e @ AccountingPeriodGuardError::PostingInLockedPeriod(..)) => {
+                    let _ = manual_transaction.record_failed(e.to_string(), audit_info);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        self.repo.update_in_op(&mut db, manual_transaction).await?;
+        db.commit().await?;
+        Ok(())
+    }
+
+    /// Marks the original of an approved reversal as reversed. Done at approval
+    /// time so that a denied reversal leaves the original untouched.
+    async fn reverse_original_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        reversal: &ManualTransaction,
+        audit_info: audit::AuditInfo,
+    ) -> Result<(), ManualTransactionError> {
+        let Some(original_id) = reversal.reversal_of else {
+            return Ok(());
+        };
+
+        let mut original = self.repo.find_by_id_in_op(&mut *db, original_id).await?;
+        original.reverse(reversal.id, audit_info)?;
+        self.repo.update_in_op(db, &mut original).await?;
+        Ok(())
+    }
+
+    /// Records a reversal of a posted transaction and starts its approval process.
+    /// The original is only marked reversed once the reversal is approved.
+    #[instrument(name = "core_accounting.manual_transaction.reverse", skip(self), err)]
+    pub async fn reverse(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ManualTransactionId> + std::fmt::Debug,
+        effective: chrono::NaiveDate,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::manual_transaction(id),
+                CoreAccountingAction::MANUAL_TRANSACTION_REVERSE,
+            )
+            .await?;
+
+        let original = self.repo.find_by_id(id).await?;
+        if original.status() != ManualTransactionStatus::Posted {
+            return Err(ManualTransactionError::NotPosted(id));
+        }
+        if original.is_reversed() {
+            return Err(ManualTransactionError::AlreadyReversed(id));
+        }
+
+        let entries = self
+            .ledger
+            .reversal_entries(original.ledger_transaction_id)
+            .await?;
+
+        let reversal_id = ManualTransactionId::new();
+        let new_tx = NewManualTransaction::builder()
+            .id(reversal_id)
+            .ledger_transaction_id(CalaTxId::new())
+            .description(format!("Reversal of {}", original.reference))
+            .reference(None)
+            .reversal_of(id)
+            .approval_process_id(reversal_id)
+            .effective(effective)
+            .entries(entries)
+            .audit_info(audit_info)
+            .build()
+            .expect("Couldn't build reversal manual transaction");
+
+        let db = self.repo.begin_op().await?;
+        self.create_pending_approval_in_op(db, new_tx, effective)
+            .await
+    }
+
+    /// Posts without approval or consulting the period guard, committing `db`
+    /// together with the ledger transaction.
+    ///
+    /// Reserved for system postings whose inputs are already controlled: FX
+    /// revaluation and the year-end closing entry. Anything user-initiated must
+    /// go through `request_approval_in_op` so that it is approved first.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn execute_in_op(
+        &self,
+        db: es_entity::DbOp<'_>,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        manual_tx_id: ManualTransactionId,
+        reference: Option<String>,
+        description: String,
+        effective: chrono::NaiveDate,
+        entries: Vec<ManualEntryInput>,
+        audit_info: audit::AuditInfo,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
         let new_tx = NewManualTransaction::builder()
             .id(manual_tx_id)
-            .ledger_transaction_id(ledger_tx_id)
-            .description(description.clone())
+            .ledger_transaction_id(CalaTxId::new())
+            .description(description)
             .reference(reference)
+            .effective(effective)
+            .entries(self.resolve_entries(sub, chart_ref, entries).await?)
             .audit_info(audit_info)
             .build()
             .expect("Couldn't build new manual transaction");
 
-        let mut db = self.repo.begin_op().await?;
+        self.create_and_post_in_op(db, new_tx).await
+    }
+
+    async fn create_and_post_in_op(
+        &self,
+        mut db: es_entity::DbOp<'_>,
+        new_tx: NewManualTransaction,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
         let manual_transaction = self.repo.create_in_op(&mut db, new_tx).await?;
+        let params = self.posting_params(&manual_transaction);
+        self.ledger
+            .execute(db, manual_transaction.ledger_transaction_id, params)
+            .await?;
 
-        let mut entry_params = vec![];
+        Ok(manual_transaction)
+    }
+
+    pub(crate) async fn resolve_entries(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<Vec<ManualTransactionEntry>, ManualTransactionError> {
+        let mut resolved = Vec::with_capacity(entries.len());
         for e in entries {
             let account_id = self
                 .chart_of_accounts
@@ -161,28 +497,29 @@ where
                     e.account_id_or_code,
                 )
                 .await?;
-            entry_params.push(EntryParams {
+            resolved.push(ManualTransactionEntry {
                 account_id: account_id.into(),
                 amount: e.amount,
                 currency: e.currency,
-                direction: e.direction,
                 description: e.description,
+                direction: e.direction,
             });
         }
+        Ok(resolved)
+    }
 
-        self.ledger
-            .execute(
-                db,
-                ledger_tx_id,
-                ManualTransactionParams {
-                    journal_id: self.journal_id,
-                    description,
-                    entry_params,
-                    effective,
-                },
-            )
-            .await?;
-
-        Ok(manual_transaction)
+    fn posting_params(&self, manual_transaction: &ManualTransaction) -> ManualTransactionParams {
+        ManualTransactionParams {
+            journal_id: self.journal_id,
+            description: manual_transaction.description.clone(),
+            entry_params: manual_transaction
+                .entries
+                .iter()
+                .map(EntryParams::from)
+                .collect(),
+            effective: manual_transaction
+                .effective
+                .expect("manual transaction without effective date"),
+        }
     }
 }
@@ -1,9 +1,12 @@
 use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use cala_ledger::{Currency, DebitOrCredit};
 use rust_decimal::Decimal;
 
-use crate::primitives::AccountIdOrCode;
+use crate::primitives::{AccountIdOrCode, CalaAccountId};
 
 pub use cala_ledger::TransactionId as CalaTransactionId;
 
@@ -22,3 +25,27 @@ impl ManualEntryInput {
         ManualEntryInputBuilder::default()
     }
 }
+
+/// A manual entry with its account resolved, as recorded for later posting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ManualTransactionEntry {
+    pub account_id: CalaAccountId,
+    pub amount: Decimal,
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+    pub currency: Currency,
+    pub description: String,
+    pub direction: DebitOrCredit,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum ManualTransactionStatus {
+    PendingApproval,
+    Posted,
+    Denied,
+    Failed,
+}
@@ -4,7 +4,7 @@ use es_entity::*;
 
 use crate::primitives::{CalaTxId, ManualTransactionId};
 
-use super::{entity::*, error::*};
+use super::{entity::*, error::*, primitives::ManualTransactionStatus};
 
 #[derive(EsRepo)]
 #[es_repo(
@@ -12,7 +12,13 @@ use super::{entity::*, error::*};
     err = "ManualTransactionError",
     columns(
         reference(ty = "String", create(accessor = "reference()")),
-        ledger_transaction_id(ty = "CalaTxId")
+        ledger_transaction_id(ty = "CalaTxId"),
+        status(
+            ty = "ManualTransactionStatus",
+            list_for,
+            create(accessor = "status()"),
+            update(accessor = "status()")
+        )
     ),
     tbl_prefix = "core"
 )]
@@ -33,3 +39,41 @@ impl ManualTransactionRepo {
         Self { pool: pool.clone() }
     }
 }
+
+mod manual_transaction_status_sqlx {
+    use sqlx::{Type, postgres::*};
+
+    use super::ManualTransactionStatus;
+
+    impl Type<Postgres> for ManualTransactionStatus {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for ManualTransactionStatus {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <String as sqlx::Encode<'_, Postgres>>::encode(self.to_string(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for ManualTransactionStatus {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse().map_err(|e: strum::ParseError| Box::new(e))?)
+        }
+    }
+
+    impl PgHasArrayType for ManualTransactionStatus {
+        fn array_type_info() -> PgTypeInfo {
+            <String as sqlx::postgres::PgHasArrayType>::array_type_info()
+        }
+    }
+}
@@ -17,17 +17,23 @@ pub use cala_ledger::{
     },
 };
 
-pub use core_money::{Satoshis, UsdCents};
+pub use core_money::{CurrencyCode, Money, Satoshis, UsdCents};
 
 es_entity::entity_id! {
     ChartId,
     ManualTransactionId,
     LedgerAccountId,
-    AccountingCsvId;
+    AccountingCsvId,
+    AccountingPeriodId,
+    FxRateId,
+    FxRevaluationId,
+    RecurringJournalEntryId,
+    ReconciliationId;
 
     LedgerAccountId => CalaAccountId,
     LedgerAccountId => CalaAccountSetId,
     AccountingCsvId => job::JobId,
+    ManualTransactionId => governance::ApprovalProcessId,
 }
 
 impl From<cala_ledger::account_set::AccountSetMemberId> for LedgerAccountId {
@@ -50,7 +56,7 @@ pub enum AccountNameParseError {
     StartsWithDigit,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountName {
     name: String,
@@ -346,6 +352,12 @@ pub type ProfitAndLossConfigurationAllOrOne = AllOrOne<LedgerAccountId>;
 pub type BalanceSheetAllOrOne = AllOrOne<LedgerAccountId>;
 pub type BalanceSheetConfigurationAllOrOne = AllOrOne<LedgerAccountId>;
 pub type AccountingCsvAllOrOne = AllOrOne<AccountingCsvId>;
+pub type AccountingPeriodAllOrOne = AllOrOne<AccountingPeriodId>;
+pub type FxRateAllOrOne = AllOrOne<FxRateId>;
+pub type FxRevaluationAllOrOne = AllOrOne<FxRevaluationId>;
+pub type RecurringJournalEntryAllOrOne = AllOrOne<RecurringJournalEntryId>;
+pub type ReconciliationAllOrOne = AllOrOne<ReconciliationId>;
+pub type StatementExportAllOrOne = AllOrOne<LedgerAccountId>;
 pub type TrialBalanceAllOrOne = AllOrOne<LedgerAccountId>; // what to do if there is only All
 // option
 
@@ -368,6 +380,12 @@ pub enum CoreAccountingAction {
     BalanceSheetConfiguration(BalanceSheetConfigurationAction),
     AccountingCsv(AccountingCsvAction),
     TrialBalance(TrialBalanceAction),
+    AccountingPeriod(AccountingPeriodAction),
+    FxRate(FxRateAction),
+    FxRevaluation(FxRevaluationAction),
+    RecurringJournalEntry(RecurringJournalEntryAction),
+    Reconciliation(ReconciliationAction),
+    StatementExport(StatementExportAction),
 }
 
 impl CoreAccountingAction {
@@ -414,6 +432,26 @@ impl CoreAccountingAction {
                 TrialBalance => {
                     map_action!(accounting, TrialBalance, TrialBalanceAction)
                 }
+                AccountingPeriod => {
+                    map_action!(accounting, AccountingPeriod, AccountingPeriodAction)
+                }
+                FxRate => map_action!(accounting, FxRate, FxRateAction),
+                FxRevaluation => {
+                    map_action!(accounting, FxRevaluation, FxRevaluationAction)
+                }
+                RecurringJournalEntry => {
+                    map_action!(
+                        accounting,
+                        RecurringJournalEntry,
+                        RecurringJournalEntryAction
+                    )
+                }
+                Reconciliation => {
+                    map_action!(accounting, Reconciliation, ReconciliationAction)
+                }
+                StatementExport => {
+                    map_action!(accounting, StatementExport, StatementExportAction)
+                }
             })
             .collect()
     }
@@ -435,6 +473,12 @@ pub enum CoreAccountingObject {
     BalanceSheetConfiguration(BalanceSheetConfigurationAllOrOne),
     AccountingCsv(AccountingCsvAllOrOne),
     TrialBalance(TrialBalanceAllOrOne),
+    AccountingPeriod(AccountingPeriodAllOrOne),
+    FxRate(FxRateAllOrOne),
+    FxRevaluation(FxRevaluationAllOrOne),
+    RecurringJournalEntry(RecurringJournalEntryAllOrOne),
+    Reconciliation(ReconciliationAllOrOne),
+    StatementExport(StatementExportAllOrOne),
 }
 
 impl CoreAccountingObject {
@@ -516,6 +560,54 @@ impl CoreAccountingObject {
     pub fn all_trial_balance() -> Self {
         CoreAccountingObject::TrialBalance(AllOrOne::All)
     }
+
+    pub fn accounting_period(id: AccountingPeriodId) -> Self {
+        CoreAccountingObject::AccountingPeriod(AllOrOne::ById(id))
+    }
+
+    pub fn all_accounting_periods() -> Self {
+        CoreAccountingObject::AccountingPeriod(AllOrOne::All)
+    }
+
+    pub fn fx_rate(id: FxRateId) -> Self {
+        CoreAccountingObject::FxRate(AllOrOne::ById(id))
+    }
+
+    pub fn all_fx_rates() -> Self {
+        CoreAccountingObject::FxRate(AllOrOne::All)
+    }
+
+    pub fn fx_revaluation(id: FxRevaluationId) -> Self {
+        CoreAccountingObject::FxRevaluation(AllOrOne::ById(id))
+    }
+
+    pub fn all_fx_revaluations() -> Self {
+        CoreAccountingObject::FxRevaluation(AllOrOne::All)
+    }
+
+    pub fn recurring_journal_entry(id: RecurringJournalEntryId) -> Self {
+        CoreAccountingObject::RecurringJournalEntry(AllOrOne::ById(id))
+    }
+
+    pub fn all_recurring_journal_entries() -> Self {
+        CoreAccountingObject::RecurringJournalEntry(AllOrOne::All)
+    }
+
+    pub fn reconciliation(id: ReconciliationId) -> Self {
+        CoreAccountingObject::Reconciliation(AllOrOne::ById(id))
+    }
+
+    pub fn all_reconciliations() -> Self {
+        CoreAccountingObject::Reconciliation(AllOrOne::All)
+    }
+
+    pub fn statement_export(statement_id: LedgerAccountId) -> Self {
+        CoreAccountingObject::StatementExport(AllOrOne::ById(statement_id))
+    }
+
+    pub fn all_statement_exports() -> Self {
+        CoreAccountingObject::StatementExport(AllOrOne::All)
+    }
 }
 
 impl Display for CoreAccountingObject {
@@ -535,6 +627,12 @@ impl Display for CoreAccountingObject {
             BalanceSheetConfiguration(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             AccountingCsv(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             TrialBalance(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            AccountingPeriod(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            FxRate(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            FxRevaluation(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            RecurringJournalEntry(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Reconciliation(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            StatementExport(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
     }
 }
@@ -604,6 +702,32 @@ impl FromStr for CoreAccountingObject {
                 let obj_ref = id.parse().map_err(|_| "could not parse TrialBalance")?;
                 CoreAccountingObject::TrialBalance(obj_ref)
             }
+            AccountingPeriod => {
+                let obj_ref = id.parse().map_err(|_| "could not parse AccountingPeriod")?;
+                CoreAccountingObject::AccountingPeriod(obj_ref)
+            }
+            FxRate => {
+                let obj_ref = id.parse().map_err(|_| "could not parse FxRate")?;
+                CoreAccountingObject::FxRate(obj_ref)
+            }
+            FxRevaluation => {
+                let obj_ref = id.parse().map_err(|_| "could not parse FxRevaluation")?;
+                CoreAccountingObject::FxRevaluation(obj_ref)
+            }
+            RecurringJournalEntry => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse RecurringJournalEntry")?;
+                CoreAccountingObject::RecurringJournalEntry(obj_ref)
+            }
+            Reconciliation => {
+                let obj_ref = id.parse().map_err(|_| "could not parse Reconciliation")?;
+                CoreAccountingObject::Reconciliation(obj_ref)
+            }
+            StatementExport => {
+                let obj_ref = id.parse().map_err(|_| "could not parse StatementExport")?;
+                CoreAccountingObject::StatementExport(obj_ref)
+            }
         };
         Ok(res)
     }
@@ -640,6 +764,10 @@ impl CoreAccountingAction {
         CoreAccountingAction::ManualTransaction(ManualTransactionAction::Create);
     pub const MANUAL_TRANSACTION_LIST: Self =
         CoreAccountingAction::ManualTransaction(ManualTransactionAction::List);
+    pub const MANUAL_TRANSACTION_REVERSE: Self =
+        CoreAccountingAction::ManualTransaction(ManualTransactionAction::Reverse);
+    pub const MANUAL_TRANSACTION_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreAccountingAction::ManualTransaction(ManualTransactionAction::ConcludeApprovalProcess);
     pub const PROFIT_AND_LOSS_READ: Self =
         CoreAccountingAction::ProfitAndLoss(ProfitAndLossAction::Read);
     pub const PROFIT_AND_LOSS_CREATE: Self =
@@ -677,6 +805,59 @@ impl CoreAccountingAction {
         CoreAccountingAction::TrialBalance(TrialBalanceAction::Create);
     pub const TRIAL_BALANCE_UPDATE: Self =
         CoreAccountingAction::TrialBalance(TrialBalanceAction::Update);
+
+    pub const ACCOUNTING_PERIOD_CREATE: Self =
+        CoreAccountingAction::AccountingPeriod(AccountingPeriodAction::Create);
+    pub const ACCOUNTING_PERIOD_READ: Self =
+        CoreAccountingAction::AccountingPeriod(AccountingPeriodAction::Read);
+    pub const ACCOUNTING_PERIOD_LIST: Self =
+        CoreAccountingAction::AccountingPeriod(AccountingPeriodAction::List);
+    pub const ACCOUNTING_PERIOD_CLOSE: Self =
+        CoreAccountingAction::AccountingPeriod(AccountingPeriodAction::Close);
+    pub const ACCOUNTING_PERIOD_REOPEN: Self =
+        CoreAccountingAction::AccountingPeriod(AccountingPeriodAction::Reopen);
+
+    pub const FX_RATE_RECORD: Self = CoreAccountingAction::FxRate(FxRateAction::Record);
+    pub const FX_RATE_READ: Self = CoreAccountingAction::FxRate(FxRateAction::Read);
+    pub const FX_RATE_LIST: Self = CoreAccountingAction::FxRate(FxRateAction::List);
+
+    pub const FX_REVALUATION_CREATE: Self =
+        CoreAccountingAction::FxRevaluation(FxRevaluationAction::Create);
+    pub const FX_REVALUATION_READ: Self =
+        CoreAccountingAction::FxRevaluation(FxRevaluationAction::Read);
+    pub const FX_REVALUATION_LIST: Self =
+        CoreAccountingAction::FxRevaluation(FxRevaluationAction::List);
+
+    pub const RECURRING_JOURNAL_ENTRY_CREATE: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::Create);
+    pub const RECURRING_JOURNAL_ENTRY_READ: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::Read);
+    pub const RECURRING_JOURNAL_ENTRY_LIST: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::List);
+    pub const RECURRING_JOURNAL_ENTRY_PAUSE: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::Pause);
+    pub const RECURRING_JOURNAL_ENTRY_RESUME: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::Resume);
+    pub const RECURRING_JOURNAL_ENTRY_POST_OCCURRENCE: Self =
+        CoreAccountingAction::RecurringJournalEntry(RecurringJournalEntryAction::PostOccurrence);
+
+    pub const RECONCILIATION_CREATE: Self =
+        CoreAccountingAction::Reconciliation(ReconciliationAction::Create);
+    pub const RECONCILIATION_READ: Self =
+        CoreAccountingAction::Reconciliation(ReconciliationAction::Read);
+    pub const RECONCILIATION_LIST: Self =
+        CoreAccountingAction::Reconciliation(ReconciliationAction::List);
+    pub const RECONCILIATION_MATCH: Self =
+        CoreAccountingAction::Reconciliation(ReconciliationAction::Match);
+    pub const RECONCILIATION_COMPLETE: Self =
+        CoreAccountingAction::Reconciliation(ReconciliationAction::Complete);
+
+    pub const STATEMENT_EXPORT_CREATE: Self =
+        CoreAccountingAction::StatementExport(StatementExportAction::Create);
+    pub const STATEMENT_EXPORT_LIST: Self =
+        CoreAccountingAction::StatementExport(StatementExportAction::List);
+    pub const STATEMENT_EXPORT_GENERATE_DOWNLOAD_LINK: Self =
+        CoreAccountingAction::StatementExport(StatementExportAction::Download);
 }
 
 impl Display for CoreAccountingAction {
@@ -696,6 +877,12 @@ impl Display for CoreAccountingAction {
             BalanceSheetConfiguration(action) => action.fmt(f),
             AccountingCsv(action) => action.fmt(f),
             TrialBalance(action) => action.fmt(f),
+            AccountingPeriod(action) => action.fmt(f),
+            FxRate(action) => action.fmt(f),
+            FxRevaluation(action) => action.fmt(f),
+            RecurringJournalEntry(action) => action.fmt(f),
+            Reconciliation(action) => action.fmt(f),
+            StatementExport(action) => action.fmt(f),
         }
     }
 }
@@ -742,6 +929,24 @@ impl FromStr for CoreAccountingAction {
             CoreAccountingActionDiscriminants::TrialBalance => {
                 CoreAccountingAction::from(action.parse::<TrialBalanceAction>()?)
             }
+            CoreAccountingActionDiscriminants::AccountingPeriod => {
+                CoreAccountingAction::from(action.parse::<AccountingPeriodAction>()?)
+            }
+            CoreAccountingActionDiscriminants::FxRate => {
+                CoreAccountingAction::from(action.parse::<FxRateAction>()?)
+            }
+            CoreAccountingActionDiscriminants::FxRevaluation => {
+                CoreAccountingAction::from(action.parse::<FxRevaluationAction>()?)
+            }
+            CoreAccountingActionDiscriminants::RecurringJournalEntry => {
+                CoreAccountingAction::from(action.parse::<RecurringJournalEntryAction>()?)
+            }
+            CoreAccountingActionDiscriminants::Reconciliation => {
+                CoreAccountingAction::from(action.parse::<ReconciliationAction>()?)
+            }
+            CoreAccountingActionDiscriminants::StatementExport => {
+                CoreAccountingAction::from(action.parse::<StatementExportAction>()?)
+            }
         };
         Ok(res)
     }
@@ -864,13 +1069,17 @@ pub enum ManualTransactionAction {
     Read,
     Create,
     List,
+    Reverse,
+    ConcludeApprovalProcess,
 }
 
 impl ActionPermission for ManualTransactionAction {
     fn permission_set(&self) -> &'static str {
         match self {
             Self::List | Self::Read => PERMISSION_SET_ACCOUNTING_VIEWER,
-            Self::Create => PERMISSION_SET_ACCOUNTING_WRITER,
+            Self::Create | Self::Reverse | Self::ConcludeApprovalProcess => {
+                PERMISSION_SET_ACCOUNTING_WRITER
+            }
         }
     }
 }
@@ -1040,6 +1249,153 @@ impl BalanceRange {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AccountingPeriodAction {
+    Create,
+    Read,
+    List,
+    Close,
+    Reopen,
+}
+
+impl ActionPermission for AccountingPeriodAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_ACCOUNTING_VIEWER,
+            Self::Create | Self::Close | Self::Reopen => PERMISSION_SET_ACCOUNTING_WRITER,
+        }
+    }
+}
+
+impl From<AccountingPeriodAction> for CoreAccountingAction {
+    fn from(action: AccountingPeriodAction) -> Self {
+        CoreAccountingAction::AccountingPeriod(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum FxRateAction {
+    Record,
+    Read,
+    List,
+}
+
+impl ActionPermission for FxRateAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_ACCOUNTING_VIEWER,
+            Self::Record => PERMISSION_SET_ACCOUNTING_WRITER,
+        }
+    }
+}
+
+impl From<FxRateAction> for CoreAccountingAction {
+    fn from(action: FxRateAction) -> Self {
+        CoreAccountingAction::FxRate(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum FxRevaluationAction {
+    Create,
+    Read,
+    List,
+}
+
+impl ActionPermission for FxRevaluationAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_ACCOUNTING_VIEWER,
+            Self::Create => PERMISSION_SET_ACCOUNTING_WRITER,
+        }
+    }
+}
+
+impl From<FxRevaluationAction> for CoreAccountingAction {
+    fn from(action: FxRevaluationAction) -> Self {
+        CoreAccountingAction::FxRevaluation(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RecurringJournalEntryAction {
+    Create,
+    Read,
+    List,
+    Pause,
+    Resume,
+    PostOccurrence,
+}
+
+impl ActionPermission for RecurringJournalEntryAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_ACCOUNTING_VIEWER,
+            Self::Create | Self::Pause | Self::Resume | Self::PostOccurrence => {
+                PERMISSION_SET_ACCOUNTING_WRITER
+            }
+        }
+    }
+}
+
+impl From<RecurringJournalEntryAction> for CoreAccountingAction {
+    fn from(action: RecurringJournalEntryAction) -> Self {
+        CoreAccountingAction::RecurringJournalEntry(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ReconciliationAction {
+    Create,
+    Read,
+    List,
+    Match,
+    Complete,
+}
+
+impl ActionPermission for ReconciliationAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_ACCOUNTING_VIEWER,
+            Self::Create | Self::Match | Self::Complete => PERMISSION_SET_ACCOUNTING_WRITER,
+        }
+    }
+}
+
+impl From<ReconciliationAction> for CoreAccountingAction {
+    fn from(action: ReconciliationAction) -> Self {
+        CoreAccountingAction::Reconciliation(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum StatementExportAction {
+    Create,
+    List,
+    Download,
+}
+
+impl ActionPermission for StatementExportAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::List | Self::Download => PERMISSION_SET_ACCOUNTING_VIEWER,
+            Self::Create => PERMISSION_SET_ACCOUNTING_WRITER,
+        }
+    }
+}
+
+impl From<StatementExportAction> for CoreAccountingAction {
+    fn from(action: StatementExportAction) -> Self {
+        CoreAccountingAction::StatementExport(action)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,66 @@
+mod job;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreAccountingAction, CoreAccountingObject,
+    manual_transaction::{ManualTransaction, ManualTransactions, error::ManualTransactionError},
+    primitives::ManualTransactionId,
+};
+
+pub use job::*;
+pub const APPROVE_MANUAL_TRANSACTION_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("manual-transaction");
+
+pub struct ApproveManualTransaction<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    manual_transactions: ManualTransactions<Perms, E>,
+}
+
+impl<Perms, E> Clone for ApproveManualTransaction<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            manual_transactions: self.manual_transactions.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveManualTransaction<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(manual_transactions: &ManualTransactions<Perms, E>) -> Self {
+        Self {
+            manual_transactions: manual_transactions.clone(),
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    #[instrument(name = "core_accounting.approve_manual_transaction", skip(self))]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<ManualTransactionId>,
+        approved: bool,
+    ) -> Result<ManualTransaction, ManualTransactionError> {
+        self.manual_transactions
+            .conclude_approval_process(id.into(), approved)
+            .await
+    }
+}
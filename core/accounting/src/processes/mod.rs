@@ -0,0 +1,3 @@
+pub mod approve_manual_transaction;
+
+pub use approve_manual_transaction::APPROVE_MANUAL_TRANSACTION_PROCESS;
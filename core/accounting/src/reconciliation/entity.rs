@@ -0,0 +1,615 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::{LedgerAccountId, LedgerTransactionId, ReconciliationId};
+
+use super::{error::ReconciliationError, primitives::*};
+
+/// How far apart the booking date on the statement and the effective date of
+/// the ledger transaction may be for them to be matched automatically.
+const AUTO_MATCH_DATE_TOLERANCE_DAYS: i64 = 3;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "ReconciliationId")]
+pub enum ReconciliationEvent {
+    Initialized {
+        id: ReconciliationId,
+        ledger_account_id: LedgerAccountId,
+        statement_reference: String,
+        format: StatementFormat,
+        lines: Vec<StatementLine>,
+        ledger_items: Vec<LedgerItem>,
+        audit_info: AuditInfo,
+    },
+    AutoMatched {
+        matches: Vec<StatementLineMatch>,
+        audit_info: AuditInfo,
+    },
+    LineMatched {
+        line_number: u32,
+        ledger_transaction_id: LedgerTransactionId,
+        audit_info: AuditInfo,
+    },
+    LineMarkedAsException {
+        line_number: u32,
+        reason: String,
+        audit_info: AuditInfo,
+    },
+    LineReset {
+        line_number: u32,
+        audit_info: AuditInfo,
+    },
+    Completed {
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Reconciliation {
+    pub id: ReconciliationId,
+    pub ledger_account_id: LedgerAccountId,
+    pub statement_reference: String,
+    pub format: StatementFormat,
+    pub lines: Vec<StatementLine>,
+    pub ledger_items: Vec<LedgerItem>,
+    events: EntityEvents<ReconciliationEvent>,
+}
+
+impl Reconciliation {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for reconciliation")
+    }
+
+    pub fn status(&self) -> ReconciliationStatus {
+        if self
+            .events
+            .iter_all()
+            .any(|event| matches!(event, ReconciliationEvent::Completed { .. }))
+        {
+            ReconciliationStatus::Completed
+        } else {
+            ReconciliationStatus::Open
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.status() == ReconciliationStatus::Completed
+    }
+
+    fn line_states(&self) -> HashMap<u32, StatementLineState> {
+        let mut states = HashMap::new();
+        for event in self.events.iter_all() {
+            match event {
+                ReconciliationEvent::AutoMatched { matches, .. } => {
+                    for m in matches {
+                        states.insert(
+                            m.line_number,
+                            StatementLineState::Matched {
+                                ledger_transaction_id: m.ledger_transaction_id,
+                                automatic: true,
+                            },
+                        );
+                    }
+                }
+                ReconciliationEvent::LineMatched {
+                    line_number,
+                    ledger_transaction_id,
+                    ..
+                } => {
+                    states.insert(
+                        *line_number,
+                        StatementLineState::Matched {
+                            ledger_transaction_id: *ledger_transaction_id,
+                            automatic: false,
+                        },
+                    );
+                }
+                ReconciliationEvent::LineMarkedAsException {
+                    line_number,
+                    reason,
+                    ..
+                } => {
+                    states.insert(
+                        *line_number,
+                        StatementLineState::Exception {
+                            reason: reason.clone(),
+                        },
+                    );
+                }
+                ReconciliationEvent::LineReset { line_number, .. } => {
+                    states.remove(line_number);
+                }
+                _ => (),
+            }
+        }
+        states
+    }
+
+    fn matched_ledger_transactions(&self) -> HashMap<LedgerTransactionId, u32> {
+        self.line_states()
+            .into_iter()
+            .filter_map(|(line_number, state)| match state {
+                StatementLineState::Matched {
+                    ledger_transaction_id,
+                    ..
+                } => Some((ledger_transaction_id, line_number)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn reconciliation_lines(&self) -> Vec<ReconciliationLine> {
+        let mut states = self.line_states();
+        self.lines
+            .iter()
+            .map(|line| ReconciliationLine {
+                line: line.clone(),
+                state: states
+                    .remove(&line.line_number)
+                    .unwrap_or(StatementLineState::Unmatched),
+            })
+            .collect()
+    }
+
+    pub fn unmatched_lines(&self) -> Vec<&StatementLine> {
+        let states = self.line_states();
+        self.lines
+            .iter()
+            .filter(|line| !states.contains_key(&line.line_number))
+            .collect()
+    }
+
+    /// Ledger activity on the account that no statement line has been matched to.
+    pub fn unmatched_ledger_items(&self) -> Vec<&LedgerItem> {
+        let matched = self.matched_ledger_transactions();
+        self.ledger_items
+            .iter()
+            .filter(|item| !matched.contains_key(&item.ledger_transaction_id))
+            .collect()
+    }
+
+    /// Matches unmatched lines to unmatched ledger items with the same amount
+    /// and currency dated within a few days of each other. Where several
+    /// ledger items qualify, the line is only matched if exactly one of them
+    /// mentions the statement reference in its description.
+    pub(super) fn auto_match(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<usize>, ReconciliationError> {
+        if self.is_completed() {
+            return Err(ReconciliationError::AlreadyCompleted);
+        }
+
+        let mut available = self.unmatched_ledger_items();
+        let mut matches = Vec::new();
+        for line in self.unmatched_lines() {
+            let candidates: Vec<_> = available
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| {
+                    item.amount == line.amount
+                        && item.currency == line.currency
+                        && (item.effective - line.booking_date).num_days().abs()
+                            <= AUTO_MATCH_DATE_TOLERANCE_DAYS
+                })
+                .collect();
+
+            let chosen = match candidates.as_slice() {
+                [(idx, _)] => Some(*idx),
+                _ => {
+                    let mut by_reference = candidates.iter().filter(|(_, item)| {
+                        line.reference
+                            .as_deref()
+                            .is_some_and(|reference| item.mentions(reference))
+                    });
+                    match (by_reference.next(), by_reference.next()) {
+                        (Some((idx, _)), None) => Some(*idx),
+                        _ => None,
+                    }
+                }
+            };
+
+            if let Some(idx) = chosen {
+                let item = available.remove(idx);
+                matches.push(StatementLineMatch {
+                    line_number: line.line_number,
+                    ledger_transaction_id: item.ledger_transaction_id,
+                });
+            }
+        }
+
+        if matches.is_empty() {
+            return Ok(Idempotent::Ignored);
+        }
+
+        let n_matches = matches.len();
+        self.events.push(ReconciliationEvent::AutoMatched {
+            matches,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(n_matches))
+    }
+
+    pub(super) fn match_line(
+        &mut self,
+        line_number: u32,
+        ledger_transaction_id: LedgerTransactionId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, ReconciliationError> {
+        self.ensure_line_can_change(line_number)?;
+        if !self
+            .ledger_items
+            .iter()
+            .any(|item| item.ledger_transaction_id == ledger_transaction_id)
+        {
+            return Err(ReconciliationError::UnknownLedgerTransaction(
+                ledger_transaction_id,
+            ));
+        }
+
+        match self
+            .matched_ledger_transactions()
+            .get(&ledger_transaction_id)
+        {
+            Some(matched_line) if *matched_line == line_number => {
+                return Ok(Idempotent::Ignored);
+            }
+            Some(matched_line) => {
+                return Err(ReconciliationError::LedgerTransactionAlreadyMatched(
+                    ledger_transaction_id,
+                    *matched_line,
+                ));
+            }
+            None => (),
+        }
+
+        self.events.push(ReconciliationEvent::LineMatched {
+            line_number,
+            ledger_transaction_id,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(super) fn mark_line_as_exception(
+        &mut self,
+        line_number: u32,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, ReconciliationError> {
+        self.ensure_line_can_change(line_number)?;
+        if matches!(
+            self.line_states().get(&line_number),
+            Some(StatementLineState::Exception { reason: current }) if *current == reason
+        ) {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events
+            .push(ReconciliationEvent::LineMarkedAsException {
+                line_number,
+                reason,
+                audit_info,
+            });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Clears a match or exception so the line counts as unmatched again.
+    pub(super) fn reset_line(
+        &mut self,
+        line_number: u32,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, ReconciliationError> {
+        self.ensure_line_can_change(line_number)?;
+        if !self.line_states().contains_key(&line_number) {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events.push(ReconciliationEvent::LineReset {
+            line_number,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Every statement line must be matched or marked as an exception first.
+    pub(super) fn complete(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, ReconciliationError> {
+        if self.is_completed() {
+            return Ok(Idempotent::Ignored);
+        }
+        let unresolved = self.unmatched_lines().len();
+        if unresolved > 0 {
+            return Err(ReconciliationError::UnresolvedStatementLines(unresolved));
+        }
+
+        self.events
+            .push(ReconciliationEvent::Completed { audit_info });
+        Ok(Idempotent::Executed(()))
+    }
+
+    fn ensure_line_can_change(&self, line_number: u32) -> Result<(), ReconciliationError> {
+        if self.is_completed() {
+            return Err(ReconciliationError::AlreadyCompleted);
+        }
+        if !self
+            .lines
+            .iter()
+            .any(|line| line.line_number == line_number)
+        {
+            return Err(ReconciliationError::UnknownStatementLine(line_number));
+        }
+        Ok(())
+    }
+}
+
+impl TryFromEvents<ReconciliationEvent> for Reconciliation {
+    fn try_from_events(events: EntityEvents<ReconciliationEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = ReconciliationBuilder::default();
+        for event in events.iter_all() {
+            if let ReconciliationEvent::Initialized {
+                id,
+                ledger_account_id,
+                statement_reference,
+                format,
+                lines,
+                ledger_items,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .ledger_account_id(*ledger_account_id)
+                    .statement_reference(statement_reference.clone())
+                    .format(*format)
+                    .lines(lines.clone())
+                    .ledger_items(ledger_items.clone())
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewReconciliation {
+    #[builder(setter(into))]
+    pub(super) id: ReconciliationId,
+    #[builder(setter(into))]
+    pub(super) ledger_account_id: LedgerAccountId,
+    statement_reference: Option<String>,
+    format: StatementFormat,
+    lines: Vec<StatementLine>,
+    ledger_items: Vec<LedgerItem>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewReconciliation {
+    pub fn builder() -> NewReconciliationBuilder {
+        NewReconciliationBuilder::default()
+    }
+
+    fn statement_reference(&self) -> String {
+        match self.statement_reference.as_deref() {
+            None | Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl IntoEvents<ReconciliationEvent> for NewReconciliation {
+    fn into_events(self) -> EntityEvents<ReconciliationEvent> {
+        EntityEvents::init(
+            self.id,
+            [ReconciliationEvent::Initialized {
+                statement_reference: self.statement_reference(),
+                id: self.id,
+                ledger_account_id: self.ledger_account_id,
+                format: self.format,
+                lines: self.lines,
+                ledger_items: self.ledger_items,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn line(
+        line_number: u32,
+        booking_date: &str,
+        amount: Decimal,
+        reference: &str,
+    ) -> StatementLine {
+        StatementLine {
+            line_number,
+            booking_date: date(booking_date),
+            amount,
+            currency: "USD".to_string(),
+            reference: Some(reference.to_string()),
+            description: None,
+        }
+    }
+
+    fn item(effective: &str, amount: Decimal, description: &str) -> LedgerItem {
+        LedgerItem {
+            ledger_transaction_id: LedgerTransactionId::new(),
+            effective: date(effective),
+            amount,
+            currency: "USD".to_string(),
+            description: Some(description.to_string()),
+        }
+    }
+
+    fn reconciliation(lines: Vec<StatementLine>, ledger_items: Vec<LedgerItem>) -> Reconciliation {
+        let new_reconciliation = NewReconciliation::builder()
+            .id(ReconciliationId::new())
+            .ledger_account_id(LedgerAccountId::new())
+            .statement_reference(Some("STMT-1".to_string()))
+            .format(StatementFormat::Csv)
+            .lines(lines)
+            .ledger_items(ledger_items)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        Reconciliation::try_from_events(new_reconciliation.into_events()).unwrap()
+    }
+
+    #[test]
+    fn auto_match_pairs_amount_and_date_within_tolerance() {
+        let deposit = item("2025-03-01", dec!(100), "deposit");
+        let fee = item("2025-03-01", dec!(-5), "fee");
+        let mut reconciliation = reconciliation(
+            vec![
+                line(1, "2025-03-03", dec!(100), "A"),
+                line(2, "2025-03-10", dec!(-5), "B"),
+            ],
+            vec![deposit.clone(), fee.clone()],
+        );
+
+        assert!(matches!(
+            reconciliation.auto_match(dummy_audit_info()).unwrap(),
+            Idempotent::Executed(1)
+        ));
+        assert_eq!(
+            reconciliation.reconciliation_lines()[0].state,
+            StatementLineState::Matched {
+                ledger_transaction_id: deposit.ledger_transaction_id,
+                automatic: true,
+            }
+        );
+        assert_eq!(reconciliation.unmatched_lines().len(), 1);
+        assert_eq!(reconciliation.unmatched_ledger_items(), vec![&fee]);
+        assert!(
+            reconciliation
+                .auto_match(dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn auto_match_uses_reference_to_break_ties() {
+        let first = item("2025-03-01", dec!(100), "transfer REF-1");
+        let second = item("2025-03-01", dec!(100), "transfer REF-2");
+        let mut reconciliation = reconciliation(
+            vec![
+                line(1, "2025-03-01", dec!(100), "ref-2"),
+                line(2, "2025-03-01", dec!(100), "REF-3"),
+            ],
+            vec![first.clone(), second.clone()],
+        );
+
+        let _ = reconciliation.auto_match(dummy_audit_info()).unwrap();
+
+        let lines = reconciliation.reconciliation_lines();
+        assert_eq!(
+            lines[0].state,
+            StatementLineState::Matched {
+                ledger_transaction_id: second.ledger_transaction_id,
+                automatic: true,
+            }
+        );
+        assert_eq!(
+            lines[1].state,
+            StatementLineState::Matched {
+                ledger_transaction_id: first.ledger_transaction_id,
+                automatic: true,
+            }
+        );
+    }
+
+    #[test]
+    fn ledger_transaction_can_only_be_matched_once() {
+        let deposit = item("2025-03-01", dec!(100), "deposit");
+        let mut reconciliation = reconciliation(
+            vec![
+                line(1, "2025-03-01", dec!(100), "A"),
+                line(2, "2025-03-01", dec!(100), "B"),
+            ],
+            vec![deposit.clone()],
+        );
+
+        assert!(
+            reconciliation
+                .match_line(1, deposit.ledger_transaction_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(matches!(
+            reconciliation.match_line(2, deposit.ledger_transaction_id, dummy_audit_info()),
+            Err(ReconciliationError::LedgerTransactionAlreadyMatched(_, 1))
+        ));
+        assert!(matches!(
+            reconciliation.match_line(2, LedgerTransactionId::new(), dummy_audit_info()),
+            Err(ReconciliationError::UnknownLedgerTransaction(_))
+        ));
+    }
+
+    #[test]
+    fn completion_requires_every_line_resolved() {
+        let mut reconciliation = reconciliation(
+            vec![
+                line(1, "2025-03-01", dec!(100), "A"),
+                line(2, "2025-03-01", dec!(-2), "B"),
+            ],
+            vec![],
+        );
+        let _ = reconciliation.mark_line_as_exception(
+            1,
+            "Not yet booked".to_string(),
+            dummy_audit_info(),
+        );
+        assert!(matches!(
+            reconciliation.complete(dummy_audit_info()),
+            Err(ReconciliationError::UnresolvedStatementLines(1))
+        ));
+
+        let _ =
+            reconciliation.mark_line_as_exception(2, "Bank fee".to_string(), dummy_audit_info());
+        assert!(
+            reconciliation
+                .complete(dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(reconciliation.status(), ReconciliationStatus::Completed);
+        assert!(matches!(
+            reconciliation.reset_line(1, dummy_audit_info()),
+            Err(ReconciliationError::AlreadyCompleted)
+        ));
+    }
+}
@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+use crate::{
+    ledger_account::error::LedgerAccountError, ledger_transaction::error::LedgerTransactionError,
+    primitives::LedgerTransactionId,
+};
+
+use super::statement::StatementParseError;
+
+#[derive(Error, Debug)]
+pub enum ReconciliationError {
+    #[error("ReconciliationError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("ReconciliationError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("ReconciliationError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("ReconciliationError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("ReconciliationError - LedgerAccountError: {0}")]
+    LedgerAccountError(#[from] LedgerAccountError),
+    #[error("ReconciliationError - LedgerTransactionError: {0}")]
+    LedgerTransactionError(#[from] LedgerTransactionError),
+    #[error("ReconciliationError - StatementParseError: {0}")]
+    StatementParseError(#[from] StatementParseError),
+    #[error("ReconciliationError - EmptyStatement")]
+    EmptyStatement,
+    #[error("ReconciliationError - AlreadyCompleted")]
+    AlreadyCompleted,
+    #[error("ReconciliationError - UnknownStatementLine: {0}")]
+    UnknownStatementLine(u32),
+    #[error("ReconciliationError - UnknownLedgerTransaction: {0}")]
+    UnknownLedgerTransaction(LedgerTransactionId),
+    #[error("ReconciliationError - LedgerTransactionAlreadyMatched: {0} to line {1}")]
+    LedgerTransactionAlreadyMatched(LedgerTransactionId, u32),
+    #[error("ReconciliationError - UnresolvedStatementLines: {0}")]
+    UnresolvedStatementLines(usize),
+}
+
+es_entity::from_es_entity_error!(ReconciliationError);
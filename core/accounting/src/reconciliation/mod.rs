@@ -0,0 +1,403 @@
+mod entity;
+pub mod error;
+mod primitives;
+mod repo;
+mod statement;
+
+use cala_ledger::{DebitOrCredit, Layer};
+use chrono::{Days, NaiveDate};
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use std::collections::HashMap;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+
+use crate::{
+    journal::JournalEntryAmount,
+    ledger_account::LedgerAccounts,
+    ledger_transaction::{LedgerTransaction, LedgerTransactions},
+    primitives::{
+        CoreAccountingAction, CoreAccountingObject, LedgerAccountId, LedgerTransactionId,
+        ReconciliationId,
+    },
+};
+
+pub use entity::Reconciliation;
+#[cfg(feature = "json-schema")]
+pub use entity::ReconciliationEvent;
+pub(super) use entity::*;
+use error::*;
+pub use primitives::*;
+pub use repo::reconciliation_cursor::ReconciliationsByCreatedAtCursor;
+use repo::*;
+pub use statement::StatementParseError;
+use statement::*;
+
+/// Ledger activity this many days either side of the statement is offered for
+/// matching, so that items booked around the statement boundaries are found.
+const LEDGER_ITEM_WINDOW_DAYS: u64 = 7;
+
+#[derive(Clone)]
+pub struct Reconciliations<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    ledger_accounts: LedgerAccounts<Perms>,
+    ledger_transactions: LedgerTransactions<Perms>,
+    repo: ReconciliationRepo,
+}
+
+impl<Perms> Reconciliations<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        ledger_accounts: &LedgerAccounts<Perms>,
+        ledger_transactions: &LedgerTransactions<Perms>,
+    ) -> Self {
+        Self {
+            authz: authz.clone(),
+            ledger_accounts: ledger_accounts.clone(),
+            ledger_transactions: ledger_transactions.clone(),
+            repo: ReconciliationRepo::new(pool),
+        }
+    }
+
+    /// Starts a reconciliation session for `ledger_account_id` from a bank
+    /// statement and matches whatever lines it can straight away.
+    #[instrument(
+        name = "core_accounting.reconciliation.import_statement",
+        skip(self, data),
+        err
+    )]
+    pub async fn import_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ledger_account_id: impl Into<LedgerAccountId> + std::fmt::Debug,
+        statement_reference: Option<String>,
+        format: StatementFormat,
+        data: impl AsRef<str>,
+    ) -> Result<Reconciliation, ReconciliationError> {
+        let ledger_account_id = ledger_account_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_reconciliations(),
+                CoreAccountingAction::RECONCILIATION_CREATE,
+            )
+            .await?;
+
+        let statement = StatementParser::new(format, data.as_ref().to_string()).parse()?;
+        let (Some(from), Some(until)) = (
+            statement.lines.iter().map(|line| line.booking_date).min(),
+            statement.lines.iter().map(|line| line.booking_date).max(),
+        ) else {
+            return Err(ReconciliationError::EmptyStatement);
+        };
+        let ledger_items = self.ledger_items(ledger_account_id, from, until).await?;
+
+        let new_reconciliation = NewReconciliation::builder()
+            .id(ReconciliationId::new())
+            .ledger_account_id(ledger_account_id)
+            .statement_reference(statement_reference.or(statement.reference))
+            .format(format)
+            .lines(statement.lines)
+            .ledger_items(ledger_items)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("Couldn't build new reconciliation");
+
+        let mut db = self.repo.begin_op().await?;
+        let mut reconciliation = self.repo.create_in_op(&mut db, new_reconciliation).await?;
+        if reconciliation.auto_match(audit_info)?.did_execute() {
+            self.repo.update_in_op(&mut db, &mut reconciliation).await?;
+        }
+        db.commit().await?;
+
+        Ok(reconciliation)
+    }
+
+    #[instrument(name = "core_accounting.reconciliation.auto_match", skip(self), err)]
+    pub async fn auto_match(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ReconciliationId> + std::fmt::Debug,
+    ) -> Result<Reconciliation, ReconciliationError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::reconciliation(id),
+                CoreAccountingAction::RECONCILIATION_MATCH,
+            )
+            .await?;
+
+        let mut reconciliation = self.repo.find_by_id(id).await?;
+        if reconciliation.auto_match(audit_info)?.did_execute() {
+            self.repo.update(&mut reconciliation).await?;
+        }
+
+        Ok(reconciliation)
+    }
+
+    #[instrument(name = "core_accounting.reconciliation.match_line", skip(self), err)]
+    pub async fn match_line(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ReconciliationId> + std::fmt::Debug,
+        line_number: u32,
+        ledger_transaction_id: impl Into<LedgerTransactionId> + std::fmt::Debug,
+    ) -> Result<Reconciliation, ReconciliationError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::reconciliation(id),
+                CoreAccountingAction::RECONCILIATION_MATCH,
+            )
+            .await?;
+
+        let mut reconciliation = self.repo.find_by_id(id).await?;
+        if reconciliation
+            .match_line(line_number, ledger_transaction_id.into(), audit_info)?
+            .did_execute()
+        {
+            self.repo.update(&mut reconciliation).await?;
+        }
+
+        Ok(reconciliation)
+    }
+
+    #[instrument(
+        name = "core_accounting.reconciliation.mark_line_as_exception",
+        skip(self),
+        err
+    )]
+    pub async fn mark_line_as_exception(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ReconciliationId> + std::fmt::Debug,
+        line_number: u32,
+        reason: String,
+    ) -> Result<Reconciliation, ReconciliationError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::reconciliation(id),
+                CoreAccountingAction::RECONCILIATION_MATCH,
+            )
+            .await?;
+
+        let mut reconciliation = self.repo.find_by_id(id).await?;
+        if reconciliation
+            .mark_line_as_exception(line_number, reason, audit_info)?
+            .did_execute()
+        {
+            self.repo.update(&mut reconciliation).await?;
+        }
+
+        Ok(reconciliation)
+    }
+
+    #[instrument(name = "core_accounting.reconciliation.reset_line", skip(self), err)]
+    pub async fn reset_line(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ReconciliationId> + std::fmt::Debug,
+        line_number: u32,
+    ) -> Result<Reconciliation, ReconciliationError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::reconciliation(id),
+                CoreAccountingAction::RECONCILIATION_MATCH,
+            )
+            .await?;
+
+        let mut reconciliation = self.repo.find_by_id(id).await?;
+        if reconciliation
+            .reset_line(line_number, audit_info)?
+            .did_execute()
+        {
+            self.repo.update(&mut reconciliation).await?;
+        }
+
+        Ok(reconciliation)
+    }
+
+    #[instrument(name = "core_accounting.reconciliation.complete", skip(self), err)]
+    pub async fn complete(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ReconciliationId> + std::fmt::Debug,
+    ) -> Result<Reconciliation, ReconciliationError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::reconciliation(id),
+                CoreAccountingAction::RECONCILIATION_COMPLETE,
+            )
+            .await?;
+
+        let mut reconciliation = self.repo.find_by_id(id).await?;
+        if reconciliation.complete(audit_info)?.did_execute() {
+            self.repo.update(&mut reconciliation).await?;
+        }
+
+        Ok(reconciliation)
+    }
+
+    #[instrument(name = "core_accounting.reconciliation.find_by_id", skip(self), err)]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<ReconciliationId> + std::fmt::Debug,
+    ) -> Result<Option<Reconciliation>, ReconciliationError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::reconciliation(id),
+                CoreAccountingAction::RECONCILIATION_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(reconciliation) => Ok(Some(reconciliation)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[instrument(name = "core_accounting.reconciliation.list", skip(self), err)]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<ReconciliationsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<Reconciliation, ReconciliationsByCreatedAtCursor>,
+        ReconciliationError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_reconciliations(),
+                CoreAccountingAction::RECONCILIATION_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    #[instrument(
+        name = "core_accounting.reconciliation.list_for_ledger_account",
+        skip(self),
+        err
+    )]
+    pub async fn list_for_ledger_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        ledger_account_id: impl Into<LedgerAccountId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<ReconciliationsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<Reconciliation, ReconciliationsByCreatedAtCursor>,
+        ReconciliationError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_reconciliations(),
+                CoreAccountingAction::RECONCILIATION_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_for_ledger_account_id_by_created_at(
+                ledger_account_id.into(),
+                query,
+                es_entity::ListDirection::Descending,
+            )
+            .await
+    }
+
+    #[instrument(name = "core_accounting.reconciliation.find_all", skip(self), err)]
+    pub async fn find_all<T: From<Reconciliation>>(
+        &self,
+        ids: &[ReconciliationId],
+    ) -> Result<HashMap<ReconciliationId, T>, ReconciliationError> {
+        self.repo.find_all(ids).await
+    }
+
+    /// Nets the settled entries of each ledger transaction on the account that
+    /// is effective within the window around the statement.
+    async fn ledger_items(
+        &self,
+        ledger_account_id: LedgerAccountId,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<LedgerItem>, ReconciliationError> {
+        let from = from - Days::new(LEDGER_ITEM_WINDOW_DAYS);
+        let until = until + Days::new(LEDGER_ITEM_WINDOW_DAYS);
+
+        let mut amounts: HashMap<(LedgerTransactionId, String), Decimal> = HashMap::new();
+        for entry in self
+            .ledger_accounts
+            .history_effective_between(ledger_account_id, from, until)
+            .await?
+            .into_iter()
+            .filter(|entry| entry.layer == Layer::Settled)
+        {
+            let units = match &entry.amount {
+                JournalEntryAmount::Usd(cents) => cents.to_usd(),
+                JournalEntryAmount::Btc(sats) => sats.to_btc(),
+                JournalEntryAmount::Money(money) => money.amount(),
+            };
+            let units = match entry.direction {
+                DebitOrCredit::Debit => units,
+                DebitOrCredit::Credit => -units,
+            };
+            *amounts
+                .entry((entry.ledger_transaction_id, entry.amount.currency_code()))
+                .or_default() += units;
+        }
+
+        let ids: Vec<_> = amounts.keys().map(|(id, _)| *id).collect();
+        let transactions: HashMap<LedgerTransactionId, LedgerTransaction> =
+            self.ledger_transactions.find_all(&ids).await?;
+
+        let mut items: Vec<_> = amounts
+            .into_iter()
+            .filter_map(|((ledger_transaction_id, currency), amount)| {
+                let tx = transactions.get(&ledger_transaction_id)?;
+                Some(LedgerItem {
+                    ledger_transaction_id,
+                    effective: tx.effective,
+                    amount,
+                    currency,
+                    description: tx.description.clone(),
+                })
+            })
+            .collect();
+        items.sort_by_key(|item| item.effective);
+
+        Ok(items)
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::LedgerTransactionId;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum StatementFormat {
+    Csv,
+    Camt053,
+}
+
+/// A line of an imported bank statement. Positive amounts are credits to the
+/// account at the bank, i.e. money coming in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct StatementLine {
+    pub line_number: u32,
+    pub booking_date: NaiveDate,
+    pub amount: Decimal,
+    pub currency: String,
+    pub reference: Option<String>,
+    pub description: Option<String>,
+}
+
+/// The net movement of a ledger transaction on the reconciled account.
+/// Positive amounts are debits, so money coming into an asset account has the
+/// same sign as on the bank statement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct LedgerItem {
+    pub ledger_transaction_id: LedgerTransactionId,
+    pub effective: NaiveDate,
+    pub amount: Decimal,
+    pub currency: String,
+    pub description: Option<String>,
+}
+
+impl LedgerItem {
+    pub(super) fn mentions(&self, reference: &str) -> bool {
+        let reference = reference.trim().to_lowercase();
+        !reference.is_empty()
+            && self
+                .description
+                .as_deref()
+                .is_some_and(|description| description.to_lowercase().contains(&reference))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct StatementLineMatch {
+    pub line_number: u32,
+    pub ledger_transaction_id: LedgerTransactionId,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum ReconciliationStatus {
+    Open,
+    Completed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatementLineState {
+    Unmatched,
+    Matched {
+        ledger_transaction_id: LedgerTransactionId,
+        automatic: bool,
+    },
+    Exception {
+        reason: String,
+    },
+}
+
+/// A statement line together with how it has been reconciled so far.
+#[derive(Debug, Clone)]
+pub struct ReconciliationLine {
+    pub line: StatementLine,
+    pub state: StatementLineState,
+}
@@ -0,0 +1,32 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::{LedgerAccountId, ReconciliationId};
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "Reconciliation",
+    err = "ReconciliationError",
+    columns(ledger_account_id(ty = "LedgerAccountId", list_for, update(persist = false))),
+    tbl_prefix = "core"
+)]
+pub struct ReconciliationRepo {
+    pool: PgPool,
+}
+
+impl Clone for ReconciliationRepo {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl ReconciliationRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
@@ -0,0 +1,265 @@
+use chrono::NaiveDate;
+use csv::{ReaderBuilder, Trim};
+use roxmltree::{Document, Node};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use thiserror::Error;
+
+use std::io::Cursor;
+
+use super::primitives::{StatementFormat, StatementLine};
+
+#[derive(Error, Debug)]
+pub enum StatementParseError {
+    #[error("StatementParseError - Csv: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("StatementParseError - Xml: {0}")]
+    Xml(#[from] roxmltree::Error),
+    #[error("StatementParseError - MissingField: {1} on line {0}")]
+    MissingField(u32, &'static str),
+    #[error("StatementParseError - InvalidAmount: {1} on line {0}")]
+    InvalidAmount(u32, String),
+    #[error("StatementParseError - InvalidDate: {1} on line {0}")]
+    InvalidDate(u32, String),
+    #[error("StatementParseError - InvalidCreditDebitIndicator: {1} on line {0}")]
+    InvalidCreditDebitIndicator(u32, String),
+}
+
+pub struct ParsedStatement {
+    pub reference: Option<String>,
+    pub lines: Vec<StatementLine>,
+}
+
+pub struct StatementParser {
+    format: StatementFormat,
+    data: String,
+}
+
+impl StatementParser {
+    pub fn new(format: StatementFormat, data: String) -> Self {
+        Self { format, data }
+    }
+
+    pub fn parse(self) -> Result<ParsedStatement, StatementParseError> {
+        match self.format {
+            StatementFormat::Csv => Ok(ParsedStatement {
+                reference: None,
+                lines: csv_lines(&self.data)?,
+            }),
+            StatementFormat::Camt053 => camt053_statement(&self.data),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CsvRecord {
+    date: NaiveDate,
+    amount: Decimal,
+    currency: String,
+    #[serde(default)]
+    reference: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+/// Expects a header row naming the `date`, `amount`, `currency` and optional
+/// `reference` and `description` columns, with amounts signed from the bank's
+/// point of view.
+fn csv_lines(data: &str) -> Result<Vec<StatementLine>, StatementParseError> {
+    let mut rdr = ReaderBuilder::new()
+        .trim(Trim::All)
+        .from_reader(Cursor::new(data));
+
+    let mut lines = Vec::new();
+    for (idx, record) in rdr.deserialize::<CsvRecord>().enumerate() {
+        let record = record?;
+        lines.push(StatementLine {
+            line_number: idx as u32 + 1,
+            booking_date: record.date,
+            amount: record.amount,
+            currency: record.currency.to_uppercase(),
+            reference: record.reference.filter(|r| !r.is_empty()),
+            description: record.description.filter(|d| !d.is_empty()),
+        });
+    }
+    Ok(lines)
+}
+
+fn camt053_statement(data: &str) -> Result<ParsedStatement, StatementParseError> {
+    let document = Document::parse(data)?;
+    let statement = document
+        .descendants()
+        .find(|node| node.has_tag_name("Stmt"))
+        .unwrap_or_else(|| document.root_element());
+    let reference = statement
+        .children()
+        .find(|node| node.has_tag_name("Id"))
+        .and_then(node_text);
+
+    let mut lines = Vec::new();
+    for (idx, entry) in statement
+        .children()
+        .filter(|node| node.has_tag_name("Ntry"))
+        .enumerate()
+    {
+        let line_number = idx as u32 + 1;
+
+        let amount =
+            element(entry, "Amt").ok_or(StatementParseError::MissingField(line_number, "Amt"))?;
+        let currency = amount
+            .attribute("Ccy")
+            .ok_or(StatementParseError::MissingField(line_number, "Ccy"))?;
+        let amount = amount.text().unwrap_or_default().trim();
+        let amount = amount
+            .parse::<Decimal>()
+            .map_err(|_| StatementParseError::InvalidAmount(line_number, amount.to_string()))?;
+        let amount = match text(entry, "CdtDbtInd").as_deref() {
+            Some("CRDT") => amount,
+            Some("DBIT") => -amount,
+            indicator => {
+                return Err(StatementParseError::InvalidCreditDebitIndicator(
+                    line_number,
+                    indicator.unwrap_or_default().to_string(),
+                ));
+            }
+        };
+
+        let booking_date = element(entry, "BookgDt")
+            .ok_or(StatementParseError::MissingField(line_number, "BookgDt"))?;
+        let booking_date = text(booking_date, "Dt")
+            .or_else(|| text(booking_date, "DtTm"))
+            .ok_or(StatementParseError::MissingField(line_number, "BookgDt"))?;
+        let booking_date = booking_date
+            .get(..10)
+            .and_then(|date| date.parse::<NaiveDate>().ok())
+            .ok_or_else(|| StatementParseError::InvalidDate(line_number, booking_date.clone()))?;
+
+        lines.push(StatementLine {
+            line_number,
+            booking_date,
+            amount,
+            currency: currency.to_uppercase(),
+            reference: text(entry, "AcctSvcrRef").or_else(|| text(entry, "EndToEndId")),
+            description: text(entry, "AddtlNtryInf").or_else(|| text(entry, "Ustrd")),
+        });
+    }
+
+    Ok(ParsedStatement { reference, lines })
+}
+
+/// Tags are matched on their local name so that statements parse whichever
+/// camt.053 schema version they declare as their namespace.
+fn element<'a, 'input>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.descendants().find(|node| node.has_tag_name(tag))
+}
+
+fn text(node: Node, tag: &str) -> Option<String> {
+    element(node, tag).and_then(node_text)
+}
+
+fn node_text(node: Node) -> Option<String> {
+    node.text()
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_csv_statement() {
+        let data = "date,amount,currency,reference,description\n\
+                    2025-03-03,1500.00,usd,INV-1,Customer deposit\n\
+                    2025-03-04,-20.5,USD,,Bank fee\n";
+
+        let statement = StatementParser::new(StatementFormat::Csv, data.to_string())
+            .parse()
+            .unwrap();
+
+        assert_eq!(statement.reference, None);
+        assert_eq!(
+            statement.lines,
+            vec![
+                StatementLine {
+                    line_number: 1,
+                    booking_date: date("2025-03-03"),
+                    amount: dec!(1500.00),
+                    currency: "USD".to_string(),
+                    reference: Some("INV-1".to_string()),
+                    description: Some("Customer deposit".to_string()),
+                },
+                StatementLine {
+                    line_number: 2,
+                    booking_date: date("2025-03-04"),
+                    amount: dec!(-20.5),
+                    currency: "USD".to_string(),
+                    reference: None,
+                    description: Some("Bank fee".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_camt053_statement() {
+        let data = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.02">
+  <BkToCstmrStmt>
+    <GrpHdr><MsgId>MSG-1</MsgId></GrpHdr>
+    <Stmt>
+      <Id>STMT-2025-03</Id>
+      <Acct><Id><IBAN>SV00BANK0000000000000001</IBAN></Id></Acct>
+      <Ntry>
+        <Amt Ccy="USD">1500.00</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Sts>BOOK</Sts>
+        <BookgDt><Dt>2025-03-03</Dt></BookgDt>
+        <AcctSvcrRef>REF-1</AcctSvcrRef>
+        <AddtlNtryInf>Deposit &amp; transfer</AddtlNtryInf>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="USD">20.50</Amt>
+        <CdtDbtInd>DBIT</CdtDbtInd>
+        <BookgDt><DtTm>2025-03-04T10:00:00</DtTm></BookgDt>
+        <NtryDtls><TxDtls><Refs><EndToEndId>E2E-2</EndToEndId></Refs></TxDtls></NtryDtls>
+      </Ntry>
+    </Stmt>
+  </BkToCstmrStmt>
+</Document>"#;
+
+        let statement = StatementParser::new(StatementFormat::Camt053, data.to_string())
+            .parse()
+            .unwrap();
+
+        assert_eq!(statement.reference.as_deref(), Some("STMT-2025-03"));
+        assert_eq!(statement.lines.len(), 2);
+        assert_eq!(statement.lines[0].amount, dec!(1500.00));
+        assert_eq!(statement.lines[0].reference.as_deref(), Some("REF-1"));
+        assert_eq!(
+            statement.lines[0].description.as_deref(),
+            Some("Deposit & transfer")
+        );
+        assert_eq!(statement.lines[1].line_number, 2);
+        assert_eq!(statement.lines[1].amount, dec!(-20.50));
+        assert_eq!(statement.lines[1].booking_date, date("2025-03-04"));
+        assert_eq!(statement.lines[1].reference.as_deref(), Some("E2E-2"));
+    }
+
+    #[test]
+    fn camt053_entry_requires_credit_debit_indicator() {
+        let data = r#"<Stmt><Id>S</Id><Ntry><Amt Ccy="USD">1</Amt><BookgDt><Dt>2025-03-03</Dt></BookgDt></Ntry></Stmt>"#;
+
+        assert!(matches!(
+            StatementParser::new(StatementFormat::Camt053, data.to_string()).parse(),
+            Err(StatementParseError::InvalidCreditDebitIndicator(1, _))
+        ));
+    }
+}
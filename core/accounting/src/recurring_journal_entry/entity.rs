@@ -0,0 +1,407 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::{
+    manual_transaction::ManualTransactionEntry,
+    primitives::{ManualTransactionId, RecurringJournalEntryId},
+};
+
+use super::{error::RecurringJournalEntryError, primitives::*};
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "RecurringJournalEntryId")]
+pub enum RecurringJournalEntryEvent {
+    Initialized {
+        id: RecurringJournalEntryId,
+        reference: String,
+        description: String,
+        schedule: RecurrenceSchedule,
+        entries: Vec<ManualTransactionEntry>,
+        next_occurrence: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    OccurrencePosted {
+        effective: NaiveDate,
+        manual_transaction_id: ManualTransactionId,
+        next_occurrence: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    OccurrenceSkipped {
+        effective: NaiveDate,
+        reason: String,
+        next_occurrence: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    Paused {
+        audit_info: AuditInfo,
+    },
+    Resumed {
+        next_occurrence: NaiveDate,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct RecurringJournalEntry {
+    pub id: RecurringJournalEntryId,
+    pub reference: String,
+    pub description: String,
+    pub schedule: RecurrenceSchedule,
+    pub entries: Vec<ManualTransactionEntry>,
+    pub next_occurrence: NaiveDate,
+    events: EntityEvents<RecurringJournalEntryEvent>,
+}
+
+impl RecurringJournalEntry {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for recurring journal entry")
+    }
+
+    pub fn status(&self) -> RecurringJournalEntryStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                RecurringJournalEntryEvent::Paused { .. } => {
+                    Some(RecurringJournalEntryStatus::Paused)
+                }
+                RecurringJournalEntryEvent::Resumed { .. } => {
+                    Some(RecurringJournalEntryStatus::Active)
+                }
+                _ => None,
+            })
+            .unwrap_or(RecurringJournalEntryStatus::Active)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.status() == RecurringJournalEntryStatus::Paused
+    }
+
+    /// Occurrences generated or skipped so far, oldest first.
+    pub fn occurrences(&self) -> Vec<RecurringJournalEntryOccurrence> {
+        self.events
+            .iter_all()
+            .filter_map(|event| match event {
+                RecurringJournalEntryEvent::OccurrencePosted {
+                    effective,
+                    manual_transaction_id,
+                    ..
+                } => Some(RecurringJournalEntryOccurrence {
+                    effective: *effective,
+                    manual_transaction_id: Some(*manual_transaction_id),
+                    skip_reason: None,
+                }),
+                RecurringJournalEntryEvent::OccurrenceSkipped {
+                    effective, reason, ..
+                } => Some(RecurringJournalEntryOccurrence {
+                    effective: *effective,
+                    manual_transaction_id: None,
+                    skip_reason: Some(reason.clone()),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn is_due_on(&self, effective: NaiveDate) -> bool {
+        !self.is_paused() && self.next_occurrence == effective
+    }
+
+    /// The reference given to the manual transaction generated on `effective`.
+    pub fn occurrence_reference(&self, effective: NaiveDate) -> String {
+        format!("{}-{}", self.reference, effective)
+    }
+
+    pub(super) fn record_occurrence(
+        &mut self,
+        effective: NaiveDate,
+        manual_transaction_id: ManualTransactionId,
+        audit_info: AuditInfo,
+    ) -> Result<(), RecurringJournalEntryError> {
+        let next_occurrence = self.next_occurrence_after(effective)?;
+        self.events
+            .push(RecurringJournalEntryEvent::OccurrencePosted {
+                effective,
+                manual_transaction_id,
+                next_occurrence,
+                audit_info,
+            });
+        self.next_occurrence = next_occurrence;
+        Ok(())
+    }
+
+    /// Moves the schedule past an occurrence that cannot be posted, e.g. because
+    /// its period has been closed, so the job does not retry it forever.
+    pub(super) fn record_skipped_occurrence(
+        &mut self,
+        effective: NaiveDate,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<(), RecurringJournalEntryError> {
+        let next_occurrence = self.next_occurrence_after(effective)?;
+        self.events
+            .push(RecurringJournalEntryEvent::OccurrenceSkipped {
+                effective,
+                reason,
+                next_occurrence,
+                audit_info,
+            });
+        self.next_occurrence = next_occurrence;
+        Ok(())
+    }
+
+    fn next_occurrence_after(
+        &self,
+        effective: NaiveDate,
+    ) -> Result<NaiveDate, RecurringJournalEntryError> {
+        if !self.is_due_on(effective) {
+            return Err(RecurringJournalEntryError::OccurrenceNotDue(
+                self.id, effective,
+            ));
+        }
+
+        Ok(self
+            .schedule
+            .first_occurrence_on_or_after(effective.succ_opt().expect("date out of range")))
+    }
+
+    pub(super) fn pause(&mut self, audit_info: AuditInfo) -> Idempotent<()> {
+        if self.is_paused() {
+            return Idempotent::Ignored;
+        }
+        self.events
+            .push(RecurringJournalEntryEvent::Paused { audit_info });
+        Idempotent::Executed(())
+    }
+
+    /// Occurrences that fell due while paused are skipped rather than posted
+    /// in a batch on resumption.
+    pub(super) fn resume(&mut self, today: NaiveDate, audit_info: AuditInfo) -> Idempotent<()> {
+        if !self.is_paused() {
+            return Idempotent::Ignored;
+        }
+        let next_occurrence = self
+            .schedule
+            .first_occurrence_on_or_after(self.next_occurrence.max(today));
+        self.events.push(RecurringJournalEntryEvent::Resumed {
+            next_occurrence,
+            audit_info,
+        });
+        self.next_occurrence = next_occurrence;
+        Idempotent::Executed(())
+    }
+}
+
+impl TryFromEvents<RecurringJournalEntryEvent> for RecurringJournalEntry {
+    fn try_from_events(
+        events: EntityEvents<RecurringJournalEntryEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = RecurringJournalEntryBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                RecurringJournalEntryEvent::Initialized {
+                    id,
+                    reference,
+                    description,
+                    schedule,
+                    entries,
+                    next_occurrence,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .reference(reference.clone())
+                        .description(description.clone())
+                        .schedule(*schedule)
+                        .entries(entries.clone())
+                        .next_occurrence(*next_occurrence)
+                }
+                RecurringJournalEntryEvent::OccurrencePosted {
+                    next_occurrence, ..
+                }
+                | RecurringJournalEntryEvent::OccurrenceSkipped {
+                    next_occurrence, ..
+                }
+                | RecurringJournalEntryEvent::Resumed {
+                    next_occurrence, ..
+                } => builder = builder.next_occurrence(*next_occurrence),
+                RecurringJournalEntryEvent::Paused { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewRecurringJournalEntry {
+    #[builder(setter(into))]
+    pub(super) id: RecurringJournalEntryId,
+    reference: Option<String>,
+    description: String,
+    schedule: RecurrenceSchedule,
+    entries: Vec<ManualTransactionEntry>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewRecurringJournalEntry {
+    pub fn builder() -> NewRecurringJournalEntryBuilder {
+        NewRecurringJournalEntryBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None | Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl IntoEvents<RecurringJournalEntryEvent> for NewRecurringJournalEntry {
+    fn into_events(self) -> EntityEvents<RecurringJournalEntryEvent> {
+        EntityEvents::init(
+            self.id,
+            [RecurringJournalEntryEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                description: self.description,
+                next_occurrence: self.schedule.first_occurrence(),
+                schedule: self.schedule,
+                entries: self.entries,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn recurring_journal_entry() -> RecurringJournalEntry {
+        let new_entry = NewRecurringJournalEntry::builder()
+            .id(RecurringJournalEntryId::new())
+            .reference(Some("rent".to_string()))
+            .description("Monthly rent accrual".to_string())
+            .schedule(RecurrenceSchedule::new(
+                RecurrenceFrequency::EndOfMonth,
+                date("2025-01-01"),
+            ))
+            .entries(vec![])
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        RecurringJournalEntry::try_from_events(new_entry.into_events()).unwrap()
+    }
+
+    #[test]
+    fn recording_an_occurrence_advances_the_schedule() {
+        let mut entry = recurring_journal_entry();
+        assert_eq!(entry.next_occurrence, date("2025-01-31"));
+
+        let tx_id = ManualTransactionId::new();
+        entry
+            .record_occurrence(date("2025-01-31"), tx_id, dummy_audit_info())
+            .unwrap();
+        assert_eq!(entry.next_occurrence, date("2025-02-28"));
+        assert_eq!(
+            entry.occurrences(),
+            vec![RecurringJournalEntryOccurrence {
+                effective: date("2025-01-31"),
+                manual_transaction_id: Some(tx_id),
+                skip_reason: None,
+            }]
+        );
+        assert_eq!(
+            entry.occurrence_reference(date("2025-01-31")),
+            "rent-2025-01-31"
+        );
+    }
+
+    #[test]
+    fn skipping_an_occurrence_advances_the_schedule() {
+        let mut entry = recurring_journal_entry();
+
+        entry
+            .record_skipped_occurrence(
+                date("2025-01-31"),
+                "period closed".to_string(),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(entry.next_occurrence, date("2025-02-28"));
+        assert_eq!(
+            entry.occurrences(),
+            vec![RecurringJournalEntryOccurrence {
+                effective: date("2025-01-31"),
+                manual_transaction_id: None,
+                skip_reason: Some("period closed".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn occurrence_must_be_due() {
+        let mut entry = recurring_journal_entry();
+        assert!(matches!(
+            entry.record_occurrence(
+                date("2025-02-28"),
+                ManualTransactionId::new(),
+                dummy_audit_info()
+            ),
+            Err(RecurringJournalEntryError::OccurrenceNotDue(..))
+        ));
+    }
+
+    #[test]
+    fn paused_entry_is_not_due() {
+        let mut entry = recurring_journal_entry();
+        assert!(entry.pause(dummy_audit_info()).did_execute());
+        assert!(entry.pause(dummy_audit_info()).was_ignored());
+        assert_eq!(entry.status(), RecurringJournalEntryStatus::Paused);
+        assert!(!entry.is_due_on(date("2025-01-31")));
+    }
+
+    #[test]
+    fn resume_skips_occurrences_missed_while_paused() {
+        let mut entry = recurring_journal_entry();
+        let _ = entry.pause(dummy_audit_info());
+
+        assert!(
+            entry
+                .resume(date("2025-03-15"), dummy_audit_info())
+                .did_execute()
+        );
+        assert_eq!(entry.status(), RecurringJournalEntryStatus::Active);
+        assert_eq!(entry.next_occurrence, date("2025-03-31"));
+        assert!(
+            entry
+                .resume(date("2025-03-15"), dummy_audit_info())
+                .was_ignored()
+        );
+    }
+}
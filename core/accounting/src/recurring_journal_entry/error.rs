@@ -0,0 +1,32 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::{accounting_period, manual_transaction, primitives::RecurringJournalEntryId};
+
+#[derive(Error, Debug)]
+pub enum RecurringJournalEntryError {
+    #[error("RecurringJournalEntryError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("RecurringJournalEntryError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("RecurringJournalEntryError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("RecurringJournalEntryError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("RecurringJournalEntryError - AuditError: {0}")]
+    AuditError(#[from] audit::error::AuditError),
+    #[error("RecurringJournalEntryError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
+    #[error("RecurringJournalEntryError - ManualTransactionError: {0}")]
+    ManualTransactionError(#[from] manual_transaction::error::ManualTransactionError),
+    #[error("RecurringJournalEntryError - AccountingPeriodGuardError: {0}")]
+    AccountingPeriodGuardError(#[from] accounting_period::error::AccountingPeriodGuardError),
+    #[error("RecurringJournalEntryError - StartsInPast: {0}")]
+    StartsInPast(NaiveDate),
+    #[error("RecurringJournalEntryError - NoEntries")]
+    NoEntries,
+    #[error("RecurringJournalEntryError - OccurrenceNotDue: {0} on {1}")]
+    OccurrenceNotDue(RecurringJournalEntryId, NaiveDate),
+}
+
+es_entity::from_es_entity_error!(RecurringJournalEntryError);
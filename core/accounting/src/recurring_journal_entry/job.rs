@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::primitives::{CoreAccountingAction, CoreAccountingObject, RecurringJournalEntryId};
+
+use super::RecurringJournalEntries;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RecurringJournalEntryJobConfig<Perms, E> {
+    pub recurring_journal_entry_id: RecurringJournalEntryId,
+    pub effective: NaiveDate,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> JobConfig for RecurringJournalEntryJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = RecurringJournalEntryInit<Perms, E>;
+}
+
+pub struct RecurringJournalEntryInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    recurring_journal_entries: RecurringJournalEntries<Perms, E>,
+}
+
+impl<Perms, E> RecurringJournalEntryInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(recurring_journal_entries: &RecurringJournalEntries<Perms, E>) -> Self {
+        Self {
+            recurring_journal_entries: recurring_journal_entries.clone(),
+        }
+    }
+}
+
+const RECURRING_JOURNAL_ENTRY_JOB: JobType = JobType::new("recurring-journal-entry");
+impl<Perms, E> JobInitializer for RecurringJournalEntryInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        RECURRING_JOURNAL_ENTRY_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(RecurringJournalEntryJobRunner::<Perms, E> {
+            config: job.config()?,
+            recurring_journal_entries: self.recurring_journal_entries.clone(),
+        }))
+    }
+}
+
+pub struct RecurringJournalEntryJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    config: RecurringJournalEntryJobConfig<Perms, E>,
+    recurring_journal_entries: RecurringJournalEntries<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for RecurringJournalEntryJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    #[instrument(
+        name = "core_accounting.job.recurring-journal-entry",
+        skip(self, _current_job)
+    )]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        self.recurring_journal_entries
+            .post_occurrence(
+                self.config.recurring_journal_entry_id,
+                self.config.effective,
+            )
+            .await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
@@ -0,0 +1,368 @@
+mod entity;
+pub mod error;
+mod job;
+mod primitives;
+mod repo;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use tracing::instrument;
+
+use std::collections::HashMap;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    Jobs,
+    accounting_period::{AccountingPeriodGuard, error::AccountingPeriodGuardError},
+    manual_transaction::{ManualEntryInput, ManualTransaction, ManualTransactions},
+    primitives::{
+        CoreAccountingAction, CoreAccountingObject, ManualTransactionId, RecurringJournalEntryId,
+    },
+};
+
+pub use entity::RecurringJournalEntry;
+#[cfg(feature = "json-schema")]
+pub use entity::RecurringJournalEntryEvent;
+pub(super) use entity::*;
+use error::*;
+use job::*;
+pub use primitives::*;
+pub use repo::recurring_journal_entry_cursor::RecurringJournalEntriesByCreatedAtCursor;
+use repo::*;
+
+pub struct RecurringJournalEntries<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    authz: Perms,
+    jobs: Jobs,
+    manual_transactions: ManualTransactions<Perms, E>,
+    period_guard: AccountingPeriodGuard,
+    repo: RecurringJournalEntryRepo,
+}
+
+impl<Perms, E> Clone for RecurringJournalEntries<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            authz: self.authz.clone(),
+            jobs: self.jobs.clone(),
+            manual_transactions: self.manual_transactions.clone(),
+            period_guard: self.period_guard.clone(),
+            repo: self.repo.clone(),
+        }
+    }
+}
+
+impl<Perms, E> RecurringJournalEntries<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreAccountingAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreAccountingObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        manual_transactions: &ManualTransactions<Perms, E>,
+        jobs: &Jobs,
+    ) -> Self {
+        let recurring_journal_entries = Self {
+            authz: authz.clone(),
+            jobs: jobs.clone(),
+            manual_transactions: manual_transactions.clone(),
+            period_guard: AccountingPeriodGuard::new(pool),
+            repo: RecurringJournalEntryRepo::new(pool),
+        };
+        jobs.add_initializer(RecurringJournalEntryInit::new(&recurring_journal_entries));
+        recurring_journal_entries
+    }
+
+    /// Entries are resolved against the chart once, so every occurrence posts
+    /// to the same accounts.
+    #[instrument(
+        name = "core_accounting.recurring_journal_entry.create",
+        skip(self, entries),
+        err
+    )]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        chart_ref: &str,
+        reference: Option<String>,
+        description: String,
+        schedule: RecurrenceSchedule,
+        entries: Vec<ManualEntryInput>,
+    ) -> Result<RecurringJournalEntry, RecurringJournalEntryError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_recurring_journal_entries(),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_CREATE,
+            )
+            .await?;
+
+        if schedule.starts_on < Utc::now().date_naive() {
+            return Err(RecurringJournalEntryError::StartsInPast(schedule.starts_on));
+        }
+        if entries.is_empty() {
+            return Err(RecurringJournalEntryError::NoEntries);
+        }
+
+        let entries = self
+            .manual_transactions
+            .resolve_entries(sub, chart_ref, entries)
+            .await?;
+        let new_entry = NewRecurringJournalEntry::builder()
+            .id(RecurringJournalEntryId::new())
+            .reference(reference)
+            .description(description)
+            .schedule(schedule)
+            .entries(entries)
+            .audit_info(audit_info)
+            .build()
+            .expect("Couldn't build new recurring journal entry");
+
+        let mut db = self.repo.begin_op().await?;
+        let recurring_journal_entry = self.repo.create_in_op(&mut db, new_entry).await?;
+        self.spawn_occurrence_job_in_op(&mut db, &recurring_journal_entry)
+            .await?;
+        db.commit().await?;
+
+        Ok(recurring_journal_entry)
+    }
+
+    #[instrument(
+        name = "core_accounting.recurring_journal_entry.pause",
+        skip(self),
+        err
+    )]
+    pub async fn pause(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<RecurringJournalEntryId> + std::fmt::Debug,
+    ) -> Result<RecurringJournalEntry, RecurringJournalEntryError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::recurring_journal_entry(id),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_PAUSE,
+            )
+            .await?;
+
+        let mut recurring_journal_entry = self.repo.find_by_id(id).await?;
+        if recurring_journal_entry.pause(audit_info).did_execute() {
+            self.repo.update(&mut recurring_journal_entry).await?;
+        }
+
+        Ok(recurring_journal_entry)
+    }
+
+    #[instrument(
+        name = "core_accounting.recurring_journal_entry.resume",
+        skip(self),
+        err
+    )]
+    pub async fn resume(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<RecurringJournalEntryId> + std::fmt::Debug,
+    ) -> Result<RecurringJournalEntry, RecurringJournalEntryError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::recurring_journal_entry(id),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_RESUME,
+            )
+            .await?;
+
+        let mut recurring_journal_entry = self.repo.find_by_id(id).await?;
+        if recurring_journal_entry
+            .resume(Utc::now().date_naive(), audit_info)
+            .did_execute()
+        {
+            let mut db = self.repo.begin_op().await?;
+            self.repo
+                .update_in_op(&mut db, &mut recurring_journal_entry)
+                .await?;
+            self.spawn_occurrence_job_in_op(&mut db, &recurring_journal_entry)
+                .await?;
+            db.commit().await?;
+        }
+
+        Ok(recurring_journal_entry)
+    }
+
+    #[instrument(
+        name = "core_accounting.recurring_journal_entry.find_by_id",
+        skip(self),
+        err
+    )]
+    pub async fn find_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<RecurringJournalEntryId> + std::fmt::Debug,
+    ) -> Result<Option<RecurringJournalEntry>, RecurringJournalEntryError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::recurring_journal_entry(id),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_READ,
+            )
+            .await?;
+
+        match self.repo.find_by_id(id).await {
+            Ok(recurring_journal_entry) => Ok(Some(recurring_journal_entry)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[instrument(name = "core_accounting.recurring_journal_entry.list", skip(self), err)]
+    pub async fn list(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<RecurringJournalEntriesByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<
+            RecurringJournalEntry,
+            RecurringJournalEntriesByCreatedAtCursor,
+        >,
+        RecurringJournalEntryError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_recurring_journal_entries(),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_LIST,
+            )
+            .await?;
+
+        self.repo
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await
+    }
+
+    #[instrument(
+        name = "core_accounting.recurring_journal_entry.find_all",
+        skip(self),
+        err
+    )]
+    pub async fn find_all<T: From<RecurringJournalEntry>>(
+        &self,
+        ids: &[RecurringJournalEntryId],
+    ) -> Result<HashMap<RecurringJournalEntryId, T>, RecurringJournalEntryError> {
+        self.repo.find_all(ids).await
+    }
+
+    /// Submits the occurrence due on `effective` as a manual transaction, going
+    /// through the same approval as one entered by hand, and schedules the next
+    /// one. Paused entries and occurrences that are no longer due are skipped.
+    pub(crate) async fn post_occurrence(
+        &self,
+        id: RecurringJournalEntryId,
+        effective: NaiveDate,
+    ) -> Result<Option<ManualTransaction>, RecurringJournalEntryError> {
+        let mut recurring_journal_entry = self.repo.find_by_id(id).await?;
+        if !recurring_journal_entry.is_due_on(effective) {
+            return Ok(None);
+        }
+
+        let mut db = self.repo.begin_op().await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                &mut db,
+                CoreAccountingObject::recurring_journal_entry(id),
+                CoreAccountingAction::RECURRING_JOURNAL_ENTRY_POST_OCCURRENCE,
+            )
+            .await?;
+
+        match self
+            .period_guard
+            .ensure_open_for_manual_postings(effective)
+            .await
+        {
+            Ok(()) => (),
+            Err(e @ AccountingPeriodGuardError::PostingInLockedPeriod(..)) => {
+                recurring_journal_entry.record_skipped_occurrence(
+                    effective,
+                    e.to_string(),
+                    audit_info,
+                )?;
+                self.repo
+                    .update_in_op(&mut db, &mut recurring_journal_entry)
+                    .await?;
+                self.spawn_occurrence_job_in_op(&mut db, &recurring_journal_entry)
+                    .await?;
+                db.commit().await?;
+                return Ok(None);
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let manual_transaction_id = ManualTransactionId::new();
+        recurring_journal_entry.record_occurrence(
+            effective,
+            manual_transaction_id,
+            audit_info.clone(),
+        )?;
+        self.repo
+            .update_in_op(&mut db, &mut recurring_journal_entry)
+            .await?;
+        self.spawn_occurrence_job_in_op(&mut db, &recurring_journal_entry)
+            .await?;
+
+        let manual_transaction = self
+            .manual_transactions
+            .request_approval_in_op(
+                db,
+                manual_transaction_id,
+                Some(recurring_journal_entry.occurrence_reference(effective)),
+                recurring_journal_entry.description.clone(),
+                effective,
+                recurring_journal_entry.entries.clone(),
+                audit_info,
+            )
+            .await?;
+
+        Ok(Some(manual_transaction))
+    }
+
+    async fn spawn_occurrence_job_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        recurring_journal_entry: &RecurringJournalEntry,
+    ) -> Result<(), RecurringJournalEntryError> {
+        let effective = recurring_journal_entry.next_occurrence;
+        self.jobs
+            .create_and_spawn_at_in_op(
+                db,
+                ::job::JobId::new(),
+                RecurringJournalEntryJobConfig::<Perms, E> {
+                    recurring_journal_entry_id: recurring_journal_entry.id,
+                    effective,
+                    _phantom: std::marker::PhantomData,
+                },
+                Utc.from_utc_datetime(&effective.and_hms_opt(0, 0, 0).expect("valid time")),
+            )
+            .await?;
+        Ok(())
+    }
+}
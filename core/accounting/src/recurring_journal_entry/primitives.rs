@@ -0,0 +1,142 @@
+use chrono::{Datelike, Months, NaiveDate};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::ManualTransactionId;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum RecurrenceFrequency {
+    Monthly,
+    Quarterly,
+    EndOfMonth,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct RecurrenceSchedule {
+    pub frequency: RecurrenceFrequency,
+    pub starts_on: NaiveDate,
+}
+
+impl RecurrenceSchedule {
+    pub fn new(frequency: RecurrenceFrequency, starts_on: NaiveDate) -> Self {
+        Self {
+            frequency,
+            starts_on,
+        }
+    }
+
+    /// Occurrences are counted from `starts_on` rather than from the previous
+    /// occurrence, so a schedule starting on the 31st returns to the 31st after
+    /// passing through shorter months.
+    pub fn occurrence(&self, n: u32) -> NaiveDate {
+        match self.frequency {
+            RecurrenceFrequency::Monthly => add_months(self.starts_on, n),
+            RecurrenceFrequency::Quarterly => add_months(self.starts_on, 3 * n),
+            RecurrenceFrequency::EndOfMonth => {
+                let month_start = self
+                    .starts_on
+                    .with_day(1)
+                    .expect("first day of month always exists");
+                add_months(month_start, n + 1)
+                    .pred_opt()
+                    .expect("date before month start always exists")
+            }
+        }
+    }
+
+    pub fn first_occurrence(&self) -> NaiveDate {
+        self.occurrence(0)
+    }
+
+    pub fn first_occurrence_on_or_after(&self, date: NaiveDate) -> NaiveDate {
+        (0..)
+            .map(|n| self.occurrence(n))
+            .find(|occurrence| *occurrence >= date)
+            .expect("schedule always has a later occurrence")
+    }
+}
+
+fn add_months(date: NaiveDate, months: u32) -> NaiveDate {
+    date.checked_add_months(Months::new(months))
+        .expect("occurrence out of range")
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum RecurringJournalEntryStatus {
+    Active,
+    Paused,
+}
+
+/// An occurrence of a recurring journal entry, either posted as a manual
+/// transaction or skipped with the reason it could not be posted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurringJournalEntryOccurrence {
+    pub effective: NaiveDate,
+    pub manual_transaction_id: Option<ManualTransactionId>,
+    pub skip_reason: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn occurrences(frequency: RecurrenceFrequency, starts_on: &str, n: u32) -> Vec<NaiveDate> {
+        let schedule = RecurrenceSchedule::new(frequency, date(starts_on));
+        (0..n).map(|i| schedule.occurrence(i)).collect()
+    }
+
+    #[test]
+    fn monthly_keeps_day_of_month_without_drifting() {
+        assert_eq!(
+            occurrences(RecurrenceFrequency::Monthly, "2025-01-31", 4),
+            vec![
+                date("2025-01-31"),
+                date("2025-02-28"),
+                date("2025-03-31"),
+                date("2025-04-30"),
+            ]
+        );
+    }
+
+    #[test]
+    fn quarterly_steps_three_months() {
+        assert_eq!(
+            occurrences(RecurrenceFrequency::Quarterly, "2025-01-15", 3),
+            vec![date("2025-01-15"), date("2025-04-15"), date("2025-07-15")]
+        );
+    }
+
+    #[test]
+    fn end_of_month_starts_at_end_of_start_month() {
+        assert_eq!(
+            occurrences(RecurrenceFrequency::EndOfMonth, "2024-01-10", 3),
+            vec![date("2024-01-31"), date("2024-02-29"), date("2024-03-31")]
+        );
+    }
+
+    #[test]
+    fn first_occurrence_on_or_after_skips_past_occurrences() {
+        let schedule = RecurrenceSchedule::new(RecurrenceFrequency::Monthly, date("2025-01-05"));
+        assert_eq!(
+            schedule.first_occurrence_on_or_after(date("2025-03-05")),
+            date("2025-03-05")
+        );
+        assert_eq!(
+            schedule.first_occurrence_on_or_after(date("2025-03-06")),
+            date("2025-04-05")
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::RecurringJournalEntryId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "RecurringJournalEntry",
+    err = "RecurringJournalEntryError",
+    columns(reference(ty = "String", create(accessor = "reference()"))),
+    tbl_prefix = "core"
+)]
+pub struct RecurringJournalEntryRepo {
+    pool: PgPool,
+}
+
+impl Clone for RecurringJournalEntryRepo {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl RecurringJournalEntryRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
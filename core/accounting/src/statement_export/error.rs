@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StatementExportError {
+    #[error("StatementExportError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("StatementExportError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("StatementExportError - JobError: {0}")]
+    JobError(#[from] job::error::JobError),
+    #[error("StatementExportError - DocumentStorageError: {0}")]
+    DocumentStorageError(#[from] document_storage::error::DocumentStorageError),
+    #[error("StatementExportError - CalaAccountSetError: {0}")]
+    CalaAccountSet(#[from] cala_ledger::account_set::error::AccountSetError),
+    #[error("StatementExportError - LedgerAccountError: {0}")]
+    LedgerAccountError(#[from] crate::ledger_account::error::LedgerAccountError),
+    #[error("StatementExportError - TrialBalanceError: {0}")]
+    TrialBalanceError(#[from] crate::trial_balance::error::TrialBalanceError),
+    #[error("StatementExportError - BalanceSheetError: {0}")]
+    BalanceSheetError(#[from] crate::balance_sheet::error::BalanceSheetError),
+    #[error("StatementExportError - ProfitAndLossStatementError: {0}")]
+    ProfitAndLossStatementError(#[from] crate::profit_and_loss::error::ProfitAndLossStatementError),
+    #[error("StatementExportError - RenderingError: {0}")]
+    RenderingError(#[from] rendering::RenderingError),
+    #[error("StatementExportError - CsvError: {0}")]
+    CsvError(String),
+    #[error("StatementExportError - InvalidDateRange: {0} is after {1}")]
+    InvalidDateRange(chrono::NaiveDate, chrono::NaiveDate),
+}
@@ -0,0 +1,398 @@
+use chrono::NaiveDate;
+use csv::Writer;
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use audit::{AuditSvc, SystemSubject};
+use authz::PermissionCheck;
+use rendering::{Renderer, XlsxCell, XlsxGenerator};
+
+use crate::{
+    CoreAccountingAction, CoreAccountingObject,
+    balance_sheet::BalanceSheets,
+    ledger_account::{LedgerAccount, LedgerAccounts},
+    primitives::{BalanceRange, CalaAccountSetId, LedgerAccountId},
+    profit_and_loss::ProfitAndLossStatements,
+    trial_balance::TrialBalances,
+};
+
+use super::{
+    error::StatementExportError,
+    primitives::{StatementExportFormat, StatementType},
+};
+
+const STATEMENT_TEMPLATE: &str = include_str!("templates/statement.md.hbs");
+
+#[derive(Serialize)]
+pub(super) struct StatementReport {
+    title: &'static str,
+    name: String,
+    from: NaiveDate,
+    until: NaiveDate,
+    sections: Vec<StatementSection>,
+}
+
+#[derive(Serialize)]
+struct StatementSection {
+    currency: &'static str,
+    rows: Vec<StatementRow>,
+    total: StatementRow,
+}
+
+#[derive(Serialize)]
+struct StatementRow {
+    depth: usize,
+    code: String,
+    name: String,
+    opening: Decimal,
+    debits: Decimal,
+    credits: Decimal,
+    closing: Decimal,
+}
+
+impl StatementRow {
+    fn new(depth: usize, code: String, name: String, range: &BalanceRange) -> Self {
+        Self {
+            depth,
+            code,
+            name,
+            opening: range.open.as_ref().map(|b| b.settled()).unwrap_or_default(),
+            debits: range
+                .period_activity
+                .as_ref()
+                .map(|b| b.details.settled.dr_balance)
+                .unwrap_or_default(),
+            credits: range
+                .period_activity
+                .as_ref()
+                .map(|b| b.details.settled.cr_balance)
+                .unwrap_or_default(),
+            closing: range
+                .close
+                .as_ref()
+                .map(|b| b.settled())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+struct StatementTotals<'a> {
+    usd: Option<&'a BalanceRange>,
+    btc: Option<&'a BalanceRange>,
+}
+
+impl StatementReport {
+    fn new(
+        statement_type: StatementType,
+        name: String,
+        from: NaiveDate,
+        until: NaiveDate,
+        totals: StatementTotals<'_>,
+        accounts: &[(usize, LedgerAccount)],
+    ) -> Self {
+        let mut sections = Vec::new();
+        for (currency, total, range_of) in [
+            (
+                "USD",
+                totals.usd,
+                (|account: &LedgerAccount| account.usd_balance_range.clone())
+                    as fn(&LedgerAccount) -> Option<BalanceRange>,
+            ),
+            ("BTC", totals.btc, |account: &LedgerAccount| {
+                account.btc_balance_range.clone()
+            }),
+        ] {
+            let Some(total) = total else {
+                continue;
+            };
+            let rows = accounts
+                .iter()
+                .filter_map(|(depth, account)| {
+                    range_of(account).map(|range| {
+                        StatementRow::new(
+                            *depth,
+                            account
+                                .code
+                                .as_ref()
+                                .map(|code| code.to_string())
+                                .unwrap_or_default(),
+                            account.name.clone(),
+                            &range,
+                        )
+                    })
+                })
+                .collect();
+            sections.push(StatementSection {
+                currency,
+                rows,
+                total: StatementRow::new(0, String::new(), "Total".to_string(), total),
+            });
+        }
+
+        Self {
+            title: statement_type.title(),
+            name,
+            from,
+            until,
+            sections,
+        }
+    }
+
+    fn file_rows(&self) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        for section in self.sections.iter() {
+            for row in section.rows.iter().chain(std::iter::once(&section.total)) {
+                rows.push(vec![
+                    section.currency.to_string(),
+                    row.code.clone(),
+                    format!("{}{}", "  ".repeat(row.depth), row.name),
+                    row.opening.to_string(),
+                    row.debits.to_string(),
+                    row.credits.to_string(),
+                    row.closing.to_string(),
+                ]);
+            }
+        }
+        rows
+    }
+}
+
+const COLUMNS: [&str; 7] = [
+    "Currency",
+    "Code",
+    "Account",
+    "Opening Balance",
+    "Debits",
+    "Credits",
+    "Closing Balance",
+];
+const FIRST_AMOUNT_COLUMN: usize = 3;
+
+#[derive(Clone, Default)]
+pub(super) struct StatementRenderer {
+    renderer: Renderer,
+    xlsx: XlsxGenerator,
+}
+
+impl StatementRenderer {
+    pub fn render(
+        &self,
+        report: &StatementReport,
+        format: StatementExportFormat,
+    ) -> Result<Vec<u8>, StatementExportError> {
+        match format {
+            StatementExportFormat::Csv => Self::render_csv(report),
+            StatementExportFormat::Xlsx => self.render_xlsx(report),
+            StatementExportFormat::Pdf => self.render_pdf(report),
+        }
+    }
+
+    fn render_csv(report: &StatementReport) -> Result<Vec<u8>, StatementExportError> {
+        let mut wtr = Writer::from_writer(vec![]);
+        wtr.write_record(COLUMNS)
+            .map_err(|e| StatementExportError::CsvError(e.to_string()))?;
+        for row in report.file_rows() {
+            wtr.write_record(&row)
+                .map_err(|e| StatementExportError::CsvError(e.to_string()))?;
+        }
+        wtr.into_inner()
+            .map_err(|e| StatementExportError::CsvError(e.to_string()))
+    }
+
+    fn render_xlsx(&self, report: &StatementReport) -> Result<Vec<u8>, StatementExportError> {
+        let mut rows = vec![
+            vec![XlsxCell::Text(format!(
+                "{} - {}",
+                report.title, report.name
+            ))],
+            vec![XlsxCell::Text(format!(
+                "{} to {}",
+                report.from, report.until
+            ))],
+            vec![],
+            COLUMNS
+                .iter()
+                .map(|column| XlsxCell::Text(column.to_string()))
+                .collect(),
+        ];
+        for row in report.file_rows() {
+            rows.push(
+                row.into_iter()
+                    .enumerate()
+                    .map(|(idx, value)| {
+                        if idx >= FIRST_AMOUNT_COLUMN {
+                            XlsxCell::Number(value)
+                        } else if value.is_empty() {
+                            XlsxCell::Empty
+                        } else {
+                            XlsxCell::Text(value)
+                        }
+                    })
+                    .collect(),
+            );
+        }
+        Ok(self.xlsx.generate_xlsx(report.title, &rows)?)
+    }
+
+    fn render_pdf(&self, report: &StatementReport) -> Result<Vec<u8>, StatementExportError> {
+        let markdown = self
+            .renderer
+            .render_template_to_markdown(STATEMENT_TEMPLATE, report)?;
+        Ok(self.renderer.markdown_to_pdf(&markdown)?)
+    }
+}
+
+pub(super) struct GenerateStatementExport<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    trial_balances: TrialBalances<Perms>,
+    balance_sheets: BalanceSheets<Perms>,
+    profit_and_loss: ProfitAndLossStatements<Perms>,
+    ledger_accounts: LedgerAccounts<Perms>,
+}
+
+impl<Perms> GenerateStatementExport<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(
+        trial_balances: &TrialBalances<Perms>,
+        balance_sheets: &BalanceSheets<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        ledger_accounts: &LedgerAccounts<Perms>,
+    ) -> Self {
+        Self {
+            trial_balances: trial_balances.clone(),
+            balance_sheets: balance_sheets.clone(),
+            profit_and_loss: profit_and_loss.clone(),
+            ledger_accounts: ledger_accounts.clone(),
+        }
+    }
+
+    pub async fn generate_report(
+        &self,
+        statement_type: StatementType,
+        name: String,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<StatementReport, StatementExportError> {
+        let sub =
+            <<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject as SystemSubject>::system();
+
+        let report = match statement_type {
+            StatementType::TrialBalance => {
+                let trial_balance = self
+                    .trial_balances
+                    .trial_balance(&sub, name.clone(), from, until)
+                    .await?;
+                let accounts = self.accounts(trial_balance.id, from, until, 0).await?;
+                StatementReport::new(
+                    statement_type,
+                    name,
+                    from,
+                    until,
+                    StatementTotals {
+                        usd: trial_balance.usd_balance_range.as_ref(),
+                        btc: trial_balance.btc_balance_range.as_ref(),
+                    },
+                    &accounts,
+                )
+            }
+            StatementType::BalanceSheet => {
+                let balance_sheet = self
+                    .balance_sheets
+                    .balance_sheet(&sub, name.clone(), from, Some(until))
+                    .await?;
+                let accounts = self
+                    .categorized_accounts(
+                        balance_sheet.id,
+                        &balance_sheet.category_ids,
+                        from,
+                        until,
+                    )
+                    .await?;
+                StatementReport::new(
+                    statement_type,
+                    name,
+                    from,
+                    until,
+                    StatementTotals {
+                        usd: balance_sheet.usd_balance_range.as_ref(),
+                        btc: balance_sheet.btc_balance_range.as_ref(),
+                    },
+                    &accounts,
+                )
+            }
+            StatementType::ProfitAndLoss => {
+                let pl_statement = self
+                    .profit_and_loss
+                    .pl_statement(&sub, name.clone(), from, Some(until))
+                    .await?;
+                let accounts = self
+                    .categorized_accounts(pl_statement.id, &pl_statement.category_ids, from, until)
+                    .await?;
+                StatementReport::new(
+                    statement_type,
+                    name,
+                    from,
+                    until,
+                    StatementTotals {
+                        usd: pl_statement.usd_balance_range.as_ref(),
+                        btc: pl_statement.btc_balance_range.as_ref(),
+                    },
+                    &accounts,
+                )
+            }
+        };
+
+        Ok(report)
+    }
+
+    /// Categories are listed in statement order, each followed by its direct children.
+    async fn categorized_accounts(
+        &self,
+        statement_id: LedgerAccountId,
+        category_ids: &[LedgerAccountId],
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<(usize, LedgerAccount)>, StatementExportError> {
+        let mut categories = self
+            .ledger_accounts
+            .complete_children(CalaAccountSetId::from(statement_id), from, until, false)
+            .await?;
+        let mut accounts = Vec::new();
+        for category_id in category_ids {
+            let Some(idx) = categories.iter().position(|c| c.id == *category_id) else {
+                continue;
+            };
+            let category = categories.swap_remove(idx);
+            let children = self
+                .accounts(CalaAccountSetId::from(category.id), from, until, 1)
+                .await?;
+            accounts.push((0, category));
+            accounts.extend(children);
+        }
+        Ok(accounts)
+    }
+
+    async fn accounts(
+        &self,
+        id: CalaAccountSetId,
+        from: NaiveDate,
+        until: NaiveDate,
+        depth: usize,
+    ) -> Result<Vec<(usize, LedgerAccount)>, StatementExportError> {
+        Ok(self
+            .ledger_accounts
+            .complete_children(id, from, until, true)
+            .await?
+            .into_iter()
+            .map(|account| (depth, account))
+            .collect())
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use document_storage::{DocumentId, DocumentStorage};
+use job::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    balance_sheet::BalanceSheets, ledger_account::LedgerAccounts,
+    profit_and_loss::ProfitAndLossStatements, trial_balance::TrialBalances,
+};
+
+use super::{
+    CoreAccountingAction, CoreAccountingObject,
+    generate::{GenerateStatementExport, StatementRenderer},
+    primitives::{StatementExportFormat, StatementType},
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GenerateStatementExportConfig<Perms> {
+    pub document_id: DocumentId,
+    pub statement_type: StatementType,
+    pub statement_name: String,
+    pub from: NaiveDate,
+    pub until: NaiveDate,
+    pub format: StatementExportFormat,
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+
+impl<Perms> JobConfig for GenerateStatementExportConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    type Initializer = GenerateStatementExportInit<Perms>;
+}
+
+pub struct GenerateStatementExportInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    document_storage: DocumentStorage,
+    trial_balances: TrialBalances<Perms>,
+    balance_sheets: BalanceSheets<Perms>,
+    profit_and_loss: ProfitAndLossStatements<Perms>,
+    ledger_accounts: LedgerAccounts<Perms>,
+    renderer: StatementRenderer,
+}
+
+impl<Perms> GenerateStatementExportInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    pub fn new(
+        document_storage: &DocumentStorage,
+        trial_balances: &TrialBalances<Perms>,
+        balance_sheets: &BalanceSheets<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        ledger_accounts: &LedgerAccounts<Perms>,
+    ) -> Self {
+        Self {
+            document_storage: document_storage.clone(),
+            trial_balances: trial_balances.clone(),
+            balance_sheets: balance_sheets.clone(),
+            profit_and_loss: profit_and_loss.clone(),
+            ledger_accounts: ledger_accounts.clone(),
+            renderer: StatementRenderer::default(),
+        }
+    }
+}
+
+pub const GENERATE_STATEMENT_EXPORT_JOB: JobType = JobType::new("generate-statement-export");
+
+impl<Perms> JobInitializer for GenerateStatementExportInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        GENERATE_STATEMENT_EXPORT_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(GenerateStatementExportJobRunner {
+            config: job.config()?,
+            document_storage: self.document_storage.clone(),
+            generator: GenerateStatementExport::new(
+                &self.trial_balances,
+                &self.balance_sheets,
+                &self.profit_and_loss,
+                &self.ledger_accounts,
+            ),
+            renderer: self.renderer.clone(),
+        }))
+    }
+}
+
+pub struct GenerateStatementExportJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    config: GenerateStatementExportConfig<Perms>,
+    document_storage: DocumentStorage,
+    generator: GenerateStatementExport<Perms>,
+    renderer: StatementRenderer,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for GenerateStatementExportJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    #[tracing::instrument(
+        name = "core_accounting.job.generate_statement_export",
+        skip(self, _current_job),
+        err
+    )]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let report = self
+            .generator
+            .generate_report(
+                self.config.statement_type,
+                self.config.statement_name.clone(),
+                self.config.from,
+                self.config.until,
+            )
+            .await?;
+        let content = self.renderer.render(&report, self.config.format)?;
+
+        let mut document = self
+            .document_storage
+            .find_by_id(self.config.document_id)
+            .await?;
+        self.document_storage.upload(content, &mut document).await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
@@ -0,0 +1,191 @@
+pub mod error;
+mod generate;
+mod job;
+mod primitives;
+
+use chrono::NaiveDate;
+use tracing::instrument;
+
+use ::job::JobId;
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use cala_ledger::CalaLedger;
+use document_storage::{
+    Document, DocumentId, DocumentStorage, DocumentType, GeneratedDocumentDownloadLink, ReferenceId,
+};
+
+use crate::{
+    Jobs, balance_sheet::BalanceSheets, ledger_account::LedgerAccounts,
+    profit_and_loss::ProfitAndLossStatements, trial_balance::TrialBalances,
+};
+
+use super::{CoreAccountingAction, CoreAccountingObject, primitives::LedgerAccountId};
+
+use error::*;
+use job::*;
+pub use primitives::*;
+
+pub const STATEMENT_EXPORT: DocumentType = DocumentType::new("accounting_statement_export");
+
+#[derive(Clone)]
+pub struct StatementExports<Perms>
+where
+    Perms: PermissionCheck,
+{
+    authz: Perms,
+    jobs: Jobs,
+    document_storage: DocumentStorage,
+    cala: CalaLedger,
+}
+
+impl<Perms> StatementExports<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreAccountingAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreAccountingObject>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        authz: &Perms,
+        jobs: &Jobs,
+        cala: &CalaLedger,
+        document_storage: DocumentStorage,
+        trial_balances: &TrialBalances<Perms>,
+        balance_sheets: &BalanceSheets<Perms>,
+        profit_and_loss: &ProfitAndLossStatements<Perms>,
+        ledger_accounts: &LedgerAccounts<Perms>,
+    ) -> Self {
+        jobs.add_initializer(GenerateStatementExportInit::new(
+            &document_storage,
+            trial_balances,
+            balance_sheets,
+            profit_and_loss,
+            ledger_accounts,
+        ));
+
+        Self {
+            authz: authz.clone(),
+            jobs: jobs.clone(),
+            document_storage,
+            cala: cala.clone(),
+        }
+    }
+
+    #[instrument(name = "core_accounting.statement_export.create", skip(self), err)]
+    pub async fn create(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        statement_type: StatementType,
+        statement_name: String,
+        from: NaiveDate,
+        until: NaiveDate,
+        format: StatementExportFormat,
+    ) -> Result<Document, StatementExportError> {
+        if from > until {
+            return Err(StatementExportError::InvalidDateRange(from, until));
+        }
+
+        let statement_id = self.statement_id(&statement_name).await?;
+
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::statement_export(statement_id),
+                CoreAccountingAction::STATEMENT_EXPORT_CREATE,
+            )
+            .await?;
+
+        let mut db = self.document_storage.begin_op().await?;
+        let document = self
+            .document_storage
+            .create_in_op(
+                audit_info.clone(),
+                format!("{statement_type}-{from}-{until}.{}", format.extension()),
+                format.content_type(),
+                ReferenceId::from(uuid::Uuid::from(statement_id)),
+                STATEMENT_EXPORT,
+                &mut db,
+            )
+            .await?;
+
+        self.jobs
+            .create_and_spawn_in_op::<GenerateStatementExportConfig<Perms>>(
+                &mut db,
+                JobId::from(uuid::Uuid::from(document.id)),
+                GenerateStatementExportConfig {
+                    document_id: document.id,
+                    statement_type,
+                    statement_name,
+                    from,
+                    until,
+                    format,
+                    _phantom: std::marker::PhantomData,
+                },
+            )
+            .await?;
+        db.commit().await?;
+        Ok(document)
+    }
+
+    #[instrument(name = "core_accounting.statement_export.list", skip(self), err)]
+    pub async fn list_for_statement(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        statement_name: String,
+    ) -> Result<Vec<Document>, StatementExportError> {
+        let statement_id = self.statement_id(&statement_name).await?;
+
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::statement_export(statement_id),
+                CoreAccountingAction::STATEMENT_EXPORT_LIST,
+            )
+            .await?;
+
+        Ok(self
+            .document_storage
+            .list_for_reference_id(ReferenceId::from(uuid::Uuid::from(statement_id)))
+            .await?)
+    }
+
+    #[instrument(
+        name = "core_accounting.statement_export.generate_download_link",
+        skip(self),
+        err
+    )]
+    pub async fn generate_download_link(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        document_id: DocumentId,
+    ) -> Result<GeneratedDocumentDownloadLink, StatementExportError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreAccountingObject::all_statement_exports(),
+                CoreAccountingAction::STATEMENT_EXPORT_GENERATE_DOWNLOAD_LINK,
+            )
+            .await?;
+
+        Ok(self
+            .document_storage
+            .generate_download_link(audit_info, document_id)
+            .await?)
+    }
+
+    /// Exports are filed under the id of the statement they were generated from.
+    async fn statement_id(
+        &self,
+        statement_name: &str,
+    ) -> Result<LedgerAccountId, StatementExportError> {
+        Ok(self
+            .cala
+            .account_sets()
+            .find_by_external_id(statement_name.to_string())
+            .await?
+            .id
+            .into())
+    }
+}
@@ -21,6 +21,7 @@ pub async fn init_journal(cala: &CalaLedger) -> anyhow::Result<cala_ledger::Jour
 
 pub mod action {
     use core_accounting::CoreAccountingAction;
+    use governance::GovernanceAction;
 
     #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct DummyAction;
@@ -31,6 +32,12 @@ pub mod action {
         }
     }
 
+    impl From<GovernanceAction> for DummyAction {
+        fn from(_: GovernanceAction) -> Self {
+            Self
+        }
+    }
+
     impl std::fmt::Display for DummyAction {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             write!(f, "dummy")?;
@@ -49,6 +56,7 @@ pub mod action {
 
 pub mod object {
     use core_accounting::CoreAccountingObject;
+    use governance::GovernanceObject;
 
     #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct DummyObject;
@@ -59,6 +67,12 @@ pub mod object {
         }
     }
 
+    impl From<GovernanceObject> for DummyObject {
+        fn from(_: GovernanceObject) -> Self {
+            Self
+        }
+    }
+
     impl std::fmt::Display for DummyObject {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             write!(f, "Dummy")?;
@@ -74,3 +88,29 @@ pub mod object {
         }
     }
 }
+
+pub mod event {
+    use serde::{Deserialize, Serialize};
+
+    use governance::GovernanceEvent;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(tag = "module")]
+    pub enum DummyEvent {
+        Governance(GovernanceEvent),
+    }
+
+    impl outbox::OutboxEventMarker<GovernanceEvent> for DummyEvent {
+        fn as_event(&self) -> Option<&GovernanceEvent> {
+            match self {
+                Self::Governance(event) => Some(event),
+            }
+        }
+    }
+
+    impl From<GovernanceEvent> for DummyEvent {
+        fn from(event: GovernanceEvent) -> Self {
+            Self::Governance(event)
+        }
+    }
+}
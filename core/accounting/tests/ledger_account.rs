@@ -9,7 +9,7 @@ use cala_ledger::{
 use cloud_storage::{Storage, config::StorageConfig};
 use core_accounting::CoreAccounting;
 use document_storage::DocumentStorage;
-use helpers::{action, object};
+use helpers::{action, event, object};
 use job::{Jobs, JobsConfig};
 
 #[tokio::test]
@@ -26,7 +26,10 @@ async fn ledger_account_ancestors() -> anyhow::Result<()> {
     let document_storage = DocumentStorage::new(&pool, &storage);
     let jobs = Jobs::new(&pool, JobsConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, document_storage, &jobs);
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let accounting = CoreAccounting::init(&pool, &authz, &outbox, &governance, &cala, journal_id, document_storage, &jobs).await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting.chart_of_accounts().create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone()).await?;
     let import = r#"
@@ -91,7 +94,10 @@ async fn ledger_account_children() -> anyhow::Result<()> {
     let document_storage = DocumentStorage::new(&pool, &storage);
     let jobs = Jobs::new(&pool, JobsConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, document_storage, &jobs);
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let accounting = CoreAccounting::init(&pool, &authz, &outbox, &governance, &cala, journal_id, document_storage, &jobs).await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting.chart_of_accounts().create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone()).await?;
     let import = r#"
@@ -145,10 +151,10 @@ async fn internal_account_contains_coa_account() -> anyhow::Result<()> {
     let document_storage = DocumentStorage::new(&pool, &storage);
     let jobs = Jobs::new(&pool, JobsConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id,
-        document_storage,
-        &jobs,
-    );
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let accounting = CoreAccounting::init(&pool, &authz, &outbox, &governance, &cala, journal_id, document_storage, &jobs).await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting.chart_of_accounts().create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone()).await?;
     let import = r#"
@@ -180,3 +186,52 @@ async fn internal_account_contains_coa_account() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[rustfmt::skip]
+async fn chart_node_changes_update_ledger_accounts() -> anyhow::Result<()> {
+    use rand::Rng;
+    let pool = helpers::init_pool().await?;
+    let cala_config = CalaLedgerConfig::builder().pool(pool.clone()).exec_migrations(false).build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let journal_id = helpers::init_journal(&cala).await?;
+    let storage = Storage::new(&StorageConfig::default());
+    let document_storage = DocumentStorage::new(&pool, &storage);
+    let jobs = Jobs::new(&pool, JobsConfig::default());
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let accounting = CoreAccounting::init(&pool, &authz, &outbox, &governance, &cala, journal_id, document_storage, &jobs).await?;
+    let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
+    let chart = accounting.chart_of_accounts().create_chart(&DummySubject, "Test chart".to_string(), chart_ref.clone()).await?;
+    let import = r#"
+        1,,Root
+        11,,Child
+        11,1,Grandchild
+        "#;
+    let chart_id = chart.id;
+    accounting.chart_of_accounts().import_from_csv(&DummySubject, chart_id, import).await?;
+
+    accounting.chart_of_accounts().rename_node(&DummySubject, chart_id, "11.1".parse()?, "Renamed".parse()?).await?;
+    accounting.chart_of_accounts().move_node(&DummySubject, chart_id, "11.1".parse()?, "1".parse()?).await?;
+
+    let root = accounting.find_ledger_account_by_code(&DummySubject, &chart_ref, "1".to_string()).await?.unwrap();
+    let child = accounting.find_ledger_account_by_code(&DummySubject, &chart_ref, "11".to_string()).await?.unwrap();
+    let grandchild = accounting.find_ledger_account_by_code(&DummySubject, &chart_ref, "11.1".to_string()).await?.unwrap();
+    assert_eq!(grandchild.name, "Renamed");
+    assert_eq!(grandchild.ancestor_ids, vec![root.id]);
+    assert_eq!(child.children_ids, vec![]);
+    assert!(root.children_ids.contains(&grandchild.id));
+
+    let chart = accounting.chart_of_accounts().deactivate_node(&DummySubject, chart_id, "11.1".parse()?).await?;
+    assert!(chart.is_deactivated(&"11.1".parse()?));
+    let res = accounting
+        .chart_of_accounts()
+        .manual_transaction_account_id_for_account_id_or_code(&DummySubject, &chart_ref, "11.1".parse()?)
+        .await;
+    assert!(matches!(res, Err(core_accounting::chart_of_accounts::error::ChartOfAccountsError::AccountDeactivated(_))));
+
+    Ok(())
+}
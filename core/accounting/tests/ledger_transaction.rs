@@ -7,7 +7,7 @@ use job::{Jobs, JobsConfig};
 
 use cala_ledger::{CalaLedger, CalaLedgerConfig, Currency, DebitOrCredit};
 use core_accounting::{AccountIdOrCode, CoreAccounting, ManualEntryInput};
-use helpers::{action, object};
+use helpers::{action, event, object};
 use rust_decimal_macros::dec;
 
 #[tokio::test]
@@ -46,13 +46,13 @@ async fn ledger_transactions_by_template_code() -> anyhow::Result<()> {
     let manual_tx = accounting.execute_manual_transaction(&DummySubject, &chart_ref, None, "Test transaction 1".to_string(), None, entries).await?;
 
     let template_txs = accounting.ledger_transactions().list_for_template_code(&DummySubject, "MANUAL_TRANSACTION_2", Default::default()).await?.entities;
-    assert!(template_txs.iter().any(|tx| tx.id == manual_tx.id));
+    assert!(template_txs.iter().any(|tx| tx.id == manual_tx.ledger_transaction_id));
 
     Ok(())
 }
 
 async fn prepare_test() -> anyhow::Result<(
-    CoreAccounting<DummyPerms<action::DummyAction, object::DummyObject>>,
+    CoreAccounting<DummyPerms<action::DummyAction, object::DummyObject>, event::DummyEvent>,
     String,
 )> {
     use rand::Rng;
@@ -69,7 +69,20 @@ async fn prepare_test() -> anyhow::Result<(
     let document_storage = DocumentStorage::new(&pool, &storage);
     let jobs = Jobs::new(&pool, JobsConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, document_storage, &jobs);
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let accounting = CoreAccounting::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &cala,
+        journal_id,
+        document_storage,
+        &jobs,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
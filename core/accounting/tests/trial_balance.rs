@@ -8,7 +8,7 @@ use document_storage::DocumentStorage;
 use job::{Jobs, JobsConfig};
 
 use core_accounting::*;
-use helpers::{action, object};
+use helpers::{action, event, object};
 
 #[tokio::test]
 async fn add_chart_to_trial_balance() -> anyhow::Result<()> {
@@ -27,7 +27,20 @@ async fn add_chart_to_trial_balance() -> anyhow::Result<()> {
     let document_storage = DocumentStorage::new(&pool, &storage);
     let jobs = Jobs::new(&pool, JobsConfig::default());
 
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, document_storage, &jobs);
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let accounting = CoreAccounting::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &cala,
+        journal_id,
+        document_storage,
+        &jobs,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
@@ -23,6 +23,24 @@ pub struct ChartOfAccountsIntegrationConfig {
     pub chart_of_account_in_liquidation_parent_code: AccountCode,
     pub chart_of_account_interest_income_parent_code: AccountCode,
     pub chart_of_account_fee_income_parent_code: AccountCode,
+    #[builder(default)]
+    #[serde(default)]
+    pub chart_of_account_unapplied_funds_parent_code: Option<AccountCode>,
+    #[builder(default)]
+    #[serde(default)]
+    pub chart_of_account_penalty_receivable_parent_code: Option<AccountCode>,
+    #[builder(default)]
+    #[serde(default)]
+    pub chart_of_account_penalty_income_parent_code: Option<AccountCode>,
+    #[builder(default)]
+    #[serde(default)]
+    pub chart_of_account_collateral_in_liquidation_parent_code: Option<AccountCode>,
+    #[builder(default)]
+    #[serde(default)]
+    pub chart_of_account_liquidation_proceeds_parent_code: Option<AccountCode>,
+    #[builder(default)]
+    #[serde(default)]
+    pub chart_of_account_liquidation_proceeds_omnibus_parent_code: Option<AccountCode>,
 
     pub chart_of_account_short_term_individual_disbursed_receivable_parent_code: AccountCode,
     pub chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: AccountCode,
@@ -155,6 +173,36 @@ where
             chart.account_set_id_from_code(&config.chart_of_account_interest_income_parent_code)?;
         let fee_income_parent_account_set_id =
             chart.account_set_id_from_code(&config.chart_of_account_fee_income_parent_code)?;
+        let unapplied_funds_parent_account_set_id = config
+            .chart_of_account_unapplied_funds_parent_code
+            .as_ref()
+            .map(|code| chart.account_set_id_from_code(code))
+            .transpose()?;
+        let penalty_receivable_parent_account_set_id = config
+            .chart_of_account_penalty_receivable_parent_code
+            .as_ref()
+            .map(|code| chart.account_set_id_from_code(code))
+            .transpose()?;
+        let penalty_income_parent_account_set_id = config
+            .chart_of_account_penalty_income_parent_code
+            .as_ref()
+            .map(|code| chart.account_set_id_from_code(code))
+            .transpose()?;
+        let collateral_in_liquidation_parent_account_set_id = config
+            .chart_of_account_collateral_in_liquidation_parent_code
+            .as_ref()
+            .map(|code| chart.account_set_id_from_code(code))
+            .transpose()?;
+        let liquidation_proceeds_parent_account_set_id = config
+            .chart_of_account_liquidation_proceeds_parent_code
+            .as_ref()
+            .map(|code| chart.account_set_id_from_code(code))
+            .transpose()?;
+        let liquidation_proceeds_omnibus_parent_account_set_id = config
+            .chart_of_account_liquidation_proceeds_omnibus_parent_code
+            .as_ref()
+            .map(|code| chart.account_set_id_from_code(code))
+            .transpose()?;
 
         let short_term_individual_disbursed_receivable_parent_account_set_id = chart
             .account_set_id_from_code(
@@ -340,6 +388,12 @@ where
             in_liquidation_parent_account_set_id,
             interest_income_parent_account_set_id,
             fee_income_parent_account_set_id,
+            unapplied_funds_parent_account_set_id,
+            penalty_receivable_parent_account_set_id,
+            penalty_income_parent_account_set_id,
+            collateral_in_liquidation_parent_account_set_id,
+            liquidation_proceeds_parent_account_set_id,
+            liquidation_proceeds_omnibus_parent_account_set_id,
 
             short_term_disbursed_integration_meta: ShortTermDisbursedIntegrationMeta {
                 short_term_individual_disbursed_receivable_parent_account_set_id,
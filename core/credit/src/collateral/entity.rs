@@ -1,19 +1,21 @@
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
+use rust_decimal::Decimal;
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::BTreeMap};
 
 use audit::AuditInfo;
 use es_entity::*;
 
 use crate::primitives::{
-    CalaAccountId, CollateralAction, CollateralId, CreditFacilityId, CustodyWalletId, LedgerTxId,
+    CalaAccountId, CollateralAction, CollateralAsset, CollateralAssetPositionUpdate, CollateralId,
+    CollateralReleaseId, CreditFacilityId, CustodyWalletId, LedgerTxId, LiquidationProcessId,
     Satoshis,
 };
 
-use super::CollateralUpdate;
+use super::{CollateralUpdate, error::CollateralError};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -39,12 +41,45 @@ pub enum CollateralEvent {
         abs_diff: Satoshis,
         action: CollateralAction,
     },
+    UpdatedViaLiquidation {
+        ledger_tx_id: LedgerTxId,
+        collateral_amount: Satoshis,
+        abs_diff: Satoshis,
+        action: CollateralAction,
+        liquidation_process_id: LiquidationProcessId,
+        audit_info: AuditInfo,
+    },
+    UpdatedViaRollover {
+        ledger_tx_id: LedgerTxId,
+        collateral_amount: Satoshis,
+        abs_diff: Satoshis,
+        action: CollateralAction,
+        counterpart_credit_facility_id: CreditFacilityId,
+        audit_info: AuditInfo,
+    },
+    UpdatedViaRelease {
+        ledger_tx_id: LedgerTxId,
+        collateral_amount: Satoshis,
+        abs_diff: Satoshis,
+        action: CollateralAction,
+        collateral_release_id: CollateralReleaseId,
+        audit_info: AuditInfo,
+    },
+    AssetPositionUpdated {
+        ledger_tx_id: LedgerTxId,
+        asset: CollateralAsset,
+        quantity: Decimal,
+        abs_diff: Decimal,
+        action: CollateralAction,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
 #[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
 pub struct Collateral {
     pub id: CollateralId,
+    pub account_id: CalaAccountId,
     pub credit_facility_id: CreditFacilityId,
     pub custody_wallet_id: Option<CustodyWalletId>,
     pub amount: Satoshis,
@@ -59,6 +94,69 @@ impl Collateral {
             .expect("entity_first_persisted_at not found")
     }
 
+    pub fn asset_positions(&self) -> BTreeMap<CollateralAsset, Decimal> {
+        let mut positions = BTreeMap::new();
+        for event in self.events.iter_all() {
+            if let CollateralEvent::AssetPositionUpdated {
+                asset, quantity, ..
+            } = event
+            {
+                if quantity.is_zero() {
+                    positions.remove(asset);
+                } else {
+                    positions.insert(*asset, *quantity);
+                }
+            }
+        }
+        positions
+    }
+
+    pub(super) fn record_asset_position_update(
+        &mut self,
+        asset: CollateralAsset,
+        quantity: Decimal,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Idempotent<CollateralAssetPositionUpdate>, CollateralError> {
+        if asset == CollateralAsset::Btc {
+            return Err(CollateralError::BtcPositionNotSupported);
+        }
+        if quantity.is_sign_negative() {
+            return Err(CollateralError::NegativeAssetQuantity(quantity));
+        }
+
+        let current = self
+            .asset_positions()
+            .get(&asset)
+            .copied()
+            .unwrap_or(Decimal::ZERO);
+        let (abs_diff, action) = match quantity.cmp(&current) {
+            Ordering::Less => (current - quantity, CollateralAction::Remove),
+            Ordering::Greater => (quantity - current, CollateralAction::Add),
+            Ordering::Equal => return Ok(Idempotent::Ignored),
+        };
+
+        let tx_id = LedgerTxId::new();
+
+        self.events.push(CollateralEvent::AssetPositionUpdated {
+            ledger_tx_id: tx_id,
+            asset,
+            quantity,
+            abs_diff,
+            action,
+            audit_info: audit_info.clone(),
+        });
+
+        Ok(Idempotent::Executed(CollateralAssetPositionUpdate {
+            tx_id,
+            collateral_account_id: self.account_id,
+            asset,
+            abs_diff,
+            action,
+            effective,
+        }))
+    }
+
     pub fn record_collateral_update_via_custodian_sync(
         &mut self,
         new_amount: Satoshis,
@@ -124,6 +222,102 @@ impl Collateral {
             effective,
         })
     }
+
+    pub fn record_collateral_reserved_for_liquidation(
+        &mut self,
+        liquidation_process_id: LiquidationProcessId,
+        ledger_tx_id: LedgerTxId,
+        reserved: Satoshis,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CollateralEvent::UpdatedViaLiquidation { ledger_tx_id: id, .. } if *id == ledger_tx_id
+        );
+
+        let new_amount = self.amount - reserved;
+
+        self.events.push(CollateralEvent::UpdatedViaLiquidation {
+            ledger_tx_id,
+            collateral_amount: new_amount,
+            abs_diff: reserved,
+            action: CollateralAction::Remove,
+            liquidation_process_id,
+            audit_info: audit_info.clone(),
+        });
+
+        self.amount = new_amount;
+
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn record_collateral_rollover(
+        &mut self,
+        ledger_tx_id: LedgerTxId,
+        new_amount: Satoshis,
+        counterpart_credit_facility_id: CreditFacilityId,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CollateralEvent::UpdatedViaRollover { ledger_tx_id: id, .. } if *id == ledger_tx_id
+        );
+
+        let current = self.amount;
+        let (abs_diff, action) = match new_amount.cmp(&current) {
+            Ordering::Less => (current - new_amount, CollateralAction::Remove),
+            Ordering::Greater => (new_amount - current, CollateralAction::Add),
+            Ordering::Equal => return Idempotent::Ignored,
+        };
+
+        self.events.push(CollateralEvent::UpdatedViaRollover {
+            ledger_tx_id,
+            collateral_amount: new_amount,
+            abs_diff,
+            action,
+            counterpart_credit_facility_id,
+            audit_info: audit_info.clone(),
+        });
+
+        self.amount = new_amount;
+
+        Idempotent::Executed(())
+    }
+
+    pub(super) fn record_collateral_release(
+        &mut self,
+        collateral_release_id: CollateralReleaseId,
+        released: Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<CollateralUpdate> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CollateralEvent::UpdatedViaRelease { collateral_release_id: id, .. }
+                if *id == collateral_release_id
+        );
+
+        let new_amount = self.amount - released;
+        let tx_id = LedgerTxId::new();
+
+        self.events.push(CollateralEvent::UpdatedViaRelease {
+            ledger_tx_id: tx_id,
+            collateral_amount: new_amount,
+            abs_diff: released,
+            action: CollateralAction::Remove,
+            collateral_release_id,
+            audit_info: audit_info.clone(),
+        });
+
+        self.amount = new_amount;
+
+        Idempotent::Executed(CollateralUpdate {
+            tx_id,
+            abs_diff: released,
+            action: CollateralAction::Remove,
+            effective,
+        })
+    }
 }
 
 #[derive(Debug, Builder)]
@@ -151,12 +345,13 @@ impl TryFromEvents<CollateralEvent> for Collateral {
             match event {
                 CollateralEvent::Initialized {
                     id,
+                    account_id,
                     credit_facility_id,
                     custody_wallet_id,
-                    ..
                 } => {
                     builder = builder
                         .id(*id)
+                        .account_id(*account_id)
                         .amount(Satoshis::ZERO)
                         .custody_wallet_id(*custody_wallet_id)
                         .credit_facility_id(*credit_facility_id)
@@ -168,9 +363,22 @@ impl TryFromEvents<CollateralEvent> for Collateral {
                 | CollateralEvent::UpdatedViaCustodianSync {
                     collateral_amount: new_value,
                     ..
+                }
+                | CollateralEvent::UpdatedViaLiquidation {
+                    collateral_amount: new_value,
+                    ..
+                }
+                | CollateralEvent::UpdatedViaRollover {
+                    collateral_amount: new_value,
+                    ..
+                }
+                | CollateralEvent::UpdatedViaRelease {
+                    collateral_amount: new_value,
+                    ..
                 } => {
                     builder = builder.amount(*new_value);
                 }
+                CollateralEvent::AssetPositionUpdated { .. } => (),
             }
         }
         builder.events(events).build()
@@ -190,3 +398,97 @@ impl IntoEvents<CollateralEvent> for NewCollateral {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn collateral() -> Collateral {
+        let id = CollateralId::new();
+        Collateral::try_from_events(EntityEvents::init(
+            id,
+            [CollateralEvent::Initialized {
+                id,
+                account_id: CalaAccountId::new(),
+                credit_facility_id: CreditFacilityId::new(),
+                custody_wallet_id: None,
+            }],
+        ))
+        .unwrap()
+    }
+
+    fn effective() -> chrono::NaiveDate {
+        Utc::now().date_naive()
+    }
+
+    #[test]
+    fn asset_positions_track_latest_quantity() {
+        let mut collateral = collateral();
+        assert!(collateral.asset_positions().is_empty());
+
+        let update = collateral
+            .record_asset_position_update(
+                CollateralAsset::Eth,
+                dec!(2.5),
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(update.abs_diff, dec!(2.5));
+        assert_eq!(update.action, CollateralAction::Add);
+        assert_eq!(update.collateral_account_id, collateral.account_id);
+        assert!(
+            collateral
+                .record_asset_position_update(
+                    CollateralAsset::Eth,
+                    dec!(2.5),
+                    effective(),
+                    &dummy_audit_info()
+                )
+                .unwrap()
+                .was_ignored()
+        );
+        assert_eq!(
+            collateral.asset_positions().get(&CollateralAsset::Eth),
+            Some(&dec!(2.5))
+        );
+
+        let update = collateral
+            .record_asset_position_update(
+                CollateralAsset::Eth,
+                Decimal::ZERO,
+                effective(),
+                &dummy_audit_info(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(update.abs_diff, dec!(2.5));
+        assert_eq!(update.action, CollateralAction::Remove);
+        assert!(collateral.asset_positions().is_empty());
+    }
+
+    #[test]
+    fn btc_position_must_go_through_ledger() {
+        let mut collateral = collateral();
+        assert!(matches!(
+            collateral.record_asset_position_update(
+                CollateralAsset::Btc,
+                dec!(1),
+                effective(),
+                &dummy_audit_info()
+            ),
+            Err(CollateralError::BtcPositionNotSupported)
+        ));
+    }
+}
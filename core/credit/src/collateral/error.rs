@@ -10,6 +10,12 @@ pub enum CollateralError {
     CreditLedgerError(#[from] crate::ledger::error::CreditLedgerError),
     #[error("CollateralError - ManualUpdateError: Cannot update collateral with a custodian")]
     ManualUpdateError,
+    #[error("CollateralError - InsufficientCollateral: cannot reserve {0} with only {1} available")]
+    InsufficientCollateral(core_money::Satoshis, core_money::Satoshis),
+    #[error("CollateralError - BtcPositionNotSupported: BTC collateral is tracked in the ledger")]
+    BtcPositionNotSupported,
+    #[error("CollateralError - NegativeAssetQuantity: {0}")]
+    NegativeAssetQuantity(rust_decimal::Decimal),
 }
 
 es_entity::from_es_entity_error!(CollateralError);
@@ -10,7 +10,8 @@ use authz::PermissionCheck;
 use outbox::OutboxEventMarker;
 
 use crate::{
-    CreditFacility, CreditFacilityPublisher, CreditLedger, event::CoreCreditEvent, primitives::*,
+    CreditFacility, CreditFacilityPublisher, CreditLedger, event::CoreCreditEvent,
+    ledger::LiquidationCollateralReservation, primitives::*,
 };
 
 pub use entity::Collateral;
@@ -19,7 +20,7 @@ pub(super) use entity::*;
 #[cfg(feature = "json-schema")]
 pub use entity::CollateralEvent;
 use error::CollateralError;
-use repo::CollateralRepo;
+pub(crate) use repo::CollateralRepo;
 
 pub struct Collaterals<Perms, E>
 where
@@ -70,9 +71,16 @@ where
         self.repo.find_all(ids).await
     }
 
+    pub(super) async fn find_by_id_without_audit(
+        &self,
+        id: CollateralId,
+    ) -> Result<Collateral, CollateralError> {
+        self.repo.find_by_id(id).await
+    }
+
     pub async fn create_in_op(
         &self,
-        db: &mut es_entity::DbOp<'_>,
+        db: &mut impl es_entity::AtomicOperation,
         collateral_id: CollateralId,
         credit_facility_id: CreditFacilityId,
         custody_wallet_id: Option<CustodyWalletId>,
@@ -148,4 +156,174 @@ where
 
         Ok(())
     }
+
+    pub(super) async fn record_collateral_reserved_for_liquidation_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        collateral_id: CollateralId,
+        liquidation_process_id: LiquidationProcessId,
+        reservation: &LiquidationCollateralReservation,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<(), CollateralError> {
+        let mut collateral = self.repo.find_by_id(collateral_id).await?;
+
+        if reservation.collateral > collateral.amount {
+            return Err(CollateralError::InsufficientCollateral(
+                reservation.collateral,
+                collateral.amount,
+            ));
+        }
+
+        if collateral
+            .record_collateral_reserved_for_liquidation(
+                liquidation_process_id,
+                reservation.tx_id,
+                reservation.collateral,
+                audit_info,
+            )
+            .did_execute()
+        {
+            self.repo.update_in_op(db, &mut collateral).await?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) async fn record_collateral_release_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        collateral_id: CollateralId,
+        collateral_release_id: CollateralReleaseId,
+        released: Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Option<CollateralUpdate>, CollateralError> {
+        let mut collateral = self.repo.find_by_id(collateral_id).await?;
+
+        if collateral.custody_wallet_id.is_some() {
+            return Err(CollateralError::ManualUpdateError);
+        }
+        if released > collateral.amount {
+            return Err(CollateralError::InsufficientCollateral(
+                released,
+                collateral.amount,
+            ));
+        }
+
+        let res = if let es_entity::Idempotent::Executed(data) = collateral
+            .record_collateral_release(collateral_release_id, released, effective, audit_info)
+        {
+            self.repo.update_in_op(db, &mut collateral).await?;
+            Some(data)
+        } else {
+            None
+        };
+
+        Ok(res)
+    }
+
+    #[instrument(
+        name = "collateral.record_asset_position_update_in_op",
+        skip(db, self),
+        err
+    )]
+    pub(super) async fn record_asset_position_update_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        collateral_id: CollateralId,
+        asset: CollateralAsset,
+        quantity: rust_decimal::Decimal,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Option<CollateralAssetPositionUpdate>, CollateralError> {
+        let mut collateral = self.repo.find_by_id(collateral_id).await?;
+
+        let res = if let es_entity::Idempotent::Executed(data) =
+            collateral.record_asset_position_update(asset, quantity, effective, audit_info)?
+        {
+            self.repo.update_in_op(db, &mut collateral).await?;
+            Some(data)
+        } else {
+            None
+        };
+
+        Ok(res)
+    }
+
+    /// Moves the collateral of a rolled over facility onto the new facility's
+    /// collateral. Bitcoin moves inside the rollover transaction, the returned
+    /// updates carry the other asset positions for posting.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) async fn roll_over_in_op(
+        &self,
+        db: &mut impl es_entity::AtomicOperation,
+        collateral_id: CollateralId,
+        new_collateral_id: CollateralId,
+        new_credit_facility_id: CreditFacilityId,
+        new_account_id: CalaAccountId,
+        ledger_tx_id: LedgerTxId,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<(Collateral, Vec<CollateralAssetPositionUpdate>), CollateralError> {
+        let mut collateral = self.repo.find_by_id_in_op(&mut *db, collateral_id).await?;
+        let amount = collateral.amount;
+        let asset_positions = collateral.asset_positions();
+        let mut asset_position_updates = Vec::new();
+
+        let mut collateral_updated = collateral
+            .record_collateral_rollover(
+                ledger_tx_id,
+                Satoshis::ZERO,
+                new_credit_facility_id,
+                audit_info,
+            )
+            .did_execute();
+        for asset in asset_positions.keys() {
+            if let es_entity::Idempotent::Executed(update) = collateral
+                .record_asset_position_update(
+                    *asset,
+                    rust_decimal::Decimal::ZERO,
+                    effective,
+                    audit_info,
+                )?
+            {
+                asset_position_updates.push(update);
+                collateral_updated = true;
+            }
+        }
+        if collateral_updated {
+            self.repo.update_in_op(&mut *db, &mut collateral).await?;
+        }
+
+        let mut new_collateral = self
+            .create_in_op(
+                &mut *db,
+                new_collateral_id,
+                new_credit_facility_id,
+                collateral.custody_wallet_id,
+                new_account_id,
+            )
+            .await?;
+        let mut new_collateral_updated = new_collateral
+            .record_collateral_rollover(
+                ledger_tx_id,
+                amount,
+                collateral.credit_facility_id,
+                audit_info,
+            )
+            .did_execute();
+        for (asset, quantity) in asset_positions {
+            if let es_entity::Idempotent::Executed(update) = new_collateral
+                .record_asset_position_update(asset, quantity, effective, audit_info)?
+            {
+                asset_position_updates.push(update);
+                new_collateral_updated = true;
+            }
+        }
+        if new_collateral_updated {
+            self.repo.update_in_op(db, &mut new_collateral).await?;
+        }
+
+        Ok((new_collateral, asset_position_updates))
+    }
 }
@@ -0,0 +1,403 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use audit::AuditInfo;
+use es_entity::*;
+
+use crate::primitives::*;
+
+use super::error::CollateralReleaseError;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "CollateralReleaseId")]
+pub enum CollateralReleaseEvent {
+    Initialized {
+        id: CollateralReleaseId,
+        approval_process_id: ApprovalProcessId,
+        credit_facility_id: CreditFacilityId,
+        collateral_id: CollateralId,
+        amount: Satoshis,
+        address: String,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    },
+    SendRequested {
+        audit_info: AuditInfo,
+    },
+    Executed {
+        ledger_tx_id: Option<LedgerTxId>,
+        external_transfer_id: Option<String>,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    Failed {
+        reason: String,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct CollateralRelease {
+    pub id: CollateralReleaseId,
+    pub approval_process_id: ApprovalProcessId,
+    pub credit_facility_id: CreditFacilityId,
+    pub collateral_id: CollateralId,
+    pub amount: Satoshis,
+    pub address: String,
+
+    events: EntityEvents<CollateralReleaseEvent>,
+}
+
+impl CollateralRelease {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("entity_first_persisted_at not found")
+    }
+
+    pub fn status(&self) -> CollateralReleaseStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                CollateralReleaseEvent::Executed { .. } => Some(CollateralReleaseStatus::Executed),
+                CollateralReleaseEvent::Failed { .. } => Some(CollateralReleaseStatus::Failed),
+                CollateralReleaseEvent::SendRequested { .. } => {
+                    Some(CollateralReleaseStatus::Executing)
+                }
+                CollateralReleaseEvent::ApprovalProcessConcluded { approved: true, .. } => {
+                    Some(CollateralReleaseStatus::Approved)
+                }
+                CollateralReleaseEvent::ApprovalProcessConcluded {
+                    approved: false, ..
+                } => Some(CollateralReleaseStatus::Denied),
+                _ => None,
+            })
+            .unwrap_or(CollateralReleaseStatus::PendingApproval)
+    }
+
+    pub fn is_approval_process_concluded(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, CollateralReleaseEvent::ApprovalProcessConcluded { .. }))
+    }
+
+    pub fn external_transfer_id(&self) -> Option<&str> {
+        self.events.iter_all().find_map(|event| match event {
+            CollateralReleaseEvent::Executed {
+                external_transfer_id: Some(id),
+                ..
+            } => Some(id.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn failure_reason(&self) -> Option<&str> {
+        self.events.iter_all().find_map(|event| match event {
+            CollateralReleaseEvent::Failed { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            CollateralReleaseEvent::ApprovalProcessConcluded { .. }
+        );
+        self.events
+            .push(CollateralReleaseEvent::ApprovalProcessConcluded {
+                approval_process_id: self.approval_process_id,
+                approved,
+                audit_info,
+            });
+
+        Idempotent::Executed(())
+    }
+
+    /// Marks the transfer as handed to the custodian. Must be persisted before
+    /// calling out so that a retry resends with the same idempotency key
+    /// instead of re-evaluating the release.
+    pub(crate) fn record_send_requested(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CollateralReleaseError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CollateralReleaseEvent::SendRequested { .. }
+        );
+        if self.status() != CollateralReleaseStatus::Approved {
+            return Err(CollateralReleaseError::NotApproved);
+        }
+
+        self.events
+            .push(CollateralReleaseEvent::SendRequested { audit_info });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn idempotency_key(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn is_executable(&self) -> bool {
+        matches!(
+            self.status(),
+            CollateralReleaseStatus::Approved | CollateralReleaseStatus::Executing
+        )
+    }
+
+    pub(crate) fn record_executed(
+        &mut self,
+        ledger_tx_id: Option<LedgerTxId>,
+        external_transfer_id: Option<String>,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CollateralReleaseError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CollateralReleaseEvent::Executed { .. } | CollateralReleaseEvent::Failed { .. }
+        );
+        if !self.is_executable() {
+            return Err(CollateralReleaseError::NotApproved);
+        }
+
+        self.events.push(CollateralReleaseEvent::Executed {
+            ledger_tx_id,
+            external_transfer_id,
+            effective,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn record_failed(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CollateralReleaseError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CollateralReleaseEvent::Executed { .. } | CollateralReleaseEvent::Failed { .. }
+        );
+        if !self.is_executable() {
+            return Err(CollateralReleaseError::NotApproved);
+        }
+
+        self.events
+            .push(CollateralReleaseEvent::Failed { reason, audit_info });
+
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<CollateralReleaseEvent> for CollateralRelease {
+    fn try_from_events(
+        events: EntityEvents<CollateralReleaseEvent>,
+    ) -> Result<Self, EsEntityError> {
+        let mut builder = CollateralReleaseBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                CollateralReleaseEvent::Initialized {
+                    id,
+                    approval_process_id,
+                    credit_facility_id,
+                    collateral_id,
+                    amount,
+                    address,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .approval_process_id(*approval_process_id)
+                        .credit_facility_id(*credit_facility_id)
+                        .collateral_id(*collateral_id)
+                        .amount(*amount)
+                        .address(address.clone())
+                }
+                CollateralReleaseEvent::ApprovalProcessConcluded { .. } => (),
+                CollateralReleaseEvent::SendRequested { .. } => (),
+                CollateralReleaseEvent::Executed { .. } => (),
+                CollateralReleaseEvent::Failed { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct NewCollateralRelease {
+    #[builder(setter(into))]
+    pub(super) id: CollateralReleaseId,
+    #[builder(setter(into))]
+    pub(super) approval_process_id: ApprovalProcessId,
+    #[builder(setter(into))]
+    pub(super) credit_facility_id: CreditFacilityId,
+    #[builder(setter(into))]
+    pub(super) collateral_id: CollateralId,
+    pub(super) amount: Satoshis,
+    #[builder(setter(into))]
+    pub(super) address: String,
+    pub(super) audit_info: AuditInfo,
+}
+
+impl NewCollateralRelease {
+    pub fn builder() -> NewCollateralReleaseBuilder {
+        NewCollateralReleaseBuilder::default()
+    }
+}
+
+impl IntoEvents<CollateralReleaseEvent> for NewCollateralRelease {
+    fn into_events(self) -> EntityEvents<CollateralReleaseEvent> {
+        EntityEvents::init(
+            self.id,
+            [CollateralReleaseEvent::Initialized {
+                id: self.id,
+                approval_process_id: self.approval_process_id,
+                credit_facility_id: self.credit_facility_id,
+                collateral_id: self.collateral_id,
+                amount: self.amount,
+                address: self.address,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn release_from(events: Vec<CollateralReleaseEvent>) -> CollateralRelease {
+        CollateralRelease::try_from_events(EntityEvents::init(CollateralReleaseId::new(), events))
+            .unwrap()
+    }
+
+    fn initial_events() -> Vec<CollateralReleaseEvent> {
+        let id = CollateralReleaseId::new();
+        vec![CollateralReleaseEvent::Initialized {
+            id,
+            approval_process_id: id.into(),
+            credit_facility_id: CreditFacilityId::new(),
+            collateral_id: CollateralId::new(),
+            amount: Satoshis::from(100_000),
+            address: "bc1qaddress".to_string(),
+            audit_info: dummy_audit_info(),
+        }]
+    }
+
+    #[test]
+    fn status_follows_approval_and_execution() {
+        let mut release = release_from(initial_events());
+        assert_eq!(release.status(), CollateralReleaseStatus::PendingApproval);
+
+        assert!(
+            release
+                .approval_process_concluded(true, dummy_audit_info())
+                .did_execute()
+        );
+        assert!(
+            release
+                .approval_process_concluded(false, dummy_audit_info())
+                .was_ignored()
+        );
+        assert_eq!(release.status(), CollateralReleaseStatus::Approved);
+
+        assert!(
+            release
+                .record_executed(
+                    None,
+                    Some("transfer-1".to_string()),
+                    Utc::now().date_naive(),
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(release.status(), CollateralReleaseStatus::Executed);
+        assert_eq!(release.external_transfer_id(), Some("transfer-1"));
+        assert!(
+            release
+                .record_failed("late".to_string(), dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn send_requested_release_can_still_be_executed() {
+        let mut release = release_from(initial_events());
+        assert!(matches!(
+            release.record_send_requested(dummy_audit_info()),
+            Err(CollateralReleaseError::NotApproved)
+        ));
+
+        let _ = release.approval_process_concluded(true, dummy_audit_info());
+        assert!(
+            release
+                .record_send_requested(dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            release
+                .record_send_requested(dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+        assert_eq!(release.status(), CollateralReleaseStatus::Executing);
+
+        assert!(
+            release
+                .record_executed(
+                    None,
+                    Some(format!("transfer-{}", release.idempotency_key())),
+                    Utc::now().date_naive(),
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(release.status(), CollateralReleaseStatus::Executed);
+    }
+
+    #[test]
+    fn cannot_execute_denied_release() {
+        let mut release = release_from(initial_events());
+        let _ = release.approval_process_concluded(false, dummy_audit_info());
+        assert_eq!(release.status(), CollateralReleaseStatus::Denied);
+
+        assert!(matches!(
+            release.record_executed(
+                Some(LedgerTxId::new()),
+                None,
+                Utc::now().date_naive(),
+                dummy_audit_info()
+            ),
+            Err(CollateralReleaseError::NotApproved)
+        ));
+    }
+}
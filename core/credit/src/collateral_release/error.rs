@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CollateralReleaseError {
+    #[error("CollateralReleaseError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("CollateralReleaseError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("CollateralReleaseError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("CollateralReleaseError - AuthorizationError: {0}")]
+    AuthorizationError(#[from] authz::error::AuthorizationError),
+    #[error("CollateralReleaseError - GovernanceError: {0}")]
+    GovernanceError(#[from] governance::error::GovernanceError),
+    #[error("CollateralReleaseError - BelowReleaseThreshold")]
+    BelowReleaseThreshold,
+    #[error("CollateralReleaseError - ReleaseAlreadyInProgress")]
+    ReleaseAlreadyInProgress,
+    #[error("CollateralReleaseError - NotApproved")]
+    NotApproved,
+    #[error("CollateralReleaseError - CustodianCannotSendFunds")]
+    CustodianCannotSendFunds,
+}
+
+es_entity::from_es_entity_error!(CollateralReleaseError);
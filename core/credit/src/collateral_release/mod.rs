@@ -0,0 +1,170 @@
+mod entity;
+pub mod error;
+mod repo;
+
+use std::collections::HashMap;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{event::CoreCreditEvent, primitives::*};
+
+pub use entity::CollateralRelease;
+pub(super) use entity::*;
+use error::CollateralReleaseError;
+use repo::CollateralReleaseRepo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::CollateralReleaseEvent;
+
+pub struct CollateralReleases<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    repo: CollateralReleaseRepo<E>,
+    authz: Perms,
+    governance: Governance<Perms, E>,
+}
+
+impl<Perms, E> Clone for CollateralReleases<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repo: self.repo.clone(),
+            authz: self.authz.clone(),
+            governance: self.governance.clone(),
+        }
+    }
+}
+
+impl<Perms, E> CollateralReleases<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub async fn new(
+        pool: &sqlx::PgPool,
+        authz: &Perms,
+        publisher: &crate::CreditFacilityPublisher<E>,
+        governance: &Governance<Perms, E>,
+    ) -> Self {
+        let _ = governance
+            .init_policy(crate::APPROVE_COLLATERAL_RELEASE_PROCESS)
+            .await;
+
+        Self {
+            repo: CollateralReleaseRepo::new(pool, publisher),
+            authz: authz.clone(),
+            governance: governance.clone(),
+        }
+    }
+
+    pub async fn begin_op(&self) -> Result<es_entity::DbOp<'_>, CollateralReleaseError> {
+        Ok(self.repo.begin_op().await?)
+    }
+
+    pub(super) async fn create_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        new_release: NewCollateralRelease,
+    ) -> Result<CollateralRelease, CollateralReleaseError> {
+        let in_progress = self
+            .repo
+            .list_for_credit_facility_id_by_created_at(
+                new_release.credit_facility_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities
+            .iter()
+            .any(|release| {
+                matches!(
+                    release.status(),
+                    CollateralReleaseStatus::PendingApproval
+                        | CollateralReleaseStatus::Approved
+                        | CollateralReleaseStatus::Executing
+                )
+            });
+        if in_progress {
+            return Err(CollateralReleaseError::ReleaseAlreadyInProgress);
+        }
+
+        self.governance
+            .start_process(
+                db,
+                new_release.approval_process_id,
+                new_release.approval_process_id.to_string(),
+                crate::APPROVE_COLLATERAL_RELEASE_PROCESS,
+            )
+            .await?;
+
+        self.repo.create_in_op(db, new_release).await
+    }
+
+    pub(super) async fn update_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        release: &mut CollateralRelease,
+    ) -> Result<(), CollateralReleaseError> {
+        self.repo.update_in_op(db, release).await?;
+        Ok(())
+    }
+
+    pub(super) async fn find_by_id_without_audit(
+        &self,
+        id: impl Into<CollateralReleaseId> + std::fmt::Debug,
+    ) -> Result<CollateralRelease, CollateralReleaseError> {
+        self.repo.find_by_id(id.into()).await
+    }
+
+    #[instrument(
+        name = "core_credit.collateral_releases.list_for_credit_facility",
+        skip(self),
+        err
+    )]
+    pub async fn list_for_credit_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<Vec<CollateralRelease>, CollateralReleaseError> {
+        let credit_facility_id = credit_facility_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        Ok(self
+            .repo
+            .list_for_credit_facility_id_by_created_at(
+                credit_facility_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    #[instrument(name = "core_credit.collateral_releases.find_all", skip(self), err)]
+    pub async fn find_all<T: From<CollateralRelease>>(
+        &self,
+        ids: &[CollateralReleaseId],
+    ) -> Result<HashMap<CollateralReleaseId, T>, CollateralReleaseError> {
+        self.repo.find_all(ids).await
+    }
+}
@@ -0,0 +1,62 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+use outbox::OutboxEventMarker;
+
+use crate::{event::CoreCreditEvent, primitives::*, publisher::CreditFacilityPublisher};
+
+use super::{entity::*, error::CollateralReleaseError};
+
+#[derive(EsRepo)]
+#[es_repo(
+    entity = "CollateralRelease",
+    err = "CollateralReleaseError",
+    columns(
+        credit_facility_id(ty = "CreditFacilityId", list_for, update(persist = false)),
+        approval_process_id(ty = "ApprovalProcessId", update(persist = false)),
+    ),
+    tbl_prefix = "core",
+    post_persist_hook = "publish"
+)]
+pub struct CollateralReleaseRepo<E>
+where
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pool: PgPool,
+    publisher: CreditFacilityPublisher<E>,
+}
+
+impl<E> Clone for CollateralReleaseRepo<E>
+where
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            publisher: self.publisher.clone(),
+        }
+    }
+}
+
+impl<E> CollateralReleaseRepo<E>
+where
+    E: OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(pool: &PgPool, publisher: &CreditFacilityPublisher<E>) -> Self {
+        Self {
+            pool: pool.clone(),
+            publisher: publisher.clone(),
+        }
+    }
+
+    async fn publish(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &CollateralRelease,
+        new_events: es_entity::LastPersisted<'_, CollateralReleaseEvent>,
+    ) -> Result<(), CollateralReleaseError> {
+        self.publisher
+            .publish_collateral_release(op, entity, new_events)
+            .await
+    }
+}
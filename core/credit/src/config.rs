@@ -11,6 +11,8 @@ pub struct CreditConfig {
     pub upgrade_buffer_cvl_pct: CVLPct,
     #[serde(default = "default_customer_active_check_enabled")]
     pub customer_active_check_enabled: bool,
+    #[serde(default)]
+    pub collateral_release_cvl_pct: Option<CVLPct>,
 }
 
 impl Default for CreditConfig {
@@ -18,6 +20,7 @@ impl Default for CreditConfig {
         CreditConfig {
             upgrade_buffer_cvl_pct: default_upgrade_buffer_cvl_pct(),
             customer_active_check_enabled: default_customer_active_check_enabled(),
+            collateral_release_cvl_pct: None,
         }
     }
 }
@@ -11,7 +11,7 @@ use es_entity::*;
 use crate::{
     interest_accrual_cycle::*,
     ledger::*,
-    obligation::{NewObligation, ObligationsAmounts},
+    obligation::{NewObligation, ObligationAccounts, ObligationsAmounts, RolledOverReceivables},
     primitives::*,
     terms::{InterestPeriod, TermValues},
 };
@@ -70,6 +70,95 @@ pub enum CreditFacilityEvent {
         collateralization_ratio: Option<Decimal>,
         audit_info: AuditInfo,
     },
+    PrepaymentRecorded {
+        payment_id: PaymentId,
+        ledger_tx_id: Option<LedgerTxId>,
+        amount: UsdCents,
+        fee_amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    UnappliedFundsRecorded {
+        payment_id: PaymentId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    UnappliedFundsApplied {
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    UnappliedFundsRefunded {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    PenaltyAccrued {
+        obligation_id: ObligationId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    TermsModificationRequested {
+        approval_process_id: ApprovalProcessId,
+        terms: TermValues,
+        audit_info: AuditInfo,
+    },
+    TermsModified {
+        approval_process_id: ApprovalProcessId,
+        previous_terms: TermValues,
+        terms: TermValues,
+        matures_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    TermsModificationDenied {
+        approval_process_id: ApprovalProcessId,
+        audit_info: AuditInfo,
+    },
+    RolloverRequested {
+        approval_process_id: ApprovalProcessId,
+        amount: UsdCents,
+        terms: TermValues,
+        customer_type: CustomerType,
+        capitalize_interest: bool,
+        audit_info: AuditInfo,
+    },
+    RolloverDenied {
+        approval_process_id: ApprovalProcessId,
+        audit_info: AuditInfo,
+    },
+    RolledOverFrom {
+        rolled_over_from: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        collateral: Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    InterestCapitalized {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    RolledOver {
+        rolled_over_to: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        collateral: Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    LedgerAccountsOpened {
+        account_ids: CreditFacilityAccountIds,
+        audit_info: AuditInfo,
+    },
     Completed {
         audit_info: AuditInfo,
     },
@@ -110,6 +199,15 @@ impl CreditFacilityReceivable {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct RolloverRequest {
+    pub approval_process_id: ApprovalProcessId,
+    pub amount: UsdCents,
+    pub terms: TermValues,
+    pub customer_type: CustomerType,
+    pub capitalize_interest: bool,
+}
+
 #[derive(Debug)]
 pub(crate) struct NewAccrualPeriods {
     pub(crate) accrual: InterestPeriod,
@@ -403,7 +501,7 @@ impl CreditFacility {
             .idx(idx)
             .period(accrual_cycle_period)
             .facility_matures_at(self.matures_at.expect("Facility is already approved"))
-            .terms(self.terms)
+            .terms(self.terms.clone())
             .audit_info(audit_info)
             .build()
             .expect("could not build new interest accrual");
@@ -568,29 +666,608 @@ impl CreditFacility {
             CreditFacilityStatus::Closed => Some(CollateralizationState::NoCollateral),
         };
 
-        if let Some(calculated_collateralization) = collateralization_update {
-            self.events
-                .push(CreditFacilityEvent::CollateralizationStateChanged {
-                    collateralization_state: calculated_collateralization,
-                    collateral: balances.collateral(),
-                    outstanding: balances.into(),
-                    price,
-                    audit_info: audit_info.clone(),
-                });
+        if let Some(calculated_collateralization) = collateralization_update {
+            self.events
+                .push(CreditFacilityEvent::CollateralizationStateChanged {
+                    collateralization_state: calculated_collateralization,
+                    collateral: balances.collateral(),
+                    outstanding: balances.into(),
+                    price,
+                    audit_info: audit_info.clone(),
+                });
+
+            Idempotent::Executed(Some(calculated_collateralization))
+        } else if ratio_changed {
+            Idempotent::Executed(None)
+        } else {
+            Idempotent::Ignored
+        }
+    }
+
+    pub(crate) fn record_prepayment(
+        &mut self,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<Option<CreditFacilityPrepaymentFee>>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::PrepaymentRecorded { payment_id: id, .. } if *id == payment_id
+        );
+        if !self.is_activated() {
+            return Err(CreditFacilityError::NotActivatedYet);
+        }
+        if self.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted);
+        }
+
+        let fee_amount = self.terms.prepayment_fee(amount);
+        let fee = (!fee_amount.is_zero()).then(|| CreditFacilityPrepaymentFee {
+            tx_id: LedgerTxId::new(),
+            tx_ref: format!("{}-prepayment-fee-{}", self.id, payment_id),
+            credit_facility_account_ids: self.account_ids,
+            debit_account_id: self.disbursal_credit_account_id,
+            fee_amount,
+            effective,
+        });
+
+        self.events.push(CreditFacilityEvent::PrepaymentRecorded {
+            payment_id,
+            ledger_tx_id: fee.as_ref().map(|fee| fee.tx_id),
+            amount,
+            fee_amount,
+            effective,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(fee))
+    }
+
+    pub fn unapplied_funds(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .fold(UsdCents::ZERO, |total, event| match event {
+                CreditFacilityEvent::UnappliedFundsRecorded { amount, .. } => total + *amount,
+                CreditFacilityEvent::UnappliedFundsApplied { amount, .. }
+                | CreditFacilityEvent::UnappliedFundsRefunded { amount, .. } => total - *amount,
+                _ => total,
+            })
+    }
+
+    pub(crate) fn record_unapplied_funds(
+        &mut self,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<CreditFacilityUnappliedFunds> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::UnappliedFundsRecorded { payment_id: id, .. } if *id == payment_id
+        );
+
+        let unapplied_funds = CreditFacilityUnappliedFunds {
+            tx_id: LedgerTxId::new(),
+            tx_ref: format!("{}-unapplied-funds-{}", self.id, payment_id),
+            credit_facility_account_ids: self.account_ids,
+            debit_account_id: self.disbursal_credit_account_id,
+            amount,
+            effective,
+        };
+
+        self.events
+            .push(CreditFacilityEvent::UnappliedFundsRecorded {
+                payment_id,
+                ledger_tx_id: unapplied_funds.tx_id,
+                amount,
+                effective,
+                audit_info,
+            });
+
+        Idempotent::Executed(unapplied_funds)
+    }
+
+    pub(crate) fn apply_unapplied_funds(
+        &mut self,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::UnappliedFundsApplied { payment_id: id, .. } if *id == payment_id
+        );
+        let unapplied_funds = self.unapplied_funds();
+        if amount > unapplied_funds {
+            return Err(CreditFacilityError::InsufficientUnappliedFunds(
+                amount,
+                unapplied_funds,
+            ));
+        }
+
+        self.events
+            .push(CreditFacilityEvent::UnappliedFundsApplied {
+                payment_id,
+                amount,
+                effective,
+                audit_info,
+            });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    pub(crate) fn refund_unapplied_funds(
+        &mut self,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<CreditFacilityUnappliedFundsRefund, CreditFacilityError> {
+        let unapplied_funds = self.unapplied_funds();
+        if amount.is_zero() || amount > unapplied_funds {
+            return Err(CreditFacilityError::InsufficientUnappliedFunds(
+                amount,
+                unapplied_funds,
+            ));
+        }
+
+        let tx_id = LedgerTxId::new();
+        let refund = CreditFacilityUnappliedFundsRefund {
+            tx_id,
+            tx_ref: format!("{}-unapplied-funds-refund-{}", self.id, tx_id),
+            credit_facility_account_ids: self.account_ids,
+            credit_account_id: self.disbursal_credit_account_id,
+            amount,
+            effective,
+        };
+
+        self.events
+            .push(CreditFacilityEvent::UnappliedFundsRefunded {
+                ledger_tx_id: refund.tx_id,
+                amount,
+                effective,
+                audit_info,
+            });
+
+        Ok(refund)
+    }
+
+    pub fn last_penalty_accrued_on(&self) -> Option<chrono::NaiveDate> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::PenaltyAccrued { effective, .. } => Some(*effective),
+            _ => None,
+        })
+    }
+
+    /// Whether `accrue_penalty` would book anything for `effective`.
+    pub(crate) fn penalty_accrual_due(
+        &self,
+        overdue_outstanding: UsdCents,
+        effective: chrono::NaiveDate,
+    ) -> bool {
+        self.last_penalty_accrued_on()
+            .is_none_or(|last| last < effective)
+            && !self.terms.daily_penalty(overdue_outstanding).is_zero()
+    }
+
+    pub(crate) fn accrue_penalty(
+        &mut self,
+        overdue_outstanding: UsdCents,
+        accrued_at: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Idempotent<Option<(NewObligation, CreditFacilityPenaltyAccrual)>> {
+        let effective = accrued_at.date_naive();
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::PenaltyAccrued { effective: e, .. } if *e == effective
+        );
+
+        let penalty = self.terms.daily_penalty(overdue_outstanding);
+        if penalty.is_zero() {
+            return Idempotent::Executed(None);
+        }
+
+        let tx_id = LedgerTxId::new();
+        let tx_ref = format!("{}-penalty-{}", self.id, effective);
+        let overdue_date = self
+            .terms
+            .obligation_overdue_duration_from_due
+            .map(|d| d.end_date(accrued_at));
+        let liquidation_date = self
+            .terms
+            .obligation_liquidation_duration_from_due
+            .map(|d| d.end_date(accrued_at));
+        let penalty_accounts = ObligationAccounts {
+            receivable_account_id: self.account_ids.penalty_receivable_account_id,
+            account_to_be_credited_id: self.account_ids.penalty_income_account_id,
+        };
+        let new_obligation = NewObligation::builder()
+            .id(ObligationId::new())
+            .credit_facility_id(self.id)
+            .obligation_type(ObligationType::Penalty)
+            .reference(tx_ref.clone())
+            .amount(penalty)
+            .tx_id(tx_id)
+            .not_yet_due_accounts(penalty_accounts)
+            .due_accounts(penalty_accounts)
+            .overdue_accounts(penalty_accounts)
+            .in_liquidation_account_id(self.account_ids.in_liquidation_account_id)
+            .defaulted_account_id(self.account_ids.penalty_receivable_account_id)
+            .due_date(accrued_at)
+            .overdue_date(overdue_date)
+            .liquidation_date(liquidation_date)
+            .effective(effective)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("could not build new penalty obligation");
+
+        self.events.push(CreditFacilityEvent::PenaltyAccrued {
+            obligation_id: new_obligation.id,
+            ledger_tx_id: tx_id,
+            amount: penalty,
+            effective,
+            audit_info,
+        });
+
+        Idempotent::Executed(Some((
+            new_obligation,
+            CreditFacilityPenaltyAccrual {
+                tx_id,
+                tx_ref,
+                penalty,
+                effective,
+                credit_facility_account_ids: self.account_ids,
+            },
+        )))
+    }
+
+    pub fn pending_terms_modification(&self) -> Option<(ApprovalProcessId, TermValues)> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::TermsModificationRequested {
+                approval_process_id,
+                terms,
+                ..
+            } => Some(Some((*approval_process_id, terms.clone()))),
+            CreditFacilityEvent::TermsModified { .. }
+            | CreditFacilityEvent::TermsModificationDenied { .. } => Some(None),
+            _ => None,
+        })?
+    }
+
+    pub(crate) fn request_terms_modification(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        terms: TermValues,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::TermsModificationRequested { approval_process_id: id, .. }
+                if *id == approval_process_id
+        );
+        let activated_at = self
+            .activated_at
+            .ok_or(CreditFacilityError::NotActivatedYet)?;
+        if self.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted);
+        }
+        if self.pending_terms_modification().is_some() {
+            return Err(CreditFacilityError::TermsModificationInProgress);
+        }
+        if self.pending_rollover().is_some() {
+            return Err(CreditFacilityError::RolloverInProgress);
+        }
+        if terms.duration.maturity_date(activated_at) <= crate::time::now() {
+            return Err(CreditFacilityError::TermsModificationMaturesInPast);
+        }
+
+        self.events
+            .push(CreditFacilityEvent::TermsModificationRequested {
+                approval_process_id,
+                terms,
+                audit_info,
+            });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Applies the requested terms to everything generated from here on.
+    /// The interest cycle in progress and existing obligations keep the terms
+    /// they were created with.
+    pub(crate) fn conclude_terms_modification(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<Option<NewAccrualPeriods>>, CreditFacilityError> {
+        let terms = match self.pending_terms_modification() {
+            Some((id, terms)) if id == approval_process_id => terms,
+            _ => return Ok(Idempotent::Ignored),
+        };
+
+        if !approved || self.is_completed() {
+            self.events
+                .push(CreditFacilityEvent::TermsModificationDenied {
+                    approval_process_id,
+                    audit_info,
+                });
+            return Ok(Idempotent::Executed(None));
+        }
+
+        let matures_at = terms
+            .duration
+            .maturity_date(self.activated_at.expect("Facility is already active"));
+        let previous_terms = std::mem::replace(&mut self.terms, terms.clone());
+        self.matures_at = Some(matures_at);
+        self.events.push(CreditFacilityEvent::TermsModified {
+            approval_process_id,
+            previous_terms,
+            terms,
+            matures_at,
+            effective: crate::time::now().date_naive(),
+            audit_info: audit_info.clone(),
+        });
+
+        if self.interest_accrual_cycle_in_progress().is_some() {
+            return Ok(Idempotent::Executed(None));
+        }
+
+        Ok(Idempotent::Executed(
+            self.start_interest_accrual_cycle(audit_info)?,
+        ))
+    }
+
+    pub fn pending_rollover(&self) -> Option<RolloverRequest> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::RolloverRequested {
+                approval_process_id,
+                amount,
+                terms,
+                customer_type,
+                capitalize_interest,
+                ..
+            } => Some(Some(RolloverRequest {
+                approval_process_id: *approval_process_id,
+                amount: *amount,
+                terms: terms.clone(),
+                customer_type: *customer_type,
+                capitalize_interest: *capitalize_interest,
+            })),
+            CreditFacilityEvent::RolledOver { .. } | CreditFacilityEvent::RolloverDenied { .. } => {
+                Some(None)
+            }
+            _ => None,
+        })?
+    }
+
+    pub(crate) fn request_rollover(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        amount: UsdCents,
+        terms: TermValues,
+        customer_type: CustomerType,
+        capitalize_interest: bool,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::RolloverRequested { approval_process_id: id, .. }
+                if *id == approval_process_id
+        );
+        if !self.is_activated() {
+            return Err(CreditFacilityError::NotActivatedYet);
+        }
+        if self.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted);
+        }
+        if self.pending_terms_modification().is_some() {
+            return Err(CreditFacilityError::TermsModificationInProgress);
+        }
+        if self.pending_rollover().is_some() {
+            return Err(CreditFacilityError::RolloverInProgress);
+        }
+
+        self.events.push(CreditFacilityEvent::RolloverRequested {
+            approval_process_id,
+            amount,
+            terms,
+            customer_type,
+            capitalize_interest,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Returns the approved request, which stays pending until `roll_over`
+    /// closes this facility.
+    pub(crate) fn conclude_rollover_approval(
+        &mut self,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<Option<RolloverRequest>> {
+        let request = match self.pending_rollover() {
+            Some(request) if request.approval_process_id == approval_process_id => request,
+            _ => return Idempotent::Ignored,
+        };
+
+        if !approved || self.is_completed() {
+            self.events.push(CreditFacilityEvent::RolloverDenied {
+                approval_process_id,
+                audit_info,
+            });
+            return Idempotent::Executed(None);
+        }
+
+        Idempotent::Executed(Some(request))
+    }
+
+    pub fn rolled_over_from(&self) -> Option<CreditFacilityId> {
+        self.events.iter_all().find_map(|event| match event {
+            CreditFacilityEvent::RolledOverFrom {
+                rolled_over_from, ..
+            } => Some(*rolled_over_from),
+            _ => None,
+        })
+    }
+
+    pub fn rolled_over_to(&self) -> Option<CreditFacilityId> {
+        self.events.iter_all().rev().find_map(|event| match event {
+            CreditFacilityEvent::RolledOver { rolled_over_to, .. } => Some(*rolled_over_to),
+            _ => None,
+        })
+    }
+
+    /// Closes this facility once its outstanding obligations and collateral
+    /// have been moved into `rolled_over_to`.
+    pub(crate) fn roll_over(
+        &mut self,
+        rolled_over_to: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        collateral: Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, CreditFacilityError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            CreditFacilityEvent::RolledOver { ledger_tx_id: id, .. } if *id == ledger_tx_id
+        );
+        if !self.is_activated() {
+            return Err(CreditFacilityError::NotActivatedYet);
+        }
+        if self.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted);
+        }
+        if self.pending_terms_modification().is_some() {
+            return Err(CreditFacilityError::TermsModificationInProgress);
+        }
+        if self.interest_accrual_cycle_in_progress().is_some() {
+            return Err(CreditFacilityError::RolloverWithInterestAccrualCycleInProgress);
+        }
+        if !self.unapplied_funds().is_zero() {
+            return Err(CreditFacilityError::RolloverWithUnappliedFunds);
+        }
+
+        self.events.push(CreditFacilityEvent::RolledOver {
+            rolled_over_to,
+            ledger_tx_id,
+            amount,
+            collateral,
+            effective,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Activates a newly created facility with the receivables and collateral
+    /// taken over from `rolled_over_from`. The rollover itself went through
+    /// approval on `rolled_over_from`, so this facility skips its own.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn activate_via_rollover(
+        &mut self,
+        rolled_over_from: &CreditFacility,
+        ledger_tx_id: LedgerTxId,
+        rolled_over_receivables: RolledOverReceivables,
+        collateral: Satoshis,
+        other_collateral_value: UsdCents,
+        activated_at: DateTime<Utc>,
+        price: PriceOfOneBTC,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<(CreditFacilityRollover, InterestPeriod)>, CreditFacilityError> {
+        if self.is_activated() {
+            return Ok(Idempotent::Ignored);
+        }
+
+        let RolledOverReceivables {
+            principal,
+            capitalized_interest,
+        } = rolled_over_receivables;
+        let rollover = CreditFacilityRollover {
+            tx_id: ledger_tx_id,
+            tx_ref: format!("{}-rollover", self.id),
+            rolled_over_account_ids: rolled_over_from.account_ids,
+            credit_facility_account_ids: self.account_ids,
+            debit_account_id: self.disbursal_credit_account_id,
+            facility_amount: self.amount,
+            rolled_over_receivables: principal,
+            capitalized_receivables: capitalized_interest,
+            structuring_fee_amount: self.structuring_fee(),
+            collateral,
+            effective: activated_at.date_naive(),
+        };
+
+        let amount = rollover.rolled_over_amount();
+        let required =
+            amount + rollover.capitalized_interest_amount() + rollover.structuring_fee_amount;
+        if self.amount < required {
+            return Err(CreditFacilityError::RolloverAmountBelowOutstanding(
+                self.amount,
+                required,
+            ));
+        }
+        if !self.terms.is_activation_allowed(
+            CreditFacilityBalanceSummary::for_new_facility(self.amount, collateral)
+                .with_collateral_valuation(
+                    self.terms
+                        .collateral_haircuts
+                        .for_asset(CollateralAsset::Btc),
+                    other_collateral_value,
+                ),
+            price,
+        ) {
+            return Err(CreditFacilityError::BelowMarginLimit);
+        }
+
+        self.events
+            .push(CreditFacilityEvent::ApprovalProcessConcluded {
+                approval_process_id: self.approval_process_id,
+                approved: true,
+                audit_info: audit_info.clone(),
+            });
+        self.events.push(CreditFacilityEvent::RolledOverFrom {
+            rolled_over_from: rolled_over_from.id,
+            ledger_tx_id,
+            amount,
+            collateral,
+            effective: rollover.effective,
+            audit_info: audit_info.clone(),
+        });
+        let capitalized = rollover.capitalized_interest_amount();
+        if !capitalized.is_zero() {
+            self.events.push(CreditFacilityEvent::InterestCapitalized {
+                ledger_tx_id,
+                amount: capitalized,
+                effective: rollover.effective,
+                audit_info: audit_info.clone(),
+            });
+        }
+
+        self.activated_at = Some(activated_at);
+        self.matures_at = Some(self.terms.duration.maturity_date(activated_at));
+        self.events.push(CreditFacilityEvent::Activated {
+            ledger_tx_id,
+            activated_at,
+            audit_info: audit_info.clone(),
+        });
+
+        let periods = self
+            .start_interest_accrual_cycle(audit_info)?
+            .expect("first accrual");
 
-            Idempotent::Executed(Some(calculated_collateralization))
-        } else if ratio_changed {
-            Idempotent::Executed(None)
-        } else {
-            Idempotent::Ignored
-        }
+        Ok(Idempotent::Executed((rollover, periods.accrual)))
     }
 
     pub(crate) fn is_completed(&self) -> bool {
-        self.events
-            .iter_all()
-            .rev()
-            .any(|event| matches!(event, CreditFacilityEvent::Completed { .. }))
+        self.events.iter_all().rev().any(|event| {
+            matches!(
+                event,
+                CreditFacilityEvent::Completed { .. } | CreditFacilityEvent::RolledOver { .. }
+            )
+        })
     }
 
     pub(crate) fn complete(
@@ -602,7 +1279,7 @@ impl CreditFacility {
     ) -> Result<Idempotent<CreditFacilityCompletion>, CreditFacilityError> {
         idempotency_guard!(
             self.events.iter_all(),
-            CreditFacilityEvent::Completed { .. }
+            CreditFacilityEvent::Completed { .. } | CreditFacilityEvent::RolledOver { .. }
         );
         if balances.any_outstanding_or_defaulted() {
             return Err(CreditFacilityError::OutstandingAmount);
@@ -620,6 +1297,25 @@ impl CreditFacility {
         Ok(Idempotent::Executed(res))
     }
 
+    /// Gives the facility the ids of ledger accounts that were added after it
+    /// had been opened.
+    pub(crate) fn open_missing_ledger_accounts(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Idempotent<CreditFacilityAccountIds> {
+        if !self.account_ids.has_unopened_accounts() {
+            return Idempotent::Ignored;
+        }
+
+        self.account_ids = self.account_ids.with_unopened_accounts_assigned();
+        self.events.push(CreditFacilityEvent::LedgerAccountsOpened {
+            account_ids: self.account_ids,
+            audit_info,
+        });
+
+        Idempotent::Executed(self.account_ids)
+    }
+
     fn update_collateralization_ratio(
         &mut self,
         balance: &CreditFacilityBalanceSummary,
@@ -659,13 +1355,13 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                     public_id,
                     ..
                 } => {
-                    terms = Some(*t);
+                    terms = Some(t.clone());
                     builder = builder
                         .id(*id)
                         .amount(*amount)
                         .customer_id(*customer_id)
                         .collateral_id(*collateral_id)
-                        .terms(*t)
+                        .terms(t.clone())
                         .account_ids(*account_ids)
                         .disbursal_credit_account_id(*disbursal_credit_account_id)
                         .approval_process_id(*approval_process_id)
@@ -673,6 +1369,7 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                 }
                 CreditFacilityEvent::Activated { activated_at, .. } => {
                     let matures_at = terms
+                        .as_ref()
                         .expect("terms should be set")
                         .duration
                         .maturity_date(*activated_at);
@@ -683,6 +1380,29 @@ impl TryFromEvents<CreditFacilityEvent> for CreditFacility {
                 CreditFacilityEvent::InterestAccrualCycleConcluded { .. } => (),
                 CreditFacilityEvent::CollateralizationStateChanged { .. } => (),
                 CreditFacilityEvent::CollateralizationRatioChanged { .. } => (),
+                CreditFacilityEvent::PrepaymentRecorded { .. } => (),
+                CreditFacilityEvent::UnappliedFundsRecorded { .. } => (),
+                CreditFacilityEvent::UnappliedFundsApplied { .. } => (),
+                CreditFacilityEvent::UnappliedFundsRefunded { .. } => (),
+                CreditFacilityEvent::PenaltyAccrued { .. } => (),
+                CreditFacilityEvent::TermsModificationRequested { .. } => (),
+                CreditFacilityEvent::TermsModified {
+                    terms: t,
+                    matures_at,
+                    ..
+                } => {
+                    terms = Some(t.clone());
+                    builder = builder.terms(t.clone()).matures_at(*matures_at)
+                }
+                CreditFacilityEvent::TermsModificationDenied { .. } => (),
+                CreditFacilityEvent::RolloverRequested { .. } => (),
+                CreditFacilityEvent::RolloverDenied { .. } => (),
+                CreditFacilityEvent::RolledOverFrom { .. } => (),
+                CreditFacilityEvent::InterestCapitalized { .. } => (),
+                CreditFacilityEvent::RolledOver { .. } => (),
+                CreditFacilityEvent::LedgerAccountsOpened { account_ids, .. } => {
+                    builder = builder.account_ids(*account_ids)
+                }
                 CreditFacilityEvent::Completed { .. } => (),
             }
         }
@@ -810,6 +1530,10 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_posted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            collateral_haircut: HaircutPct::ZERO,
+            other_collateral_value: UsdCents::ZERO,
         }
     }
 
@@ -929,7 +1653,7 @@ mod test {
                         .matures_at
                         .expect("Facility is already approved"),
                 )
-                .terms(credit_facility.terms)
+                .terms(credit_facility.terms.clone())
                 .audit_info(dummy_audit_info())
                 .build()
                 .unwrap();
@@ -1017,6 +1741,188 @@ mod test {
         assert_eq!(credit_facility.structuring_fee(), expected_fee);
     }
 
+    #[test]
+    fn record_prepayment() {
+        let mut events = initial_events();
+        if let CreditFacilityEvent::Initialized { terms, .. } = &mut events[0] {
+            terms.prepayment_fee_rate = Some(OneTimeFeeRatePct::new(2));
+        }
+        let mut credit_facility = facility_from(events.clone());
+        let payment_id = PaymentId::new();
+        let amount = UsdCents::from(10_000);
+        let effective = Utc::now().date_naive();
+
+        assert!(matches!(
+            credit_facility.record_prepayment(payment_id, amount, effective, dummy_audit_info()),
+            Err(CreditFacilityError::NotActivatedYet)
+        ));
+
+        events.push(CreditFacilityEvent::Activated {
+            ledger_tx_id: LedgerTxId::new(),
+            activated_at: Utc::now(),
+            audit_info: dummy_audit_info(),
+        });
+        let mut credit_facility = facility_from(events);
+        let fee = credit_facility
+            .record_prepayment(payment_id, amount, effective, dummy_audit_info())
+            .unwrap()
+            .unwrap()
+            .expect("fee should be charged");
+        assert_eq!(fee.fee_amount, UsdCents::from(200));
+
+        assert!(
+            credit_facility
+                .record_prepayment(payment_id, amount, effective, dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn unapplied_funds() {
+        let mut credit_facility = facility_from(initial_events());
+        let payment_id = PaymentId::new();
+        let effective = Utc::now().date_naive();
+
+        assert!(
+            credit_facility
+                .record_unapplied_funds(
+                    payment_id,
+                    UsdCents::from(1_000),
+                    effective,
+                    dummy_audit_info()
+                )
+                .did_execute()
+        );
+        assert!(
+            credit_facility
+                .record_unapplied_funds(
+                    payment_id,
+                    UsdCents::from(1_000),
+                    effective,
+                    dummy_audit_info()
+                )
+                .was_ignored()
+        );
+        assert_eq!(credit_facility.unapplied_funds(), UsdCents::from(1_000));
+
+        assert!(
+            credit_facility
+                .apply_unapplied_funds(
+                    PaymentId::new(),
+                    UsdCents::from(400),
+                    effective,
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(credit_facility.unapplied_funds(), UsdCents::from(600));
+
+        assert!(matches!(
+            credit_facility.refund_unapplied_funds(
+                UsdCents::from(601),
+                effective,
+                dummy_audit_info()
+            ),
+            Err(CreditFacilityError::InsufficientUnappliedFunds(_, _))
+        ));
+        let refund = credit_facility
+            .refund_unapplied_funds(UsdCents::from(600), effective, dummy_audit_info())
+            .unwrap();
+        assert_eq!(refund.amount, UsdCents::from(600));
+        assert_eq!(credit_facility.unapplied_funds(), UsdCents::ZERO);
+    }
+
+    #[test]
+    fn terms_modification() {
+        let activated_at = Utc::now() - chrono::Duration::days(100);
+        let mut events = initial_events();
+        events.push(CreditFacilityEvent::Activated {
+            ledger_tx_id: LedgerTxId::new(),
+            activated_at,
+            audit_info: dummy_audit_info(),
+        });
+        let mut credit_facility = facility_from(events);
+        credit_facility
+            .start_interest_accrual_cycle(dummy_audit_info())
+            .unwrap()
+            .unwrap();
+        hydrate_accruals_in_facility(&mut credit_facility);
+
+        let mut extended = default_terms();
+        extended.duration = FacilityDuration::Months(1);
+        assert!(matches!(
+            credit_facility.request_terms_modification(
+                ApprovalProcessId::new(),
+                extended.clone(),
+                dummy_audit_info()
+            ),
+            Err(CreditFacilityError::TermsModificationMaturesInPast)
+        ));
+
+        extended.duration = FacilityDuration::Months(12);
+        extended.annual_rate = dec!(8).into();
+        let approval_process_id = ApprovalProcessId::new();
+        assert!(
+            credit_facility
+                .request_terms_modification(
+                    approval_process_id,
+                    extended.clone(),
+                    dummy_audit_info()
+                )
+                .unwrap()
+                .did_execute()
+        );
+        assert!(matches!(
+            credit_facility.request_terms_modification(
+                ApprovalProcessId::new(),
+                extended.clone(),
+                dummy_audit_info()
+            ),
+            Err(CreditFacilityError::TermsModificationInProgress)
+        ));
+        assert!(
+            credit_facility
+                .conclude_terms_modification(ApprovalProcessId::new(), true, dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+
+        let new_cycle = credit_facility
+            .conclude_terms_modification(approval_process_id, true, dummy_audit_info())
+            .unwrap()
+            .unwrap();
+        assert!(new_cycle.is_none());
+        assert!(credit_facility.pending_terms_modification().is_none());
+        assert_eq!(credit_facility.terms.annual_rate, extended.annual_rate);
+        assert_eq!(
+            credit_facility.matures_at,
+            Some(extended.duration.maturity_date(activated_at))
+        );
+        assert!(matches!(
+            credit_facility.events.iter_all().last(),
+            Some(CreditFacilityEvent::TermsModified { previous_terms, .. })
+                if previous_terms.annual_rate == default_terms().annual_rate
+        ));
+
+        let mut reduced_rate = extended.clone();
+        reduced_rate.annual_rate = dec!(4).into();
+        let approval_process_id = ApprovalProcessId::new();
+        let _ = credit_facility.request_terms_modification(
+            approval_process_id,
+            reduced_rate,
+            dummy_audit_info(),
+        );
+        assert!(
+            credit_facility
+                .conclude_terms_modification(approval_process_id, false, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(credit_facility.terms.annual_rate, extended.annual_rate);
+    }
+
     mod activate {
         use super::*;
 
@@ -1146,6 +2052,251 @@ mod test {
         }
     }
 
+    mod rollover {
+        use super::*;
+
+        fn activated_facility(activated_at: DateTime<Utc>) -> CreditFacility {
+            let mut events = initial_events();
+            events.extend([
+                CreditFacilityEvent::ApprovalProcessConcluded {
+                    approval_process_id: ApprovalProcessId::new(),
+                    approved: true,
+                    audit_info: dummy_audit_info(),
+                },
+                CreditFacilityEvent::Activated {
+                    ledger_tx_id: LedgerTxId::new(),
+                    activated_at,
+                    audit_info: dummy_audit_info(),
+                },
+            ]);
+            facility_from(events)
+        }
+
+        fn receivables(amount: UsdCents) -> RolledOverReceivables {
+            RolledOverReceivables {
+                principal: [(CalaAccountId::new(), amount)].into_iter().collect(),
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn errors_if_not_activated() {
+            let mut credit_facility = facility_from(initial_events());
+            assert!(matches!(
+                credit_facility.roll_over(
+                    CreditFacilityId::new(),
+                    LedgerTxId::new(),
+                    UsdCents::ZERO,
+                    Satoshis::ZERO,
+                    Utc::now().date_naive(),
+                    dummy_audit_info()
+                ),
+                Err(CreditFacilityError::NotActivatedYet)
+            ));
+        }
+
+        #[test]
+        fn errors_if_accrual_cycle_in_progress() {
+            let mut credit_facility = activated_facility(Utc::now());
+            credit_facility
+                .start_interest_accrual_cycle(dummy_audit_info())
+                .unwrap()
+                .unwrap();
+            hydrate_accruals_in_facility(&mut credit_facility);
+
+            assert!(matches!(
+                credit_facility.roll_over(
+                    CreditFacilityId::new(),
+                    LedgerTxId::new(),
+                    UsdCents::ZERO,
+                    Satoshis::ZERO,
+                    Utc::now().date_naive(),
+                    dummy_audit_info()
+                ),
+                Err(CreditFacilityError::RolloverWithInterestAccrualCycleInProgress)
+            ));
+        }
+
+        #[test]
+        fn rollover_needs_approval() {
+            let mut credit_facility = activated_facility(Utc::now());
+            let approval_process_id = ApprovalProcessId::new();
+            assert!(
+                credit_facility
+                    .request_rollover(
+                        approval_process_id,
+                        UsdCents::from(10_00),
+                        default_terms(),
+                        CustomerType::Individual,
+                        false,
+                        dummy_audit_info()
+                    )
+                    .unwrap()
+                    .did_execute()
+            );
+            assert!(matches!(
+                credit_facility.request_rollover(
+                    ApprovalProcessId::new(),
+                    UsdCents::from(10_00),
+                    default_terms(),
+                    CustomerType::Individual,
+                    false,
+                    dummy_audit_info()
+                ),
+                Err(CreditFacilityError::RolloverInProgress)
+            ));
+
+            assert!(
+                credit_facility
+                    .conclude_rollover_approval(ApprovalProcessId::new(), true, dummy_audit_info())
+                    .was_ignored()
+            );
+            assert!(matches!(
+                credit_facility.conclude_rollover_approval(
+                    approval_process_id,
+                    false,
+                    dummy_audit_info()
+                ),
+                Idempotent::Executed(None)
+            ));
+            assert!(credit_facility.pending_rollover().is_none());
+        }
+
+        #[test]
+        fn capitalized_interest_is_booked_with_the_rollover() {
+            let old = activated_facility(Utc::now() - chrono::Duration::days(120));
+            let mut credit_facility = facility_from(initial_events());
+            let ledger_tx_id = LedgerTxId::new();
+
+            let (rollover, _) = credit_facility
+                .activate_via_rollover(
+                    &old,
+                    ledger_tx_id,
+                    RolledOverReceivables {
+                        principal: [(CalaAccountId::new(), UsdCents::from(8_00))]
+                            .into_iter()
+                            .collect(),
+                        capitalized_interest: [(CalaAccountId::new(), UsdCents::from(1_00))]
+                            .into_iter()
+                            .collect(),
+                    },
+                    default_full_collateral(),
+                    UsdCents::ZERO,
+                    Utc::now(),
+                    default_price(),
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .unwrap();
+            assert_eq!(rollover.tx_id, ledger_tx_id);
+            assert_eq!(rollover.rolled_over_amount(), UsdCents::from(8_00));
+            assert_eq!(rollover.capitalized_interest_amount(), UsdCents::from(1_00));
+            assert!(credit_facility.events.iter_all().any(|event| matches!(
+                event,
+                CreditFacilityEvent::InterestCapitalized { ledger_tx_id: tx_id, amount, .. }
+                    if *tx_id == ledger_tx_id && *amount == UsdCents::from(1_00)
+            )));
+        }
+
+        #[test]
+        fn new_facility_must_cover_outstanding_and_fee() {
+            let old = activated_facility(Utc::now() - chrono::Duration::days(120));
+            let mut credit_facility = facility_from(initial_events());
+
+            assert!(matches!(
+                credit_facility.activate_via_rollover(
+                    &old,
+                    LedgerTxId::new(),
+                    receivables(UsdCents::from(9_60)),
+                    default_full_collateral(),
+                    UsdCents::ZERO,
+                    Utc::now(),
+                    default_price(),
+                    dummy_audit_info()
+                ),
+                Err(CreditFacilityError::RolloverAmountBelowOutstanding(_, _))
+            ));
+        }
+
+        #[test]
+        fn new_facility_must_be_collateralized() {
+            let old = activated_facility(Utc::now() - chrono::Duration::days(120));
+            let mut credit_facility = facility_from(initial_events());
+
+            assert!(matches!(
+                credit_facility.activate_via_rollover(
+                    &old,
+                    LedgerTxId::new(),
+                    receivables(UsdCents::from(9_00)),
+                    Satoshis::ZERO,
+                    UsdCents::ZERO,
+                    Utc::now(),
+                    default_price(),
+                    dummy_audit_info()
+                ),
+                Err(CreditFacilityError::BelowMarginLimit)
+            ));
+        }
+
+        #[test]
+        fn can_roll_over() {
+            let mut old = activated_facility(Utc::now() - chrono::Duration::days(120));
+            let mut credit_facility = facility_from(initial_events());
+            let ledger_tx_id = LedgerTxId::new();
+
+            let (rollover, _) = credit_facility
+                .activate_via_rollover(
+                    &old,
+                    ledger_tx_id,
+                    receivables(UsdCents::from(9_00)),
+                    default_full_collateral(),
+                    UsdCents::ZERO,
+                    Utc::now(),
+                    default_price(),
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .unwrap();
+            assert_eq!(rollover.rolled_over_amount(), UsdCents::from(9_00));
+            assert_eq!(rollover.structuring_fee_amount, UsdCents::from(50));
+            assert_eq!(credit_facility.rolled_over_from(), Some(old.id));
+            assert_eq!(credit_facility.status(), CreditFacilityStatus::Active);
+            hydrate_accruals_in_facility(&mut credit_facility);
+            assert!(
+                credit_facility
+                    .interest_accrual_cycle_in_progress()
+                    .is_some()
+            );
+
+            assert!(
+                old.roll_over(
+                    credit_facility.id,
+                    ledger_tx_id,
+                    rollover.rolled_over_amount(),
+                    rollover.collateral,
+                    rollover.effective,
+                    dummy_audit_info()
+                )
+                .unwrap()
+                .did_execute()
+            );
+            assert_eq!(old.rolled_over_to(), Some(credit_facility.id));
+            assert_eq!(old.status(), CreditFacilityStatus::Closed);
+            assert!(
+                old.roll_over(
+                    credit_facility.id,
+                    ledger_tx_id,
+                    rollover.rolled_over_amount(),
+                    rollover.collateral,
+                    rollover.effective,
+                    dummy_audit_info()
+                )
+                .unwrap()
+                .was_ignored()
+            );
+        }
+    }
+
     mod completion {
         use super::*;
 
@@ -1177,6 +2328,10 @@ mod test {
                         due_interest_outstanding: UsdCents::ZERO,
                         overdue_interest_outstanding: UsdCents::ZERO,
                         interest_defaulted: UsdCents::ZERO,
+                        penalty_posted: UsdCents::ZERO,
+                        penalty_outstanding: UsdCents::ZERO,
+                        collateral_haircut: HaircutPct::ZERO,
+                        other_collateral_value: UsdCents::ZERO,
 
                         facility: UsdCents::from(2),
                         facility_remaining: UsdCents::from(1),
@@ -1208,6 +2363,10 @@ mod test {
                     due_interest_outstanding: UsdCents::ZERO,
                     overdue_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_posted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    collateral_haircut: HaircutPct::ZERO,
+                    other_collateral_value: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1235,6 +2394,10 @@ mod test {
                     due_interest_outstanding: UsdCents::ZERO,
                     overdue_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_posted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    collateral_haircut: HaircutPct::ZERO,
+                    other_collateral_value: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1267,6 +2430,10 @@ mod test {
                     not_yet_due_interest_outstanding: UsdCents::ZERO,
                     overdue_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_posted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    collateral_haircut: HaircutPct::ZERO,
+                    other_collateral_value: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1294,6 +2461,10 @@ mod test {
                     not_yet_due_interest_outstanding: UsdCents::ZERO,
                     overdue_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_posted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    collateral_haircut: HaircutPct::ZERO,
+                    other_collateral_value: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1326,6 +2497,10 @@ mod test {
                     not_yet_due_interest_outstanding: UsdCents::ZERO,
                     due_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_posted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    collateral_haircut: HaircutPct::ZERO,
+                    other_collateral_value: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1353,6 +2528,10 @@ mod test {
                     not_yet_due_interest_outstanding: UsdCents::ZERO,
                     due_interest_outstanding: UsdCents::ZERO,
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_posted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    collateral_haircut: HaircutPct::ZERO,
+                    other_collateral_value: UsdCents::ZERO,
 
                     facility: UsdCents::from(2),
                     facility_remaining: UsdCents::from(1),
@@ -1377,6 +2556,10 @@ mod test {
                 CreditFacilityBalanceSummary {
                     disbursed_defaulted: UsdCents::from(1),
                     interest_defaulted: UsdCents::ZERO,
+                    penalty_posted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    collateral_haircut: HaircutPct::ZERO,
+                    other_collateral_value: UsdCents::ZERO,
 
                     collateral: Satoshis::ZERO,
                     not_yet_due_disbursed_outstanding: UsdCents::ZERO,
@@ -1404,6 +2587,10 @@ mod test {
                 CreditFacilityBalanceSummary {
                     disbursed_defaulted: UsdCents::ZERO,
                     interest_defaulted: UsdCents::from(1),
+                    penalty_posted: UsdCents::ZERO,
+                    penalty_outstanding: UsdCents::ZERO,
+                    collateral_haircut: HaircutPct::ZERO,
+                    other_collateral_value: UsdCents::ZERO,
 
                     collateral: Satoshis::ZERO,
                     not_yet_due_disbursed_outstanding: UsdCents::ZERO,
@@ -40,6 +40,22 @@ pub enum CreditFacilityError {
     FacilityLedgerBalanceMismatch,
     #[error("CreditFacilityError - OutstandingAmount")]
     OutstandingAmount,
+    #[error("CreditFacilityError - AlreadyCompleted")]
+    AlreadyCompleted,
+    #[error("CreditFacilityError - InsufficientUnappliedFunds: {0} > {1}")]
+    InsufficientUnappliedFunds(UsdCents, UsdCents),
+    #[error("CreditFacilityError - TermsModificationInProgress")]
+    TermsModificationInProgress,
+    #[error("CreditFacilityError - RolloverInProgress")]
+    RolloverInProgress,
+    #[error("CreditFacilityError - TermsModificationMaturesInPast")]
+    TermsModificationMaturesInPast,
+    #[error("CreditFacilityError - RolloverWithInterestAccrualCycleInProgress")]
+    RolloverWithInterestAccrualCycleInProgress,
+    #[error("CreditFacilityError - RolloverWithUnappliedFunds")]
+    RolloverWithUnappliedFunds,
+    #[error("CreditFacilityError - RolloverAmountBelowOutstanding: {0} < {1}")]
+    RolloverAmountBelowOutstanding(UsdCents, UsdCents),
     #[error("CreditFacilityError - InterestAccrualCycleWithInvalidFutureStartDate")]
     InterestAccrualCycleWithInvalidFutureStartDate,
     #[error(
@@ -52,6 +68,8 @@ pub enum CreditFacilityError {
     AuditError(#[from] audit::error::AuditError),
     #[error("CreditFacilityError - LedgerError: {0}")]
     LedgerError(#[from] crate::ledger::error::CreditLedgerError),
+    #[error("CreditFacilityError - CollateralError: {0}")]
+    CollateralError(#[from] crate::collateral::error::CollateralError),
     #[error("CreditFacilityError - PriceError: {0}")]
     PriceError(#[from] core_price::error::PriceError),
     #[error("CreditFacilityError - ObligationError: {0}")]
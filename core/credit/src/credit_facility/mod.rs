@@ -2,6 +2,10 @@ mod entity;
 pub mod error;
 mod repo;
 
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+
 use tracing::instrument;
 
 use audit::AuditSvc;
@@ -11,15 +15,18 @@ use governance::{Governance, GovernanceAction, GovernanceEvent, GovernanceObject
 use outbox::OutboxEventMarker;
 
 use crate::{
+    collateral::{Collateral, CollateralRepo},
     event::CoreCreditEvent,
     interest_accrual_cycle::NewInterestAccrualCycleData,
     ledger::{
         CreditFacilityActivation, CreditFacilityInterestAccrual,
-        CreditFacilityInterestAccrualCycle, CreditLedger,
+        CreditFacilityInterestAccrualCycle, CreditFacilityPenaltyAccrual,
+        CreditFacilityPrepaymentFee, CreditFacilityRollover, CreditFacilityUnappliedFunds,
+        CreditFacilityUnappliedFundsRefund, CreditLedger,
     },
-    obligation::Obligations,
+    obligation::{NewObligation, Obligations, RolledOverReceivables},
     primitives::*,
-    terms::InterestPeriod,
+    terms::{CollateralHaircuts, InterestPeriod},
 };
 
 pub use entity::CreditFacility;
@@ -40,6 +47,7 @@ where
 {
     repo: CreditFacilityRepo<E>,
     obligations: Obligations<Perms, E>,
+    collateral_repo: CollateralRepo<E>,
     authz: Perms,
     ledger: CreditLedger,
     price: Price,
@@ -55,6 +63,7 @@ where
         Self {
             repo: self.repo.clone(),
             obligations: self.obligations.clone(),
+            collateral_repo: self.collateral_repo.clone(),
             authz: self.authz.clone(),
             ledger: self.ledger.clone(),
             price: self.price.clone(),
@@ -76,6 +85,12 @@ pub(super) struct ActivationData {
     pub audit_info: audit::AuditInfo,
 }
 
+pub(super) struct RolloverData {
+    pub credit_facility: CreditFacility,
+    pub credit_facility_rollover: CreditFacilityRollover,
+    pub next_accrual_period: InterestPeriod,
+}
+
 #[allow(clippy::large_enum_variant)]
 pub(super) enum CompletionOutcome {
     Ignored(CreditFacility),
@@ -116,6 +131,7 @@ where
         Self {
             repo,
             obligations: obligations.clone(),
+            collateral_repo: CollateralRepo::new(pool, publisher),
             authz: authz.clone(),
             ledger: ledger.clone(),
             price: price.clone(),
@@ -161,8 +177,7 @@ where
         let price = self.price.usd_cents_per_btc().await?;
         let now = db.now();
         let balances = self
-            .ledger
-            .get_credit_facility_balance(credit_facility.account_ids)
+            .balances_with_collateral_valuation(&credit_facility)
             .await?;
 
         let Ok(es_entity::Idempotent::Executed((credit_facility_activation, next_accrual_period))) =
@@ -181,6 +196,67 @@ where
         }))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub(super) async fn roll_over_in_op(
+        &self,
+        db: &mut es_entity::DbOpWithTime<'_>,
+        id: CreditFacilityId,
+        new_credit_facility: NewCreditFacility,
+        ledger_tx_id: LedgerTxId,
+        rolled_over_receivables: RolledOverReceivables,
+        collateral: &Collateral,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<RolloverData, CreditFacilityError> {
+        let mut rolled_over = self.repo.find_by_id_in_op(&mut *db, id).await?;
+        let price = self.price.usd_cents_per_btc().await?;
+        let now = db.now();
+
+        let mut credit_facility = self.repo.create_in_op(db, new_credit_facility).await?;
+        let other_collateral_value = self
+            .other_collateral_value(
+                &collateral.asset_positions(),
+                &credit_facility.terms.collateral_haircuts,
+            )
+            .await?;
+        let (credit_facility_rollover, next_accrual_period) = match credit_facility
+            .activate_via_rollover(
+                &rolled_over,
+                ledger_tx_id,
+                rolled_over_receivables,
+                collateral.amount,
+                other_collateral_value,
+                now,
+                price,
+                audit_info.clone(),
+            )? {
+            es_entity::Idempotent::Executed(res) => res,
+            es_entity::Idempotent::Ignored => {
+                unreachable!("newly created facility was already activated")
+            }
+        };
+
+        if rolled_over
+            .roll_over(
+                credit_facility.id,
+                ledger_tx_id,
+                credit_facility_rollover.rolled_over_amount(),
+                collateral.amount,
+                credit_facility_rollover.effective,
+                audit_info.clone(),
+            )?
+            .did_execute()
+        {
+            self.repo.update_in_op(db, &mut rolled_over).await?;
+        }
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok(RolloverData {
+            credit_facility,
+            credit_facility_rollover,
+            next_accrual_period,
+        })
+    }
+
     pub(super) async fn approve(
         &self,
         id: CreditFacilityId,
@@ -218,6 +294,158 @@ where
         Ok(credit_facility)
     }
 
+    pub(super) async fn request_terms_modification(
+        &self,
+        id: CreditFacilityId,
+        terms: crate::terms::TermValues,
+        audit_info: audit::AuditInfo,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        let approval_process_id = ApprovalProcessId::new();
+        if credit_facility
+            .request_terms_modification(approval_process_id, terms, audit_info)?
+            .was_ignored()
+        {
+            return Ok(credit_facility);
+        }
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo
+            .update_in_op(&mut op, &mut credit_facility)
+            .await?;
+        self.governance
+            .start_process(
+                &mut op,
+                approval_process_id,
+                credit_facility.id.to_string(),
+                crate::APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS,
+            )
+            .await?;
+        op.commit().await?;
+
+        Ok(credit_facility)
+    }
+
+    pub(super) async fn request_rollover(
+        &self,
+        id: CreditFacilityId,
+        amount: UsdCents,
+        terms: crate::terms::TermValues,
+        customer_type: CustomerType,
+        capitalize_interest: bool,
+        audit_info: audit::AuditInfo,
+    ) -> Result<CreditFacility, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        let approval_process_id = ApprovalProcessId::new();
+        if credit_facility
+            .request_rollover(
+                approval_process_id,
+                amount,
+                terms,
+                customer_type,
+                capitalize_interest,
+                audit_info,
+            )?
+            .was_ignored()
+        {
+            return Ok(credit_facility);
+        }
+
+        let mut op = self.repo.begin_op().await?;
+        self.repo
+            .update_in_op(&mut op, &mut credit_facility)
+            .await?;
+        self.governance
+            .start_process(
+                &mut op,
+                approval_process_id,
+                credit_facility.id.to_string(),
+                crate::APPROVE_CREDIT_FACILITY_ROLLOVER_PROCESS,
+            )
+            .await?;
+        op.commit().await?;
+
+        Ok(credit_facility)
+    }
+
+    /// Returns the approved rollover request, if the approval concluded one.
+    pub(super) async fn conclude_rollover_approval_in_op(
+        &self,
+        db: &mut es_entity::DbOpWithTime<'_>,
+        id: CreditFacilityId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<Option<(CreditFacility, RolloverRequest, audit::AuditInfo)>, CreditFacilityError>
+    {
+        let mut credit_facility = self.repo.find_by_id_in_op(&mut *db, id).await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db,
+                CoreCreditObject::credit_facility(credit_facility.id),
+                CoreCreditAction::CREDIT_FACILITY_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+
+        match credit_facility.conclude_rollover_approval(
+            approval_process_id,
+            approved,
+            audit_info.clone(),
+        ) {
+            es_entity::Idempotent::Executed(Some(request)) => {
+                Ok(Some((credit_facility, request, audit_info)))
+            }
+            es_entity::Idempotent::Executed(None) => {
+                self.repo.update_in_op(db, &mut credit_facility).await?;
+                Ok(None)
+            }
+            es_entity::Idempotent::Ignored => Ok(None),
+        }
+    }
+
+    pub(super) async fn conclude_terms_modification_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<(CreditFacility, Option<NewInterestAccrualCycleData>), CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db,
+                CoreCreditObject::credit_facility(credit_facility.id),
+                CoreCreditAction::CREDIT_FACILITY_CONCLUDE_APPROVAL_PROCESS,
+            )
+            .await?;
+
+        let periods = match credit_facility.conclude_terms_modification(
+            approval_process_id,
+            approved,
+            audit_info,
+        )? {
+            es_entity::Idempotent::Executed(periods) => periods,
+            es_entity::Idempotent::Ignored => return Ok((credit_facility, None)),
+        };
+
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        let new_cycle_data = periods.map(|periods| NewInterestAccrualCycleData {
+            id: credit_facility
+                .interest_accrual_cycle_in_progress()
+                .expect("New accrual cycle not found")
+                .id,
+            first_accrual_end_date: periods.accrual.end,
+        });
+
+        Ok((credit_facility, new_cycle_data))
+    }
+
     pub(super) async fn confirm_interest_accrual_in_op(
         &self,
         op: &mut impl es_entity::AtomicOperation,
@@ -271,8 +499,7 @@ where
         let mut credit_facility = self.repo.find_by_id(id).await?;
 
         let balances = self
-            .ledger
-            .get_credit_facility_balance(credit_facility.account_ids)
+            .balances_with_collateral_valuation(&credit_facility)
             .await?;
 
         let completion = if let es_entity::Idempotent::Executed(completion) =
@@ -288,6 +515,129 @@ where
         Ok(CompletionOutcome::Completed((credit_facility, completion)))
     }
 
+    pub(super) async fn record_prepayment_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Option<CreditFacilityPrepaymentFee>, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        let fee = if let es_entity::Idempotent::Executed(fee) =
+            credit_facility.record_prepayment(payment_id, amount, effective, audit_info.clone())?
+        {
+            fee
+        } else {
+            return Ok(None);
+        };
+
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok(fee)
+    }
+
+    pub(super) async fn record_unapplied_funds_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<Option<CreditFacilityUnappliedFunds>, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        let unapplied_funds =
+            if let es_entity::Idempotent::Executed(unapplied_funds) = credit_facility
+                .record_unapplied_funds(payment_id, amount, effective, audit_info.clone())
+            {
+                unapplied_funds
+            } else {
+                return Ok(None);
+            };
+
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok(Some(unapplied_funds))
+    }
+
+    pub(super) async fn accrue_penalty_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        overdue_outstanding: UsdCents,
+        accrued_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<(NewObligation, CreditFacilityPenaltyAccrual)>, CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        if !credit_facility.penalty_accrual_due(overdue_outstanding, accrued_at.date_naive()) {
+            return Ok(None);
+        }
+
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                db,
+                CoreCreditObject::credit_facility(id),
+                CoreCreditAction::CREDIT_FACILITY_RECORD_INTEREST,
+            )
+            .await?;
+
+        let penalty = if let es_entity::Idempotent::Executed(penalty) =
+            credit_facility.accrue_penalty(overdue_outstanding, accrued_at, audit_info)
+        {
+            penalty
+        } else {
+            return Ok(None);
+        };
+
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok(penalty)
+    }
+
+    pub(super) async fn apply_unapplied_funds_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<(), CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id_in_op(&mut *db, id).await?;
+
+        if credit_facility
+            .apply_unapplied_funds(payment_id, amount, effective, audit_info.clone())?
+            .did_execute()
+        {
+            self.repo.update_in_op(db, &mut credit_facility).await?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) async fn refund_unapplied_funds_in_op(
+        &self,
+        db: &mut es_entity::DbOp<'_>,
+        id: CreditFacilityId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &audit::AuditInfo,
+    ) -> Result<(CreditFacility, CreditFacilityUnappliedFundsRefund), CreditFacilityError> {
+        let mut credit_facility = self.repo.find_by_id(id).await?;
+
+        let refund =
+            credit_facility.refund_unapplied_funds(amount, effective, audit_info.clone())?;
+        self.repo.update_in_op(db, &mut credit_facility).await?;
+
+        Ok((credit_facility, refund))
+    }
+
     #[instrument(
         name = "credit.facility.complete_interest_cycle_and_maybe_start_new_cycle",
         skip(self, db)
@@ -364,6 +714,54 @@ where
         }
     }
 
+    /// Opens the ledger accounts that were added after some facilities had
+    /// already been opened, recording their ids on each facility.
+    pub(super) async fn open_missing_ledger_accounts(&self) -> Result<(), CreditFacilityError> {
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_by_created_at(query, es_entity::ListDirection::Ascending)
+                .await?;
+
+            for credit_facility in res
+                .entities
+                .iter_mut()
+                .filter(|facility| facility.account_ids.has_unopened_accounts())
+            {
+                let mut db = self.repo.begin_op().await?;
+                let audit_info = self
+                    .authz
+                    .audit()
+                    .record_system_entry_in_tx(
+                        &mut db,
+                        CoreCreditObject::credit_facility(credit_facility.id),
+                        CoreCreditAction::CREDIT_FACILITY_ACTIVATE,
+                    )
+                    .await?;
+                if let es_entity::Idempotent::Executed(account_ids) =
+                    credit_facility.open_missing_ledger_accounts(audit_info)
+                {
+                    self.repo.update_in_op(&mut db, credit_facility).await?;
+                    self.ledger
+                        .create_later_added_credit_facility_accounts(
+                            db,
+                            credit_facility.id,
+                            account_ids,
+                        )
+                        .await?;
+                }
+            }
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
     pub(super) async fn update_collateralization_from_price(
         &self,
         upgrade_buffer_cvl_pct: CVLPct,
@@ -405,10 +803,7 @@ where
                 if facility.status() == CreditFacilityStatus::Closed {
                     continue;
                 }
-                let balances = self
-                    .ledger
-                    .get_credit_facility_balance(facility.account_ids)
-                    .await?;
+                let balances = self.balances_with_collateral_valuation(facility).await?;
                 if facility
                     .update_collateralization(price, upgrade_buffer_cvl_pct, balances, &audit_info)
                     .did_execute()
@@ -447,8 +842,7 @@ where
             .await?;
 
         let balances = self
-            .ledger
-            .get_credit_facility_balance(credit_facility.account_ids)
+            .balances_with_collateral_valuation(&credit_facility)
             .await?;
         let price = self.price.usd_cents_per_btc().await?;
 
@@ -600,6 +994,46 @@ where
         Ok(balances)
     }
 
+    /// Ledger balances with the non-BTC collateral positions valued at current prices
+    /// and every position weighted by the haircut in the facility terms.
+    pub(crate) async fn balances_with_collateral_valuation(
+        &self,
+        credit_facility: &CreditFacility,
+    ) -> Result<crate::CreditFacilityBalanceSummary, CreditFacilityError> {
+        let balances = self
+            .ledger
+            .get_credit_facility_balance(credit_facility.account_ids)
+            .await?;
+        let collateral = self
+            .collateral_repo
+            .find_by_id(credit_facility.collateral_id)
+            .await?;
+        let haircuts = &credit_facility.terms.collateral_haircuts;
+        let other_collateral_value = self
+            .other_collateral_value(&collateral.asset_positions(), haircuts)
+            .await?;
+
+        Ok(balances.with_collateral_valuation(
+            haircuts.for_asset(CollateralAsset::Btc),
+            other_collateral_value,
+        ))
+    }
+
+    async fn other_collateral_value(
+        &self,
+        asset_positions: &BTreeMap<CollateralAsset, Decimal>,
+        haircuts: &CollateralHaircuts,
+    ) -> Result<UsdCents, CreditFacilityError> {
+        let mut value = UsdCents::ZERO;
+        for (asset, quantity) in asset_positions {
+            let price = self.price.usd_cents_per_unit(*asset).await?;
+            value += haircuts
+                .for_asset(*asset)
+                .apply(price.units_to_cents_round_down(*quantity));
+        }
+        Ok(value)
+    }
+
     pub async fn has_outstanding_obligations(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -96,8 +96,9 @@ where
             r#"
                 SELECT cf.id FROM core_credit_facilities cf
                 LEFT JOIN core_collaterals co ON cf.collateral_id = co.id
-                WHERE co.custody_wallet_id = $1"#,
-            wallet_id as CustodyWalletId
+                WHERE co.custody_wallet_id = $1 AND cf.status != $2"#,
+            wallet_id as CustodyWalletId,
+            CreditFacilityStatus::Closed as CreditFacilityStatus
         )
         .fetch_one(&mut self.pool().begin().await?)
         .await
@@ -11,6 +11,7 @@ use crate::{
     ledger::CreditFacilityAccountIds,
     obligation::{NewObligation, ObligationAccounts},
     primitives::*,
+    terms::PrincipalInstallment,
 };
 
 #[allow(clippy::large_enum_variant)]
@@ -29,6 +30,8 @@ pub enum DisbursalEvent {
         due_date: DateTime<Utc>,
         overdue_date: Option<DateTime<Utc>>,
         liquidation_date: Option<DateTime<Utc>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        principal_installments: Vec<PrincipalInstallment>,
         public_id: PublicId,
         audit_info: AuditInfo,
     },
@@ -40,6 +43,8 @@ pub enum DisbursalEvent {
     Settled {
         ledger_tx_id: LedgerTxId,
         obligation_id: ObligationId,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        installment_obligation_ids: Vec<ObligationId>,
         amount: UsdCents,
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
@@ -62,6 +67,8 @@ pub struct Disbursal {
     pub due_date: DateTime<Utc>,
     pub overdue_date: Option<DateTime<Utc>>,
     pub liquidation_date: Option<DateTime<Utc>>,
+    #[builder(default)]
+    pub principal_installments: Vec<PrincipalInstallment>,
     #[builder(setter(strip_option), default)]
     pub concluded_tx_id: Option<LedgerTxId>,
     pub public_id: PublicId,
@@ -83,6 +90,7 @@ impl TryFromEvents<DisbursalEvent> for Disbursal {
                     due_date,
                     overdue_date,
                     liquidation_date,
+                    principal_installments,
                     public_id,
                     ..
                 } => {
@@ -96,6 +104,7 @@ impl TryFromEvents<DisbursalEvent> for Disbursal {
                         .due_date(*due_date)
                         .overdue_date(*overdue_date)
                         .liquidation_date(*liquidation_date)
+                        .principal_installments(principal_installments.clone())
                         .public_id(public_id.clone())
                 }
                 DisbursalEvent::Settled { ledger_tx_id, .. } => {
@@ -142,13 +151,31 @@ impl Disbursal {
         })
     }
 
+    pub fn obligation_ids(&self) -> Vec<ObligationId> {
+        self.events
+            .iter_all()
+            .find_map(|event| match event {
+                DisbursalEvent::Settled {
+                    obligation_id,
+                    installment_obligation_ids,
+                    ..
+                } if installment_obligation_ids.is_empty() => Some(vec![*obligation_id]),
+                DisbursalEvent::Settled {
+                    installment_obligation_ids,
+                    ..
+                } => Some(installment_obligation_ids.clone()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
     pub(crate) fn approval_process_concluded(
         &mut self,
         tx_id: LedgerTxId,
         approved: bool,
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
-    ) -> Idempotent<Option<NewObligation>> {
+    ) -> Idempotent<Option<Vec<NewObligation>>> {
         idempotency_guard!(
             self.events.iter_all(),
             DisbursalEvent::ApprovalProcessConcluded { .. }
@@ -158,12 +185,12 @@ impl Disbursal {
             approved,
             audit_info: audit_info.clone(),
         });
-        let tx_ref: &str = &format!("disbursal-{}", self.id);
-        let new_obligation = if approved {
-            if let Idempotent::Executed(new_obligation) =
+        let tx_ref: &str = &self.tx_ref();
+        let new_obligations = if approved {
+            if let Idempotent::Executed(new_obligations) =
                 self.settle_disbursal(tx_id, tx_ref, effective, audit_info.clone())
             {
-                Some(new_obligation)
+                Some(new_obligations)
             } else {
                 return Idempotent::Ignored;
             }
@@ -176,7 +203,11 @@ impl Disbursal {
         };
         self.concluded_tx_id = Some(tx_id);
 
-        Idempotent::Executed(new_obligation)
+        Idempotent::Executed(new_obligations)
+    }
+
+    pub(crate) fn tx_ref(&self) -> String {
+        format!("disbursal-{}", self.id)
     }
 
     pub(super) fn is_approved(&self) -> Option<bool> {
@@ -194,49 +225,92 @@ impl Disbursal {
         tx_ref: &str,
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
-    ) -> Idempotent<NewObligation> {
+    ) -> Idempotent<Vec<NewObligation>> {
         idempotency_guard!(self.events.iter_all(), DisbursalEvent::Settled { .. });
-        let obligation_id = ObligationId::new();
+
+        let new_obligations: Vec<_> = if self.principal_installments.is_empty() {
+            let installment = PrincipalInstallment {
+                due_date: self.due_date,
+                overdue_date: self.overdue_date,
+                liquidation_date: self.liquidation_date,
+                amount: self.amount,
+            };
+            vec![self.new_obligation(
+                tx_id,
+                tx_ref.to_string(),
+                installment,
+                effective,
+                audit_info.clone(),
+            )]
+        } else {
+            self.principal_installments
+                .iter()
+                .enumerate()
+                .map(|(idx, installment)| {
+                    self.new_obligation(
+                        tx_id,
+                        format!("{}-{}", tx_ref, idx + 1),
+                        *installment,
+                        effective,
+                        audit_info.clone(),
+                    )
+                })
+                .collect()
+        };
+
+        let installment_obligation_ids = if self.principal_installments.is_empty() {
+            vec![]
+        } else {
+            new_obligations.iter().map(|o| o.id).collect()
+        };
         self.events.push(DisbursalEvent::Settled {
             ledger_tx_id: tx_id,
-            obligation_id,
+            obligation_id: new_obligations[0].id,
+            installment_obligation_ids,
             amount: self.amount,
             effective,
-            audit_info: audit_info.clone(),
+            audit_info,
         });
 
-        Idempotent::Executed(
-            NewObligation::builder()
-                .id(obligation_id)
-                .credit_facility_id(self.facility_id)
-                .obligation_type(ObligationType::Disbursal)
-                .reference(tx_ref.to_string())
-                .amount(self.amount)
-                .tx_id(tx_id)
-                .not_yet_due_accounts(ObligationAccounts {
-                    receivable_account_id: self
-                        .account_ids
-                        .disbursed_receivable_not_yet_due_account_id,
-                    account_to_be_credited_id: self.disbursal_credit_account_id,
-                })
-                .due_accounts(ObligationAccounts {
-                    receivable_account_id: self.account_ids.disbursed_receivable_due_account_id,
-                    account_to_be_credited_id: self.disbursal_credit_account_id,
-                })
-                .overdue_accounts(ObligationAccounts {
-                    receivable_account_id: self.account_ids.disbursed_receivable_overdue_account_id,
-                    account_to_be_credited_id: self.disbursal_credit_account_id,
-                })
-                .in_liquidation_account_id(self.account_ids.in_liquidation_account_id)
-                .defaulted_account_id(self.account_ids.disbursed_defaulted_account_id)
-                .due_date(self.due_date)
-                .overdue_date(self.overdue_date)
-                .liquidation_date(self.liquidation_date)
-                .effective(effective)
-                .audit_info(audit_info)
-                .build()
-                .expect("could not build new disbursal obligation"),
-        )
+        Idempotent::Executed(new_obligations)
+    }
+
+    fn new_obligation(
+        &self,
+        tx_id: LedgerTxId,
+        reference: String,
+        installment: PrincipalInstallment,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> NewObligation {
+        NewObligation::builder()
+            .id(ObligationId::new())
+            .credit_facility_id(self.facility_id)
+            .obligation_type(ObligationType::Disbursal)
+            .reference(reference)
+            .amount(installment.amount)
+            .tx_id(tx_id)
+            .not_yet_due_accounts(ObligationAccounts {
+                receivable_account_id: self.account_ids.disbursed_receivable_not_yet_due_account_id,
+                account_to_be_credited_id: self.disbursal_credit_account_id,
+            })
+            .due_accounts(ObligationAccounts {
+                receivable_account_id: self.account_ids.disbursed_receivable_due_account_id,
+                account_to_be_credited_id: self.disbursal_credit_account_id,
+            })
+            .overdue_accounts(ObligationAccounts {
+                receivable_account_id: self.account_ids.disbursed_receivable_overdue_account_id,
+                account_to_be_credited_id: self.disbursal_credit_account_id,
+            })
+            .in_liquidation_account_id(self.account_ids.in_liquidation_account_id)
+            .defaulted_account_id(self.account_ids.disbursed_defaulted_account_id)
+            .due_date(installment.due_date)
+            .overdue_date(installment.overdue_date)
+            .liquidation_date(installment.liquidation_date)
+            .effective(effective)
+            .audit_info(audit_info)
+            .build()
+            .expect("could not build new disbursal obligation")
     }
 
     pub(super) fn is_confirmed(&self) -> bool {
@@ -265,6 +339,8 @@ pub struct NewDisbursal {
     pub(super) due_date: DateTime<Utc>,
     pub(super) overdue_date: Option<DateTime<Utc>>,
     pub(super) liquidation_date: Option<DateTime<Utc>>,
+    #[builder(default)]
+    pub(super) principal_installments: Vec<PrincipalInstallment>,
     #[builder(setter(into))]
     pub(super) public_id: PublicId,
     #[builder(setter(into))]
@@ -300,6 +376,7 @@ impl IntoEvents<DisbursalEvent> for NewDisbursal {
                 due_date: self.due_date,
                 overdue_date: self.overdue_date,
                 liquidation_date: self.liquidation_date,
+                principal_installments: self.principal_installments,
                 audit_info: self.audit_info,
                 public_id: self.public_id,
             }],
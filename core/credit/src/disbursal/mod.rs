@@ -49,7 +49,7 @@ where
 
 pub(super) enum ApprovalProcessOutcome {
     Ignored(Disbursal),
-    Approved((Disbursal, Obligation)),
+    Approved((Disbursal, Vec<Obligation>)),
     Denied(Disbursal),
 }
 
@@ -112,7 +112,7 @@ where
     ) -> Result<Disbursal, DisbursalError> {
         let mut disbursal = self.repo.create_in_op(db, new_disbursal).await?;
 
-        let new_obligation = disbursal
+        let new_obligations = disbursal
             .approval_process_concluded(
                 LedgerTxId::new(),
                 true,
@@ -122,9 +122,11 @@ where
             .expect("First instance of idempotent action ignored")
             .expect("First disbursal obligation was already created");
 
-        self.obligations
-            .create_with_jobs_in_op(db, new_obligation)
-            .await?;
+        for new_obligation in new_obligations {
+            self.obligations
+                .create_with_jobs_in_op(db, new_obligation)
+                .await?;
+        }
 
         self.repo.update_in_op(db, &mut disbursal).await?;
 
@@ -211,13 +213,17 @@ where
             audit_info,
         ) {
             es_entity::Idempotent::Ignored => ApprovalProcessOutcome::Ignored(disbursal),
-            es_entity::Idempotent::Executed(Some(new_obligation)) => {
-                let obligation = self
-                    .obligations
-                    .create_with_jobs_in_op(op, new_obligation)
-                    .await?;
+            es_entity::Idempotent::Executed(Some(new_obligations)) => {
+                let mut obligations = Vec::with_capacity(new_obligations.len());
+                for new_obligation in new_obligations {
+                    obligations.push(
+                        self.obligations
+                            .create_with_jobs_in_op(op, new_obligation)
+                            .await?,
+                    );
+                }
                 self.repo.update_in_op(op, &mut disbursal).await?;
-                ApprovalProcessOutcome::Approved((disbursal, obligation))
+                ApprovalProcessOutcome::Approved((disbursal, obligations))
             }
             es_entity::Idempotent::Executed(None) => {
                 self.repo.update_in_op(op, &mut disbursal).await?;
@@ -10,6 +10,7 @@ use crate::{CollateralizationState, CreditFacilityReceivable, TermValues, terms:
 
 use super::primitives::*;
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type")]
@@ -33,6 +34,32 @@ pub enum CoreCreditEvent {
         id: CreditFacilityId,
         completed_at: DateTime<Utc>,
     },
+    FacilityTermsModified {
+        id: CreditFacilityId,
+        previous_terms: TermValues,
+        terms: TermValues,
+        matures_at: DateTime<Utc>,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    FacilityRolledOver {
+        id: CreditFacilityId,
+        rolled_over_to: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        collateral: Satoshis,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    FacilityRolledOverFrom {
+        id: CreditFacilityId,
+        rolled_over_from: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        collateral: Satoshis,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     FacilityRepaymentRecorded {
         credit_facility_id: CreditFacilityId,
         obligation_id: ObligationId,
@@ -42,6 +69,23 @@ pub enum CoreCreditEvent {
         recorded_at: DateTime<Utc>,
         effective: chrono::NaiveDate,
     },
+    FacilityPrepaymentRecorded {
+        credit_facility_id: CreditFacilityId,
+        payment_id: PaymentId,
+        ledger_tx_id: Option<LedgerTxId>,
+        amount: UsdCents,
+        fee_amount: UsdCents,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
+    FacilityPenaltyAccrued {
+        credit_facility_id: CreditFacilityId,
+        obligation_id: ObligationId,
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     FacilityCollateralUpdated {
         credit_facility_id: CreditFacilityId,
         ledger_tx_id: LedgerTxId,
@@ -51,6 +95,16 @@ pub enum CoreCreditEvent {
         recorded_at: DateTime<Utc>,
         effective: chrono::NaiveDate,
     },
+    FacilityCollateralAssetUpdated {
+        credit_facility_id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        asset: CollateralAsset,
+        quantity: rust_decimal::Decimal,
+        abs_diff: rust_decimal::Decimal,
+        action: CollateralAction,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     FacilityCollateralizationChanged {
         id: CreditFacilityId,
         state: CollateralizationState,
@@ -60,6 +114,14 @@ pub enum CoreCreditEvent {
         outstanding: CreditFacilityReceivable,
         price: PriceOfOneBTC,
     },
+    FacilityCollateralReleaseUpdated {
+        id: CollateralReleaseId,
+        credit_facility_id: CreditFacilityId,
+        amount: Satoshis,
+        status: CollateralReleaseStatus,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     DisbursalSettled {
         credit_facility_id: CreditFacilityId,
         ledger_tx_id: LedgerTxId,
@@ -117,6 +179,17 @@ pub enum CoreCreditEvent {
         recorded_at: DateTime<Utc>,
         effective: chrono::NaiveDate,
     },
+    LiquidationCollateralSold {
+        id: LiquidationProcessId,
+        obligation_id: ObligationId,
+        credit_facility_id: CreditFacilityId,
+        collateral: Satoshis,
+        price: PriceOfOneBTC,
+        proceeds: UsdCents,
+        ledger_tx_id: LedgerTxId,
+        recorded_at: DateTime<Utc>,
+        effective: chrono::NaiveDate,
+    },
     LiquidationProcessConcluded {
         id: LiquidationProcessId,
         obligation_id: ObligationId,
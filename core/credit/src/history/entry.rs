@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 
-use crate::primitives::*;
+use crate::{primitives::*, terms::TermValues};
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct CreditFacilityApproved {
@@ -18,6 +18,15 @@ pub struct IncrementalPayment {
     pub payment_id: ObligationInstallmentId,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PrepaymentRecorded {
+    pub cents: UsdCents,
+    pub fee_cents: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+    pub payment_id: PaymentId,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct CollateralUpdated {
     pub satoshis: Satoshis,
@@ -27,6 +36,26 @@ pub struct CollateralUpdated {
     pub tx_id: LedgerTxId,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CollateralAssetUpdated {
+    pub asset: CollateralAsset,
+    pub quantity: rust_decimal::Decimal,
+    pub abs_diff: rust_decimal::Decimal,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+    pub action: CollateralAction,
+    pub tx_id: LedgerTxId,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CollateralReleaseUpdated {
+    pub collateral_release_id: CollateralReleaseId,
+    pub satoshis: Satoshis,
+    pub status: CollateralReleaseStatus,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct CollateralizationUpdated {
     pub state: CollateralizationState,
@@ -55,6 +84,14 @@ pub struct InterestAccrualsPosted {
     pub tx_id: LedgerTxId,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PenaltyAccrued {
+    pub cents: UsdCents,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+    pub tx_id: LedgerTxId,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct ObligationMovedToLiquidation {
     pub cents: UsdCents,
@@ -63,17 +100,44 @@ pub struct ObligationMovedToLiquidation {
     pub tx_id: LedgerTxId,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct FacilityRollover {
+    pub cents: UsdCents,
+    pub collateral: Satoshis,
+    pub credit_facility_id: CreditFacilityId,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+    pub tx_id: LedgerTxId,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct TermsModified {
+    pub previous_terms: TermValues,
+    pub terms: TermValues,
+    pub matures_at: DateTime<Utc>,
+    pub recorded_at: DateTime<Utc>,
+    pub effective: chrono::NaiveDate,
+}
+
 /// Represents an entry in Credit Facility history as it is stored in a database.
 /// The entries contain no running sums; if needed, they have to be calculated
 /// during replaying.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(tag = "type")]
 pub enum CreditFacilityHistoryEntry {
     Approved(CreditFacilityApproved),
     Collateral(CollateralUpdated),
+    CollateralAsset(CollateralAssetUpdated),
+    CollateralRelease(CollateralReleaseUpdated),
     Collateralization(CollateralizationUpdated),
     Payment(IncrementalPayment),
+    Prepayment(PrepaymentRecorded),
     Disbursal(DisbursalExecuted),
     Interest(InterestAccrualsPosted),
+    Penalty(PenaltyAccrued),
     ReservedForLiquidation(ObligationMovedToLiquidation),
+    RolledOver(FacilityRollover),
+    RolledOverFrom(FacilityRollover),
+    TermsModified(TermsModified),
 }
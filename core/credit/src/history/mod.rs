@@ -50,6 +50,25 @@ impl CreditFacilityHistory {
                         tx_id: *ledger_tx_id,
                     }));
             }
+            FacilityCollateralReleaseUpdated {
+                id,
+                amount,
+                status,
+                recorded_at,
+                effective,
+                ..
+            } => {
+                self.entries
+                    .push(CreditFacilityHistoryEntry::CollateralRelease(
+                        CollateralReleaseUpdated {
+                            collateral_release_id: *id,
+                            satoshis: *amount,
+                            status: *status,
+                            recorded_at: *recorded_at,
+                            effective: *effective,
+                        },
+                    ));
+            }
             FacilityCollateralizationChanged {
                 state,
                 recorded_at,
@@ -137,11 +156,124 @@ impl CreditFacilityHistory {
                         },
                     ));
             }
+            FacilityPenaltyAccrued {
+                amount,
+                ledger_tx_id,
+                recorded_at,
+                effective,
+                ..
+            } => {
+                self.entries
+                    .push(CreditFacilityHistoryEntry::Penalty(PenaltyAccrued {
+                        cents: *amount,
+                        recorded_at: *recorded_at,
+                        effective: *effective,
+                        tx_id: *ledger_tx_id,
+                    }));
+            }
+            FacilityRolledOver {
+                rolled_over_to,
+                ledger_tx_id,
+                amount,
+                collateral,
+                recorded_at,
+                effective,
+                ..
+            } => {
+                self.entries
+                    .push(CreditFacilityHistoryEntry::RolledOver(FacilityRollover {
+                        cents: *amount,
+                        collateral: *collateral,
+                        credit_facility_id: *rolled_over_to,
+                        recorded_at: *recorded_at,
+                        effective: *effective,
+                        tx_id: *ledger_tx_id,
+                    }));
+            }
+            FacilityRolledOverFrom {
+                rolled_over_from,
+                ledger_tx_id,
+                amount,
+                collateral,
+                recorded_at,
+                effective,
+                ..
+            } => {
+                self.entries
+                    .push(CreditFacilityHistoryEntry::RolledOverFrom(
+                        FacilityRollover {
+                            cents: *amount,
+                            collateral: *collateral,
+                            credit_facility_id: *rolled_over_from,
+                            recorded_at: *recorded_at,
+                            effective: *effective,
+                            tx_id: *ledger_tx_id,
+                        },
+                    ));
+            }
+            FacilityPrepaymentRecorded {
+                payment_id,
+                amount,
+                fee_amount,
+                recorded_at,
+                effective,
+                ..
+            } => {
+                self.entries
+                    .push(CreditFacilityHistoryEntry::Prepayment(PrepaymentRecorded {
+                        cents: *amount,
+                        fee_cents: *fee_amount,
+                        recorded_at: *recorded_at,
+                        effective: *effective,
+                        payment_id: *payment_id,
+                    }));
+            }
             FacilityCompleted { .. } => {}
+            FacilityTermsModified {
+                previous_terms,
+                terms,
+                matures_at,
+                recorded_at,
+                effective,
+                ..
+            } => {
+                self.entries
+                    .push(CreditFacilityHistoryEntry::TermsModified(TermsModified {
+                        previous_terms: previous_terms.clone(),
+                        terms: terms.clone(),
+                        matures_at: *matures_at,
+                        recorded_at: *recorded_at,
+                        effective: *effective,
+                    }));
+            }
+            FacilityCollateralAssetUpdated {
+                ledger_tx_id,
+                asset,
+                quantity,
+                abs_diff,
+                action,
+                recorded_at,
+                effective,
+                ..
+            } => {
+                self.entries
+                    .push(CreditFacilityHistoryEntry::CollateralAsset(
+                        CollateralAssetUpdated {
+                            asset: *asset,
+                            quantity: *quantity,
+                            abs_diff: *abs_diff,
+                            recorded_at: *recorded_at,
+                            effective: *effective,
+                            action: *action,
+                            tx_id: *ledger_tx_id,
+                        },
+                    ));
+            }
             ObligationCreated { .. } => {}
             ObligationDue { .. } => {}
             ObligationOverdue { .. } => {}
             ObligationDefaulted { .. } => {}
+            LiquidationCollateralSold { .. } => {}
             LiquidationProcessConcluded { .. } => {}
             ObligationCompleted { .. } => {}
         }
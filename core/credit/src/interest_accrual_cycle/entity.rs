@@ -134,7 +134,7 @@ impl TryFromEvents<InterestAccrualCycleEvent> for InterestAccrualCycle {
                         .idx(*idx)
                         .period(*period)
                         .facility_matures_at(*facility_matures_at)
-                        .terms(*terms)
+                        .terms(terms.clone())
                 }
                 InterestAccrualCycleEvent::InterestAccrued { .. } => (),
                 InterestAccrualCycleEvent::InterestAccrualsPosted { .. } => (),
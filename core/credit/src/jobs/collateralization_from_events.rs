@@ -128,6 +128,10 @@ where
                     credit_facility_id: id,
                     ..
                 })
+                | Some(CoreCreditEvent::FacilityCollateralAssetUpdated {
+                    credit_facility_id: id,
+                    ..
+                })
                 | Some(CoreCreditEvent::ObligationCreated {
                     credit_facility_id: id,
                     ..
@@ -46,15 +46,34 @@ where
                     | FacilityApproved { id }
                     | FacilityActivated { id, .. }
                     | FacilityCompleted { id, .. }
+                    | FacilityTermsModified { id, .. }
+                    | FacilityRolledOver { id, .. }
+                    | FacilityRolledOverFrom { id, .. }
                     | FacilityRepaymentRecorded {
                         credit_facility_id: id,
                         ..
                     }
+                    | FacilityPrepaymentRecorded {
+                        credit_facility_id: id,
+                        ..
+                    }
+                    | FacilityPenaltyAccrued {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | FacilityCollateralUpdated {
                         credit_facility_id: id,
                         ..
                     }
+                    | FacilityCollateralAssetUpdated {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | FacilityCollateralizationChanged { id, .. }
+                    | FacilityCollateralReleaseUpdated {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | DisbursalSettled {
                         credit_facility_id: id,
                         ..
@@ -87,6 +106,10 @@ where
                         credit_facility_id: id,
                         ..
                     }
+                    | LiquidationCollateralSold {
+                        credit_facility_id: id,
+                        ..
+                    }
                     | LiquidationProcessConcluded {
                         credit_facility_id: id,
                         ..
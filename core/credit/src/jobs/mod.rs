@@ -8,4 +8,5 @@ pub mod obligation_defaulted;
 pub mod obligation_due;
 pub mod obligation_liquidation;
 pub mod obligation_overdue;
+pub mod penalty_accruals;
 pub mod wallet_collateral_sync;
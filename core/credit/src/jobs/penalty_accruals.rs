@@ -0,0 +1,181 @@
+use async_trait::async_trait;
+use chrono::Days;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::CreditFacilities, event::CoreCreditEvent, ledger::*, obligation::Obligations,
+    primitives::*,
+};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PenaltyAccrualJobConfig<Perms, E> {
+    pub credit_facility_id: CreditFacilityId,
+    pub _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+
+impl<Perms, E> JobConfig for PenaltyAccrualJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = PenaltyAccrualInit<Perms, E>;
+}
+
+pub struct PenaltyAccrualInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    ledger: CreditLedger,
+    obligations: Obligations<Perms, E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+}
+
+impl<Perms, E> PenaltyAccrualInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        ledger: &CreditLedger,
+        obligations: &Obligations<Perms, E>,
+        credit_facilities: &CreditFacilities<Perms, E>,
+    ) -> Self {
+        Self {
+            ledger: ledger.clone(),
+            obligations: obligations.clone(),
+            credit_facilities: credit_facilities.clone(),
+        }
+    }
+}
+
+const PENALTY_ACCRUAL_JOB: JobType = JobType::new("penalty-accrual");
+impl<Perms, E> JobInitializer for PenaltyAccrualInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        PENALTY_ACCRUAL_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(PenaltyAccrualJobRunner::<Perms, E> {
+            config: job.config()?,
+            obligations: self.obligations.clone(),
+            credit_facilities: self.credit_facilities.clone(),
+            ledger: self.ledger.clone(),
+        }))
+    }
+}
+
+pub struct PenaltyAccrualJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    config: PenaltyAccrualJobConfig<Perms, E>,
+    obligations: Obligations<Perms, E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    ledger: CreditLedger,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for PenaltyAccrualJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    #[instrument(
+        name = "credit.job.penalty-accruals",
+        skip(self, _current_job),
+        fields(attempt)
+    )]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let credit_facility_id = self.config.credit_facility_id;
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?;
+        if credit_facility.is_completed() {
+            return Ok(JobCompletion::Complete);
+        }
+
+        let now = crate::time::now();
+        let today = now.date_naive();
+        // Catch up on every overdue day missed since the last accrual, e.g. after
+        // downtime, charging each day on the overdue balance of that day.
+        let daily_overdue_outstanding = self
+            .obligations
+            .daily_overdue_outstanding_for_facility(
+                credit_facility_id,
+                credit_facility.last_penalty_accrued_on().map(|last| {
+                    last.checked_add_days(Days::new(1))
+                        .expect("next day overflow")
+                }),
+                today,
+            )
+            .await?;
+        for (day, overdue_outstanding) in daily_overdue_outstanding {
+            let accrued_at = if day == today {
+                now
+            } else {
+                day.and_hms_opt(0, 0, 0)
+                    .expect("midnight is a valid time")
+                    .and_utc()
+            };
+
+            let mut db = self.credit_facilities.begin_op().await?;
+            if let Some((new_obligation, penalty_accrual)) = self
+                .credit_facilities
+                .accrue_penalty_in_op(&mut db, credit_facility_id, overdue_outstanding, accrued_at)
+                .await?
+            {
+                self.obligations
+                    .create_with_jobs_in_op(&mut db, new_obligation)
+                    .await?;
+                self.ledger
+                    .record_penalty_accrual(db, penalty_accrual)
+                    .await?;
+            }
+        }
+
+        let next_run = now
+            .date_naive()
+            .checked_add_days(Days::new(1))
+            .expect("next day overflow")
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time")
+            .and_utc();
+        Ok(JobCompletion::RescheduleAt(next_run))
+    }
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 
 use core_money::{Satoshis, UsdCents};
 
-use crate::CVLPct;
+use crate::{CVLPct, terms::HaircutPct};
 
 #[cfg(not(test))]
 #[derive(Debug, Default, Copy, Clone, Serialize, Deserialize)]
@@ -25,6 +25,14 @@ pub struct CreditFacilityBalanceSummary {
     pub(super) due_interest_outstanding: UsdCents,
     pub(super) overdue_interest_outstanding: UsdCents,
     pub(super) interest_defaulted: UsdCents,
+    #[serde(default)]
+    pub(super) penalty_posted: UsdCents,
+    #[serde(default)]
+    pub(super) penalty_outstanding: UsdCents,
+    #[serde(default)]
+    pub(super) collateral_haircut: HaircutPct,
+    #[serde(default)]
+    pub(super) other_collateral_value: UsdCents,
 }
 
 // For testing we want to be able to construct the struct
@@ -45,9 +53,26 @@ pub struct CreditFacilityBalanceSummary {
     pub due_interest_outstanding: UsdCents,
     pub overdue_interest_outstanding: UsdCents,
     pub interest_defaulted: UsdCents,
+    #[serde(default)]
+    pub penalty_posted: UsdCents,
+    #[serde(default)]
+    pub penalty_outstanding: UsdCents,
+    #[serde(default)]
+    pub collateral_haircut: HaircutPct,
+    #[serde(default)]
+    pub other_collateral_value: UsdCents,
 }
 
 impl CreditFacilityBalanceSummary {
+    pub(crate) fn for_new_facility(facility: UsdCents, collateral: Satoshis) -> Self {
+        Self {
+            facility,
+            facility_remaining: facility,
+            collateral,
+            ..Default::default()
+        }
+    }
+
     pub fn any_disbursed(&self) -> bool {
         !self.disbursed.is_zero()
     }
@@ -84,8 +109,16 @@ impl CreditFacilityBalanceSummary {
         self.not_yet_due_interest_outstanding + self.interest_outstanding_payable()
     }
 
+    pub fn penalty_posted(&self) -> UsdCents {
+        self.penalty_posted
+    }
+
+    pub fn penalty_outstanding(&self) -> UsdCents {
+        self.penalty_outstanding
+    }
+
     pub fn total_outstanding(&self) -> UsdCents {
-        self.disbursed_outstanding() + self.interest_outstanding()
+        self.disbursed_outstanding() + self.interest_outstanding() + self.penalty_outstanding
     }
 
     pub fn interest_posted(&self) -> UsdCents {
@@ -96,7 +129,9 @@ impl CreditFacilityBalanceSummary {
         self.collateral
     }
     pub fn total_outstanding_payable(&self) -> UsdCents {
-        self.disbursed_outstanding_payable() + self.interest_outstanding_payable()
+        self.disbursed_outstanding_payable()
+            + self.interest_outstanding_payable()
+            + self.penalty_outstanding
     }
 
     fn total_outstanding_not_yet_payable(&self) -> UsdCents {
@@ -121,13 +156,26 @@ impl CreditFacilityBalanceSummary {
             && self.total_defaulted().is_zero())
     }
 
+    pub fn other_collateral_value(&self) -> UsdCents {
+        self.other_collateral_value
+    }
+
     pub fn facility_amount_cvl(&self, price: PriceOfOneBTC) -> CVLPct {
         let facility_amount = self.facility;
-        CVLData::new(self.collateral, facility_amount).cvl(price)
+        self.cvl_data(facility_amount).cvl(price)
     }
 
     pub fn outstanding_amount_cvl(&self, price: PriceOfOneBTC) -> CVLPct {
-        CVLData::new(self.collateral, self.total_outstanding()).cvl(price)
+        self.cvl_data(self.total_outstanding()).cvl(price)
+    }
+
+    fn cvl_data(&self, amount: UsdCents) -> CVLData {
+        CVLData {
+            amount,
+            collateral: self.collateral,
+            collateral_haircut: self.collateral_haircut,
+            other_collateral_value: self.other_collateral_value,
+        }
     }
 
     pub fn current_cvl(&self, price: PriceOfOneBTC) -> CVLPct {
@@ -144,6 +192,18 @@ impl CreditFacilityBalanceSummary {
         Self { collateral, ..self }
     }
 
+    pub fn with_collateral_valuation(
+        self,
+        collateral_haircut: HaircutPct,
+        other_collateral_value: UsdCents,
+    ) -> Self {
+        Self {
+            collateral_haircut,
+            other_collateral_value,
+            ..self
+        }
+    }
+
     pub fn with_added_disbursal(self, disbursal: UsdCents) -> Self {
         Self {
             disbursed: self.disbursed + disbursal,
@@ -173,15 +233,16 @@ impl CreditFacilityBalanceSummary {
 struct CVLData {
     amount: UsdCents,
     collateral: Satoshis,
+    collateral_haircut: HaircutPct,
+    other_collateral_value: UsdCents,
 }
 
 impl CVLData {
-    fn new(collateral: Satoshis, amount: UsdCents) -> Self {
-        Self { collateral, amount }
-    }
-
     fn cvl(&self, price: PriceOfOneBTC) -> CVLPct {
-        let collateral_value = price.sats_to_cents_round_down(self.collateral);
+        let collateral_value = self
+            .collateral_haircut
+            .apply(price.sats_to_cents_round_down(self.collateral))
+            + self.other_collateral_value;
         if collateral_value == UsdCents::ZERO {
             CVLPct::ZERO
         } else {
@@ -210,6 +271,10 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_posted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            collateral_haircut: HaircutPct::ZERO,
+            other_collateral_value: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -241,6 +306,10 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_posted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            collateral_haircut: HaircutPct::ZERO,
+            other_collateral_value: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -272,6 +341,10 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_posted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            collateral_haircut: HaircutPct::ZERO,
+            other_collateral_value: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -296,6 +369,10 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_posted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            collateral_haircut: HaircutPct::ZERO,
+            other_collateral_value: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -324,6 +401,10 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_posted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            collateral_haircut: HaircutPct::ZERO,
+            other_collateral_value: UsdCents::ZERO,
 
             facility_remaining: UsdCents::from(1),
             interest_posted: UsdCents::from(1),
@@ -15,6 +15,13 @@ pub const CREDIT_FACILITY_IN_LIQUIDATION_OMNIBUS_ACCOUNT_SET_REF: &str =
 pub const CREDIT_FACILITY_IN_LIQUIDATION_OMNIBUS_ACCOUNT_REF: &str =
     "credit-facility-in-liquidation-omnibus-account";
 
+pub const CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_SET_NAME: &str =
+    "Credit Liquidation Proceeds Omnibus Account Set";
+pub const CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_SET_REF: &str =
+    "credit-liquidation-proceeds-omnibus-account-set";
+pub const CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_REF: &str =
+    "credit-liquidation-proceeds-omnibus-account";
+
 // Summary Accounts
 pub const CREDIT_FACILITY_REMAINING_ACCOUNT_SET_NAME: &str =
     "Credit Facility Remaining Account Set";
@@ -188,6 +195,26 @@ pub const CREDIT_INTEREST_INCOME_ACCOUNT_SET_REF: &str = "credit-interest-income
 pub const CREDIT_FEE_INCOME_ACCOUNT_SET_NAME: &str = "Credit Fee Income Account Set";
 pub const CREDIT_FEE_INCOME_ACCOUNT_SET_REF: &str = "credit-fee-income-account-set";
 
+pub const CREDIT_UNAPPLIED_FUNDS_ACCOUNT_SET_NAME: &str = "Credit Unapplied Funds Account Set";
+pub const CREDIT_UNAPPLIED_FUNDS_ACCOUNT_SET_REF: &str = "credit-unapplied-funds-account-set";
+
+pub const CREDIT_PENALTY_RECEIVABLE_ACCOUNT_SET_NAME: &str =
+    "Credit Penalty Receivable Account Set";
+pub const CREDIT_PENALTY_RECEIVABLE_ACCOUNT_SET_REF: &str = "credit-penalty-receivable-account-set";
+
+pub const CREDIT_PENALTY_INCOME_ACCOUNT_SET_NAME: &str = "Credit Penalty Income Account Set";
+pub const CREDIT_PENALTY_INCOME_ACCOUNT_SET_REF: &str = "credit-penalty-income-account-set";
+
+pub const CREDIT_COLLATERAL_IN_LIQUIDATION_ACCOUNT_SET_NAME: &str =
+    "Credit Collateral In-Liquidation Account Set";
+pub const CREDIT_COLLATERAL_IN_LIQUIDATION_ACCOUNT_SET_REF: &str =
+    "credit-collateral-in-liquidation-account-set";
+
+pub const CREDIT_LIQUIDATION_PROCEEDS_ACCOUNT_SET_NAME: &str =
+    "Credit Liquidation Proceeds Account Set";
+pub const CREDIT_LIQUIDATION_PROCEEDS_ACCOUNT_SET_REF: &str =
+    "credit-liquidation-proceeds-account-set";
+
 // Velocity Controls
 pub(super) const CREDIT_FACILITY_VELOCITY_CONTROL_ID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000002");
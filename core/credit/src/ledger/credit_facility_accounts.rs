@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -25,6 +27,22 @@ pub struct CreditFacilityAccountIds {
     pub interest_defaulted_account_id: CalaAccountId,
     pub interest_income_account_id: CalaAccountId,
     pub fee_income_account_id: CalaAccountId,
+    #[serde(default = "unopened_account_id")]
+    pub unapplied_funds_account_id: CalaAccountId,
+    #[serde(default = "unopened_account_id")]
+    pub penalty_receivable_account_id: CalaAccountId,
+    #[serde(default = "unopened_account_id")]
+    pub penalty_income_account_id: CalaAccountId,
+    #[serde(default = "unopened_account_id")]
+    pub collateral_in_liquidation_account_id: CalaAccountId,
+    #[serde(default = "unopened_account_id")]
+    pub liquidation_proceeds_account_id: CalaAccountId,
+}
+
+/// Stands in for accounts that were added after a facility had been opened,
+/// until the facility records their ids in `LedgerAccountsOpened`.
+fn unopened_account_id() -> CalaAccountId {
+    CalaAccountId::from(uuid::Uuid::nil())
 }
 
 impl CreditFacilityAccountIds {
@@ -44,8 +62,45 @@ impl CreditFacilityAccountIds {
             interest_defaulted_account_id: CalaAccountId::new(),
             interest_income_account_id: CalaAccountId::new(),
             fee_income_account_id: CalaAccountId::new(),
+            unapplied_funds_account_id: CalaAccountId::new(),
+            penalty_receivable_account_id: CalaAccountId::new(),
+            penalty_income_account_id: CalaAccountId::new(),
+            collateral_in_liquidation_account_id: CalaAccountId::new(),
+            liquidation_proceeds_account_id: CalaAccountId::new(),
         }
     }
+
+    pub fn has_unopened_accounts(&self) -> bool {
+        [
+            self.unapplied_funds_account_id,
+            self.penalty_receivable_account_id,
+            self.penalty_income_account_id,
+            self.collateral_in_liquidation_account_id,
+            self.liquidation_proceeds_account_id,
+        ]
+        .contains(&unopened_account_id())
+    }
+
+    /// Assigns new ids to the accounts that have not been opened yet.
+    pub fn with_unopened_accounts_assigned(mut self) -> Self {
+        for id in self.unopened_accounts_mut() {
+            if *id == unopened_account_id() {
+                *id = CalaAccountId::new();
+            }
+        }
+        self
+    }
+
+    fn unopened_accounts_mut(&mut self) -> impl Iterator<Item = &mut CalaAccountId> {
+        [
+            &mut self.unapplied_funds_account_id,
+            &mut self.penalty_receivable_account_id,
+            &mut self.penalty_income_account_id,
+            &mut self.collateral_in_liquidation_account_id,
+            &mut self.liquidation_proceeds_account_id,
+        ]
+        .into_iter()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +128,109 @@ pub struct CreditFacilityActivation {
     pub structuring_fee_amount: UsdCents,
 }
 
+#[derive(Debug, Clone)]
+pub struct CreditFacilityRollover {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub rolled_over_account_ids: CreditFacilityAccountIds,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
+    pub debit_account_id: CalaAccountId,
+    pub facility_amount: UsdCents,
+    pub rolled_over_receivables: HashMap<CalaAccountId, UsdCents>,
+    /// Interest and penalty receivables moved into the principal of the new facility.
+    pub capitalized_receivables: HashMap<CalaAccountId, UsdCents>,
+    pub structuring_fee_amount: UsdCents,
+    pub collateral: Satoshis,
+    pub effective: chrono::NaiveDate,
+}
+
+impl CreditFacilityRollover {
+    pub fn rolled_over_amount(&self) -> UsdCents {
+        self.rolled_over_receivables
+            .values()
+            .fold(UsdCents::ZERO, |total, amount| total + *amount)
+    }
+
+    pub fn capitalized_interest_amount(&self) -> UsdCents {
+        self.capitalized_receivables
+            .values()
+            .fold(UsdCents::ZERO, |total, amount| total + *amount)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityPrepaymentFee {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
+    pub debit_account_id: CalaAccountId,
+    pub fee_amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityUnappliedFunds {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
+    pub debit_account_id: CalaAccountId,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityUnappliedFundsRefund {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
+    pub credit_account_id: CalaAccountId,
+    pub amount: UsdCents,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreditFacilityPenaltyAccrual {
+    pub tx_id: LedgerTxId,
+    pub tx_ref: String,
+    pub penalty: UsdCents,
+    pub effective: chrono::NaiveDate,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
+}
+
+#[derive(Debug, Clone)]
+pub struct LiquidationCollateralReservation {
+    pub tx_id: LedgerTxId,
+    pub collateral: Satoshis,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct LiquidationCollateralSale {
+    pub tx_id: LedgerTxId,
+    pub collateral: Satoshis,
+    pub proceeds: UsdCents,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct LiquidationSurplusReturn {
+    pub tx_id: LedgerTxId,
+    pub amount: UsdCents,
+    pub credit_facility_account_ids: CreditFacilityAccountIds,
+    pub deposit_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+pub struct LiquidationReserveRelease {
+    pub tx_id: LedgerTxId,
+    pub amount: UsdCents,
+    pub in_liquidation_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
 #[derive(Debug, Clone)]
 pub struct CreditFacilityInterestAccrual {
     pub tx_id: LedgerTxId,
@@ -90,3 +248,29 @@ pub struct CreditFacilityInterestAccrualCycle {
     pub effective: chrono::NaiveDate,
     pub credit_facility_account_ids: CreditFacilityAccountIds,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accounts_missing_from_older_events_are_unopened() {
+        let account_ids = CreditFacilityAccountIds::new();
+        assert!(!account_ids.has_unopened_accounts());
+
+        let mut stored = serde_json::to_value(account_ids).unwrap();
+        stored
+            .as_object_mut()
+            .unwrap()
+            .remove("unapplied_funds_account_id");
+        let stored: CreditFacilityAccountIds = serde_json::from_value(stored).unwrap();
+        assert!(stored.has_unopened_accounts());
+
+        let opened = stored.with_unopened_accounts_assigned();
+        assert!(!opened.has_unopened_accounts());
+        assert_eq!(
+            opened.penalty_receivable_account_id,
+            account_ids.penalty_receivable_account_id
+        );
+    }
+}
@@ -26,4 +26,10 @@ pub enum CreditLedgerError {
     NonAccountMemberFoundInAccountSet(String),
     #[error("CreditLedgerError - JournalIdMismatch: Account sets have wrong JournalId")]
     JournalIdMismatch,
+    #[error("CreditLedgerError - DisbursalWithoutObligations: disbursal {0} has no obligations")]
+    DisbursalWithoutObligations(crate::primitives::DisbursalId),
+    #[error("CreditLedgerError - AccountingPeriodGuardError: {0}")]
+    AccountingPeriodGuardError(
+        #[from] core_accounting::accounting_period::error::AccountingPeriodGuardError,
+    ),
 }
@@ -15,20 +15,24 @@ use cala_ledger::{
     CalaLedger, Currency, DebitOrCredit, JournalId, LedgerOperation, TransactionId,
     account::NewAccount,
     account_set::{AccountSet, AccountSetMemberId, AccountSetUpdate, NewAccountSet},
+    tx_template::Params,
     velocity::{NewVelocityControl, VelocityControlId},
 };
+use core_accounting::AccountingPeriodGuard;
 
 use crate::{
-    ChartOfAccountsIntegrationConfig, FacilityDurationType, Obligation,
+    ChartOfAccountsIntegrationConfig, Disbursal, FacilityDurationType, Obligation,
     ObligationDefaultedReallocationData, ObligationDueReallocationData,
     ObligationOverdueReallocationData,
     liquidation_process::LiquidationProcess,
     obligation_installment::ObligationInstallment,
     primitives::{
-        CalaAccountId, CalaAccountSetId, CollateralAction, CollateralUpdate, CreditFacilityId,
-        CustomerType, DisbursedReceivableAccountCategory, DisbursedReceivableAccountType,
+        CalaAccountId, CalaAccountSetId, CollateralAction, CollateralAsset,
+        CollateralAssetPositionUpdate, CollateralUpdate, CreditFacilityId, CustomerType,
+        DisbursedReceivableAccountCategory, DisbursedReceivableAccountType,
         InterestReceivableAccountType, LedgerOmnibusAccountIds, LedgerTxId, Satoshis, UsdCents,
     },
+    terms::HaircutPct,
 };
 
 pub use balance::*;
@@ -36,6 +40,16 @@ use constants::*;
 pub use credit_facility_accounts::*;
 use error::*;
 
+/// Ledger currency that non-BTC collateral positions are booked in. One XAUT
+/// token is backed by one troy ounce of gold, so it is booked as XAU.
+fn collateral_asset_currency(asset: CollateralAsset) -> Currency {
+    match asset {
+        CollateralAsset::Btc => Currency::BTC,
+        CollateralAsset::Eth => "ETH".parse().expect("ETH is a known currency"),
+        CollateralAsset::Xaut => "XAU".parse().expect("XAU is a known currency"),
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct InternalAccountSetDetails {
     id: CalaAccountSetId,
@@ -116,6 +130,11 @@ pub struct CreditFacilityInternalAccountSets {
     pub interest_defaulted: InternalAccountSetDetails,
     pub interest_income: InternalAccountSetDetails,
     pub fee_income: InternalAccountSetDetails,
+    pub unapplied_funds: InternalAccountSetDetails,
+    pub penalty_receivable: InternalAccountSetDetails,
+    pub penalty_income: InternalAccountSetDetails,
+    pub collateral_in_liquidation: InternalAccountSetDetails,
+    pub liquidation_proceeds: InternalAccountSetDetails,
 }
 
 impl CreditFacilityInternalAccountSets {
@@ -126,6 +145,11 @@ impl CreditFacilityInternalAccountSets {
             in_liquidation,
             interest_income,
             fee_income,
+            unapplied_funds,
+            penalty_receivable,
+            penalty_income,
+            collateral_in_liquidation,
+            liquidation_proceeds,
 
             disbursed_receivable:
                 DisbursedReceivable {
@@ -148,6 +172,11 @@ impl CreditFacilityInternalAccountSets {
             in_liquidation.id,
             interest_income.id,
             fee_income.id,
+            unapplied_funds.id,
+            penalty_receivable.id,
+            penalty_income.id,
+            collateral_in_liquidation.id,
+            liquidation_proceeds.id,
             disbursed_defaulted.id,
             interest_defaulted.id,
         ];
@@ -172,19 +201,29 @@ pub struct CreditLedger {
     facility_omnibus_account_ids: LedgerOmnibusAccountIds,
     collateral_omnibus_account_ids: LedgerOmnibusAccountIds,
     in_liquidation_omnibus_account_ids: LedgerOmnibusAccountIds,
+    liquidation_proceeds_omnibus_account_ids: LedgerOmnibusAccountIds,
     internal_account_sets: CreditFacilityInternalAccountSets,
     credit_facility_control_id: VelocityControlId,
+    period_guard: AccountingPeriodGuard,
     usd: Currency,
     btc: Currency,
 }
 
 impl CreditLedger {
-    pub async fn init(cala: &CalaLedger, journal_id: JournalId) -> Result<Self, CreditLedgerError> {
+    pub async fn init(
+        cala: &CalaLedger,
+        journal_id: JournalId,
+        period_guard: AccountingPeriodGuard,
+    ) -> Result<Self, CreditLedgerError> {
         templates::AddCollateral::init(cala).await?;
         templates::CreateCreditFacility::init(cala).await?;
         templates::ActivateCreditFacility::init(cala).await?;
         templates::RemoveCollateral::init(cala).await?;
         templates::RecordObligationInstallment::init(cala).await?;
+        templates::RecordPrepaymentFee::init(cala).await?;
+        templates::RecordUnappliedFunds::init(cala).await?;
+        templates::RefundUnappliedFunds::init(cala).await?;
+        templates::AccruePenalty::init(cala).await?;
         templates::RecordObligationDueBalance::init(cala).await?;
         templates::RecordObligationOverdueBalance::init(cala).await?;
         templates::RecordObligationDefaultedBalance::init(cala).await?;
@@ -194,6 +233,11 @@ impl CreditLedger {
         templates::CancelDisbursal::init(cala).await?;
         templates::ConfirmDisbursal::init(cala).await?;
         templates::ReserveForLiquidation::init(cala).await?;
+        templates::ReserveCollateralForLiquidation::init(cala).await?;
+        templates::RecordLiquidationSale::init(cala).await?;
+        templates::ReturnLiquidationSurplus::init(cala).await?;
+        templates::ReleaseLiquidationReserve::init(cala).await?;
+        templates::RolloverCreditFacility::init(cala).await?;
 
         let collateral_omnibus_normal_balance_type = DebitOrCredit::Debit;
         let collateral_omnibus_account_ids = Self::find_or_create_omnibus_account(
@@ -228,6 +272,17 @@ impl CreditLedger {
         )
         .await?;
 
+        let liquidation_proceeds_omnibus_normal_balance_type = DebitOrCredit::Debit;
+        let liquidation_proceeds_omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_REF}"),
+            CREDIT_LIQUIDATION_PROCEEDS_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            liquidation_proceeds_omnibus_normal_balance_type,
+        )
+        .await?;
+
         let facility_normal_balance_type = DebitOrCredit::Credit;
         let facility_account_set_id = Self::find_or_create_account_set(
             cala,
@@ -610,6 +665,56 @@ impl CreditLedger {
         )
         .await?;
 
+        let unapplied_funds_normal_balance_type = DebitOrCredit::Credit;
+        let unapplied_funds_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_UNAPPLIED_FUNDS_ACCOUNT_SET_REF}"),
+            CREDIT_UNAPPLIED_FUNDS_ACCOUNT_SET_NAME.to_string(),
+            unapplied_funds_normal_balance_type,
+        )
+        .await?;
+
+        let penalty_receivable_normal_balance_type = DebitOrCredit::Debit;
+        let penalty_receivable_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_PENALTY_RECEIVABLE_ACCOUNT_SET_REF}"),
+            CREDIT_PENALTY_RECEIVABLE_ACCOUNT_SET_NAME.to_string(),
+            penalty_receivable_normal_balance_type,
+        )
+        .await?;
+
+        let penalty_income_normal_balance_type = DebitOrCredit::Credit;
+        let penalty_income_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_PENALTY_INCOME_ACCOUNT_SET_REF}"),
+            CREDIT_PENALTY_INCOME_ACCOUNT_SET_NAME.to_string(),
+            penalty_income_normal_balance_type,
+        )
+        .await?;
+
+        let collateral_in_liquidation_normal_balance_type = DebitOrCredit::Credit;
+        let collateral_in_liquidation_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_COLLATERAL_IN_LIQUIDATION_ACCOUNT_SET_REF}"),
+            CREDIT_COLLATERAL_IN_LIQUIDATION_ACCOUNT_SET_NAME.to_string(),
+            collateral_in_liquidation_normal_balance_type,
+        )
+        .await?;
+
+        let liquidation_proceeds_normal_balance_type = DebitOrCredit::Credit;
+        let liquidation_proceeds_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{CREDIT_LIQUIDATION_PROCEEDS_ACCOUNT_SET_REF}"),
+            CREDIT_LIQUIDATION_PROCEEDS_ACCOUNT_SET_NAME.to_string(),
+            liquidation_proceeds_normal_balance_type,
+        )
+        .await?;
+
         let disbursed_receivable = DisbursedReceivable {
             short_term: DisbursedReceivableAccountSets {
                 individual: InternalAccountSetDetails {
@@ -797,6 +902,26 @@ impl CreditLedger {
                 id: fee_income_account_set_id,
                 normal_balance_type: fee_income_normal_balance_type,
             },
+            unapplied_funds: InternalAccountSetDetails {
+                id: unapplied_funds_account_set_id,
+                normal_balance_type: unapplied_funds_normal_balance_type,
+            },
+            penalty_receivable: InternalAccountSetDetails {
+                id: penalty_receivable_account_set_id,
+                normal_balance_type: penalty_receivable_normal_balance_type,
+            },
+            penalty_income: InternalAccountSetDetails {
+                id: penalty_income_account_set_id,
+                normal_balance_type: penalty_income_normal_balance_type,
+            },
+            collateral_in_liquidation: InternalAccountSetDetails {
+                id: collateral_in_liquidation_account_set_id,
+                normal_balance_type: collateral_in_liquidation_normal_balance_type,
+            },
+            liquidation_proceeds: InternalAccountSetDetails {
+                id: liquidation_proceeds_account_set_id,
+                normal_balance_type: liquidation_proceeds_normal_balance_type,
+            },
         };
 
         let disbursal_limit_id = velocity::DisbursalLimit::init(cala).await?;
@@ -819,8 +944,10 @@ impl CreditLedger {
             facility_omnibus_account_ids,
             collateral_omnibus_account_ids,
             in_liquidation_omnibus_account_ids,
+            liquidation_proceeds_omnibus_account_ids,
             internal_account_sets,
             credit_facility_control_id,
+            period_guard,
             usd: Currency::USD,
             btc: Currency::BTC,
         })
@@ -956,10 +1083,15 @@ impl CreditLedger {
             interest_receivable_due_account_id,
             interest_receivable_overdue_account_id,
             interest_defaulted_account_id,
+            penalty_receivable_account_id,
 
             in_liquidation_account_id: _,
             fee_income_account_id: _,
             interest_income_account_id: _,
+            unapplied_funds_account_id: _,
+            penalty_income_account_id: _,
+            collateral_in_liquidation_account_id: _,
+            liquidation_proceeds_account_id: _,
         }: CreditFacilityAccountIds,
     ) -> Result<CreditFacilityBalanceSummary, CreditLedgerError> {
         let facility_id = (self.journal_id, facility_account_id, self.usd);
@@ -996,6 +1128,7 @@ impl CreditLedger {
             self.usd,
         );
         let interest_defaulted_id = (self.journal_id, interest_defaulted_account_id, self.usd);
+        let penalty_receivable_id = (self.journal_id, penalty_receivable_account_id, self.usd);
         let balances = self
             .cala
             .balances()
@@ -1010,6 +1143,7 @@ impl CreditLedger {
                 interest_receivable_due_id,
                 interest_receivable_overdue_id,
                 interest_defaulted_id,
+                penalty_receivable_id,
             ])
             .await?;
         let facility = if let Some(b) = balances.get(&facility_id) {
@@ -1079,6 +1213,17 @@ impl CreditLedger {
             UsdCents::ZERO
         };
 
+        let penalty_posted = if let Some(b) = balances.get(&penalty_receivable_id) {
+            UsdCents::try_from_usd(b.details.settled.dr_balance)?
+        } else {
+            UsdCents::ZERO
+        };
+        let penalty_outstanding = if let Some(b) = balances.get(&penalty_receivable_id) {
+            UsdCents::try_from_usd(b.settled())?
+        } else {
+            UsdCents::ZERO
+        };
+
         let collateral = if let Some(b) = balances.get(&collateral_id) {
             Satoshis::try_from_btc(b.settled())?
         } else {
@@ -1101,9 +1246,32 @@ impl CreditLedger {
             due_interest_outstanding,
             overdue_interest_outstanding,
             interest_defaulted,
+
+            penalty_posted,
+            penalty_outstanding,
+
+            collateral_haircut: HaircutPct::ZERO,
+            other_collateral_value: UsdCents::ZERO,
         })
     }
 
+    async fn post_transaction_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        tx_id: TransactionId,
+        tx_template_code: &str,
+        params: impl Into<Params> + std::fmt::Debug,
+    ) -> Result<(), CreditLedgerError> {
+        let tx = self
+            .cala
+            .post_transaction_in_op(op, tx_id, tx_template_code, params)
+            .await?;
+        self.period_guard
+            .ensure_open_for_system_postings(tx.values().effective)
+            .await?;
+        Ok(())
+    }
+
     pub async fn update_credit_facility_collateral(
         &self,
         op: es_entity::DbOp<'_>,
@@ -1120,50 +1288,106 @@ impl CreditLedger {
             .ledger_operation_from_db_op(op.with_db_time().await?);
         match action {
             CollateralAction::Add => {
-                self.cala
-                    .post_transaction_in_op(
-                        &mut op,
-                        tx_id,
-                        templates::ADD_COLLATERAL_CODE,
-                        templates::AddCollateralParams {
-                            journal_id: self.journal_id,
-                            currency: self.btc,
-                            amount: abs_diff.to_btc(),
-                            collateral_account_id: credit_facility_account_ids
-                                .collateral_account_id,
-                            bank_collateral_account_id: self
-                                .collateral_omnibus_account_ids
-                                .account_id,
-                            effective,
-                        },
-                    )
-                    .await
+                self.post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::ADD_COLLATERAL_CODE,
+                    templates::AddCollateralParams {
+                        journal_id: self.journal_id,
+                        currency: self.btc,
+                        amount: abs_diff.to_btc(),
+                        collateral_account_id: credit_facility_account_ids.collateral_account_id,
+                        bank_collateral_account_id: self.collateral_omnibus_account_ids.account_id,
+                        effective,
+                    },
+                )
+                .await
             }
             CollateralAction::Remove => {
-                self.cala
-                    .post_transaction_in_op(
-                        &mut op,
-                        tx_id,
-                        templates::REMOVE_COLLATERAL_CODE,
-                        templates::RemoveCollateralParams {
-                            journal_id: self.journal_id,
-                            currency: self.btc,
-                            amount: abs_diff.to_btc(),
-                            collateral_account_id: credit_facility_account_ids
-                                .collateral_account_id,
-                            bank_collateral_account_id: self
-                                .collateral_omnibus_account_ids
-                                .account_id,
-                            effective,
-                        },
-                    )
-                    .await
+                self.post_transaction_in_op(
+                    &mut op,
+                    tx_id,
+                    templates::REMOVE_COLLATERAL_CODE,
+                    templates::RemoveCollateralParams {
+                        journal_id: self.journal_id,
+                        currency: self.btc,
+                        amount: abs_diff.to_btc(),
+                        collateral_account_id: credit_facility_account_ids.collateral_account_id,
+                        bank_collateral_account_id: self.collateral_omnibus_account_ids.account_id,
+                        effective,
+                    },
+                )
+                .await
             }
         }?;
         op.commit().await?;
         Ok(())
     }
 
+    pub async fn update_credit_facility_collateral_asset_position(
+        &self,
+        op: es_entity::DbOp<'_>,
+        update: CollateralAssetPositionUpdate,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+        self.update_collateral_asset_position_in_op(&mut op, update)
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn update_collateral_asset_position_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        CollateralAssetPositionUpdate {
+            tx_id,
+            collateral_account_id,
+            asset,
+            abs_diff,
+            action,
+            effective,
+        }: CollateralAssetPositionUpdate,
+    ) -> Result<(), CreditLedgerError> {
+        let currency = collateral_asset_currency(asset);
+        match action {
+            CollateralAction::Add => {
+                self.post_transaction_in_op(
+                    op,
+                    tx_id,
+                    templates::ADD_COLLATERAL_CODE,
+                    templates::AddCollateralParams {
+                        journal_id: self.journal_id,
+                        currency,
+                        amount: abs_diff,
+                        collateral_account_id,
+                        bank_collateral_account_id: self.collateral_omnibus_account_ids.account_id,
+                        effective,
+                    },
+                )
+                .await
+            }
+            CollateralAction::Remove => {
+                self.post_transaction_in_op(
+                    op,
+                    tx_id,
+                    templates::REMOVE_COLLATERAL_CODE,
+                    templates::RemoveCollateralParams {
+                        journal_id: self.journal_id,
+                        currency,
+                        amount: abs_diff,
+                        collateral_account_id,
+                        bank_collateral_account_id: self.collateral_omnibus_account_ids.account_id,
+                        effective,
+                    },
+                )
+                .await
+            }
+        }?;
+        Ok(())
+    }
+
     async fn record_obligation_repayment_in_op(
         &self,
         op: &mut LedgerOperation<'_>,
@@ -1185,22 +1409,154 @@ impl CreditLedger {
             tx_ref: installment.tx_ref(),
             effective,
         };
-        self.cala
-            .post_transaction_in_op(
-                op,
-                id.into(),
-                templates::RECORD_OBLIGATION_INSTALLMENT_CODE,
-                params,
+        self.post_transaction_in_op(
+            op,
+            id.into(),
+            templates::RECORD_OBLIGATION_INSTALLMENT_CODE,
+            params,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn record_obligation_installments(
+        &self,
+        op: es_entity::DbOp<'_>,
+        payments: Vec<ObligationInstallment>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+
+        for payment in payments {
+            self.record_obligation_repayment_in_op(&mut op, payment)
+                .await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn record_payment(
+        &self,
+        op: es_entity::DbOp<'_>,
+        payments: Vec<ObligationInstallment>,
+        unapplied_funds: Option<CreditFacilityUnappliedFunds>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+
+        for payment in payments {
+            self.record_obligation_repayment_in_op(&mut op, payment)
+                .await?;
+        }
+
+        if let Some(CreditFacilityUnappliedFunds {
+            tx_id,
+            tx_ref,
+            credit_facility_account_ids,
+            debit_account_id,
+            amount,
+            effective,
+        }) = unapplied_funds
+        {
+            self.post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::RECORD_UNAPPLIED_FUNDS_CODE,
+                templates::RecordUnappliedFundsParams {
+                    journal_id: self.journal_id,
+                    currency: self.usd,
+                    amount: amount.to_usd(),
+                    account_to_be_debited_id: debit_account_id,
+                    unapplied_funds_account_id: credit_facility_account_ids
+                        .unapplied_funds_account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
             )
             .await?;
+        }
 
+        op.commit().await?;
         Ok(())
     }
 
-    pub async fn record_obligation_installments(
+    pub async fn refund_unapplied_funds(
+        &self,
+        op: es_entity::DbOp<'_>,
+        CreditFacilityUnappliedFundsRefund {
+            tx_id,
+            tx_ref,
+            credit_facility_account_ids,
+            credit_account_id,
+            amount,
+            effective,
+        }: CreditFacilityUnappliedFundsRefund,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::REFUND_UNAPPLIED_FUNDS_CODE,
+            templates::RefundUnappliedFundsParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                amount: amount.to_usd(),
+                account_to_be_credited_id: credit_account_id,
+                unapplied_funds_account_id: credit_facility_account_ids.unapplied_funds_account_id,
+                external_id: tx_ref,
+                effective,
+            },
+        )
+        .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn record_penalty_accrual(
+        &self,
+        op: es_entity::DbOp<'_>,
+        CreditFacilityPenaltyAccrual {
+            tx_id,
+            tx_ref,
+            penalty,
+            effective,
+            credit_facility_account_ids,
+        }: CreditFacilityPenaltyAccrual,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::ACCRUE_PENALTY_CODE,
+            templates::AccruePenaltyParams {
+                journal_id: self.journal_id,
+                currency: self.usd,
+                penalty_amount: penalty.to_usd(),
+                penalty_receivable_account: credit_facility_account_ids
+                    .penalty_receivable_account_id,
+                penalty_income_account: credit_facility_account_ids.penalty_income_account_id,
+                external_id: tx_ref,
+                effective,
+            },
+        )
+        .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn record_prepayment(
         &self,
         op: es_entity::DbOp<'_>,
         payments: Vec<ObligationInstallment>,
+        fee: Option<CreditFacilityPrepaymentFee>,
     ) -> Result<(), CreditLedgerError> {
         let mut op = self
             .cala
@@ -1211,6 +1567,32 @@ impl CreditLedger {
                 .await?;
         }
 
+        if let Some(CreditFacilityPrepaymentFee {
+            tx_id,
+            tx_ref,
+            credit_facility_account_ids,
+            debit_account_id,
+            fee_amount,
+            effective,
+        }) = fee
+        {
+            self.post_transaction_in_op(
+                &mut op,
+                tx_id,
+                templates::RECORD_PREPAYMENT_FEE_CODE,
+                templates::RecordPrepaymentFeeParams {
+                    journal_id: self.journal_id,
+                    currency: self.usd,
+                    fee_amount: fee_amount.to_usd(),
+                    account_to_be_debited_id: debit_account_id,
+                    facility_fee_income_account: credit_facility_account_ids.fee_income_account_id,
+                    external_id: tx_ref,
+                    effective,
+                },
+            )
+            .await?;
+        }
+
         op.commit().await?;
         Ok(())
     }
@@ -1230,20 +1612,19 @@ impl CreditLedger {
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::RECORD_OBLIGATION_DUE_BALANCE_CODE,
-                templates::RecordObligationDueBalanceParams {
-                    journal_id: self.journal_id,
-                    amount: outstanding_amount.to_usd(),
-                    receivable_not_yet_due_account_id: not_yet_due_account_id,
-                    receivable_due_account_id: due_account_id,
-                    effective,
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::RECORD_OBLIGATION_DUE_BALANCE_CODE,
+            templates::RecordObligationDueBalanceParams {
+                journal_id: self.journal_id,
+                amount: outstanding_amount.to_usd(),
+                receivable_not_yet_due_account_id: not_yet_due_account_id,
+                receivable_due_account_id: due_account_id,
+                effective,
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1263,20 +1644,19 @@ impl CreditLedger {
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::RECORD_OBLIGATION_OVERDUE_BALANCE_CODE,
-                templates::RecordObligationOverdueBalanceParams {
-                    journal_id: self.journal_id,
-                    amount: outstanding_amount.to_usd(),
-                    receivable_due_account_id: due_account_id,
-                    receivable_overdue_account_id: overdue_account_id,
-                    effective,
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::RECORD_OBLIGATION_OVERDUE_BALANCE_CODE,
+            templates::RecordObligationOverdueBalanceParams {
+                journal_id: self.journal_id,
+                amount: outstanding_amount.to_usd(),
+                receivable_due_account_id: due_account_id,
+                receivable_overdue_account_id: overdue_account_id,
+                effective,
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1296,20 +1676,19 @@ impl CreditLedger {
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::RECORD_OBLIGATION_DEFAULTED_BALANCE_CODE,
-                templates::RecordObligationDefaultedBalanceParams {
-                    journal_id: self.journal_id,
-                    amount: outstanding_amount.to_usd(),
-                    receivable_account_id,
-                    defaulted_account_id,
-                    effective,
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::RECORD_OBLIGATION_DEFAULTED_BALANCE_CODE,
+            templates::RecordObligationDefaultedBalanceParams {
+                journal_id: self.journal_id,
+                amount: outstanding_amount.to_usd(),
+                receivable_account_id,
+                defaulted_account_id,
+                effective,
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1328,53 +1707,172 @@ impl CreditLedger {
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                ledger_tx_id,
-                templates::RESERVE_FOR_LIQUIDATION_CODE,
-                templates::ReserveForLiquidationParams {
-                    journal_id: self.journal_id,
-                    amount: outstanding.to_usd(),
-                    liquidation_omnibus_account_id: self
-                        .in_liquidation_omnibus_account_ids
-                        .account_id,
-                    facility_liquidation_account_id: in_liquidation_account_id,
-                    effective,
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            ledger_tx_id,
+            templates::RESERVE_FOR_LIQUIDATION_CODE,
+            templates::ReserveForLiquidationParams {
+                journal_id: self.journal_id,
+                amount: outstanding.to_usd(),
+                liquidation_omnibus_account_id: self.in_liquidation_omnibus_account_ids.account_id,
+                facility_liquidation_account_id: in_liquidation_account_id,
+                effective,
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
 
-    pub async fn complete_credit_facility(
+    pub async fn reserve_collateral_for_liquidation(
         &self,
         op: es_entity::DbOp<'_>,
-        CreditFacilityCompletion {
+        LiquidationCollateralReservation {
             tx_id,
             collateral,
             credit_facility_account_ids,
-        }: CreditFacilityCompletion,
+            effective,
+        }: LiquidationCollateralReservation,
     ) -> Result<(), CreditLedgerError> {
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
-        self.cala
-            .post_transaction_in_op(
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::RESERVE_COLLATERAL_FOR_LIQUIDATION_CODE,
+            templates::ReserveCollateralForLiquidationParams {
+                journal_id: self.journal_id,
+                currency: self.btc,
+                amount: collateral.to_btc(),
+                collateral_account_id: credit_facility_account_ids.collateral_account_id,
+                collateral_in_liquidation_account_id: credit_facility_account_ids
+                    .collateral_in_liquidation_account_id,
+                effective,
+            },
+        )
+        .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn record_liquidation_proceeds(
+        &self,
+        op: es_entity::DbOp<'_>,
+        LiquidationCollateralSale {
+            tx_id,
+            collateral,
+            proceeds,
+            credit_facility_account_ids,
+            effective,
+        }: LiquidationCollateralSale,
+        installments: Vec<ObligationInstallment>,
+        surplus: Option<LiquidationSurplusReturn>,
+        LiquidationReserveRelease {
+            tx_id: release_tx_id,
+            amount: reserved,
+            in_liquidation_account_id,
+            effective: released_at,
+        }: LiquidationReserveRelease,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::RECORD_LIQUIDATION_SALE_CODE,
+            templates::RecordLiquidationSaleParams {
+                journal_id: self.journal_id,
+                collateral_amount: collateral.to_btc(),
+                proceeds_amount: proceeds.to_usd(),
+                collateral_in_liquidation_account_id: credit_facility_account_ids
+                    .collateral_in_liquidation_account_id,
+                bank_collateral_account_id: self.collateral_omnibus_account_ids.account_id,
+                liquidation_proceeds_omnibus_account_id: self
+                    .liquidation_proceeds_omnibus_account_ids
+                    .account_id,
+                liquidation_proceeds_account_id: credit_facility_account_ids
+                    .liquidation_proceeds_account_id,
+                effective,
+            },
+        )
+        .await?;
+
+        for installment in installments {
+            self.record_obligation_repayment_in_op(&mut op, installment)
+                .await?;
+        }
+
+        if let Some(LiquidationSurplusReturn {
+            tx_id,
+            amount,
+            credit_facility_account_ids,
+            deposit_account_id,
+            effective,
+        }) = surplus
+        {
+            self.post_transaction_in_op(
                 &mut op,
                 tx_id,
-                templates::REMOVE_COLLATERAL_CODE,
-                templates::RemoveCollateralParams {
+                templates::RETURN_LIQUIDATION_SURPLUS_CODE,
+                templates::ReturnLiquidationSurplusParams {
                     journal_id: self.journal_id,
-                    currency: self.btc,
-                    amount: collateral.to_btc(),
-                    collateral_account_id: credit_facility_account_ids.collateral_account_id,
-                    bank_collateral_account_id: self.collateral_omnibus_account_ids.account_id,
-                    effective: crate::time::now().date_naive(),
+                    currency: self.usd,
+                    amount: amount.to_usd(),
+                    liquidation_proceeds_account_id: credit_facility_account_ids
+                        .liquidation_proceeds_account_id,
+                    deposit_account_id,
+                    effective,
                 },
             )
             .await?;
+        }
+
+        self.post_transaction_in_op(
+            &mut op,
+            release_tx_id,
+            templates::RELEASE_LIQUIDATION_RESERVE_CODE,
+            templates::ReleaseLiquidationReserveParams {
+                journal_id: self.journal_id,
+                amount: reserved.to_usd(),
+                liquidation_omnibus_account_id: self.in_liquidation_omnibus_account_ids.account_id,
+                facility_liquidation_account_id: in_liquidation_account_id,
+                effective: released_at,
+            },
+        )
+        .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn complete_credit_facility(
+        &self,
+        op: es_entity::DbOp<'_>,
+        CreditFacilityCompletion {
+            tx_id,
+            collateral,
+            credit_facility_account_ids,
+        }: CreditFacilityCompletion,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::REMOVE_COLLATERAL_CODE,
+            templates::RemoveCollateralParams {
+                journal_id: self.journal_id,
+                currency: self.btc,
+                amount: collateral.to_btc(),
+                collateral_account_id: credit_facility_account_ids.collateral_account_id,
+                bank_collateral_account_id: self.collateral_omnibus_account_ids.account_id,
+                effective: crate::time::now().date_naive(),
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1389,21 +1887,20 @@ impl CreditLedger {
             facility_amount,
         }: CreditFacilityCreation,
     ) -> Result<(), CreditLedgerError> {
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::CREATE_CREDIT_FACILITY_CODE,
-                templates::CreateCreditFacilityParams {
-                    journal_id: self.journal_id,
-                    credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
-                    credit_facility_account: credit_facility_account_ids.facility_account_id,
-                    facility_amount: facility_amount.to_usd(),
-                    currency: self.usd,
-                    external_id: tx_ref,
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::CREATE_CREDIT_FACILITY_CODE,
+            templates::CreateCreditFacilityParams {
+                journal_id: self.journal_id,
+                credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
+                credit_facility_account: credit_facility_account_ids.facility_account_id,
+                facility_amount: facility_amount.to_usd(),
+                currency: self.usd,
+                external_id: tx_ref,
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1421,26 +1918,25 @@ impl CreditLedger {
         }: CreditFacilityActivation,
     ) -> Result<(), CreditLedgerError> {
         let mut op = self.cala.ledger_operation_from_db_op(op);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::ACTIVATE_CREDIT_FACILITY_CODE,
-                templates::ActivateCreditFacilityParams {
-                    journal_id: self.journal_id,
-                    credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
-                    credit_facility_account: credit_facility_account_ids.facility_account_id,
-                    facility_disbursed_receivable_account: credit_facility_account_ids
-                        .disbursed_receivable_not_yet_due_account_id,
-                    facility_fee_income_account: credit_facility_account_ids.fee_income_account_id,
-                    debit_account_id,
-                    facility_amount: facility_amount.to_usd(),
-                    structuring_fee_amount: structuring_fee_amount.to_usd(),
-                    currency: self.usd,
-                    external_id: tx_ref,
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::ACTIVATE_CREDIT_FACILITY_CODE,
+            templates::ActivateCreditFacilityParams {
+                journal_id: self.journal_id,
+                credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
+                credit_facility_account: credit_facility_account_ids.facility_account_id,
+                facility_disbursed_receivable_account: credit_facility_account_ids
+                    .disbursed_receivable_not_yet_due_account_id,
+                facility_fee_income_account: credit_facility_account_ids.fee_income_account_id,
+                debit_account_id,
+                facility_amount: facility_amount.to_usd(),
+                structuring_fee_amount: structuring_fee_amount.to_usd(),
+                currency: self.usd,
+                external_id: tx_ref,
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1459,24 +1955,23 @@ impl CreditLedger {
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::CREDIT_FACILITY_ACCRUE_INTEREST_CODE,
-                templates::CreditFacilityAccrueInterestParams {
-                    journal_id: self.journal_id,
-
-                    credit_facility_interest_receivable_account: credit_facility_account_ids
-                        .interest_receivable_not_yet_due_account_id,
-                    credit_facility_interest_income_account: credit_facility_account_ids
-                        .interest_income_account_id,
-                    interest_amount: interest.to_usd(),
-                    external_id: tx_ref,
-                    effective: period.end.date_naive(),
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::CREDIT_FACILITY_ACCRUE_INTEREST_CODE,
+            templates::CreditFacilityAccrueInterestParams {
+                journal_id: self.journal_id,
+
+                credit_facility_interest_receivable_account: credit_facility_account_ids
+                    .interest_receivable_not_yet_due_account_id,
+                credit_facility_interest_income_account: credit_facility_account_ids
+                    .interest_income_account_id,
+                interest_amount: interest.to_usd(),
+                external_id: tx_ref,
+                effective: period.end.date_naive(),
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1495,24 +1990,23 @@ impl CreditLedger {
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::CREDIT_FACILITY_POST_ACCRUED_INTEREST_CODE,
-                templates::CreditFacilityPostAccruedInterestParams {
-                    journal_id: self.journal_id,
-
-                    credit_facility_interest_receivable_account: credit_facility_account_ids
-                        .interest_receivable_not_yet_due_account_id,
-                    credit_facility_interest_income_account: credit_facility_account_ids
-                        .interest_income_account_id,
-                    interest_amount: interest.to_usd(),
-                    external_id: tx_ref,
-                    effective,
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::CREDIT_FACILITY_POST_ACCRUED_INTEREST_CODE,
+            templates::CreditFacilityPostAccruedInterestParams {
+                journal_id: self.journal_id,
+
+                credit_facility_interest_receivable_account: credit_facility_account_ids
+                    .interest_receivable_not_yet_due_account_id,
+                credit_facility_interest_income_account: credit_facility_account_ids
+                    .interest_income_account_id,
+                interest_amount: interest.to_usd(),
+                external_id: tx_ref,
+                effective,
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1527,19 +2021,18 @@ impl CreditLedger {
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id.into(),
-                templates::INITIATE_DISBURSAL_CODE,
-                templates::InitiateDisbursalParams {
-                    journal_id: self.journal_id,
-                    credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
-                    credit_facility_account: facility_account_id,
-                    disbursed_amount: amount.to_usd(),
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id.into(),
+            templates::INITIATE_DISBURSAL_CODE,
+            templates::InitiateDisbursalParams {
+                journal_id: self.journal_id,
+                credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
+                credit_facility_account: facility_account_id,
+                disbursed_amount: amount.to_usd(),
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1552,19 +2045,18 @@ impl CreditLedger {
         facility_account_id: CalaAccountId,
     ) -> Result<(), CreditLedgerError> {
         let mut op = self.cala.ledger_operation_from_db_op(op);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::CANCEL_DISBURSAL_CODE,
-                templates::CancelDisbursalParams {
-                    journal_id: self.journal_id,
-                    credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
-                    credit_facility_account: facility_account_id,
-                    disbursed_amount: amount.to_usd(),
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::CANCEL_DISBURSAL_CODE,
+            templates::CancelDisbursalParams {
+                journal_id: self.journal_id,
+                credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
+                credit_facility_account: facility_account_id,
+                disbursed_amount: amount.to_usd(),
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1572,36 +2064,36 @@ impl CreditLedger {
     pub async fn settle_disbursal(
         &self,
         op: es_entity::DbOpWithTime<'_>,
-        obligation: Obligation,
+        tx_id: LedgerTxId,
+        disbursal: &Disbursal,
+        obligations: &[Obligation],
         facility_account_id: CalaAccountId,
     ) -> Result<(), CreditLedgerError> {
-        let facility_disbursed_receivable_account =
-            obligation.not_yet_due_accounts().receivable_account_id;
-        let account_to_be_credited_id = obligation.not_yet_due_accounts().account_to_be_credited_id;
-        let Obligation {
-            tx_id,
-            reference: external_id,
-            initial_amount: amount,
-            ..
-        } = obligation;
+        if obligations.is_empty() {
+            return Err(CreditLedgerError::DisbursalWithoutObligations(disbursal.id));
+        }
+        let amount = obligations
+            .iter()
+            .fold(UsdCents::ZERO, |acc, o| acc + o.initial_amount);
 
         let mut op = self.cala.ledger_operation_from_db_op(op);
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                tx_id,
-                templates::CONFIRM_DISBURSAL_CODE,
-                templates::ConfirmDisbursalParams {
-                    journal_id: self.journal_id,
-                    credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
-                    credit_facility_account: facility_account_id,
-                    facility_disbursed_receivable_account,
-                    account_to_be_credited_id,
-                    disbursed_amount: amount.to_usd(),
-                    external_id,
-                },
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::CONFIRM_DISBURSAL_CODE,
+            templates::ConfirmDisbursalParams {
+                journal_id: self.journal_id,
+                credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
+                credit_facility_account: facility_account_id,
+                facility_disbursed_receivable_account: disbursal
+                    .account_ids
+                    .disbursed_receivable_not_yet_due_account_id,
+                account_to_be_credited_id: disbursal.disbursal_credit_account_id,
+                disbursed_amount: amount.to_usd(),
+                external_id: disbursal.tx_ref(),
+            },
+        )
+        .await?;
         op.commit().await?;
         Ok(())
     }
@@ -1767,6 +2259,107 @@ impl CreditLedger {
         Ok(())
     }
 
+    pub(super) async fn handle_facility_rollover(
+        &self,
+        op: es_entity::DbOpWithTime<'_>,
+        credit_facility: &crate::CreditFacility,
+        customer_type: CustomerType,
+        duration_type: FacilityDurationType,
+        rollover: CreditFacilityRollover,
+        collateral_asset_position_updates: Vec<CollateralAssetPositionUpdate>,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self.cala.ledger_operation_from_db_op(op);
+
+        self.create_accounts_for_credit_facility(
+            &mut op,
+            credit_facility.id,
+            credit_facility.account_ids,
+            customer_type,
+            duration_type,
+        )
+        .await?;
+
+        self.add_credit_facility_control_to_account(
+            &mut op,
+            credit_facility.account_ids.facility_account_id,
+        )
+        .await?;
+
+        let rolled_over_amount =
+            rollover.rolled_over_amount() + rollover.capitalized_interest_amount();
+        let CreditFacilityRollover {
+            tx_id,
+            tx_ref,
+            rolled_over_account_ids: old,
+            credit_facility_account_ids,
+            debit_account_id,
+            facility_amount,
+            rolled_over_receivables,
+            capitalized_receivables,
+            structuring_fee_amount,
+            collateral,
+            effective,
+        } = rollover;
+        let receivable = |account_id: CalaAccountId| {
+            (
+                account_id,
+                rolled_over_receivables
+                    .get(&account_id)
+                    .or_else(|| capitalized_receivables.get(&account_id))
+                    .copied()
+                    .unwrap_or(UsdCents::ZERO)
+                    .to_usd(),
+            )
+        };
+
+        self.post_transaction_in_op(
+            &mut op,
+            tx_id,
+            templates::ROLLOVER_CREDIT_FACILITY_CODE,
+            templates::RolloverCreditFacilityParams {
+                journal_id: self.journal_id,
+                credit_omnibus_account: self.facility_omnibus_account_ids.account_id,
+                credit_facility_account: credit_facility_account_ids.facility_account_id,
+                facility_disbursed_receivable_account: credit_facility_account_ids
+                    .disbursed_receivable_not_yet_due_account_id,
+                facility_fee_income_account: credit_facility_account_ids.fee_income_account_id,
+                facility_collateral_account: credit_facility_account_ids.collateral_account_id,
+                debit_account_id,
+                rolled_over_collateral_account: old.collateral_account_id,
+                rolled_over_receivables: templates::RolledOverReceivableParams {
+                    disbursed_not_yet_due: receivable(
+                        old.disbursed_receivable_not_yet_due_account_id,
+                    ),
+                    disbursed_due: receivable(old.disbursed_receivable_due_account_id),
+                    disbursed_overdue: receivable(old.disbursed_receivable_overdue_account_id),
+                    disbursed_defaulted: receivable(old.disbursed_defaulted_account_id),
+                    interest_not_yet_due: receivable(
+                        old.interest_receivable_not_yet_due_account_id,
+                    ),
+                    interest_due: receivable(old.interest_receivable_due_account_id),
+                    interest_overdue: receivable(old.interest_receivable_overdue_account_id),
+                    interest_defaulted: receivable(old.interest_defaulted_account_id),
+                    penalty: receivable(old.penalty_receivable_account_id),
+                },
+                facility_amount: facility_amount.to_usd(),
+                rolled_over_amount: rolled_over_amount.to_usd(),
+                structuring_fee_amount: structuring_fee_amount.to_usd(),
+                collateral_amount: collateral.to_btc(),
+                external_id: tx_ref,
+                effective,
+            },
+        )
+        .await?;
+
+        for update in collateral_asset_position_updates {
+            self.update_collateral_asset_position_in_op(&mut op, update)
+                .await?;
+        }
+
+        op.commit().await?;
+        Ok(())
+    }
+
     async fn create_accounts_for_credit_facility(
         &self,
         op: &mut cala_ledger::LedgerOperation<'_>,
@@ -1789,6 +2382,11 @@ impl CreditLedger {
             interest_defaulted_account_id,
             interest_income_account_id,
             fee_income_account_id,
+            unapplied_funds_account_id: _,
+            penalty_receivable_account_id: _,
+            penalty_income_account_id: _,
+            collateral_in_liquidation_account_id: _,
+            liquidation_proceeds_account_id: _,
         } = account_ids;
 
         let collateral_reference = &format!("credit-facility-collateral:{credit_facility_id}");
@@ -1978,6 +2576,76 @@ impl CreditLedger {
         )
         .await?;
 
+        for (id, parent_account_set, reference, name) in
+            self.later_added_accounts(credit_facility_id, account_ids)
+        {
+            self.create_account_in_op(op, id, parent_account_set, &reference, &name, &name)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Accounts added to `CreditFacilityAccountIds` after facilities were already
+    /// being opened. Facilities opened before that get them through
+    /// `create_later_added_credit_facility_accounts`.
+    fn later_added_accounts(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        account_ids: CreditFacilityAccountIds,
+    ) -> Vec<(CalaAccountId, InternalAccountSetDetails, String, String)> {
+        vec![
+            (
+                account_ids.unapplied_funds_account_id,
+                self.internal_account_sets.unapplied_funds,
+                format!("credit-facility-unapplied-funds:{credit_facility_id}"),
+                format!("Unapplied Funds Account for Credit Facility {credit_facility_id}"),
+            ),
+            (
+                account_ids.penalty_receivable_account_id,
+                self.internal_account_sets.penalty_receivable,
+                format!("credit-facility-penalty-receivable:{credit_facility_id}"),
+                format!("Penalty Receivable Account for Credit Facility {credit_facility_id}"),
+            ),
+            (
+                account_ids.penalty_income_account_id,
+                self.internal_account_sets.penalty_income,
+                format!("credit-facility-penalty-income:{credit_facility_id}"),
+                format!("Penalty Income Account for Credit Facility {credit_facility_id}"),
+            ),
+            (
+                account_ids.collateral_in_liquidation_account_id,
+                self.internal_account_sets.collateral_in_liquidation,
+                format!("credit-facility-collateral-in-liquidation:{credit_facility_id}"),
+                format!(
+                    "Collateral In-Liquidation Account for Credit Facility {credit_facility_id}"
+                ),
+            ),
+            (
+                account_ids.liquidation_proceeds_account_id,
+                self.internal_account_sets.liquidation_proceeds,
+                format!("credit-facility-liquidation-proceeds:{credit_facility_id}"),
+                format!("Liquidation Proceeds Account for Credit Facility {credit_facility_id}"),
+            ),
+        ]
+    }
+
+    pub async fn create_later_added_credit_facility_accounts(
+        &self,
+        op: es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        account_ids: CreditFacilityAccountIds,
+    ) -> Result<(), CreditLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+        for (id, parent_account_set, reference, name) in
+            self.later_added_accounts(credit_facility_id, account_ids)
+        {
+            self.create_account_in_op(&mut op, id, parent_account_set, &reference, &name, &name)
+                .await?;
+        }
+        op.commit().await?;
         Ok(())
     }
 
@@ -2054,11 +2722,13 @@ impl CreditLedger {
             facility_omnibus_account_ids,
             collateral_omnibus_account_ids,
             in_liquidation_omnibus_account_ids,
+            liquidation_proceeds_omnibus_account_ids,
             internal_account_sets,
 
             cala: _,
             journal_id: _,
             credit_facility_control_id: _,
+            period_guard: _,
             usd: _,
             btc: _,
         } = self;
@@ -2067,6 +2737,7 @@ impl CreditLedger {
             facility_omnibus_account_ids.account_set_id,
             collateral_omnibus_account_ids.account_set_id,
             in_liquidation_omnibus_account_ids.account_set_id,
+            liquidation_proceeds_omnibus_account_ids.account_set_id,
         ];
         account_set_ids.extend(internal_account_sets.account_set_ids());
         let mut account_sets = self
@@ -2087,6 +2758,12 @@ impl CreditLedger {
             in_liquidation_parent_account_set_id,
             interest_income_parent_account_set_id,
             fee_income_parent_account_set_id,
+            unapplied_funds_parent_account_set_id,
+            penalty_receivable_parent_account_set_id,
+            penalty_income_parent_account_set_id,
+            collateral_in_liquidation_parent_account_set_id,
+            liquidation_proceeds_parent_account_set_id,
+            liquidation_proceeds_omnibus_parent_account_set_id,
             short_term_disbursed_integration_meta,
             long_term_disbursed_integration_meta,
             short_term_interest_integration_meta,
@@ -2169,6 +2846,90 @@ impl CreditLedger {
             |meta| meta.fee_income_parent_account_set_id,
         )
         .await?;
+        if let Some(parent_account_set_id) = unapplied_funds_parent_account_set_id {
+            self.attach_charts_account_set(
+                &mut op,
+                &mut account_sets,
+                self.internal_account_sets.unapplied_funds.id,
+                *parent_account_set_id,
+                &charts_integration_meta,
+                |meta| {
+                    meta.unapplied_funds_parent_account_set_id
+                        .unwrap_or(*parent_account_set_id)
+                },
+            )
+            .await?;
+        }
+        if let Some(parent_account_set_id) = penalty_receivable_parent_account_set_id {
+            self.attach_charts_account_set(
+                &mut op,
+                &mut account_sets,
+                self.internal_account_sets.penalty_receivable.id,
+                *parent_account_set_id,
+                &charts_integration_meta,
+                |meta| {
+                    meta.penalty_receivable_parent_account_set_id
+                        .unwrap_or(*parent_account_set_id)
+                },
+            )
+            .await?;
+        }
+        if let Some(parent_account_set_id) = penalty_income_parent_account_set_id {
+            self.attach_charts_account_set(
+                &mut op,
+                &mut account_sets,
+                self.internal_account_sets.penalty_income.id,
+                *parent_account_set_id,
+                &charts_integration_meta,
+                |meta| {
+                    meta.penalty_income_parent_account_set_id
+                        .unwrap_or(*parent_account_set_id)
+                },
+            )
+            .await?;
+        }
+        if let Some(parent_account_set_id) = collateral_in_liquidation_parent_account_set_id {
+            self.attach_charts_account_set(
+                &mut op,
+                &mut account_sets,
+                self.internal_account_sets.collateral_in_liquidation.id,
+                *parent_account_set_id,
+                &charts_integration_meta,
+                |meta| {
+                    meta.collateral_in_liquidation_parent_account_set_id
+                        .unwrap_or(*parent_account_set_id)
+                },
+            )
+            .await?;
+        }
+        if let Some(parent_account_set_id) = liquidation_proceeds_parent_account_set_id {
+            self.attach_charts_account_set(
+                &mut op,
+                &mut account_sets,
+                self.internal_account_sets.liquidation_proceeds.id,
+                *parent_account_set_id,
+                &charts_integration_meta,
+                |meta| {
+                    meta.liquidation_proceeds_parent_account_set_id
+                        .unwrap_or(*parent_account_set_id)
+                },
+            )
+            .await?;
+        }
+        if let Some(parent_account_set_id) = liquidation_proceeds_omnibus_parent_account_set_id {
+            self.attach_charts_account_set(
+                &mut op,
+                &mut account_sets,
+                self.liquidation_proceeds_omnibus_account_ids.account_set_id,
+                *parent_account_set_id,
+                &charts_integration_meta,
+                |meta| {
+                    meta.liquidation_proceeds_omnibus_parent_account_set_id
+                        .unwrap_or(*parent_account_set_id)
+                },
+            )
+            .await?;
+        }
 
         self.attach_short_term_disbursed_receivable_account_sets(
             &mut op,
@@ -2852,6 +3613,18 @@ pub struct ChartOfAccountsIntegrationMeta {
     pub in_liquidation_parent_account_set_id: CalaAccountSetId,
     pub interest_income_parent_account_set_id: CalaAccountSetId,
     pub fee_income_parent_account_set_id: CalaAccountSetId,
+    #[serde(default)]
+    pub unapplied_funds_parent_account_set_id: Option<CalaAccountSetId>,
+    #[serde(default)]
+    pub penalty_receivable_parent_account_set_id: Option<CalaAccountSetId>,
+    #[serde(default)]
+    pub penalty_income_parent_account_set_id: Option<CalaAccountSetId>,
+    #[serde(default)]
+    pub collateral_in_liquidation_parent_account_set_id: Option<CalaAccountSetId>,
+    #[serde(default)]
+    pub liquidation_proceeds_parent_account_set_id: Option<CalaAccountSetId>,
+    #[serde(default)]
+    pub liquidation_proceeds_omnibus_parent_account_set_id: Option<CalaAccountSetId>,
 
     pub short_term_disbursed_integration_meta: ShortTermDisbursedIntegrationMeta,
     pub long_term_disbursed_integration_meta: LongTermDisbursedIntegrationMeta,
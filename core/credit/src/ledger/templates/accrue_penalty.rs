@@ -0,0 +1,140 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const ACCRUE_PENALTY_CODE: &str = "ACCRUE_PENALTY";
+
+#[derive(Debug)]
+pub struct AccruePenaltyParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub penalty_amount: Decimal,
+    pub penalty_receivable_account: CalaAccountId,
+    pub penalty_income_account: CalaAccountId,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl AccruePenaltyParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("penalty_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("penalty_receivable_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("penalty_income_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<AccruePenaltyParams> for Params {
+    fn from(
+        AccruePenaltyParams {
+            journal_id,
+            currency,
+            penalty_amount,
+            penalty_receivable_account,
+            penalty_income_account,
+            external_id,
+            effective,
+        }: AccruePenaltyParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("penalty_amount", penalty_amount);
+        params.insert("penalty_receivable_account", penalty_receivable_account);
+        params.insert("penalty_income_account", penalty_income_account);
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+        params
+    }
+}
+
+pub struct AccruePenalty;
+
+impl AccruePenalty {
+    #[instrument(name = "ledger.accrue_penalty.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Accrue penalty interest on overdue credit facility balances'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'ACCRUE_PENALTY_DR'")
+                .currency("params.currency")
+                .account_id("params.penalty_receivable_account")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.penalty_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'ACCRUE_PENALTY_CR'")
+                .currency("params.currency")
+                .account_id("params.penalty_income_account")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.penalty_amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = AccruePenaltyParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(ACCRUE_PENALTY_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 mod accrue_interest;
+mod accrue_penalty;
 mod activate_credit_facility;
 mod add_collateral;
 mod cancel_disbursal;
@@ -10,10 +11,19 @@ mod obligation_due_balance;
 mod obligation_installment;
 mod obligation_overdue_balance;
 mod post_accrued_interest;
+mod record_liquidation_sale;
+mod record_prepayment_fee;
+mod record_unapplied_funds;
+mod refund_unapplied_funds;
+mod release_liquidation_reserve;
 mod remove_collateral;
+mod reserve_collateral_for_liquidation;
 mod reserve_for_liquidation;
+mod return_liquidation_surplus;
+mod rollover_credit_facility;
 
 pub use accrue_interest::*;
+pub use accrue_penalty::*;
 pub use activate_credit_facility::*;
 pub use add_collateral::*;
 pub use cancel_disbursal::*;
@@ -25,5 +35,13 @@ pub use obligation_due_balance::*;
 pub use obligation_installment::*;
 pub use obligation_overdue_balance::*;
 pub use post_accrued_interest::*;
+pub use record_liquidation_sale::*;
+pub use record_prepayment_fee::*;
+pub use record_unapplied_funds::*;
+pub use refund_unapplied_funds::*;
+pub use release_liquidation_reserve::*;
 pub use remove_collateral::*;
+pub use reserve_collateral_for_liquidation::*;
 pub use reserve_for_liquidation::*;
+pub use return_liquidation_surplus::*;
+pub use rollover_credit_facility::*;
@@ -0,0 +1,140 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RECORD_PREPAYMENT_FEE_CODE: &str = "RECORD_PREPAYMENT_FEE";
+
+#[derive(Debug)]
+pub struct RecordPrepaymentFeeParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub fee_amount: Decimal,
+    pub account_to_be_debited_id: CalaAccountId,
+    pub facility_fee_income_account: CalaAccountId,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+impl RecordPrepaymentFeeParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("fee_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("account_to_be_debited_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("facility_fee_income_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<RecordPrepaymentFeeParams> for Params {
+    fn from(
+        RecordPrepaymentFeeParams {
+            journal_id,
+            currency,
+            fee_amount,
+            account_to_be_debited_id,
+            facility_fee_income_account,
+            external_id,
+            effective,
+        }: RecordPrepaymentFeeParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("fee_amount", fee_amount);
+        params.insert("account_to_be_debited_id", account_to_be_debited_id);
+        params.insert("facility_fee_income_account", facility_fee_income_account);
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+        params
+    }
+}
+
+pub struct RecordPrepaymentFee;
+
+impl RecordPrepaymentFee {
+    #[instrument(name = "ledger.record_prepayment_fee.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Record a prepayment fee for credit facility'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_PREPAYMENT_FEE_DR'")
+                .currency("params.currency")
+                .account_id("params.account_to_be_debited_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.fee_amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RECORD_PREPAYMENT_FEE_CR'")
+                .currency("params.currency")
+                .account_id("params.facility_fee_income_account")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.fee_amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = RecordPrepaymentFeeParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RECORD_PREPAYMENT_FEE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RELEASE_LIQUIDATION_RESERVE_CODE: &str = "RELEASE_LIQUIDATION_RESERVE";
+
+#[derive(Debug)]
+pub struct ReleaseLiquidationReserveParams {
+    pub journal_id: JournalId,
+    pub amount: Decimal,
+    pub liquidation_omnibus_account_id: CalaAccountId,
+    pub facility_liquidation_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+impl ReleaseLiquidationReserveParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("liquidation_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("facility_liquidation_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<ReleaseLiquidationReserveParams> for Params {
+    fn from(
+        ReleaseLiquidationReserveParams {
+            journal_id,
+            amount,
+            liquidation_omnibus_account_id,
+            facility_liquidation_account_id,
+            effective,
+        }: ReleaseLiquidationReserveParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("amount", amount);
+        params.insert(
+            "liquidation_omnibus_account_id",
+            liquidation_omnibus_account_id,
+        );
+        params.insert(
+            "facility_liquidation_account_id",
+            facility_liquidation_account_id,
+        );
+        params.insert("effective", effective);
+        params
+    }
+}
+
+pub struct ReleaseLiquidationReserve;
+
+impl ReleaseLiquidationReserve {
+    #[instrument(name = "ledger.release_liquidation_reserve.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Release an amount reserved to be repaid via liquidation'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RELEASE_LIQUIDATION_RESERVE_DR'")
+                .currency("'USD'")
+                .account_id("params.facility_liquidation_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RELEASE_LIQUIDATION_RESERVE_CR'")
+                .currency("'USD'")
+                .account_id("params.liquidation_omnibus_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = ReleaseLiquidationReserveParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RELEASE_LIQUIDATION_RESERVE_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
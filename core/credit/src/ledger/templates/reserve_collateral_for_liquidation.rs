@@ -0,0 +1,134 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const RESERVE_COLLATERAL_FOR_LIQUIDATION_CODE: &str = "RESERVE_COLLATERAL_FOR_LIQUIDATION";
+
+#[derive(Debug)]
+pub struct ReserveCollateralForLiquidationParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub amount: Decimal,
+    pub collateral_account_id: CalaAccountId,
+    pub collateral_in_liquidation_account_id: CalaAccountId,
+    pub effective: chrono::NaiveDate,
+}
+
+impl ReserveCollateralForLiquidationParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("collateral_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("collateral_in_liquidation_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ]
+    }
+}
+
+impl From<ReserveCollateralForLiquidationParams> for Params {
+    fn from(
+        ReserveCollateralForLiquidationParams {
+            journal_id,
+            currency,
+            amount,
+            collateral_account_id,
+            collateral_in_liquidation_account_id,
+            effective,
+        }: ReserveCollateralForLiquidationParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("amount", amount);
+        params.insert("collateral_account_id", collateral_account_id);
+        params.insert(
+            "collateral_in_liquidation_account_id",
+            collateral_in_liquidation_account_id,
+        );
+        params.insert("effective", effective);
+        params
+    }
+}
+
+pub struct ReserveCollateralForLiquidation;
+
+impl ReserveCollateralForLiquidation {
+    #[instrument(name = "ledger.reserve_collateral_for_liquidation.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .description("'Reserve collateral to be sold in a liquidation'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let entries = vec![
+            NewTxTemplateEntry::builder()
+                .entry_type("'RESERVE_COLLATERAL_FOR_LIQUIDATION_DR'")
+                .currency("params.currency")
+                .account_id("params.collateral_account_id")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .entry_type("'RESERVE_COLLATERAL_FOR_LIQUIDATION_CR'")
+                .currency("params.currency")
+                .account_id("params.collateral_in_liquidation_account_id")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .units("params.amount")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        let params = ReserveCollateralForLiquidationParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(RESERVE_COLLATERAL_FOR_LIQUIDATION_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use rust_decimal::Decimal;
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{ledger::error::*, primitives::CalaAccountId};
+
+pub const ROLLOVER_CREDIT_FACILITY_CODE: &str = "ROLLOVER_CREDIT_FACILITY";
+
+#[derive(Debug)]
+pub struct RolloverCreditFacilityParams {
+    pub journal_id: JournalId,
+    pub credit_omnibus_account: CalaAccountId,
+    pub credit_facility_account: CalaAccountId,
+    pub facility_disbursed_receivable_account: CalaAccountId,
+    pub facility_fee_income_account: CalaAccountId,
+    pub facility_collateral_account: CalaAccountId,
+    pub debit_account_id: CalaAccountId,
+    pub rolled_over_collateral_account: CalaAccountId,
+    pub rolled_over_receivables: RolledOverReceivableParams,
+    pub facility_amount: Decimal,
+    pub rolled_over_amount: Decimal,
+    pub structuring_fee_amount: Decimal,
+    pub collateral_amount: Decimal,
+    pub external_id: String,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug)]
+pub struct RolledOverReceivableParams {
+    pub disbursed_not_yet_due: (CalaAccountId, Decimal),
+    pub disbursed_due: (CalaAccountId, Decimal),
+    pub disbursed_overdue: (CalaAccountId, Decimal),
+    pub disbursed_defaulted: (CalaAccountId, Decimal),
+    pub interest_not_yet_due: (CalaAccountId, Decimal),
+    pub interest_due: (CalaAccountId, Decimal),
+    pub interest_overdue: (CalaAccountId, Decimal),
+    pub interest_defaulted: (CalaAccountId, Decimal),
+    pub penalty: (CalaAccountId, Decimal),
+}
+
+impl RolledOverReceivableParams {
+    const NAMES: [&'static str; 9] = [
+        "disbursed_not_yet_due",
+        "disbursed_due",
+        "disbursed_overdue",
+        "disbursed_defaulted",
+        "interest_not_yet_due",
+        "interest_due",
+        "interest_overdue",
+        "interest_defaulted",
+        "penalty",
+    ];
+
+    fn into_iter(self) -> impl Iterator<Item = (&'static str, (CalaAccountId, Decimal))> {
+        Self::NAMES.into_iter().zip([
+            self.disbursed_not_yet_due,
+            self.disbursed_due,
+            self.disbursed_overdue,
+            self.disbursed_defaulted,
+            self.interest_not_yet_due,
+            self.interest_due,
+            self.interest_overdue,
+            self.interest_defaulted,
+            self.penalty,
+        ])
+    }
+}
+
+impl RolloverCreditFacilityParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        let mut defs = vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_omnibus_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("credit_facility_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("facility_disbursed_receivable_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("facility_fee_income_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("facility_collateral_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("debit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("rolled_over_collateral_account")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("facility_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("rolled_over_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("structuring_fee_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("collateral_amount")
+                .r#type(ParamDataType::Decimal)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ];
+        for name in RolledOverReceivableParams::NAMES {
+            defs.push(
+                NewParamDefinition::builder()
+                    .name(format!("rolled_over_{name}_account"))
+                    .r#type(ParamDataType::Uuid)
+                    .build()
+                    .unwrap(),
+            );
+            defs.push(
+                NewParamDefinition::builder()
+                    .name(format!("rolled_over_{name}_amount"))
+                    .r#type(ParamDataType::Decimal)
+                    .build()
+                    .unwrap(),
+            );
+        }
+        defs
+    }
+}
+
+impl From<RolloverCreditFacilityParams> for Params {
+    fn from(
+        RolloverCreditFacilityParams {
+            journal_id,
+            credit_omnibus_account,
+            credit_facility_account,
+            facility_disbursed_receivable_account,
+            facility_fee_income_account,
+            facility_collateral_account,
+            debit_account_id,
+            rolled_over_collateral_account,
+            rolled_over_receivables,
+            facility_amount,
+            rolled_over_amount,
+            structuring_fee_amount,
+            collateral_amount,
+            external_id,
+            effective,
+        }: RolloverCreditFacilityParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("credit_omnibus_account", credit_omnibus_account);
+        params.insert("credit_facility_account", credit_facility_account);
+        params.insert(
+            "facility_disbursed_receivable_account",
+            facility_disbursed_receivable_account,
+        );
+        params.insert("facility_fee_income_account", facility_fee_income_account);
+        params.insert("facility_collateral_account", facility_collateral_account);
+        params.insert("debit_account_id", debit_account_id);
+        params.insert(
+            "rolled_over_collateral_account",
+            rolled_over_collateral_account,
+        );
+        for (name, (account_id, amount)) in rolled_over_receivables.into_iter() {
+            params.insert(format!("rolled_over_{name}_account"), account_id);
+            params.insert(format!("rolled_over_{name}_amount"), amount);
+        }
+        params.insert("facility_amount", facility_amount);
+        params.insert("rolled_over_amount", rolled_over_amount);
+        params.insert("structuring_fee_amount", structuring_fee_amount);
+        params.insert("collateral_amount", collateral_amount);
+        params.insert("external_id", external_id);
+        params.insert("effective", effective);
+        params
+    }
+}
+
+pub struct RolloverCreditFacility;
+
+impl RolloverCreditFacility {
+    #[instrument(name = "ledger.rollover_credit_facility.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), CreditLedgerError> {
+        let tx_input = NewTxTemplateTransaction::builder()
+            .journal_id("params.journal_id")
+            .effective("params.effective")
+            .external_id("params.external_id")
+            .description("'Roll over credit facility'")
+            .build()
+            .expect("Couldn't build TxInput");
+
+        let mut entries = vec![
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_omnibus_account")
+                .units("params.facility_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_SETTLED_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_facility_account")
+                .units("params.facility_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_SETTLED_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_facility_account")
+                .units("params.rolled_over_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_DRAWDOWN_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_omnibus_account")
+                .units("params.rolled_over_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_DRAWDOWN_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.facility_disbursed_receivable_account")
+                .units("params.rolled_over_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_RECEIVABLE_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+        ];
+
+        for name in RolledOverReceivableParams::NAMES {
+            entries.push(
+                NewTxTemplateEntry::builder()
+                    .account_id(format!("params.rolled_over_{name}_account"))
+                    .units(format!("params.rolled_over_{name}_amount"))
+                    .currency("'USD'")
+                    .entry_type(format!(
+                        "'ROLLOVER_CREDIT_FACILITY_{}_RECEIVABLE_CR'",
+                        name.to_uppercase()
+                    ))
+                    .direction("CREDIT")
+                    .layer("SETTLED")
+                    .build()
+                    .expect("Couldn't build entry"),
+            );
+        }
+
+        entries.extend([
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_facility_account")
+                .units("params.structuring_fee_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_FEE_DRAWDOWN_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.credit_omnibus_account")
+                .units("params.structuring_fee_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_FEE_DRAWDOWN_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.facility_disbursed_receivable_account")
+                .units("params.structuring_fee_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_FEE_DISBURSEMENT_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.debit_account_id")
+                .units("params.structuring_fee_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_FEE_DISBURSEMENT_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.debit_account_id")
+                .units("params.structuring_fee_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_STRUCTURING_FEE_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.facility_fee_income_account")
+                .units("params.structuring_fee_amount")
+                .currency("'USD'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_STRUCTURING_FEE_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.rolled_over_collateral_account")
+                .units("params.collateral_amount")
+                .currency("'BTC'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_COLLATERAL_DR'")
+                .direction("DEBIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+            NewTxTemplateEntry::builder()
+                .account_id("params.facility_collateral_account")
+                .units("params.collateral_amount")
+                .currency("'BTC'")
+                .entry_type("'ROLLOVER_CREDIT_FACILITY_COLLATERAL_CR'")
+                .direction("CREDIT")
+                .layer("SETTLED")
+                .build()
+                .expect("Couldn't build entry"),
+        ]);
+
+        let params = RolloverCreditFacilityParams::defs();
+        let template = NewTxTemplate::builder()
+            .id(TxTemplateId::new())
+            .code(ROLLOVER_CREDIT_FACILITY_CODE)
+            .transaction(tx_input)
+            .entries(entries)
+            .params(params)
+            .build()
+            .expect("Couldn't build template");
+
+        match ledger.tx_templates().create(template).await {
+            Err(TxTemplateError::DuplicateCode) => Ok(()),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+}
@@ -7,6 +7,7 @@
 
 mod chart_of_accounts_integration;
 mod collateral;
+mod collateral_release;
 mod config;
 mod credit_facility;
 mod disbursal;
@@ -32,6 +33,7 @@ mod time;
 use audit::{AuditInfo, AuditSvc};
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
+use core_accounting::AccountingPeriodGuard;
 use core_custody::{
     CoreCustody, CoreCustodyAction, CoreCustodyEvent, CoreCustodyObject, CustodianId,
 };
@@ -48,6 +50,9 @@ pub use chart_of_accounts_integration::{
     ChartOfAccountsIntegrations, error::ChartOfAccountsIntegrationError,
 };
 pub use collateral::*;
+pub use collateral_release::{
+    CollateralRelease, CollateralReleases, error::CollateralReleaseError,
+};
 pub use config::*;
 pub use credit_facility::error::CreditFacilityError;
 pub use credit_facility::*;
@@ -59,13 +64,18 @@ pub use history::*;
 pub use interest_accrual_cycle::*;
 use jobs::*;
 pub use ledger::*;
+pub use liquidation_process::LiquidationProcess;
 pub use obligation::{error::*, obligation_cursor::*, *};
 pub use obligation_installment::*;
 pub use payment::*;
 pub use primitives::*;
 use processes::activate_credit_facility::*;
+use processes::apply_unapplied_funds::*;
+pub use processes::approve_collateral_release::*;
 pub use processes::approve_credit_facility::*;
 pub use processes::approve_disbursal::*;
+pub use processes::restructure_credit_facility::*;
+pub use processes::rollover_credit_facility::*;
 use publisher::CreditFacilityPublisher;
 pub use repayment_plan::*;
 pub use terms::*;
@@ -74,7 +84,8 @@ pub use terms_template::{error as terms_template_error, *};
 #[cfg(feature = "json-schema")]
 pub mod event_schema {
     pub use crate::{
-        TermsTemplateEvent, collateral::CollateralEvent, credit_facility::CreditFacilityEvent,
+        TermsTemplateEvent, collateral::CollateralEvent,
+        collateral_release::CollateralReleaseEvent, credit_facility::CreditFacilityEvent,
         disbursal::DisbursalEvent, interest_accrual_cycle::InterestAccrualCycleEvent,
         liquidation_process::LiquidationProcessEvent, obligation::ObligationEvent,
         obligation_installment::ObligationInstallmentEvent, payment::PaymentEvent,
@@ -105,6 +116,7 @@ where
     approve_credit_facility: ApproveCreditFacility<Perms, E>,
     obligations: Obligations<Perms, E>,
     collaterals: Collaterals<Perms, E>,
+    collateral_releases: CollateralReleases<Perms, E>,
     custody: CoreCustody<Perms, E>,
     chart_of_accounts_integrations: ChartOfAccountsIntegrations<Perms>,
     terms_templates: TermsTemplates<Perms>,
@@ -125,6 +137,7 @@ where
             facilities: self.facilities.clone(),
             obligations: self.obligations.clone(),
             collaterals: self.collaterals.clone(),
+            collateral_releases: self.collateral_releases.clone(),
             custody: self.custody.clone(),
             disbursals: self.disbursals.clone(),
             payments: self.payments.clone(),
@@ -177,7 +190,7 @@ where
         public_ids: &PublicIds,
     ) -> Result<Self, CoreCreditError> {
         let publisher = CreditFacilityPublisher::new(outbox);
-        let ledger = CreditLedger::init(cala, journal_id).await?;
+        let ledger = CreditLedger::init(cala, journal_id, AccountingPeriodGuard::new(pool)).await?;
         let obligations = Obligations::new(pool, authz, &ledger, jobs, &publisher);
         let credit_facilities = CreditFacilities::new(
             pool,
@@ -189,7 +202,30 @@ where
             governance,
         )
         .await;
+        credit_facilities.open_missing_ledger_accounts().await?;
+        match governance
+            .init_policy(APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS)
+            .await
+        {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+        match governance
+            .init_policy(APPROVE_CREDIT_FACILITY_ROLLOVER_PROCESS)
+            .await
+        {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
         let collaterals = Collaterals::new(pool, authz, &publisher, &ledger);
+        let collateral_releases =
+            CollateralReleases::new(pool, authz, &publisher, governance).await;
         let disbursals = Disbursals::new(pool, authz, &publisher, &obligations, governance).await;
         let payments = Payments::new(pool, authz);
         let history_repo = HistoryRepo::new(pool);
@@ -199,6 +235,7 @@ where
 
         let approve_credit_facility =
             ApproveCreditFacility::new(&credit_facilities, authz.audit(), governance);
+        let restructure_credit_facility = RestructureCreditFacility::new(&credit_facilities, jobs);
         let activate_credit_facility = ActivateCreditFacility::new(
             &credit_facilities,
             &disbursals,
@@ -208,6 +245,33 @@ where
             authz.audit(),
             public_ids,
         );
+        let rollover_credit_facility = RolloverCreditFacility::new(
+            &credit_facilities,
+            &obligations,
+            &collaterals,
+            &disbursals,
+            &ledger,
+            jobs,
+            public_ids,
+        );
+        let approve_collateral_release = ApproveCollateralRelease::new(
+            &collateral_releases,
+            &credit_facilities,
+            &collaterals,
+            custody,
+            &ledger,
+            price,
+            authz.audit(),
+            jobs,
+            config.collateral_release_cvl_pct,
+        );
+        let apply_unapplied_funds = ApplyUnappliedFunds::new(
+            &credit_facilities,
+            &obligations,
+            &payments,
+            &ledger,
+            authz.audit(),
+        );
         let chart_of_accounts_integrations = ChartOfAccountsIntegrations::new(authz, &ledger);
         let terms_templates = TermsTemplates::new(pool, authz);
 
@@ -258,6 +322,11 @@ where
             &credit_facilities,
             jobs,
         ));
+        jobs.add_initializer(penalty_accruals::PenaltyAccrualInit::<Perms, E>::new(
+            &ledger,
+            &obligations,
+            &credit_facilities,
+        ));
         jobs.add_initializer(
             interest_accrual_cycles::InterestAccrualCycleInit::<Perms, E>::new(
                 &ledger,
@@ -292,16 +361,37 @@ where
             CreditFacilityApprovalJobConfig::<Perms, E>::new(),
         )
         .await?;
+        jobs.add_initializer_and_spawn_unique(
+            CreditFacilityRestructuringInit::new(outbox, &restructure_credit_facility),
+            CreditFacilityRestructuringJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+        jobs.add_initializer_and_spawn_unique(
+            CreditFacilityRolloverInit::new(outbox, &rollover_credit_facility),
+            CreditFacilityRolloverJobConfig::<Perms, E>::new(),
+        )
+        .await?;
         jobs.add_initializer_and_spawn_unique(
             DisbursalApprovalInit::new(outbox, &approve_disbursal),
             DisbursalApprovalJobConfig::<Perms, E>::new(),
         )
         .await?;
+        jobs.add_initializer(CollateralReleaseSendInit::new(&approve_collateral_release));
+        jobs.add_initializer_and_spawn_unique(
+            CollateralReleaseApprovalInit::new(outbox, &approve_collateral_release),
+            CollateralReleaseApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
         jobs.add_initializer_and_spawn_unique(
             CreditFacilityActivationInit::new(outbox, &activate_credit_facility),
             CreditFacilityActivationJobConfig::<Perms, E>::new(),
         )
         .await?;
+        jobs.add_initializer_and_spawn_unique(
+            ApplyUnappliedFundsInit::new(outbox, &apply_unapplied_funds),
+            ApplyUnappliedFundsJobConfig::<Perms, E>::new(),
+        )
+        .await?;
 
         jobs.add_initializer_and_spawn_unique(
             wallet_collateral_sync::WalletCollateralSyncInit::new(
@@ -319,6 +409,7 @@ where
             facilities: credit_facilities,
             obligations,
             collaterals,
+            collateral_releases,
             custody: custody.clone(),
             disbursals,
             payments,
@@ -345,6 +436,10 @@ where
         &self.collaterals
     }
 
+    pub fn collateral_releases(&self) -> &CollateralReleases<Perms, E> {
+        &self.collateral_releases
+    }
+
     pub fn disbursals(&self) -> &Disbursals<Perms, E> {
         &self.disbursals
     }
@@ -495,7 +590,7 @@ where
                 db,
                 &credit_facility,
                 customer.customer_type,
-                terms.duration.duration_type(),
+                credit_facility.terms.duration.duration_type(),
             )
             .await?;
 
@@ -585,8 +680,8 @@ where
             return Err(CreditFacilityError::DisbursalPastMaturityDate.into());
         }
         let balance = self
-            .ledger
-            .get_credit_facility_balance(facility.account_ids)
+            .facilities
+            .balances_with_collateral_valuation(&facility)
             .await?;
 
         let price = self.price.usd_cents_per_btc().await?;
@@ -605,6 +700,10 @@ where
             .terms
             .obligation_liquidation_duration_from_due
             .map(|d| d.end_date(due_date));
+        let principal_installments = match facility.terms.principal_repayment {
+            PrincipalRepayment::AtMaturity => vec![],
+            _ => facility.terms.principal_installments(amount, now, due_date),
+        };
 
         let public_id = self
             .public_ids
@@ -621,6 +720,7 @@ where
             .due_date(due_date)
             .overdue_date(overdue_date)
             .liquidation_date(liquidation_date)
+            .principal_installments(principal_installments)
             .audit_info(audit_info)
             .public_id(public_id.id)
             .build()?;
@@ -717,6 +817,392 @@ where
         Ok(credit_facility)
     }
 
+    #[instrument(name = "credit.update_collateral_asset_position", skip(self), err)]
+    pub async fn update_collateral_asset_position(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        asset: CollateralAsset,
+        quantity: rust_decimal::Decimal,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let credit_facility_id = credit_facility_id.into();
+
+        let audit_info = self
+            .subject_can_update_collateral(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?;
+        if credit_facility.status() == CreditFacilityStatus::Closed {
+            return Err(CreditFacilityError::AlreadyCompleted.into());
+        }
+
+        let mut db = self.facilities.begin_op().await?;
+        let Some(asset_position_update) = self
+            .collaterals
+            .record_asset_position_update_in_op(
+                &mut db,
+                credit_facility.collateral_id,
+                asset,
+                quantity,
+                crate::time::now().date_naive(),
+                &audit_info,
+            )
+            .await?
+        else {
+            return Ok(credit_facility);
+        };
+
+        self.ledger
+            .update_credit_facility_collateral_asset_position(db, asset_position_update)
+            .await?;
+
+        Ok(credit_facility)
+    }
+
+    #[instrument(name = "credit.reserve_collateral_for_liquidation", skip(self), err)]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn reserve_collateral_for_liquidation(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        liquidation_process_id: impl Into<LiquidationProcessId> + std::fmt::Debug + Copy,
+        collateral: Satoshis,
+    ) -> Result<LiquidationProcess, CoreCreditError> {
+        let liquidation_process_id = liquidation_process_id.into();
+
+        let audit_info = self
+            .subject_can_update_collateral(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let liquidation_process = self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?;
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(liquidation_process.credit_facility_id)
+            .await?;
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let reservation = if let Some(reservation) = self
+            .obligations
+            .reserve_collateral_for_liquidation_in_op(
+                &mut db,
+                liquidation_process_id,
+                collateral,
+                credit_facility.account_ids,
+                crate::time::now().date_naive(),
+                &audit_info,
+            )
+            .await?
+        {
+            reservation
+        } else {
+            return Ok(liquidation_process);
+        };
+
+        self.collaterals
+            .record_collateral_reserved_for_liquidation_in_op(
+                &mut db,
+                credit_facility.collateral_id,
+                liquidation_process_id,
+                &reservation,
+                &audit_info,
+            )
+            .await?;
+
+        self.ledger
+            .reserve_collateral_for_liquidation(db, reservation)
+            .await?;
+
+        Ok(self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?)
+    }
+
+    #[instrument(name = "credit.record_liquidation_sale", skip(self), err)]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn record_liquidation_sale(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        liquidation_process_id: impl Into<LiquidationProcessId> + std::fmt::Debug + Copy,
+        price: PriceOfOneBTC,
+    ) -> Result<LiquidationProcess, CoreCreditError> {
+        let liquidation_process_id = liquidation_process_id.into();
+
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_obligations(),
+                CoreCreditAction::OBLIGATION_RECORD_PAYMENT,
+            )
+            .await?;
+
+        let liquidation_process = self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?;
+        let credit_facility_id = liquidation_process.credit_facility_id;
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?;
+        let effective = crate::time::now().date_naive();
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let sale = if let Some(sale) = self
+            .obligations
+            .record_liquidation_sale_in_op(
+                &mut db,
+                liquidation_process_id,
+                price,
+                credit_facility.account_ids,
+                effective,
+                &audit_info,
+            )
+            .await?
+        {
+            sale
+        } else {
+            return Ok(liquidation_process);
+        };
+
+        let payment = self
+            .payments
+            .record_in_op(&mut db, credit_facility_id, sale.proceeds, &audit_info)
+            .await?;
+
+        let installments = self
+            .obligations
+            .apply_installment_in_op(
+                &mut db,
+                InstallmentPayment {
+                    credit_facility_id,
+                    payment_id: payment.id,
+                    amount: sale.proceeds,
+                    strategy: credit_facility.terms.payment_allocation_strategy,
+                    payment_source_account_id: Some(
+                        credit_facility.account_ids.liquidation_proceeds_account_id,
+                    ),
+                    effective,
+                },
+                &audit_info,
+            )
+            .await?;
+
+        let allocated = installments
+            .iter()
+            .fold(UsdCents::ZERO, |total, installment| {
+                total + installment.amount
+            });
+
+        let (surplus, release) = self
+            .obligations
+            .complete_liquidation_in_op(
+                &mut db,
+                liquidation_process_id,
+                payment.id,
+                allocated,
+                credit_facility.account_ids,
+                credit_facility.disbursal_credit_account_id,
+                effective,
+                &audit_info,
+            )
+            .await?;
+
+        self.ledger
+            .record_liquidation_proceeds(db, sale, installments, surplus, release)
+            .await?;
+
+        Ok(self
+            .obligations
+            .find_liquidation_process_by_id_without_audit(liquidation_process_id)
+            .await?)
+    }
+
+    pub async fn subject_can_restructure_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_RESTRUCTURE,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit.restructure_facility", skip(self), err)]
+    pub async fn restructure_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        terms: TermValues,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let audit_info = self
+            .subject_can_restructure_facility(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        Ok(self
+            .facilities
+            .request_terms_modification(credit_facility_id.into(), terms, audit_info)
+            .await?)
+    }
+
+    pub async fn subject_can_rollover_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_ROLLOVER,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit.rollover_facility", skip(self), err)]
+    pub async fn rollover_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        amount: UsdCents,
+        terms: TermValues,
+        capitalize_interest: bool,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let audit_info = self
+            .subject_can_rollover_facility(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id.into())
+            .await?;
+        let customer = self
+            .customer
+            .find_by_id_without_audit(credit_facility.customer_id)
+            .await?;
+        if self.config.customer_active_check_enabled && customer.status.is_inactive() {
+            return Err(CoreCreditError::CustomerNotActive);
+        }
+
+        Ok(self
+            .facilities
+            .request_rollover(
+                credit_facility.id,
+                amount,
+                terms,
+                customer.customer_type,
+                capitalize_interest,
+                audit_info,
+            )
+            .await?)
+    }
+
+    pub async fn subject_can_request_collateral_release(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        enforce: bool,
+    ) -> Result<Option<AuditInfo>, CoreCreditError> {
+        Ok(self
+            .authz
+            .evaluate_permission(
+                sub,
+                CoreCreditObject::all_credit_facilities(),
+                CoreCreditAction::CREDIT_FACILITY_REQUEST_COLLATERAL_RELEASE,
+                enforce,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "credit.request_collateral_release", skip(self), err)]
+    pub async fn request_collateral_release(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        amount: Satoshis,
+        address: impl Into<String> + std::fmt::Debug,
+    ) -> Result<CollateralRelease, CoreCreditError> {
+        let audit_info = self
+            .subject_can_request_collateral_release(sub, true)
+            .await?
+            .expect("audit info missing");
+
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id.into())
+            .await?;
+        if !credit_facility.is_activated() {
+            return Err(CreditFacilityError::NotActivatedYet.into());
+        }
+        if credit_facility.is_completed() {
+            return Err(CreditFacilityError::AlreadyCompleted.into());
+        }
+
+        let balance = self
+            .facilities
+            .balances_with_collateral_valuation(&credit_facility)
+            .await?;
+        let price = self.price.usd_cents_per_btc().await?;
+        if !credit_facility.terms.is_collateral_release_allowed(
+            balance,
+            amount,
+            price,
+            self.config.collateral_release_cvl_pct,
+        ) {
+            return Err(CollateralReleaseError::BelowReleaseThreshold.into());
+        }
+
+        let collateral = self
+            .collaterals
+            .find_by_id_without_audit(credit_facility.collateral_id)
+            .await?;
+        if let Some(wallet_id) = collateral.custody_wallet_id
+            && !self.custody.can_send_funds(wallet_id).await?
+        {
+            return Err(CollateralReleaseError::CustodianCannotSendFunds.into());
+        }
+
+        let id = CollateralReleaseId::new();
+        let new_release = collateral_release::NewCollateralRelease::builder()
+            .id(id)
+            .approval_process_id(id)
+            .credit_facility_id(credit_facility.id)
+            .collateral_id(credit_facility.collateral_id)
+            .amount(amount)
+            .address(address)
+            .audit_info(audit_info)
+            .build()
+            .expect("could not build new collateral release");
+
+        let mut db = self.collateral_releases.begin_op().await?;
+        let release = self
+            .collateral_releases
+            .create_in_op(&mut db, new_release)
+            .await?;
+        db.commit().await?;
+
+        Ok(release)
+    }
+
     pub async fn subject_can_record_payment(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -752,6 +1238,7 @@ where
             .await?;
 
         let credit_facility_id = credit_facility_id.into();
+        let effective = effective.into();
 
         let credit_facility = self
             .facilities
@@ -765,20 +1252,179 @@ where
             .record_in_op(&mut db, credit_facility_id, amount, &audit_info)
             .await?;
 
-        self.obligations
+        let installments = self
+            .obligations
             .apply_installment_in_op(
-                db,
+                &mut db,
+                InstallmentPayment {
+                    credit_facility_id,
+                    payment_id: payment.id,
+                    amount,
+                    strategy: credit_facility.terms.payment_allocation_strategy,
+                    payment_source_account_id: None,
+                    effective,
+                },
+                &audit_info,
+            )
+            .await?;
+
+        let allocated = installments
+            .iter()
+            .fold(UsdCents::ZERO, |total, installment| {
+                total + installment.amount
+            });
+        let unapplied_funds = if amount > allocated {
+            self.facilities
+                .record_unapplied_funds_in_op(
+                    &mut db,
+                    credit_facility_id,
+                    payment.id,
+                    amount - allocated,
+                    effective,
+                    &audit_info,
+                )
+                .await?
+        } else {
+            None
+        };
+
+        self.ledger
+            .record_payment(db, installments, unapplied_funds)
+            .await?;
+
+        Ok(self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?)
+    }
+
+    #[instrument(name = "credit.refund_unapplied_funds", skip(self), err)]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn refund_unapplied_funds(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        amount: UsdCents,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let credit_facility_id = credit_facility_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_REFUND_UNAPPLIED_FUNDS,
+            )
+            .await?;
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let (credit_facility, refund) = self
+            .facilities
+            .refund_unapplied_funds_in_op(
+                &mut db,
                 credit_facility_id,
-                payment.id,
                 amount,
-                effective.into(),
+                crate::time::now().date_naive(),
                 &audit_info,
             )
             .await?;
 
+        self.ledger.refund_unapplied_funds(db, refund).await?;
+
         Ok(credit_facility)
     }
 
+    #[instrument(name = "credit.record_prepayment", skip(self), err)]
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    pub async fn record_prepayment(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        amount: UsdCents,
+        effective: impl Into<chrono::NaiveDate> + std::fmt::Debug + Copy,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::all_obligations(),
+                CoreCreditAction::OBLIGATION_RECORD_PAYMENT,
+            )
+            .await?;
+
+        let credit_facility_id = credit_facility_id.into();
+        let effective = effective.into();
+
+        let mut db = self.facilities.begin_op().await?;
+
+        let payment = self
+            .payments
+            .record_in_op(&mut db, credit_facility_id, amount, &audit_info)
+            .await?;
+
+        let installments = self
+            .obligations
+            .apply_prepayment_in_op(
+                &mut db,
+                credit_facility_id,
+                payment.id,
+                amount,
+                effective,
+                &audit_info,
+            )
+            .await?;
+
+        let fee = self
+            .facilities
+            .record_prepayment_in_op(
+                &mut db,
+                credit_facility_id,
+                payment.id,
+                amount,
+                effective,
+                &audit_info,
+            )
+            .await?;
+
+        self.ledger.record_prepayment(db, installments, fee).await?;
+
+        Ok(self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?)
+    }
+
+    #[instrument(name = "credit.preview_payment_allocation", skip(self), err)]
+    pub async fn preview_payment_allocation(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug + Copy,
+        amount: UsdCents,
+    ) -> Result<PaymentAllocationPreview, CoreCreditError> {
+        let credit_facility_id = credit_facility_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        let credit_facility = self
+            .facilities
+            .find_by_id_without_audit(credit_facility_id)
+            .await?;
+
+        Ok(self
+            .obligations
+            .preview_allocation(
+                credit_facility_id,
+                amount,
+                credit_facility.terms.payment_allocation_strategy,
+            )
+            .await?)
+    }
+
     pub async fn subject_can_complete(
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
@@ -843,8 +1489,8 @@ where
 
     pub async fn current_cvl(&self, entity: &CreditFacility) -> Result<CVLPct, CoreCreditError> {
         let balances = self
-            .ledger
-            .get_credit_facility_balance(entity.account_ids)
+            .facilities
+            .balances_with_collateral_valuation(entity)
             .await?;
         let price = self.price.usd_cents_per_btc().await?;
         Ok(balances.current_cvl(price))
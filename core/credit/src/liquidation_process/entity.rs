@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use audit::AuditInfo;
 use es_entity::*;
 
-use crate::primitives::*;
+use crate::{ledger::*, primitives::*};
+
+use super::error::LiquidationProcessError;
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -23,7 +25,36 @@ pub enum LiquidationProcessEvent {
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
     },
+    CollateralReserved {
+        ledger_tx_id: LedgerTxId,
+        collateral: Satoshis,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    CollateralSold {
+        ledger_tx_id: LedgerTxId,
+        collateral: Satoshis,
+        price: PriceOfOneBTC,
+        proceeds: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    ProceedsAllocated {
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
+    SurplusReturned {
+        ledger_tx_id: LedgerTxId,
+        amount: UsdCents,
+        deposit_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
     Completed {
+        #[serde(default)]
+        ledger_tx_id: Option<LedgerTxId>,
         audit_info: AuditInfo,
     },
 }
@@ -41,6 +72,187 @@ pub struct LiquidationProcess {
     events: EntityEvents<LiquidationProcessEvent>,
 }
 
+impl LiquidationProcess {
+    pub fn collateral_reserved(&self) -> Option<Satoshis> {
+        self.events.iter_all().find_map(|event| match event {
+            LiquidationProcessEvent::CollateralReserved { collateral, .. } => Some(*collateral),
+            _ => None,
+        })
+    }
+
+    pub fn proceeds(&self) -> Option<UsdCents> {
+        self.events.iter_all().find_map(|event| match event {
+            LiquidationProcessEvent::CollateralSold { proceeds, .. } => Some(*proceeds),
+            _ => None,
+        })
+    }
+
+    pub fn surplus_returned(&self) -> UsdCents {
+        self.events
+            .iter_all()
+            .find_map(|event| match event {
+                LiquidationProcessEvent::SurplusReturned { amount, .. } => Some(*amount),
+                _ => None,
+            })
+            .unwrap_or(UsdCents::ZERO)
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|event| matches!(event, LiquidationProcessEvent::Completed { .. }))
+    }
+
+    pub(crate) fn reserve_collateral(
+        &mut self,
+        collateral: Satoshis,
+        account_ids: CreditFacilityAccountIds,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<LiquidationCollateralReservation>, LiquidationProcessError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            LiquidationProcessEvent::CollateralReserved { .. }
+        );
+        if self.is_completed() {
+            return Err(LiquidationProcessError::AlreadyCompleted);
+        }
+        if collateral == Satoshis::ZERO {
+            return Err(LiquidationProcessError::InvalidCollateralAmount);
+        }
+
+        let reservation = LiquidationCollateralReservation {
+            tx_id: LedgerTxId::new(),
+            collateral,
+            credit_facility_account_ids: account_ids,
+            effective,
+        };
+
+        self.events
+            .push(LiquidationProcessEvent::CollateralReserved {
+                ledger_tx_id: reservation.tx_id,
+                collateral,
+                effective,
+                audit_info,
+            });
+
+        Ok(Idempotent::Executed(reservation))
+    }
+
+    pub(crate) fn record_collateral_sale(
+        &mut self,
+        price: PriceOfOneBTC,
+        account_ids: CreditFacilityAccountIds,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<LiquidationCollateralSale>, LiquidationProcessError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            LiquidationProcessEvent::CollateralSold { .. }
+        );
+        let collateral = self
+            .collateral_reserved()
+            .ok_or(LiquidationProcessError::CollateralNotReserved)?;
+
+        let sale = LiquidationCollateralSale {
+            tx_id: LedgerTxId::new(),
+            collateral,
+            proceeds: price.sats_to_cents_round_down(collateral),
+            credit_facility_account_ids: account_ids,
+            effective,
+        };
+
+        self.events.push(LiquidationProcessEvent::CollateralSold {
+            ledger_tx_id: sale.tx_id,
+            collateral,
+            price,
+            proceeds: sale.proceeds,
+            effective,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(sale))
+    }
+
+    pub(crate) fn allocate_proceeds(
+        &mut self,
+        payment_id: PaymentId,
+        allocated: UsdCents,
+        account_ids: CreditFacilityAccountIds,
+        deposit_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<Option<LiquidationSurplusReturn>>, LiquidationProcessError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            LiquidationProcessEvent::ProceedsAllocated { .. }
+        );
+        let proceeds = self
+            .proceeds()
+            .ok_or(LiquidationProcessError::CollateralNotSold)?;
+        if allocated > proceeds {
+            return Err(LiquidationProcessError::AllocationExceedsProceeds(
+                allocated, proceeds,
+            ));
+        }
+
+        self.events
+            .push(LiquidationProcessEvent::ProceedsAllocated {
+                payment_id,
+                amount: allocated,
+                effective,
+                audit_info: audit_info.clone(),
+            });
+
+        if proceeds == allocated {
+            return Ok(Idempotent::Executed(None));
+        }
+
+        let surplus = LiquidationSurplusReturn {
+            tx_id: LedgerTxId::new(),
+            amount: proceeds - allocated,
+            credit_facility_account_ids: account_ids,
+            deposit_account_id,
+            effective,
+        };
+
+        self.events.push(LiquidationProcessEvent::SurplusReturned {
+            ledger_tx_id: surplus.tx_id,
+            amount: surplus.amount,
+            deposit_account_id,
+            effective,
+            audit_info,
+        });
+
+        Ok(Idempotent::Executed(Some(surplus)))
+    }
+
+    pub(crate) fn complete(
+        &mut self,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Idempotent<LiquidationReserveRelease> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            LiquidationProcessEvent::Completed { .. }
+        );
+
+        let release = LiquidationReserveRelease {
+            tx_id: LedgerTxId::new(),
+            amount: self.initial_amount,
+            in_liquidation_account_id: self.in_liquidation_account_id,
+            effective,
+        };
+
+        self.events.push(LiquidationProcessEvent::Completed {
+            ledger_tx_id: Some(release.tx_id),
+            audit_info,
+        });
+
+        Idempotent::Executed(release)
+    }
+}
+
 impl TryFromEvents<LiquidationProcessEvent> for LiquidationProcess {
     fn try_from_events(
         events: EntityEvents<LiquidationProcessEvent>,
@@ -67,6 +279,10 @@ impl TryFromEvents<LiquidationProcessEvent> for LiquidationProcess {
                         .initial_amount(*initial_amount)
                         .effective(*effective)
                 }
+                LiquidationProcessEvent::CollateralReserved { .. } => (),
+                LiquidationProcessEvent::CollateralSold { .. } => (),
+                LiquidationProcessEvent::ProceedsAllocated { .. } => (),
+                LiquidationProcessEvent::SurplusReturned { .. } => (),
                 LiquidationProcessEvent::Completed { .. } => (),
             }
         }
@@ -115,3 +331,149 @@ impl IntoEvents<LiquidationProcessEvent> for NewLiquidationProcess {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use audit::{AuditEntryId, AuditInfo};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn effective() -> chrono::NaiveDate {
+        chrono::Utc::now().date_naive()
+    }
+
+    fn liquidation_process(initial_amount: UsdCents) -> LiquidationProcess {
+        let id = LiquidationProcessId::new();
+        LiquidationProcess::try_from_events(EntityEvents::init(
+            id,
+            [LiquidationProcessEvent::Initialized {
+                id,
+                ledger_tx_id: LedgerTxId::new(),
+                obligation_id: ObligationId::new(),
+                credit_facility_id: CreditFacilityId::new(),
+                in_liquidation_account_id: CalaAccountId::new(),
+                initial_amount,
+                effective: effective(),
+                audit_info: dummy_audit_info(),
+            }],
+        ))
+        .unwrap()
+    }
+
+    fn price() -> PriceOfOneBTC {
+        PriceOfOneBTC::new(UsdCents::from(10_000_000))
+    }
+
+    #[test]
+    fn sale_requires_reserved_collateral() {
+        let mut process = liquidation_process(UsdCents::from(5_000));
+
+        let res = process.record_collateral_sale(
+            price(),
+            CreditFacilityAccountIds::new(),
+            effective(),
+            dummy_audit_info(),
+        );
+        assert!(matches!(
+            res,
+            Err(LiquidationProcessError::CollateralNotReserved)
+        ));
+    }
+
+    #[test]
+    fn liquidation_with_surplus() {
+        let account_ids = CreditFacilityAccountIds::new();
+        let mut process = liquidation_process(UsdCents::from(5_000));
+
+        let reservation = process
+            .reserve_collateral(
+                Satoshis::from(100_000),
+                account_ids,
+                effective(),
+                dummy_audit_info(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(reservation.collateral, Satoshis::from(100_000));
+        assert!(
+            process
+                .reserve_collateral(
+                    Satoshis::from(100_000),
+                    account_ids,
+                    effective(),
+                    dummy_audit_info(),
+                )
+                .unwrap()
+                .was_ignored()
+        );
+
+        let sale = process
+            .record_collateral_sale(price(), account_ids, effective(), dummy_audit_info())
+            .unwrap()
+            .unwrap();
+        assert_eq!(sale.proceeds, UsdCents::from(10_000));
+        assert_eq!(process.proceeds(), Some(UsdCents::from(10_000)));
+
+        let surplus = process
+            .allocate_proceeds(
+                PaymentId::new(),
+                UsdCents::from(5_000),
+                account_ids,
+                CalaAccountId::new(),
+                effective(),
+                dummy_audit_info(),
+            )
+            .unwrap()
+            .unwrap()
+            .expect("surplus should be returned");
+        assert_eq!(surplus.amount, UsdCents::from(5_000));
+        assert_eq!(process.surplus_returned(), UsdCents::from(5_000));
+
+        let release = process.complete(effective(), dummy_audit_info()).unwrap();
+        assert_eq!(release.amount, UsdCents::from(5_000));
+        assert!(process.is_completed());
+        assert!(matches!(
+            process.reserve_collateral(
+                Satoshis::from(1),
+                account_ids,
+                effective(),
+                dummy_audit_info(),
+            ),
+            Ok(Idempotent::Ignored)
+        ));
+    }
+
+    #[test]
+    fn allocation_cannot_exceed_proceeds() {
+        let account_ids = CreditFacilityAccountIds::new();
+        let mut process = liquidation_process(UsdCents::from(50_000));
+        let _ = process.reserve_collateral(
+            Satoshis::from(100_000),
+            account_ids,
+            effective(),
+            dummy_audit_info(),
+        );
+        let _ =
+            process.record_collateral_sale(price(), account_ids, effective(), dummy_audit_info());
+
+        let res = process.allocate_proceeds(
+            PaymentId::new(),
+            UsdCents::from(10_001),
+            account_ids,
+            CalaAccountId::new(),
+            effective(),
+            dummy_audit_info(),
+        );
+        assert!(matches!(
+            res,
+            Err(LiquidationProcessError::AllocationExceedsProceeds(..))
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use core_money::UsdCents;
+
 #[derive(Error, Debug)]
 pub enum LiquidationProcessError {
     #[error("LiquidationProcessError - Sqlx: {0}")]
@@ -8,6 +10,16 @@ pub enum LiquidationProcessError {
     EsEntityError(es_entity::EsEntityError),
     #[error("LiquidationProcessError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("LiquidationProcessError - AlreadyCompleted")]
+    AlreadyCompleted,
+    #[error("LiquidationProcessError - InvalidCollateralAmount")]
+    InvalidCollateralAmount,
+    #[error("LiquidationProcessError - CollateralNotReserved")]
+    CollateralNotReserved,
+    #[error("LiquidationProcessError - CollateralNotSold")]
+    CollateralNotSold,
+    #[error("LiquidationProcessError - AllocationExceedsProceeds: {0} > {1}")]
+    AllocationExceedsProceeds(UsdCents, UsdCents),
 }
 
 es_entity::from_es_entity_error!(LiquidationProcessError);
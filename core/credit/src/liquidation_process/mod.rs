@@ -2,6 +2,7 @@ mod entity;
 pub mod error;
 mod repo;
 
+pub use entity::LiquidationProcess;
 #[cfg(feature = "json-schema")]
 pub use entity::LiquidationProcessEvent;
 pub(crate) use entity::*;
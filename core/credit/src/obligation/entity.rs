@@ -11,6 +11,7 @@ use es_entity::*;
 use crate::{
     CreditFacilityId, liquidation_process::NewLiquidationProcess,
     obligation_installment::NewObligationInstallment, primitives::*,
+    terms::PaymentAllocationStrategy,
 };
 
 use super::{error::ObligationError, primitives::*};
@@ -72,6 +73,13 @@ pub enum ObligationEvent {
         liquidation_process_id: LiquidationProcessId,
         audit_info: AuditInfo,
     },
+    RolledOver {
+        ledger_tx_id: LedgerTxId,
+        credit_facility_id: CreditFacilityId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: AuditInfo,
+    },
     Completed {
         effective: chrono::NaiveDate,
         audit_info: AuditInfo,
@@ -316,7 +324,8 @@ impl Obligation {
                     ObligationEvent::InstallmentApplied {
                         obligation_installment_amount: amount,
                         ..
-                    } => {
+                    }
+                    | ObligationEvent::RolledOver { amount, .. } => {
                         total_sum -= *amount;
                     }
                     _ => (),
@@ -325,6 +334,35 @@ impl Obligation {
             })
     }
 
+    /// Outstanding amount at the end of `date`. The installments paid towards
+    /// this obligation are passed in as `(effective, amount)`, as the applied
+    /// events do not carry the date the payment took effect.
+    pub(crate) fn outstanding_on(
+        &self,
+        date: chrono::NaiveDate,
+        installments: impl IntoIterator<Item = (chrono::NaiveDate, UsdCents)>,
+    ) -> UsdCents {
+        if self.effective > date {
+            return UsdCents::ZERO;
+        }
+
+        let rolled_over = self
+            .events
+            .iter_all()
+            .fold(UsdCents::ZERO, |total, event| match event {
+                ObligationEvent::RolledOver {
+                    amount, effective, ..
+                } if *effective <= date => total + *amount,
+                _ => total,
+            });
+        let paid = installments
+            .into_iter()
+            .filter(|(effective, _)| *effective <= date)
+            .fold(UsdCents::ZERO, |total, (_, amount)| total + amount);
+
+        self.initial_amount - rolled_over - paid
+    }
+
     pub fn has_outstanding_balance(&self) -> bool {
         !self.outstanding().is_zero()
     }
@@ -490,6 +528,24 @@ impl Obligation {
         Idempotent::Executed(new_liquidation_process)
     }
 
+    pub(crate) fn conclude_liquidation(
+        &mut self,
+        liquidation_process_id: LiquidationProcessId,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<()> {
+        if !self.is_in_liquidation() {
+            return Idempotent::Ignored;
+        }
+
+        self.events
+            .push(ObligationEvent::LiquidationProcessConcluded {
+                liquidation_process_id,
+                audit_info: audit_info.clone(),
+            });
+
+        Idempotent::Executed(())
+    }
+
     pub(crate) fn apply_installment(
         &mut self,
         amount: UsdCents,
@@ -553,6 +609,47 @@ impl Obligation {
 
         Idempotent::Executed(installment)
     }
+
+    pub(crate) fn roll_over(
+        &mut self,
+        credit_facility_id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Idempotent<(CalaAccountId, UsdCents)>, ObligationError> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            ObligationEvent::RolledOver { ledger_tx_id: id, .. } if *id == ledger_tx_id
+        );
+        let amount = self.outstanding();
+        if amount.is_zero() {
+            return Ok(Idempotent::Ignored);
+        }
+        if self.is_in_liquidation() {
+            return Err(ObligationError::RolloverWithObligationInLiquidation);
+        }
+
+        let receivable_account_id = match self.status() {
+            ObligationStatus::Defaulted => self.defaulted_account(),
+            _ => self
+                .receivable_account_id()
+                .expect("Obligation was already paid"),
+        };
+
+        self.events.push(ObligationEvent::RolledOver {
+            ledger_tx_id,
+            credit_facility_id,
+            amount,
+            effective,
+            audit_info: audit_info.clone(),
+        });
+        self.events.push(ObligationEvent::Completed {
+            effective,
+            audit_info: audit_info.clone(),
+        });
+
+        Ok(Idempotent::Executed((receivable_account_id, amount)))
+    }
 }
 
 impl TryFromEvents<ObligationEvent> for Obligation {
@@ -585,6 +682,7 @@ impl TryFromEvents<ObligationEvent> for Obligation {
                 ObligationEvent::InstallmentApplied { .. } => (),
                 ObligationEvent::LiquidationProcessStarted { .. } => (),
                 ObligationEvent::LiquidationProcessConcluded { .. } => (),
+                ObligationEvent::RolledOver { .. } => (),
                 ObligationEvent::Completed { .. } => (),
             }
         }
@@ -673,23 +771,77 @@ impl IntoEvents<ObligationEvent> for NewObligation {
     }
 }
 
-impl Ord for Obligation {
-    fn cmp(&self, other: &Self) -> Ordering {
-        match (&self.obligation_type, &other.obligation_type) {
-            (ObligationType::Interest, ObligationType::Disbursal) => Ordering::Less,
-            (ObligationType::Disbursal, ObligationType::Interest) => Ordering::Greater,
-            _ => self
-                .effective
-                .cmp(&other.effective)
-                .then_with(|| self.created_at().cmp(&other.created_at())),
-        }
+impl Obligation {
+    pub(crate) fn cmp_for_allocation(
+        &self,
+        other: &Self,
+        strategy: PaymentAllocationStrategy,
+    ) -> Ordering {
+        let bucket = strategy.bucket(self.obligation_type, self.status());
+        let other_bucket = strategy.bucket(other.obligation_type, other.status());
+
+        bucket
+            .cmp(&other_bucket)
+            .then_with(|| {
+                if strategy.orders_by_due_date() {
+                    self.due_at().cmp(&other.due_at()).then_with(|| {
+                        match (self.obligation_type, other.obligation_type) {
+                            (ObligationType::Interest, ObligationType::Disbursal) => Ordering::Less,
+                            (ObligationType::Disbursal, ObligationType::Interest) => {
+                                Ordering::Greater
+                            }
+                            _ => Ordering::Equal,
+                        }
+                    })
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .then_with(|| self.effective.cmp(&other.effective))
+            .then_with(|| self.created_at().cmp(&other.created_at()))
     }
 }
-impl PartialOrd for Obligation {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+
+/// Splits a payment over the obligations in the order of the allocation
+/// strategy. Shared by the preview and the actual application of a payment.
+pub(crate) fn allocate_payment(
+    obligations: &[Obligation],
+    amount: UsdCents,
+    strategy: PaymentAllocationStrategy,
+) -> PaymentAllocationPreview {
+    let mut ordered = obligations.iter().collect::<Vec<_>>();
+    ordered.sort_by(|a, b| a.cmp_for_allocation(b, strategy));
+
+    let mut remaining = amount;
+    let mut entries = Vec::new();
+    for obligation in ordered {
+        if remaining == UsdCents::ZERO {
+            break;
+        }
+        let outstanding = obligation.outstanding();
+        if outstanding.is_zero() || obligation.is_in_liquidation() {
+            continue;
+        }
+
+        let allocated = std::cmp::min(outstanding, remaining);
+        remaining -= allocated;
+        entries.push(PaymentAllocationPreviewEntry {
+            obligation_id: obligation.id,
+            obligation_type: obligation.obligation_type,
+            status: obligation.status(),
+            outstanding,
+            allocated,
+        });
+    }
+
+    PaymentAllocationPreview {
+        strategy,
+        amount,
+        unallocated: remaining,
+        entries,
     }
 }
+
 impl Eq for Obligation {}
 impl PartialEq for Obligation {
     fn eq(&self, other: &Self) -> bool {
@@ -924,6 +1076,72 @@ mod test {
         );
     }
 
+    #[test]
+    fn roll_over_completes_obligation() {
+        let mut obligation = obligation_from(initial_events());
+        let ledger_tx_id = LedgerTxId::new();
+        let (account_id, amount) = obligation
+            .roll_over(
+                CreditFacilityId::new(),
+                ledger_tx_id,
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            account_id,
+            obligation.not_yet_due_accounts().receivable_account_id
+        );
+        assert_eq!(amount, obligation.initial_amount);
+        assert_eq!(obligation.outstanding(), UsdCents::ZERO);
+        assert_eq!(obligation.status(), ObligationStatus::Paid);
+
+        assert!(
+            obligation
+                .roll_over(
+                    CreditFacilityId::new(),
+                    ledger_tx_id,
+                    Utc::now().date_naive(),
+                    &dummy_audit_info(),
+                )
+                .unwrap()
+                .was_ignored()
+        );
+    }
+
+    #[test]
+    fn roll_over_uses_defaulted_account() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.record_due(Utc::now().date_naive(), dummy_audit_info());
+        let _ = obligation.record_defaulted(Utc::now().date_naive(), dummy_audit_info());
+        let (account_id, _) = obligation
+            .roll_over(
+                CreditFacilityId::new(),
+                LedgerTxId::new(),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(account_id, obligation.defaulted_account());
+    }
+
+    #[test]
+    fn errors_if_roll_over_in_liquidation() {
+        let mut obligation = obligation_from(initial_events());
+        let _ = obligation.start_liquidation(Utc::now().date_naive(), &dummy_audit_info());
+        assert!(matches!(
+            obligation.roll_over(
+                CreditFacilityId::new(),
+                LedgerTxId::new(),
+                Utc::now().date_naive(),
+                &dummy_audit_info(),
+            ),
+            Err(ObligationError::RolloverWithObligationInLiquidation)
+        ));
+    }
+
     mod is_status_up_to_date {
 
         use super::*;
@@ -1131,4 +1349,202 @@ mod test {
             assert!(obligation.is_status_up_to_date(now));
         }
     }
+
+    #[test]
+    fn outstanding_on_counts_installments_effective_by_that_day() {
+        let obligation = obligation_from(initial_events());
+        let today = Utc::now().date_naive();
+        let tomorrow = today.succ_opt().unwrap();
+        let installments = [(tomorrow, UsdCents::from(4))];
+
+        assert_eq!(
+            obligation.outstanding_on(today, installments),
+            UsdCents::from(10)
+        );
+        assert_eq!(
+            obligation.outstanding_on(tomorrow, installments),
+            UsdCents::from(6)
+        );
+        assert_eq!(
+            obligation.outstanding_on(today.pred_opt().unwrap(), installments),
+            UsdCents::ZERO
+        );
+    }
+
+    mod allocation_order {
+        use super::*;
+
+        fn obligation(
+            obligation_type: ObligationType,
+            due_date: DateTime<Utc>,
+            status_events: Vec<ObligationEvent>,
+        ) -> Obligation {
+            let mut events = initial_events();
+            if let ObligationEvent::Initialized {
+                obligation_type: t,
+                due_date: d,
+                effective,
+                ..
+            } = &mut events[0]
+            {
+                *t = obligation_type;
+                *d = due_date;
+                *effective = due_date.date_naive();
+            }
+            events.extend(status_events);
+            obligation_from(events)
+        }
+
+        fn due() -> ObligationEvent {
+            ObligationEvent::DueRecorded {
+                ledger_tx_id: LedgerTxId::new(),
+                due_amount: UsdCents::from(10),
+                audit_info: dummy_audit_info(),
+            }
+        }
+
+        fn overdue() -> ObligationEvent {
+            ObligationEvent::OverdueRecorded {
+                ledger_tx_id: LedgerTxId::new(),
+                overdue_amount: UsdCents::from(10),
+                audit_info: dummy_audit_info(),
+            }
+        }
+
+        fn defaulted() -> ObligationEvent {
+            ObligationEvent::DefaultedRecorded {
+                ledger_tx_id: LedgerTxId::new(),
+                defaulted_amount: UsdCents::from(10),
+                audit_info: dummy_audit_info(),
+            }
+        }
+
+        fn sorted(
+            obligations: &mut [Obligation],
+            strategy: PaymentAllocationStrategy,
+        ) -> Vec<ObligationId> {
+            obligations.sort_by(|a, b| a.cmp_for_allocation(b, strategy));
+            obligations.iter().map(|o| o.id).collect()
+        }
+
+        #[test]
+        fn interest_first_pays_all_interest_before_principal() {
+            let now = Utc::now();
+            let old_principal = obligation(
+                ObligationType::Disbursal,
+                now - chrono::Duration::days(60),
+                vec![due(), overdue()],
+            );
+            let new_interest = obligation(ObligationType::Interest, now, vec![due()]);
+            let expected = vec![new_interest.id, old_principal.id];
+
+            let mut obligations = vec![old_principal, new_interest];
+            assert_eq!(
+                sorted(&mut obligations, PaymentAllocationStrategy::InterestFirst),
+                expected
+            );
+        }
+
+        #[test]
+        fn oldest_first_pays_by_due_date() {
+            let now = Utc::now();
+            let old_principal = obligation(
+                ObligationType::Disbursal,
+                now - chrono::Duration::days(60),
+                vec![due(), overdue()],
+            );
+            let new_interest = obligation(ObligationType::Interest, now, vec![due()]);
+            let expected = vec![old_principal.id, new_interest.id];
+
+            let mut obligations = vec![new_interest, old_principal];
+            assert_eq!(
+                sorted(&mut obligations, PaymentAllocationStrategy::OldestFirst),
+                expected
+            );
+        }
+
+        #[test]
+        fn delinquent_first_follows_waterfall() {
+            let now = Utc::now();
+            let current_interest = obligation(
+                ObligationType::Interest,
+                now - chrono::Duration::days(90),
+                vec![due()],
+            );
+            let overdue_principal = obligation(
+                ObligationType::Disbursal,
+                now - chrono::Duration::days(80),
+                vec![due(), overdue()],
+            );
+            let overdue_interest =
+                obligation(ObligationType::Interest, now, vec![due(), overdue()]);
+            let defaulted_interest = obligation(
+                ObligationType::Interest,
+                now - chrono::Duration::days(10),
+                vec![due(), overdue(), defaulted()],
+            );
+            let expected = vec![
+                defaulted_interest.id,
+                overdue_interest.id,
+                overdue_principal.id,
+                current_interest.id,
+            ];
+
+            let mut obligations = vec![
+                current_interest,
+                overdue_principal,
+                overdue_interest,
+                defaulted_interest,
+            ];
+            assert_eq!(
+                sorted(&mut obligations, PaymentAllocationStrategy::DelinquentFirst),
+                expected
+            );
+        }
+
+        #[test]
+        fn allocate_payment_fills_obligations_in_order() {
+            let now = Utc::now();
+            let principal = obligation(ObligationType::Disbursal, now, vec![due()]);
+            let interest = obligation(
+                ObligationType::Interest,
+                now - chrono::Duration::days(30),
+                vec![due()],
+            );
+            let (principal_id, interest_id) = (principal.id, interest.id);
+
+            let allocation = allocate_payment(
+                &[principal, interest],
+                UsdCents::from(15),
+                PaymentAllocationStrategy::OldestFirst,
+            );
+
+            assert_eq!(
+                allocation
+                    .entries
+                    .iter()
+                    .map(|e| (e.obligation_id, e.allocated))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (interest_id, UsdCents::from(10)),
+                    (principal_id, UsdCents::from(5))
+                ]
+            );
+            assert_eq!(allocation.unallocated, UsdCents::ZERO);
+        }
+
+        #[test]
+        fn allocate_payment_leaves_overflow_unallocated() {
+            let principal = obligation(ObligationType::Disbursal, Utc::now(), vec![due()]);
+
+            let allocation = allocate_payment(
+                &[principal],
+                UsdCents::from(25),
+                PaymentAllocationStrategy::InterestFirst,
+            );
+
+            assert_eq!(allocation.entries.len(), 1);
+            assert_eq!(allocation.unallocated, UsdCents::from(15));
+        }
+    }
 }
@@ -24,6 +24,16 @@ pub enum ObligationError {
     ),
     #[error("ObligationError - PaymentAmountGreaterThanOutstandingObligations")]
     PaymentAmountGreaterThanOutstandingObligations,
+    #[error("ObligationError - PrepaymentAmountGreaterThanOutstandingPrincipal")]
+    PrepaymentAmountGreaterThanOutstandingPrincipal,
+    #[error("ObligationError - PrepaymentWithObligationsDue")]
+    PrepaymentWithObligationsDue,
+    #[error("ObligationError - RolloverWithObligationInLiquidation")]
+    RolloverWithObligationInLiquidation,
+    #[error("ObligationError - RolloverWithOutstandingInterest")]
+    RolloverWithOutstandingInterest,
+    #[error("ObligationError - LiquidationAlreadyCompleted: {0}")]
+    LiquidationAlreadyCompleted(crate::primitives::LiquidationProcessId),
     #[error("CoreCreditError - ObligationError: {0}")]
     CreditLedgerError(#[from] crate::ledger::error::CreditLedgerError),
 }
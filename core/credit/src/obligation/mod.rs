@@ -15,11 +15,18 @@ use crate::{
     CreditLedger, ObligationInstallment, ObligationInstallmentId, ObligationInstallmentRepo,
     event::CoreCreditEvent,
     jobs::obligation_due,
+    ledger::{
+        CreditFacilityAccountIds, LiquidationCollateralReservation, LiquidationCollateralSale,
+        LiquidationReserveRelease, LiquidationSurplusReturn,
+    },
     liquidation_process::{LiquidationProcess, LiquidationProcessRepo},
     primitives::{
-        CoreCreditAction, CoreCreditObject, CreditFacilityId, ObligationId, PaymentId, UsdCents,
+        CalaAccountId, CoreCreditAction, CoreCreditObject, CreditFacilityId, LedgerTxId,
+        LiquidationProcessId, ObligationId, ObligationStatus, ObligationType, PaymentId,
+        PriceOfOneBTC, Satoshis, UsdCents,
     },
     publisher::CreditFacilityPublisher,
+    terms::PaymentAllocationStrategy,
 };
 
 pub use entity::Obligation;
@@ -244,6 +251,150 @@ where
         Ok((obligation, liquidation_process))
     }
 
+    pub async fn find_liquidation_process_by_id_without_audit(
+        &self,
+        id: LiquidationProcessId,
+    ) -> Result<LiquidationProcess, ObligationError> {
+        Ok(self.liquidation_process_repo.find_by_id(id).await?)
+    }
+
+    #[instrument(
+        name = "core_credit.obligation.list_liquidation_processes_for_credit_facility",
+        skip(self),
+        err
+    )]
+    pub async fn list_liquidation_processes_for_credit_facility(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        credit_facility_id: impl Into<CreditFacilityId> + std::fmt::Debug,
+    ) -> Result<Vec<LiquidationProcess>, ObligationError> {
+        let credit_facility_id = credit_facility_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreCreditObject::credit_facility(credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_READ,
+            )
+            .await?;
+
+        Ok(self
+            .liquidation_process_repo
+            .list_for_credit_facility_id_by_created_at(
+                credit_facility_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities)
+    }
+
+    pub async fn reserve_collateral_for_liquidation_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: LiquidationProcessId,
+        collateral: Satoshis,
+        account_ids: CreditFacilityAccountIds,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Option<LiquidationCollateralReservation>, ObligationError> {
+        let mut liquidation_process = self.liquidation_process_repo.find_by_id(id).await?;
+
+        let reservation = if let Idempotent::Executed(reservation) = liquidation_process
+            .reserve_collateral(collateral, account_ids, effective, audit_info.clone())?
+        {
+            reservation
+        } else {
+            return Ok(None);
+        };
+
+        self.liquidation_process_repo
+            .update_in_op(op, &mut liquidation_process)
+            .await?;
+
+        Ok(Some(reservation))
+    }
+
+    pub async fn record_liquidation_sale_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: LiquidationProcessId,
+        price: PriceOfOneBTC,
+        account_ids: CreditFacilityAccountIds,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Option<LiquidationCollateralSale>, ObligationError> {
+        let mut liquidation_process = self.liquidation_process_repo.find_by_id(id).await?;
+
+        let sale = if let Idempotent::Executed(sale) = liquidation_process.record_collateral_sale(
+            price,
+            account_ids,
+            effective,
+            audit_info.clone(),
+        )? {
+            sale
+        } else {
+            return Ok(None);
+        };
+
+        self.liquidation_process_repo
+            .update_in_op(op, &mut liquidation_process)
+            .await?;
+
+        let mut obligation = self
+            .repo
+            .find_by_id_in_op(&mut *op, liquidation_process.obligation_id)
+            .await?;
+        if obligation
+            .conclude_liquidation(id, audit_info)
+            .did_execute()
+        {
+            self.repo.update_in_op(op, &mut obligation).await?;
+        }
+
+        Ok(Some(sale))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn complete_liquidation_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        id: LiquidationProcessId,
+        payment_id: PaymentId,
+        allocated: UsdCents,
+        account_ids: CreditFacilityAccountIds,
+        deposit_account_id: CalaAccountId,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<(Option<LiquidationSurplusReturn>, LiquidationReserveRelease), ObligationError>
+    {
+        let mut liquidation_process = self
+            .liquidation_process_repo
+            .find_by_id_in_op(&mut *op, id)
+            .await?;
+
+        let es_entity::Idempotent::Executed(surplus) = liquidation_process.allocate_proceeds(
+            payment_id,
+            allocated,
+            account_ids,
+            deposit_account_id,
+            effective,
+            audit_info.clone(),
+        )?
+        else {
+            return Err(ObligationError::LiquidationAlreadyCompleted(id));
+        };
+        let es_entity::Idempotent::Executed(release) =
+            liquidation_process.complete(effective, audit_info.clone())
+        else {
+            return Err(ObligationError::LiquidationAlreadyCompleted(id));
+        };
+        self.liquidation_process_repo
+            .update_in_op(op, &mut liquidation_process)
+            .await?;
+
+        Ok((surplus, release))
+    }
+
     pub async fn find_by_id_without_audit(
         &self,
         id: ObligationId,
@@ -258,31 +409,37 @@ where
     )]
     pub async fn apply_installment_in_op(
         &self,
-        mut op: es_entity::DbOp<'_>,
-        credit_facility_id: CreditFacilityId,
-        payment_id: PaymentId,
-        amount: UsdCents,
-        effective: chrono::NaiveDate,
+        op: &mut es_entity::DbOp<'_>,
+        payment: InstallmentPayment,
         audit_info: &AuditInfo,
-    ) -> Result<(), ObligationError> {
+    ) -> Result<Vec<ObligationInstallment>, ObligationError> {
         let span = Span::current();
-        let mut obligations = self.facility_obligations(credit_facility_id).await?;
+        let mut obligations = self
+            .facility_obligations_in_op(op, payment.credit_facility_id)
+            .await?;
         span.record("n_facility_obligations", obligations.len());
 
-        obligations.sort();
+        let allocation = allocate_payment(&obligations, payment.amount, payment.strategy);
 
-        let mut remaining = amount;
         let mut new_installments = Vec::new();
-        for obligation in obligations.iter_mut() {
-            if let es_entity::Idempotent::Executed(new_installment) =
-                obligation.apply_installment(remaining, payment_id, effective, audit_info)
+        for entry in allocation.entries {
+            let obligation = obligations
+                .iter_mut()
+                .find(|o| o.id == entry.obligation_id)
+                .expect("allocated obligation not found");
+            if let es_entity::Idempotent::Executed(mut new_installment) = obligation
+                .apply_installment(
+                    entry.allocated,
+                    payment.payment_id,
+                    payment.effective,
+                    audit_info,
+                )
             {
-                self.repo.update_in_op(&mut op, obligation).await?;
-                remaining -= new_installment.amount;
-                new_installments.push(new_installment);
-                if remaining == UsdCents::ZERO {
-                    break;
+                self.repo.update_in_op(op, obligation).await?;
+                if let Some(account_id) = payment.payment_source_account_id {
+                    new_installment.account_to_be_debited_id = account_id;
                 }
+                new_installments.push(new_installment);
             }
         }
 
@@ -290,7 +447,7 @@ where
 
         let installments = self
             .installment_repo
-            .create_all_in_op(&mut op, new_installments)
+            .create_all_in_op(op, new_installments)
             .await?;
 
         let amount_allocated = installments
@@ -301,11 +458,135 @@ where
             tracing::field::display(amount_allocated),
         );
 
-        self.ledger
-            .record_obligation_installments(op, installments)
+        Ok(installments)
+    }
+
+    #[instrument(
+        name = "credit.obligation.apply_prepayment_in_op",
+        skip(self, op),
+        fields(n_new_installments)
+    )]
+    pub async fn apply_prepayment_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        credit_facility_id: CreditFacilityId,
+        payment_id: PaymentId,
+        amount: UsdCents,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<Vec<ObligationInstallment>, ObligationError> {
+        let obligations = self
+            .facility_obligations_in_op(&mut *op, credit_facility_id)
             .await?;
+        if obligations
+            .iter()
+            .any(|o| !o.outstanding().is_zero() && o.status() != ObligationStatus::NotYetDue)
+        {
+            return Err(ObligationError::PrepaymentWithObligationsDue);
+        }
+
+        let mut principal_obligations = obligations
+            .into_iter()
+            .filter(|o| {
+                o.obligation_type == ObligationType::Disbursal && !o.outstanding().is_zero()
+            })
+            .collect::<Vec<_>>();
+        let outstanding_principal = principal_obligations
+            .iter()
+            .fold(UsdCents::ZERO, |acc, o| acc + o.outstanding());
+        if amount > outstanding_principal {
+            return Err(ObligationError::PrepaymentAmountGreaterThanOutstandingPrincipal);
+        }
+
+        principal_obligations.sort_by_key(|o| o.due_at());
 
-        Ok(())
+        let mut remaining = amount;
+        let mut new_installments = Vec::new();
+        for obligation in principal_obligations.iter_mut() {
+            if let es_entity::Idempotent::Executed(new_installment) =
+                obligation.apply_installment(remaining, payment_id, effective, audit_info)
+            {
+                self.repo.update_in_op(op, obligation).await?;
+                remaining -= new_installment.amount;
+                new_installments.push(new_installment);
+                if remaining == UsdCents::ZERO {
+                    break;
+                }
+            }
+        }
+        Span::current().record("n_new_installments", new_installments.len());
+
+        Ok(self
+            .installment_repo
+            .create_all_in_op(op, new_installments)
+            .await?)
+    }
+
+    /// Interest and penalty obligations are only taken over when
+    /// `capitalize_interest` is set.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn roll_over_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        credit_facility_id: CreditFacilityId,
+        new_credit_facility_id: CreditFacilityId,
+        ledger_tx_id: LedgerTxId,
+        capitalize_interest: bool,
+        effective: chrono::NaiveDate,
+        audit_info: &AuditInfo,
+    ) -> Result<RolledOverReceivables, ObligationError> {
+        let mut obligations = self
+            .facility_obligations_in_op(&mut *op, credit_facility_id)
+            .await?;
+
+        let mut rolled_over = RolledOverReceivables::default();
+        for obligation in obligations.iter_mut() {
+            let receivables = match obligation.obligation_type {
+                ObligationType::Disbursal => &mut rolled_over.principal,
+                ObligationType::Interest | ObligationType::Penalty if capitalize_interest => {
+                    &mut rolled_over.capitalized_interest
+                }
+                ObligationType::Interest | ObligationType::Penalty
+                    if obligation.outstanding().is_zero() =>
+                {
+                    continue;
+                }
+                ObligationType::Interest | ObligationType::Penalty => {
+                    return Err(ObligationError::RolloverWithOutstandingInterest);
+                }
+            };
+            if let Idempotent::Executed((account_id, amount)) =
+                obligation.roll_over(new_credit_facility_id, ledger_tx_id, effective, audit_info)?
+            {
+                self.repo.update_in_op(&mut *op, obligation).await?;
+                *receivables.entry(account_id).or_insert(UsdCents::ZERO) += amount;
+            }
+        }
+
+        Ok(rolled_over)
+    }
+
+    pub async fn preview_allocation(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        amount: UsdCents,
+        strategy: PaymentAllocationStrategy,
+    ) -> Result<PaymentAllocationPreview, ObligationError> {
+        let obligations = self.facility_obligations(credit_facility_id).await?;
+        Ok(allocate_payment(&obligations, amount, strategy))
+    }
+
+    pub async fn preview_allocation_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        credit_facility_id: CreditFacilityId,
+        amount: UsdCents,
+        strategy: PaymentAllocationStrategy,
+    ) -> Result<PaymentAllocationPreview, ObligationError> {
+        let obligations = self
+            .facility_obligations_in_op(op, credit_facility_id)
+            .await?;
+        Ok(allocate_payment(&obligations, amount, strategy))
     }
 
     pub(super) async fn find_installment_by_id_without_audit(
@@ -358,6 +639,82 @@ where
         Ok(true)
     }
 
+    /// Outstanding amount of overdue obligations at the end of each day from
+    /// `from` through `until`, as it stood on that day. Starts on the day the
+    /// first obligation became overdue when `from` is not given.
+    pub async fn daily_overdue_outstanding_for_facility(
+        &self,
+        credit_facility_id: CreditFacilityId,
+        from: Option<chrono::NaiveDate>,
+        until: chrono::NaiveDate,
+    ) -> Result<Vec<(chrono::NaiveDate, UsdCents)>, ObligationError> {
+        let obligations = self
+            .facility_obligations(credit_facility_id)
+            .await?
+            .into_iter()
+            .filter(|obligation| obligation.obligation_type != ObligationType::Penalty)
+            .filter_map(|obligation| {
+                obligation
+                    .overdue_at()
+                    .map(|overdue_at| (overdue_at.date_naive(), obligation))
+            })
+            .collect::<Vec<_>>();
+        let Some(first_overdue_on) = obligations.iter().map(|(on, _)| *on).min() else {
+            return Ok(Vec::new());
+        };
+        let installments = self.facility_installments(credit_facility_id).await?;
+
+        let mut day = from.map_or(first_overdue_on, |from| from.max(first_overdue_on));
+        let mut res = Vec::new();
+        while day <= until {
+            let outstanding = obligations
+                .iter()
+                .filter(|(overdue_on, _)| *overdue_on <= day)
+                .fold(UsdCents::ZERO, |total, (_, obligation)| {
+                    total
+                        + obligation.outstanding_on(
+                            day,
+                            installments
+                                .iter()
+                                .filter(|installment| installment.obligation_id == obligation.id)
+                                .map(|installment| (installment.effective, installment.amount)),
+                        )
+                });
+            res.push((day, outstanding));
+            day = day.succ_opt().expect("next day overflow");
+        }
+
+        Ok(res)
+    }
+
+    async fn facility_installments(
+        &self,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<Vec<ObligationInstallment>, ObligationError> {
+        let mut installments = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .installment_repo
+                .list_for_credit_facility_id_by_created_at(
+                    credit_facility_id,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+
+            installments.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(installments)
+    }
+
     async fn facility_obligations(
         &self,
         credit_facility_id: CreditFacilityId,
@@ -385,4 +742,34 @@ where
 
         Ok(obligations)
     }
+
+    async fn facility_obligations_in_op(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        credit_facility_id: CreditFacilityId,
+    ) -> Result<Vec<Obligation>, ObligationError> {
+        let mut obligations = Vec::new();
+        let mut query = Default::default();
+        loop {
+            let mut res = self
+                .repo
+                .list_for_credit_facility_id_by_created_at_in_op(
+                    &mut *op,
+                    credit_facility_id,
+                    query,
+                    es_entity::ListDirection::Ascending,
+                )
+                .await?;
+
+            obligations.append(&mut res.entities);
+
+            if let Some(q) = res.into_next_query() {
+                query = q;
+            } else {
+                break;
+            };
+        }
+
+        Ok(obligations)
+    }
 }
@@ -1,7 +1,8 @@
-use crate::primitives::*;
+use crate::{primitives::*, terms::PaymentAllocationStrategy};
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -34,6 +35,14 @@ pub struct ObligationDefaultedReallocationData {
     pub effective: chrono::NaiveDate,
 }
 
+/// Receivables taken over by a rollover, keyed by the account they were held in.
+#[derive(Debug, Default)]
+pub struct RolledOverReceivables {
+    pub principal: HashMap<CalaAccountId, UsdCents>,
+    /// Interest and penalties capitalized into the principal of the new facility.
+    pub capitalized_interest: HashMap<CalaAccountId, UsdCents>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ObligationsAmounts {
     pub disbursed: UsdCents,
@@ -65,3 +74,33 @@ impl ObligationsAmounts {
         self.disbursed.is_zero() && self.interest.is_zero()
     }
 }
+
+/// A payment to be spread over the obligations of a facility. When
+/// `payment_source_account_id` is set the installments are debited from it
+/// instead of the obligations' own accounts.
+#[derive(Debug, Clone, Copy)]
+pub struct InstallmentPayment {
+    pub credit_facility_id: CreditFacilityId,
+    pub payment_id: PaymentId,
+    pub amount: UsdCents,
+    pub strategy: PaymentAllocationStrategy,
+    pub payment_source_account_id: Option<CalaAccountId>,
+    pub effective: chrono::NaiveDate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaymentAllocationPreviewEntry {
+    pub obligation_id: ObligationId,
+    pub obligation_type: ObligationType,
+    pub status: ObligationStatus,
+    pub outstanding: UsdCents,
+    pub allocated: UsdCents,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentAllocationPreview {
+    pub strategy: PaymentAllocationStrategy,
+    pub amount: UsdCents,
+    pub unallocated: UsdCents,
+    pub entries: Vec<PaymentAllocationPreviewEntry>,
+}
@@ -18,7 +18,7 @@ pub use cala_ledger::primitives::{
 pub use core_custody::WalletId as CustodyWalletId;
 pub use core_customer::{CustomerId, CustomerType};
 pub use core_money::*;
-pub use core_price::PriceOfOneBTC;
+pub use core_price::{CollateralAsset, PriceOfOneBTC, PriceOfOneUnit};
 pub use governance::ApprovalProcessId;
 pub use public_id::PublicId;
 
@@ -34,14 +34,17 @@ es_entity::entity_id! {
     ObligationId,
     LiquidationProcessId,
     InterestAccrualCycleId,
-    TermsTemplateId;
+    TermsTemplateId,
+    CollateralReleaseId;
 
     CreditFacilityId => governance::ApprovalProcessId,
     DisbursalId => governance::ApprovalProcessId,
+    CollateralReleaseId => governance::ApprovalProcessId,
 
     CreditFacilityId => job::JobId,
     InterestAccrualCycleId => job::JobId,
     ObligationId => job::JobId,
+    CollateralReleaseId => job::JobId,
 
     DisbursalId => LedgerTxId,
     ObligationInstallmentId => LedgerTxId,
@@ -64,6 +67,7 @@ pub enum ObligationStatus {
 pub enum ObligationType {
     Disbursal,
     Interest,
+    Penalty,
 }
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
@@ -71,6 +75,7 @@ pub enum ObligationType {
 pub enum BalanceUpdatedType {
     Disbursal,
     InterestAccrual,
+    Penalty,
 }
 
 impl From<ObligationType> for BalanceUpdatedType {
@@ -78,6 +83,7 @@ impl From<ObligationType> for BalanceUpdatedType {
         match obligation_type {
             ObligationType::Disbursal => Self::Disbursal,
             ObligationType::Interest => Self::InterestAccrual,
+            ObligationType::Penalty => Self::Penalty,
         }
     }
 }
@@ -256,6 +262,16 @@ impl CoreCreditAction {
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateral);
     pub const CREDIT_FACILITY_UPDATE_COLLATERALIZATION_STATE: Self =
         CoreCreditAction::CreditFacility(CreditFacilityAction::UpdateCollateralizationState);
+    pub const CREDIT_FACILITY_RESTRUCTURE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::Restructure);
+    pub const CREDIT_FACILITY_ROLLOVER: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::Rollover);
+    pub const CREDIT_FACILITY_REQUEST_COLLATERAL_RELEASE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::RequestCollateralRelease);
+    pub const CREDIT_FACILITY_CONCLUDE_COLLATERAL_RELEASE: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::ConcludeCollateralRelease);
+    pub const CREDIT_FACILITY_REFUND_UNAPPLIED_FUNDS: Self =
+        CoreCreditAction::CreditFacility(CreditFacilityAction::RefundUnappliedFunds);
 
     pub const CHART_OF_ACCOUNTS_INTEGRATION_CONFIG_READ: Self =
         CoreCreditAction::ChartOfAccountsIntegrationConfig(
@@ -354,6 +370,11 @@ pub enum CreditFacilityAction {
     RecordInterest,
     Complete,
     UpdateCollateralizationState,
+    Restructure,
+    Rollover,
+    RequestCollateralRelease,
+    ConcludeCollateralRelease,
+    RefundUnappliedFunds,
 }
 
 impl ActionPermission for CreditFacilityAction {
@@ -366,7 +387,12 @@ impl ActionPermission for CreditFacilityAction {
             | Self::UpdateCollateral
             | Self::RecordInterest
             | Self::Complete
-            | Self::UpdateCollateralizationState => PERMISSION_SET_CREDIT_WRITER,
+            | Self::UpdateCollateralizationState
+            | Self::Restructure
+            | Self::Rollover
+            | Self::RequestCollateralRelease
+            | Self::ConcludeCollateralRelease
+            | Self::RefundUnappliedFunds => PERMISSION_SET_CREDIT_WRITER,
         }
     }
 }
@@ -520,6 +546,18 @@ impl InterestAccrualCycleIdx {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum CollateralReleaseStatus {
+    PendingApproval,
+    Approved,
+    Denied,
+    Executing,
+    Executed,
+    Failed,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -558,6 +596,15 @@ pub struct CollateralUpdate {
     pub effective: chrono::NaiveDate,
 }
 
+pub struct CollateralAssetPositionUpdate {
+    pub tx_id: LedgerTxId,
+    pub collateral_account_id: CalaAccountId,
+    pub asset: CollateralAsset,
+    pub abs_diff: rust_decimal::Decimal,
+    pub action: CollateralAction,
+    pub effective: chrono::NaiveDate,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 pub enum DisbursedReceivableAccountType {
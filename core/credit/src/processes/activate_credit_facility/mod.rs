@@ -15,7 +15,7 @@ use crate::{
     disbursal::{Disbursals, NewDisbursal},
     error::CoreCreditError,
     event::CoreCreditEvent,
-    jobs::interest_accruals,
+    jobs::{interest_accruals, penalty_accruals},
     ledger::CreditLedger,
     primitives::{CoreCreditAction, CoreCreditObject, CreditFacilityId, DisbursalId},
 };
@@ -161,6 +161,19 @@ where
                     )
                     .await?;
 
+                if credit_facility.terms.penalty_rate.is_some() {
+                    self.jobs
+                        .create_and_spawn_in_op(
+                            &mut op,
+                            uuid::Uuid::new_v4(),
+                            penalty_accruals::PenaltyAccrualJobConfig::<Perms, E> {
+                                credit_facility_id: id,
+                                _phantom: std::marker::PhantomData,
+                            },
+                        )
+                        .await?;
+                }
+
                 self.ledger
                     .activate_credit_facility(op, credit_facility_activation)
                     .await?;
@@ -0,0 +1,150 @@
+mod job;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    credit_facility::CreditFacilities,
+    error::CoreCreditError,
+    event::CoreCreditEvent,
+    ledger::CreditLedger,
+    obligation::{InstallmentPayment, Obligations},
+    payment::Payments,
+    primitives::{CoreCreditAction, CoreCreditObject, CreditFacilityId, UsdCents},
+};
+
+pub use job::*;
+
+pub struct ApplyUnappliedFunds<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    credit_facilities: CreditFacilities<Perms, E>,
+    obligations: Obligations<Perms, E>,
+    payments: Payments<Perms>,
+    ledger: CreditLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms, E> Clone for ApplyUnappliedFunds<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            credit_facilities: self.credit_facilities.clone(),
+            obligations: self.obligations.clone(),
+            payments: self.payments.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApplyUnappliedFunds<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        credit_facilities: &CreditFacilities<Perms, E>,
+        obligations: &Obligations<Perms, E>,
+        payments: &Payments<Perms>,
+        ledger: &CreditLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            credit_facilities: credit_facilities.clone(),
+            obligations: obligations.clone(),
+            payments: payments.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(
+        name = "credit.credit_facility.apply_unapplied_funds.execute",
+        skip(self)
+    )]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<CreditFacilityId>,
+    ) -> Result<(), CoreCreditError> {
+        let id = id.into();
+        let credit_facility = self.credit_facilities.find_by_id_without_audit(id).await?;
+        let unapplied_funds = credit_facility.unapplied_funds();
+        if unapplied_funds.is_zero() {
+            return Ok(());
+        }
+
+        let strategy = credit_facility.terms.payment_allocation_strategy;
+        let mut op = self.credit_facilities.begin_op().await?;
+        let preview = self
+            .obligations
+            .preview_allocation_in_op(&mut op, id, unapplied_funds, strategy)
+            .await?;
+        let amount = unapplied_funds - preview.unallocated;
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                &mut op,
+                CoreCreditObject::all_obligations(),
+                CoreCreditAction::OBLIGATION_RECORD_PAYMENT,
+            )
+            .await?;
+        let effective = crate::time::now().date_naive();
+
+        let payment = self
+            .payments
+            .record_in_op(&mut op, id, amount, &audit_info)
+            .await?;
+
+        let installments = self
+            .obligations
+            .apply_installment_in_op(
+                &mut op,
+                InstallmentPayment {
+                    credit_facility_id: id,
+                    payment_id: payment.id,
+                    amount,
+                    strategy,
+                    payment_source_account_id: Some(
+                        credit_facility.account_ids.unapplied_funds_account_id,
+                    ),
+                    effective,
+                },
+                &audit_info,
+            )
+            .await?;
+        let applied = installments
+            .iter()
+            .fold(UsdCents::ZERO, |total, installment| {
+                total + installment.amount
+            });
+
+        self.credit_facilities
+            .apply_unapplied_funds_in_op(&mut op, id, payment.id, applied, effective, &audit_info)
+            .await?;
+
+        self.ledger
+            .record_obligation_installments(op, installments)
+            .await?;
+
+        Ok(())
+    }
+}
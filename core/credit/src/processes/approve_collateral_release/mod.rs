@@ -0,0 +1,298 @@
+mod job;
+mod send_job;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use core_custody::{CoreCustody, CoreCustodyAction, CoreCustodyEvent, CoreCustodyObject, WalletId};
+use core_price::Price;
+use governance::{ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CVLPct, CoreCreditAction, CoreCreditError, CoreCreditEvent, CoreCreditObject, Jobs,
+    collateral::Collaterals,
+    collateral_release::{CollateralRelease, CollateralReleases},
+    credit_facility::CreditFacilities,
+    ledger::CreditLedger,
+    primitives::{CollateralReleaseId, CollateralReleaseStatus},
+};
+
+pub use job::*;
+pub use send_job::*;
+pub const APPROVE_COLLATERAL_RELEASE_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("collateral-release");
+
+const BELOW_RELEASE_THRESHOLD_REASON: &str = "post-release CVL below threshold";
+
+pub struct ApproveCollateralRelease<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    collateral_releases: CollateralReleases<Perms, E>,
+    credit_facilities: CreditFacilities<Perms, E>,
+    collaterals: Collaterals<Perms, E>,
+    custody: CoreCustody<Perms, E>,
+    ledger: CreditLedger,
+    price: Price,
+    audit: Perms::Audit,
+    jobs: Jobs,
+    release_cvl: Option<CVLPct>,
+}
+
+impl<Perms, E> Clone for ApproveCollateralRelease<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            collateral_releases: self.collateral_releases.clone(),
+            credit_facilities: self.credit_facilities.clone(),
+            collaterals: self.collaterals.clone(),
+            custody: self.custody.clone(),
+            ledger: self.ledger.clone(),
+            price: self.price.clone(),
+            audit: self.audit.clone(),
+            jobs: self.jobs.clone(),
+            release_cvl: self.release_cvl,
+        }
+    }
+}
+
+impl<Perms, E> ApproveCollateralRelease<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction> + From<CoreCustodyAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject> + From<CoreCustodyObject>,
+    E: OutboxEventMarker<GovernanceEvent>
+        + OutboxEventMarker<CoreCreditEvent>
+        + OutboxEventMarker<CoreCustodyEvent>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        collateral_releases: &CollateralReleases<Perms, E>,
+        credit_facilities: &CreditFacilities<Perms, E>,
+        collaterals: &Collaterals<Perms, E>,
+        custody: &CoreCustody<Perms, E>,
+        ledger: &CreditLedger,
+        price: &Price,
+        audit: &Perms::Audit,
+        jobs: &Jobs,
+        release_cvl: Option<CVLPct>,
+    ) -> Self {
+        Self {
+            collateral_releases: collateral_releases.clone(),
+            credit_facilities: credit_facilities.clone(),
+            collaterals: collaterals.clone(),
+            custody: custody.clone(),
+            ledger: ledger.clone(),
+            price: price.clone(),
+            audit: audit.clone(),
+            jobs: jobs.clone(),
+            release_cvl,
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(name = "credit_facility.approve_collateral_release", skip(self))]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<CollateralReleaseId>,
+        approved: bool,
+    ) -> Result<CollateralRelease, CoreCreditError> {
+        let mut release = self
+            .collateral_releases
+            .find_by_id_without_audit(id.into())
+            .await?;
+
+        let mut op = self.collateral_releases.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                &mut op,
+                CoreCreditObject::credit_facility(release.credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_CONCLUDE_COLLATERAL_RELEASE,
+            )
+            .await?;
+
+        let concluded = release
+            .approval_process_concluded(approved, audit_info.clone())
+            .did_execute();
+        let status = release.status();
+        if !matches!(
+            status,
+            CollateralReleaseStatus::Approved | CollateralReleaseStatus::Executing
+        ) {
+            if concluded {
+                self.collateral_releases
+                    .update_in_op(&mut op, &mut release)
+                    .await?;
+                op.commit().await?;
+            }
+            return Ok(release);
+        }
+
+        let credit_facility = self
+            .credit_facilities
+            .find_by_id_without_audit(release.credit_facility_id)
+            .await?;
+        let balance = self
+            .credit_facilities
+            .balances_with_collateral_valuation(&credit_facility)
+            .await?;
+        let price = self.price.usd_cents_per_btc().await?;
+        // A release already handed to the custodian is driven to completion by
+        // its send job rather than re-evaluated.
+        if status == CollateralReleaseStatus::Approved
+            && !credit_facility.terms.is_collateral_release_allowed(
+                balance,
+                release.amount,
+                price,
+                self.release_cvl,
+            )
+        {
+            let _ =
+                release.record_failed(BELOW_RELEASE_THRESHOLD_REASON.to_string(), audit_info)?;
+            self.collateral_releases
+                .update_in_op(&mut op, &mut release)
+                .await?;
+            op.commit().await?;
+            return Ok(release);
+        }
+
+        let collateral = self
+            .collaterals
+            .find_by_id_without_audit(release.collateral_id)
+            .await?;
+        let effective = crate::time::now().date_naive();
+
+        match collateral.custody_wallet_id {
+            Some(wallet_id) => {
+                // Only the intent to send is committed here; the send job calls
+                // the custodian and records the outcome.
+                if release
+                    .record_send_requested(audit_info.clone())?
+                    .did_execute()
+                {
+                    self.jobs
+                        .create_and_spawn_in_op(
+                            &mut op,
+                            release.id,
+                            CollateralReleaseSendJobConfig::<Perms, E> {
+                                collateral_release_id: release.id,
+                                wallet_id,
+                                _phantom: std::marker::PhantomData,
+                            },
+                        )
+                        .await?;
+                }
+                self.collateral_releases
+                    .update_in_op(&mut op, &mut release)
+                    .await?;
+                op.commit().await?;
+            }
+            None => {
+                let collateral_update = self
+                    .collaterals
+                    .record_collateral_release_in_op(
+                        &mut op,
+                        collateral.id,
+                        release.id,
+                        release.amount,
+                        effective,
+                        &audit_info,
+                    )
+                    .await?;
+                let _ = release.record_executed(
+                    collateral_update.as_ref().map(|update| update.tx_id),
+                    None,
+                    effective,
+                    audit_info,
+                )?;
+                self.collateral_releases
+                    .update_in_op(&mut op, &mut release)
+                    .await?;
+
+                match collateral_update {
+                    Some(update) => {
+                        self.ledger
+                            .update_credit_facility_collateral(
+                                op,
+                                update,
+                                credit_facility.account_ids,
+                            )
+                            .await?
+                    }
+                    None => op.commit().await?,
+                }
+            }
+        }
+
+        Ok(release)
+    }
+
+    /// Hands an `Executing` release to the custodian. The idempotency key of the
+    /// release lets the custodian deduplicate a retried send.
+    #[instrument(
+        name = "credit_facility.collateral_release.send_to_custodian",
+        skip(self)
+    )]
+    pub async fn send_to_custodian(
+        &self,
+        id: CollateralReleaseId,
+        wallet_id: WalletId,
+    ) -> Result<CollateralRelease, CoreCreditError> {
+        let mut release = self
+            .collateral_releases
+            .find_by_id_without_audit(id)
+            .await?;
+        if release.status() != CollateralReleaseStatus::Executing {
+            return Ok(release);
+        }
+
+        let external_transfer_id = self
+            .custody
+            .send_funds(
+                wallet_id,
+                &release.address,
+                release.amount,
+                &release.idempotency_key(),
+            )
+            .await?;
+
+        let mut op = self.collateral_releases.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                &mut op,
+                CoreCreditObject::credit_facility(release.credit_facility_id),
+                CoreCreditAction::CREDIT_FACILITY_CONCLUDE_COLLATERAL_RELEASE,
+            )
+            .await?;
+        if release
+            .record_executed(
+                None,
+                Some(external_transfer_id),
+                crate::time::now().date_naive(),
+                audit_info,
+            )?
+            .did_execute()
+        {
+            self.collateral_releases
+                .update_in_op(&mut op, &mut release)
+                .await?;
+        }
+        op.commit().await?;
+
+        Ok(release)
+    }
+}
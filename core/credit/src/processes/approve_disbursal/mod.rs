@@ -118,7 +118,7 @@ where
                 tracing::Span::current().record("already_applied", true);
                 disbursal
             }
-            crate::ApprovalProcessOutcome::Approved((disbursal, obligation)) => {
+            crate::ApprovalProcessOutcome::Approved((disbursal, obligations)) => {
                 tracing::Span::current().record("already_applied", false);
 
                 let credit_facility = self
@@ -128,7 +128,9 @@ where
                 self.ledger
                     .settle_disbursal(
                         op,
-                        obligation,
+                        tx_id,
+                        &disbursal,
+                        &obligations,
                         credit_facility.account_ids.facility_account_id,
                     )
                     .await?;
@@ -1,3 +1,7 @@
 pub mod activate_credit_facility;
+pub mod apply_unapplied_funds;
+pub mod approve_collateral_release;
 pub mod approve_credit_facility;
 pub mod approve_disbursal;
+pub mod restructure_credit_facility;
+pub mod rollover_credit_facility;
@@ -0,0 +1,98 @@
+mod job;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{
+    ApprovalProcessId, ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject,
+};
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreCreditAction, CoreCreditEvent, CoreCreditObject, CreditFacilities, CreditFacility,
+    CreditFacilityId, Jobs, error::CoreCreditError,
+    interest_accrual_cycle::NewInterestAccrualCycleData, jobs::interest_accruals,
+};
+
+pub use job::*;
+pub const APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("credit-facility-restructuring");
+
+pub struct RestructureCreditFacility<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    credit_facilities: CreditFacilities<Perms, E>,
+    jobs: Jobs,
+}
+
+impl<Perms, E> Clone for RestructureCreditFacility<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            credit_facilities: self.credit_facilities.clone(),
+            jobs: self.jobs.clone(),
+        }
+    }
+}
+
+impl<Perms, E> RestructureCreditFacility<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreCreditEvent>,
+{
+    pub fn new(credit_facilities: &CreditFacilities<Perms, E>, jobs: &Jobs) -> Self {
+        Self {
+            credit_facilities: credit_facilities.clone(),
+            jobs: jobs.clone(),
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(name = "credit_facility.restructuring.execute", skip(self))]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<CreditFacilityId>,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<CreditFacility, CoreCreditError> {
+        let id = id.into();
+        let mut op = self.credit_facilities.begin_op().await?;
+
+        let (credit_facility, new_cycle_data) = self
+            .credit_facilities
+            .conclude_terms_modification_in_op(&mut op, id, approval_process_id, approved)
+            .await?;
+
+        if let Some(NewInterestAccrualCycleData {
+            id: accrual_cycle_id,
+            first_accrual_end_date,
+        }) = new_cycle_data
+        {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    &mut op,
+                    accrual_cycle_id,
+                    interest_accruals::InterestAccrualJobConfig::<Perms, E> {
+                        credit_facility_id: id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                    first_accrual_end_date,
+                )
+                .await?;
+        }
+
+        op.commit().await?;
+
+        Ok(credit_facility)
+    }
+}
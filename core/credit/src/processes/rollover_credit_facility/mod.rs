@@ -0,0 +1,305 @@
+mod job;
+
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{
+    ApprovalProcessId, ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject,
+};
+use outbox::OutboxEventMarker;
+use public_id::PublicIds;
+
+use crate::{
+    Jobs,
+    collateral::Collaterals,
+    credit_facility::{CreditFacilities, NewCreditFacility, RolloverRequest},
+    disbursal::{Disbursals, NewDisbursal},
+    error::CoreCreditError,
+    event::CoreCreditEvent,
+    jobs::{interest_accruals, penalty_accruals},
+    ledger::{CreditFacilityAccountIds, CreditLedger},
+    obligation::Obligations,
+    primitives::{
+        CREDIT_FACILITY_REF_TARGET, CollateralId, CoreCreditAction, CoreCreditObject,
+        CreditFacilityId, DISBURSAL_REF_TARGET, DisbursalId, LedgerTxId,
+    },
+    terms::PrincipalRepayment,
+};
+
+pub use job::*;
+pub const APPROVE_CREDIT_FACILITY_ROLLOVER_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("credit-facility-rollover");
+
+pub struct RolloverCreditFacility<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    credit_facilities: CreditFacilities<Perms, E>,
+    obligations: Obligations<Perms, E>,
+    collaterals: Collaterals<Perms, E>,
+    disbursals: Disbursals<Perms, E>,
+    ledger: CreditLedger,
+    jobs: Jobs,
+    public_ids: PublicIds,
+}
+
+impl<Perms, E> Clone for RolloverCreditFacility<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            credit_facilities: self.credit_facilities.clone(),
+            obligations: self.obligations.clone(),
+            collaterals: self.collaterals.clone(),
+            disbursals: self.disbursals.clone(),
+            ledger: self.ledger.clone(),
+            jobs: self.jobs.clone(),
+            public_ids: self.public_ids.clone(),
+        }
+    }
+}
+
+impl<Perms, E> RolloverCreditFacility<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreCreditAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreCreditObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreCreditEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(
+        credit_facilities: &CreditFacilities<Perms, E>,
+        obligations: &Obligations<Perms, E>,
+        collaterals: &Collaterals<Perms, E>,
+        disbursals: &Disbursals<Perms, E>,
+        ledger: &CreditLedger,
+        jobs: &Jobs,
+        public_ids: &PublicIds,
+    ) -> Self {
+        Self {
+            credit_facilities: credit_facilities.clone(),
+            obligations: obligations.clone(),
+            collaterals: collaterals.clone(),
+            disbursals: disbursals.clone(),
+            ledger: ledger.clone(),
+            jobs: jobs.clone(),
+            public_ids: public_ids.clone(),
+        }
+    }
+
+    /// Once the rollover of `rolled_over_id` is approved, moves its outstanding
+    /// obligations and collateral into a new, already active facility within a
+    /// single ledger operation. Capitalized interest is booked in the rollover
+    /// transaction itself.
+    #[es_entity::retry_on_concurrent_modification(any_error = true)]
+    #[instrument(name = "credit.credit_facility.rollover.execute", skip(self))]
+    pub async fn execute(
+        &self,
+        rolled_over_id: impl es_entity::RetryableInto<CreditFacilityId>,
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+    ) -> Result<(), CoreCreditError> {
+        let rolled_over_id = rolled_over_id.into();
+        let mut op = self
+            .credit_facilities
+            .begin_op()
+            .await?
+            .with_db_time()
+            .await?;
+
+        let Some((
+            rolled_over,
+            RolloverRequest {
+                approval_process_id: _,
+                amount,
+                terms,
+                customer_type,
+                capitalize_interest,
+            },
+            audit_info,
+        )) = self
+            .credit_facilities
+            .conclude_rollover_approval_in_op(
+                &mut op,
+                rolled_over_id,
+                approval_process_id,
+                approved,
+            )
+            .await?
+        else {
+            op.commit().await?;
+            return Ok(());
+        };
+
+        let id = CreditFacilityId::new();
+        let account_ids = CreditFacilityAccountIds::new();
+        let collateral_id = CollateralId::new();
+        let tx_id = LedgerTxId::new();
+        let effective = op.now().date_naive();
+
+        let rolled_over_receivables = self
+            .obligations
+            .roll_over_in_op(
+                &mut op,
+                rolled_over.id,
+                id,
+                tx_id,
+                capitalize_interest,
+                effective,
+                &audit_info,
+            )
+            .await?;
+
+        let (collateral, collateral_asset_position_updates) = self
+            .collaterals
+            .roll_over_in_op(
+                &mut op,
+                rolled_over.collateral_id,
+                collateral_id,
+                id,
+                account_ids.collateral_account_id,
+                tx_id,
+                effective,
+                &audit_info,
+            )
+            .await?;
+
+        let public_id = self
+            .public_ids
+            .create_in_op(&mut op, CREDIT_FACILITY_REF_TARGET, id)
+            .await?;
+        let new_credit_facility = NewCreditFacility::builder()
+            .id(id)
+            .ledger_tx_id(tx_id)
+            .approval_process_id(approval_process_id)
+            .collateral_id(collateral_id)
+            .customer_id(rolled_over.customer_id)
+            .terms(terms.clone())
+            .amount(amount)
+            .account_ids(account_ids)
+            .disbursal_credit_account_id(rolled_over.disbursal_credit_account_id)
+            .public_id(public_id.id)
+            .audit_info(audit_info.clone())
+            .build()
+            .expect("could not build new credit facility");
+
+        let crate::RolloverData {
+            credit_facility,
+            credit_facility_rollover,
+            next_accrual_period,
+        } = self
+            .credit_facilities
+            .roll_over_in_op(
+                &mut op,
+                rolled_over.id,
+                new_credit_facility,
+                tx_id,
+                rolled_over_receivables,
+                &collateral,
+                &audit_info,
+            )
+            .await?;
+
+        let now = op.now();
+        let due_date = credit_facility.matures_at.expect("Facility is not active");
+        let overdue_date = terms
+            .obligation_overdue_duration_from_due
+            .map(|d| d.end_date(due_date));
+        let liquidation_date = terms
+            .obligation_liquidation_duration_from_due
+            .map(|d| d.end_date(due_date));
+
+        let rolled_over_amount = credit_facility_rollover.rolled_over_amount();
+        let disbursals = [
+            (
+                rolled_over_amount,
+                match terms.principal_repayment {
+                    PrincipalRepayment::AtMaturity => vec![],
+                    _ => terms.principal_installments(rolled_over_amount, now, due_date),
+                },
+            ),
+            (
+                credit_facility_rollover.capitalized_interest_amount(),
+                vec![],
+            ),
+            (credit_facility.structuring_fee(), vec![]),
+        ];
+        for (amount, principal_installments) in disbursals {
+            if amount.is_zero() {
+                continue;
+            }
+
+            let disbursal_id = DisbursalId::new();
+            let public_id = self
+                .public_ids
+                .create_in_op(&mut op, DISBURSAL_REF_TARGET, disbursal_id)
+                .await?;
+            let new_disbursal = NewDisbursal::builder()
+                .id(disbursal_id)
+                .credit_facility_id(credit_facility.id)
+                .approval_process_id(credit_facility.approval_process_id)
+                .amount(amount)
+                .account_ids(credit_facility.account_ids)
+                .disbursal_credit_account_id(credit_facility.disbursal_credit_account_id)
+                .due_date(due_date)
+                .overdue_date(overdue_date)
+                .liquidation_date(liquidation_date)
+                .principal_installments(principal_installments)
+                .audit_info(audit_info.clone())
+                .public_id(public_id.id)
+                .build()
+                .expect("could not build new disbursal");
+
+            self.disbursals
+                .create_first_disbursal_in_op(&mut op, new_disbursal, &audit_info)
+                .await?;
+        }
+
+        let accrual_id = credit_facility
+            .interest_accrual_cycle_in_progress()
+            .expect("First accrual not found")
+            .id;
+        self.jobs
+            .create_and_spawn_at_in_op(
+                &mut op,
+                accrual_id,
+                interest_accruals::InterestAccrualJobConfig::<Perms, E> {
+                    credit_facility_id: id,
+                    _phantom: std::marker::PhantomData,
+                },
+                next_accrual_period.end,
+            )
+            .await?;
+
+        if terms.penalty_rate.is_some() {
+            self.jobs
+                .create_and_spawn_in_op(
+                    &mut op,
+                    uuid::Uuid::new_v4(),
+                    penalty_accruals::PenaltyAccrualJobConfig::<Perms, E> {
+                        credit_facility_id: id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                )
+                .await?;
+        }
+
+        self.ledger
+            .handle_facility_rollover(
+                op,
+                &credit_facility,
+                customer_type,
+                terms.duration.duration_type(),
+                credit_facility_rollover,
+                collateral_asset_position_updates,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
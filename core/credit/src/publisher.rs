@@ -2,6 +2,9 @@ use outbox::{Outbox, OutboxEventMarker};
 
 use crate::{
     collateral::{Collateral, CollateralEvent, error::CollateralError},
+    collateral_release::{
+        CollateralRelease, CollateralReleaseEvent, error::CollateralReleaseError,
+    },
     credit_facility::{CreditFacility, CreditFacilityEvent, error::CreditFacilityError},
     disbursal::{Disbursal, DisbursalEvent, error::DisbursalError},
     event::*,
@@ -15,6 +18,7 @@ use crate::{
     obligation_installment::{
         ObligationInstallment, ObligationInstallmentEvent, error::ObligationInstallmentError,
     },
+    primitives::CollateralReleaseStatus,
 };
 
 pub struct CreditFacilityPublisher<E>
@@ -56,7 +60,7 @@ where
             .filter_map(|event| match &event.event {
                 Initialized { amount, terms, .. } => Some(CoreCreditEvent::FacilityCreated {
                     id: entity.id,
-                    terms: *terms,
+                    terms: terms.clone(),
                     amount: *amount,
                     created_at: entity.created_at(),
                 }),
@@ -92,6 +96,82 @@ where
                     outstanding: *outstanding,
                     price: *price,
                 }),
+                PrepaymentRecorded {
+                    payment_id,
+                    ledger_tx_id,
+                    amount,
+                    fee_amount,
+                    effective,
+                    ..
+                } => Some(CoreCreditEvent::FacilityPrepaymentRecorded {
+                    credit_facility_id: entity.id,
+                    payment_id: *payment_id,
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *amount,
+                    fee_amount: *fee_amount,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                }),
+                PenaltyAccrued {
+                    obligation_id,
+                    ledger_tx_id,
+                    amount,
+                    effective,
+                    ..
+                } => Some(CoreCreditEvent::FacilityPenaltyAccrued {
+                    credit_facility_id: entity.id,
+                    obligation_id: *obligation_id,
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *amount,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                }),
+                TermsModified {
+                    previous_terms,
+                    terms,
+                    matures_at,
+                    effective,
+                    ..
+                } => Some(CoreCreditEvent::FacilityTermsModified {
+                    id: entity.id,
+                    previous_terms: previous_terms.clone(),
+                    terms: terms.clone(),
+                    matures_at: *matures_at,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                }),
+                RolledOver {
+                    rolled_over_to,
+                    ledger_tx_id,
+                    amount,
+                    collateral,
+                    effective,
+                    ..
+                } => Some(CoreCreditEvent::FacilityRolledOver {
+                    id: entity.id,
+                    rolled_over_to: *rolled_over_to,
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *amount,
+                    collateral: *collateral,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                }),
+                RolledOverFrom {
+                    rolled_over_from,
+                    ledger_tx_id,
+                    amount,
+                    collateral,
+                    effective,
+                    ..
+                } => Some(CoreCreditEvent::FacilityRolledOverFrom {
+                    id: entity.id,
+                    rolled_over_from: *rolled_over_from,
+                    ledger_tx_id: *ledger_tx_id,
+                    amount: *amount,
+                    collateral: *collateral,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                }),
 
                 _ => None,
             })
@@ -122,6 +202,24 @@ where
                     action,
                     ledger_tx_id,
                     ..
+                }
+                | UpdatedViaLiquidation {
+                    abs_diff,
+                    action,
+                    ledger_tx_id,
+                    ..
+                }
+                | UpdatedViaRollover {
+                    abs_diff,
+                    action,
+                    ledger_tx_id,
+                    ..
+                }
+                | UpdatedViaRelease {
+                    abs_diff,
+                    action,
+                    ledger_tx_id,
+                    ..
                 } => Some(CoreCreditEvent::FacilityCollateralUpdated {
                     ledger_tx_id: *ledger_tx_id,
                     abs_diff: *abs_diff,
@@ -131,6 +229,23 @@ where
                     new_amount: entity.amount,
                     credit_facility_id: entity.credit_facility_id,
                 }),
+                AssetPositionUpdated {
+                    ledger_tx_id,
+                    asset,
+                    quantity,
+                    abs_diff,
+                    action,
+                    ..
+                } => Some(CoreCreditEvent::FacilityCollateralAssetUpdated {
+                    credit_facility_id: entity.credit_facility_id,
+                    ledger_tx_id: *ledger_tx_id,
+                    asset: *asset,
+                    quantity: *quantity,
+                    abs_diff: *abs_diff,
+                    action: *action,
+                    recorded_at: event.recorded_at,
+                    effective: event.recorded_at.date_naive(),
+                }),
                 _ => None,
             })
             .collect::<Vec<_>>();
@@ -140,6 +255,56 @@ where
         Ok(())
     }
 
+    pub async fn publish_collateral_release(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &CollateralRelease,
+        new_events: es_entity::LastPersisted<'_, CollateralReleaseEvent>,
+    ) -> Result<(), CollateralReleaseError> {
+        use CollateralReleaseEvent::*;
+        let publish_events = new_events
+            .map(|event| {
+                let (status, effective) = match &event.event {
+                    Initialized { .. } => (
+                        CollateralReleaseStatus::PendingApproval,
+                        event.recorded_at.date_naive(),
+                    ),
+                    ApprovalProcessConcluded { approved: true, .. } => (
+                        CollateralReleaseStatus::Approved,
+                        event.recorded_at.date_naive(),
+                    ),
+                    ApprovalProcessConcluded {
+                        approved: false, ..
+                    } => (
+                        CollateralReleaseStatus::Denied,
+                        event.recorded_at.date_naive(),
+                    ),
+                    SendRequested { .. } => (
+                        CollateralReleaseStatus::Executing,
+                        event.recorded_at.date_naive(),
+                    ),
+                    Executed { effective, .. } => (CollateralReleaseStatus::Executed, *effective),
+                    Failed { .. } => (
+                        CollateralReleaseStatus::Failed,
+                        event.recorded_at.date_naive(),
+                    ),
+                };
+                CoreCreditEvent::FacilityCollateralReleaseUpdated {
+                    id: entity.id,
+                    credit_facility_id: entity.credit_facility_id,
+                    amount: entity.amount,
+                    status,
+                    recorded_at: event.recorded_at,
+                    effective,
+                }
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(op, publish_events)
+            .await?;
+        Ok(())
+    }
+
     pub async fn publish_disbursal(
         &self,
         op: &mut impl es_entity::AtomicOperation,
@@ -303,7 +468,7 @@ where
     ) -> Result<(), LiquidationProcessError> {
         use LiquidationProcessEvent::*;
         let publish_events = new_events
-            .map(|event| match &event.event {
+            .filter_map(|event| match &event.event {
                 Initialized {
                     id,
                     obligation_id,
@@ -312,7 +477,7 @@ where
                     initial_amount,
                     effective,
                     ..
-                } => CoreCreditEvent::LiquidationProcessStarted {
+                } => Some(CoreCreditEvent::LiquidationProcessStarted {
                     id: *id,
                     obligation_id: *obligation_id,
                     credit_facility_id: *credit_facility_id,
@@ -320,12 +485,31 @@ where
                     effective: *effective,
                     ledger_tx_id: *ledger_tx_id,
                     recorded_at: event.recorded_at,
-                },
-                Completed { .. } => CoreCreditEvent::LiquidationProcessConcluded {
+                }),
+                CollateralSold {
+                    ledger_tx_id,
+                    collateral,
+                    price,
+                    proceeds,
+                    effective,
+                    ..
+                } => Some(CoreCreditEvent::LiquidationCollateralSold {
                     id: entity.id,
                     obligation_id: entity.obligation_id,
                     credit_facility_id: entity.credit_facility_id,
-                },
+                    collateral: *collateral,
+                    price: *price,
+                    proceeds: *proceeds,
+                    ledger_tx_id: *ledger_tx_id,
+                    recorded_at: event.recorded_at,
+                    effective: *effective,
+                }),
+                Completed { .. } => Some(CoreCreditEvent::LiquidationProcessConcluded {
+                    id: entity.id,
+                    obligation_id: entity.obligation_id,
+                    credit_facility_id: entity.credit_facility_id,
+                }),
+                _ => None,
             })
             .collect::<Vec<_>>();
         self.outbox
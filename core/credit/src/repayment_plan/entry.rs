@@ -7,6 +7,7 @@ use crate::primitives::*;
 pub enum RepaymentType {
     Disbursal,
     Interest,
+    Penalty,
 }
 
 impl From<&ObligationType> for RepaymentType {
@@ -14,6 +15,7 @@ impl From<&ObligationType> for RepaymentType {
         match value {
             ObligationType::Disbursal => Self::Disbursal,
             ObligationType::Interest => Self::Interest,
+            ObligationType::Penalty => Self::Penalty,
         }
     }
 }
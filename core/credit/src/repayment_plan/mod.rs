@@ -37,7 +37,7 @@ impl CreditFacilityRepaymentPlan {
     }
 
     fn planned_disbursals(&self) -> Vec<CreditFacilityRepaymentPlanEntry> {
-        let terms = self.terms.expect("Missing FacilityCreated event");
+        let terms = self.terms.as_ref().expect("Missing FacilityCreated event");
         let facility_amount = self.facility_amount;
         let structuring_fee = terms.one_time_fee_rate.apply(facility_amount);
 
@@ -61,20 +61,25 @@ impl CreditFacilityRepaymentPlan {
                 effective: activated_at.date_naive(),
             })
         }
-        disbursals.push(CreditFacilityRepaymentPlanEntry {
-            repayment_type: RepaymentType::Disbursal,
-            obligation_id: None,
-            status: RepaymentStatus::Upcoming,
+        disbursals.extend(
+            terms
+                .principal_installments(facility_amount, activated_at, maturity_date)
+                .into_iter()
+                .map(|installment| CreditFacilityRepaymentPlanEntry {
+                    repayment_type: RepaymentType::Disbursal,
+                    obligation_id: None,
+                    status: RepaymentStatus::Upcoming,
 
-            initial: facility_amount,
-            outstanding: facility_amount,
+                    initial: installment.amount,
+                    outstanding: installment.amount,
 
-            due_at: maturity_date,
-            overdue_at: None,
-            defaulted_at: None,
-            recorded_at: activated_at,
-            effective: activated_at.date_naive(),
-        });
+                    due_at: installment.due_date,
+                    overdue_at: None,
+                    defaulted_at: None,
+                    recorded_at: activated_at,
+                    effective: activated_at.date_naive(),
+                }),
+        );
 
         disbursals
     }
@@ -83,7 +88,7 @@ impl CreditFacilityRepaymentPlan {
         &self,
         updated_entries: &[CreditFacilityRepaymentPlanEntry],
     ) -> Vec<CreditFacilityRepaymentPlanEntry> {
-        let terms = self.terms.expect("Missing FacilityCreated event");
+        let terms = self.terms.as_ref().expect("Missing FacilityCreated event");
         let activated_at = self.activated_at();
 
         let maturity_date = terms.duration.maturity_date(activated_at);
@@ -101,20 +106,24 @@ impl CreditFacilityRepaymentPlan {
                     .truncate(maturity_date)
             };
 
-        let disbursed_outstanding = updated_entries
-            .iter()
-            .filter_map(|entry| match entry {
-                CreditFacilityRepaymentPlanEntry {
-                    repayment_type: RepaymentType::Disbursal,
-                    outstanding,
-                    ..
-                } => Some(*outstanding),
-                _ => None,
-            })
-            .fold(UsdCents::ZERO, |acc, outstanding| acc + outstanding);
-
         let mut planned_interest_entries = vec![];
         while let Some(period) = next_interest_period {
+            let disbursed_outstanding = updated_entries
+                .iter()
+                .filter_map(|entry| match entry {
+                    CreditFacilityRepaymentPlanEntry {
+                        repayment_type: RepaymentType::Disbursal,
+                        status,
+                        outstanding,
+                        due_at,
+                        ..
+                    } if *status != RepaymentStatus::Paid && *due_at >= period.start => {
+                        Some(*outstanding)
+                    }
+                    _ => None,
+                })
+                .fold(UsdCents::ZERO, |acc, outstanding| acc + outstanding);
+
             let interest = terms
                 .annual_rate
                 .interest_for_time_period(disbursed_outstanding, period.days());
@@ -150,12 +159,15 @@ impl CreditFacilityRepaymentPlan {
 
         match event {
             CoreCreditEvent::FacilityCreated { terms, amount, .. } => {
-                self.terms = Some(*terms);
+                self.terms = Some(terms.clone());
                 self.facility_amount = *amount;
             }
             CoreCreditEvent::FacilityActivated { activated_at, .. } => {
                 self.activated_at = Some(*activated_at);
             }
+            CoreCreditEvent::FacilityTermsModified { terms, .. } => {
+                self.terms = Some(terms.clone());
+            }
             CoreCreditEvent::ObligationCreated {
                 id,
                 obligation_type,
@@ -292,6 +304,7 @@ mod tests {
         disbursals_unpaid: usize,
         disbursals_paid: usize,
         disbursals_upcoming: usize,
+        penalties: usize,
     }
 
     fn terms(one_time_fee_rate: u64) -> TermValues {
@@ -386,6 +399,10 @@ mod tests {
                     repayment_type: RepaymentType::Interest,
                     ..
                 } => res.interest_unpaid += 1,
+                CreditFacilityRepaymentPlanEntry {
+                    repayment_type: RepaymentType::Penalty,
+                    ..
+                } => res.penalties += 1,
             }
         }
 
@@ -405,10 +422,59 @@ mod tests {
                 disbursals_unpaid: 0,
                 disbursals_paid: 0,
                 disbursals_upcoming: 2,
+                penalties: 0,
             }
         );
     }
 
+    #[test]
+    fn facility_created_with_amortizing_principal() {
+        let terms = TermValues {
+            principal_repayment: crate::terms::PrincipalRepayment::EqualPrincipal,
+            ..terms(0)
+        };
+        let mut plan = plan(terms);
+        process_events(
+            &mut plan,
+            vec![CoreCreditEvent::FacilityActivated {
+                id: CreditFacilityId::new(),
+                activation_tx_id: LedgerTxId::new(),
+                activated_at: default_start_date(),
+                amount: default_facility_amount(),
+            }],
+        );
+
+        let counts = count_entries(&plan);
+        assert_eq!(
+            counts,
+            EntriesCount {
+                interest_unpaid: 0,
+                interest_paid: 0,
+                interest_upcoming: 4,
+                disbursals_unpaid: 0,
+                disbursals_paid: 0,
+                disbursals_upcoming: 4,
+                penalties: 0,
+            }
+        );
+
+        let principal_total = plan
+            .entries
+            .iter()
+            .filter(|e| e.repayment_type == RepaymentType::Disbursal)
+            .fold(UsdCents::ZERO, |acc, e| acc + e.initial);
+        assert_eq!(principal_total, default_facility_amount());
+
+        let interest = plan
+            .entries
+            .iter()
+            .filter(|e| e.repayment_type == RepaymentType::Interest)
+            .map(|e| e.initial)
+            .collect::<Vec<_>>();
+        assert!(interest[1] < interest[0]);
+        assert!(interest[2] < interest[1]);
+    }
+
     #[test]
     fn with_zero_structuring_fee() {
         let mut plan = initial_plan_no_structuring_fee();
@@ -431,6 +497,7 @@ mod tests {
                 disbursals_unpaid: 0,
                 disbursals_paid: 0,
                 disbursals_upcoming: 1,
+                penalties: 0,
             }
         );
     }
@@ -469,6 +536,7 @@ mod tests {
                 disbursals_unpaid: 0,
                 disbursals_paid: 0,
                 disbursals_upcoming: 0,
+                penalties: 0,
             }
         );
     }
@@ -517,6 +585,7 @@ mod tests {
                 disbursals_unpaid: 0,
                 disbursals_paid: 0,
                 disbursals_upcoming: 0,
+                penalties: 0,
             }
         );
     }
@@ -557,6 +626,7 @@ mod tests {
                 disbursals_unpaid: 1,
                 disbursals_paid: 0,
                 disbursals_upcoming: 0,
+                penalties: 0,
             }
         );
     }
@@ -609,6 +679,60 @@ mod tests {
                 disbursals_unpaid: 1,
                 disbursals_paid: 0,
                 disbursals_upcoming: 0,
+                penalties: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn with_penalty_obligation_created() {
+        let mut plan = initial_plan();
+
+        let disbursal_recorded_at = default_start_date();
+        let penalty_recorded_at = default_start_date_with_days(45);
+        let events = vec![
+            CoreCreditEvent::FacilityActivated {
+                id: CreditFacilityId::new(),
+                activation_tx_id: LedgerTxId::new(),
+                activated_at: default_start_date(),
+                amount: default_facility_amount(),
+            },
+            CoreCreditEvent::ObligationCreated {
+                id: ObligationId::new(),
+                obligation_type: ObligationType::Disbursal,
+                credit_facility_id: CreditFacilityId::new(),
+                amount: UsdCents::from(10_000_000),
+                due_at: disbursal_recorded_at,
+                overdue_at: None,
+                defaulted_at: None,
+                recorded_at: disbursal_recorded_at,
+                effective: disbursal_recorded_at.date_naive(),
+            },
+            CoreCreditEvent::ObligationCreated {
+                id: ObligationId::new(),
+                obligation_type: ObligationType::Penalty,
+                credit_facility_id: CreditFacilityId::new(),
+                amount: UsdCents::from(5_000),
+                due_at: penalty_recorded_at,
+                overdue_at: None,
+                defaulted_at: None,
+                recorded_at: penalty_recorded_at,
+                effective: penalty_recorded_at.date_naive(),
+            },
+        ];
+        process_events(&mut plan, events);
+
+        let counts = count_entries(&plan);
+        assert_eq!(
+            counts,
+            EntriesCount {
+                interest_unpaid: 0,
+                interest_paid: 0,
+                interest_upcoming: 4,
+                disbursals_unpaid: 1,
+                disbursals_paid: 0,
+                disbursals_upcoming: 0,
+                penalties: 1,
             }
         );
     }
@@ -672,6 +796,7 @@ mod tests {
                 disbursals_unpaid: 1,
                 disbursals_paid: 0,
                 disbursals_upcoming: 0,
+                penalties: 0,
             }
         );
 
@@ -750,6 +875,7 @@ mod tests {
                 disbursals_unpaid: 1,
                 disbursals_paid: 0,
                 disbursals_upcoming: 0,
+                penalties: 0,
             }
         );
 
@@ -877,6 +1003,7 @@ mod tests {
                 disbursals_unpaid: 1,
                 disbursals_paid: 0,
                 disbursals_upcoming: 0,
+                penalties: 0,
             }
         );
     }
@@ -19,6 +19,8 @@ pub enum TermsError {
         "TermsError - MarginCallBelowLiquidationLimit: margin_call_cvl {0} <= liquidation_cvl {1}"
     )]
     MarginCallBelowLiquidationLimit(CVLPct, CVLPct),
+    #[error("TermsError - InvalidCollateralHaircut: haircuts must be between 0 and 100")]
+    InvalidCollateralHaircut,
     #[error("TermsError - UninitializedField: {0}")]
     UninitializedField(#[from] derive_builder::UninitializedFieldError),
 }
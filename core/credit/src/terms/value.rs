@@ -1,4 +1,6 @@
-use chrono::{DateTime, Datelike, TimeZone, Utc};
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
 use derive_builder::{Builder, UninitializedFieldError};
 use rust_decimal::{Decimal, prelude::*};
 use rust_decimal_macros::dec;
@@ -10,14 +12,16 @@ use schemars::JsonSchema;
 use crate::{
     ledger::CreditFacilityBalanceSummary,
     primitives::{
-        CVLPct, CollateralizationState, DisbursedReceivableAccountCategory, PriceOfOneBTC,
-        Satoshis, UsdCents,
+        CVLPct, CollateralAsset, CollateralizationState, DisbursedReceivableAccountCategory,
+        ObligationStatus, ObligationType, PriceOfOneBTC, Satoshis, UsdCents,
     },
 };
 
 use super::error::TermsError;
 
-const NUMBER_OF_DAYS_IN_YEAR: u64 = 365;
+use core_money::NUMBER_OF_DAYS_IN_YEAR;
+pub use core_money::{InterestInterval, InterestPeriod};
+
 const SHORT_TERM_DURATION_MONTHS_THRESHOLD: u32 = 12;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -41,12 +45,24 @@ impl AnnualRatePct {
     }
 }
 
+impl AnnualRatePct {
+    fn periodic_rate(&self, periods_per_year: u32) -> Decimal {
+        self.0 / dec!(100) / Decimal::from(periods_per_year)
+    }
+}
+
 impl From<Decimal> for AnnualRatePct {
     fn from(value: Decimal) -> Self {
         AnnualRatePct(value)
     }
 }
 
+impl From<AnnualRatePct> for Decimal {
+    fn from(value: AnnualRatePct) -> Self {
+        value.0
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(transparent)]
@@ -74,6 +90,63 @@ impl From<Decimal> for OneTimeFeeRatePct {
         OneTimeFeeRatePct(value)
     }
 }
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct HaircutPct(Decimal);
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(HaircutPct);
+
+impl HaircutPct {
+    pub const ZERO: Self = Self(dec!(0));
+
+    pub fn new(pct: u64) -> Self {
+        HaircutPct(Decimal::from(pct))
+    }
+
+    pub fn apply(&self, value: UsdCents) -> UsdCents {
+        let haircut_value = (value.to_usd() * ((dec!(100) - self.0) / dec!(100)))
+            .round_dp_with_strategy(2, RoundingStrategy::ToZero);
+
+        UsdCents::try_from_usd(haircut_value).expect("Unexpected negative number")
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0 >= dec!(0) && self.0 < dec!(100)
+    }
+}
+
+impl From<Decimal> for HaircutPct {
+    fn from(value: Decimal) -> Self {
+        HaircutPct(value)
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct CollateralHaircuts(BTreeMap<CollateralAsset, HaircutPct>);
+
+impl CollateralHaircuts {
+    pub fn for_asset(&self, asset: CollateralAsset) -> HaircutPct {
+        self.0.get(&asset).copied().unwrap_or(HaircutPct::ZERO)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (CollateralAsset, HaircutPct)> + '_ {
+        self.0.iter().map(|(asset, haircut)| (*asset, *haircut))
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.values().all(HaircutPct::is_valid)
+    }
+}
+
+impl FromIterator<(CollateralAsset, HaircutPct)> for CollateralHaircuts {
+    fn from_iter<T: IntoIterator<Item = (CollateralAsset, HaircutPct)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value", rename_all = "snake_case")]
@@ -139,92 +212,71 @@ impl ObligationDuration {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
-pub struct InterestPeriod {
-    pub interval: InterestInterval,
-    pub start: DateTime<Utc>,
-    pub end: DateTime<Utc>,
+#[serde(rename_all = "snake_case")]
+pub enum PaymentAllocationStrategy {
+    #[default]
+    InterestFirst,
+    OldestFirst,
+    // defaulted interest, overdue interest, defaulted/overdue principal, then current amounts
+    DelinquentFirst,
 }
 
-impl InterestPeriod {
-    fn new(interval: InterestInterval, start: DateTime<Utc>) -> Self {
-        let end = interval.end_date_starting_at(start);
-        Self {
-            interval,
-            start,
-            end,
+impl PaymentAllocationStrategy {
+    pub fn bucket(&self, obligation_type: ObligationType, status: ObligationStatus) -> u8 {
+        match self {
+            Self::InterestFirst => match obligation_type {
+                ObligationType::Penalty | ObligationType::Interest => 0,
+                ObligationType::Disbursal => 1,
+            },
+            Self::OldestFirst => 0,
+            Self::DelinquentFirst => match (obligation_type, status) {
+                (
+                    ObligationType::Penalty | ObligationType::Interest,
+                    ObligationStatus::Defaulted,
+                ) => 0,
+                (ObligationType::Penalty | ObligationType::Interest, ObligationStatus::Overdue) => {
+                    1
+                }
+                (ObligationType::Disbursal, ObligationStatus::Defaulted) => 2,
+                (ObligationType::Disbursal, ObligationStatus::Overdue) => 3,
+                (ObligationType::Penalty | ObligationType::Interest, _) => 4,
+                (ObligationType::Disbursal, _) => 5,
+            },
         }
     }
 
-    pub fn next(&self) -> Self {
-        Self::new(self.interval, self.end + chrono::Duration::seconds(1))
-    }
-
-    pub fn truncate(&self, latest_possible_end_date: DateTime<Utc>) -> Option<Self> {
-        if self.start > latest_possible_end_date {
-            return None;
+    pub fn orders_by_due_date(&self) -> bool {
+        match self {
+            Self::InterestFirst => false,
+            Self::OldestFirst | Self::DelinquentFirst => true,
         }
-
-        Some(Self {
-            interval: self.interval,
-            start: self.start,
-            end: self.end.min(latest_possible_end_date),
-        })
-    }
-
-    pub fn days(&self) -> u32 {
-        self.end.day() - self.start.day() + 1
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
-#[serde(tag = "type", rename_all = "snake_case")]
-pub enum InterestInterval {
-    EndOfMonth,
-    EndOfDay,
+#[serde(rename_all = "snake_case")]
+pub enum PrincipalRepayment {
+    #[default]
+    AtMaturity,
+    EqualPrincipal,
+    Annuity,
 }
 
-impl InterestInterval {
-    pub fn period_from(&self, start_date: DateTime<Utc>) -> InterestPeriod {
-        InterestPeriod::new(*self, start_date)
-    }
-
-    fn end_date_starting_at(&self, current_date: DateTime<Utc>) -> DateTime<Utc> {
-        match self {
-            InterestInterval::EndOfMonth => {
-                let current_year = current_date.year();
-                let current_month = current_date.month();
-
-                let (year, month) = if current_month == 12 {
-                    (current_year + 1, 1)
-                } else {
-                    (current_year, current_month + 1)
-                };
-
-                Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0)
-                    .single()
-                    .expect("should return a valid date time")
-                    - chrono::Duration::seconds(1)
-            }
-            InterestInterval::EndOfDay => Utc
-                .with_ymd_and_hms(
-                    current_date.year(),
-                    current_date.month(),
-                    current_date.day(),
-                    23,
-                    59,
-                    59,
-                )
-                .single()
-                .expect("should return a valid date time"),
-        }
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct PrincipalInstallment {
+    pub due_date: DateTime<Utc>,
+    pub overdue_date: Option<DateTime<Utc>>,
+    pub liquidation_date: Option<DateTime<Utc>>,
+    pub amount: UsdCents,
 }
 
-#[derive(Builder, Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Builder, Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[builder(build_fn(validate = "Self::validate", error = "TermsError"))]
 pub struct TermValues {
@@ -250,6 +302,24 @@ pub struct TermValues {
     pub margin_call_cvl: CVLPct,
     #[builder(setter(into))]
     pub initial_cvl: CVLPct,
+    #[builder(default)]
+    #[serde(default)]
+    pub payment_allocation_strategy: PaymentAllocationStrategy,
+    #[builder(default)]
+    #[serde(default)]
+    pub principal_repayment: PrincipalRepayment,
+    #[builder(default, setter(into))]
+    #[serde(default)]
+    pub principal_repayment_interval: InterestInterval,
+    #[builder(default, setter(into))]
+    #[serde(default)]
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
+    #[builder(default, setter(into))]
+    #[serde(default)]
+    pub penalty_rate: Option<AnnualRatePct>,
+    #[builder(default)]
+    #[serde(default)]
+    pub collateral_haircuts: CollateralHaircuts,
 }
 
 impl TermValues {
@@ -274,10 +344,124 @@ impl TermValues {
         total >= self.margin_call_cvl
     }
 
+    pub fn is_collateral_release_allowed(
+        &self,
+        balance: CreditFacilityBalanceSummary,
+        release: Satoshis,
+        price: PriceOfOneBTC,
+        release_cvl: Option<CVLPct>,
+    ) -> bool {
+        if release > balance.collateral() {
+            return false;
+        }
+        let cvl = balance
+            .with_collateral(balance.collateral() - release)
+            .current_cvl(price);
+        cvl >= release_cvl.unwrap_or(self.initial_cvl)
+    }
+
     pub fn builder() -> TermValuesBuilder {
         TermValuesBuilder::default()
     }
 
+    pub fn prepayment_fee(&self, prepaid_principal: UsdCents) -> UsdCents {
+        self.prepayment_fee_rate
+            .map(|rate| rate.apply(prepaid_principal))
+            .unwrap_or(UsdCents::ZERO)
+    }
+
+    pub fn daily_penalty(&self, overdue_outstanding: UsdCents) -> UsdCents {
+        self.penalty_rate
+            .map(|rate| rate.interest_for_time_period(overdue_outstanding, 1))
+            .unwrap_or(UsdCents::ZERO)
+    }
+
+    pub fn principal_installments(
+        &self,
+        principal: UsdCents,
+        start_date: DateTime<Utc>,
+        maturity_date: DateTime<Utc>,
+    ) -> Vec<PrincipalInstallment> {
+        let mut due_dates = vec![];
+        if self.principal_repayment != PrincipalRepayment::AtMaturity {
+            let mut next_period = self
+                .principal_repayment_interval
+                .period_from(start_date)
+                .truncate(maturity_date);
+            while let Some(period) = next_period {
+                due_dates.push(period.end);
+                next_period = period.next().truncate(maturity_date);
+            }
+        }
+        if due_dates.is_empty() {
+            due_dates.push(maturity_date);
+        }
+
+        let amounts = match self.principal_repayment {
+            PrincipalRepayment::Annuity => {
+                self.annuity_principal_amounts(principal, due_dates.len())
+            }
+            _ => Self::equal_principal_amounts(principal, due_dates.len()),
+        };
+
+        due_dates
+            .into_iter()
+            .zip(amounts)
+            .map(|(due_date, amount)| PrincipalInstallment {
+                due_date,
+                overdue_date: self
+                    .obligation_overdue_duration_from_due
+                    .map(|d| d.end_date(due_date)),
+                liquidation_date: self
+                    .obligation_liquidation_duration_from_due
+                    .map(|d| d.end_date(due_date)),
+                amount,
+            })
+            .collect()
+    }
+
+    fn equal_principal_amounts(principal: UsdCents, n_installments: usize) -> Vec<UsdCents> {
+        let n = n_installments as u64;
+        let base = principal.into_inner() / n;
+        let remainder = principal.into_inner() % n;
+
+        let mut amounts = vec![UsdCents::from(base); n_installments];
+        amounts[n_installments - 1] += UsdCents::from(remainder);
+        amounts
+    }
+
+    fn annuity_principal_amounts(
+        &self,
+        principal: UsdCents,
+        n_installments: usize,
+    ) -> Vec<UsdCents> {
+        let rate = self
+            .annual_rate
+            .periodic_rate(self.principal_repayment_interval.periods_per_year());
+        if rate.is_zero() {
+            return Self::equal_principal_amounts(principal, n_installments);
+        }
+
+        let growth = (0..n_installments).fold(Decimal::ONE, |acc, _| acc * (Decimal::ONE + rate));
+        let balance = Decimal::from(principal.into_inner());
+        let payment = balance * rate * growth / (growth - Decimal::ONE);
+
+        let mut remaining = principal.into_inner();
+        let mut amounts = Vec::with_capacity(n_installments);
+        for _ in 1..n_installments {
+            let interest = Decimal::from(remaining) * rate;
+            let amount = (payment - interest)
+                .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+                .to_u64()
+                .unwrap_or(0)
+                .min(remaining);
+            remaining -= amount;
+            amounts.push(UsdCents::from(amount));
+        }
+        amounts.push(UsdCents::from(remaining));
+        amounts
+    }
+
     pub fn required_collateral(
         &self,
         desired_principal: UsdCents,
@@ -394,6 +578,12 @@ impl TermValuesBuilder {
             ));
         }
 
+        if let Some(haircuts) = &self.collateral_haircuts
+            && !haircuts.is_valid()
+        {
+            return Err(TermsError::InvalidCollateralHaircut);
+        }
+
         Ok(())
     }
 }
@@ -495,46 +685,6 @@ mod test {
         assert_eq!(required_collateral, sats);
     }
 
-    #[test]
-    fn days() {
-        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        assert_eq!(
-            InterestInterval::EndOfMonth.period_from(start_date).days(),
-            29
-        );
-
-        let start_date = "2024-12-01T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        assert_eq!(
-            InterestInterval::EndOfMonth.period_from(start_date).days(),
-            31
-        );
-    }
-
-    #[test]
-    fn truncate() {
-        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        let period = InterestInterval::EndOfMonth.period_from(start_date);
-
-        let latest_before_start_date = "2024-12-02T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        assert_eq!(period.truncate(latest_before_start_date), None);
-
-        let latest_after_start_date_before_end_date =
-            "2024-12-20T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        assert!(
-            period
-                .truncate(latest_after_start_date_before_end_date)
-                .unwrap()
-                .end
-                < period.end
-        );
-
-        let latest_after_end_date = "2025-01-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        assert_eq!(
-            period.truncate(latest_after_end_date).unwrap().end,
-            period.end
-        );
-    }
-
     #[test]
     fn interest_calculation() {
         let terms = terms();
@@ -558,74 +708,91 @@ mod test {
     }
 
     #[test]
-    fn next_period() {
-        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
-
-        let expected_next_start_date = "2024-12-04T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        let day_period = InterestInterval::EndOfDay.period_from(start_date);
-        assert_eq!(day_period.next().start, expected_next_start_date);
-
-        let expected_next_start_date = "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        let month_period = InterestInterval::EndOfMonth.period_from(start_date);
-        assert_eq!(month_period.next().start, expected_next_start_date);
+    fn can_apply_one_time_fee() {
+        let fee = OneTimeFeeRatePct(dec!(5)).apply(UsdCents::from(1000));
+        assert_eq!(fee, UsdCents::from(50));
     }
 
     #[test]
-    fn end_date_starting_at_month_interval() {
-        let expected_end_date = "2024-12-31T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
-
-        let start_of_month = "2024-12-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        assert_eq!(
-            InterestInterval::EndOfMonth.end_date_starting_at(start_of_month),
-            expected_end_date
-        );
-
-        let middle_of_month = "2024-12-15T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        assert_eq!(
-            InterestInterval::EndOfMonth.end_date_starting_at(middle_of_month),
-            expected_end_date
-        );
+    fn one_time_fee_rounds_up() {
+        let fee = OneTimeFeeRatePct(dec!(5.01)).apply(UsdCents::from(1000));
+        assert_eq!(fee, UsdCents::from(51));
+    }
 
-        let end_of_month = "2024-12-31T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
-        assert_eq!(
-            InterestInterval::EndOfMonth.end_date_starting_at(end_of_month),
-            expected_end_date
-        );
+    fn amortizing_terms(principal_repayment: PrincipalRepayment) -> TermValues {
+        TermValues {
+            principal_repayment,
+            ..terms()
+        }
     }
 
     #[test]
-    fn end_date_starting_at_day_interval() {
-        let expected_end_date = "2024-12-03T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
+    fn at_maturity_principal_is_single_installment() {
+        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let maturity_date = FacilityDuration::Months(3).maturity_date(start_date);
+        let installments =
+            terms().principal_installments(UsdCents::from(100_000), start_date, maturity_date);
 
-        let start_of_day = "2024-12-03T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        assert_eq!(
-            InterestInterval::EndOfDay.end_date_starting_at(start_of_day),
-            expected_end_date
-        );
+        assert_eq!(installments.len(), 1);
+        assert_eq!(installments[0].due_date, maturity_date);
+        assert_eq!(installments[0].amount, UsdCents::from(100_000));
+    }
 
-        let middle_of_day = "2024-12-03T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
-        assert_eq!(
-            InterestInterval::EndOfDay.end_date_starting_at(middle_of_day),
-            expected_end_date
-        );
+    #[test]
+    fn equal_principal_installments() {
+        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let maturity_date = "2025-02-28T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
+        let installments = amortizing_terms(PrincipalRepayment::EqualPrincipal)
+            .principal_installments(UsdCents::from(100_000), start_date, maturity_date);
 
-        let end_of_day = "2024-12-03T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
         assert_eq!(
-            InterestInterval::EndOfDay.end_date_starting_at(end_of_day),
-            expected_end_date
+            installments.iter().map(|i| i.amount).collect::<Vec<_>>(),
+            vec![
+                UsdCents::from(33_333),
+                UsdCents::from(33_333),
+                UsdCents::from(33_334)
+            ]
         );
+        assert_eq!(installments[2].due_date, maturity_date);
     }
 
     #[test]
-    fn can_apply_one_time_fee() {
-        let fee = OneTimeFeeRatePct(dec!(5)).apply(UsdCents::from(1000));
-        assert_eq!(fee, UsdCents::from(50));
+    fn principal_installments_follow_repayment_interval() {
+        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let maturity_date = "2025-02-28T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
+        let terms = TermValues {
+            accrual_cycle_interval: InterestInterval::EndOfDay,
+            ..amortizing_terms(PrincipalRepayment::EqualPrincipal)
+        };
+        let installments =
+            terms.principal_installments(UsdCents::from(100_000), start_date, maturity_date);
+
+        assert_eq!(installments.len(), 3);
+        assert_eq!(installments[2].due_date, maturity_date);
     }
 
     #[test]
-    fn one_time_fee_rounds_up() {
-        let fee = OneTimeFeeRatePct(dec!(5.01)).apply(UsdCents::from(1000));
-        assert_eq!(fee, UsdCents::from(51));
+    fn annuity_principal_installments() {
+        let start_date = "2024-12-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let maturity_date = "2025-11-30T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
+        let principal = UsdCents::from(1_200_000);
+        let installments = amortizing_terms(PrincipalRepayment::Annuity).principal_installments(
+            principal,
+            start_date,
+            maturity_date,
+        );
+
+        assert_eq!(installments.len(), 12);
+        assert_eq!(installments[0].amount, UsdCents::from(94_619));
+        assert!(
+            installments
+                .windows(2)
+                .all(|pair| pair[0].amount < pair[1].amount)
+        );
+        let total = installments
+            .iter()
+            .fold(UsdCents::ZERO, |acc, i| acc + i.amount);
+        assert_eq!(total, principal);
     }
 
     fn default_terms() -> TermValues {
@@ -844,6 +1011,10 @@ mod test {
             due_interest_outstanding: UsdCents::ZERO,
             overdue_interest_outstanding: UsdCents::ZERO,
             interest_defaulted: UsdCents::ZERO,
+            penalty_posted: UsdCents::ZERO,
+            penalty_outstanding: UsdCents::ZERO,
+            collateral_haircut: HaircutPct::ZERO,
+            other_collateral_value: UsdCents::ZERO,
         }
     }
 
@@ -864,6 +1035,17 @@ mod test {
         assert!(terms.is_activation_allowed(balance, price));
     }
 
+    #[test]
+    fn daily_penalty() {
+        let mut terms = default_terms();
+        let overdue = UsdCents::try_from_usd(dec!(1_000)).unwrap();
+        assert_eq!(terms.daily_penalty(overdue), UsdCents::ZERO);
+
+        terms.penalty_rate = Some(AnnualRatePct(dec!(36.5)));
+        assert_eq!(terms.daily_penalty(overdue), UsdCents::from(100));
+        assert_eq!(terms.daily_penalty(UsdCents::ZERO), UsdCents::ZERO);
+    }
+
     #[test]
     fn check_disbursal_allowed() {
         let terms = default_terms();
@@ -878,4 +1060,72 @@ mod test {
         let amount = UsdCents::try_from_usd(dec!(80_000)).unwrap();
         assert!(terms.is_disbursal_allowed(balance, amount, price));
     }
+
+    #[test]
+    fn check_disbursal_allowed_with_haircut_collateral() {
+        let terms = default_terms();
+        let price = PriceOfOneBTC::new(UsdCents::try_from_usd(dec!(100_000)).unwrap());
+        let principal = UsdCents::try_from_usd(dec!(100_000)).unwrap();
+        let mut balance = default_balances(principal);
+        balance.collateral = Satoshis::try_from_btc(dec!(1)).unwrap();
+
+        let amount = UsdCents::try_from_usd(dec!(64_000)).unwrap();
+        let haircut_only = balance.with_collateral_valuation(HaircutPct::new(20), UsdCents::ZERO);
+        assert!(terms.is_disbursal_allowed(haircut_only, amount, price));
+        assert!(!terms.is_disbursal_allowed(haircut_only, amount + UsdCents::ONE, price));
+
+        let amount = UsdCents::try_from_usd(dec!(80_000)).unwrap();
+        let with_other = balance.with_collateral_valuation(
+            HaircutPct::new(20),
+            UsdCents::try_from_usd(dec!(20_000)).unwrap(),
+        );
+        assert!(terms.is_disbursal_allowed(with_other, amount, price));
+    }
+
+    #[test]
+    fn haircut_must_be_below_full_value() {
+        let result = TermValues::builder()
+            .annual_rate(AnnualRatePct(dec!(12)))
+            .duration(FacilityDuration::Months(3))
+            .interest_due_duration_from_accrual(ObligationDuration::Days(0))
+            .obligation_overdue_duration_from_due(None)
+            .obligation_liquidation_duration_from_due(None)
+            .accrual_cycle_interval(InterestInterval::EndOfMonth)
+            .accrual_interval(InterestInterval::EndOfDay)
+            .one_time_fee_rate(OneTimeFeeRatePct(dec!(1)))
+            .liquidation_cvl(dec!(105))
+            .margin_call_cvl(dec!(125))
+            .initial_cvl(dec!(140))
+            .collateral_haircuts(CollateralHaircuts::from_iter([(
+                CollateralAsset::Eth,
+                HaircutPct::new(100),
+            )]))
+            .build();
+
+        assert!(matches!(result, Err(TermsError::InvalidCollateralHaircut)));
+    }
+
+    #[test]
+    fn check_collateral_release_allowed() {
+        let terms = default_terms();
+        let price = PriceOfOneBTC::new(UsdCents::try_from_usd(dec!(100_000)).unwrap());
+        let principal = UsdCents::try_from_usd(dec!(100_000)).unwrap();
+        let mut balance = default_balances(principal);
+        balance.collateral = Satoshis::try_from_btc(dec!(2)).unwrap();
+
+        let release = Satoshis::try_from_btc(dec!(0.6)).unwrap();
+        assert!(terms.is_collateral_release_allowed(balance, release, price, None));
+
+        let release = Satoshis::try_from_btc(dec!(0.61)).unwrap();
+        assert!(!terms.is_collateral_release_allowed(balance, release, price, None));
+        assert!(terms.is_collateral_release_allowed(
+            balance,
+            release,
+            price,
+            Some(CVLPct::new(125))
+        ));
+
+        let release = Satoshis::try_from_btc(dec!(3)).unwrap();
+        assert!(!terms.is_collateral_release_allowed(balance, release, price, None));
+    }
 }
@@ -43,7 +43,7 @@ impl TermsTemplate {
 
     pub fn update_values(&mut self, new_values: TermValues, audit_info: AuditInfo) {
         self.events.push(TermsTemplateEvent::TermValuesUpdated {
-            values: new_values,
+            values: new_values.clone(),
             audit_info,
         });
         self.values = new_values;
@@ -59,10 +59,10 @@ impl TryFromEvents<TermsTemplateEvent> for TermsTemplate {
                 TermsTemplateEvent::Initialized {
                     id, name, values, ..
                 } => {
-                    builder = builder.id(*id).name(name.clone()).values(*values);
+                    builder = builder.id(*id).name(name.clone()).values(values.clone());
                 }
                 TermsTemplateEvent::TermValuesUpdated { values, .. } => {
-                    builder = builder.values(*values);
+                    builder = builder.values(values.clone());
                 }
             }
         }
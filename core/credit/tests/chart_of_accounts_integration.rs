@@ -56,14 +56,17 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
     .await?;
 
     let accounting_document_storage = DocumentStorage::new(&pool, &storage);
-    let accounting = CoreAccounting::new(
+    let accounting = CoreAccounting::init(
         &pool,
         &authz,
+        &outbox,
+        &governance,
         &cala,
         journal_id,
         accounting_document_storage,
         &jobs,
-    );
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
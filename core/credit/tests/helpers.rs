@@ -149,6 +149,7 @@ pub mod event {
     use core_customer::CoreCustomerEvent;
     use governance::GovernanceEvent;
 
+    #[allow(clippy::large_enum_variant)]
     #[derive(Debug, Serialize, Deserialize)]
     #[serde(tag = "module")]
     pub enum DummyEvent {
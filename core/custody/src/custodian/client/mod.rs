@@ -33,6 +33,17 @@ pub trait CustodianClient: Send {
         headers: &http::HeaderMap,
         payload: Bytes,
     ) -> Result<Option<CustodianNotification>, CustodianClientError>;
+
+    /// Requests a transfer of funds from a wallet to an external address.
+    /// Repeated calls with the same `idempotency_key` must not send twice.
+    /// Returns the custodian's reference of the transfer.
+    async fn send_funds(
+        &self,
+        external_wallet_id: &str,
+        address: &str,
+        amount: Satoshis,
+        idempotency_key: &str,
+    ) -> Result<String, CustodianClientError>;
 }
 
 #[async_trait]
@@ -104,6 +115,26 @@ impl CustodianClient for bitgo::BitgoClient {
 
         Ok(custodian_notification)
     }
+
+    async fn send_funds(
+        &self,
+        external_wallet_id: &str,
+        address: &str,
+        amount: Satoshis,
+        idempotency_key: &str,
+    ) -> Result<String, CustodianClientError> {
+        let transfer = self
+            .send_coins(
+                external_wallet_id,
+                address,
+                amount.into_inner(),
+                idempotency_key,
+            )
+            .await
+            .map_err(CustodianClientError::client)?;
+
+        Ok(transfer.id)
+    }
 }
 
 #[async_trait]
@@ -164,6 +195,23 @@ impl CustodianClient for komainu::KomainuClient {
 
         Ok(custodian_notification)
     }
+
+    async fn send_funds(
+        &self,
+        external_wallet_id: &str,
+        address: &str,
+        amount: Satoshis,
+        idempotency_key: &str,
+    ) -> Result<String, CustodianClientError> {
+        self.create_transaction(
+            external_wallet_id,
+            address,
+            amount.to_btc(),
+            idempotency_key,
+        )
+        .await
+        .map_err(CustodianClientError::client)
+    }
 }
 
 #[cfg(feature = "mock-custodian")]
@@ -212,6 +260,16 @@ pub mod mock {
                 Ok(None)
             }
         }
+
+        async fn send_funds(
+            &self,
+            _external_wallet_id: &str,
+            _address: &str,
+            _amount: Satoshis,
+            idempotency_key: &str,
+        ) -> Result<String, CustodianClientError> {
+            Ok(format!("mock-transfer-{idempotency_key}"))
+        }
     }
 }
 
@@ -5,6 +5,7 @@ pub mod error;
 mod notification;
 mod repo;
 
+pub use client::error::CustodianClientError;
 pub use config::{
     BitgoConfig, CustodianConfig, CustodianConfigDiscriminants, CustodyProviderConfig,
     DeprecatedEncryptionKey, EncryptionConfig, KomainuConfig,
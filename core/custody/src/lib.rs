@@ -361,6 +361,65 @@ where
         Ok(())
     }
 
+    #[instrument(name = "custody.can_send_funds", skip(self), err)]
+    pub async fn can_send_funds(&self, wallet_id: WalletId) -> Result<bool, CoreCustodyError> {
+        let wallet = self.wallets.find_by_id(wallet_id).await?;
+        Ok(wallet.external_wallet_id.is_some())
+    }
+
+    /// Sends funds out of `wallet_id`. The `idempotency_key` is forwarded to the
+    /// custodian so that retries of the same request never send twice.
+    /// No transaction is held open while waiting on the custodian.
+    #[instrument(name = "custody.send_funds", skip(self), err)]
+    pub async fn send_funds(
+        &self,
+        wallet_id: WalletId,
+        address: &str,
+        amount: Satoshis,
+        idempotency_key: &str,
+    ) -> Result<String, CoreCustodyError> {
+        let wallet = self.wallets.find_by_id(wallet_id).await?;
+        let external_wallet_id = wallet
+            .external_wallet_id
+            .clone()
+            .ok_or(crate::wallet::error::WalletError::ExternalWalletNotAttached)?;
+
+        let custodian = self.custodians.find_by_id(wallet.custodian_id).await?;
+
+        let external_transfer_id = custodian
+            .custodian_client(self.config.encryption.key, &self.config.custody_providers)?
+            .send_funds(&external_wallet_id, address, amount, idempotency_key)
+            .await?;
+
+        let mut db = self.wallets.begin_op().await?;
+        let mut wallet = self.wallets.find_by_id_in_op(&mut db, wallet_id).await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                &mut db,
+                CoreCustodyObject::wallet(wallet.id),
+                CoreCustodyAction::WALLET_SEND_FUNDS,
+            )
+            .await?;
+
+        if wallet
+            .record_funds_sent(
+                external_transfer_id.clone(),
+                address.to_owned(),
+                amount,
+                &audit_info,
+            )
+            .did_execute()
+        {
+            self.wallets.update_in_op(&mut db, &mut wallet).await?;
+        }
+
+        db.commit().await?;
+
+        Ok(external_transfer_id)
+    }
+
     #[instrument(name = "custody.update_wallet_balance", skip(self), err)]
     async fn update_wallet_balance(
         &self,
@@ -35,6 +35,7 @@ impl CoreCustodyAction {
         CoreCustodyAction::Custodian(CustodianAction::CreateWallet);
 
     pub const WALLET_UPDATE: Self = CoreCustodyAction::Wallet(WalletAction::Update);
+    pub const WALLET_SEND_FUNDS: Self = CoreCustodyAction::Wallet(WalletAction::SendFunds);
 
     pub fn actions() -> Vec<ActionMapping> {
         use CoreCustodyActionDiscriminants::*;
@@ -105,12 +106,13 @@ impl From<CustodianAction> for CoreCustodyAction {
 #[strum(serialize_all = "kebab-case")]
 pub enum WalletAction {
     Update,
+    SendFunds,
 }
 
 impl ActionPermission for WalletAction {
     fn permission_set(&self) -> &'static str {
         match self {
-            Self::Update => PERMISSION_SET_CUSTODY_WRITER,
+            Self::Update | Self::SendFunds => PERMISSION_SET_CUSTODY_WRITER,
         }
     }
 }
@@ -45,6 +45,7 @@ where
                     new_balance: *new_balance,
                     changed_at: *changed_at,
                 }),
+                FundsSent { .. } => None,
             })
             .collect::<Vec<_>>();
 
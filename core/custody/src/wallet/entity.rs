@@ -29,6 +29,12 @@ pub enum WalletEvent {
         changed_at: DateTime<Utc>,
         audit_info: AuditInfo,
     },
+    FundsSent {
+        external_transfer_id: String,
+        address: String,
+        amount: Satoshis,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -88,6 +94,28 @@ impl Wallet {
         Idempotent::Executed(())
     }
 
+    pub fn record_funds_sent(
+        &mut self,
+        external_transfer_id: String,
+        address: String,
+        amount: Satoshis,
+        audit_info: &AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            WalletEvent::FundsSent { external_transfer_id: existing, .. } if existing == &external_transfer_id
+        );
+
+        self.events.push(WalletEvent::FundsSent {
+            external_transfer_id,
+            address,
+            amount,
+            audit_info: audit_info.clone(),
+        });
+
+        Idempotent::Executed(())
+    }
+
     pub fn address(&self) -> Option<&str> {
         self.events.iter_all().find_map(|e| match e {
             WalletEvent::ExternalWalletAttached { address, .. } => Some(address.as_str()),
@@ -6,6 +6,8 @@ pub enum WalletError {
     EsEntityError(es_entity::EsEntityError),
     #[error("WalletError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("WalletError - ExternalWalletNotAttached")]
+    ExternalWalletNotAttached,
 }
 
 es_entity::from_es_entity_error!(WalletError);
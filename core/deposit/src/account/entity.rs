@@ -9,6 +9,8 @@ use audit::AuditInfo;
 
 use crate::primitives::*;
 
+use super::error::DepositAccountError;
+
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -23,12 +25,21 @@ pub enum DepositAccountEvent {
         description: String,
         status: AccountStatus,
         public_id: PublicId,
+        #[serde(default)]
+        currency: CurrencyCode,
+        #[serde(default)]
+        account_type: Option<DepositAccountType>,
         audit_info: AuditInfo,
     },
     AccountStatusUpdated {
         status: AccountStatus,
         audit_info: AuditInfo,
     },
+    DepositProductAssigned {
+        deposit_product_id: DepositProductId,
+        interest_payable_account_id: CalaAccountId,
+        audit_info: AuditInfo,
+    },
 }
 
 #[derive(EsEntity, Builder)]
@@ -41,6 +52,14 @@ pub struct DepositAccount {
     pub description: String,
     pub status: AccountStatus,
     pub public_id: PublicId,
+    pub currency: CurrencyCode,
+    /// Not recorded for accounts opened before account types were tracked.
+    #[builder(default)]
+    pub account_type: Option<DepositAccountType>,
+    #[builder(setter(strip_option), default)]
+    pub deposit_product_id: Option<DepositProductId>,
+    #[builder(setter(strip_option), default)]
+    pub interest_payable_account_id: Option<CalaAccountId>,
 
     events: EntityEvents<DepositAccountEvent>,
 }
@@ -52,6 +71,16 @@ impl DepositAccount {
             .expect("Deposit Account has never been persisted")
     }
 
+    pub fn ensure_currency(&self, amount: &Money) -> Result<(), DepositAccountError> {
+        if amount.currency() != &self.currency {
+            return Err(DepositAccountError::CurrencyMismatch {
+                expected: self.currency.clone(),
+                actual: amount.currency().clone(),
+            });
+        }
+        Ok(())
+    }
+
     pub fn update_account_status(
         &mut self,
         status: AccountStatus,
@@ -66,6 +95,32 @@ impl DepositAccount {
         self.status = status;
         Idempotent::Executed(())
     }
+
+    /// Interest accrues into a dedicated payable account per deposit account,
+    /// which is allocated on the first assignment and kept across re-assignments.
+    pub fn assign_deposit_product(
+        &mut self,
+        deposit_product_id: DepositProductId,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all().rev(),
+            DepositAccountEvent::DepositProductAssigned { deposit_product_id: existing_id, .. } if existing_id == &deposit_product_id,
+            => DepositAccountEvent::DepositProductAssigned { .. }
+        );
+        let interest_payable_account_id = self
+            .interest_payable_account_id
+            .unwrap_or_else(CalaAccountId::new);
+        self.events
+            .push(DepositAccountEvent::DepositProductAssigned {
+                deposit_product_id,
+                interest_payable_account_id,
+                audit_info,
+            });
+        self.deposit_product_id = Some(deposit_product_id);
+        self.interest_payable_account_id = Some(interest_payable_account_id);
+        Idempotent::Executed(())
+    }
 }
 
 impl TryFromEvents<DepositAccountEvent> for DepositAccount {
@@ -81,6 +136,8 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                     description,
                     status,
                     public_id,
+                    currency,
+                    account_type,
                     ..
                 } => {
                     builder = builder
@@ -91,10 +148,21 @@ impl TryFromEvents<DepositAccountEvent> for DepositAccount {
                         .description(description.to_string())
                         .status(*status)
                         .public_id(public_id.clone())
+                        .currency(currency.clone())
+                        .account_type(*account_type)
                 }
                 DepositAccountEvent::AccountStatusUpdated { status, .. } => {
                     builder = builder.status(*status);
                 }
+                DepositAccountEvent::DepositProductAssigned {
+                    deposit_product_id,
+                    interest_payable_account_id,
+                    ..
+                } => {
+                    builder = builder
+                        .deposit_product_id(*deposit_product_id)
+                        .interest_payable_account_id(*interest_payable_account_id);
+                }
             }
         }
         builder.events(events).build()
@@ -113,6 +181,10 @@ pub struct NewDepositAccount {
     pub(super) active: bool,
     #[builder(setter(into))]
     pub(super) public_id: PublicId,
+    #[builder(default)]
+    pub(super) currency: CurrencyCode,
+    #[builder(setter(into))]
+    pub(super) account_type: DepositAccountType,
     pub audit_info: AuditInfo,
 }
 
@@ -139,6 +211,8 @@ impl IntoEvents<DepositAccountEvent> for NewDepositAccount {
                     AccountStatus::Inactive
                 },
                 public_id: self.public_id,
+                currency: self.currency,
+                account_type: Some(self.account_type),
                 audit_info: self.audit_info,
             }],
         )
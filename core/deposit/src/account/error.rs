@@ -8,6 +8,11 @@ pub enum DepositAccountError {
     EsEntityError(es_entity::EsEntityError),
     #[error("CommitteeError - CursorDestructureError: {0}")]
     CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("DepositAccountError - CurrencyMismatch: account is {expected}, amount is {actual}")]
+    CurrencyMismatch {
+        expected: crate::primitives::CurrencyCode,
+        actual: crate::primitives::CurrencyCode,
+    },
 }
 
 es_entity::from_es_entity_error!(DepositAccountError);
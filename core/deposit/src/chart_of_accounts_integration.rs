@@ -14,6 +14,12 @@ pub struct ChartOfAccountsIntegrationConfig {
     pub chart_of_account_bank_deposit_accounts_parent_code: AccountCode,
     pub chart_of_account_financial_institution_deposit_accounts_parent_code: AccountCode,
     pub chart_of_account_non_domiciled_individual_deposit_accounts_parent_code: AccountCode,
+    #[builder(default)]
+    #[serde(default)]
+    pub chart_of_account_interest_expense_parent_code: Option<AccountCode>,
+    #[builder(default)]
+    #[serde(default)]
+    pub chart_of_account_interest_payable_parent_code: Option<AccountCode>,
 }
 
 impl ChartOfAccountsIntegrationConfig {
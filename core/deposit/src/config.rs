@@ -0,0 +1,25 @@
+use rust_decimal::Decimal;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+
+use crate::primitives::{CurrencyCode, Money};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct DepositConfig {
+    /// Transfers above the threshold for their currency wait for the transfer
+    /// approval process. Currencies without a threshold never need approval.
+    #[serde(default)]
+    pub transfer_approval_thresholds: HashMap<CurrencyCode, Decimal>,
+}
+
+impl DepositConfig {
+    pub(crate) fn transfer_requires_approval(&self, amount: &Money) -> bool {
+        self.transfer_approval_thresholds
+            .get(amount.currency())
+            .is_some_and(|threshold| amount.amount() > *threshold)
+    }
+}
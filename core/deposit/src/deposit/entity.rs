@@ -4,10 +4,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use audit::AuditInfo;
-use core_money::UsdCents;
 use es_entity::*;
 
-use crate::primitives::{CalaTransactionId, DepositAccountId, DepositId, DepositStatus};
+use crate::primitives::{CalaTransactionId, DepositAccountId, DepositId, DepositStatus, Money};
 
 #[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
@@ -18,7 +17,7 @@ pub enum DepositEvent {
         id: DepositId,
         ledger_tx_id: CalaTransactionId,
         deposit_account_id: DepositAccountId,
-        amount: UsdCents,
+        amount: Money,
         reference: String,
         status: DepositStatus,
         audit_info: AuditInfo,
@@ -33,7 +32,7 @@ pub enum DepositEvent {
 pub struct DepositReversalData {
     pub ledger_tx_id: CalaTransactionId,
     pub credit_account_id: DepositAccountId,
-    pub amount: UsdCents,
+    pub amount: Money,
     pub correlation_id: String,
     pub external_id: String,
 }
@@ -43,7 +42,7 @@ pub struct DepositReversalData {
 pub struct Deposit {
     pub id: DepositId,
     pub deposit_account_id: DepositAccountId,
-    pub amount: UsdCents,
+    pub amount: Money,
     pub reference: String,
     events: EntityEvents<DepositEvent>,
 }
@@ -83,7 +82,7 @@ impl Deposit {
         Idempotent::Executed(DepositReversalData {
             ledger_tx_id,
             credit_account_id: self.deposit_account_id,
-            amount: self.amount,
+            amount: self.amount.clone(),
             correlation_id: self.id.to_string(),
             external_id: format!("lana:deposit:{}:reverted", self.id),
         })
@@ -105,7 +104,7 @@ impl TryFromEvents<DepositEvent> for Deposit {
                     builder = builder
                         .id(*id)
                         .deposit_account_id(*deposit_account_id)
-                        .amount(*amount)
+                        .amount(amount.clone())
                         .reference(reference.clone());
                 }
                 DepositEvent::Reverted { .. } => {}
@@ -125,7 +124,7 @@ pub struct NewDeposit {
     #[builder(setter(into))]
     pub(super) deposit_account_id: DepositAccountId,
     #[builder(setter(into))]
-    pub(super) amount: UsdCents,
+    pub(super) amount: Money,
     reference: Option<String>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
@@ -147,8 +146,11 @@ impl NewDeposit {
 
 impl NewDepositBuilder {
     fn validate(&self) -> Result<(), String> {
-        match self.amount {
+        match self.amount.as_ref() {
             Some(amount) if amount.is_zero() => Err("Deposit amount cannot be zero".to_string()),
+            Some(amount) if amount.is_negative() => {
+                Err("Deposit amount cannot be negative".to_string())
+            }
             _ => Ok(()),
         }
     }
@@ -175,6 +177,8 @@ impl IntoEvents<DepositEvent> for NewDeposit {
 mod test {
     use audit::AuditEntryId;
 
+    use crate::primitives::UsdCents;
+
     use super::*;
 
     fn dummy_audit_info() -> AuditInfo {
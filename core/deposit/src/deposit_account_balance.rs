@@ -1,13 +1,24 @@
-use core_money::UsdCents;
+use core_money::{CurrencyCode, Money};
 
 pub struct DepositAccountBalance {
-    pub settled: UsdCents,
-    pub pending: UsdCents,
+    pub settled: Money,
+    pub pending: Money,
+    pub held: Money,
 }
 
 impl DepositAccountBalance {
-    pub const ZERO: Self = DepositAccountBalance {
-        settled: UsdCents::ZERO,
-        pending: UsdCents::ZERO,
-    };
+    pub fn zero(currency: CurrencyCode) -> Self {
+        DepositAccountBalance {
+            settled: Money::zero(currency.clone()),
+            pending: Money::zero(currency.clone()),
+            held: Money::zero(currency),
+        }
+    }
+
+    /// Settled funds that are not reserved by an active hold.
+    pub fn available(&self) -> Money {
+        self.settled
+            .checked_sub(&self.held)
+            .expect("balance layers share the account currency")
+    }
 }
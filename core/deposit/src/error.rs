@@ -14,6 +14,16 @@ pub enum CoreDepositError {
     DepositError(#[from] crate::deposit::error::DepositError),
     #[error("CoreDepositError - WithdrawalError: {0}")]
     WithdrawalError(#[from] crate::withdrawal::error::WithdrawalError),
+    #[error("CoreDepositError - TransferError: {0}")]
+    TransferError(#[from] crate::transfer::error::TransferError),
+    #[error("CoreDepositError - DepositProductError: {0}")]
+    DepositProductError(#[from] crate::product::error::DepositProductError),
+    #[error("CoreDepositError - HoldError: {0}")]
+    HoldError(#[from] crate::hold::error::HoldError),
+    #[error("CoreDepositError - WithdrawalLimitError: {0}")]
+    WithdrawalLimitError(#[from] crate::withdrawal_limit::error::WithdrawalLimitError),
+    #[error("CoreDepositError - StandingOrderError: {0}")]
+    StandingOrderError(#[from] crate::standing_order::error::StandingOrderError),
     #[error("CoreDepositError - DepositLedgerError: {0}")]
     DepositLedgerError(#[from] crate::ledger::error::DepositLedgerError),
     #[error("CoreDepositError - GovernanceError: {0}")]
@@ -36,8 +46,24 @@ pub enum CoreDepositError {
     DepositConfigAlreadyExists,
     #[error("CoreDepositError - DepositAccountNotActive")]
     DepositAccountNotActive,
+    #[error("CoreDepositError - InsufficientAvailableBalance")]
+    InsufficientAvailableBalance,
+    #[error("CoreDepositError - WithdrawalLimitExceeded: {0}")]
+    WithdrawalLimitExceeded(crate::withdrawal_limit::WithdrawalLimitInterval),
+    #[error("CoreDepositError - WithdrawalLimitApprovalNotConfigured")]
+    WithdrawalLimitApprovalNotConfigured,
     #[error("CoreDepositError - WithdrawalBuilderError: {0}")]
     WithdrawalBuilderError(#[from] super::NewWithdrawalBuilderError),
+    #[error("CoreDepositError - TransferBuilderError: {0}")]
+    TransferBuilderError(#[from] super::NewTransferBuilderError),
+    #[error("CoreDepositError - DepositProductBuilderError: {0}")]
+    DepositProductBuilderError(#[from] super::NewDepositProductBuilderError),
+    #[error("CoreDepositError - HoldBuilderError: {0}")]
+    HoldBuilderError(#[from] super::NewHoldBuilderError),
+    #[error("CoreDepositError - WithdrawalLimitBuilderError: {0}")]
+    WithdrawalLimitBuilderError(#[from] super::NewWithdrawalLimitBuilderError),
+    #[error("CoreDepositError - StandingOrderBuilderError: {0}")]
+    StandingOrderBuilderError(#[from] super::NewStandingOrderBuilderError),
     #[error("CoreDepositError - DepositBuilderError: {0}")]
     DepositBuilderError(#[from] super::NewDepositBuilderError),
     #[error("CoreDepositError - PublicIdError: {0}")]
@@ -53,4 +79,28 @@ impl CoreDepositError {
             ))
         )
     }
+
+    pub(crate) fn is_withdrawal_limit_requiring_approval_exceeded(&self) -> bool {
+        matches!(
+            self,
+            Self::DepositLedgerError(
+                crate::ledger::error::DepositLedgerError::WithdrawalLimitExceeded {
+                    breach_action:
+                        crate::withdrawal_limit::WithdrawalLimitBreachAction::RequireApproval,
+                    ..
+                }
+            )
+        )
+    }
+
+    pub(crate) fn with_withdrawal_limit_breach_surfaced(self) -> Self {
+        match self {
+            Self::DepositLedgerError(
+                crate::ledger::error::DepositLedgerError::WithdrawalLimitExceeded {
+                    interval, ..
+                },
+            ) => Self::WithdrawalLimitExceeded(interval),
+            e => e,
+        }
+    }
 }
@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::primitives::{
-    DepositAccountHolderId, DepositAccountId, DepositId, DepositStatus, WithdrawalId,
+    DepositAccountHolderId, DepositAccountId, DepositId, DepositStatus, TransferId, WithdrawalId,
 };
-use core_money::UsdCents;
+use core_money::Money;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -19,16 +19,22 @@ pub enum CoreDepositEvent {
     DepositInitialized {
         id: DepositId,
         deposit_account_id: DepositAccountId,
-        amount: UsdCents,
+        amount: Money,
     },
     WithdrawalConfirmed {
         id: WithdrawalId,
         deposit_account_id: DepositAccountId,
-        amount: UsdCents,
+        amount: Money,
     },
     DepositReverted {
         id: DepositId,
         deposit_account_id: DepositAccountId,
-        amount: UsdCents,
+        amount: Money,
+    },
+    TransferCompleted {
+        id: TransferId,
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: Money,
     },
 }
@@ -13,6 +13,7 @@ use crate::{
     history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry},
     ledger::*,
     primitives::*,
+    transfer::*,
     withdrawal::*,
 };
 
@@ -26,6 +27,7 @@ where
     accounts: &'a DepositAccountRepo<E>,
     deposits: &'a DepositRepo<E>,
     withdrawals: &'a WithdrawalRepo<E>,
+    transfers: &'a TransferRepo<E>,
     ledger: &'a DepositLedger,
     authz: &'a Perms,
 }
@@ -39,12 +41,14 @@ where
         From<CoreDepositObject> + From<GovernanceObject>,
     E: OutboxEventMarker<CoreDepositEvent>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
         subject: &'a <<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         account_holder_id: DepositAccountHolderId,
         accounts: &'a DepositAccountRepo<E>,
         deposits: &'a DepositRepo<E>,
         withdrawals: &'a WithdrawalRepo<E>,
+        transfers: &'a TransferRepo<E>,
         ledger: &'a DepositLedger,
         authz: &'a Perms,
     ) -> Self {
@@ -54,6 +58,7 @@ where
             accounts,
             deposits,
             withdrawals,
+            transfers,
             ledger,
             authz,
         }
@@ -94,14 +99,15 @@ where
     ) -> Result<DepositAccountBalance, CoreDepositError> {
         let account_id = account_id.into();
 
-        self.ensure_account_access(
-            account_id,
-            CoreDepositObject::deposit_account(account_id),
-            CoreDepositAction::DEPOSIT_ACCOUNT_READ_BALANCE,
-        )
-        .await?;
+        let account = self
+            .ensure_account_access(
+                account_id,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_READ_BALANCE,
+            )
+            .await?;
 
-        let balance = self.ledger.balance(account_id).await?;
+        let balance = self.ledger.balance(account_id, &account.currency).await?;
         Ok(balance)
     }
 
@@ -234,12 +240,36 @@ where
         Ok(withdrawal)
     }
 
+    pub async fn find_transfer_by_id(
+        &self,
+        transfer_id: impl Into<TransferId> + std::fmt::Debug,
+    ) -> Result<Transfer, CoreDepositError> {
+        let transfer_id = transfer_id.into();
+        let transfer = self.transfers.find_by_id(transfer_id).await?;
+
+        // The holder may be on either side of the transfer
+        let from_account = self.accounts.find_by_id(transfer.from_account_id).await?;
+        let account_id = if from_account.account_holder_id == self.account_holder_id {
+            transfer.from_account_id
+        } else {
+            transfer.to_account_id
+        };
+        self.ensure_account_access(
+            account_id,
+            CoreDepositObject::transfer(transfer_id),
+            CoreDepositAction::TRANSFER_READ,
+        )
+        .await?;
+
+        Ok(transfer)
+    }
+
     async fn ensure_account_access(
         &self,
         account_id: DepositAccountId,
         object: CoreDepositObject,
         action: CoreDepositAction,
-    ) -> Result<(), CoreDepositError> {
+    ) -> Result<DepositAccount, CoreDepositError> {
         let account = self.accounts.find_by_id(account_id).await?;
 
         if account.account_holder_id != self.account_holder_id {
@@ -254,6 +284,6 @@ where
             .record_entry(self.sub, object, action, true)
             .await?;
 
-        Ok(())
+        Ok(account)
     }
 }
@@ -7,8 +7,11 @@ pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(WithdrawalEntry),
+    TransferIn(TransferEntry),
+    TransferOut(TransferEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    Interest(InterestEntry),
     Unknown(UnknownEntry),
     Ignored,
 }
@@ -24,6 +27,12 @@ pub struct WithdrawalEntry {
     pub recorded_at: DateTime<Utc>,
 }
 
+pub struct TransferEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+}
+
 pub struct DisbursalEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -36,6 +45,12 @@ pub struct PaymentEntry {
     pub recorded_at: DateTime<Utc>,
 }
 
+pub struct InterestEntry {
+    pub tx_id: CalaTxId,
+    pub entry_id: CalaEntryId,
+    pub recorded_at: DateTime<Utc>,
+}
+
 pub struct UnknownEntry {
     pub tx_id: CalaTxId,
     pub entry_id: CalaEntryId,
@@ -45,12 +60,17 @@ pub struct UnknownEntry {
 const RECORD_DEPOSIT: &str = "RECORD_DEPOSIT_CR";
 const INITIATE_WITHDRAW: &str = "INITIATE_WITHDRAW_SETTLED_DR";
 const CANCEL_WITHDRAW: &str = "CANCEL_WITHDRAW_SETTLED_CR";
+const TRANSFER_IN: &str = "TRANSFER_SETTLED_CR";
+const TRANSFER_OUT: &str = "TRANSFER_SETTLED_DR";
 const CONFIRM_DISBURSAL: &str = "CONFIRM_DISBURSAL_SETTLED_CR";
 const RECORD_OBLIGATION_INSTALLMENT: &str = "RECORD_OBLIGATION_INSTALLMENT_DR";
+const POST_DEPOSIT_INTEREST: &str = "POST_DEPOSIT_INTEREST_CR";
 
 const IGNORE_INITIATE_WITHDRAW_PENDING: &str = "INITIATE_WITHDRAW_PENDING_CR";
 const IGNORE_CONFIRM_WITHDRAWAL_PENDING: &str = "CONFIRM_WITHDRAW_PENDING_DR";
 const IGNORE_CANCEL_WITHDRAW_PENDING: &str = "CANCEL_WITHDRAW_PENDING_DR";
+const IGNORE_PLACE_HOLD_ENCUMBRANCE: &str = "PLACE_HOLD_ENCUMBRANCE_DR";
+const IGNORE_RELEASE_HOLD_ENCUMBRANCE: &str = "RELEASE_HOLD_ENCUMBRANCE_CR";
 
 impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
     fn from(entry: cala_ledger::entry::Entry) -> Self {
@@ -70,6 +90,16 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
             }),
+            TRANSFER_IN => DepositAccountHistoryEntry::TransferIn(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
+            TRANSFER_OUT => DepositAccountHistoryEntry::TransferOut(TransferEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
             CONFIRM_DISBURSAL => DepositAccountHistoryEntry::Disbursal(DisbursalEntry {
                 tx_id: entry.values().transaction_id,
                 entry_id: entry.id,
@@ -80,10 +110,17 @@ impl From<cala_ledger::entry::Entry> for DepositAccountHistoryEntry {
                 entry_id: entry.id,
                 recorded_at: entry.created_at(),
             }),
+            POST_DEPOSIT_INTEREST => DepositAccountHistoryEntry::Interest(InterestEntry {
+                tx_id: entry.values().transaction_id,
+                entry_id: entry.id,
+                recorded_at: entry.created_at(),
+            }),
 
             IGNORE_CONFIRM_WITHDRAWAL_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_INITIATE_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
             IGNORE_CANCEL_WITHDRAW_PENDING => DepositAccountHistoryEntry::Ignored,
+            IGNORE_PLACE_HOLD_ENCUMBRANCE => DepositAccountHistoryEntry::Ignored,
+            IGNORE_RELEASE_HOLD_ENCUMBRANCE => DepositAccountHistoryEntry::Ignored,
 
             _ => DepositAccountHistoryEntry::Unknown(UnknownEntry {
                 tx_id: entry.values().transaction_id,
@@ -115,6 +152,14 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::TransferIn(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
+            DepositAccountHistoryEntry::TransferOut(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Disbursal(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
@@ -123,6 +168,10 @@ impl From<&DepositAccountHistoryEntry> for DepositAccountHistoryCursor {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
             },
+            DepositAccountHistoryEntry::Interest(entry) => Self {
+                entry_id: entry.entry_id,
+                created_at: entry.recorded_at,
+            },
             DepositAccountHistoryEntry::Unknown(entry) => Self {
                 entry_id: entry.entry_id,
                 created_at: entry.recorded_at,
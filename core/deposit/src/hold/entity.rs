@@ -0,0 +1,269 @@
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{CalaTransactionId, DepositAccountId, HoldId, Money};
+use audit::AuditInfo;
+
+use super::error::HoldError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum HoldStatus {
+    Active,
+    Released,
+    Expired,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "HoldId")]
+pub enum HoldEvent {
+    Initialized {
+        id: HoldId,
+        deposit_account_id: DepositAccountId,
+        ledger_tx_id: CalaTransactionId,
+        amount: Money,
+        reason: String,
+        expires_at: Option<DateTime<Utc>>,
+        audit_info: AuditInfo,
+    },
+    Released {
+        ledger_tx_id: CalaTransactionId,
+        audit_info: AuditInfo,
+    },
+    Expired {
+        ledger_tx_id: CalaTransactionId,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Hold {
+    pub id: HoldId,
+    pub deposit_account_id: DepositAccountId,
+    pub ledger_tx_id: CalaTransactionId,
+    pub amount: Money,
+    pub reason: String,
+    pub expires_at: Option<DateTime<Utc>>,
+
+    events: EntityEvents<HoldEvent>,
+}
+
+impl Hold {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for hold")
+    }
+
+    pub fn status(&self) -> HoldStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|e| match e {
+                HoldEvent::Released { .. } => Some(HoldStatus::Released),
+                HoldEvent::Expired { .. } => Some(HoldStatus::Expired),
+                _ => None,
+            })
+            .unwrap_or(HoldStatus::Active)
+    }
+
+    /// The transaction that took the hold off the encumbrance layer, once it
+    /// has been released or has expired.
+    pub fn concluded_tx_id(&self) -> Option<CalaTransactionId> {
+        self.events.iter_all().find_map(|e| match e {
+            HoldEvent::Released { ledger_tx_id, .. } | HoldEvent::Expired { ledger_tx_id, .. } => {
+                Some(*ledger_tx_id)
+            }
+            _ => None,
+        })
+    }
+
+    pub fn release(
+        &mut self,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<CalaTransactionId>, HoldError> {
+        idempotency_guard!(self.events.iter_all(), HoldEvent::Released { .. });
+        if self.status() != HoldStatus::Active {
+            return Err(HoldError::AlreadyConcluded(self.id));
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(HoldEvent::Released {
+            ledger_tx_id,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(ledger_tx_id))
+    }
+
+    pub fn expire(
+        &mut self,
+        now: DateTime<Utc>,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<CalaTransactionId>, HoldError> {
+        idempotency_guard!(self.events.iter_all(), HoldEvent::Expired { .. });
+        if self.status() != HoldStatus::Active {
+            return Err(HoldError::AlreadyConcluded(self.id));
+        }
+        match self.expires_at {
+            Some(expires_at) if expires_at <= now => (),
+            _ => return Err(HoldError::NotYetExpired(self.id)),
+        }
+
+        let ledger_tx_id = CalaTransactionId::new();
+        self.events.push(HoldEvent::Expired {
+            ledger_tx_id,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(ledger_tx_id))
+    }
+}
+
+impl TryFromEvents<HoldEvent> for Hold {
+    fn try_from_events(events: EntityEvents<HoldEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = HoldBuilder::default();
+        for event in events.iter_all() {
+            if let HoldEvent::Initialized {
+                id,
+                deposit_account_id,
+                ledger_tx_id,
+                amount,
+                reason,
+                expires_at,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .deposit_account_id(*deposit_account_id)
+                    .ledger_tx_id(*ledger_tx_id)
+                    .amount(amount.clone())
+                    .reason(reason.clone())
+                    .expires_at(*expires_at)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewHold {
+    #[builder(setter(into))]
+    pub(super) id: HoldId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: Money,
+    #[builder(setter(into))]
+    pub(super) reason: String,
+    #[builder(default)]
+    pub(super) expires_at: Option<DateTime<Utc>>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewHold {
+    pub fn builder() -> NewHoldBuilder {
+        NewHoldBuilder::default()
+    }
+}
+
+impl NewHoldBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.amount.as_ref() {
+            Some(amount) if amount.is_zero() => Err("Hold amount cannot be zero".to_string()),
+            Some(amount) if amount.is_negative() => {
+                Err("Hold amount cannot be negative".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<HoldEvent> for NewHold {
+    fn into_events(self) -> EntityEvents<HoldEvent> {
+        EntityEvents::init(
+            self.id,
+            [HoldEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                ledger_tx_id: self.id.into(),
+                amount: self.amount,
+                reason: self.reason,
+                expires_at: self.expires_at,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use chrono::Duration;
+
+    use crate::primitives::UsdCents;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn new_hold(expires_at: Option<DateTime<Utc>>) -> Hold {
+        let new_hold = NewHold::builder()
+            .id(HoldId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reason("legal order")
+            .expires_at(expires_at)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        Hold::try_from_events(new_hold.into_events()).unwrap()
+    }
+
+    #[test]
+    fn release_is_idempotent() {
+        let mut hold = new_hold(None);
+        assert_eq!(hold.status(), HoldStatus::Active);
+
+        assert!(hold.release(dummy_audit_info()).unwrap().did_execute());
+        assert!(hold.release(dummy_audit_info()).unwrap().was_ignored());
+        assert_eq!(hold.status(), HoldStatus::Released);
+        assert!(hold.concluded_tx_id().is_some());
+    }
+
+    #[test]
+    fn expires_only_after_expiry() {
+        let now = crate::time::now();
+        let mut hold = new_hold(Some(now + Duration::days(1)));
+        assert!(matches!(
+            hold.expire(now, dummy_audit_info()),
+            Err(HoldError::NotYetExpired(_))
+        ));
+
+        assert!(
+            hold.expire(now + Duration::days(1), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(hold.status(), HoldStatus::Expired);
+        assert!(matches!(
+            hold.release(dummy_audit_info()),
+            Err(HoldError::AlreadyConcluded(_))
+        ));
+    }
+}
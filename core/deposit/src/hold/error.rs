@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+use crate::primitives::HoldId;
+
+#[derive(Error, Debug)]
+pub enum HoldError {
+    #[error("HoldError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("HoldError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("HoldError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("HoldError - AlreadyConcluded: {0}")]
+    AlreadyConcluded(HoldId),
+    #[error("HoldError - NotYetExpired: {0}")]
+    NotYetExpired(HoldId),
+}
+
+es_entity::from_es_entity_error!(HoldError);
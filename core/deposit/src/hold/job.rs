@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use authz::PermissionCheck;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use audit::AuditSvc;
+use job::*;
+
+use crate::{CoreDepositAction, CoreDepositObject, ledger::DepositLedger, primitives::HoldId};
+
+use super::{error::HoldError, repo::HoldRepo};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct HoldExpiryJobConfig<Perms> {
+    pub hold_id: HoldId,
+    pub _phantom: std::marker::PhantomData<Perms>,
+}
+
+impl<Perms> JobConfig for HoldExpiryJobConfig<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    type Initializer = HoldExpiryInit<Perms>;
+}
+
+pub struct HoldExpiryInit<Perms>
+where
+    Perms: PermissionCheck,
+{
+    holds: HoldRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+impl<Perms> HoldExpiryInit<Perms>
+where
+    Perms: PermissionCheck,
+{
+    pub fn new(holds: &HoldRepo, ledger: &DepositLedger, audit: &Perms::Audit) -> Self {
+        Self {
+            holds: holds.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+}
+
+const DEPOSIT_HOLD_EXPIRY_JOB: JobType = JobType::new("deposit-hold-expiry");
+impl<Perms> JobInitializer for HoldExpiryInit<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_HOLD_EXPIRY_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(HoldExpiryJobRunner::<Perms> {
+            config: job.config()?,
+            holds: self.holds.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }))
+    }
+}
+
+pub struct HoldExpiryJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+{
+    config: HoldExpiryJobConfig<Perms>,
+    holds: HoldRepo,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+
+#[async_trait]
+impl<Perms> JobRunner for HoldExpiryJobRunner<Perms>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action: From<CoreDepositAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object: From<CoreDepositObject>,
+{
+    #[instrument(
+        name = "deposit.job.hold-expiry",
+        skip(self, _current_job),
+        fields(attempt)
+    )]
+    async fn run(
+        &self,
+        _current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut hold = self.holds.find_by_id(self.config.hold_id).await?;
+        let Some(expires_at) = hold.expires_at else {
+            return Ok(JobCompletion::Complete);
+        };
+        let now = crate::time::now();
+        if expires_at > now {
+            return Ok(JobCompletion::RescheduleAt(expires_at));
+        }
+
+        let mut op = self.holds.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                &mut op,
+                CoreDepositObject::hold(hold.id),
+                CoreDepositAction::HOLD_EXPIRE,
+            )
+            .await?;
+        let ledger_tx_id = match hold.expire(now, audit_info) {
+            Ok(es_entity::Idempotent::Executed(ledger_tx_id)) => ledger_tx_id,
+            Ok(es_entity::Idempotent::Ignored) | Err(HoldError::AlreadyConcluded(_)) => {
+                return Ok(JobCompletion::Complete);
+            }
+            Err(e) => return Err(e.into()),
+        };
+        self.holds.update_in_op(&mut op, &mut hold).await?;
+        self.ledger
+            .release_hold(
+                op,
+                ledger_tx_id,
+                hold.amount.clone(),
+                hold.deposit_account_id,
+            )
+            .await?;
+
+        Ok(JobCompletion::Complete)
+    }
+}
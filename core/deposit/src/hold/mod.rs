@@ -0,0 +1,12 @@
+mod entity;
+pub mod error;
+mod job;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::HoldEvent;
+pub(super) use entity::*;
+pub use entity::{Hold, HoldStatus};
+pub(super) use job::*;
+pub use repo::hold_cursor::HoldsByCreatedAtCursor;
+pub(super) use repo::*;
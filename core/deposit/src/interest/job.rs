@@ -0,0 +1,193 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use job::*;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    account::DepositAccountRepo,
+    event::CoreDepositEvent,
+    ledger::DepositLedger,
+    primitives::{DepositAccountId, Money},
+    product::DepositProductRepo,
+};
+
+use super::{DepositInterestAccrual, InterestInterval, InterestPeriod, payable_interest};
+
+const ACCRUAL_INTERVAL: InterestInterval = InterestInterval::EndOfDay;
+const POSTING_INTERVAL: InterestInterval = InterestInterval::EndOfMonth;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DepositInterestAccrualJobConfig<E> {
+    pub deposit_account_id: DepositAccountId,
+    pub accrual_start: DateTime<Utc>,
+    pub _phantom: std::marker::PhantomData<E>,
+}
+
+impl<E> JobConfig for DepositInterestAccrualJobConfig<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    type Initializer = DepositInterestAccrualInit<E>;
+}
+
+#[derive(Serialize, Deserialize)]
+struct DepositInterestAccrualJobState {
+    accrual_period: InterestPeriod,
+    /// Interest accrued since the last posting, at full precision.
+    #[serde(default)]
+    accrued_in_posting_period: Decimal,
+}
+
+pub struct DepositInterestAccrualInit<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    accounts: DepositAccountRepo<E>,
+    products: DepositProductRepo,
+    ledger: DepositLedger,
+}
+
+impl<E> DepositInterestAccrualInit<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        accounts: &DepositAccountRepo<E>,
+        products: &DepositProductRepo,
+        ledger: &DepositLedger,
+    ) -> Self {
+        Self {
+            accounts: accounts.clone(),
+            products: products.clone(),
+            ledger: ledger.clone(),
+        }
+    }
+}
+
+const DEPOSIT_INTEREST_ACCRUAL_JOB: JobType = JobType::new("deposit-interest-accrual");
+impl<E> JobInitializer for DepositInterestAccrualInit<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_INTEREST_ACCRUAL_JOB
+    }
+
+    fn init(&self, job: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(DepositInterestAccrualJobRunner::<E> {
+            config: job.config()?,
+            accounts: self.accounts.clone(),
+            products: self.products.clone(),
+            ledger: self.ledger.clone(),
+        }))
+    }
+}
+
+pub struct DepositInterestAccrualJobRunner<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    config: DepositInterestAccrualJobConfig<E>,
+    accounts: DepositAccountRepo<E>,
+    products: DepositProductRepo,
+    ledger: DepositLedger,
+}
+
+#[async_trait]
+impl<E> JobRunner for DepositInterestAccrualJobRunner<E>
+where
+    E: OutboxEventMarker<CoreDepositEvent>,
+{
+    #[instrument(
+        name = "deposit.job.interest-accrual",
+        skip(self, current_job),
+        fields(attempt)
+    )]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<DepositInterestAccrualJobState>()?
+            .unwrap_or_else(|| DepositInterestAccrualJobState {
+                accrual_period: ACCRUAL_INTERVAL.period_from(self.config.accrual_start),
+                accrued_in_posting_period: Decimal::ZERO,
+            });
+        let period = state.accrual_period;
+        if period.end > crate::time::now() {
+            return Ok(JobCompletion::RescheduleAt(period.end));
+        }
+
+        let account = self
+            .accounts
+            .find_by_id(self.config.deposit_account_id)
+            .await?;
+        let (Some(deposit_product_id), Some(interest_payable_account_id)) = (
+            account.deposit_product_id,
+            account.interest_payable_account_id,
+        ) else {
+            return Ok(JobCompletion::Complete);
+        };
+        let product = self.products.find_by_id(deposit_product_id).await?;
+
+        let effective = period.end.date_naive();
+        let settled = self
+            .ledger
+            .settled_balance_as_of(account.id, &account.currency, effective)
+            .await?;
+        let interest = if settled.is_negative() {
+            Decimal::ZERO
+        } else {
+            product
+                .annual_rate
+                .interest_for_time_period(&settled, period.days())
+        };
+        // Each accrual books the growth of the rounded running total so that
+        // rounding is applied once per posting period rather than daily.
+        let accrued_before = payable_interest(state.accrued_in_posting_period, &account.currency);
+        state.accrued_in_posting_period += interest;
+        let accrued_after = payable_interest(state.accrued_in_posting_period, &account.currency);
+        let accrued = Money::try_new(
+            accrued_after.amount() - accrued_before.amount(),
+            account.currency.clone(),
+        )?;
+
+        let posted = if period.ends_with(POSTING_INTERVAL) {
+            state.accrued_in_posting_period = Decimal::ZERO;
+            let payable = self
+                .ledger
+                .settled_balance_as_of(interest_payable_account_id, &account.currency, effective)
+                .await?;
+            Some(payable.checked_add(&accrued)?)
+        } else {
+            None
+        };
+
+        state.accrual_period = period.next();
+        let mut op = self.accounts.begin_op().await?;
+        current_job
+            .update_execution_state_in_tx(&mut op, &state)
+            .await?;
+        self.ledger
+            .record_interest_accrual(
+                op,
+                DepositInterestAccrual {
+                    deposit_account_id: account.id,
+                    interest_payable_account_id,
+                    accrued,
+                    posted,
+                    effective,
+                },
+            )
+            .await?;
+
+        Ok(JobCompletion::RescheduleAt(state.accrual_period.end))
+    }
+}
@@ -0,0 +1,119 @@
+mod job;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use core_money::NUMBER_OF_DAYS_IN_YEAR;
+pub use core_money::{InterestInterval, InterestPeriod};
+
+use crate::primitives::{CalaAccountId, CurrencyCode, DepositAccountId, Money};
+
+pub(crate) use job::{DepositInterestAccrualInit, DepositInterestAccrualJobConfig};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(transparent)]
+pub struct AnnualRatePct(Decimal);
+
+impl AnnualRatePct {
+    /// Interest earned on `balance` over `days`, at full precision. It is
+    /// only rounded once it becomes payable, see [`payable_interest`].
+    pub fn interest_for_time_period(&self, balance: &Money, days: u32) -> Decimal {
+        balance.amount() * Decimal::from(days) * self.0
+            / Decimal::ONE_HUNDRED
+            / Decimal::from(NUMBER_OF_DAYS_IN_YEAR)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0.is_sign_negative() && !self.0.is_zero()
+    }
+}
+
+impl From<Decimal> for AnnualRatePct {
+    fn from(value: Decimal) -> Self {
+        AnnualRatePct(value)
+    }
+}
+
+impl From<AnnualRatePct> for Decimal {
+    fn from(value: AnnualRatePct) -> Self {
+        value.0
+    }
+}
+
+/// Interest owed to the holder is truncated to the currency's minor unit so
+/// that rounding never pays out more than has been earned.
+pub fn payable_interest(accrued: Decimal, currency: &CurrencyCode) -> Money {
+    Money::try_new(
+        accrued.round_dp_with_strategy(currency.minor_units(), RoundingStrategy::ToZero),
+        currency.clone(),
+    )
+    .expect("rounded to minor units")
+}
+
+/// Interest accrued on a deposit account for one period, together with the
+/// payable balance to post to the holder when the period closes a month.
+#[derive(Debug, Clone)]
+pub struct DepositInterestAccrual {
+    pub deposit_account_id: DepositAccountId,
+    pub interest_payable_account_id: CalaAccountId,
+    pub accrued: Money,
+    pub posted: Option<Money>,
+    pub effective: chrono::NaiveDate,
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use crate::primitives::UsdCents;
+
+    use super::*;
+
+    #[test]
+    fn interest_calculation() {
+        let rate = AnnualRatePct::from(dec!(12));
+        let balance = Money::from(UsdCents::try_from_usd(dec!(100)).unwrap());
+        let interest = rate.interest_for_time_period(&balance, 365);
+        assert_eq!(
+            payable_interest(interest, &CurrencyCode::USD),
+            Money::from(UsdCents::from(1200))
+        );
+
+        let balance = Money::from(UsdCents::try_from_usd(dec!(1000)).unwrap());
+        let interest = rate.interest_for_time_period(&balance, 1);
+        assert_eq!(
+            payable_interest(interest, &CurrencyCode::USD),
+            Money::from(UsdCents::from(32))
+        );
+    }
+
+    #[test]
+    fn interest_keeps_full_precision_until_payable() {
+        let rate = AnnualRatePct::from(dec!(12));
+        let balance = Money::from(UsdCents::try_from_usd(dec!(1000)).unwrap());
+        let accrued: Decimal = (0..30)
+            .map(|_| rate.interest_for_time_period(&balance, 1))
+            .sum();
+        // Truncating each day would pay 30 * $0.32
+        assert_eq!(
+            payable_interest(accrued, &CurrencyCode::USD),
+            Money::from(UsdCents::from(986))
+        );
+    }
+
+    #[test]
+    fn payable_interest_keeps_currency() {
+        let rate = AnnualRatePct::from(dec!(5));
+        let eur: CurrencyCode = "EUR".parse().unwrap();
+        let balance = Money::try_new(dec!(7300), eur.clone()).unwrap();
+        let interest = rate.interest_for_time_period(&balance, 1);
+        assert_eq!(
+            payable_interest(interest, &eur),
+            Money::try_new(dec!(1), eur).unwrap()
+        );
+    }
+}
@@ -24,14 +24,31 @@ pub enum DepositLedgerError {
     CalaVelocity(#[from] cala_ledger::velocity::error::VelocityError),
     #[error("DepositLedgerError - ConversionError: {0}")]
     ConversionError(#[from] core_money::ConversionError),
+    #[error("DepositLedgerError - MoneyError: {0}")]
+    MoneyError(#[from] core_money::MoneyError),
+    #[error("DepositLedgerError - UnsupportedCurrency: {0}")]
+    UnsupportedCurrency(crate::primitives::CurrencyCode),
     #[error("DepositLedgerError - MissingTxMetadata")]
     MissingTxMetadata,
     #[error("DepositLedgerError - MismatchedTxMetadata: {0}")]
     MismatchedTxMetadata(serde_json::Error),
+    #[error("DepositLedgerError - IntegrationMetaError: {0}")]
+    IntegrationMetaError(#[from] serde_json::Error),
     #[error(
         "DepositLedgerError - NonAccountMemberFoundInAccountSet: Found non-Account typed member in account set {0}"
     )]
     NonAccountMemberFoundInAccountSet(String),
     #[error("DepositLedgerError - JournalIdMismatch: Account sets have wrong JournalId")]
     JournalIdMismatch,
+    #[error(
+        "DepositLedgerError - WithdrawalLimitExceeded: {interval} limit with breach action {breach_action:?}"
+    )]
+    WithdrawalLimitExceeded {
+        breach_action: crate::withdrawal_limit::WithdrawalLimitBreachAction,
+        interval: crate::withdrawal_limit::WithdrawalLimitInterval,
+    },
+    #[error("DepositLedgerError - AccountingPeriodGuardError: {0}")]
+    AccountingPeriodGuardError(
+        #[from] core_accounting::accounting_period::error::AccountingPeriodGuardError,
+    ),
 }
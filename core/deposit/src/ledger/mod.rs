@@ -14,11 +14,14 @@ use cala_ledger::{
     tx_template::Params,
     velocity::{NewVelocityControl, VelocityControlId},
 };
+use core_accounting::AccountingPeriodGuard;
 
 use crate::{
     DepositAccountBalance, DepositReversalData, LedgerOmnibusAccountIds, WithdrawalReversalData,
     chart_of_accounts_integration::ChartOfAccountsIntegrationConfig,
-    primitives::{CalaAccountId, CalaAccountSetId, DepositAccountType, UsdCents},
+    interest::DepositInterestAccrual,
+    primitives::{CalaAccountId, CalaAccountSetId, CurrencyCode, DepositAccountType, Money},
+    withdrawal_limit::{MAX_WITHDRAWAL_LIMIT_AMOUNT, WithdrawalLimitUsage},
 };
 
 use error::*;
@@ -45,8 +48,31 @@ pub const DEPOSIT_OMNIBUS_ACCOUNT_SET_NAME: &str = "Deposit Omnibus Account Set"
 pub const DEPOSIT_OMNIBUS_ACCOUNT_SET_REF: &str = "deposit-omnibus-account-set";
 pub const DEPOSIT_OMNIBUS_ACCOUNT_REF: &str = "deposit-omnibus-account";
 
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME: &str = "Deposit Interest Expense Account Set";
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF: &str = "deposit-interest-expense-account-set";
+pub const DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF: &str = "deposit-interest-expense-account";
+pub const DEPOSIT_INTEREST_PAYABLE_ACCOUNT_SET_NAME: &str = "Deposit Interest Payable Account Set";
+pub const DEPOSIT_INTEREST_PAYABLE_ACCOUNT_SET_REF: &str = "deposit-interest-payable-account-set";
+
+pub const DEPOSIT_HOLDS_ACCOUNT_SET_NAME: &str = "Deposit Holds Account Set";
+pub const DEPOSIT_HOLDS_ACCOUNT_SET_REF: &str = "deposit-holds-account-set";
+pub const DEPOSIT_HOLDS_ACCOUNT_REF: &str = "deposit-holds-account";
+
+pub const DEPOSIT_WITHDRAWAL_LIMIT_USAGE_OMNIBUS_ACCOUNT_SET_NAME: &str =
+    "Deposit Withdrawal Limit Usage Omnibus Account Set";
+pub const DEPOSIT_WITHDRAWAL_LIMIT_USAGE_OMNIBUS_ACCOUNT_SET_REF: &str =
+    "deposit-withdrawal-limit-usage-omnibus-account-set";
+pub const DEPOSIT_WITHDRAWAL_LIMIT_USAGE_OMNIBUS_ACCOUNT_REF: &str =
+    "deposit-withdrawal-limit-usage-omnibus-account";
+pub const DEPOSIT_WITHDRAWAL_LIMIT_USAGE_ACCOUNT_NAME: &str =
+    "Deposit Withdrawal Limit Usage Account";
+pub const DEPOSIT_WITHDRAWAL_LIMIT_USAGE_ACCOUNT_REF: &str =
+    "deposit-withdrawal-limit-usage-account";
+
 pub const DEPOSITS_VELOCITY_CONTROL_ID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000001");
+pub const WITHDRAWAL_LIMITS_VELOCITY_CONTROL_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000002");
 
 #[derive(Clone, Copy)]
 pub struct InternalAccountSetDetails {
@@ -79,6 +105,51 @@ impl DepositAccountSets {
     fn account_set_id_for_config(&self) -> CalaAccountSetId {
         self.individual.id
     }
+
+    fn account_type(&self, account_set_id: CalaAccountSetId) -> Option<DepositAccountType> {
+        [
+            (self.individual.id, DepositAccountType::Individual),
+            (
+                self.government_entity.id,
+                DepositAccountType::GovernmentEntity,
+            ),
+            (self.private_company.id, DepositAccountType::PrivateCompany),
+            (self.bank.id, DepositAccountType::Bank),
+            (
+                self.financial_institution.id,
+                DepositAccountType::FinancialInstitution,
+            ),
+            (
+                self.non_domiciled_individual.id,
+                DepositAccountType::NonDomiciledCompany,
+            ),
+        ]
+        .into_iter()
+        .find_map(|(id, account_type)| (id == account_set_id).then_some(account_type))
+    }
+}
+
+/// Memo accounts that withdrawal limit usage is posted to, one per usage
+/// slot so that a velocity error tells which limit was breached.
+#[derive(Clone)]
+pub struct WithdrawalLimitUsageAccountIds {
+    omnibus_account_id: CalaAccountId,
+    usage_account_ids: HashMap<&'static str, CalaAccountId>,
+}
+
+impl WithdrawalLimitUsageAccountIds {
+    fn breached_slot(&self, error: &DepositLedgerError) -> Option<&'static str> {
+        let DepositLedgerError::CalaLedger(cala_ledger::error::LedgerError::VelocityError(
+            cala_ledger::velocity::error::VelocityError::Enforcement(e),
+        )) = error
+        else {
+            return None;
+        };
+        self.usage_account_ids
+            .iter()
+            .find(|(_, id)| **id == e.account_id)
+            .map(|(slot, _)| *slot)
+    }
 }
 
 #[derive(Clone)]
@@ -87,14 +158,19 @@ pub struct DepositLedger {
     journal_id: JournalId,
     deposits_account_set: DepositAccountSets,
     deposit_omnibus_account_ids: LedgerOmnibusAccountIds,
-    usd: Currency,
+    interest_expense_account_ids: LedgerOmnibusAccountIds,
+    holds_omnibus_account_ids: LedgerOmnibusAccountIds,
+    withdrawal_limit_usage_account_ids: WithdrawalLimitUsageAccountIds,
+    interest_payable_account_set: InternalAccountSetDetails,
     deposit_control_id: VelocityControlId,
+    period_guard: AccountingPeriodGuard,
 }
 
 impl DepositLedger {
     pub async fn init(
         cala: &CalaLedger,
         journal_id: JournalId,
+        period_guard: AccountingPeriodGuard,
     ) -> Result<Self, DepositLedgerError> {
         templates::RecordDeposit::init(cala).await?;
         templates::InitiateWithdraw::init(cala).await?;
@@ -102,6 +178,13 @@ impl DepositLedger {
         templates::ConfirmWithdraw::init(cala).await?;
         templates::RevertWithdraw::init(cala).await?;
         templates::RevertDeposit::init(cala).await?;
+        templates::Transfer::init(cala).await?;
+        templates::AccrueDepositInterest::init(cala).await?;
+        templates::PostDepositInterest::init(cala).await?;
+        templates::PlaceHold::init(cala).await?;
+        templates::ReleaseHold::init(cala).await?;
+        templates::RecordWithdrawalLimitUsage::init(cala).await?;
+        templates::ReleaseWithdrawalLimitUsage::init(cala).await?;
 
         let deposits_normal_balance_type = DebitOrCredit::Credit;
 
@@ -169,19 +252,55 @@ impl DepositLedger {
         )
         .await?;
 
+        let interest_expense_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_INTEREST_EXPENSE_ACCOUNT_REF}"),
+            DEPOSIT_INTEREST_EXPENSE_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Debit,
+        )
+        .await?;
+
+        let holds_omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_HOLDS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_HOLDS_ACCOUNT_REF}"),
+            DEPOSIT_HOLDS_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Credit,
+        )
+        .await?;
+
+        let interest_payable_account_set_id = Self::find_or_create_account_set(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_INTEREST_PAYABLE_ACCOUNT_SET_REF}"),
+            DEPOSIT_INTEREST_PAYABLE_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Credit,
+        )
+        .await?;
+
         let overdraft_prevention_id = velocity::OverdraftPrevention::init(cala).await?;
+        let held_funds_protection_id = velocity::HeldFundsProtection::init(cala).await?;
 
         let deposit_control_id = Self::create_deposit_control(cala).await?;
 
-        match cala
-            .velocities()
-            .add_limit_to_control(deposit_control_id, overdraft_prevention_id)
-            .await
-        {
-            Ok(_)
-            | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
-            Err(e) => return Err(e.into()),
+        for limit_id in [overdraft_prevention_id, held_funds_protection_id] {
+            match cala
+                .velocities()
+                .add_limit_to_control(deposit_control_id, limit_id)
+                .await
+            {
+                Ok(_)
+                | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
+                Err(e) => return Err(e.into()),
+            }
         }
+
+        let withdrawal_limit_usage_account_ids =
+            Self::find_or_create_withdrawal_limit_usage_accounts(cala, journal_id).await?;
+
         Ok(Self {
             cala: cala.clone(),
             journal_id,
@@ -212,11 +331,35 @@ impl DepositLedger {
                 },
             },
             deposit_omnibus_account_ids,
+            interest_expense_account_ids,
+            holds_omnibus_account_ids,
+            withdrawal_limit_usage_account_ids,
+            interest_payable_account_set: InternalAccountSetDetails {
+                id: interest_payable_account_set_id,
+                normal_balance_type: DebitOrCredit::Credit,
+            },
             deposit_control_id,
-            usd: Currency::USD,
+            period_guard,
         })
     }
 
+    async fn post_transaction_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        tx_id: TransactionId,
+        tx_template_code: &str,
+        params: impl Into<Params> + std::fmt::Debug,
+    ) -> Result<(), DepositLedgerError> {
+        let tx = self
+            .cala
+            .post_transaction_in_op(op, tx_id, tx_template_code, params)
+            .await?;
+        self.period_guard
+            .ensure_open_for_system_postings(tx.values().effective)
+            .await?;
+        Ok(())
+    }
+
     async fn find_or_create_account_set(
         cala: &CalaLedger,
         journal_id: JournalId,
@@ -333,6 +476,105 @@ impl DepositLedger {
         })
     }
 
+    async fn find_or_create_withdrawal_limit_usage_accounts(
+        cala: &CalaLedger,
+        journal_id: JournalId,
+    ) -> Result<WithdrawalLimitUsageAccountIds, DepositLedgerError> {
+        let omnibus_account_ids = Self::find_or_create_omnibus_account(
+            cala,
+            journal_id,
+            format!("{journal_id}:{DEPOSIT_WITHDRAWAL_LIMIT_USAGE_OMNIBUS_ACCOUNT_SET_REF}"),
+            format!("{journal_id}:{DEPOSIT_WITHDRAWAL_LIMIT_USAGE_OMNIBUS_ACCOUNT_REF}"),
+            DEPOSIT_WITHDRAWAL_LIMIT_USAGE_OMNIBUS_ACCOUNT_SET_NAME.to_string(),
+            DebitOrCredit::Credit,
+        )
+        .await?;
+
+        let usage_cap_id = velocity::WithdrawalLimitUsageCap::init(cala).await?;
+        let control = NewVelocityControl::builder()
+            .id(WITHDRAWAL_LIMITS_VELOCITY_CONTROL_ID)
+            .name("Withdrawal Limits Control")
+            .description("Velocity Control for Withdrawal Limits")
+            .build()
+            .expect("build control");
+        let control_id = match cala.velocities().create_control(control).await {
+            Err(cala_ledger::velocity::error::VelocityError::ControlIdAlreadyExists) => {
+                WITHDRAWAL_LIMITS_VELOCITY_CONTROL_ID.into()
+            }
+            Err(e) => return Err(e.into()),
+            Ok(control) => control.id(),
+        };
+        match cala
+            .velocities()
+            .add_limit_to_control(control_id, usage_cap_id)
+            .await
+        {
+            Ok(_)
+            | Err(cala_ledger::velocity::error::VelocityError::LimitAlreadyAddedToControl) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut usage_account_ids = HashMap::new();
+        for slot in templates::WithdrawalLimitUsageSlots::NAMES {
+            let reference =
+                format!("{journal_id}:{DEPOSIT_WITHDRAWAL_LIMIT_USAGE_ACCOUNT_REF}:{slot}");
+            let name = format!("{DEPOSIT_WITHDRAWAL_LIMIT_USAGE_ACCOUNT_NAME} ({slot})");
+            let id = Self::find_or_create_withdrawal_limit_usage_account(
+                cala, control_id, reference, name,
+            )
+            .await?;
+            usage_account_ids.insert(slot, id);
+        }
+
+        Ok(WithdrawalLimitUsageAccountIds {
+            omnibus_account_id: omnibus_account_ids.account_id,
+            usage_account_ids,
+        })
+    }
+
+    async fn find_or_create_withdrawal_limit_usage_account(
+        cala: &CalaLedger,
+        control_id: VelocityControlId,
+        reference: String,
+        name: String,
+    ) -> Result<CalaAccountId, DepositLedgerError> {
+        match cala.accounts().find_by_external_id(reference.clone()).await {
+            Ok(account) => return Ok(account.id),
+            Err(cala_ledger::account::error::AccountError::CouldNotFindByExternalId(_)) => (),
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut op = cala.begin_operation().await?;
+        let id = CalaAccountId::new();
+        let new_ledger_account = NewAccount::builder()
+            .id(id)
+            .external_id(reference.to_string())
+            .name(name.clone())
+            .description(name)
+            .code(id.to_string())
+            .normal_balance_type(DebitOrCredit::Debit)
+            .build()
+            .expect("Could not build new account");
+
+        match cala
+            .accounts()
+            .create_in_op(&mut op, new_ledger_account)
+            .await
+        {
+            Ok(_) => {
+                cala.velocities()
+                    .attach_control_to_account_in_op(&mut op, control_id, id, Params::default())
+                    .await?;
+                op.commit().await?;
+                Ok(id)
+            }
+            Err(cala_ledger::account::error::AccountError::ExternalIdAlreadyExists) => {
+                Ok(cala.accounts().find_by_external_id(reference).await?.id)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub async fn account_history<T, U>(
         &self,
         id: impl Into<AccountId>,
@@ -369,7 +611,7 @@ impl DepositLedger {
         &self,
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
@@ -379,13 +621,12 @@ impl DepositLedger {
 
         let params = templates::RecordDepositParams {
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
+            currency: cala_currency(amount.currency())?,
+            amount: amount.amount(),
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id: credit_account_id.into(),
         };
-        self.cala
-            .post_transaction_in_op(&mut op, tx_id, templates::RECORD_DEPOSIT_CODE, params)
+        self.post_transaction_in_op(&mut op, tx_id, templates::RECORD_DEPOSIT_CODE, params)
             .await?;
 
         op.commit().await?;
@@ -396,24 +637,34 @@ impl DepositLedger {
         &self,
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
+        withdrawal_limit_usage: &[WithdrawalLimitUsage],
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
+        let credit_account_id = credit_account_id.into();
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
 
+        self.record_withdrawal_limit_usage_in_op(
+            &mut op,
+            tx_id,
+            credit_account_id,
+            &amount,
+            withdrawal_limit_usage,
+        )
+        .await?;
+
         let params = templates::InitiateWithdrawParams {
             journal_id: self.journal_id,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
-            credit_account_id: credit_account_id.into(),
-            amount: amount.to_usd(),
-            currency: self.usd,
+            credit_account_id,
+            currency: cala_currency(amount.currency())?,
+            amount: amount.amount(),
         };
 
-        self.cala
-            .post_transaction_in_op(&mut op, tx_id, templates::INITIATE_WITHDRAW_CODE, params)
+        self.post_transaction_in_op(&mut op, tx_id, templates::INITIATE_WITHDRAW_CODE, params)
             .await?;
 
         op.commit().await?;
@@ -423,30 +674,38 @@ impl DepositLedger {
     pub async fn revert_withdrawal(
         &self,
         op: es_entity::DbOp<'_>,
+        initiated_tx_id: impl Into<TransactionId>,
         reversal_data: WithdrawalReversalData,
     ) -> Result<(), DepositLedgerError> {
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
 
+        self.release_withdrawal_limit_usage_in_op(
+            &mut op,
+            initiated_tx_id.into(),
+            reversal_data.credit_account_id.into(),
+            reversal_data.amount.currency(),
+        )
+        .await?;
+
         let params = templates::RevertWithdrawParams {
             journal_id: self.journal_id,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id: reversal_data.credit_account_id.into(),
-            amount: reversal_data.amount.to_usd(),
-            currency: self.usd,
+            currency: cala_currency(reversal_data.amount.currency())?,
+            amount: reversal_data.amount.amount(),
             correlation_id: reversal_data.correlation_id,
             external_id: reversal_data.external_id,
         };
 
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                reversal_data.ledger_tx_id,
-                templates::REVERT_WITHDRAW_CODE,
-                params,
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            reversal_data.ledger_tx_id,
+            templates::REVERT_WITHDRAW_CODE,
+            params,
+        )
+        .await?;
         op.commit().await?;
 
         Ok(())
@@ -467,18 +726,17 @@ impl DepositLedger {
             credit_account_id: reversal_data.credit_account_id.into(),
             correlation_id: reversal_data.correlation_id,
             external_id: reversal_data.external_id,
-            amount: reversal_data.amount.to_usd(),
-            currency: self.usd,
+            currency: cala_currency(reversal_data.amount.currency())?,
+            amount: reversal_data.amount.amount(),
         };
 
-        self.cala
-            .post_transaction_in_op(
-                &mut op,
-                reversal_data.ledger_tx_id,
-                templates::REVERT_DEPOSIT_CODE,
-                params,
-            )
-            .await?;
+        self.post_transaction_in_op(
+            &mut op,
+            reversal_data.ledger_tx_id,
+            templates::REVERT_DEPOSIT_CODE,
+            params,
+        )
+        .await?;
         op.commit().await?;
 
         Ok(())
@@ -489,7 +747,7 @@ impl DepositLedger {
         op: es_entity::DbOp<'_>,
         tx_id: impl Into<TransactionId>,
         correlation_id: String,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
         external_id: String,
     ) -> Result<(), DepositLedgerError> {
@@ -500,16 +758,15 @@ impl DepositLedger {
 
         let params = templates::ConfirmWithdrawParams {
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
+            currency: cala_currency(amount.currency())?,
+            amount: amount.amount(),
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
             credit_account_id: credit_account_id.into(),
             correlation_id,
             external_id,
         };
 
-        self.cala
-            .post_transaction_in_op(&mut op, tx_id, templates::CONFIRM_WITHDRAW_CODE, params)
+        self.post_transaction_in_op(&mut op, tx_id, templates::CONFIRM_WITHDRAW_CODE, params)
             .await?;
         op.commit().await?;
         Ok(())
@@ -518,26 +775,351 @@ impl DepositLedger {
     pub async fn cancel_withdrawal(
         &self,
         op: es_entity::DbOp<'_>,
+        initiated_tx_id: impl Into<TransactionId>,
         tx_id: impl Into<TransactionId>,
-        amount: UsdCents,
+        amount: Money,
         credit_account_id: impl Into<AccountId>,
     ) -> Result<(), DepositLedgerError> {
         let tx_id = tx_id.into();
+        let credit_account_id = credit_account_id.into();
         let mut op = self
             .cala
             .ledger_operation_from_db_op(op.with_db_time().await?);
 
+        self.release_withdrawal_limit_usage_in_op(
+            &mut op,
+            initiated_tx_id.into(),
+            credit_account_id,
+            amount.currency(),
+        )
+        .await?;
+
         let params = templates::CancelWithdrawParams {
             journal_id: self.journal_id,
-            currency: self.usd,
-            amount: amount.to_usd(),
-            credit_account_id: credit_account_id.into(),
+            currency: cala_currency(amount.currency())?,
+            amount: amount.amount(),
+            credit_account_id,
             deposit_omnibus_account_id: self.deposit_omnibus_account_ids.account_id,
         };
 
-        self.cala
-            .post_transaction_in_op(&mut op, tx_id, templates::CANCEL_WITHDRAW_CODE, params)
+        self.post_transaction_in_op(&mut op, tx_id, templates::CANCEL_WITHDRAW_CODE, params)
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn transfer(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: Money,
+        debit_account_id: impl Into<AccountId>,
+        credit_account_id: impl Into<AccountId>,
+        withdrawal_limit_usage: &[WithdrawalLimitUsage],
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let debit_account_id = debit_account_id.into();
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+
+        self.record_withdrawal_limit_usage_in_op(
+            &mut op,
+            tx_id,
+            debit_account_id,
+            &amount,
+            withdrawal_limit_usage,
+        )
+        .await?;
+
+        let params = templates::TransferParams {
+            journal_id: self.journal_id,
+            currency: cala_currency(amount.currency())?,
+            amount: amount.amount(),
+            debit_account_id,
+            credit_account_id: credit_account_id.into(),
+        };
+
+        self.post_transaction_in_op(&mut op, tx_id, templates::TRANSFER_CODE, params)
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// Records usage for funds that will leave `debit_account_id` in
+    /// `tx_id` once an approval concludes, so that the limits are enforced
+    /// when the request is made rather than when it is posted.
+    pub async fn record_withdrawal_limit_usage(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        debit_account_id: impl Into<AccountId>,
+        amount: &Money,
+        withdrawal_limit_usage: &[WithdrawalLimitUsage],
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+
+        self.record_withdrawal_limit_usage_in_op(
+            &mut op,
+            tx_id.into(),
+            debit_account_id.into(),
+            amount,
+            withdrawal_limit_usage,
+        )
+        .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn release_withdrawal_limit_usage(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        debit_account_id: impl Into<AccountId>,
+        currency: &CurrencyCode,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+
+        self.release_withdrawal_limit_usage_in_op(
+            &mut op,
+            tx_id.into(),
+            debit_account_id.into(),
+            currency,
+        )
+        .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn record_withdrawal_limit_usage_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        tx_id: TransactionId,
+        debit_account_id: AccountId,
+        amount: &Money,
+        withdrawal_limit_usage: &[WithdrawalLimitUsage],
+    ) -> Result<(), DepositLedgerError> {
+        if withdrawal_limit_usage.is_empty() {
+            return Ok(());
+        }
+
+        let usage_accounts = &self.withdrawal_limit_usage_account_ids;
+        let mut slots =
+            templates::WithdrawalLimitUsageSlots::unused(usage_accounts.omnibus_account_id);
+        for usage in withdrawal_limit_usage {
+            *slots.slot_mut(&usage.scope, usage.interval) = templates::WithdrawalLimitUsageSlot {
+                usage_account_id: usage_accounts.usage_account_ids
+                    [templates::WithdrawalLimitUsageSlots::name(&usage.scope, usage.interval)],
+                amount: usage.amount,
+                headroom: MAX_WITHDRAWAL_LIMIT_AMOUNT - usage.limit,
+                withdrawal_limit_id: usage.withdrawal_limit_id.into(),
+                period: usage.period.clone(),
+            };
+        }
+
+        let params = templates::WithdrawalLimitUsageParams {
+            journal_id: self.journal_id,
+            currency: cala_currency(amount.currency())?,
+            usage_omnibus_account_id: usage_accounts.omnibus_account_id,
+            deposit_account_id: debit_account_id,
+            slots,
+            correlation_id: tx_id.to_string(),
+            external_id: withdrawal_limit_usage_external_id(tx_id),
+        };
+        self.post_transaction_in_op(
+            op,
+            TransactionId::new(),
+            templates::RECORD_WITHDRAWAL_LIMIT_USAGE_CODE,
+            params,
+        )
+        .await
+        .map_err(|e| {
+            let breached = usage_accounts.breached_slot(&e).and_then(|slot| {
+                withdrawal_limit_usage.iter().find(|usage| {
+                    templates::WithdrawalLimitUsageSlots::name(&usage.scope, usage.interval) == slot
+                })
+            });
+            match breached {
+                Some(usage) => DepositLedgerError::WithdrawalLimitExceeded {
+                    breach_action: usage.breach_action,
+                    interval: usage.interval,
+                },
+                None => e,
+            }
+        })
+    }
+
+    /// Gives back the usage recorded for `tx_id`. Transactions initiated
+    /// before withdrawal limits were enforced by the ledger have none.
+    async fn release_withdrawal_limit_usage_in_op(
+        &self,
+        op: &mut LedgerOperation<'_>,
+        tx_id: TransactionId,
+        debit_account_id: AccountId,
+        currency: &CurrencyCode,
+    ) -> Result<(), DepositLedgerError> {
+        let external_id = withdrawal_limit_usage_external_id(tx_id);
+        let release_external_id = format!("{external_id}:release");
+        let usage_tx = match self
+            .cala
+            .transactions()
+            .find_by_external_id(external_id)
+            .await
+        {
+            Ok(tx) => tx,
+            Err(cala_ledger::transaction::error::TransactionError::CouldNotFindByExternalId(_)) => {
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+        match self
+            .cala
+            .transactions()
+            .find_by_external_id(release_external_id.clone())
+            .await
+        {
+            Ok(_) => return Ok(()),
+            Err(cala_ledger::transaction::error::TransactionError::CouldNotFindByExternalId(_)) => {
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let slots: templates::WithdrawalLimitUsageSlots = serde_json::from_value(
+            usage_tx
+                .values()
+                .metadata
+                .clone()
+                .ok_or(DepositLedgerError::MissingTxMetadata)?,
+        )
+        .map_err(DepositLedgerError::MismatchedTxMetadata)?;
+
+        let params = templates::WithdrawalLimitUsageParams {
+            journal_id: self.journal_id,
+            currency: cala_currency(currency)?,
+            usage_omnibus_account_id: self.withdrawal_limit_usage_account_ids.omnibus_account_id,
+            deposit_account_id: debit_account_id,
+            slots,
+            correlation_id: tx_id.to_string(),
+            external_id: release_external_id,
+        };
+        self.post_transaction_in_op(
+            op,
+            TransactionId::new(),
+            templates::RELEASE_WITHDRAWAL_LIMIT_USAGE_CODE,
+            params,
+        )
+        .await
+    }
+
+    pub async fn place_hold(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: Money,
+        deposit_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+
+        let params = templates::PlaceHoldParams {
+            journal_id: self.journal_id,
+            holds_omnibus_account_id: self.holds_omnibus_account_ids.account_id,
+            deposit_account_id: deposit_account_id.into(),
+            currency: cala_currency(amount.currency())?,
+            amount: amount.amount(),
+        };
+
+        self.post_transaction_in_op(&mut op, tx_id, templates::PLACE_HOLD_CODE, params)
             .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn release_hold(
+        &self,
+        op: es_entity::DbOp<'_>,
+        tx_id: impl Into<TransactionId>,
+        amount: Money,
+        deposit_account_id: impl Into<AccountId>,
+    ) -> Result<(), DepositLedgerError> {
+        let tx_id = tx_id.into();
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+
+        let params = templates::ReleaseHoldParams {
+            journal_id: self.journal_id,
+            holds_omnibus_account_id: self.holds_omnibus_account_ids.account_id,
+            deposit_account_id: deposit_account_id.into(),
+            currency: cala_currency(amount.currency())?,
+            amount: amount.amount(),
+        };
+
+        self.post_transaction_in_op(&mut op, tx_id, templates::RELEASE_HOLD_CODE, params)
+            .await?;
+
+        op.commit().await?;
+        Ok(())
+    }
+
+    pub async fn record_interest_accrual(
+        &self,
+        op: es_entity::DbOp<'_>,
+        DepositInterestAccrual {
+            deposit_account_id,
+            interest_payable_account_id,
+            accrued,
+            posted,
+            effective,
+        }: DepositInterestAccrual,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+
+        if !accrued.is_zero() {
+            let params = templates::AccrueDepositInterestParams {
+                journal_id: self.journal_id,
+                currency: cala_currency(accrued.currency())?,
+                amount: accrued.amount(),
+                interest_expense_account_id: self.interest_expense_account_ids.account_id,
+                interest_payable_account_id,
+                effective,
+            };
+            self.post_transaction_in_op(
+                &mut op,
+                TransactionId::new(),
+                templates::ACCRUE_DEPOSIT_INTEREST_CODE,
+                params,
+            )
+            .await?;
+        }
+
+        if let Some(posted) = posted.filter(|amount| !amount.is_zero()) {
+            let params = templates::PostDepositInterestParams {
+                journal_id: self.journal_id,
+                currency: cala_currency(posted.currency())?,
+                amount: posted.amount(),
+                deposit_account_id: deposit_account_id.into(),
+                interest_payable_account_id,
+                effective,
+            };
+            self.post_transaction_in_op(
+                &mut op,
+                TransactionId::new(),
+                templates::POST_DEPOSIT_INTEREST_CODE,
+                params,
+            )
+            .await?;
+        }
+
         op.commit().await?;
         Ok(())
     }
@@ -545,19 +1127,49 @@ impl DepositLedger {
     pub async fn balance(
         &self,
         account_id: impl Into<AccountId>,
+        currency: &CurrencyCode,
     ) -> Result<DepositAccountBalance, DepositLedgerError> {
         match self
             .cala
             .balances()
-            .find(self.journal_id, account_id.into(), self.usd)
+            .find(self.journal_id, account_id.into(), cala_currency(currency)?)
             .await
         {
             Ok(balances) => Ok(DepositAccountBalance {
-                settled: UsdCents::try_from_usd(balances.settled())?,
-                pending: UsdCents::try_from_usd(balances.pending())?,
+                settled: Money::try_new(balances.settled(), currency.clone())?,
+                pending: Money::try_new(balances.pending(), currency.clone())?,
+                held: Money::try_new(-balances.encumbrance(), currency.clone())?,
             }),
             Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => {
-                Ok(DepositAccountBalance::ZERO)
+                Ok(DepositAccountBalance::zero(currency.clone()))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Settled balance at the close of `date`, as recorded by effective date
+    /// rather than by when the entries were posted.
+    pub async fn settled_balance_as_of(
+        &self,
+        account_id: impl Into<AccountId>,
+        currency: &CurrencyCode,
+        date: chrono::NaiveDate,
+    ) -> Result<Money, DepositLedgerError> {
+        match self
+            .cala
+            .balances()
+            .effective()
+            .find_cumulative(
+                self.journal_id,
+                account_id.into(),
+                cala_currency(currency)?,
+                date,
+            )
+            .await
+        {
+            Ok(balance) => Ok(Money::try_new(balance.settled(), currency.clone())?),
+            Err(cala_ledger::balance::error::BalanceError::NotFound(..)) => {
+                Ok(Money::zero(currency.clone()))
             }
             Err(e) => Err(e.into()),
         }
@@ -594,6 +1206,56 @@ impl DepositLedger {
         Ok(())
     }
 
+    pub async fn create_interest_payable_account(
+        &self,
+        op: es_entity::DbOp<'_>,
+        id: impl Into<CalaAccountId>,
+        deposit_account_reference: &str,
+    ) -> Result<(), DepositLedgerError> {
+        let mut op = self
+            .cala
+            .ledger_operation_from_db_op(op.with_db_time().await?);
+
+        let name = format!("Interest Payable for {deposit_account_reference}");
+        self.create_account_in_op(
+            &mut op,
+            id,
+            self.interest_payable_account_set,
+            &format!("{deposit_account_reference}:interest-payable"),
+            &name,
+            &name,
+        )
+        .await?;
+
+        op.commit().await?;
+
+        Ok(())
+    }
+
+    /// Deposit account type as recorded by the account set the ledger account
+    /// was filed under, for accounts opened before the type was kept on the
+    /// deposit account itself.
+    pub async fn deposit_account_type(
+        &self,
+        id: impl Into<AccountId>,
+    ) -> Result<Option<DepositAccountType>, DepositLedgerError> {
+        let account_sets = self
+            .cala
+            .account_sets()
+            .find_where_member(
+                id.into(),
+                es_entity::PaginatedQueryArgs {
+                    first: 10,
+                    after: None,
+                },
+            )
+            .await?;
+        Ok(account_sets
+            .entities
+            .iter()
+            .find_map(|account_set| self.deposits_account_set.account_type(account_set.id)))
+    }
+
     fn deposit_internal_account_set_from_type(
         &self,
         deposit_account_type: DepositAccountType,
@@ -691,8 +1353,7 @@ impl DepositLedger {
             .find(self.deposits_account_set.account_set_id_for_config())
             .await?;
         if let Some(meta) = account_set.values().metadata.as_ref() {
-            let meta: ChartOfAccountsIntegrationMeta =
-                serde_json::from_value(meta.clone()).expect("Could not deserialize metadata");
+            let meta: ChartOfAccountsIntegrationMeta = serde_json::from_value(meta.clone())?;
             Ok(Some(meta.config))
         } else {
             Ok(None)
@@ -750,7 +1411,11 @@ impl DepositLedger {
     ) -> Result<(), DepositLedgerError> {
         let mut op = self.cala.begin_operation().await?;
 
-        let mut account_set_ids = vec![self.deposit_omnibus_account_ids.account_set_id];
+        let mut account_set_ids = vec![
+            self.deposit_omnibus_account_ids.account_set_id,
+            self.interest_expense_account_ids.account_set_id,
+            self.interest_payable_account_set.id,
+        ];
         account_set_ids.extend(self.deposits_account_set.account_set_ids());
         let mut account_sets = self
             .cala
@@ -773,6 +1438,8 @@ impl DepositLedger {
                 financial_institution_deposit_parent_account_set_id,
             non_domiciled_individual_deposit_accounts_parent_account_set_id:
                 non_domiciled_company_deposit_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            interest_payable_parent_account_set_id,
         } = &charts_integration_meta;
 
         self.attach_charts_account_set(
@@ -845,6 +1512,36 @@ impl DepositLedger {
         )
         .await?;
 
+        if let Some(parent_account_set_id) = interest_expense_parent_account_set_id {
+            self.attach_charts_account_set(
+                &mut op,
+                &mut account_sets,
+                self.interest_expense_account_ids.account_set_id,
+                *parent_account_set_id,
+                &charts_integration_meta,
+                |meta| {
+                    meta.interest_expense_parent_account_set_id
+                        .unwrap_or(*parent_account_set_id)
+                },
+            )
+            .await?;
+        }
+
+        if let Some(parent_account_set_id) = interest_payable_parent_account_set_id {
+            self.attach_charts_account_set(
+                &mut op,
+                &mut account_sets,
+                self.interest_payable_account_set.id,
+                *parent_account_set_id,
+                &charts_integration_meta,
+                |meta| {
+                    meta.interest_payable_parent_account_set_id
+                        .unwrap_or(*parent_account_set_id)
+                },
+            )
+            .await?;
+        }
+
         op.commit().await?;
 
         Ok(())
@@ -864,4 +1561,20 @@ pub struct ChartOfAccountsIntegrationMeta {
     pub bank_deposit_accounts_parent_account_set_id: CalaAccountSetId,
     pub financial_institution_deposit_accounts_parent_account_set_id: CalaAccountSetId,
     pub non_domiciled_individual_deposit_accounts_parent_account_set_id: CalaAccountSetId,
+
+    #[serde(default)]
+    pub interest_expense_parent_account_set_id: Option<CalaAccountSetId>,
+    #[serde(default)]
+    pub interest_payable_parent_account_set_id: Option<CalaAccountSetId>,
+}
+
+fn withdrawal_limit_usage_external_id(tx_id: TransactionId) -> String {
+    format!("{tx_id}:withdrawal-limit-usage")
+}
+
+fn cala_currency(currency: &CurrencyCode) -> Result<Currency, DepositLedgerError> {
+    currency
+        .as_str()
+        .parse()
+        .map_err(|_| DepositLedgerError::UnsupportedCurrency(currency.clone()))
 }
@@ -1,13 +1,25 @@
+mod accrue_interest;
 mod cancel_withdraw;
 mod confirm_withdraw;
 mod initiate_withdraw;
+mod place_hold;
+mod post_interest;
 mod record_deposit;
+mod release_hold;
 mod revert_deposit;
 mod revert_withdraw;
+mod transfer;
+mod withdrawal_limit_usage;
 
+pub use accrue_interest::*;
 pub use cancel_withdraw::*;
 pub use confirm_withdraw::*;
 pub use initiate_withdraw::*;
+pub use place_hold::*;
+pub use post_interest::*;
 pub use record_deposit::*;
+pub use release_hold::*;
 pub use revert_deposit::*;
 pub use revert_withdraw::*;
+pub use transfer::*;
+pub use withdrawal_limit_usage::*;
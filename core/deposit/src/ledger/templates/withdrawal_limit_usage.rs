@@ -0,0 +1,400 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use cala_ledger::{
+    tx_template::{Params, error::TxTemplateError, *},
+    *,
+};
+
+use crate::{
+    ledger::error::*,
+    primitives::CalaAccountId,
+    withdrawal_limit::{WithdrawalLimitInterval, WithdrawalLimitScope},
+};
+
+pub const RECORD_WITHDRAWAL_LIMIT_USAGE_CODE: &str = "RECORD_WITHDRAWAL_LIMIT_USAGE";
+pub const RELEASE_WITHDRAWAL_LIMIT_USAGE_CODE: &str = "RELEASE_WITHDRAWAL_LIMIT_USAGE";
+
+#[derive(Debug)]
+pub struct WithdrawalLimitUsageParams {
+    pub journal_id: JournalId,
+    pub currency: Currency,
+    pub usage_omnibus_account_id: CalaAccountId,
+    pub deposit_account_id: CalaAccountId,
+    pub slots: WithdrawalLimitUsageSlots,
+    pub correlation_id: String,
+    pub external_id: String,
+}
+
+/// One slot per limit scope and interval. Slots without a limit post zero
+/// amounts against the omnibus account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalLimitUsageSlots {
+    pub account_daily: WithdrawalLimitUsageSlot,
+    pub account_weekly: WithdrawalLimitUsageSlot,
+    pub account_monthly: WithdrawalLimitUsageSlot,
+    pub account_type_daily: WithdrawalLimitUsageSlot,
+    pub account_type_weekly: WithdrawalLimitUsageSlot,
+    pub account_type_monthly: WithdrawalLimitUsageSlot,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalLimitUsageSlot {
+    pub usage_account_id: CalaAccountId,
+    pub amount: Decimal,
+    /// Difference between the usage cap and the configured limit. Only the
+    /// recording of usage reserves it.
+    #[serde(default)]
+    pub headroom: Decimal,
+    pub withdrawal_limit_id: uuid::Uuid,
+    pub period: String,
+}
+
+impl WithdrawalLimitUsageSlot {
+    pub fn unused(usage_omnibus_account_id: CalaAccountId) -> Self {
+        Self {
+            usage_account_id: usage_omnibus_account_id,
+            amount: Decimal::ZERO,
+            headroom: Decimal::ZERO,
+            withdrawal_limit_id: uuid::Uuid::nil(),
+            period: String::new(),
+        }
+    }
+}
+
+impl WithdrawalLimitUsageSlots {
+    pub const NAMES: [&'static str; 6] = [
+        "account_daily",
+        "account_weekly",
+        "account_monthly",
+        "account_type_daily",
+        "account_type_weekly",
+        "account_type_monthly",
+    ];
+
+    pub fn unused(usage_omnibus_account_id: CalaAccountId) -> Self {
+        let unused = WithdrawalLimitUsageSlot::unused(usage_omnibus_account_id);
+        Self {
+            account_daily: unused.clone(),
+            account_weekly: unused.clone(),
+            account_monthly: unused.clone(),
+            account_type_daily: unused.clone(),
+            account_type_weekly: unused.clone(),
+            account_type_monthly: unused,
+        }
+    }
+
+    pub fn name(scope: &WithdrawalLimitScope, interval: WithdrawalLimitInterval) -> &'static str {
+        match (scope, interval) {
+            (WithdrawalLimitScope::Account { .. }, WithdrawalLimitInterval::Daily) => {
+                "account_daily"
+            }
+            (WithdrawalLimitScope::Account { .. }, WithdrawalLimitInterval::Weekly) => {
+                "account_weekly"
+            }
+            (WithdrawalLimitScope::Account { .. }, WithdrawalLimitInterval::Monthly) => {
+                "account_monthly"
+            }
+            (WithdrawalLimitScope::AccountType { .. }, WithdrawalLimitInterval::Daily) => {
+                "account_type_daily"
+            }
+            (WithdrawalLimitScope::AccountType { .. }, WithdrawalLimitInterval::Weekly) => {
+                "account_type_weekly"
+            }
+            (WithdrawalLimitScope::AccountType { .. }, WithdrawalLimitInterval::Monthly) => {
+                "account_type_monthly"
+            }
+        }
+    }
+
+    pub fn slot_mut(
+        &mut self,
+        scope: &WithdrawalLimitScope,
+        interval: WithdrawalLimitInterval,
+    ) -> &mut WithdrawalLimitUsageSlot {
+        match (scope, interval) {
+            (WithdrawalLimitScope::Account { .. }, WithdrawalLimitInterval::Daily) => {
+                &mut self.account_daily
+            }
+            (WithdrawalLimitScope::Account { .. }, WithdrawalLimitInterval::Weekly) => {
+                &mut self.account_weekly
+            }
+            (WithdrawalLimitScope::Account { .. }, WithdrawalLimitInterval::Monthly) => {
+                &mut self.account_monthly
+            }
+            (WithdrawalLimitScope::AccountType { .. }, WithdrawalLimitInterval::Daily) => {
+                &mut self.account_type_daily
+            }
+            (WithdrawalLimitScope::AccountType { .. }, WithdrawalLimitInterval::Weekly) => {
+                &mut self.account_type_weekly
+            }
+            (WithdrawalLimitScope::AccountType { .. }, WithdrawalLimitInterval::Monthly) => {
+                &mut self.account_type_monthly
+            }
+        }
+    }
+
+    fn into_iter(self) -> impl Iterator<Item = (&'static str, WithdrawalLimitUsageSlot)> {
+        Self::NAMES.into_iter().zip([
+            self.account_daily,
+            self.account_weekly,
+            self.account_monthly,
+            self.account_type_daily,
+            self.account_type_weekly,
+            self.account_type_monthly,
+        ])
+    }
+}
+
+impl WithdrawalLimitUsageParams {
+    pub fn defs() -> Vec<NewParamDefinition> {
+        let mut defs = vec![
+            NewParamDefinition::builder()
+                .name("journal_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("currency")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("usage_omnibus_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("deposit_account_id")
+                .r#type(ParamDataType::Uuid)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("meta")
+                .r#type(ParamDataType::Json)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("correlation_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("external_id")
+                .r#type(ParamDataType::String)
+                .build()
+                .unwrap(),
+            NewParamDefinition::builder()
+                .name("effective")
+                .r#type(ParamDataType::Date)
+                .build()
+                .unwrap(),
+        ];
+        for name in WithdrawalLimitUsageSlots::NAMES {
+            defs.push(
+                NewParamDefinition::builder()
+                    .name(format!("{name}_account_id"))
+                    .r#type(ParamDataType::Uuid)
+                    .build()
+                    .unwrap(),
+            );
+            defs.push(
+                NewParamDefinition::builder()
+                    .name(format!("{name}_amount"))
+                    .r#type(ParamDataType::Decimal)
+                    .build()
+                    .unwrap(),
+            );
+            defs.push(
+                NewParamDefinition::builder()
+                    .name(format!("{name}_headroom"))
+                    .r#type(ParamDataType::Decimal)
+                    .build()
+                    .unwrap(),
+            );
+            defs.push(
+                NewParamDefinition::builder()
+                    .name(format!("{name}_withdrawal_limit_id"))
+                    .r#type(ParamDataType::Uuid)
+                    .build()
+                    .unwrap(),
+            );
+            defs.push(
+                NewParamDefinition::builder()
+                    .name(format!("{name}_period"))
+                    .r#type(ParamDataType::String)
+                    .build()
+                    .unwrap(),
+            );
+        }
+        defs
+    }
+}
+
+impl From<WithdrawalLimitUsageParams> for Params {
+    fn from(
+        WithdrawalLimitUsageParams {
+            journal_id,
+            currency,
+            usage_omnibus_account_id,
+            deposit_account_id,
+            slots,
+            correlation_id,
+            external_id,
+        }: WithdrawalLimitUsageParams,
+    ) -> Self {
+        let mut params = Self::default();
+        params.insert("journal_id", journal_id);
+        params.insert("currency", currency);
+        params.insert("usage_omnibus_account_id", usage_omnibus_account_id);
+        params.insert("deposit_account_id", deposit_account_id);
+        params.insert(
+            "meta",
+            serde_json::to_value(&slots).expect("Couldn't serialize usage slots"),
+        );
+        for (name, slot) in slots.into_iter() {
+            params.insert(format!("{name}_account_id"), slot.usage_account_id);
+            params.insert(format!("{name}_amount"), slot.amount);
+            params.insert(format!("{name}_headroom"), slot.headroom);
+            params.insert(
+                format!("{name}_withdrawal_limit_id"),
+                slot.withdrawal_limit_id,
+            );
+            params.insert(format!("{name}_period"), slot.period);
+        }
+        params.insert("correlation_id", correlation_id);
+        params.insert("external_id", external_id);
+        params.insert("effective", crate::time::now().date_naive());
+        params
+    }
+}
+
+/// Debits the usage accounts, which carry the withdrawal limit velocity
+/// control, with the amount of a withdrawal or transfer. The usage entry is
+/// bracketed by a reservation of each slot's headroom on the encumbrance
+/// layer, so the velocity check it triggers applies the configured limit.
+pub struct RecordWithdrawalLimitUsage;
+
+impl RecordWithdrawalLimitUsage {
+    #[instrument(name = "ledger.record_withdrawal_limit_usage.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        init_usage_template(
+            ledger,
+            RECORD_WITHDRAWAL_LIMIT_USAGE_CODE,
+            "'Record withdrawal limit usage'",
+            ("DEBIT", "CREDIT"),
+            true,
+        )
+        .await
+    }
+}
+
+/// Gives back the usage recorded for a withdrawal or transfer that did not
+/// go through.
+pub struct ReleaseWithdrawalLimitUsage;
+
+impl ReleaseWithdrawalLimitUsage {
+    #[instrument(name = "ledger.release_withdrawal_limit_usage.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<(), DepositLedgerError> {
+        init_usage_template(
+            ledger,
+            RELEASE_WITHDRAWAL_LIMIT_USAGE_CODE,
+            "'Release withdrawal limit usage'",
+            ("CREDIT", "DEBIT"),
+            false,
+        )
+        .await
+    }
+}
+
+async fn init_usage_template(
+    ledger: &CalaLedger,
+    code: &str,
+    description: &str,
+    (usage_direction, omnibus_direction): (&str, &str),
+    reserve_headroom: bool,
+) -> Result<(), DepositLedgerError> {
+    let tx_input = NewTxTemplateTransaction::builder()
+        .journal_id("params.journal_id")
+        .effective("params.effective")
+        .correlation_id("params.correlation_id")
+        .external_id("params.external_id")
+        .metadata("params.meta")
+        .description(description)
+        .build()
+        .expect("Couldn't build TxInput");
+
+    let mut entries = vec![];
+    for name in WithdrawalLimitUsageSlots::NAMES {
+        let entry_type = format!("{code}_{}", name.to_uppercase());
+        let metadata = format!(
+            "{{'deposit_account_id': params.deposit_account_id, \
+             'withdrawal_limit_id': params.{name}_withdrawal_limit_id, \
+             'period': params.{name}_period}}"
+        );
+        let headroom_entry = |direction: &str| {
+            NewTxTemplateEntry::builder()
+                .entry_type(format!("'{entry_type}_HEADROOM_{}'", suffix(direction)))
+                .currency("params.currency")
+                .account_id(format!("params.{name}_account_id"))
+                .direction(direction)
+                .layer("ENCUMBRANCE")
+                .units(format!("params.{name}_headroom"))
+                .metadata(metadata.clone())
+                .build()
+                .expect("Couldn't build entry")
+        };
+        if reserve_headroom {
+            entries.push(headroom_entry("DEBIT"));
+        }
+        entries.push(
+            NewTxTemplateEntry::builder()
+                .entry_type(format!("'{entry_type}_{}'", suffix(usage_direction)))
+                .currency("params.currency")
+                .account_id(format!("params.{name}_account_id"))
+                .direction(usage_direction)
+                .layer("SETTLED")
+                .units(format!("params.{name}_amount"))
+                .metadata(metadata.clone())
+                .build()
+                .expect("Couldn't build entry"),
+        );
+        entries.push(
+            NewTxTemplateEntry::builder()
+                .entry_type(format!("'{entry_type}_{}'", suffix(omnibus_direction)))
+                .currency("params.currency")
+                .account_id("params.usage_omnibus_account_id")
+                .direction(omnibus_direction)
+                .layer("SETTLED")
+                .units(format!("params.{name}_amount"))
+                .build()
+                .expect("Couldn't build entry"),
+        );
+        if reserve_headroom {
+            entries.push(headroom_entry("CREDIT"));
+        }
+    }
+
+    let template = NewTxTemplate::builder()
+        .id(TxTemplateId::new())
+        .code(code)
+        .transaction(tx_input)
+        .entries(entries)
+        .params(WithdrawalLimitUsageParams::defs())
+        .build()
+        .expect("Couldn't build template");
+
+    match ledger.tx_templates().create(template).await {
+        Err(TxTemplateError::DuplicateCode) => Ok(()),
+        Err(e) => Err(e.into()),
+        Ok(_) => Ok(()),
+    }
+}
+
+fn suffix(direction: &str) -> &'static str {
+    match direction {
+        "DEBIT" => "DR",
+        _ => "CR",
+    }
+}
@@ -7,37 +7,73 @@ use crate::ledger::error::*;
 pub struct OverdraftPrevention;
 
 const OVERDRAFT_PREVENTION_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000001");
+const HELD_FUNDS_PROTECTION_ID: uuid::Uuid = uuid::uuid!("00000000-0000-0000-0000-000000000002");
 
 impl OverdraftPrevention {
     #[instrument(name = "ledger.overdraft_prevention.init", skip_all)]
     pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
-        let limit = NewVelocityLimit::builder()
-            .id(OVERDRAFT_PREVENTION_ID)
-            .name("Overdraft Prevention")
-            .description("Prevent overdraft on withdrawals")
-            .window(vec![])
-            .limit(
-                NewLimit::builder()
-                    .balance(vec![
-                        NewBalanceLimit::builder()
-                            .layer("SETTLED")
-                            .amount("decimal('0.0')")
-                            .enforcement_direction("DEBIT")
-                            .build()
-                            .expect("balance limit"),
-                    ])
-                    .build()
-                    .expect("limit"),
-            )
-            .build()
-            .expect("velocity limit");
-
-        match ledger.velocities().create_limit(limit).await {
-            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
-                Ok(OVERDRAFT_PREVENTION_ID.into())
-            }
-            Err(e) => Err(e.into()),
-            Ok(limit) => Ok(limit.id()),
-        }
+        create_zero_balance_limit(
+            ledger,
+            OVERDRAFT_PREVENTION_ID,
+            "Overdraft Prevention",
+            "Prevent overdraft on withdrawals",
+            "SETTLED",
+        )
+        .await
+    }
+}
+
+/// Keeps the balance of a deposit account from dropping below the funds
+/// reserved by holds. Holds debit the ENCUMBRANCE layer, so enforcing
+/// on that layer rejects both a withdrawal or transfer that would spend held
+/// funds and a hold larger than what is left to reserve.
+pub struct HeldFundsProtection;
+
+impl HeldFundsProtection {
+    #[instrument(name = "ledger.held_funds_protection.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        create_zero_balance_limit(
+            ledger,
+            HELD_FUNDS_PROTECTION_ID,
+            "Held Funds Protection",
+            "Prevent spending funds reserved by holds",
+            "ENCUMBRANCE",
+        )
+        .await
+    }
+}
+
+async fn create_zero_balance_limit(
+    ledger: &CalaLedger,
+    id: uuid::Uuid,
+    name: &str,
+    description: &str,
+    layer: &str,
+) -> Result<VelocityLimitId, DepositLedgerError> {
+    let limit = NewVelocityLimit::builder()
+        .id(id)
+        .name(name)
+        .description(description)
+        .window(vec![])
+        .limit(
+            NewLimit::builder()
+                .balance(vec![
+                    NewBalanceLimit::builder()
+                        .layer(layer)
+                        .amount("decimal('0.0')")
+                        .enforcement_direction("DEBIT")
+                        .build()
+                        .expect("balance limit"),
+                ])
+                .build()
+                .expect("limit"),
+        )
+        .build()
+        .expect("velocity limit");
+
+    match ledger.velocities().create_limit(limit).await {
+        Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => Ok(id.into()),
+        Err(e) => Err(e.into()),
+        Ok(limit) => Ok(limit.id()),
     }
 }
@@ -0,0 +1,61 @@
+use tracing::instrument;
+
+use cala_ledger::{velocity::*, *};
+
+use crate::{ledger::error::*, withdrawal_limit::MAX_WITHDRAWAL_LIMIT_AMOUNT};
+
+/// Caps the usage recorded against a withdrawal limit usage account per
+/// deposit account, withdrawal limit and calendar period. Velocity limit
+/// amounts are fixed when a control is attached, so the cap is the largest
+/// configurable limit and recording usage reserves the headroom above the
+/// configured amount. A single velocity limit thereby covers every configured
+/// amount and picks up changes without re-attaching controls.
+pub struct WithdrawalLimitUsageCap;
+
+const WITHDRAWAL_LIMIT_USAGE_CAP_ID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000003");
+
+impl WithdrawalLimitUsageCap {
+    #[instrument(name = "ledger.withdrawal_limit_usage_cap.init", skip_all)]
+    pub async fn init(ledger: &CalaLedger) -> Result<VelocityLimitId, DepositLedgerError> {
+        let window = ["deposit_account_id", "withdrawal_limit_id", "period"]
+            .into_iter()
+            .map(|key| {
+                NewPartitionKey::builder()
+                    .alias(key)
+                    .value(format!("context.vars.entry.metadata.{key}"))
+                    .build()
+                    .expect("partition key")
+            })
+            .collect();
+
+        let limit = NewVelocityLimit::builder()
+            .id(WITHDRAWAL_LIMIT_USAGE_CAP_ID)
+            .name("Withdrawal Limit Usage Cap")
+            .description("Reject usage beyond a configured withdrawal limit")
+            .window(window)
+            .limit(
+                NewLimit::builder()
+                    .balance(vec![
+                        NewBalanceLimit::builder()
+                            .layer("ENCUMBRANCE")
+                            .amount(format!("decimal('{MAX_WITHDRAWAL_LIMIT_AMOUNT}')"))
+                            .enforcement_direction("DEBIT")
+                            .build()
+                            .expect("balance limit"),
+                    ])
+                    .build()
+                    .expect("limit"),
+            )
+            .build()
+            .expect("velocity limit");
+
+        match ledger.velocities().create_limit(limit).await {
+            Err(cala_ledger::velocity::error::VelocityError::LimitIdAlreadyExists) => {
+                Ok(WITHDRAWAL_LIMIT_USAGE_CAP_ID.into())
+            }
+            Err(e) => Err(e.into()),
+            Ok(limit) => Ok(limit.id()),
+        }
+    }
+}
@@ -3,27 +3,34 @@
 
 mod account;
 mod chart_of_accounts_integration;
+mod config;
 mod deposit;
 mod deposit_account_balance;
 pub mod error;
 mod event;
 mod for_subject;
 mod history;
+mod hold;
+mod interest;
 mod ledger;
 mod primitives;
 mod processes;
+mod product;
 mod publisher;
+mod standing_order;
 mod time;
+mod transfer;
 mod withdrawal;
+mod withdrawal_limit;
 
 use deposit_account_cursor::DepositAccountsByCreatedAtCursor;
 use tracing::instrument;
 
-use audit::AuditSvc;
+use audit::{AuditSvc, SystemSubject};
 use authz::PermissionCheck;
 use cala_ledger::CalaLedger;
-use core_accounting::Chart;
-use governance::{Governance, GovernanceEvent};
+use core_accounting::{AccountingPeriodGuard, Chart};
+use governance::{ApprovalProcessType, Governance, GovernanceEvent};
 use job::Jobs;
 use outbox::{Outbox, OutboxEventMarker};
 use public_id::PublicIds;
@@ -33,6 +40,7 @@ use account::*;
 pub use chart_of_accounts_integration::{
     ChartOfAccountsIntegrationConfig, ChartOfAccountsIntegrationConfigBuilderError,
 };
+pub use config::DepositConfig;
 use deposit::*;
 pub use deposit::{Deposit, DepositsByCreatedAtCursor};
 pub use deposit_account_balance::DepositAccountBalance;
@@ -40,19 +48,48 @@ use error::*;
 pub use event::*;
 pub use for_subject::DepositsForSubject;
 pub use history::{DepositAccountHistoryCursor, DepositAccountHistoryEntry};
+use hold::*;
+pub use hold::{Hold, HoldStatus, HoldsByCreatedAtCursor};
+pub use interest::AnnualRatePct;
+use interest::{DepositInterestAccrualInit, DepositInterestAccrualJobConfig};
 use ledger::*;
 pub use primitives::*;
-pub use processes::approval::APPROVE_WITHDRAWAL_PROCESS;
+pub use processes::approval::{APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS, APPROVE_WITHDRAWAL_PROCESS};
 use processes::approval::{ApproveWithdrawal, WithdrawApprovalInit, WithdrawApprovalJobConfig};
+pub use processes::approve_transfer::APPROVE_TRANSFER_PROCESS;
+use processes::approve_transfer::{
+    ApproveTransfer, TransferApprovalInit, TransferApprovalJobConfig,
+};
+use product::*;
+pub use product::{DepositProduct, DepositProductsByNameCursor};
 use publisher::DepositPublisher;
+use standing_order::*;
+pub use standing_order::{
+    DEFAULT_MAX_RETRIES as STANDING_ORDER_DEFAULT_MAX_RETRIES, StandingOrder,
+    StandingOrderExecution, StandingOrderExecutionOutcome, StandingOrderFailureReason,
+    StandingOrderFrequency, StandingOrderRun, StandingOrderSchedule, StandingOrderStatus,
+    StandingOrdersByCreatedAtCursor,
+};
+use transfer::*;
+pub use transfer::{Transfer, TransferStatus, TransfersByCreatedAtCursor};
 use withdrawal::*;
 pub use withdrawal::{Withdrawal, WithdrawalStatus, WithdrawalsByCreatedAtCursor};
+use withdrawal_limit::*;
+pub use withdrawal_limit::{
+    WithdrawalLimit, WithdrawalLimitAmounts, WithdrawalLimitBreachAction, WithdrawalLimitInterval,
+    WithdrawalLimitScope, WithdrawalLimitsByCreatedAtCursor,
+};
 
 #[cfg(feature = "json-schema")]
 pub mod event_schema {
     pub use crate::account::DepositAccountEvent;
     pub use crate::deposit::DepositEvent;
+    pub use crate::hold::HoldEvent;
+    pub use crate::product::DepositProductEvent;
+    pub use crate::standing_order::StandingOrderEvent;
+    pub use crate::transfer::TransferEvent;
     pub use crate::withdrawal::WithdrawalEvent;
+    pub use crate::withdrawal_limit::WithdrawalLimitEvent;
 }
 
 pub struct CoreDeposit<Perms, E>
@@ -63,13 +100,21 @@ where
     accounts: DepositAccountRepo<E>,
     deposits: DepositRepo<E>,
     withdrawals: WithdrawalRepo<E>,
+    transfers: TransferRepo<E>,
+    products: DepositProductRepo,
+    holds: HoldRepo,
+    withdrawal_limits: WithdrawalLimitRepo,
+    standing_orders: StandingOrderRepo,
     approve_withdrawal: ApproveWithdrawal<Perms, E>,
+    approve_transfer: ApproveTransfer<Perms, E>,
     ledger: DepositLedger,
     cala: CalaLedger,
     authz: Perms,
     governance: Governance<Perms, E>,
     outbox: Outbox<E>,
     public_ids: PublicIds,
+    jobs: Jobs,
+    config: DepositConfig,
 }
 
 impl<Perms, E> Clone for CoreDeposit<Perms, E>
@@ -82,13 +127,21 @@ where
             accounts: self.accounts.clone(),
             deposits: self.deposits.clone(),
             withdrawals: self.withdrawals.clone(),
+            transfers: self.transfers.clone(),
+            products: self.products.clone(),
+            holds: self.holds.clone(),
+            withdrawal_limits: self.withdrawal_limits.clone(),
+            standing_orders: self.standing_orders.clone(),
             ledger: self.ledger.clone(),
             cala: self.cala.clone(),
             authz: self.authz.clone(),
             governance: self.governance.clone(),
             approve_withdrawal: self.approve_withdrawal.clone(),
+            approve_transfer: self.approve_transfer.clone(),
             outbox: self.outbox.clone(),
             public_ids: self.public_ids.clone(),
+            jobs: self.jobs.clone(),
+            config: self.config.clone(),
         }
     }
 }
@@ -105,6 +158,7 @@ where
     #[allow(clippy::too_many_arguments)]
     pub async fn init(
         pool: &sqlx::PgPool,
+        config: DepositConfig,
         authz: &Perms,
         outbox: &Outbox<E>,
         governance: &Governance<Perms, E>,
@@ -117,9 +171,16 @@ where
         let accounts = DepositAccountRepo::new(pool, &publisher);
         let deposits = DepositRepo::new(pool, &publisher);
         let withdrawals = WithdrawalRepo::new(pool, &publisher);
-        let ledger = DepositLedger::init(cala, journal_id).await?;
+        let transfers = TransferRepo::new(pool, &publisher);
+        let products = DepositProductRepo::new(pool);
+        let holds = HoldRepo::new(pool);
+        let withdrawal_limits = WithdrawalLimitRepo::new(pool);
+        let standing_orders = StandingOrderRepo::new(pool);
+        let ledger =
+            DepositLedger::init(cala, journal_id, AccountingPeriodGuard::new(pool)).await?;
 
-        let approve_withdrawal = ApproveWithdrawal::new(&withdrawals, authz.audit(), governance);
+        let approve_withdrawal =
+            ApproveWithdrawal::new(&withdrawals, &ledger, authz.audit(), governance);
 
         jobs.add_initializer_and_spawn_unique(
             WithdrawApprovalInit::new(outbox, &approve_withdrawal),
@@ -135,18 +196,64 @@ where
             _ => (),
         }
 
+        match governance
+            .init_policy(APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS)
+            .await
+        {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
+        let approve_transfer = ApproveTransfer::new(&transfers, &accounts, &ledger, authz.audit());
+
+        jobs.add_initializer_and_spawn_unique(
+            TransferApprovalInit::new(outbox, &approve_transfer),
+            TransferApprovalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
+
+        match governance.init_policy(APPROVE_TRANSFER_PROCESS).await {
+            Err(governance::error::GovernanceError::PolicyError(
+                governance::policy_error::PolicyError::DuplicateApprovalProcessType,
+            )) => (),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+
+        jobs.add_initializer(DepositInterestAccrualInit::new(
+            &accounts, &products, &ledger,
+        ));
+        jobs.add_initializer(HoldExpiryInit::<Perms>::new(&holds, &ledger, authz.audit()));
+
         let res = Self {
             accounts,
             deposits,
             withdrawals,
+            transfers,
+            products,
+            holds,
+            withdrawal_limits,
+            standing_orders,
             authz: authz.clone(),
             outbox: outbox.clone(),
             governance: governance.clone(),
             cala: cala.clone(),
             approve_withdrawal,
+            approve_transfer,
             ledger,
             public_ids: public_ids.clone(),
+            jobs: jobs.clone(),
+            config,
         };
+        jobs.add_initializer(StandingOrderInit::new(&res));
+        jobs.add_initializer_and_spawn_unique(
+            StandingOrderWithdrawalInit::new(outbox, &res),
+            StandingOrderWithdrawalJobConfig::<Perms, E>::new(),
+        )
+        .await?;
         Ok(res)
     }
 
@@ -166,6 +273,7 @@ where
             &self.accounts,
             &self.deposits,
             &self.withdrawals,
+            &self.transfers,
             &self.ledger,
             &self.authz,
         ))
@@ -178,11 +286,23 @@ where
         holder_id: impl Into<DepositAccountHolderId> + std::fmt::Debug,
         active: bool,
         deposit_account_type: impl Into<DepositAccountType>,
+        currency: CurrencyCode,
     ) -> Result<DepositAccount, CoreDepositError> {
         let holder_id = holder_id.into();
+        let deposit_account_type = deposit_account_type.into();
 
-        let name = &format!("Deposit Account {holder_id}");
-        let reference = &format!("deposit-customer-account:{holder_id}");
+        // Each holder has at most one account per currency
+        let (name, reference) = if currency.is_usd() {
+            (
+                format!("Deposit Account {holder_id}"),
+                format!("deposit-customer-account:{holder_id}"),
+            )
+        } else {
+            (
+                format!("Deposit Account {holder_id} ({currency})"),
+                format!("deposit-customer-account:{holder_id}:{currency}"),
+            )
+        };
         let audit_info = self
             .authz
             .enforce_permission(
@@ -204,11 +324,13 @@ where
         let new_account = NewDepositAccount::builder()
             .id(account_id)
             .account_holder_id(holder_id)
-            .reference(reference.to_string())
-            .name(name.to_string())
-            .description(name.to_string())
+            .reference(reference)
+            .name(name.clone())
+            .description(name)
             .active(active)
             .public_id(public_id.id)
+            .currency(currency)
+            .account_type(deposit_account_type)
             .audit_info(audit_info.clone())
             .build()
             .expect("Could not build new account");
@@ -293,6 +415,167 @@ where
         Ok(())
     }
 
+    #[instrument(name = "deposit.create_deposit_product", skip(self), err)]
+    pub async fn create_deposit_product(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        name: String,
+        annual_rate: impl Into<AnnualRatePct> + std::fmt::Debug,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_CREATE,
+            )
+            .await?;
+
+        let new_product = NewDepositProduct::builder()
+            .id(DepositProductId::new())
+            .name(name)
+            .annual_rate(annual_rate)
+            .audit_info(audit_info)
+            .build()?;
+
+        Ok(self.products.create(new_product).await?)
+    }
+
+    #[instrument(name = "deposit.update_deposit_product_annual_rate", skip(self), err)]
+    pub async fn update_deposit_product_annual_rate(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+        annual_rate: impl Into<AnnualRatePct> + std::fmt::Debug,
+    ) -> Result<DepositProduct, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_UPDATE_RATE,
+            )
+            .await?;
+
+        let mut product = self.products.find_by_id(id).await?;
+        if product
+            .update_annual_rate(annual_rate.into(), audit_info)?
+            .did_execute()
+        {
+            self.products.update(&mut product).await?;
+        }
+        Ok(product)
+    }
+
+    #[instrument(name = "deposit.find_deposit_product_by_id", skip(self), err)]
+    pub async fn find_deposit_product_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<Option<DepositProduct>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_product(id),
+                CoreDepositAction::DEPOSIT_PRODUCT_READ,
+            )
+            .await?;
+
+        match self.products.find_by_id(id).await {
+            Ok(product) => Ok(Some(product)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.list_deposit_products", skip(self), err)]
+    pub async fn list_deposit_products(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<DepositProductsByNameCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<DepositProduct, DepositProductsByNameCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_deposit_products(),
+                CoreDepositAction::DEPOSIT_PRODUCT_LIST,
+            )
+            .await?;
+        Ok(self
+            .products
+            .list_by_name(query, es_entity::ListDirection::Ascending)
+            .await?)
+    }
+
+    /// Starts daily interest accrual on the account the first time it is
+    /// assigned a product; later assignments only change the rate applied.
+    #[instrument(name = "deposit.assign_deposit_product", skip(self), err)]
+    pub async fn assign_deposit_product(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        product_id: impl Into<DepositProductId> + std::fmt::Debug,
+    ) -> Result<DepositAccount, CoreDepositError> {
+        let account_id = account_id.into();
+        let product_id = product_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::deposit_account(account_id),
+                CoreDepositAction::DEPOSIT_ACCOUNT_ASSIGN_PRODUCT,
+            )
+            .await?;
+
+        let product = self.products.find_by_id(product_id).await?;
+        let mut account = self.accounts.find_by_id(account_id).await?;
+        let starts_accrual = account.interest_payable_account_id.is_none();
+        if account
+            .assign_deposit_product(product.id, audit_info)
+            .was_ignored()
+        {
+            return Ok(account);
+        }
+
+        let mut op = self.accounts.begin_op().await?;
+        self.accounts.update_in_op(&mut op, &mut account).await?;
+        if !starts_accrual {
+            op.commit().await?;
+            return Ok(account);
+        }
+
+        let now = crate::time::now();
+        let accrual_start = interest::InterestInterval::EndOfDay.period_from(now);
+        self.jobs
+            .create_and_spawn_at_in_op(
+                &mut op,
+                uuid::Uuid::from(account.id),
+                DepositInterestAccrualJobConfig::<E> {
+                    deposit_account_id: account.id,
+                    accrual_start: now,
+                    _phantom: std::marker::PhantomData,
+                },
+                accrual_start.end,
+            )
+            .await?;
+        self.ledger
+            .create_interest_payable_account(
+                op,
+                account
+                    .interest_payable_account_id
+                    .expect("interest payable account allocated on assignment"),
+                &account.reference,
+            )
+            .await?;
+
+        Ok(account)
+    }
+
     #[instrument(name = "deposit.account_history", skip(self), err)]
     pub async fn account_history(
         &self,
@@ -326,10 +609,11 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-        amount: UsdCents,
+        amount: impl Into<Money> + std::fmt::Debug,
         reference: Option<String>,
     ) -> Result<Deposit, CoreDepositError> {
         let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
         let audit_info = self
             .authz
             .enforce_permission(
@@ -338,13 +622,15 @@ where
                 CoreDepositAction::DEPOSIT_CREATE,
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        self.check_account_active(deposit_account_id)
+            .await?
+            .ensure_currency(&amount)?;
         let deposit_id = DepositId::new();
         let new_deposit = NewDeposit::builder()
             .id(deposit_id)
             .ledger_transaction_id(deposit_id)
             .deposit_account_id(deposit_account_id)
-            .amount(amount)
+            .amount(amount.clone())
             .reference(reference)
             .audit_info(audit_info)
             .build()?;
@@ -362,10 +648,36 @@ where
         &self,
         sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
         deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
-        amount: UsdCents,
+        amount: impl Into<Money> + std::fmt::Debug,
         reference: Option<String>,
     ) -> Result<Withdrawal, CoreDepositError> {
-        let deposit_account_id = deposit_account_id.into();
+        if let Some(reference) = reference
+            .as_deref()
+            .filter(|reference| StandingOrder::is_reserved_withdrawal_reference(reference))
+        {
+            return Err(withdrawal::error::WithdrawalError::ReservedReference(
+                reference.to_string(),
+            )
+            .into());
+        }
+        self.initiate_withdrawal_for_run(
+            sub,
+            deposit_account_id.into(),
+            amount.into(),
+            reference,
+            None,
+        )
+        .await
+    }
+
+    async fn initiate_withdrawal_for_run(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: DepositAccountId,
+        amount: Money,
+        reference: Option<String>,
+        standing_order_run: Option<StandingOrderRun>,
+    ) -> Result<Withdrawal, CoreDepositError> {
         let audit_info = self
             .authz
             .enforce_permission(
@@ -374,33 +686,81 @@ where
                 CoreDepositAction::WITHDRAWAL_INITIATE,
             )
             .await?;
-        self.check_account_active(deposit_account_id).await?;
+        let account = self.check_account_active(deposit_account_id).await?;
+        account.ensure_currency(&amount)?;
+        self.ensure_available_balance(&account, &amount).await?;
         let withdrawal_id = WithdrawalId::new();
-        let new_withdrawal = NewWithdrawal::builder()
+        let mut new_withdrawal = NewWithdrawal::builder();
+        new_withdrawal
             .id(withdrawal_id)
             .deposit_account_id(deposit_account_id)
-            .amount(amount)
+            .amount(amount.clone())
             .approval_process_id(withdrawal_id)
             .reference(reference)
-            .audit_info(audit_info)
-            .build()?;
+            .standing_order_run(standing_order_run)
+            .audit_info(audit_info);
+
+        let withdrawal_limit_usage = self.withdrawal_limit_usage(&account, &amount).await?;
+        let res = self
+            .create_withdrawal(
+                withdrawal_id,
+                new_withdrawal.build()?,
+                APPROVE_WITHDRAWAL_PROCESS,
+                &withdrawal_limit_usage,
+            )
+            .await;
+        match res {
+            Err(e) if e.is_withdrawal_limit_requiring_approval_exceeded() => {
+                self.create_withdrawal(
+                    withdrawal_id,
+                    new_withdrawal.build()?,
+                    APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS,
+                    &withdrawal_limit_usage
+                        .into_iter()
+                        .filter(|usage| usage.breach_action == WithdrawalLimitBreachAction::Reject)
+                        .collect::<Vec<_>>(),
+                )
+                .await
+            }
+            res => res,
+        }
+        .map_err(CoreDepositError::with_withdrawal_limit_breach_surfaced)
+    }
 
+    async fn create_withdrawal(
+        &self,
+        withdrawal_id: WithdrawalId,
+        new_withdrawal: NewWithdrawal,
+        process_type: ApprovalProcessType,
+        withdrawal_limit_usage: &[WithdrawalLimitUsage],
+    ) -> Result<Withdrawal, CoreDepositError> {
         let mut op = self.withdrawals.begin_op().await?;
-        self.governance
+        let process = self
+            .governance
             .start_process(
                 &mut op,
                 withdrawal_id,
                 withdrawal_id.to_string(),
-                APPROVE_WITHDRAWAL_PROCESS,
+                process_type.clone(),
             )
             .await?;
+        if process_type == APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS && process.committee_id().is_none()
+        {
+            return Err(CoreDepositError::WithdrawalLimitApprovalNotConfigured);
+        }
         let withdrawal = self
             .withdrawals
             .create_in_op(&mut op, new_withdrawal)
             .await?;
 
         self.ledger
-            .initiate_withdrawal(op, withdrawal_id, amount, deposit_account_id)
+            .initiate_withdrawal(
+                op,
+                withdrawal.id,
+                withdrawal.amount.clone(),
+                withdrawal.deposit_account_id,
+                withdrawal_limit_usage,
+            )
             .await?;
         Ok(withdrawal)
     }
@@ -465,7 +825,7 @@ where
                 .update_in_op(&mut op, &mut withdrawal)
                 .await?;
             self.ledger
-                .revert_withdrawal(op, withdrawal_reversal_data)
+                .revert_withdrawal(op, withdrawal.id, withdrawal_reversal_data)
                 .await?;
         }
 
@@ -501,7 +861,7 @@ where
                 op,
                 tx_id,
                 withdrawal.id.to_string(),
-                withdrawal.amount,
+                withdrawal.amount.clone(),
                 withdrawal.deposit_account_id,
                 format!("lana:withdraw:{}:confirm", withdrawal.id),
             )
@@ -534,11 +894,146 @@ where
             .update_in_op(&mut op, &mut withdrawal)
             .await?;
         self.ledger
-            .cancel_withdrawal(op, tx_id, withdrawal.amount, withdrawal.deposit_account_id)
+            .cancel_withdrawal(
+                op,
+                withdrawal.id,
+                tx_id,
+                withdrawal.amount.clone(),
+                withdrawal.deposit_account_id,
+            )
             .await?;
         Ok(withdrawal)
     }
 
+    #[instrument(name = "deposit.initiate_transfer", skip(self), err)]
+    pub async fn initiate_transfer(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        from_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        to_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<Money> + std::fmt::Debug,
+        reference: Option<String>,
+    ) -> Result<Transfer, CoreDepositError> {
+        let from_account_id = from_account_id.into();
+        let to_account_id = to_account_id.into();
+        let amount = amount.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_INITIATE,
+            )
+            .await?;
+        let from_account = self.check_account_active(from_account_id).await?;
+        from_account.ensure_currency(&amount)?;
+        self.ensure_available_balance(&from_account, &amount)
+            .await?;
+        self.check_account_active(to_account_id)
+            .await?
+            .ensure_currency(&amount)?;
+
+        let transfer_id = TransferId::new();
+        let mut new_transfer = NewTransfer::builder();
+        new_transfer
+            .id(transfer_id)
+            .from_account_id(from_account_id)
+            .to_account_id(to_account_id)
+            .amount(amount.clone())
+            .reference(reference)
+            .audit_info(audit_info.clone());
+
+        let withdrawal_limit_usage = self.withdrawal_limit_usage(&from_account, &amount).await?;
+        let res = self
+            .create_transfer(
+                transfer_id,
+                new_transfer.clone(),
+                audit_info.clone(),
+                self.config.transfer_requires_approval(&amount),
+                false,
+                &withdrawal_limit_usage,
+            )
+            .await;
+        match res {
+            Err(e) if e.is_withdrawal_limit_requiring_approval_exceeded() => {
+                self.create_transfer(
+                    transfer_id,
+                    new_transfer,
+                    audit_info,
+                    true,
+                    true,
+                    &withdrawal_limit_usage
+                        .into_iter()
+                        .filter(|usage| usage.breach_action == WithdrawalLimitBreachAction::Reject)
+                        .collect::<Vec<_>>(),
+                )
+                .await
+            }
+            res => res,
+        }
+        .map_err(CoreDepositError::with_withdrawal_limit_breach_surfaced)
+    }
+
+    /// Transfers awaiting approval only record their withdrawal limit usage;
+    /// the usage is released again if the transfer is denied or fails.
+    async fn create_transfer(
+        &self,
+        transfer_id: TransferId,
+        mut new_transfer: NewTransferBuilder,
+        audit_info: audit::AuditInfo,
+        requires_approval: bool,
+        over_limit: bool,
+        withdrawal_limit_usage: &[WithdrawalLimitUsage],
+    ) -> Result<Transfer, CoreDepositError> {
+        let mut op = self.transfers.begin_op().await?;
+        if requires_approval {
+            let new_transfer = new_transfer.approval_process_id(transfer_id).build()?;
+            let process = self
+                .governance
+                .start_process(
+                    &mut op,
+                    transfer_id,
+                    transfer_id.to_string(),
+                    APPROVE_TRANSFER_PROCESS,
+                )
+                .await?;
+            if over_limit && process.committee_id().is_none() {
+                return Err(CoreDepositError::WithdrawalLimitApprovalNotConfigured);
+            }
+            let transfer = self.transfers.create_in_op(&mut op, new_transfer).await?;
+            self.ledger
+                .record_withdrawal_limit_usage(
+                    op,
+                    transfer.ledger_tx_id,
+                    transfer.from_account_id,
+                    &transfer.amount,
+                    withdrawal_limit_usage,
+                )
+                .await?;
+            return Ok(transfer);
+        }
+
+        let mut transfer = self
+            .transfers
+            .create_in_op(&mut op, new_transfer.build()?)
+            .await?;
+        if transfer.complete(audit_info)?.was_ignored() {
+            return Ok(transfer);
+        }
+        self.transfers.update_in_op(&mut op, &mut transfer).await?;
+        self.ledger
+            .transfer(
+                op,
+                transfer.ledger_tx_id,
+                transfer.amount.clone(),
+                transfer.from_account_id,
+                transfer.to_account_id,
+                withdrawal_limit_usage,
+            )
+            .await?;
+        Ok(transfer)
+    }
+
     #[instrument(name = "deposit.account_balance", skip(self), err)]
     pub async fn account_balance(
         &self,
@@ -555,7 +1050,8 @@ where
             )
             .await?;
 
-        let balance = self.ledger.balance(account_id).await?;
+        let account = self.accounts.find_by_id(account_id).await?;
+        let balance = self.ledger.balance(account_id, &account.currency).await?;
         Ok(balance)
     }
 
@@ -603,6 +1099,28 @@ where
         }
     }
 
+    #[instrument(name = "deposit.find_transfer_by_id", skip(self), err)]
+    pub async fn find_transfer_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<TransferId> + std::fmt::Debug,
+    ) -> Result<Option<Transfer>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::TRANSFER_READ,
+            )
+            .await?;
+
+        match self.transfers.find_by_id(id).await {
+            Ok(transfer) => Ok(Some(transfer)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     #[instrument(name = "deposit.find_withdrawal_by_cancelled_tx_id", skip(self), err)]
     pub async fn find_withdrawal_by_cancelled_tx_id(
         &self,
@@ -633,6 +1151,22 @@ where
         Ok(self.withdrawals.find_all(ids).await?)
     }
 
+    #[instrument(name = "deposit.find_all_transfers", skip(self), err)]
+    pub async fn find_all_transfers<T: From<Transfer>>(
+        &self,
+        ids: &[TransferId],
+    ) -> Result<std::collections::HashMap<TransferId, T>, CoreDepositError> {
+        Ok(self.transfers.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.find_all_deposit_products", skip(self), err)]
+    pub async fn find_all_deposit_products<T: From<DepositProduct>>(
+        &self,
+        ids: &[DepositProductId],
+    ) -> Result<std::collections::HashMap<DepositProductId, T>, CoreDepositError> {
+        Ok(self.products.find_all(ids).await?)
+    }
+
     #[instrument(name = "deposit.find_all_deposits", skip(self), err)]
     pub async fn find_all_deposits<T: From<Deposit>>(
         &self,
@@ -671,6 +1205,26 @@ where
             .await?)
     }
 
+    #[instrument(name = "deposit.list_transfers", skip(self), err)]
+    pub async fn list_transfers(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<TransfersByCreatedAtCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<Transfer, TransfersByCreatedAtCursor>, CoreDepositError>
+    {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_LIST,
+            )
+            .await?;
+        Ok(self
+            .transfers
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
     #[instrument(name = "deposit.list_deposits", skip(self), err)]
     pub async fn list_deposits(
         &self,
@@ -749,6 +1303,606 @@ where
             .entities)
     }
 
+    #[instrument(name = "deposit.list_transfers_for_account", skip(self), err)]
+    pub async fn list_transfers_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+    ) -> Result<Vec<Transfer>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_transfers(),
+                CoreDepositAction::TRANSFER_LIST,
+            )
+            .await?;
+        let mut transfers = self
+            .transfers
+            .list_for_from_account_id_by_created_at(
+                account_id,
+                Default::default(),
+                es_entity::ListDirection::Descending,
+            )
+            .await?
+            .entities;
+        transfers.extend(
+            self.transfers
+                .list_for_to_account_id_by_created_at(
+                    account_id,
+                    Default::default(),
+                    es_entity::ListDirection::Descending,
+                )
+                .await?
+                .entities,
+        );
+        transfers.sort_by_key(|t| std::cmp::Reverse(t.created_at()));
+        Ok(transfers)
+    }
+
+    #[instrument(name = "deposit.place_hold", skip(self), err)]
+    pub async fn place_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<Money> + std::fmt::Debug,
+        reason: String,
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Hold, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_PLACE,
+            )
+            .await?;
+        let account = self.accounts.find_by_id(deposit_account_id).await?;
+        account.ensure_currency(&amount)?;
+        self.ensure_available_balance(&account, &amount).await?;
+
+        let hold_id = HoldId::new();
+        let new_hold = NewHold::builder()
+            .id(hold_id)
+            .deposit_account_id(deposit_account_id)
+            .amount(amount.clone())
+            .reason(reason)
+            .expires_at(expires_at)
+            .audit_info(audit_info)
+            .build()?;
+
+        let mut op = self.holds.begin_op().await?;
+        let hold = self.holds.create_in_op(&mut op, new_hold).await?;
+        if let Some(expires_at) = expires_at {
+            self.jobs
+                .create_and_spawn_at_in_op(
+                    &mut op,
+                    uuid::Uuid::from(hold_id),
+                    HoldExpiryJobConfig::<Perms> {
+                        hold_id,
+                        _phantom: std::marker::PhantomData,
+                    },
+                    expires_at,
+                )
+                .await?;
+        }
+        self.ledger
+            .place_hold(op, hold.ledger_tx_id, amount, deposit_account_id)
+            .await?;
+        Ok(hold)
+    }
+
+    #[instrument(name = "deposit.release_hold", skip(self), err)]
+    pub async fn release_hold(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        hold_id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Hold, CoreDepositError> {
+        let id = hold_id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(id),
+                CoreDepositAction::HOLD_RELEASE,
+            )
+            .await?;
+
+        let mut hold = self.holds.find_by_id(id).await?;
+        let ledger_tx_id = match hold.release(audit_info)? {
+            es_entity::Idempotent::Executed(ledger_tx_id) => ledger_tx_id,
+            es_entity::Idempotent::Ignored => return Ok(hold),
+        };
+
+        let mut op = self.holds.begin_op().await?;
+        self.holds.update_in_op(&mut op, &mut hold).await?;
+        self.ledger
+            .release_hold(
+                op,
+                ledger_tx_id,
+                hold.amount.clone(),
+                hold.deposit_account_id,
+            )
+            .await?;
+        Ok(hold)
+    }
+
+    #[instrument(name = "deposit.find_hold_by_id", skip(self), err)]
+    pub async fn find_hold_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<HoldId> + std::fmt::Debug,
+    ) -> Result<Option<Hold>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::hold(id),
+                CoreDepositAction::HOLD_READ,
+            )
+            .await?;
+
+        match self.holds.find_by_id(id).await {
+            Ok(hold) => Ok(Some(hold)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.find_all_holds", skip(self), err)]
+    pub async fn find_all_holds<T: From<Hold>>(
+        &self,
+        ids: &[HoldId],
+    ) -> Result<std::collections::HashMap<HoldId, T>, CoreDepositError> {
+        Ok(self.holds.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.list_holds_for_account", skip(self), err)]
+    pub async fn list_holds_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<HoldsByCreatedAtCursor>,
+    ) -> Result<es_entity::PaginatedQueryRet<Hold, HoldsByCreatedAtCursor>, CoreDepositError> {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_holds(),
+                CoreDepositAction::HOLD_LIST,
+            )
+            .await?;
+        Ok(self
+            .holds
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                query,
+                es_entity::ListDirection::Descending,
+            )
+            .await?)
+    }
+
+    #[instrument(name = "deposit.create_withdrawal_limit", skip(self), err)]
+    pub async fn create_withdrawal_limit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        scope: WithdrawalLimitScope,
+        currency: CurrencyCode,
+        amounts: WithdrawalLimitAmounts,
+        breach_action: WithdrawalLimitBreachAction,
+    ) -> Result<WithdrawalLimit, CoreDepositError> {
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawal_limits(),
+                CoreDepositAction::WITHDRAWAL_LIMIT_CREATE,
+            )
+            .await?;
+        if let WithdrawalLimitScope::Account { deposit_account_id } = scope {
+            self.accounts.find_by_id(deposit_account_id).await?;
+        }
+
+        let new_limit = NewWithdrawalLimit::builder()
+            .id(WithdrawalLimitId::new())
+            .scope(scope)
+            .currency(currency)
+            .amounts(amounts)
+            .breach_action(breach_action)
+            .audit_info(audit_info)
+            .build()?;
+        Ok(self.withdrawal_limits.create(new_limit).await?)
+    }
+
+    #[instrument(name = "deposit.update_withdrawal_limit", skip(self), err)]
+    pub async fn update_withdrawal_limit(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<WithdrawalLimitId> + std::fmt::Debug,
+        amounts: WithdrawalLimitAmounts,
+        breach_action: WithdrawalLimitBreachAction,
+    ) -> Result<WithdrawalLimit, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::withdrawal_limit(id),
+                CoreDepositAction::WITHDRAWAL_LIMIT_UPDATE,
+            )
+            .await?;
+
+        let mut limit = self.withdrawal_limits.find_by_id(id).await?;
+        if limit
+            .update(amounts, breach_action, audit_info)?
+            .did_execute()
+        {
+            self.withdrawal_limits.update(&mut limit).await?;
+        }
+        Ok(limit)
+    }
+
+    #[instrument(name = "deposit.find_withdrawal_limit_by_id", skip(self), err)]
+    pub async fn find_withdrawal_limit_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<WithdrawalLimitId> + std::fmt::Debug,
+    ) -> Result<Option<WithdrawalLimit>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::withdrawal_limit(id),
+                CoreDepositAction::WITHDRAWAL_LIMIT_READ,
+            )
+            .await?;
+
+        match self.withdrawal_limits.find_by_id(id).await {
+            Ok(limit) => Ok(Some(limit)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.find_all_withdrawal_limits", skip(self), err)]
+    pub async fn find_all_withdrawal_limits<T: From<WithdrawalLimit>>(
+        &self,
+        ids: &[WithdrawalLimitId],
+    ) -> Result<std::collections::HashMap<WithdrawalLimitId, T>, CoreDepositError> {
+        Ok(self.withdrawal_limits.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.list_withdrawal_limits", skip(self), err)]
+    pub async fn list_withdrawal_limits(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        query: es_entity::PaginatedQueryArgs<WithdrawalLimitsByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<WithdrawalLimit, WithdrawalLimitsByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_withdrawal_limits(),
+                CoreDepositAction::WITHDRAWAL_LIMIT_LIST,
+            )
+            .await?;
+        Ok(self
+            .withdrawal_limits
+            .list_by_created_at(query, es_entity::ListDirection::Descending)
+            .await?)
+    }
+
+    #[instrument(name = "deposit.create_standing_order", skip(self), err)]
+    pub async fn create_standing_order(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        deposit_account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        amount: impl Into<Money> + std::fmt::Debug,
+        destination_reference: String,
+        schedule: StandingOrderSchedule,
+        max_retries: Option<u32>,
+    ) -> Result<StandingOrder, CoreDepositError> {
+        let deposit_account_id = deposit_account_id.into();
+        let amount = amount.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_standing_orders(),
+                CoreDepositAction::STANDING_ORDER_CREATE,
+            )
+            .await?;
+        if schedule.starts_on < crate::time::now().date_naive() {
+            return Err(standing_order::error::StandingOrderError::StartsInPast(
+                schedule.starts_on,
+            )
+            .into());
+        }
+        let account = self.check_account_active(deposit_account_id).await?;
+        account.ensure_currency(&amount)?;
+
+        let new_standing_order = NewStandingOrder::builder()
+            .id(StandingOrderId::new())
+            .deposit_account_id(deposit_account_id)
+            .amount(amount)
+            .destination_reference(destination_reference)
+            .schedule(schedule)
+            .max_retries(max_retries.unwrap_or(STANDING_ORDER_DEFAULT_MAX_RETRIES))
+            .audit_info(audit_info)
+            .build()?;
+
+        let mut op = self.standing_orders.begin_op().await?;
+        let standing_order = self
+            .standing_orders
+            .create_in_op(&mut op, new_standing_order)
+            .await?;
+        self.spawn_standing_order_job_in_op(&mut op, &standing_order)
+            .await?;
+        op.commit().await?;
+        Ok(standing_order)
+    }
+
+    #[instrument(name = "deposit.cancel_standing_order", skip(self), err)]
+    pub async fn cancel_standing_order(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<StandingOrderId> + std::fmt::Debug,
+    ) -> Result<StandingOrder, CoreDepositError> {
+        let id = id.into();
+        let audit_info = self
+            .authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::standing_order(id),
+                CoreDepositAction::STANDING_ORDER_CANCEL,
+            )
+            .await?;
+
+        let mut standing_order = self.standing_orders.find_by_id(id).await?;
+        if standing_order.cancel(audit_info)?.did_execute() {
+            self.standing_orders.update(&mut standing_order).await?;
+        }
+        Ok(standing_order)
+    }
+
+    #[instrument(name = "deposit.find_standing_order_by_id", skip(self), err)]
+    pub async fn find_standing_order_by_id(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        id: impl Into<StandingOrderId> + std::fmt::Debug,
+    ) -> Result<Option<StandingOrder>, CoreDepositError> {
+        let id = id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::standing_order(id),
+                CoreDepositAction::STANDING_ORDER_READ,
+            )
+            .await?;
+
+        match self.standing_orders.find_by_id(id).await {
+            Ok(standing_order) => Ok(Some(standing_order)),
+            Err(e) if e.was_not_found() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[instrument(name = "deposit.find_all_standing_orders", skip(self), err)]
+    pub async fn find_all_standing_orders<T: From<StandingOrder>>(
+        &self,
+        ids: &[StandingOrderId],
+    ) -> Result<std::collections::HashMap<StandingOrderId, T>, CoreDepositError> {
+        Ok(self.standing_orders.find_all(ids).await?)
+    }
+
+    #[instrument(name = "deposit.list_standing_orders_for_account", skip(self), err)]
+    pub async fn list_standing_orders_for_account(
+        &self,
+        sub: &<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject,
+        account_id: impl Into<DepositAccountId> + std::fmt::Debug,
+        query: es_entity::PaginatedQueryArgs<StandingOrdersByCreatedAtCursor>,
+    ) -> Result<
+        es_entity::PaginatedQueryRet<StandingOrder, StandingOrdersByCreatedAtCursor>,
+        CoreDepositError,
+    > {
+        let account_id = account_id.into();
+        self.authz
+            .enforce_permission(
+                sub,
+                CoreDepositObject::all_standing_orders(),
+                CoreDepositAction::STANDING_ORDER_LIST,
+            )
+            .await?;
+        Ok(self
+            .standing_orders
+            .list_for_deposit_account_id_by_created_at(
+                account_id,
+                query,
+                es_entity::ListDirection::Descending,
+            )
+            .await?)
+    }
+
+    /// Pays out one run of a standing order. The withdrawal goes through the
+    /// same path as `initiate_withdrawal` as the system subject, so balance
+    /// checks, withdrawal limits and approval all apply. The run stays open
+    /// until the approval of the withdrawal concludes.
+    #[instrument(name = "deposit.execute_standing_order", skip(self), err)]
+    pub(crate) async fn execute_standing_order(
+        &self,
+        id: StandingOrderId,
+        run_date: chrono::NaiveDate,
+    ) -> Result<(), CoreDepositError> {
+        let standing_order = self.standing_orders.find_by_id(id).await?;
+        if !standing_order.is_due_on(run_date) {
+            return Ok(());
+        }
+
+        let reference = standing_order.withdrawal_reference(run_date);
+        let result = match self.withdrawals.find_by_reference(&reference).await {
+            Ok(withdrawal) if standing_order.is_paid_out_by(&withdrawal, run_date) => {
+                Ok(withdrawal)
+            }
+            Ok(_) => {
+                return Err(
+                    standing_order::error::StandingOrderError::WithdrawalReferenceTaken(reference)
+                        .into(),
+                );
+            }
+            Err(e) if e.was_not_found() => {
+                self.initiate_withdrawal_for_run(
+                    &<<<Perms as PermissionCheck>::Audit as AuditSvc>::Subject as SystemSubject>::system(),
+                    standing_order.deposit_account_id,
+                    standing_order.amount.clone(),
+                    Some(reference),
+                    Some(standing_order.run(run_date)),
+                )
+                .await
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        // An auto-approved withdrawal may already have concluded the run.
+        let mut standing_order = self.standing_orders.find_by_id(id).await?;
+        if !standing_order.is_due_on(run_date) {
+            return Ok(());
+        }
+        let mut op = self.standing_orders.begin_op().await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                &mut op,
+                CoreDepositObject::standing_order(id),
+                CoreDepositAction::STANDING_ORDER_EXECUTE,
+            )
+            .await?;
+        match result {
+            Ok(withdrawal) => {
+                if standing_order
+                    .record_withdrawal_initiated(run_date, withdrawal.id, audit_info)?
+                    .was_ignored()
+                {
+                    return Ok(());
+                }
+            }
+            Err(e) => {
+                let reason = match e {
+                    CoreDepositError::InsufficientAvailableBalance => {
+                        StandingOrderFailureReason::InsufficientFunds
+                    }
+                    CoreDepositError::WithdrawalLimitExceeded(_)
+                    | CoreDepositError::WithdrawalLimitApprovalNotConfigured => {
+                        StandingOrderFailureReason::WithdrawalLimitExceeded
+                    }
+                    CoreDepositError::DepositAccountNotActive => {
+                        StandingOrderFailureReason::AccountNotActive
+                    }
+                    e => return Err(e),
+                };
+                standing_order.record_failure(
+                    run_date,
+                    reason,
+                    crate::time::now().date_naive(),
+                    audit_info,
+                )?
+            }
+        }
+        self.standing_orders
+            .update_in_op(&mut op, &mut standing_order)
+            .await?;
+        self.spawn_standing_order_job_in_op(&mut op, &standing_order)
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    /// Concludes the run of a standing order paid out by the withdrawal
+    /// once its approval process concludes. Withdrawals not initiated by a
+    /// standing order are ignored.
+    #[instrument(name = "deposit.conclude_standing_order_run", skip(self), err)]
+    pub(crate) async fn conclude_standing_order_run(
+        &self,
+        withdrawal_id: impl es_entity::RetryableInto<WithdrawalId>,
+        approved: bool,
+    ) -> Result<(), CoreDepositError> {
+        let withdrawal = self.withdrawals.find_by_id(withdrawal_id.into()).await?;
+        let Some(StandingOrderRun {
+            standing_order_id: id,
+            run_date,
+        }) = withdrawal.standing_order_run
+        else {
+            return Ok(());
+        };
+        let mut standing_order = match self.standing_orders.find_by_id(id).await {
+            Ok(standing_order) => standing_order,
+            Err(e) if e.was_not_found() => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        if !standing_order.is_due_on(run_date) {
+            return Ok(());
+        }
+
+        let mut op = self.standing_orders.begin_op().await?;
+        let audit_info = self
+            .authz
+            .audit()
+            .record_system_entry_in_tx(
+                &mut op,
+                CoreDepositObject::standing_order(id),
+                CoreDepositAction::STANDING_ORDER_EXECUTE,
+            )
+            .await?;
+        if approved {
+            standing_order.record_execution(run_date, withdrawal.id, audit_info)?;
+        } else {
+            standing_order.record_failure(
+                run_date,
+                StandingOrderFailureReason::WithdrawalDenied,
+                crate::time::now().date_naive(),
+                audit_info,
+            )?;
+        }
+        self.standing_orders
+            .update_in_op(&mut op, &mut standing_order)
+            .await?;
+        self.spawn_standing_order_job_in_op(&mut op, &standing_order)
+            .await?;
+        op.commit().await?;
+        Ok(())
+    }
+
+    async fn spawn_standing_order_job_in_op(
+        &self,
+        op: &mut es_entity::DbOp<'_>,
+        standing_order: &StandingOrder,
+    ) -> Result<(), CoreDepositError> {
+        let Some(next_attempt) = standing_order.next_attempt() else {
+            return Ok(());
+        };
+        self.jobs
+            .create_and_spawn_at_in_op(
+                op,
+                ::job::JobId::new(),
+                StandingOrderJobConfig::<Perms, E> {
+                    standing_order_id: standing_order.id,
+                    run_date: next_attempt.run_date,
+                    _phantom: std::marker::PhantomData,
+                },
+                next_attempt
+                    .attempt_on
+                    .and_hms_opt(0, 0, 0)
+                    .expect("valid time")
+                    .and_utc(),
+            )
+            .await?;
+        Ok(())
+    }
+
     #[instrument(
         name = "deposit.list_accounts_by_created_at_for_account_holder",
         skip(self),
@@ -839,6 +1993,16 @@ where
 
         let omnibus_parent_account_set_id =
             chart.account_set_id_from_code(&config.chart_of_accounts_omnibus_parent_code)?;
+        let interest_expense_parent_account_set_id = config
+            .chart_of_account_interest_expense_parent_code
+            .as_ref()
+            .map(|code| chart.account_set_id_from_code(code))
+            .transpose()?;
+        let interest_payable_parent_account_set_id = config
+            .chart_of_account_interest_payable_parent_code
+            .as_ref()
+            .map(|code| chart.account_set_id_from_code(code))
+            .transpose()?;
 
         let audit_info = self
             .authz
@@ -859,6 +2023,8 @@ where
             bank_deposit_accounts_parent_account_set_id,
             financial_institution_deposit_accounts_parent_account_set_id,
             non_domiciled_individual_deposit_accounts_parent_account_set_id,
+            interest_expense_parent_account_set_id,
+            interest_payable_parent_account_set_id,
         };
 
         self.ledger
@@ -871,11 +2037,61 @@ where
     async fn check_account_active(
         &self,
         deposit_account_id: DepositAccountId,
-    ) -> Result<(), CoreDepositError> {
+    ) -> Result<DepositAccount, CoreDepositError> {
         let account = self.accounts.find_by_id(deposit_account_id).await?;
         if account.status.is_inactive() {
             return Err(CoreDepositError::DepositAccountNotActive);
         }
+        Ok(account)
+    }
+
+    async fn ensure_available_balance(
+        &self,
+        account: &DepositAccount,
+        amount: &Money,
+    ) -> Result<(), CoreDepositError> {
+        let balance = self.ledger.balance(account.id, &account.currency).await?;
+        if balance.available().amount() < amount.amount() {
+            return Err(CoreDepositError::InsufficientAvailableBalance);
+        }
         Ok(())
     }
+
+    /// Checks `amount` against the limits configured for the account and for
+    /// its account type, counting every withdrawal initiated in the current
+    /// window that has not been denied, cancelled or reverted. Breaching a
+    /// rejecting limit errors; returns whether any other limit was breached.
+    /// The share of each withdrawal limit on the account and its account
+    /// type taken up by `amount`. Accounts opened without a type fall back to
+    /// the type of the account set they belong to.
+    async fn withdrawal_limit_usage(
+        &self,
+        account: &DepositAccount,
+        amount: &Money,
+    ) -> Result<Vec<WithdrawalLimitUsage>, CoreDepositError> {
+        let account_type = match account.account_type {
+            Some(account_type) => Some(account_type),
+            None => self.ledger.deposit_account_type(account.id).await?,
+        };
+        let mut scopes = vec![WithdrawalLimitScope::Account {
+            deposit_account_id: account.id,
+        }];
+        if let Some(account_type) = account_type {
+            scopes.push(WithdrawalLimitScope::AccountType { account_type });
+        }
+        let now = crate::time::now();
+        let mut usage = Vec::new();
+        for scope in scopes {
+            match self
+                .withdrawal_limits
+                .find_by_scope_key(scope.key(&account.currency))
+                .await
+            {
+                Ok(limit) => usage.extend(limit.usage(amount, now)),
+                Err(e) if e.was_not_found() => (),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(usage)
+    }
 }
@@ -20,6 +20,11 @@ es_entity::entity_id! {
     DepositAccountHolderId,
     DepositAccountId,
     WithdrawalId,
+    TransferId,
+    DepositProductId,
+    HoldId,
+    WithdrawalLimitId,
+    StandingOrderId,
     ChartOfAccountsIntegrationConfigId,
     DepositId;
 
@@ -28,17 +33,25 @@ es_entity::entity_id! {
     DepositAccountId => public_id::PublicIdTargetId,
     DepositId => CalaTransactionId,
     WithdrawalId => CalaTransactionId,
-    WithdrawalId => ApprovalProcessId
+    WithdrawalId => ApprovalProcessId,
+    TransferId => CalaTransactionId,
+    TransferId => ApprovalProcessId,
+    HoldId => CalaTransactionId
 }
 
 pub use core_customer::AccountStatus;
-pub use core_money::UsdCents;
+pub use core_money::{CurrencyCode, Money, UsdCents};
 
 pub type DepositAccountAllOrOne = AllOrOne<DepositAccountId>;
 pub type DepositAccountByHolderAllOrOne = AllOrOne<DepositAccountHolderId>;
 pub type DepositAllOrOne = AllOrOne<DepositId>;
 pub type ChartOfAccountsIntegrationConfigAllOrOne = AllOrOne<ChartOfAccountsIntegrationConfigId>;
 pub type WithdrawalAllOrOne = AllOrOne<WithdrawalId>;
+pub type TransferAllOrOne = AllOrOne<TransferId>;
+pub type DepositProductAllOrOne = AllOrOne<DepositProductId>;
+pub type HoldAllOrOne = AllOrOne<HoldId>;
+pub type WithdrawalLimitAllOrOne = AllOrOne<WithdrawalLimitId>;
+pub type StandingOrderAllOrOne = AllOrOne<StandingOrderId>;
 
 pub const PERMISSION_SET_DEPOSIT_VIEWER: &str = "deposit_viewer";
 pub const PERMISSION_SET_DEPOSIT_WRITER: &str = "deposit_writer";
@@ -60,6 +73,11 @@ pub enum CoreDepositObject {
     Deposit(DepositAllOrOne),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAllOrOne),
     Withdrawal(WithdrawalAllOrOne),
+    Transfer(TransferAllOrOne),
+    DepositProduct(DepositProductAllOrOne),
+    Hold(HoldAllOrOne),
+    WithdrawalLimit(WithdrawalLimitAllOrOne),
+    StandingOrder(StandingOrderAllOrOne),
 }
 
 impl CoreDepositObject {
@@ -87,6 +105,46 @@ impl CoreDepositObject {
         CoreDepositObject::Withdrawal(AllOrOne::ById(id))
     }
 
+    pub fn all_transfers() -> Self {
+        CoreDepositObject::Transfer(AllOrOne::All)
+    }
+
+    pub fn transfer(id: TransferId) -> Self {
+        CoreDepositObject::Transfer(AllOrOne::ById(id))
+    }
+
+    pub fn all_deposit_products() -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::All)
+    }
+
+    pub fn deposit_product(id: DepositProductId) -> Self {
+        CoreDepositObject::DepositProduct(AllOrOne::ById(id))
+    }
+
+    pub fn all_holds() -> Self {
+        CoreDepositObject::Hold(AllOrOne::All)
+    }
+
+    pub fn hold(id: HoldId) -> Self {
+        CoreDepositObject::Hold(AllOrOne::ById(id))
+    }
+
+    pub fn all_withdrawal_limits() -> Self {
+        CoreDepositObject::WithdrawalLimit(AllOrOne::All)
+    }
+
+    pub fn withdrawal_limit(id: WithdrawalLimitId) -> Self {
+        CoreDepositObject::WithdrawalLimit(AllOrOne::ById(id))
+    }
+
+    pub fn all_standing_orders() -> Self {
+        CoreDepositObject::StandingOrder(AllOrOne::All)
+    }
+
+    pub fn standing_order(id: StandingOrderId) -> Self {
+        CoreDepositObject::StandingOrder(AllOrOne::ById(id))
+    }
+
     pub fn chart_of_accounts_integration() -> Self {
         CoreDepositObject::ChartOfAccountsIntegrationConfig(AllOrOne::All)
     }
@@ -100,6 +158,11 @@ impl Display for CoreDepositObject {
             DepositAccount(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Deposit(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             Withdrawal(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Transfer(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            DepositProduct(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            Hold(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            WithdrawalLimit(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
+            StandingOrder(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
             ChartOfAccountsIntegrationConfig(obj_ref) => write!(f, "{discriminant}/{obj_ref}"),
         }
     }
@@ -130,6 +193,36 @@ impl FromStr for CoreDepositObject {
                     .map_err(|_| "could not parse CoreDepositObject")?;
                 CoreDepositObject::Withdrawal(obj_ref)
             }
+            Transfer => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Transfer(obj_ref)
+            }
+            DepositProduct => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::DepositProduct(obj_ref)
+            }
+            Hold => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::Hold(obj_ref)
+            }
+            WithdrawalLimit => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::WithdrawalLimit(obj_ref)
+            }
+            StandingOrder => {
+                let obj_ref = id
+                    .parse()
+                    .map_err(|_| "could not parse CoreDepositObject")?;
+                CoreDepositObject::StandingOrder(obj_ref)
+            }
             ChartOfAccountsIntegrationConfig => {
                 let obj_ref = id
                     .parse()
@@ -149,6 +242,11 @@ pub enum CoreDepositAction {
     Deposit(DepositAction),
     ChartOfAccountsIntegrationConfig(ChartOfAccountsIntegrationConfigAction),
     Withdrawal(WithdrawalAction),
+    Transfer(TransferAction),
+    DepositProduct(DepositProductAction),
+    Hold(HoldAction),
+    WithdrawalLimit(WithdrawalLimitAction),
+    StandingOrder(StandingOrderAction),
 }
 
 impl CoreDepositAction {
@@ -162,6 +260,8 @@ impl CoreDepositAction {
         CoreDepositAction::DepositAccount(DepositAccountAction::Read);
     pub const DEPOSIT_ACCOUNT_LIST: Self =
         CoreDepositAction::DepositAccount(DepositAccountAction::List);
+    pub const DEPOSIT_ACCOUNT_ASSIGN_PRODUCT: Self =
+        CoreDepositAction::DepositAccount(DepositAccountAction::AssignProduct);
 
     pub const DEPOSIT_CREATE: Self = CoreDepositAction::Deposit(DepositAction::Create);
     pub const DEPOSIT_READ: Self = CoreDepositAction::Deposit(DepositAction::Read);
@@ -186,6 +286,47 @@ impl CoreDepositAction {
     pub const WITHDRAWAL_LIST: Self = CoreDepositAction::Withdrawal(WithdrawalAction::List);
     pub const WITHDRAWAL_REVERT: Self = CoreDepositAction::Withdrawal(WithdrawalAction::Revert);
 
+    pub const TRANSFER_INITIATE: Self = CoreDepositAction::Transfer(TransferAction::Initiate);
+    pub const TRANSFER_CONCLUDE_APPROVAL_PROCESS: Self =
+        CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess);
+    pub const TRANSFER_READ: Self = CoreDepositAction::Transfer(TransferAction::Read);
+    pub const TRANSFER_LIST: Self = CoreDepositAction::Transfer(TransferAction::List);
+
+    pub const DEPOSIT_PRODUCT_CREATE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Create);
+    pub const DEPOSIT_PRODUCT_UPDATE_RATE: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::UpdateRate);
+    pub const DEPOSIT_PRODUCT_READ: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::Read);
+    pub const DEPOSIT_PRODUCT_LIST: Self =
+        CoreDepositAction::DepositProduct(DepositProductAction::List);
+
+    pub const HOLD_PLACE: Self = CoreDepositAction::Hold(HoldAction::Place);
+    pub const HOLD_RELEASE: Self = CoreDepositAction::Hold(HoldAction::Release);
+    pub const HOLD_EXPIRE: Self = CoreDepositAction::Hold(HoldAction::Expire);
+    pub const HOLD_READ: Self = CoreDepositAction::Hold(HoldAction::Read);
+    pub const HOLD_LIST: Self = CoreDepositAction::Hold(HoldAction::List);
+
+    pub const WITHDRAWAL_LIMIT_CREATE: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::Create);
+    pub const WITHDRAWAL_LIMIT_UPDATE: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::Update);
+    pub const WITHDRAWAL_LIMIT_READ: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::Read);
+    pub const WITHDRAWAL_LIMIT_LIST: Self =
+        CoreDepositAction::WithdrawalLimit(WithdrawalLimitAction::List);
+
+    pub const STANDING_ORDER_CREATE: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::Create);
+    pub const STANDING_ORDER_CANCEL: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::Cancel);
+    pub const STANDING_ORDER_EXECUTE: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::Execute);
+    pub const STANDING_ORDER_READ: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::Read);
+    pub const STANDING_ORDER_LIST: Self =
+        CoreDepositAction::StandingOrder(StandingOrderAction::List);
+
     pub fn actions() -> Vec<ActionMapping> {
         use CoreDepositActionDiscriminants::*;
         use strum::VariantArray;
@@ -203,6 +344,11 @@ impl CoreDepositAction {
                     ChartOfAccountsIntegrationConfigAction
                 ),
                 Withdrawal => map_action!(deposit, Withdrawal, WithdrawalAction),
+                Transfer => map_action!(deposit, Transfer, TransferAction),
+                DepositProduct => map_action!(deposit, DepositProduct, DepositProductAction),
+                Hold => map_action!(deposit, Hold, HoldAction),
+                WithdrawalLimit => map_action!(deposit, WithdrawalLimit, WithdrawalLimitAction),
+                StandingOrder => map_action!(deposit, StandingOrder, StandingOrderAction),
             })
             .collect()
     }
@@ -217,6 +363,11 @@ impl Display for CoreDepositAction {
             Deposit(action) => action.fmt(f),
             ChartOfAccountsIntegrationConfig(action) => action.fmt(f),
             Withdrawal(action) => action.fmt(f),
+            Transfer(action) => action.fmt(f),
+            DepositProduct(action) => action.fmt(f),
+            Hold(action) => action.fmt(f),
+            WithdrawalLimit(action) => action.fmt(f),
+            StandingOrder(action) => action.fmt(f),
         }
     }
 }
@@ -234,6 +385,11 @@ impl FromStr for CoreDepositAction {
                 CoreDepositAction::from(action.parse::<ChartOfAccountsIntegrationConfigAction>()?)
             }
             Withdrawal => CoreDepositAction::from(action.parse::<WithdrawalAction>()?),
+            Transfer => CoreDepositAction::from(action.parse::<TransferAction>()?),
+            DepositProduct => CoreDepositAction::from(action.parse::<DepositProductAction>()?),
+            Hold => CoreDepositAction::from(action.parse::<HoldAction>()?),
+            WithdrawalLimit => CoreDepositAction::from(action.parse::<WithdrawalLimitAction>()?),
+            StandingOrder => CoreDepositAction::from(action.parse::<StandingOrderAction>()?),
         };
 
         Ok(res)
@@ -249,6 +405,7 @@ pub enum DepositAccountAction {
     ReadTxHistory,
     Read,
     List,
+    AssignProduct,
 }
 
 impl ActionPermission for DepositAccountAction {
@@ -257,7 +414,9 @@ impl ActionPermission for DepositAccountAction {
             Self::Read | Self::List | Self::ReadBalance | Self::ReadTxHistory => {
                 PERMISSION_SET_DEPOSIT_VIEWER
             }
-            Self::Create | Self::UpdateStatus => PERMISSION_SET_DEPOSIT_WRITER,
+            Self::Create | Self::UpdateStatus | Self::AssignProduct => {
+                PERMISSION_SET_DEPOSIT_WRITER
+            }
         }
     }
 }
@@ -323,6 +482,128 @@ impl From<WithdrawalAction> for CoreDepositAction {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum TransferAction {
+    Initiate,
+    ConcludeApprovalProcess,
+    Read,
+    List,
+}
+
+impl ActionPermission for TransferAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Initiate | Self::ConcludeApprovalProcess => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<TransferAction> for CoreDepositAction {
+    fn from(action: TransferAction) -> Self {
+        CoreDepositAction::Transfer(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DepositProductAction {
+    Create,
+    UpdateRate,
+    Read,
+    List,
+}
+
+impl ActionPermission for DepositProductAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Create | Self::UpdateRate => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<DepositProductAction> for CoreDepositAction {
+    fn from(action: DepositProductAction) -> Self {
+        CoreDepositAction::DepositProduct(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum HoldAction {
+    Place,
+    Release,
+    Expire,
+    Read,
+    List,
+}
+
+impl ActionPermission for HoldAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Place | Self::Release | Self::Expire => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<HoldAction> for CoreDepositAction {
+    fn from(action: HoldAction) -> Self {
+        CoreDepositAction::Hold(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum WithdrawalLimitAction {
+    Create,
+    Update,
+    Read,
+    List,
+}
+
+impl ActionPermission for WithdrawalLimitAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Create | Self::Update => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<WithdrawalLimitAction> for CoreDepositAction {
+    fn from(action: WithdrawalLimitAction) -> Self {
+        CoreDepositAction::WithdrawalLimit(action)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
+#[strum(serialize_all = "kebab-case")]
+pub enum StandingOrderAction {
+    Create,
+    Cancel,
+    Execute,
+    Read,
+    List,
+}
+
+impl ActionPermission for StandingOrderAction {
+    fn permission_set(&self) -> &'static str {
+        match self {
+            Self::Read | Self::List => PERMISSION_SET_DEPOSIT_VIEWER,
+            Self::Create | Self::Cancel | Self::Execute => PERMISSION_SET_DEPOSIT_WRITER,
+        }
+    }
+}
+
+impl From<StandingOrderAction> for CoreDepositAction {
+    fn from(action: StandingOrderAction) -> Self {
+        CoreDepositAction::StandingOrder(action)
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, strum::Display, strum::EnumString, strum::VariantArray)]
 #[strum(serialize_all = "kebab-case")]
 pub enum ChartOfAccountsIntegrationConfigAction {
@@ -345,6 +626,10 @@ impl From<ChartOfAccountsIntegrationConfigAction> for CoreDepositAction {
     }
 }
 
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, strum::Display)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[strum(serialize_all = "kebab-case")]
 pub enum DepositAccountType {
     Individual,
     GovernmentEntity,
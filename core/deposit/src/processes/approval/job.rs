@@ -140,7 +140,9 @@ where
                     approved,
                     process_type,
                     ..
-                }) if process_type == &super::APPROVE_WITHDRAWAL_PROCESS => {
+                }) if process_type == &super::APPROVE_WITHDRAWAL_PROCESS
+                    || process_type == &super::APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS =>
+                {
                     self.process.execute(*id, *approved).await?;
                     state.sequence = message.sequence;
                     current_job.update_execution_state(state).await?;
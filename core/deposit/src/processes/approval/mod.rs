@@ -13,8 +13,9 @@ use outbox::OutboxEventMarker;
 use crate::{
     CoreDepositAction, CoreDepositObject, WithdrawalAction,
     event::CoreDepositEvent,
+    ledger::DepositLedger,
     primitives::WithdrawalId,
-    withdrawal::{Withdrawal, error::WithdrawalError, repo::WithdrawalRepo},
+    withdrawal::{Withdrawal, repo::WithdrawalRepo},
 };
 
 use super::error::ProcessError;
@@ -22,6 +23,10 @@ use super::error::ProcessError;
 pub use job::*;
 
 pub const APPROVE_WITHDRAWAL_PROCESS: ApprovalProcessType = ApprovalProcessType::new("withdraw");
+/// Withdrawals that breach a withdrawal limit configured to require approval
+/// go through their own policy so a dedicated committee can be assigned.
+pub const APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS: ApprovalProcessType =
+    ApprovalProcessType::new("withdraw-over-limit");
 
 pub struct ApproveWithdrawal<Perms, E>
 where
@@ -29,6 +34,7 @@ where
     E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
 {
     repo: WithdrawalRepo<E>,
+    ledger: DepositLedger,
     audit: Perms::Audit,
     governance: Governance<Perms, E>,
 }
@@ -40,6 +46,7 @@ where
     fn clone(&self) -> Self {
         Self {
             repo: self.repo.clone(),
+            ledger: self.ledger.clone(),
             audit: self.audit.clone(),
             governance: self.governance.clone(),
         }
@@ -57,11 +64,13 @@ where
 {
     pub fn new(
         repo: &WithdrawalRepo<E>,
+        ledger: &DepositLedger,
         audit: &Perms::Audit,
         governance: &Governance<Perms, E>,
     ) -> Self {
         Self {
             repo: repo.clone(),
+            ledger: ledger.clone(),
             audit: audit.clone(),
             governance: governance.clone(),
         }
@@ -95,7 +104,7 @@ where
         &self,
         id: impl es_entity::RetryableInto<WithdrawalId>,
         approved: bool,
-    ) -> Result<Withdrawal, WithdrawalError> {
+    ) -> Result<Withdrawal, ProcessError> {
         let id = id.into();
         let mut withdraw = self.repo.find_by_id(id).await?;
         if withdraw.is_approved_or_denied().is_some() {
@@ -115,7 +124,18 @@ where
             .did_execute()
         {
             self.repo.update_in_op(&mut op, &mut withdraw).await?;
-            op.commit().await?;
+            if approved {
+                op.commit().await?;
+            } else {
+                self.ledger
+                    .release_withdrawal_limit_usage(
+                        op,
+                        withdraw.id,
+                        withdraw.deposit_account_id,
+                        withdraw.amount.currency(),
+                    )
+                    .await?;
+            }
         }
         Ok(withdraw)
     }
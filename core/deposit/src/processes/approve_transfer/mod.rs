@@ -0,0 +1,165 @@
+mod job;
+
+use authz::PermissionCheck;
+use governance::{ApprovalProcessType, GovernanceAction, GovernanceEvent, GovernanceObject};
+
+use audit::AuditSvc;
+use outbox::OutboxEventMarker;
+
+use crate::{
+    CoreDepositAction, CoreDepositObject, TransferAction,
+    account::DepositAccountRepo,
+    event::CoreDepositEvent,
+    ledger::DepositLedger,
+    primitives::{DepositAccountId, TransferId},
+    transfer::{Transfer, repo::TransferRepo},
+};
+
+use super::error::ProcessError;
+
+pub use job::*;
+
+pub const APPROVE_TRANSFER_PROCESS: ApprovalProcessType = ApprovalProcessType::new("transfer");
+
+pub struct ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    transfers: TransferRepo<E>,
+    accounts: DepositAccountRepo<E>,
+    ledger: DepositLedger,
+    audit: Perms::Audit,
+}
+impl<Perms, E> Clone for ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            transfers: self.transfers.clone(),
+            accounts: self.accounts.clone(),
+            ledger: self.ledger.clone(),
+            audit: self.audit.clone(),
+        }
+    }
+}
+
+impl<Perms, E> ApproveTransfer<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<GovernanceEvent> + OutboxEventMarker<CoreDepositEvent>,
+{
+    pub fn new(
+        transfers: &TransferRepo<E>,
+        accounts: &DepositAccountRepo<E>,
+        ledger: &DepositLedger,
+        audit: &Perms::Audit,
+    ) -> Self {
+        Self {
+            transfers: transfers.clone(),
+            accounts: accounts.clone(),
+            ledger: ledger.clone(),
+            audit: audit.clone(),
+        }
+    }
+
+    #[es_entity::retry_on_concurrent_modification]
+    pub async fn execute(
+        &self,
+        id: impl es_entity::RetryableInto<TransferId>,
+        approved: bool,
+    ) -> Result<Transfer, ProcessError> {
+        let id = id.into();
+        let mut transfer = self.transfers.find_by_id(id).await?;
+        if transfer.is_approval_process_concluded() {
+            return Ok(transfer);
+        }
+        let mut op = self.transfers.begin_op().await?;
+        let audit_info = self
+            .audit
+            .record_system_entry_in_tx(
+                &mut op,
+                CoreDepositObject::transfer(id),
+                CoreDepositAction::Transfer(TransferAction::ConcludeApprovalProcess),
+            )
+            .await?;
+        if transfer
+            .approval_process_concluded(approved, audit_info.clone())
+            .was_ignored()
+        {
+            return Ok(transfer);
+        }
+
+        if approved {
+            // Balances and statuses may have changed while the transfer waited for
+            // approval, so they are checked again before anything is posted.
+            match self.reason_transfer_cannot_post(&transfer).await? {
+                Some(reason) => {
+                    if transfer.fail(reason, audit_info)?.was_ignored() {
+                        return Ok(transfer);
+                    }
+                }
+                None => {
+                    if transfer.complete(audit_info)?.was_ignored() {
+                        return Ok(transfer);
+                    }
+                    self.transfers.update_in_op(&mut op, &mut transfer).await?;
+                    self.ledger
+                        .transfer(
+                            op,
+                            transfer.ledger_tx_id,
+                            transfer.amount.clone(),
+                            transfer.from_account_id,
+                            transfer.to_account_id,
+                            &[],
+                        )
+                        .await?;
+                    return Ok(transfer);
+                }
+            }
+        }
+
+        // The withdrawal limit usage recorded when the transfer was requested
+        // is given back as the funds never leave the account.
+        self.transfers.update_in_op(&mut op, &mut transfer).await?;
+        self.ledger
+            .release_withdrawal_limit_usage(
+                op,
+                transfer.ledger_tx_id,
+                transfer.from_account_id,
+                transfer.amount.currency(),
+            )
+            .await?;
+        Ok(transfer)
+    }
+
+    async fn reason_transfer_cannot_post(
+        &self,
+        transfer: &Transfer,
+    ) -> Result<Option<String>, ProcessError> {
+        for account_id in [transfer.from_account_id, transfer.to_account_id] {
+            if self.is_account_inactive(account_id).await? {
+                return Ok(Some(format!("Deposit account {account_id} is not active")));
+            }
+        }
+        let balance = self
+            .ledger
+            .balance(transfer.from_account_id, transfer.amount.currency())
+            .await?;
+        if balance.available().amount() < transfer.amount.amount() {
+            return Ok(Some("Insufficient available balance".to_string()));
+        }
+        Ok(None)
+    }
+
+    async fn is_account_inactive(&self, id: DepositAccountId) -> Result<bool, ProcessError> {
+        let account = self.accounts.find_by_id(id).await?;
+        Ok(account.status.is_inactive())
+    }
+}
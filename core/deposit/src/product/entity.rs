@@ -0,0 +1,190 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::{interest::AnnualRatePct, primitives::DepositProductId};
+use audit::AuditInfo;
+
+use super::error::DepositProductError;
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "DepositProductId")]
+pub enum DepositProductEvent {
+    Initialized {
+        id: DepositProductId,
+        name: String,
+        annual_rate: AnnualRatePct,
+        audit_info: AuditInfo,
+    },
+    AnnualRateUpdated {
+        annual_rate: AnnualRatePct,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct DepositProduct {
+    pub id: DepositProductId,
+    pub name: String,
+    pub annual_rate: AnnualRatePct,
+    events: EntityEvents<DepositProductEvent>,
+}
+
+impl DepositProduct {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("DepositProduct has never been persisted")
+    }
+
+    pub fn update_annual_rate(
+        &mut self,
+        annual_rate: AnnualRatePct,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, DepositProductError> {
+        if annual_rate.is_negative() {
+            return Err(DepositProductError::NegativeAnnualRate);
+        }
+        if self.annual_rate == annual_rate {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events.push(DepositProductEvent::AnnualRateUpdated {
+            annual_rate,
+            audit_info,
+        });
+        self.annual_rate = annual_rate;
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<DepositProductEvent> for DepositProduct {
+    fn try_from_events(events: EntityEvents<DepositProductEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = DepositProductBuilder::default();
+
+        for event in events.iter_all() {
+            match event {
+                DepositProductEvent::Initialized {
+                    id,
+                    name,
+                    annual_rate,
+                    ..
+                } => {
+                    builder = builder.id(*id).name(name.clone()).annual_rate(*annual_rate);
+                }
+                DepositProductEvent::AnnualRateUpdated { annual_rate, .. } => {
+                    builder = builder.annual_rate(*annual_rate);
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewDepositProduct {
+    #[builder(setter(into))]
+    pub(super) id: DepositProductId,
+    #[builder(setter(into))]
+    pub(super) name: String,
+    #[builder(setter(into))]
+    pub(super) annual_rate: AnnualRatePct,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewDepositProduct {
+    pub fn builder() -> NewDepositProductBuilder {
+        NewDepositProductBuilder::default()
+    }
+}
+
+impl NewDepositProductBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.annual_rate {
+            Some(annual_rate) if annual_rate.is_negative() => {
+                Err("Annual rate cannot be negative".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<DepositProductEvent> for NewDepositProduct {
+    fn into_events(self) -> EntityEvents<DepositProductEvent> {
+        EntityEvents::init(
+            self.id,
+            [DepositProductEvent::Initialized {
+                id: self.id,
+                name: self.name,
+                annual_rate: self.annual_rate,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn new_product(annual_rate: AnnualRatePct) -> Result<NewDepositProduct, String> {
+        NewDepositProduct::builder()
+            .id(DepositProductId::new())
+            .name("Savings")
+            .annual_rate(annual_rate)
+            .audit_info(dummy_audit_info())
+            .build()
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn errors_on_negative_rate() {
+        assert!(new_product(AnnualRatePct::from(dec!(-1))).is_err());
+    }
+
+    #[test]
+    fn update_annual_rate_is_idempotent() {
+        let mut product = DepositProduct::try_from_events(
+            new_product(AnnualRatePct::from(dec!(2)))
+                .unwrap()
+                .into_events(),
+        )
+        .unwrap();
+
+        assert!(
+            product
+                .update_annual_rate(AnnualRatePct::from(dec!(2)), dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+        assert!(
+            product
+                .update_annual_rate(AnnualRatePct::from(dec!(3.5)), dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(product.annual_rate, AnnualRatePct::from(dec!(3.5)));
+        assert!(matches!(
+            product.update_annual_rate(AnnualRatePct::from(dec!(-1)), dummy_audit_info()),
+            Err(DepositProductError::NegativeAnnualRate)
+        ));
+    }
+}
@@ -0,0 +1,10 @@
+mod entity;
+pub mod error;
+mod repo;
+
+pub use entity::DepositProduct;
+#[cfg(feature = "json-schema")]
+pub use entity::DepositProductEvent;
+pub(super) use entity::*;
+pub use repo::deposit_product_cursor::DepositProductsByNameCursor;
+pub(super) use repo::*;
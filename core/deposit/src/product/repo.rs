@@ -0,0 +1,24 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::DepositProductId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "DepositProduct",
+    err = "DepositProductError",
+    columns(name(ty = "String", list_by)),
+    tbl_prefix = "core"
+)]
+pub struct DepositProductRepo {
+    pool: PgPool,
+}
+
+impl DepositProductRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
@@ -4,6 +4,7 @@ use super::event::CoreDepositEvent;
 use crate::{
     account::{DepositAccount, DepositAccountEvent, error::DepositAccountError},
     deposit::{Deposit, DepositEvent, error::DepositError},
+    transfer::{Transfer, TransferEvent, error::TransferError},
     withdrawal::{Withdrawal, WithdrawalEvent, error::WithdrawalError},
 };
 
@@ -69,7 +70,31 @@ where
                 Confirmed { .. } => Some(CoreDepositEvent::WithdrawalConfirmed {
                     id: entity.id,
                     deposit_account_id: entity.deposit_account_id,
-                    amount: entity.amount,
+                    amount: entity.amount.clone(),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.outbox
+            .publish_all_persisted(op, publish_events)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn publish_transfer(
+        &self,
+        op: &mut impl es_entity::AtomicOperation,
+        entity: &Transfer,
+        new_events: es_entity::LastPersisted<'_, TransferEvent>,
+    ) -> Result<(), TransferError> {
+        use TransferEvent::*;
+        let publish_events = new_events
+            .filter_map(|event| match &event.event {
+                Completed { .. } => Some(CoreDepositEvent::TransferCompleted {
+                    id: entity.id,
+                    from_account_id: entity.from_account_id,
+                    to_account_id: entity.to_account_id,
+                    amount: entity.amount.clone(),
                 }),
                 _ => None,
             })
@@ -92,12 +117,12 @@ where
                 Initialized { .. } => CoreDepositEvent::DepositInitialized {
                     id: entity.id,
                     deposit_account_id: entity.deposit_account_id,
-                    amount: entity.amount,
+                    amount: entity.amount.clone(),
                 },
                 Reverted { .. } => CoreDepositEvent::DepositReverted {
                     id: entity.id,
                     deposit_account_id: entity.deposit_account_id,
-                    amount: entity.amount,
+                    amount: entity.amount.clone(),
                 },
             })
             .collect::<Vec<_>>();
@@ -0,0 +1,708 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::{
+    primitives::{DepositAccountId, Money, StandingOrderId, WithdrawalId},
+    withdrawal::Withdrawal,
+};
+use audit::AuditInfo;
+
+use super::{error::StandingOrderError, primitives::*};
+
+const WITHDRAWAL_REFERENCE_PREFIX: &str = "standing-order-";
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum StandingOrderStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "StandingOrderId")]
+pub enum StandingOrderEvent {
+    Initialized {
+        id: StandingOrderId,
+        deposit_account_id: DepositAccountId,
+        amount: Money,
+        destination_reference: String,
+        schedule: StandingOrderSchedule,
+        max_retries: u32,
+        next_run_date: Option<NaiveDate>,
+        audit_info: AuditInfo,
+    },
+    WithdrawalInitiated {
+        run_date: NaiveDate,
+        attempt: u32,
+        withdrawal_id: WithdrawalId,
+        audit_info: AuditInfo,
+    },
+    Executed {
+        run_date: NaiveDate,
+        attempt: u32,
+        withdrawal_id: WithdrawalId,
+        next_run_date: Option<NaiveDate>,
+        audit_info: AuditInfo,
+    },
+    ExecutionFailed {
+        run_date: NaiveDate,
+        attempt: u32,
+        reason: StandingOrderFailureReason,
+        retry_on: NaiveDate,
+        audit_info: AuditInfo,
+    },
+    Skipped {
+        run_date: NaiveDate,
+        attempt: u32,
+        reason: StandingOrderFailureReason,
+        next_run_date: Option<NaiveDate>,
+        audit_info: AuditInfo,
+    },
+    Cancelled {
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct StandingOrder {
+    pub id: StandingOrderId,
+    pub deposit_account_id: DepositAccountId,
+    pub amount: Money,
+    pub destination_reference: String,
+    pub schedule: StandingOrderSchedule,
+    pub max_retries: u32,
+    pub next_run_date: Option<NaiveDate>,
+
+    events: EntityEvents<StandingOrderEvent>,
+}
+
+impl StandingOrder {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for standing order")
+    }
+
+    pub fn status(&self) -> StandingOrderStatus {
+        if self
+            .events
+            .iter_all()
+            .any(|e| matches!(e, StandingOrderEvent::Cancelled { .. }))
+        {
+            StandingOrderStatus::Cancelled
+        } else if self.next_run_date.is_none() {
+            StandingOrderStatus::Completed
+        } else {
+            StandingOrderStatus::Active
+        }
+    }
+
+    pub fn is_due_on(&self, run_date: NaiveDate) -> bool {
+        self.status() == StandingOrderStatus::Active && self.next_run_date == Some(run_date)
+    }
+
+    /// The reference given to the withdrawal paying out `run_date`. It is
+    /// derived from the run so a retried job finds the withdrawal it already
+    /// initiated instead of paying twice.
+    pub fn withdrawal_reference(&self, run_date: NaiveDate) -> String {
+        format!("{WITHDRAWAL_REFERENCE_PREFIX}{}-{}", self.id, run_date)
+    }
+
+    /// Whether `reference` has the form given to withdrawals paying out a
+    /// standing order. Such references are reserved for standing orders.
+    pub fn is_reserved_withdrawal_reference(reference: &str) -> bool {
+        reference.starts_with(WITHDRAWAL_REFERENCE_PREFIX)
+    }
+
+    pub fn run(&self, run_date: NaiveDate) -> StandingOrderRun {
+        StandingOrderRun {
+            standing_order_id: self.id,
+            run_date,
+        }
+    }
+
+    /// Whether `withdrawal` pays out the run on `run_date`, checked against
+    /// the run recorded on the withdrawal rather than its reference.
+    pub(crate) fn is_paid_out_by(&self, withdrawal: &Withdrawal, run_date: NaiveDate) -> bool {
+        withdrawal.standing_order_run == Some(self.run(run_date))
+            && withdrawal.deposit_account_id == self.deposit_account_id
+            && withdrawal.amount == self.amount
+    }
+
+    /// The withdrawal paying out the current run while its approval is
+    /// still pending.
+    pub fn awaiting_withdrawal(&self) -> Option<WithdrawalId> {
+        let run_date = self.next_run_date?;
+        self.events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                StandingOrderEvent::WithdrawalInitiated {
+                    run_date: initiated_run_date,
+                    withdrawal_id,
+                    ..
+                } if *initiated_run_date == run_date => Some(Some(*withdrawal_id)),
+                StandingOrderEvent::ExecutionFailed {
+                    run_date: failed_run_date,
+                    ..
+                } if *failed_run_date == run_date => Some(None),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Every attempt made so far, oldest first. An attempt awaiting approval
+    /// of its withdrawal is replaced by its outcome once the approval
+    /// concludes.
+    pub fn executions(&self) -> Vec<StandingOrderExecution> {
+        let mut executions: Vec<StandingOrderExecution> = Vec::new();
+        for execution in self.events.iter_all().filter_map(|event| match event {
+            StandingOrderEvent::WithdrawalInitiated {
+                run_date,
+                attempt,
+                withdrawal_id,
+                ..
+            } => Some(StandingOrderExecution {
+                run_date: *run_date,
+                attempt: *attempt,
+                outcome: StandingOrderExecutionOutcome::AwaitingApproval,
+                withdrawal_id: Some(*withdrawal_id),
+                failure_reason: None,
+            }),
+            StandingOrderEvent::Executed {
+                run_date,
+                attempt,
+                withdrawal_id,
+                ..
+            } => Some(StandingOrderExecution {
+                run_date: *run_date,
+                attempt: *attempt,
+                outcome: StandingOrderExecutionOutcome::Executed,
+                withdrawal_id: Some(*withdrawal_id),
+                failure_reason: None,
+            }),
+            StandingOrderEvent::ExecutionFailed {
+                run_date,
+                attempt,
+                reason,
+                ..
+            } => Some(StandingOrderExecution {
+                run_date: *run_date,
+                attempt: *attempt,
+                outcome: StandingOrderExecutionOutcome::RetryScheduled,
+                withdrawal_id: None,
+                failure_reason: Some(*reason),
+            }),
+            StandingOrderEvent::Skipped {
+                run_date,
+                attempt,
+                reason,
+                ..
+            } => Some(StandingOrderExecution {
+                run_date: *run_date,
+                attempt: *attempt,
+                outcome: StandingOrderExecutionOutcome::Skipped,
+                withdrawal_id: None,
+                failure_reason: Some(*reason),
+            }),
+            _ => None,
+        }) {
+            match executions.last_mut() {
+                Some(last)
+                    if last.outcome == StandingOrderExecutionOutcome::AwaitingApproval
+                        && last.run_date == execution.run_date
+                        && last.attempt == execution.attempt =>
+                {
+                    *last = StandingOrderExecution {
+                        withdrawal_id: execution.withdrawal_id.or(last.withdrawal_id),
+                        ..execution
+                    };
+                }
+                _ => executions.push(execution),
+            }
+        }
+        executions
+    }
+
+    /// `None` while the current run waits on the approval of its withdrawal;
+    /// the run is concluded by the approval instead.
+    pub fn next_attempt(&self) -> Option<StandingOrderNextAttempt> {
+        if self.status() != StandingOrderStatus::Active || self.awaiting_withdrawal().is_some() {
+            return None;
+        }
+        let run_date = self.next_run_date?;
+        let attempt_on = self
+            .events
+            .iter_all()
+            .rev()
+            .find_map(|event| match event {
+                StandingOrderEvent::ExecutionFailed {
+                    run_date: failed_run_date,
+                    retry_on,
+                    ..
+                } if *failed_run_date == run_date => Some(*retry_on),
+                _ => None,
+            })
+            .unwrap_or(run_date);
+        Some(StandingOrderNextAttempt {
+            run_date,
+            attempt_on,
+        })
+    }
+
+    fn failed_attempts_for(&self, run_date: NaiveDate) -> u32 {
+        self.events
+            .iter_all()
+            .filter(|event| {
+                matches!(
+                    event,
+                    StandingOrderEvent::ExecutionFailed { run_date: failed_run_date, .. }
+                        if *failed_run_date == run_date
+                )
+            })
+            .count() as u32
+    }
+
+    fn ensure_due(&self, run_date: NaiveDate) -> Result<(), StandingOrderError> {
+        if !self.is_due_on(run_date) {
+            return Err(StandingOrderError::RunNotDue(self.id, run_date));
+        }
+        Ok(())
+    }
+
+    fn run_date_after(&self, run_date: NaiveDate) -> Option<NaiveDate> {
+        self.schedule
+            .first_run_on_or_after(run_date.succ_opt().expect("date out of range"))
+    }
+
+    pub fn record_withdrawal_initiated(
+        &mut self,
+        run_date: NaiveDate,
+        withdrawal_id: WithdrawalId,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, StandingOrderError> {
+        self.ensure_due(run_date)?;
+        if self.awaiting_withdrawal() == Some(withdrawal_id) {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events.push(StandingOrderEvent::WithdrawalInitiated {
+            run_date,
+            attempt: self.failed_attempts_for(run_date) + 1,
+            withdrawal_id,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Records the run as paid out once its withdrawal has been approved.
+    pub fn record_execution(
+        &mut self,
+        run_date: NaiveDate,
+        withdrawal_id: WithdrawalId,
+        audit_info: AuditInfo,
+    ) -> Result<(), StandingOrderError> {
+        self.ensure_due(run_date)?;
+
+        let next_run_date = self.run_date_after(run_date);
+        self.events.push(StandingOrderEvent::Executed {
+            run_date,
+            attempt: self.failed_attempts_for(run_date) + 1,
+            withdrawal_id,
+            next_run_date,
+            audit_info,
+        });
+        self.next_run_date = next_run_date;
+        Ok(())
+    }
+
+    /// Insufficient funds are retried daily up to `max_retries` times, as long
+    /// as the retry still falls before the next run date. Any other failure,
+    /// or running out of retries, skips the run.
+    pub fn record_failure(
+        &mut self,
+        run_date: NaiveDate,
+        reason: StandingOrderFailureReason,
+        today: NaiveDate,
+        audit_info: AuditInfo,
+    ) -> Result<(), StandingOrderError> {
+        self.ensure_due(run_date)?;
+
+        let attempt = self.failed_attempts_for(run_date) + 1;
+        let next_run_date = self.run_date_after(run_date);
+        let retry_on = std::cmp::max(today, run_date)
+            .checked_add_days(Days::new(1))
+            .expect("date out of range");
+        let can_retry = reason.is_retryable()
+            && attempt <= self.max_retries
+            && next_run_date.is_none_or(|next| retry_on < next);
+
+        if can_retry {
+            self.events.push(StandingOrderEvent::ExecutionFailed {
+                run_date,
+                attempt,
+                reason,
+                retry_on,
+                audit_info,
+            });
+        } else {
+            self.events.push(StandingOrderEvent::Skipped {
+                run_date,
+                attempt,
+                reason,
+                next_run_date,
+                audit_info,
+            });
+            self.next_run_date = next_run_date;
+        }
+        Ok(())
+    }
+
+    pub fn cancel(&mut self, audit_info: AuditInfo) -> Result<Idempotent<()>, StandingOrderError> {
+        idempotency_guard!(self.events.iter_all(), StandingOrderEvent::Cancelled { .. });
+        if self.status() == StandingOrderStatus::Completed {
+            return Err(StandingOrderError::AlreadyCompleted(self.id));
+        }
+
+        self.events
+            .push(StandingOrderEvent::Cancelled { audit_info });
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<StandingOrderEvent> for StandingOrder {
+    fn try_from_events(events: EntityEvents<StandingOrderEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = StandingOrderBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                StandingOrderEvent::Initialized {
+                    id,
+                    deposit_account_id,
+                    amount,
+                    destination_reference,
+                    schedule,
+                    max_retries,
+                    next_run_date,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .deposit_account_id(*deposit_account_id)
+                        .amount(amount.clone())
+                        .destination_reference(destination_reference.clone())
+                        .schedule(*schedule)
+                        .max_retries(*max_retries)
+                        .next_run_date(*next_run_date)
+                }
+                StandingOrderEvent::Executed { next_run_date, .. }
+                | StandingOrderEvent::Skipped { next_run_date, .. } => {
+                    builder = builder.next_run_date(*next_run_date)
+                }
+                StandingOrderEvent::WithdrawalInitiated { .. }
+                | StandingOrderEvent::ExecutionFailed { .. }
+                | StandingOrderEvent::Cancelled { .. } => (),
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewStandingOrder {
+    #[builder(setter(into))]
+    pub(super) id: StandingOrderId,
+    #[builder(setter(into))]
+    pub(super) deposit_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: Money,
+    #[builder(setter(into))]
+    pub(super) destination_reference: String,
+    pub(super) schedule: StandingOrderSchedule,
+    #[builder(default = "DEFAULT_MAX_RETRIES")]
+    pub(super) max_retries: u32,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewStandingOrder {
+    pub fn builder() -> NewStandingOrderBuilder {
+        NewStandingOrderBuilder::default()
+    }
+}
+
+impl NewStandingOrderBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.amount.as_ref() {
+            Some(amount) if amount.is_zero() || amount.is_negative() => {
+                return Err("Standing order amount must be positive".to_string());
+            }
+            _ => (),
+        }
+        match self.schedule {
+            Some(StandingOrderSchedule {
+                starts_on,
+                ends_on: Some(ends_on),
+                ..
+            }) if ends_on < starts_on => {
+                return Err("Standing order cannot end before it starts".to_string());
+            }
+            _ => (),
+        }
+        match self.destination_reference.as_deref() {
+            Some(reference) if reference.trim().is_empty() => {
+                Err("Destination reference cannot be empty".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<StandingOrderEvent> for NewStandingOrder {
+    fn into_events(self) -> EntityEvents<StandingOrderEvent> {
+        EntityEvents::init(
+            self.id,
+            [StandingOrderEvent::Initialized {
+                id: self.id,
+                deposit_account_id: self.deposit_account_id,
+                amount: self.amount,
+                destination_reference: self.destination_reference,
+                next_run_date: self.schedule.first_run_on_or_after(self.schedule.starts_on),
+                schedule: self.schedule,
+                max_retries: self.max_retries,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use crate::{primitives::UsdCents, withdrawal::NewWithdrawal};
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn monthly_rent(max_retries: u32) -> StandingOrder {
+        let new_order = NewStandingOrder::builder()
+            .id(StandingOrderId::new())
+            .deposit_account_id(DepositAccountId::new())
+            .amount(UsdCents::from(100_000))
+            .destination_reference("landlord")
+            .schedule(StandingOrderSchedule::new(
+                StandingOrderFrequency::Monthly,
+                date("2025-01-01"),
+                Some(date("2025-02-01")),
+            ))
+            .max_retries(max_retries)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        StandingOrder::try_from_events(new_order.into_events()).unwrap()
+    }
+
+    #[test]
+    fn execution_advances_until_the_schedule_ends() {
+        let mut order = monthly_rent(3);
+        assert_eq!(order.next_run_date, Some(date("2025-01-01")));
+
+        order
+            .record_execution(date("2025-01-01"), WithdrawalId::new(), dummy_audit_info())
+            .unwrap();
+        assert_eq!(order.next_run_date, Some(date("2025-02-01")));
+        assert!(matches!(
+            order.record_execution(date("2025-01-01"), WithdrawalId::new(), dummy_audit_info()),
+            Err(StandingOrderError::RunNotDue(..))
+        ));
+
+        order
+            .record_execution(date("2025-02-01"), WithdrawalId::new(), dummy_audit_info())
+            .unwrap();
+        assert_eq!(order.status(), StandingOrderStatus::Completed);
+        assert_eq!(order.next_attempt(), None);
+        assert_eq!(order.executions().len(), 2);
+    }
+
+    #[test]
+    fn insufficient_funds_are_retried_then_skipped() {
+        let mut order = monthly_rent(1);
+        let run_date = date("2025-01-01");
+
+        order
+            .record_failure(
+                run_date,
+                StandingOrderFailureReason::InsufficientFunds,
+                run_date,
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(
+            order.next_attempt(),
+            Some(StandingOrderNextAttempt {
+                run_date,
+                attempt_on: date("2025-01-02"),
+            })
+        );
+
+        order
+            .record_failure(
+                run_date,
+                StandingOrderFailureReason::InsufficientFunds,
+                date("2025-01-02"),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(order.next_run_date, Some(date("2025-02-01")));
+        assert_eq!(
+            order
+                .executions()
+                .iter()
+                .map(|e| (e.attempt, e.outcome))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, StandingOrderExecutionOutcome::RetryScheduled),
+                (2, StandingOrderExecutionOutcome::Skipped),
+            ]
+        );
+    }
+
+    #[test]
+    fn non_retryable_failures_skip_the_run() {
+        let mut order = monthly_rent(3);
+        order
+            .record_failure(
+                date("2025-01-01"),
+                StandingOrderFailureReason::WithdrawalLimitExceeded,
+                date("2025-01-01"),
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(order.next_run_date, Some(date("2025-02-01")));
+        assert_eq!(
+            order.executions()[0].outcome,
+            StandingOrderExecutionOutcome::Skipped
+        );
+    }
+
+    #[test]
+    fn run_waits_on_the_approval_of_its_withdrawal() {
+        let mut order = monthly_rent(3);
+        let run_date = date("2025-01-01");
+        let withdrawal_id = WithdrawalId::new();
+
+        assert!(
+            order
+                .record_withdrawal_initiated(run_date, withdrawal_id, dummy_audit_info())
+                .unwrap()
+                .did_execute()
+        );
+        assert!(
+            order
+                .record_withdrawal_initiated(run_date, withdrawal_id, dummy_audit_info())
+                .unwrap()
+                .was_ignored()
+        );
+        assert_eq!(order.awaiting_withdrawal(), Some(withdrawal_id));
+        assert_eq!(order.next_attempt(), None);
+        assert_eq!(
+            order.executions()[0].outcome,
+            StandingOrderExecutionOutcome::AwaitingApproval
+        );
+
+        order
+            .record_failure(
+                run_date,
+                StandingOrderFailureReason::WithdrawalDenied,
+                run_date,
+                dummy_audit_info(),
+            )
+            .unwrap();
+        assert_eq!(order.awaiting_withdrawal(), None);
+        assert_eq!(
+            order.executions(),
+            vec![StandingOrderExecution {
+                run_date,
+                attempt: 1,
+                outcome: StandingOrderExecutionOutcome::Skipped,
+                withdrawal_id: Some(withdrawal_id),
+                failure_reason: Some(StandingOrderFailureReason::WithdrawalDenied),
+            }]
+        );
+        assert_eq!(
+            order.next_attempt(),
+            Some(StandingOrderNextAttempt {
+                run_date: date("2025-02-01"),
+                attempt_on: date("2025-02-01"),
+            })
+        );
+    }
+
+    #[test]
+    fn run_is_paid_out_only_by_a_withdrawal_recording_it() {
+        let order = monthly_rent(3);
+        let run_date = date("2025-01-01");
+        let reference = order.withdrawal_reference(run_date);
+        assert!(StandingOrder::is_reserved_withdrawal_reference(&reference));
+        assert!(!StandingOrder::is_reserved_withdrawal_reference("rent"));
+
+        let withdrawal = |standing_order_run, amount: UsdCents| {
+            let id = WithdrawalId::new();
+            let new_withdrawal = NewWithdrawal::builder()
+                .id(id)
+                .deposit_account_id(order.deposit_account_id)
+                .amount(amount)
+                .approval_process_id(id)
+                .reference(Some(reference.clone()))
+                .standing_order_run(standing_order_run)
+                .audit_info(dummy_audit_info())
+                .build()
+                .unwrap();
+            Withdrawal::try_from_events(new_withdrawal.into_events()).unwrap()
+        };
+        assert!(order.is_paid_out_by(
+            &withdrawal(Some(order.run(run_date)), UsdCents::from(100_000)),
+            run_date
+        ));
+        assert!(!order.is_paid_out_by(&withdrawal(None, UsdCents::from(100_000)), run_date));
+        assert!(!order.is_paid_out_by(
+            &withdrawal(Some(order.run(run_date)), UsdCents::from(1)),
+            run_date
+        ));
+        assert!(!order.is_paid_out_by(
+            &withdrawal(Some(order.run(run_date)), UsdCents::from(100_000)),
+            date("2025-02-01")
+        ));
+    }
+
+    #[test]
+    fn cancel_is_idempotent() {
+        let mut order = monthly_rent(3);
+        assert!(order.cancel(dummy_audit_info()).unwrap().did_execute());
+        assert!(order.cancel(dummy_audit_info()).unwrap().was_ignored());
+        assert_eq!(order.status(), StandingOrderStatus::Cancelled);
+        assert!(!order.is_due_on(date("2025-01-01")));
+        assert_eq!(order.next_attempt(), None);
+    }
+}
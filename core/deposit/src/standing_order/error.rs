@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::primitives::StandingOrderId;
+
+#[derive(Error, Debug)]
+pub enum StandingOrderError {
+    #[error("StandingOrderError - Sqlx: {0}")]
+    Sqlx(#[from] sqlx::Error),
+    #[error("StandingOrderError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("StandingOrderError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("StandingOrderError - StartsInPast: {0}")]
+    StartsInPast(NaiveDate),
+    #[error("StandingOrderError - RunNotDue: {0} on {1}")]
+    RunNotDue(StandingOrderId, NaiveDate),
+    #[error("StandingOrderError - AlreadyCompleted: {0}")]
+    AlreadyCompleted(StandingOrderId),
+    #[error("StandingOrderError - WithdrawalReferenceTaken: {0}")]
+    WithdrawalReferenceTaken(String),
+}
+
+es_entity::from_es_entity_error!(StandingOrderError);
@@ -0,0 +1,16 @@
+mod entity;
+pub mod error;
+mod job;
+mod primitives;
+mod repo;
+mod withdrawal_job;
+
+#[cfg(feature = "json-schema")]
+pub use entity::StandingOrderEvent;
+pub(super) use entity::*;
+pub use entity::{StandingOrder, StandingOrderStatus};
+pub(super) use job::*;
+pub use primitives::*;
+pub use repo::standing_order_cursor::StandingOrdersByCreatedAtCursor;
+pub(super) use repo::*;
+pub(super) use withdrawal_job::*;
@@ -0,0 +1,156 @@
+use chrono::{Days, Months, NaiveDate};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::{StandingOrderId, WithdrawalId};
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum StandingOrderFrequency {
+    Weekly,
+    Monthly,
+}
+
+/// A run of a standing order, recorded on the withdrawal paying it out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct StandingOrderRun {
+    pub standing_order_id: StandingOrderId,
+    pub run_date: NaiveDate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct StandingOrderSchedule {
+    pub frequency: StandingOrderFrequency,
+    pub starts_on: NaiveDate,
+    pub ends_on: Option<NaiveDate>,
+}
+
+impl StandingOrderSchedule {
+    pub fn new(
+        frequency: StandingOrderFrequency,
+        starts_on: NaiveDate,
+        ends_on: Option<NaiveDate>,
+    ) -> Self {
+        Self {
+            frequency,
+            starts_on,
+            ends_on,
+        }
+    }
+
+    /// Run dates are counted from `starts_on` rather than from the previous
+    /// run, so a monthly order starting on the 31st returns to the 31st after
+    /// passing through shorter months.
+    fn occurrence(&self, n: u32) -> NaiveDate {
+        match self.frequency {
+            StandingOrderFrequency::Weekly => self
+                .starts_on
+                .checked_add_days(Days::new(7 * u64::from(n)))
+                .expect("run date out of range"),
+            StandingOrderFrequency::Monthly => self
+                .starts_on
+                .checked_add_months(Months::new(n))
+                .expect("run date out of range"),
+        }
+    }
+
+    /// `None` once the schedule has run past `ends_on`.
+    pub fn first_run_on_or_after(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let run_date = (0..)
+            .map(|n| self.occurrence(n))
+            .find(|run_date| *run_date >= date)
+            .expect("schedule always has a later run date");
+        match self.ends_on {
+            Some(ends_on) if run_date > ends_on => None,
+            _ => Some(run_date),
+        }
+    }
+}
+
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum StandingOrderFailureReason {
+    InsufficientFunds,
+    WithdrawalLimitExceeded,
+    AccountNotActive,
+    WithdrawalDenied,
+}
+
+impl StandingOrderFailureReason {
+    /// Only a shortfall in funds can resolve itself before the next run date.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, StandingOrderFailureReason::InsufficientFunds)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum StandingOrderExecutionOutcome {
+    AwaitingApproval,
+    Executed,
+    RetryScheduled,
+    Skipped,
+}
+
+/// A single attempt at paying out a run of a standing order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StandingOrderExecution {
+    pub run_date: NaiveDate,
+    pub attempt: u32,
+    pub outcome: StandingOrderExecutionOutcome,
+    pub withdrawal_id: Option<WithdrawalId>,
+    pub failure_reason: Option<StandingOrderFailureReason>,
+}
+
+/// When the execution job should next run, and for which scheduled run date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StandingOrderNextAttempt {
+    pub run_date: NaiveDate,
+    pub attempt_on: NaiveDate,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn monthly_keeps_day_of_month_without_drifting() {
+        let schedule =
+            StandingOrderSchedule::new(StandingOrderFrequency::Monthly, date("2025-01-31"), None);
+        assert_eq!(
+            schedule.first_run_on_or_after(date("2025-02-01")),
+            Some(date("2025-02-28"))
+        );
+        assert_eq!(
+            schedule.first_run_on_or_after(date("2025-03-01")),
+            Some(date("2025-03-31"))
+        );
+    }
+
+    #[test]
+    fn stops_after_ends_on() {
+        let schedule = StandingOrderSchedule::new(
+            StandingOrderFrequency::Weekly,
+            date("2025-01-06"),
+            Some(date("2025-01-20")),
+        );
+        assert_eq!(
+            schedule.first_run_on_or_after(date("2025-01-14")),
+            Some(date("2025-01-20"))
+        );
+        assert_eq!(schedule.first_run_on_or_after(date("2025-01-21")), None);
+    }
+}
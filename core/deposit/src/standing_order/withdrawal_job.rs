@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use futures::StreamExt;
+use tracing::instrument;
+
+use audit::AuditSvc;
+use authz::PermissionCheck;
+use governance::{GovernanceAction, GovernanceEvent, GovernanceObject};
+use job::*;
+use outbox::{Outbox, OutboxEventMarker};
+
+use crate::{
+    APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS, APPROVE_WITHDRAWAL_PROCESS, CoreDeposit,
+    CoreDepositAction, CoreDepositEvent, CoreDepositObject,
+};
+
+#[derive(serde::Serialize)]
+pub struct StandingOrderWithdrawalJobConfig<Perms, E> {
+    _phantom: std::marker::PhantomData<(Perms, E)>,
+}
+impl<Perms, E> StandingOrderWithdrawalJobConfig<Perms, E> {
+    pub fn new() -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+impl<Perms, E> JobConfig for StandingOrderWithdrawalJobConfig<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    type Initializer = StandingOrderWithdrawalInit<Perms, E>;
+}
+
+pub struct StandingOrderWithdrawalInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    deposits: CoreDeposit<Perms, E>,
+}
+
+impl<Perms, E> StandingOrderWithdrawalInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    pub fn new(outbox: &Outbox<E>, deposits: &CoreDeposit<Perms, E>) -> Self {
+        Self {
+            outbox: outbox.clone(),
+            deposits: deposits.clone(),
+        }
+    }
+}
+
+const DEPOSIT_STANDING_ORDER_WITHDRAWAL_JOB: JobType =
+    JobType::new("deposit-standing-order-withdrawal");
+impl<Perms, E> JobInitializer for StandingOrderWithdrawalInit<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    fn job_type() -> JobType
+    where
+        Self: Sized,
+    {
+        DEPOSIT_STANDING_ORDER_WITHDRAWAL_JOB
+    }
+
+    fn init(&self, _: &Job) -> Result<Box<dyn JobRunner>, Box<dyn std::error::Error>> {
+        Ok(Box::new(StandingOrderWithdrawalJobRunner {
+            outbox: self.outbox.clone(),
+            deposits: self.deposits.clone(),
+        }))
+    }
+
+    fn retry_on_error_settings() -> RetrySettings
+    where
+        Self: Sized,
+    {
+        RetrySettings::repeat_indefinitely()
+    }
+}
+
+#[derive(Default, Clone, Copy, serde::Deserialize, serde::Serialize)]
+struct StandingOrderWithdrawalJobData {
+    sequence: outbox::EventSequence,
+}
+
+pub struct StandingOrderWithdrawalJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    outbox: Outbox<E>,
+    deposits: CoreDeposit<Perms, E>,
+}
+
+#[async_trait]
+impl<Perms, E> JobRunner for StandingOrderWithdrawalJobRunner<Perms, E>
+where
+    Perms: PermissionCheck,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Action:
+        From<CoreDepositAction> + From<GovernanceAction>,
+    <<Perms as PermissionCheck>::Audit as AuditSvc>::Object:
+        From<CoreDepositObject> + From<GovernanceObject>,
+    E: OutboxEventMarker<CoreDepositEvent> + OutboxEventMarker<GovernanceEvent>,
+{
+    #[allow(clippy::single_match)]
+    #[instrument(name = "deposit.job.standing-order-withdrawal", skip_all)]
+    async fn run(
+        &self,
+        mut current_job: CurrentJob,
+    ) -> Result<JobCompletion, Box<dyn std::error::Error>> {
+        let mut state = current_job
+            .execution_state::<StandingOrderWithdrawalJobData>()?
+            .unwrap_or_default();
+        let mut stream = self.outbox.listen_persisted(Some(state.sequence)).await?;
+
+        while let Some(message) = stream.next().await {
+            match message.as_ref().as_event() {
+                Some(GovernanceEvent::ApprovalProcessConcluded {
+                    id,
+                    approved,
+                    process_type,
+                    ..
+                }) if process_type == &APPROVE_WITHDRAWAL_PROCESS
+                    || process_type == &APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS =>
+                {
+                    self.deposits
+                        .conclude_standing_order_run(*id, *approved)
+                        .await?;
+                    state.sequence = message.sequence;
+                    current_job.update_execution_state(state).await?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(JobCompletion::RescheduleNow)
+    }
+}
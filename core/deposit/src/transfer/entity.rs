@@ -0,0 +1,391 @@
+use derive_builder::Builder;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    ApprovalProcessId, CalaTransactionId, DepositAccountId, Money, TransferId,
+};
+use audit::AuditInfo;
+
+use super::error::TransferError;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum TransferStatus {
+    PendingApproval,
+    Completed,
+    Denied,
+    Failed,
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "TransferId")]
+pub enum TransferEvent {
+    Initialized {
+        id: TransferId,
+        ledger_tx_id: CalaTransactionId,
+        from_account_id: DepositAccountId,
+        to_account_id: DepositAccountId,
+        amount: Money,
+        reference: String,
+        approval_process_id: Option<ApprovalProcessId>,
+        status: TransferStatus,
+        audit_info: AuditInfo,
+    },
+    ApprovalProcessConcluded {
+        approval_process_id: ApprovalProcessId,
+        approved: bool,
+        status: TransferStatus,
+        audit_info: AuditInfo,
+    },
+    Completed {
+        ledger_tx_id: CalaTransactionId,
+        status: TransferStatus,
+        audit_info: AuditInfo,
+    },
+    Failed {
+        reason: String,
+        status: TransferStatus,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct Transfer {
+    pub id: TransferId,
+    pub ledger_tx_id: CalaTransactionId,
+    pub from_account_id: DepositAccountId,
+    pub to_account_id: DepositAccountId,
+    pub reference: String,
+    pub amount: Money,
+    pub approval_process_id: Option<ApprovalProcessId>,
+
+    events: EntityEvents<TransferEvent>,
+}
+
+impl Transfer {
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for transfer")
+    }
+
+    pub fn status(&self) -> TransferStatus {
+        self.events
+            .iter_all()
+            .rev()
+            .map(|e| match e {
+                TransferEvent::Completed { status, .. } => *status,
+                TransferEvent::Failed { status, .. } => *status,
+                TransferEvent::ApprovalProcessConcluded { status, .. } => *status,
+                TransferEvent::Initialized { status, .. } => *status,
+            })
+            .next()
+            .expect("status should always exist")
+    }
+
+    pub fn failure_reason(&self) -> Option<&str> {
+        self.events.iter_all().find_map(|e| match e {
+            TransferEvent::Failed { reason, .. } => Some(reason.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn is_approval_process_concluded(&self) -> bool {
+        self.events
+            .iter_all()
+            .any(|e| matches!(e, TransferEvent::ApprovalProcessConcluded { .. }))
+    }
+
+    fn is_approved(&self) -> bool {
+        self.events.iter_all().any(|e| {
+            matches!(
+                e,
+                TransferEvent::ApprovalProcessConcluded { approved: true, .. }
+            )
+        })
+    }
+
+    pub fn approval_process_concluded(
+        &mut self,
+        approved: bool,
+        audit_info: AuditInfo,
+    ) -> Idempotent<()> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            TransferEvent::ApprovalProcessConcluded { .. }
+        );
+        let approval_process_id = self
+            .approval_process_id
+            .expect("transfer without approval process concluded one");
+        let status = if approved {
+            TransferStatus::PendingApproval
+        } else {
+            TransferStatus::Denied
+        };
+        self.events.push(TransferEvent::ApprovalProcessConcluded {
+            approval_process_id,
+            approved,
+            status,
+            audit_info,
+        });
+        Idempotent::Executed(())
+    }
+
+    /// Marks the transfer as posted. Transfers with an approval process can
+    /// only complete once it has concluded approved.
+    pub fn complete(&mut self, audit_info: AuditInfo) -> Result<Idempotent<()>, TransferError> {
+        idempotency_guard!(
+            self.events.iter_all(),
+            TransferEvent::Completed { .. } | TransferEvent::Failed { .. }
+        );
+        if self.approval_process_id.is_some() && !self.is_approved() {
+            return Err(TransferError::NotApproved(self.id));
+        }
+
+        self.events.push(TransferEvent::Completed {
+            ledger_tx_id: self.ledger_tx_id,
+            status: TransferStatus::Completed,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+
+    /// Records that an approved transfer could no longer be posted, e.g.
+    /// because the balance was spent while it waited for approval.
+    pub fn fail(
+        &mut self,
+        reason: String,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, TransferError> {
+        idempotency_guard!(self.events.iter_all(), TransferEvent::Failed { .. });
+        if self.status() == TransferStatus::Completed {
+            return Err(TransferError::AlreadyCompleted(self.id));
+        }
+
+        self.events.push(TransferEvent::Failed {
+            reason,
+            status: TransferStatus::Failed,
+            audit_info,
+        });
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<TransferEvent> for Transfer {
+    fn try_from_events(events: EntityEvents<TransferEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = TransferBuilder::default();
+        for event in events.iter_all() {
+            if let TransferEvent::Initialized {
+                id,
+                ledger_tx_id,
+                from_account_id,
+                to_account_id,
+                amount,
+                reference,
+                approval_process_id,
+                ..
+            } = event
+            {
+                builder = builder
+                    .id(*id)
+                    .ledger_tx_id(*ledger_tx_id)
+                    .from_account_id(*from_account_id)
+                    .to_account_id(*to_account_id)
+                    .amount(amount.clone())
+                    .reference(reference.clone())
+                    .approval_process_id(*approval_process_id)
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewTransfer {
+    #[builder(setter(into))]
+    pub(super) id: TransferId,
+    #[builder(setter(into))]
+    pub(super) from_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) to_account_id: DepositAccountId,
+    #[builder(setter(into))]
+    pub(super) amount: Money,
+    #[builder(setter(strip_option, into), default)]
+    pub(super) approval_process_id: Option<ApprovalProcessId>,
+    reference: Option<String>,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewTransfer {
+    pub fn builder() -> NewTransferBuilder {
+        NewTransferBuilder::default()
+    }
+
+    pub(super) fn reference(&self) -> String {
+        match self.reference.as_deref() {
+            None => self.id.to_string(),
+            Some("") => self.id.to_string(),
+            Some(reference) => reference.to_string(),
+        }
+    }
+}
+
+impl NewTransferBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.from_account_id.is_some() && self.from_account_id == self.to_account_id {
+            return Err("Transfer must be between two different accounts".to_string());
+        }
+        match self.amount.as_ref() {
+            Some(amount) if amount.is_zero() => Err("Transfer amount cannot be zero".to_string()),
+            Some(amount) if amount.is_negative() => {
+                Err("Transfer amount cannot be negative".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<TransferEvent> for NewTransfer {
+    fn into_events(self) -> EntityEvents<TransferEvent> {
+        EntityEvents::init(
+            self.id,
+            [TransferEvent::Initialized {
+                reference: self.reference(),
+                id: self.id,
+                ledger_tx_id: self.id.into(),
+                from_account_id: self.from_account_id,
+                to_account_id: self.to_account_id,
+                amount: self.amount,
+                approval_process_id: self.approval_process_id,
+                status: TransferStatus::PendingApproval,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+
+    use crate::primitives::UsdCents;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn new_transfer(approval_process_id: Option<ApprovalProcessId>) -> Transfer {
+        let mut builder = NewTransfer::builder();
+        builder
+            .id(TransferId::new())
+            .from_account_id(DepositAccountId::new())
+            .to_account_id(DepositAccountId::new())
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .audit_info(dummy_audit_info());
+        if let Some(id) = approval_process_id {
+            builder.approval_process_id(id);
+        }
+        Transfer::try_from_events(builder.build().unwrap().into_events()).unwrap()
+    }
+
+    #[test]
+    fn errors_when_transferring_to_the_same_account() {
+        let account_id = DepositAccountId::new();
+        let transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(account_id)
+            .to_account_id(account_id)
+            .amount(UsdCents::ONE)
+            .reference(None)
+            .audit_info(dummy_audit_info())
+            .build();
+
+        assert!(matches!(
+            transfer,
+            Err(NewTransferBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn errors_when_zero_amount_is_passed() {
+        let transfer = NewTransfer::builder()
+            .id(TransferId::new())
+            .from_account_id(DepositAccountId::new())
+            .to_account_id(DepositAccountId::new())
+            .amount(UsdCents::ZERO)
+            .reference(None)
+            .audit_info(dummy_audit_info())
+            .build();
+
+        assert!(matches!(
+            transfer,
+            Err(NewTransferBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn completes_transfer_without_approval_process() {
+        let mut transfer = new_transfer(None);
+
+        assert!(transfer.complete(dummy_audit_info()).unwrap().did_execute());
+        assert_eq!(transfer.status(), TransferStatus::Completed);
+        assert!(transfer.complete(dummy_audit_info()).unwrap().was_ignored());
+    }
+
+    #[test]
+    fn cannot_complete_before_approval() {
+        let mut transfer = new_transfer(Some(ApprovalProcessId::new()));
+
+        assert!(matches!(
+            transfer.complete(dummy_audit_info()),
+            Err(TransferError::NotApproved(_))
+        ));
+
+        let _ = transfer.approval_process_concluded(true, dummy_audit_info());
+        assert!(transfer.complete(dummy_audit_info()).unwrap().did_execute());
+        assert_eq!(transfer.status(), TransferStatus::Completed);
+    }
+
+    #[test]
+    fn denied_transfer_cannot_complete() {
+        let mut transfer = new_transfer(Some(ApprovalProcessId::new()));
+
+        let _ = transfer.approval_process_concluded(false, dummy_audit_info());
+
+        assert_eq!(transfer.status(), TransferStatus::Denied);
+        assert!(matches!(
+            transfer.complete(dummy_audit_info()),
+            Err(TransferError::NotApproved(_))
+        ));
+    }
+
+    #[test]
+    fn failed_transfer_is_ignored_on_complete() {
+        let mut transfer = new_transfer(Some(ApprovalProcessId::new()));
+        let _ = transfer.approval_process_concluded(true, dummy_audit_info());
+
+        let _ = transfer
+            .fail("insufficient balance".to_string(), dummy_audit_info())
+            .unwrap();
+
+        assert_eq!(transfer.status(), TransferStatus::Failed);
+        assert_eq!(transfer.failure_reason(), Some("insufficient balance"));
+        assert!(transfer.complete(dummy_audit_info()).unwrap().was_ignored());
+    }
+}
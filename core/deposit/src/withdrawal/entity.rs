@@ -5,8 +5,9 @@ use serde::{Deserialize, Serialize};
 
 use es_entity::*;
 
-use crate::primitives::{
-    ApprovalProcessId, CalaTransactionId, DepositAccountId, UsdCents, WithdrawalId,
+use crate::{
+    primitives::{ApprovalProcessId, CalaTransactionId, DepositAccountId, Money, WithdrawalId},
+    standing_order::StandingOrderRun,
 };
 use audit::AuditInfo;
 
@@ -33,8 +34,10 @@ pub enum WithdrawalEvent {
         id: WithdrawalId,
         ledger_tx_id: CalaTransactionId,
         deposit_account_id: DepositAccountId,
-        amount: UsdCents,
+        amount: Money,
         reference: String,
+        #[serde(default)]
+        standing_order_run: Option<StandingOrderRun>,
         approval_process_id: ApprovalProcessId,
         status: WithdrawalStatus,
         audit_info: AuditInfo,
@@ -68,8 +71,10 @@ pub struct Withdrawal {
     pub id: WithdrawalId,
     pub deposit_account_id: DepositAccountId,
     pub reference: String,
-    pub amount: UsdCents,
+    pub amount: Money,
     pub approval_process_id: ApprovalProcessId,
+    #[builder(default)]
+    pub standing_order_run: Option<StandingOrderRun>,
     #[builder(setter(strip_option), default)]
     pub cancelled_tx_id: Option<CalaTransactionId>,
 
@@ -80,7 +85,7 @@ pub struct Withdrawal {
 pub struct WithdrawalReversalData {
     pub ledger_tx_id: CalaTransactionId,
     pub credit_account_id: DepositAccountId,
-    pub amount: UsdCents,
+    pub amount: Money,
     pub correlation_id: String,
     pub external_id: String,
 }
@@ -145,7 +150,7 @@ impl Withdrawal {
 
         Ok(Idempotent::Executed(WithdrawalReversalData {
             ledger_tx_id,
-            amount: self.amount,
+            amount: self.amount.clone(),
             credit_account_id: self.deposit_account_id,
             correlation_id: self.id.to_string(),
             external_id: format!("lana:withdraw:{}:reverted", self.id),
@@ -242,6 +247,7 @@ impl TryFromEvents<WithdrawalEvent> for Withdrawal {
                 WithdrawalEvent::Initialized {
                     id,
                     reference,
+                    standing_order_run,
                     deposit_account_id,
                     amount,
                     approval_process_id,
@@ -250,8 +256,9 @@ impl TryFromEvents<WithdrawalEvent> for Withdrawal {
                     builder = builder
                         .id(*id)
                         .deposit_account_id(*deposit_account_id)
-                        .amount(*amount)
+                        .amount(amount.clone())
                         .reference(reference.clone())
+                        .standing_order_run(*standing_order_run)
                         .approval_process_id(*approval_process_id)
                 }
                 WithdrawalEvent::Cancelled { ledger_tx_id, .. } => {
@@ -272,10 +279,12 @@ pub struct NewWithdrawal {
     #[builder(setter(into))]
     pub(super) deposit_account_id: DepositAccountId,
     #[builder(setter(into))]
-    pub(super) amount: UsdCents,
+    pub(super) amount: Money,
     #[builder(setter(into))]
     pub(super) approval_process_id: ApprovalProcessId,
     reference: Option<String>,
+    #[builder(default)]
+    standing_order_run: Option<StandingOrderRun>,
     #[builder(setter(into))]
     pub audit_info: AuditInfo,
 }
@@ -296,8 +305,11 @@ impl NewWithdrawal {
 
 impl NewWithdrawalBuilder {
     fn validate(&self) -> Result<(), String> {
-        match self.amount {
+        match self.amount.as_ref() {
             Some(amount) if amount.is_zero() => Err("Withdrawal amount cannot be zero".to_string()),
+            Some(amount) if amount.is_negative() => {
+                Err("Withdrawal amount cannot be negative".to_string())
+            }
             _ => Ok(()),
         }
     }
@@ -309,6 +321,7 @@ impl IntoEvents<WithdrawalEvent> for NewWithdrawal {
             self.id,
             [WithdrawalEvent::Initialized {
                 reference: self.reference(),
+                standing_order_run: self.standing_order_run,
                 id: self.id,
                 ledger_tx_id: self.id.into(),
                 deposit_account_id: self.deposit_account_id,
@@ -325,6 +338,8 @@ impl IntoEvents<WithdrawalEvent> for NewWithdrawal {
 mod test {
     use audit::AuditEntryId;
 
+    use crate::primitives::UsdCents;
+
     use super::*;
 
     fn dummy_audit_info() -> AuditInfo {
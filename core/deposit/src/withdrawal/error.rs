@@ -20,6 +20,8 @@ pub enum WithdrawalError {
     AuditError(#[from] audit::error::AuditError),
     #[error("WithdrawalError - NotConfirmed: {0}")]
     NotConfirmed(WithdrawalId),
+    #[error("WithdrawalError - ReservedReference: {0}")]
+    ReservedReference(String),
 }
 
 es_entity::from_es_entity_error!(WithdrawalError);
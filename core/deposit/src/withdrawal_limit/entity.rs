@@ -0,0 +1,495 @@
+use chrono::{DateTime, Datelike, Utc};
+use derive_builder::Builder;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use es_entity::*;
+
+use crate::primitives::{
+    CurrencyCode, DepositAccountId, DepositAccountType, Money, WithdrawalLimitId,
+};
+use audit::AuditInfo;
+
+use super::error::WithdrawalLimitError;
+
+/// Largest amount a limit can be configured with. The ledger enforces usage
+/// against it and reserves the headroom above the configured amount while
+/// recording usage.
+pub const MAX_WITHDRAWAL_LIMIT_AMOUNT: Decimal = dec!(1_000_000_000_000);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WithdrawalLimitScope {
+    AccountType {
+        account_type: DepositAccountType,
+    },
+    Account {
+        deposit_account_id: DepositAccountId,
+    },
+}
+
+impl WithdrawalLimitScope {
+    /// There is at most one limit per scope and currency.
+    pub(crate) fn key(&self, currency: &CurrencyCode) -> String {
+        match self {
+            WithdrawalLimitScope::AccountType { account_type } => {
+                format!("account-type:{account_type}:{currency}")
+            }
+            WithdrawalLimitScope::Account { deposit_account_id } => {
+                format!("account:{deposit_account_id}:{currency}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum WithdrawalLimitBreachAction {
+    Reject,
+    RequireApproval,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum WithdrawalLimitInterval {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl WithdrawalLimitInterval {
+    /// Identifies the calendar window `now` falls in. Windows are aligned
+    /// in UTC and weeks are ISO weeks, starting on Monday.
+    pub fn period(&self, now: DateTime<Utc>) -> String {
+        let date = now.date_naive();
+        match self {
+            WithdrawalLimitInterval::Daily => date.format("%Y-%m-%d").to_string(),
+            WithdrawalLimitInterval::Weekly => {
+                let week = date.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            WithdrawalLimitInterval::Monthly => date.format("%Y-%m").to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct WithdrawalLimitAmounts {
+    pub daily: Option<Money>,
+    pub weekly: Option<Money>,
+    pub monthly: Option<Money>,
+}
+
+impl WithdrawalLimitAmounts {
+    pub fn iter(&self) -> impl Iterator<Item = (WithdrawalLimitInterval, &Money)> {
+        [
+            (WithdrawalLimitInterval::Daily, self.daily.as_ref()),
+            (WithdrawalLimitInterval::Weekly, self.weekly.as_ref()),
+            (WithdrawalLimitInterval::Monthly, self.monthly.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(interval, limit)| limit.map(|limit| (interval, limit)))
+    }
+
+    fn validate(&self, currency: &CurrencyCode) -> Result<(), WithdrawalLimitError> {
+        for (interval, limit) in self.iter() {
+            if limit.currency() != currency {
+                return Err(WithdrawalLimitError::CurrencyMismatch {
+                    expected: currency.clone(),
+                    actual: limit.currency().clone(),
+                });
+            }
+            if limit.is_zero() || limit.is_negative() {
+                return Err(WithdrawalLimitError::NonPositiveLimit(interval));
+            }
+            if limit.amount() > MAX_WITHDRAWAL_LIMIT_AMOUNT {
+                return Err(WithdrawalLimitError::LimitTooLarge(interval));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(EsEvent, Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[es_event(id = "WithdrawalLimitId")]
+pub enum WithdrawalLimitEvent {
+    Initialized {
+        id: WithdrawalLimitId,
+        scope: WithdrawalLimitScope,
+        currency: CurrencyCode,
+        amounts: WithdrawalLimitAmounts,
+        breach_action: WithdrawalLimitBreachAction,
+        audit_info: AuditInfo,
+    },
+    Updated {
+        amounts: WithdrawalLimitAmounts,
+        breach_action: WithdrawalLimitBreachAction,
+        audit_info: AuditInfo,
+    },
+}
+
+#[derive(EsEntity, Builder)]
+#[builder(pattern = "owned", build_fn(error = "EsEntityError"))]
+pub struct WithdrawalLimit {
+    pub id: WithdrawalLimitId,
+    pub scope: WithdrawalLimitScope,
+    pub currency: CurrencyCode,
+    pub amounts: WithdrawalLimitAmounts,
+    pub breach_action: WithdrawalLimitBreachAction,
+
+    events: EntityEvents<WithdrawalLimitEvent>,
+}
+
+/// Amount of a withdrawal or an outgoing transfer counted against one
+/// interval of a withdrawal limit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithdrawalLimitUsage {
+    pub withdrawal_limit_id: WithdrawalLimitId,
+    pub scope: WithdrawalLimitScope,
+    pub breach_action: WithdrawalLimitBreachAction,
+    pub interval: WithdrawalLimitInterval,
+    pub period: String,
+    pub amount: Decimal,
+    pub limit: Decimal,
+}
+
+impl WithdrawalLimit {
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.events
+            .entity_first_persisted_at()
+            .expect("No events for withdrawal limit")
+    }
+
+    /// Usage is recorded in the currency of the limit and checked against the
+    /// amount configured when the funds leave the account, so changing a limit
+    /// applies to the usage already recorded in the current window.
+    pub(crate) fn usage(
+        &self,
+        amount: &Money,
+        now: DateTime<Utc>,
+    ) -> impl Iterator<Item = WithdrawalLimitUsage> + '_ {
+        let amount = amount.amount();
+        self.amounts
+            .iter()
+            .map(move |(interval, max)| WithdrawalLimitUsage {
+                withdrawal_limit_id: self.id,
+                scope: self.scope,
+                breach_action: self.breach_action,
+                interval,
+                period: interval.period(now),
+                amount,
+                limit: max.amount(),
+            })
+    }
+
+    pub fn update(
+        &mut self,
+        amounts: WithdrawalLimitAmounts,
+        breach_action: WithdrawalLimitBreachAction,
+        audit_info: AuditInfo,
+    ) -> Result<Idempotent<()>, WithdrawalLimitError> {
+        amounts.validate(&self.currency)?;
+        if self.amounts == amounts && self.breach_action == breach_action {
+            return Ok(Idempotent::Ignored);
+        }
+
+        self.events.push(WithdrawalLimitEvent::Updated {
+            amounts: amounts.clone(),
+            breach_action,
+            audit_info,
+        });
+        self.amounts = amounts;
+        self.breach_action = breach_action;
+        Ok(Idempotent::Executed(()))
+    }
+}
+
+impl TryFromEvents<WithdrawalLimitEvent> for WithdrawalLimit {
+    fn try_from_events(events: EntityEvents<WithdrawalLimitEvent>) -> Result<Self, EsEntityError> {
+        let mut builder = WithdrawalLimitBuilder::default();
+        for event in events.iter_all() {
+            match event {
+                WithdrawalLimitEvent::Initialized {
+                    id,
+                    scope,
+                    currency,
+                    amounts,
+                    breach_action,
+                    ..
+                } => {
+                    builder = builder
+                        .id(*id)
+                        .scope(*scope)
+                        .currency(currency.clone())
+                        .amounts(amounts.clone())
+                        .breach_action(*breach_action)
+                }
+                WithdrawalLimitEvent::Updated {
+                    amounts,
+                    breach_action,
+                    ..
+                } => {
+                    builder = builder
+                        .amounts(amounts.clone())
+                        .breach_action(*breach_action)
+                }
+            }
+        }
+        builder.events(events).build()
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct NewWithdrawalLimit {
+    #[builder(setter(into))]
+    pub(super) id: WithdrawalLimitId,
+    pub(super) scope: WithdrawalLimitScope,
+    pub(super) currency: CurrencyCode,
+    pub(super) amounts: WithdrawalLimitAmounts,
+    pub(super) breach_action: WithdrawalLimitBreachAction,
+    #[builder(setter(into))]
+    pub audit_info: AuditInfo,
+}
+
+impl NewWithdrawalLimit {
+    pub fn builder() -> NewWithdrawalLimitBuilder {
+        NewWithdrawalLimitBuilder::default()
+    }
+
+    pub(super) fn scope_key(&self) -> String {
+        self.scope.key(&self.currency)
+    }
+}
+
+impl NewWithdrawalLimitBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match (self.amounts.as_ref(), self.currency.as_ref()) {
+            (Some(amounts), _) if amounts.iter().next().is_none() => {
+                Err("At least one limit must be set".to_string())
+            }
+            (Some(amounts), Some(currency)) => {
+                amounts.validate(currency).map_err(|e| e.to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl IntoEvents<WithdrawalLimitEvent> for NewWithdrawalLimit {
+    fn into_events(self) -> EntityEvents<WithdrawalLimitEvent> {
+        EntityEvents::init(
+            self.id,
+            [WithdrawalLimitEvent::Initialized {
+                id: self.id,
+                scope: self.scope,
+                currency: self.currency,
+                amounts: self.amounts,
+                breach_action: self.breach_action,
+                audit_info: self.audit_info,
+            }],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use audit::AuditEntryId;
+    use chrono::TimeZone;
+
+    use crate::primitives::UsdCents;
+
+    use super::*;
+
+    fn dummy_audit_info() -> AuditInfo {
+        AuditInfo {
+            audit_entry_id: AuditEntryId::from(1),
+            sub: "sub".to_string(),
+        }
+    }
+
+    fn daily(cents: u64) -> WithdrawalLimitAmounts {
+        WithdrawalLimitAmounts {
+            daily: Some(UsdCents::from(cents).into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn period_is_calendar_aligned() {
+        // Thursday
+        let now = Utc.with_ymd_and_hms(2025, 5, 15, 13, 30, 0).unwrap();
+        assert_eq!(WithdrawalLimitInterval::Daily.period(now), "2025-05-15");
+        assert_eq!(WithdrawalLimitInterval::Weekly.period(now), "2025-W20");
+        assert_eq!(WithdrawalLimitInterval::Monthly.period(now), "2025-05");
+
+        // Monday of the same ISO week
+        let monday = Utc.with_ymd_and_hms(2025, 5, 12, 0, 0, 0).unwrap();
+        assert_eq!(WithdrawalLimitInterval::Weekly.period(monday), "2025-W20");
+        // Sunday before belongs to the previous week
+        let sunday = Utc.with_ymd_and_hms(2025, 5, 11, 23, 59, 59).unwrap();
+        assert_eq!(WithdrawalLimitInterval::Weekly.period(sunday), "2025-W19");
+    }
+
+    #[test]
+    fn usage_is_counted_in_currency_against_each_limit() {
+        let new_limit = NewWithdrawalLimit::builder()
+            .id(WithdrawalLimitId::new())
+            .scope(WithdrawalLimitScope::AccountType {
+                account_type: DepositAccountType::Individual,
+            })
+            .currency(CurrencyCode::USD)
+            .amounts(WithdrawalLimitAmounts {
+                daily: Some(UsdCents::from(30000).into()),
+                monthly: Some(UsdCents::from(90000).into()),
+                ..Default::default()
+            })
+            .breach_action(WithdrawalLimitBreachAction::Reject)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        let limit = WithdrawalLimit::try_from_events(new_limit.into_events()).unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 5, 15, 13, 30, 0).unwrap();
+
+        let usage: Vec<_> = limit
+            .usage(&UsdCents::from(10000).into(), now)
+            .map(|usage| (usage.interval, usage.period, usage.amount, usage.limit))
+            .collect();
+        assert_eq!(
+            usage,
+            vec![
+                (
+                    WithdrawalLimitInterval::Daily,
+                    "2025-05-15".to_string(),
+                    dec!(100),
+                    dec!(300)
+                ),
+                (
+                    WithdrawalLimitInterval::Monthly,
+                    "2025-05".to_string(),
+                    dec!(100),
+                    dec!(900)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn raised_limit_applies_to_later_usage_in_the_same_period() {
+        let new_limit = NewWithdrawalLimit::builder()
+            .id(WithdrawalLimitId::new())
+            .scope(WithdrawalLimitScope::AccountType {
+                account_type: DepositAccountType::Individual,
+            })
+            .currency(CurrencyCode::USD)
+            .amounts(daily(30000))
+            .breach_action(WithdrawalLimitBreachAction::Reject)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        let mut limit = WithdrawalLimit::try_from_events(new_limit.into_events()).unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 5, 15, 13, 30, 0).unwrap();
+
+        let reached = limit
+            .usage(&UsdCents::from(30000).into(), now)
+            .next()
+            .unwrap();
+        assert_eq!(reached.amount, reached.limit);
+
+        assert!(
+            limit
+                .update(
+                    daily(50000),
+                    WithdrawalLimitBreachAction::Reject,
+                    dummy_audit_info()
+                )
+                .unwrap()
+                .did_execute()
+        );
+        let later = limit
+            .usage(&UsdCents::from(20000).into(), now)
+            .next()
+            .unwrap();
+        assert_eq!(later.period, reached.period);
+        assert_eq!(reached.amount + later.amount, later.limit);
+    }
+
+    #[test]
+    fn update_is_idempotent_and_validated() {
+        let new_limit = NewWithdrawalLimit::builder()
+            .id(WithdrawalLimitId::new())
+            .scope(WithdrawalLimitScope::AccountType {
+                account_type: DepositAccountType::Individual,
+            })
+            .currency(CurrencyCode::USD)
+            .amounts(daily(1000))
+            .breach_action(WithdrawalLimitBreachAction::Reject)
+            .audit_info(dummy_audit_info())
+            .build()
+            .unwrap();
+        assert_eq!(new_limit.scope_key(), "account-type:individual:USD");
+        let mut limit = WithdrawalLimit::try_from_events(new_limit.into_events()).unwrap();
+
+        assert!(
+            limit
+                .update(
+                    daily(1000),
+                    WithdrawalLimitBreachAction::Reject,
+                    dummy_audit_info()
+                )
+                .unwrap()
+                .was_ignored()
+        );
+        assert!(
+            limit
+                .update(
+                    daily(2000),
+                    WithdrawalLimitBreachAction::RequireApproval,
+                    dummy_audit_info()
+                )
+                .unwrap()
+                .did_execute()
+        );
+        assert_eq!(
+            limit.breach_action,
+            WithdrawalLimitBreachAction::RequireApproval
+        );
+        assert!(matches!(
+            limit.update(
+                daily(0),
+                WithdrawalLimitBreachAction::Reject,
+                dummy_audit_info()
+            ),
+            Err(WithdrawalLimitError::NonPositiveLimit(
+                WithdrawalLimitInterval::Daily
+            ))
+        ));
+        assert!(matches!(
+            limit.update(
+                WithdrawalLimitAmounts {
+                    weekly: Some(
+                        Money::try_new(
+                            MAX_WITHDRAWAL_LIMIT_AMOUNT + Decimal::ONE,
+                            CurrencyCode::USD
+                        )
+                        .unwrap()
+                    ),
+                    ..Default::default()
+                },
+                WithdrawalLimitBreachAction::Reject,
+                dummy_audit_info()
+            ),
+            Err(WithdrawalLimitError::LimitTooLarge(
+                WithdrawalLimitInterval::Weekly
+            ))
+        ));
+    }
+}
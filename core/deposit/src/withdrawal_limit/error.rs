@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+use crate::primitives::CurrencyCode;
+
+use super::WithdrawalLimitInterval;
+
+#[derive(Error, Debug)]
+pub enum WithdrawalLimitError {
+    #[error("WithdrawalLimitError - Sqlx: {0}")]
+    Sqlx(sqlx::Error),
+    #[error("WithdrawalLimitError - EsEntityError: {0}")]
+    EsEntityError(es_entity::EsEntityError),
+    #[error("WithdrawalLimitError - CursorDestructureError: {0}")]
+    CursorDestructureError(#[from] es_entity::CursorDestructureError),
+    #[error("WithdrawalLimitError - DuplicateScope")]
+    DuplicateScope,
+    #[error("WithdrawalLimitError - NonPositiveLimit: {0}")]
+    NonPositiveLimit(WithdrawalLimitInterval),
+    #[error("WithdrawalLimitError - LimitTooLarge: {0}")]
+    LimitTooLarge(WithdrawalLimitInterval),
+    #[error("WithdrawalLimitError - CurrencyMismatch: expected {expected}, got {actual}")]
+    CurrencyMismatch {
+        expected: CurrencyCode,
+        actual: CurrencyCode,
+    },
+}
+
+es_entity::from_es_entity_error!(WithdrawalLimitError);
+
+impl From<sqlx::Error> for WithdrawalLimitError {
+    fn from(error: sqlx::Error) -> Self {
+        if let Some(err) = error.as_database_error()
+            && let Some(constraint) = err.constraint()
+            && constraint.contains("scope_key")
+        {
+            return Self::DuplicateScope;
+        }
+        Self::Sqlx(error)
+    }
+}
@@ -0,0 +1,13 @@
+mod entity;
+pub mod error;
+mod repo;
+
+#[cfg(feature = "json-schema")]
+pub use entity::WithdrawalLimitEvent;
+pub(super) use entity::*;
+pub use entity::{
+    WithdrawalLimit, WithdrawalLimitAmounts, WithdrawalLimitBreachAction, WithdrawalLimitInterval,
+    WithdrawalLimitScope, WithdrawalLimitUsage,
+};
+pub use repo::withdrawal_limit_cursor::WithdrawalLimitsByCreatedAtCursor;
+pub(super) use repo::*;
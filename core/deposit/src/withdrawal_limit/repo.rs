@@ -0,0 +1,28 @@
+use sqlx::PgPool;
+
+use es_entity::*;
+
+use crate::primitives::WithdrawalLimitId;
+
+use super::{entity::*, error::*};
+
+#[derive(EsRepo, Clone)]
+#[es_repo(
+    entity = "WithdrawalLimit",
+    err = "WithdrawalLimitError",
+    columns(scope_key(
+        ty = "String",
+        create(accessor = "scope_key()"),
+        update(persist = false)
+    )),
+    tbl_prefix = "core"
+)]
+pub struct WithdrawalLimitRepo {
+    pool: PgPool,
+}
+
+impl WithdrawalLimitRepo {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
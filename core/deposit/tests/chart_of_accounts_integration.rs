@@ -30,6 +30,7 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
 
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -42,7 +43,17 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
 
     let storage = Storage::new(&StorageConfig::default());
     let document_storage = DocumentStorage::new(&pool, &storage);
-    let accounting = CoreAccounting::new(&pool, &authz, &cala, journal_id, document_storage, &jobs);
+    let accounting = CoreAccounting::init(
+        &pool,
+        &authz,
+        &outbox,
+        &governance,
+        &cala,
+        journal_id,
+        document_storage,
+        &jobs,
+    )
+    .await?;
     let chart_ref = format!("ref-{:08}", rand::rng().random_range(0..10000));
     let chart = accounting
         .chart_of_accounts()
@@ -56,6 +67,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         4,Bank Deposit Accounts
         5,Financial Institution Deposit Accounts
         6,Non Domiciled Individual Deposit Accounts
+        8,Interest Expense
+        9,Interest Payable
         "#
     .to_string();
     let chart_id = chart.id;
@@ -90,6 +103,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_non_domiciled_individual_deposit_accounts_parent_code(
                     "6".parse().unwrap(),
                 )
+                .chart_of_account_interest_expense_parent_code(Some("8".parse().unwrap()))
+                .chart_of_account_interest_payable_parent_code(Some("9".parse().unwrap()))
                 .build()
                 .unwrap(),
         )
@@ -116,6 +131,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
         4,Other Bank Deposit Accounts
         5,Other Financial Institution Deposit Accounts
         6,Other Non Domiciled Individual Deposit Accounts
+        8,Other Interest Expense
+        9,Other Interest Payable
         "#
     .to_string();
     let chart_id = chart.id;
@@ -143,6 +160,8 @@ async fn chart_of_accounts_integration() -> anyhow::Result<()> {
                 .chart_of_account_non_domiciled_individual_deposit_accounts_parent_code(
                     "6".parse().unwrap(),
                 )
+                .chart_of_account_interest_expense_parent_code(Some("8".parse().unwrap()))
+                .chart_of_account_interest_payable_parent_code(Some("9".parse().unwrap()))
                 .build()
                 .unwrap(),
         )
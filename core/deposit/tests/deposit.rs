@@ -27,6 +27,7 @@ async fn deposit() -> anyhow::Result<()> {
 
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -44,6 +45,7 @@ async fn deposit() -> anyhow::Result<()> {
             account_holder_id,
             true,
             DepositAccountType::Individual,
+            CurrencyCode::USD,
         )
         .await?;
 
@@ -60,7 +62,7 @@ async fn deposit() -> anyhow::Result<()> {
     let balance = deposit.account_balance(&DummySubject, account.id).await?;
     assert_eq!(
         balance.settled,
-        UsdCents::try_from_usd(dec!(1000000)).unwrap()
+        Money::from(UsdCents::try_from_usd(dec!(1000000)).unwrap())
     );
 
     Ok(())
@@ -86,6 +88,7 @@ async fn revert_deposit() -> anyhow::Result<()> {
 
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -103,6 +106,7 @@ async fn revert_deposit() -> anyhow::Result<()> {
             account_holder_id,
             true,
             DepositAccountType::Individual,
+            CurrencyCode::USD,
         )
         .await?;
 
@@ -119,14 +123,93 @@ async fn revert_deposit() -> anyhow::Result<()> {
     let balance = deposit.account_balance(&DummySubject, account.id).await?;
     assert_eq!(
         balance.settled,
-        UsdCents::try_from_usd(dec!(1000000)).unwrap()
+        Money::from(UsdCents::try_from_usd(dec!(1000000)).unwrap())
     );
 
     // revert deposit
     deposit.revert_deposit(&DummySubject, res.id).await?;
     let balance = deposit.account_balance(&DummySubject, account.id).await?;
 
-    assert_eq!(balance.settled, UsdCents::ZERO);
+    assert_eq!(balance.settled, Money::from(UsdCents::ZERO));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deposit_in_foreign_currency() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobsConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+    )
+    .await?;
+
+    let eur: CurrencyCode = "EUR".parse()?;
+    let account_holder_id = DepositAccountHolderId::new();
+    deposit
+        .create_account(
+            &DummySubject,
+            account_holder_id,
+            true,
+            DepositAccountType::Individual,
+            CurrencyCode::USD,
+        )
+        .await?;
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            account_holder_id,
+            true,
+            DepositAccountType::Individual,
+            eur.clone(),
+        )
+        .await?;
+    assert_eq!(account.currency, eur);
+
+    let res = deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(100)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(core_deposit::error::CoreDepositError::DepositAccountError(
+            _
+        ))
+    ));
+
+    let amount = Money::try_new(dec!(250.50), eur)?;
+    deposit
+        .record_deposit(&DummySubject, account.id, amount.clone(), None)
+        .await?;
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, amount);
 
     Ok(())
 }
@@ -0,0 +1,90 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use core_deposit::*;
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn assign_deposit_product_to_account() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobsConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+    )
+    .await?;
+
+    let product = deposit
+        .create_deposit_product(
+            &DummySubject,
+            format!("Savings {}", uuid::Uuid::new_v4()),
+            AnnualRatePct::from(dec!(2.5)),
+        )
+        .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+            CurrencyCode::USD,
+        )
+        .await?;
+    assert!(account.deposit_product_id.is_none());
+
+    let account = deposit
+        .assign_deposit_product(&DummySubject, account.id, product.id)
+        .await?;
+    assert_eq!(account.deposit_product_id, Some(product.id));
+    let interest_payable_account_id = account.interest_payable_account_id;
+    assert!(interest_payable_account_id.is_some());
+
+    let product = deposit
+        .update_deposit_product_annual_rate(&DummySubject, product.id, AnnualRatePct::from(dec!(3)))
+        .await?;
+    assert_eq!(product.annual_rate, AnnualRatePct::from(dec!(3)));
+
+    let other = deposit
+        .create_deposit_product(
+            &DummySubject,
+            format!("Savings {}", uuid::Uuid::new_v4()),
+            AnnualRatePct::from(dec!(4)),
+        )
+        .await?;
+    let account = deposit
+        .assign_deposit_product(&DummySubject, account.id, other.id)
+        .await?;
+    assert_eq!(account.deposit_product_id, Some(other.id));
+    assert_eq!(
+        account.interest_payable_account_id,
+        interest_payable_account_id
+    );
+
+    Ok(())
+}
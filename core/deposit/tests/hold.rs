@@ -0,0 +1,97 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use core_deposit::*;
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn hold_reserves_available_balance() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobsConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+            CurrencyCode::USD,
+        )
+        .await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, account.id, deposit_amount, None)
+        .await?;
+
+    let hold_amount = UsdCents::try_from_usd(dec!(600)).unwrap();
+    let hold = deposit
+        .place_hold(
+            &DummySubject,
+            account.id,
+            hold_amount,
+            "legal order".to_string(),
+            None,
+        )
+        .await?;
+    assert_eq!(hold.status(), HoldStatus::Active);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert_eq!(balance.settled, Money::from(deposit_amount));
+    assert_eq!(balance.held, Money::from(hold_amount));
+    assert_eq!(
+        balance.available(),
+        Money::from(deposit_amount - hold_amount)
+    );
+
+    let withdrawal_amount = UsdCents::try_from_usd(dec!(500)).unwrap();
+    let withdrawal = deposit
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .await;
+    assert!(matches!(
+        withdrawal,
+        Err(core_deposit::error::CoreDepositError::InsufficientAvailableBalance)
+    ));
+
+    let hold = deposit.release_hold(&DummySubject, hold.id).await?;
+    assert_eq!(hold.status(), HoldStatus::Released);
+
+    let balance = deposit.account_balance(&DummySubject, account.id).await?;
+    assert!(balance.held.is_zero());
+
+    deposit
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+mod helpers;
+
+use chrono::{Days, Utc};
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use core_deposit::*;
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn standing_order_create_and_cancel() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobsConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+            CurrencyCode::USD,
+        )
+        .await?;
+
+    let today = Utc::now().date_naive();
+    let amount = UsdCents::try_from_usd(dec!(500)).unwrap();
+    let res = deposit
+        .create_standing_order(
+            &DummySubject,
+            account.id,
+            amount,
+            "rent".to_string(),
+            StandingOrderSchedule::new(
+                StandingOrderFrequency::Monthly,
+                today.checked_sub_days(Days::new(1)).unwrap(),
+                None,
+            ),
+            None,
+        )
+        .await;
+    assert!(res.is_err());
+
+    let starts_on = today.checked_add_days(Days::new(1)).unwrap();
+    let standing_order = deposit
+        .create_standing_order(
+            &DummySubject,
+            account.id,
+            amount,
+            "rent".to_string(),
+            StandingOrderSchedule::new(StandingOrderFrequency::Monthly, starts_on, None),
+            None,
+        )
+        .await?;
+    assert_eq!(standing_order.next_run_date, Some(starts_on));
+    assert_eq!(
+        standing_order.max_retries,
+        STANDING_ORDER_DEFAULT_MAX_RETRIES
+    );
+    assert_eq!(standing_order.status(), StandingOrderStatus::Active);
+
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            amount,
+            Some(standing_order.withdrawal_reference(starts_on)),
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(core_deposit::error::CoreDepositError::WithdrawalError(_))
+    ));
+
+    let standing_orders = deposit
+        .list_standing_orders_for_account(
+            &DummySubject,
+            account.id,
+            es_entity::PaginatedQueryArgs {
+                first: 1,
+                after: None,
+            },
+        )
+        .await?;
+    assert_eq!(standing_orders.entities.len(), 1);
+    assert!(!standing_orders.has_next_page);
+
+    let standing_order = deposit
+        .cancel_standing_order(&DummySubject, standing_order.id)
+        .await?;
+    assert_eq!(standing_order.status(), StandingOrderStatus::Cancelled);
+    assert!(standing_order.executions().is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use core_deposit::*;
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn transfer_between_accounts() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobsConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+    )
+    .await?;
+
+    let from_account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+            CurrencyCode::USD,
+        )
+        .await?;
+    let to_account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+            CurrencyCode::USD,
+        )
+        .await?;
+
+    let deposit_amount = UsdCents::try_from_usd(dec!(1000)).unwrap();
+    deposit
+        .record_deposit(&DummySubject, from_account.id, deposit_amount, None)
+        .await?;
+
+    // overdraw
+    let transfer_amount = UsdCents::try_from_usd(dec!(5000)).unwrap();
+    let transfer = deposit
+        .initiate_transfer(
+            &DummySubject,
+            from_account.id,
+            to_account.id,
+            transfer_amount,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        transfer,
+        Err(core_deposit::error::CoreDepositError::InsufficientAvailableBalance)
+    ));
+
+    let transfer_amount = UsdCents::try_from_usd(dec!(400)).unwrap();
+    let transfer = deposit
+        .initiate_transfer(
+            &DummySubject,
+            from_account.id,
+            to_account.id,
+            transfer_amount,
+            None,
+        )
+        .await?;
+    assert_eq!(transfer.status(), TransferStatus::Completed);
+
+    let balance = deposit
+        .account_balance(&DummySubject, from_account.id)
+        .await?;
+    assert_eq!(
+        balance.settled,
+        Money::from(deposit_amount - transfer_amount)
+    );
+    let balance = deposit
+        .account_balance(&DummySubject, to_account.id)
+        .await?;
+    assert_eq!(balance.settled, Money::from(transfer_amount));
+
+    let history = deposit
+        .account_history(&DummySubject, to_account.id, Default::default())
+        .await?;
+    assert!(matches!(
+        history.entities.first(),
+        Some(DepositAccountHistoryEntry::TransferIn(_))
+    ));
+
+    Ok(())
+}
@@ -28,6 +28,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
 
     let deposit = CoreDeposit::init(
         &pool,
+        DepositConfig::default(),
         &authz,
         &outbox,
         &governance,
@@ -45,6 +46,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
             account_holder_id,
             true,
             DepositAccountType::Individual,
+            CurrencyCode::USD,
         )
         .await?;
 
@@ -61,7 +63,7 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
         .await;
     assert!(matches!(
         withdrawal,
-        Err(core_deposit::error::CoreDepositError::DepositLedgerError(_))
+        Err(core_deposit::error::CoreDepositError::InsufficientAvailableBalance)
     ));
 
     let withdrawal_amount = UsdCents::try_from_usd(dec!(500000)).unwrap();
@@ -71,14 +73,17 @@ async fn overdraw_and_cancel_withdrawal() -> anyhow::Result<()> {
         .await?;
 
     let balance = deposit.account_balance(&DummySubject, account.id).await?;
-    assert_eq!(balance.settled, deposit_amount - withdrawal_amount);
-    assert_eq!(balance.pending, withdrawal_amount);
+    assert_eq!(
+        balance.settled,
+        Money::from(deposit_amount - withdrawal_amount)
+    );
+    assert_eq!(balance.pending, Money::from(withdrawal_amount));
 
     deposit
         .cancel_withdrawal(&DummySubject, withdrawal.id)
         .await?;
     let balance = deposit.account_balance(&DummySubject, account.id).await?;
-    assert_eq!(balance.settled, deposit_amount);
+    assert_eq!(balance.settled, Money::from(deposit_amount));
 
     Ok(())
 }
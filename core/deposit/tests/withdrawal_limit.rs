@@ -0,0 +1,298 @@
+mod helpers;
+
+use rust_decimal_macros::dec;
+
+use authz::dummy::DummySubject;
+use cala_ledger::{CalaLedger, CalaLedgerConfig};
+use core_deposit::*;
+
+use helpers::{action, event, object};
+
+#[tokio::test]
+async fn withdrawal_limit_rejects_or_requires_approval() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobsConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+            CurrencyCode::USD,
+        )
+        .await?;
+    assert_eq!(account.account_type, Some(DepositAccountType::Individual));
+    let other_account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+            CurrencyCode::USD,
+        )
+        .await?;
+
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let limit = deposit
+        .create_withdrawal_limit(
+            &DummySubject,
+            WithdrawalLimitScope::Account {
+                deposit_account_id: account.id,
+            },
+            CurrencyCode::USD,
+            WithdrawalLimitAmounts {
+                daily: Some(UsdCents::try_from_usd(dec!(300)).unwrap().into()),
+                ..Default::default()
+            },
+            WithdrawalLimitBreachAction::Reject,
+        )
+        .await?;
+
+    let withdrawal_amount = UsdCents::try_from_usd(dec!(200)).unwrap();
+    deposit
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .await?;
+    let res = deposit
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .await;
+    assert!(matches!(
+        res,
+        Err(
+            core_deposit::error::CoreDepositError::WithdrawalLimitExceeded(
+                WithdrawalLimitInterval::Daily
+            )
+        )
+    ));
+
+    let res = deposit
+        .initiate_transfer(
+            &DummySubject,
+            account.id,
+            other_account.id,
+            withdrawal_amount,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(
+            core_deposit::error::CoreDepositError::WithdrawalLimitExceeded(
+                WithdrawalLimitInterval::Daily
+            )
+        )
+    ));
+
+    deposit
+        .update_withdrawal_limit(
+            &DummySubject,
+            limit.id,
+            limit.amounts.clone(),
+            WithdrawalLimitBreachAction::RequireApproval,
+        )
+        .await?;
+
+    let committee = governance
+        .create_committee(
+            &DummySubject,
+            format!("withdrawal-limit-{}", governance::CommitteeId::new()),
+        )
+        .await?;
+    governance
+        .add_member_to_committee(
+            &DummySubject,
+            committee.id,
+            governance::CommitteeMemberId::new(),
+        )
+        .await?;
+    let policy = governance
+        .list_policies_by_created_at(
+            &DummySubject,
+            es_entity::PaginatedQueryArgs {
+                first: 100,
+                after: None,
+            },
+        )
+        .await?
+        .entities
+        .into_iter()
+        .find(|policy| policy.process_type == APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS)
+        .expect("over limit policy not found");
+    governance
+        .assign_committee_to_policy(&DummySubject, policy.id, committee.id, 1)
+        .await?;
+
+    let withdrawal = deposit
+        .initiate_withdrawal(&DummySubject, account.id, withdrawal_amount, None)
+        .await?;
+    let process = governance
+        .find_approval_process_by_id(&DummySubject, withdrawal.approval_process_id)
+        .await?
+        .expect("approval process not found");
+    assert_eq!(process.process_type, APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn raising_a_reached_withdrawal_limit_allows_the_difference() -> anyhow::Result<()> {
+    let pool = helpers::init_pool().await?;
+
+    let outbox = outbox::Outbox::<event::DummyEvent>::init(&pool).await?;
+    let authz = authz::dummy::DummyPerms::<action::DummyAction, object::DummyObject>::new();
+    let governance = governance::Governance::new(&pool, &authz, &outbox);
+
+    let cala_config = CalaLedgerConfig::builder()
+        .pool(pool.clone())
+        .exec_migrations(false)
+        .build()?;
+    let cala = CalaLedger::init(cala_config).await?;
+    let jobs = job::Jobs::new(&pool, job::JobsConfig::default());
+
+    let journal_id = helpers::init_journal(&cala).await?;
+    let public_ids = public_id::PublicIds::new(&pool);
+
+    let deposit = CoreDeposit::init(
+        &pool,
+        DepositConfig::default(),
+        &authz,
+        &outbox,
+        &governance,
+        &jobs,
+        &cala,
+        journal_id,
+        &public_ids,
+    )
+    .await?;
+
+    let account = deposit
+        .create_account(
+            &DummySubject,
+            DepositAccountHolderId::new(),
+            true,
+            DepositAccountType::Individual,
+            CurrencyCode::USD,
+        )
+        .await?;
+    deposit
+        .record_deposit(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1000)).unwrap(),
+            None,
+        )
+        .await?;
+
+    let limit = deposit
+        .create_withdrawal_limit(
+            &DummySubject,
+            WithdrawalLimitScope::Account {
+                deposit_account_id: account.id,
+            },
+            CurrencyCode::USD,
+            WithdrawalLimitAmounts {
+                daily: Some(UsdCents::try_from_usd(dec!(300)).unwrap().into()),
+                ..Default::default()
+            },
+            WithdrawalLimitBreachAction::Reject,
+        )
+        .await?;
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(300)).unwrap(),
+            None,
+        )
+        .await?;
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(
+            core_deposit::error::CoreDepositError::WithdrawalLimitExceeded(
+                WithdrawalLimitInterval::Daily
+            )
+        )
+    ));
+
+    deposit
+        .update_withdrawal_limit(
+            &DummySubject,
+            limit.id,
+            WithdrawalLimitAmounts {
+                daily: Some(UsdCents::try_from_usd(dec!(500)).unwrap().into()),
+                ..Default::default()
+            },
+            WithdrawalLimitBreachAction::Reject,
+        )
+        .await?;
+
+    deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(200)).unwrap(),
+            None,
+        )
+        .await?;
+    let res = deposit
+        .initiate_withdrawal(
+            &DummySubject,
+            account.id,
+            UsdCents::try_from_usd(dec!(1)).unwrap(),
+            None,
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(
+            core_deposit::error::CoreDepositError::WithdrawalLimitExceeded(
+                WithdrawalLimitInterval::Daily
+            )
+        )
+    ));
+
+    Ok(())
+}
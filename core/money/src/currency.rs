@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use std::{borrow::Cow, fmt, str::FromStr};
+
+use crate::MoneyError;
+
+/// ISO 4217 currency code (plus `BTC`), e.g. `USD` or `EUR`.
+#[derive(Clone, Eq, Hash, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct CurrencyCode(Cow<'static, str>);
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(CurrencyCode);
+
+impl CurrencyCode {
+    pub const USD: Self = Self(Cow::Borrowed("USD"));
+    pub const BTC: Self = Self(Cow::Borrowed("BTC"));
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_usd(&self) -> bool {
+        self == &Self::USD
+    }
+
+    /// Number of decimal places used by the currency's minor unit.
+    pub fn minor_units(&self) -> u32 {
+        match self.as_str() {
+            "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF"
+            | "UGX" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+            "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+            "BTC" => 8,
+            _ => 2,
+        }
+    }
+}
+
+impl Default for CurrencyCode {
+    fn default() -> Self {
+        Self::USD
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.trim().to_ascii_uppercase();
+        if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(MoneyError::InvalidCurrencyCode(s.to_string()));
+        }
+        Ok(Self(Cow::Owned(code)))
+    }
+}
+
+mod sqlx_impls {
+    use sqlx::{
+        Postgres, Type,
+        postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef},
+    };
+
+    use super::CurrencyCode;
+
+    impl Type<Postgres> for CurrencyCode {
+        fn type_info() -> PgTypeInfo {
+            <String as Type<Postgres>>::type_info()
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            <String as Type<Postgres>>::compatible(ty)
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for CurrencyCode {
+        fn encode_by_ref(
+            &self,
+            buf: &mut PgArgumentBuffer,
+        ) -> Result<sqlx::encode::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+            <&str as sqlx::Encode<'_, Postgres>>::encode(self.as_str(), buf)
+        }
+    }
+
+    impl<'r> sqlx::Decode<'r, Postgres> for CurrencyCode {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+            let s = <String as sqlx::Decode<Postgres>>::decode(value)?;
+            Ok(s.parse()?)
+        }
+    }
+
+    impl PgHasArrayType for CurrencyCode {
+        fn array_type_info() -> PgTypeInfo {
+            <String as PgHasArrayType>::array_type_info()
+        }
+    }
+}
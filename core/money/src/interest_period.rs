@@ -0,0 +1,228 @@
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+pub const NUMBER_OF_DAYS_IN_YEAR: u64 = 365;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct InterestPeriod {
+    pub interval: InterestInterval,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl InterestPeriod {
+    fn new(interval: InterestInterval, start: DateTime<Utc>) -> Self {
+        let end = interval.end_date_starting_at(start);
+        Self {
+            interval,
+            start,
+            end,
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        Self::new(self.interval, self.end + chrono::Duration::seconds(1))
+    }
+
+    pub fn truncate(&self, latest_possible_end_date: DateTime<Utc>) -> Option<Self> {
+        if self.start > latest_possible_end_date {
+            return None;
+        }
+
+        Some(Self {
+            interval: self.interval,
+            start: self.start,
+            end: self.end.min(latest_possible_end_date),
+        })
+    }
+
+    pub fn days(&self) -> u32 {
+        self.end.day() - self.start.day() + 1
+    }
+
+    /// Whether this period closes at the same instant as the enclosing
+    /// period of the (coarser) `interval`.
+    pub fn ends_with(&self, interval: InterestInterval) -> bool {
+        interval.end_date_starting_at(self.start) == self.end
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InterestInterval {
+    #[default]
+    EndOfMonth,
+    EndOfDay,
+}
+
+impl InterestInterval {
+    pub fn period_from(&self, start_date: DateTime<Utc>) -> InterestPeriod {
+        InterestPeriod::new(*self, start_date)
+    }
+
+    pub fn periods_per_year(&self) -> u32 {
+        match self {
+            InterestInterval::EndOfMonth => 12,
+            InterestInterval::EndOfDay => NUMBER_OF_DAYS_IN_YEAR as u32,
+        }
+    }
+
+    pub fn end_date_starting_at(&self, current_date: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            InterestInterval::EndOfMonth => {
+                let current_year = current_date.year();
+                let current_month = current_date.month();
+
+                let (year, month) = if current_month == 12 {
+                    (current_year + 1, 1)
+                } else {
+                    (current_year, current_month + 1)
+                };
+
+                Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0)
+                    .single()
+                    .expect("should return a valid date time")
+                    - chrono::Duration::seconds(1)
+            }
+            InterestInterval::EndOfDay => Utc
+                .with_ymd_and_hms(
+                    current_date.year(),
+                    current_date.month(),
+                    current_date.day(),
+                    23,
+                    59,
+                    59,
+                )
+                .single()
+                .expect("should return a valid date time"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn days() {
+        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfMonth.period_from(start_date).days(),
+            29
+        );
+
+        let start_date = "2024-12-01T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfMonth.period_from(start_date).days(),
+            31
+        );
+
+        let start_date = "2024-02-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfMonth.period_from(start_date).days(),
+            29
+        );
+    }
+
+    #[test]
+    fn truncate() {
+        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::EndOfMonth.period_from(start_date);
+
+        let latest_before_start_date = "2024-12-02T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(period.truncate(latest_before_start_date), None);
+
+        let latest_after_start_date_before_end_date =
+            "2024-12-20T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert!(
+            period
+                .truncate(latest_after_start_date_before_end_date)
+                .unwrap()
+                .end
+                < period.end
+        );
+
+        let latest_after_end_date = "2025-01-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            period.truncate(latest_after_end_date).unwrap().end,
+            period.end
+        );
+    }
+
+    #[test]
+    fn next_period() {
+        let start_date = "2024-12-03T14:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let expected_next_start_date = "2024-12-04T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let day_period = InterestInterval::EndOfDay.period_from(start_date);
+        assert_eq!(day_period.next().start, expected_next_start_date);
+
+        let expected_next_start_date = "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let month_period = InterestInterval::EndOfMonth.period_from(start_date);
+        assert_eq!(month_period.next().start, expected_next_start_date);
+    }
+
+    #[test]
+    fn daily_period_ends_with_month() {
+        let last_day = "2024-12-31T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::EndOfDay.period_from(last_day);
+        assert!(period.ends_with(InterestInterval::EndOfMonth));
+
+        let middle_day = "2024-12-15T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let period = InterestInterval::EndOfDay.period_from(middle_day);
+        assert!(!period.ends_with(InterestInterval::EndOfMonth));
+        assert_eq!(period.days(), 1);
+    }
+
+    #[test]
+    fn end_date_starting_at_month_interval() {
+        let expected_end_date = "2024-12-31T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
+
+        let start_of_month = "2024-12-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfMonth.end_date_starting_at(start_of_month),
+            expected_end_date
+        );
+
+        let middle_of_month = "2024-12-15T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfMonth.end_date_starting_at(middle_of_month),
+            expected_end_date
+        );
+
+        let end_of_month = "2024-12-31T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfMonth.end_date_starting_at(end_of_month),
+            expected_end_date
+        );
+    }
+
+    #[test]
+    fn end_date_starting_at_day_interval() {
+        let expected_end_date = "2024-12-03T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
+
+        let start_of_day = "2024-12-03T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfDay.end_date_starting_at(start_of_day),
+            expected_end_date
+        );
+
+        let middle_of_day = "2024-12-03T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfDay.end_date_starting_at(middle_of_day),
+            expected_end_date
+        );
+
+        let end_of_day = "2024-12-03T23:59:59Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            InterestInterval::EndOfDay.end_date_starting_at(end_of_day),
+            expected_end_date
+        );
+    }
+}
@@ -1,6 +1,10 @@
 #![cfg_attr(feature = "fail-on-warnings", deny(warnings))]
 #![cfg_attr(feature = "fail-on-warnings", deny(clippy::all))]
 
+mod currency;
+mod interest_period;
+mod money;
+
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
@@ -11,6 +15,10 @@ use schemars::JsonSchema;
 
 use std::fmt;
 
+pub use currency::CurrencyCode;
+pub use interest_period::{InterestInterval, InterestPeriod, NUMBER_OF_DAYS_IN_YEAR};
+pub use money::Money;
+
 pub const SATS_PER_BTC: Decimal = dec!(100_000_000);
 pub const CENTS_PER_USD: Decimal = dec!(100);
 
@@ -87,6 +95,21 @@ pub enum ConversionError {
     Overflow,
 }
 
+#[derive(Error, Debug)]
+pub enum MoneyError {
+    #[error("MoneyError - InvalidCurrencyCode: {0}")]
+    InvalidCurrencyCode(String),
+    #[error("MoneyError - CurrencyMismatch: expected {expected}, got {actual}")]
+    CurrencyMismatch {
+        expected: CurrencyCode,
+        actual: CurrencyCode,
+    },
+    #[error("MoneyError - TooManyDecimalPlaces: {0} {1}")]
+    TooManyDecimalPlaces(Decimal, CurrencyCode),
+    #[error("MoneyError - ConversionError: {0}")]
+    ConversionError(#[from] ConversionError),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 pub struct Satoshis(u64);
@@ -0,0 +1,215 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+
+use std::fmt;
+
+use crate::{CurrencyCode, MoneyError, UsdCents};
+
+/// An amount denominated in a specific currency.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(from = "MoneyRepr")]
+pub struct Money {
+    amount: Decimal,
+    currency: CurrencyCode,
+}
+
+/// Amounts persisted before they carried a currency are bare USD cents.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(untagged)]
+enum MoneyRepr {
+    UsdCents(UsdCents),
+    Money {
+        amount: Decimal,
+        currency: CurrencyCode,
+    },
+}
+
+impl From<MoneyRepr> for Money {
+    fn from(repr: MoneyRepr) -> Self {
+        match repr {
+            MoneyRepr::UsdCents(cents) => cents.into(),
+            MoneyRepr::Money { amount, currency } => Self { amount, currency },
+        }
+    }
+}
+
+impl Money {
+    pub fn try_new(amount: Decimal, currency: CurrencyCode) -> Result<Self, MoneyError> {
+        if amount.normalize().scale() > currency.minor_units() {
+            return Err(MoneyError::TooManyDecimalPlaces(amount, currency));
+        }
+        Ok(Self { amount, currency })
+    }
+
+    pub fn zero(currency: CurrencyCode) -> Self {
+        Self {
+            amount: Decimal::ZERO,
+            currency,
+        }
+    }
+
+    pub fn from_minor_units(minor_units: i64, currency: CurrencyCode) -> Self {
+        Self {
+            amount: Decimal::new(minor_units, currency.minor_units()),
+            currency,
+        }
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> &CurrencyCode {
+        &self.currency
+    }
+
+    pub fn to_minor_units(&self) -> i64 {
+        let scaled = self.amount * Decimal::from(10i64.pow(self.currency.minor_units()));
+        i64::try_from(scaled.trunc()).expect("Minor units must fit in i64")
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.amount.is_sign_negative() && !self.amount.is_zero()
+    }
+
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.ensure_same_currency(other)?;
+        Ok(Self {
+            amount: self.amount + other.amount,
+            currency: self.currency.clone(),
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.ensure_same_currency(other)?;
+        Ok(Self {
+            amount: self.amount - other.amount,
+            currency: self.currency.clone(),
+        })
+    }
+
+    /// Converts at `rate` units of `to` per unit of this currency, rounding
+    /// half away from zero to the minor unit of `to`.
+    pub fn convert(&self, rate: Decimal, to: CurrencyCode) -> Money {
+        let amount = (self.amount * rate)
+            .round_dp_with_strategy(to.minor_units(), RoundingStrategy::MidpointAwayFromZero);
+        Self {
+            amount,
+            currency: to,
+        }
+    }
+
+    fn ensure_same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch {
+                expected: self.currency.clone(),
+                actual: other.currency.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.*} {}",
+            self.currency.minor_units() as usize,
+            self.amount,
+            self.currency
+        )
+    }
+}
+
+impl From<UsdCents> for Money {
+    fn from(cents: UsdCents) -> Self {
+        Self {
+            amount: cents.to_usd(),
+            currency: CurrencyCode::USD,
+        }
+    }
+}
+
+impl TryFrom<Money> for UsdCents {
+    type Error = MoneyError;
+
+    fn try_from(money: Money) -> Result<Self, Self::Error> {
+        if !money.currency.is_usd() {
+            return Err(MoneyError::CurrencyMismatch {
+                expected: CurrencyCode::USD,
+                actual: money.currency,
+            });
+        }
+        Ok(UsdCents::try_from_usd(money.amount)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn eur() -> CurrencyCode {
+        "EUR".parse().unwrap()
+    }
+
+    #[test]
+    fn rejects_amounts_below_minor_unit() {
+        assert!(Money::try_new(dec!(1.23), eur()).is_ok());
+        assert!(matches!(
+            Money::try_new(dec!(1.234), eur()),
+            Err(MoneyError::TooManyDecimalPlaces(..))
+        ));
+        assert!(Money::try_new(dec!(1.5), "JPY".parse().unwrap()).is_err());
+    }
+
+    #[test]
+    fn arithmetic_requires_same_currency() {
+        let a = Money::try_new(dec!(10), eur()).unwrap();
+        let b = Money::try_new(dec!(2.5), eur()).unwrap();
+        assert_eq!(a.checked_sub(&b).unwrap().amount(), dec!(7.5));
+
+        let usd = Money::from(UsdCents::from(100));
+        assert!(matches!(
+            a.checked_add(&usd),
+            Err(MoneyError::CurrencyMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn minor_units_round_trip() {
+        let money = Money::from_minor_units(12345, eur());
+        assert_eq!(money.amount(), dec!(123.45));
+        assert_eq!(money.to_minor_units(), 12345);
+        assert_eq!(money.to_string(), "123.45 EUR");
+    }
+
+    #[test]
+    fn deserializes_legacy_usd_cents() {
+        let money: Money = serde_json::from_str("12345").unwrap();
+        assert_eq!(money, Money::from(UsdCents::from(12345)));
+
+        let eur = Money::from_minor_units(250, eur());
+        let json = serde_json::to_string(&eur).unwrap();
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), eur);
+    }
+
+    #[test]
+    fn convert_rounds_to_target_minor_unit() {
+        let money = Money::try_new(dec!(100), eur()).unwrap();
+        let usd = money.convert(dec!(1.083456), CurrencyCode::USD);
+        assert_eq!(usd.amount(), dec!(108.35));
+        assert_eq!(UsdCents::try_from(usd).unwrap(), UsdCents::from(10835));
+    }
+}
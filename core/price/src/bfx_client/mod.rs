@@ -4,7 +4,7 @@ mod response;
 use reqwest::Client as ReqwestClient;
 
 use error::BfxClientError;
-use response::{BfxErrorResponse, BtcUsdTick};
+use response::{BfxErrorResponse, UsdTick};
 
 const BASE_URL: &str = "https://api-pub.bitfinex.com/v2/";
 
@@ -23,15 +23,15 @@ impl BfxClient {
         }
     }
 
-    pub async fn btc_usd_tick(&self) -> Result<BtcUsdTick, BfxClientError> {
-        let url = format!("{BASE_URL}ticker/tBTCUSD");
+    pub async fn usd_tick(&self, symbol: &str) -> Result<UsdTick, BfxClientError> {
+        let url = format!("{BASE_URL}ticker/{symbol}");
         let response = self
             .client
             .get(&url)
             .header("accept", "application/json")
             .send()
             .await?;
-        let tick = Self::extract_response_data::<BtcUsdTick>(response).await?;
+        let tick = Self::extract_response_data::<UsdTick>(response).await?;
 
         Ok(tick)
     }
@@ -3,7 +3,7 @@ use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
 #[allow(dead_code)]
-pub struct BtcUsdTick {
+pub struct UsdTick {
     pub bid: Decimal,
     pub bid_size: Decimal,
     pub ask: Decimal,
@@ -32,7 +32,7 @@ mod tests {
     fn last_price_data() {
         let response_text =
             "[16808,24.10170847,16809,55.3107456,-26,-0.0015,16809,147.2349813,16884,16769]";
-        let details = serde_json::from_str::<BtcUsdTick>(response_text).unwrap();
+        let details = serde_json::from_str::<UsdTick>(response_text).unwrap();
         assert_eq!(details.last_price, dec!(16809));
     }
 }
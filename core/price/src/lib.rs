@@ -28,6 +28,16 @@ impl Price {
     pub async fn usd_cents_per_btc(&self) -> Result<PriceOfOneBTC, PriceError> {
         usd_cents_per_btc_cached(&self.bfx).await
     }
+
+    pub async fn usd_cents_per_unit(
+        &self,
+        asset: CollateralAsset,
+    ) -> Result<PriceOfOneUnit, PriceError> {
+        match asset {
+            CollateralAsset::Btc => Ok(self.usd_cents_per_btc().await?.into()),
+            _ => usd_cents_per_unit_cached(&self.bfx, asset).await,
+        }
+    }
 }
 
 impl Default for Price {
@@ -44,6 +54,38 @@ async fn usd_cents_per_btc_cached(bfx: &BfxClient) -> Result<PriceOfOneBTC, Pric
         )?));
     }
 
-    let last_price = bfx.btc_usd_tick().await?.last_price;
+    let last_price = bfx
+        .usd_tick(CollateralAsset::Btc.bfx_symbol())
+        .await?
+        .last_price;
     Ok(PriceOfOneBTC::new(UsdCents::try_from_usd(last_price)?))
 }
+
+#[cached(
+    time = 60,
+    result = true,
+    key = "CollateralAsset",
+    convert = r#"{ asset }"#
+)]
+async fn usd_cents_per_unit_cached(
+    bfx: &BfxClient,
+    asset: CollateralAsset,
+) -> Result<PriceOfOneUnit, PriceError> {
+    if std::env::var("BFX_LOCAL_PRICE").is_ok() {
+        let local_price = match asset {
+            CollateralAsset::Btc => rust_decimal_macros::dec!(100_000),
+            CollateralAsset::Eth => rust_decimal_macros::dec!(4_000),
+            CollateralAsset::Xaut => rust_decimal_macros::dec!(3_000),
+        };
+        return Ok(PriceOfOneUnit::new(
+            asset,
+            UsdCents::try_from_usd(local_price)?,
+        ));
+    }
+
+    let last_price = bfx.usd_tick(asset.bfx_symbol()).await?.last_price;
+    Ok(PriceOfOneUnit::new(
+        asset,
+        UsdCents::try_from_usd(last_price)?,
+    ))
+}
@@ -1,4 +1,4 @@
-use rust_decimal::RoundingStrategy;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 
 use core_money::{Satoshis, UsdCents};
@@ -30,3 +30,77 @@ impl PriceOfOneBTC {
         self.0
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum CollateralAsset {
+    Btc,
+    Eth,
+    Xaut,
+}
+
+impl CollateralAsset {
+    pub(crate) fn bfx_symbol(&self) -> &'static str {
+        match self {
+            Self::Btc => "tBTCUSD",
+            Self::Eth => "tETHUSD",
+            Self::Xaut => "tXAUT:USD",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PriceOfOneUnit {
+    asset: CollateralAsset,
+    price: UsdCents,
+}
+
+impl PriceOfOneUnit {
+    pub const fn new(asset: CollateralAsset, price: UsdCents) -> Self {
+        Self { asset, price }
+    }
+
+    pub fn asset(&self) -> CollateralAsset {
+        self.asset
+    }
+
+    pub fn units_to_cents_round_down(self, units: Decimal) -> UsdCents {
+        let usd = (units * self.price.to_usd()).round_dp_with_strategy(2, RoundingStrategy::ToZero);
+        UsdCents::try_from_usd(usd).expect("Decimal should have no fractional component here")
+    }
+
+    pub fn into_inner(self) -> UsdCents {
+        self.price
+    }
+}
+
+impl From<PriceOfOneBTC> for PriceOfOneUnit {
+    fn from(price: PriceOfOneBTC) -> Self {
+        Self::new(CollateralAsset::Btc, price.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn units_to_cents_rounds_down() {
+        let price = PriceOfOneUnit::new(
+            CollateralAsset::Eth,
+            UsdCents::try_from_usd(dec!(4_000)).unwrap(),
+        );
+        assert_eq!(
+            price.units_to_cents_round_down(dec!(1.5)),
+            UsdCents::try_from_usd(dec!(6_000)).unwrap()
+        );
+        assert_eq!(
+            price.units_to_cents_round_down(dec!(0.0000001)),
+            UsdCents::ZERO
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::accounting::accounting_period::{
+    AccountingPeriod as DomainAccountingPeriod, AccountingPeriodStatus, AccountingPeriodType,
+    AccountingPeriodsByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+pub struct AccountingPeriod {
+    id: ID,
+    accounting_period_id: UUID,
+    period_type: AccountingPeriodType,
+    starts_on: Date,
+    ends_on: Date,
+    status: AccountingPeriodStatus,
+    closing_transaction_id: Option<UUID>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub entity: Arc<DomainAccountingPeriod>,
+}
+
+impl From<DomainAccountingPeriod> for AccountingPeriod {
+    fn from(period: DomainAccountingPeriod) -> Self {
+        Self {
+            id: period.id.to_global_id(),
+            accounting_period_id: UUID::from(period.id),
+            period_type: period.period_type,
+            starts_on: period.starts_on.into(),
+            ends_on: period.ends_on.into(),
+            status: period.status(),
+            closing_transaction_id: period.closing_transaction_id.map(UUID::from),
+            created_at: period.created_at().into(),
+            entity: Arc::new(period),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct AccountingPeriodCreateInput {
+    pub period_type: AccountingPeriodType,
+    pub starts_on: Date,
+}
+crate::mutation_payload! { AccountingPeriodCreatePayload, accounting_period: AccountingPeriod }
+
+#[derive(InputObject)]
+pub struct AccountingPeriodSoftCloseInput {
+    pub accounting_period_id: UUID,
+}
+crate::mutation_payload! { AccountingPeriodSoftClosePayload, accounting_period: AccountingPeriod }
+
+#[derive(InputObject)]
+pub struct AccountingPeriodReopenInput {
+    pub accounting_period_id: UUID,
+}
+crate::mutation_payload! { AccountingPeriodReopenPayload, accounting_period: AccountingPeriod }
+
+#[derive(InputObject)]
+pub struct AccountingPeriodCloseInput {
+    pub accounting_period_id: UUID,
+    /// Required for annual periods, where P&L balances are closed into this account.
+    pub retained_earnings_account_code: Option<String>,
+}
+crate::mutation_payload! { AccountingPeriodClosePayload, accounting_period: AccountingPeriod }
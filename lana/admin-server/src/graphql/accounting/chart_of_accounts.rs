@@ -2,7 +2,13 @@ use async_graphql::*;
 
 use crate::{graphql::accounting::AccountCode, primitives::*};
 
-use lana_app::accounting::Chart as DomainChart;
+use lana_app::accounting::{
+    Chart as DomainChart,
+    chart_of_accounts::{
+        ChangedAccount, ChartImportDiff as DomainChartImportDiff, ConflictingAccount,
+        ImportConflict,
+    },
+};
 use lana_app::primitives::DebitOrCredit;
 
 #[derive(SimpleObject, Clone)]
@@ -44,6 +50,7 @@ impl ChartOfAccounts {
 pub struct ChartNode {
     name: String,
     account_code: AccountCode,
+    deactivated: bool,
     children: Vec<ChartNode>,
 }
 
@@ -52,6 +59,7 @@ impl From<lana_app::accounting::tree::TreeNode> for ChartNode {
         Self {
             name: node.name.to_string(),
             account_code: AccountCode::from(&node.code),
+            deactivated: node.deactivated,
             children: node.children.into_iter().map(ChartNode::from).collect(),
         }
     }
@@ -101,3 +109,140 @@ impl TryFrom<ChartOfAccountsAddRootNodeInput> for AccountSpec {
         )?)
     }
 }
+
+#[derive(InputObject)]
+pub struct ChartOfAccountsNodeRenameInput {
+    pub chart_id: UUID,
+    pub code: AccountCode,
+    pub name: String,
+}
+crate::mutation_payload! { ChartOfAccountsNodeRenamePayload, chart_of_accounts: ChartOfAccounts }
+
+#[derive(InputObject)]
+pub struct ChartOfAccountsNodeDeactivateInput {
+    pub chart_id: UUID,
+    pub code: AccountCode,
+}
+crate::mutation_payload! { ChartOfAccountsNodeDeactivatePayload, chart_of_accounts: ChartOfAccounts }
+
+#[derive(InputObject)]
+pub struct ChartOfAccountsNodeMoveInput {
+    pub chart_id: UUID,
+    pub code: AccountCode,
+    pub new_parent: AccountCode,
+}
+crate::mutation_payload! { ChartOfAccountsNodeMovePayload, chart_of_accounts: ChartOfAccounts }
+
+#[derive(SimpleObject)]
+pub struct ChartOfAccountsCsvImportDiff {
+    added: Vec<ChartImportAccount>,
+    changed: Vec<ChartImportChange>,
+    conflicting: Vec<ChartImportConflict>,
+}
+
+impl From<DomainChartImportDiff> for ChartOfAccountsCsvImportDiff {
+    fn from(diff: DomainChartImportDiff) -> Self {
+        Self {
+            added: diff
+                .added
+                .into_iter()
+                .map(ChartImportAccount::from)
+                .collect(),
+            changed: diff
+                .changed
+                .into_iter()
+                .map(ChartImportChange::from)
+                .collect(),
+            conflicting: diff
+                .conflicting
+                .into_iter()
+                .map(ChartImportConflict::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ChartImportAccount {
+    code: AccountCode,
+    parent: Option<AccountCode>,
+    name: String,
+    normal_balance_type: DebitOrCredit,
+}
+
+impl From<AccountSpec> for ChartImportAccount {
+    fn from(spec: AccountSpec) -> Self {
+        Self {
+            code: AccountCode::from(&spec.code),
+            parent: spec.parent.as_ref().map(AccountCode::from),
+            name: spec.name.to_string(),
+            normal_balance_type: spec.normal_balance_type,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ChartImportChange {
+    code: AccountCode,
+    current_name: String,
+    new_name: String,
+}
+
+impl From<ChangedAccount> for ChartImportChange {
+    fn from(change: ChangedAccount) -> Self {
+        Self {
+            code: AccountCode::from(&change.code),
+            current_name: change.current_name.to_string(),
+            new_name: change.new_name.to_string(),
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ChartImportConflictReason {
+    ParentMismatch,
+    NormalBalanceTypeMismatch,
+    AccountDeactivated,
+    ParentDeactivated,
+    DuplicateCode,
+}
+
+#[derive(SimpleObject)]
+pub struct ChartImportConflict {
+    account: ChartImportAccount,
+    reason: ChartImportConflictReason,
+    current_parent: Option<AccountCode>,
+    current_normal_balance_type: Option<DebitOrCredit>,
+}
+
+impl From<ConflictingAccount> for ChartImportConflict {
+    fn from(ConflictingAccount { spec, conflict }: ConflictingAccount) -> Self {
+        let (reason, current_parent, current_normal_balance_type) = match conflict {
+            ImportConflict::ParentMismatch { current } => (
+                ChartImportConflictReason::ParentMismatch,
+                current.as_ref().map(AccountCode::from),
+                None,
+            ),
+            ImportConflict::NormalBalanceTypeMismatch { current } => (
+                ChartImportConflictReason::NormalBalanceTypeMismatch,
+                None,
+                Some(current),
+            ),
+            ImportConflict::AccountDeactivated => {
+                (ChartImportConflictReason::AccountDeactivated, None, None)
+            }
+            ImportConflict::ParentDeactivated => {
+                (ChartImportConflictReason::ParentDeactivated, None, None)
+            }
+            ImportConflict::DuplicateCode => (ChartImportConflictReason::DuplicateCode, None, None),
+        };
+        Self {
+            account: ChartImportAccount::from(spec),
+            reason,
+            current_parent,
+            current_normal_balance_type,
+        }
+    }
+}
+
+crate::mutation_payload! { ChartOfAccountsCsvImportDryRunPayload, diff: ChartOfAccountsCsvImportDiff }
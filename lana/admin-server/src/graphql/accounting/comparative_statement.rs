@@ -0,0 +1,122 @@
+use async_graphql::*;
+
+pub use lana_app::accounting::comparative_statement::{
+    ComparativeLine as DomainComparativeLine, ComparativePeriod as DomainComparativePeriod,
+    ComparativeStatement as DomainComparativeStatement, ComparisonPreset,
+    Variance as DomainVariance,
+};
+
+use crate::{graphql::primitives::Decimal, primitives::*};
+
+#[derive(SimpleObject)]
+pub struct ComparativeStatement {
+    name: String,
+    periods: Vec<ComparativePeriod>,
+    lines: Vec<ComparativeLine>,
+    totals: Vec<ComparativeLine>,
+}
+
+impl From<DomainComparativeStatement> for ComparativeStatement {
+    fn from(statement: DomainComparativeStatement) -> Self {
+        Self {
+            name: statement.name,
+            periods: statement.periods.into_iter().map(Into::into).collect(),
+            lines: statement.lines.into_iter().map(Into::into).collect(),
+            totals: statement.totals.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ComparativePeriod {
+    from: Date,
+    until: Date,
+}
+
+impl From<DomainComparativePeriod> for ComparativePeriod {
+    fn from(period: DomainComparativePeriod) -> Self {
+        Self {
+            from: period.from.into(),
+            until: period.until.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ComparativeLine {
+    ledger_account_id: UUID,
+    code: Option<String>,
+    name: String,
+    depth: u32,
+    currency: String,
+    amounts: Vec<Decimal>,
+    variances: Vec<Variance>,
+}
+
+impl From<DomainComparativeLine> for ComparativeLine {
+    fn from(line: DomainComparativeLine) -> Self {
+        Self {
+            ledger_account_id: line.ledger_account_id.into(),
+            code: line.code.map(|code| code.to_string()),
+            name: line.name,
+            depth: line.depth as u32,
+            currency: line.currency.to_string(),
+            amounts: line.amounts.into_iter().map(Decimal::from).collect(),
+            variances: line.variances.into_iter().map(Variance::from).collect(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct Variance {
+    absolute: Decimal,
+    percent: Option<Decimal>,
+}
+
+impl From<DomainVariance> for Variance {
+    fn from(variance: DomainVariance) -> Self {
+        Self {
+            absolute: variance.absolute.into(),
+            percent: variance.percent.map(Decimal::from),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct ComparativePeriodInput {
+    pub from: Date,
+    pub until: Date,
+}
+
+#[derive(InputObject)]
+pub struct ComparativeStatementInput {
+    /// Explicit column sets, current period first. Takes precedence over `preset`.
+    pub periods: Option<Vec<ComparativePeriodInput>>,
+    pub preset: Option<ComparisonPreset>,
+    /// Reference date for `preset`, defaults to today.
+    pub as_of: Option<Date>,
+}
+
+impl ComparativeStatementInput {
+    pub fn periods(self) -> async_graphql::Result<Vec<DomainComparativePeriod>> {
+        if let Some(periods) = self.periods {
+            return Ok(periods
+                .into_iter()
+                .map(|period| {
+                    DomainComparativePeriod::new(
+                        period.from.into_inner(),
+                        period.until.into_inner(),
+                    )
+                })
+                .collect());
+        }
+        let Some(preset) = self.preset else {
+            return Err(Error::new("Either periods or preset must be provided"));
+        };
+        let as_of = self
+            .as_of
+            .map(|date| date.into_inner())
+            .unwrap_or_else(|| chrono::Utc::now().date_naive());
+        Ok(preset.periods(as_of))
+    }
+}
@@ -8,7 +8,10 @@ use lana_app::primitives::{DebitOrCredit, Layer};
 
 use super::{ledger_account::LedgerAccount, ledger_transaction::LedgerTransaction};
 
-use crate::{graphql::loader::LanaDataLoader, primitives::*};
+use crate::{
+    graphql::{loader::LanaDataLoader, primitives::Decimal},
+    primitives::*,
+};
 
 #[derive(SimpleObject)]
 #[graphql(complex)]
@@ -31,7 +34,7 @@ impl From<DomainJournalEntry> for JournalEntry {
             id: entry.entry_id.into(),
             entry_id: entry.entry_id.into(),
             tx_id: entry.ledger_transaction_id.into(),
-            amount: entry.amount.into(),
+            amount: entry.amount.clone().into(),
             direction: entry.direction,
             layer: entry.layer,
             created_at: entry.created_at.into(),
@@ -76,6 +79,7 @@ impl JournalEntry {
 pub enum JournalEntryAmount {
     Usd(UsdAmount),
     Btc(BtcAmount),
+    Money(MoneyAmount),
 }
 
 #[derive(SimpleObject)]
@@ -88,11 +92,21 @@ pub struct BtcAmount {
     btc: Satoshis,
 }
 
+#[derive(SimpleObject)]
+pub struct MoneyAmount {
+    amount: Decimal,
+    currency: String,
+}
+
 impl From<DomainJournalEntryAmount> for JournalEntryAmount {
     fn from(amount: DomainJournalEntryAmount) -> Self {
         match amount {
             DomainJournalEntryAmount::Usd(usd) => JournalEntryAmount::Usd(UsdAmount { usd }),
             DomainJournalEntryAmount::Btc(btc) => JournalEntryAmount::Btc(BtcAmount { btc }),
+            DomainJournalEntryAmount::Money(money) => JournalEntryAmount::Money(MoneyAmount {
+                amount: money.amount().into(),
+                currency: money.currency().to_string(),
+            }),
         }
     }
 }
@@ -1,14 +1,119 @@
 use async_graphql::*;
 
-pub use lana_app::accounting::manual_transaction::ManualEntryInput;
+pub use lana_app::accounting::manual_transaction::{
+    ManualEntryInput, ManualTransaction as DomainManualTransaction,
+    ManualTransactionEntry as DomainManualTransactionEntry, ManualTransactionStatus,
+    ManualTransactionsByCreatedAtCursor,
+};
 
-use crate::graphql::primitives::*;
-use crate::primitives::Date;
+use crate::graphql::{approval_process::ApprovalProcess, loader::LanaDataLoader, primitives::*};
+use crate::primitives::*;
 
 use lana_app::primitives::DebitOrCredit;
 
 use super::ledger_transaction::LedgerTransaction;
 
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ManualTransaction {
+    id: ID,
+    manual_transaction_id: UUID,
+    reference: String,
+    description: String,
+    reversed: bool,
+    reversal_of: Option<UUID>,
+    reversal_transaction_id: Option<UUID>,
+    status: ManualTransactionStatus,
+    effective: Option<Date>,
+    entries: Vec<ManualTransactionEntry>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub entity: Arc<DomainManualTransaction>,
+}
+
+impl From<DomainManualTransaction> for ManualTransaction {
+    fn from(tx: DomainManualTransaction) -> Self {
+        Self {
+            id: tx.id.to_global_id(),
+            manual_transaction_id: UUID::from(tx.id),
+            reference: tx.reference.clone(),
+            description: tx.description.clone(),
+            reversed: tx.is_reversed(),
+            reversal_of: tx.reversal_of.map(UUID::from),
+            reversal_transaction_id: tx.reversal_transaction_id.map(UUID::from),
+            status: tx.status(),
+            effective: tx.effective.map(Date::from),
+            entries: tx
+                .entries
+                .iter()
+                .cloned()
+                .map(ManualTransactionEntry::from)
+                .collect(),
+            created_at: tx.created_at().into(),
+            entity: Arc::new(tx),
+        }
+    }
+}
+
+#[ComplexObject]
+impl ManualTransaction {
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        if self.status != ManualTransactionStatus::Posted {
+            return Ok(None);
+        }
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let tx = loader
+            .load_one(self.entity.ledger_transaction_id)
+            .await?
+            .expect("ledger transaction not found");
+        Ok(Some(tx))
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(Some(process))
+    }
+
+    async fn failure_reason(&self) -> Option<&str> {
+        self.entity.failure_reason()
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct ManualTransactionEntry {
+    account_id: UUID,
+    amount: Decimal,
+    currency: String,
+    direction: DebitOrCredit,
+    description: String,
+}
+
+impl From<DomainManualTransactionEntry> for ManualTransactionEntry {
+    fn from(entry: DomainManualTransactionEntry) -> Self {
+        Self {
+            account_id: UUID::from(entry.account_id),
+            amount: entry.amount.into(),
+            currency: entry.currency.to_string(),
+            direction: entry.direction,
+            description: entry.description,
+        }
+    }
+}
+
 #[derive(InputObject)]
 pub struct ManualTransactionExecuteInput {
     pub description: String,
@@ -16,7 +121,7 @@ pub struct ManualTransactionExecuteInput {
     pub effective: Option<Date>,
     pub entries: Vec<ManualTransactionEntryInput>,
 }
-crate::mutation_payload! { ManualTransactionExecutePayload, transaction: LedgerTransaction }
+crate::mutation_payload! { ManualTransactionExecutePayload, manual_transaction: ManualTransaction }
 
 #[derive(InputObject)]
 pub struct ManualTransactionEntryInput {
@@ -43,3 +148,10 @@ impl TryFrom<ManualTransactionEntryInput> for ManualEntryInput {
         Ok(builder.build().expect("all fields provided"))
     }
 }
+
+#[derive(InputObject)]
+pub struct ManualTransactionReverseInput {
+    pub manual_transaction_id: UUID,
+    pub effective: Option<Date>,
+}
+crate::mutation_payload! { ManualTransactionReversePayload, manual_transaction: ManualTransaction }
@@ -1,21 +1,31 @@
+mod accounting_period;
 mod balance_sheet;
 mod chart_of_accounts;
+mod comparative_statement;
 mod csv;
 mod journal_entry;
 mod ledger_account;
 mod ledger_transaction;
 mod manual_transaction;
 mod profit_and_loss;
+mod reconciliation;
+mod recurring_journal_entry;
+mod statement_export;
 mod transaction_templates;
 mod trial_balance;
 
+pub use accounting_period::*;
 pub use balance_sheet::*;
 pub use chart_of_accounts::*;
+pub use comparative_statement::*;
 pub use csv::*;
 pub use journal_entry::*;
 pub use ledger_account::*;
 pub use ledger_transaction::*;
 pub use manual_transaction::*;
 pub use profit_and_loss::*;
+pub use reconciliation::*;
+pub use recurring_journal_entry::*;
+pub use statement_export::*;
 pub use transaction_templates::*;
 pub use trial_balance::*;
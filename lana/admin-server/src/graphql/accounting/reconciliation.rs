@@ -0,0 +1,222 @@
+use async_graphql::*;
+
+pub use lana_app::accounting::reconciliation::{
+    LedgerItem as DomainLedgerItem, Reconciliation as DomainReconciliation,
+    ReconciliationLine as DomainReconciliationLine, ReconciliationStatus,
+    ReconciliationsByCreatedAtCursor, StatementFormat, StatementLineState,
+};
+
+use crate::graphql::{loader::LanaDataLoader, primitives::*};
+use crate::primitives::*;
+
+use super::ledger_transaction::LedgerTransaction;
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Reconciliation {
+    id: ID,
+    reconciliation_id: UUID,
+    ledger_account_id: UUID,
+    statement_reference: String,
+    format: StatementFormat,
+    status: ReconciliationStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub entity: Arc<DomainReconciliation>,
+}
+
+impl From<DomainReconciliation> for Reconciliation {
+    fn from(reconciliation: DomainReconciliation) -> Self {
+        Self {
+            id: reconciliation.id.to_global_id(),
+            reconciliation_id: UUID::from(reconciliation.id),
+            ledger_account_id: UUID::from(reconciliation.ledger_account_id),
+            statement_reference: reconciliation.statement_reference.clone(),
+            format: reconciliation.format,
+            status: reconciliation.status(),
+            created_at: reconciliation.created_at().into(),
+            entity: Arc::new(reconciliation),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Reconciliation {
+    async fn lines(&self) -> Vec<ReconciliationLine> {
+        self.entity
+            .reconciliation_lines()
+            .into_iter()
+            .map(ReconciliationLine::from)
+            .collect()
+    }
+
+    async fn unmatched_ledger_items(&self) -> Vec<ReconciliationLedgerItem> {
+        self.entity
+            .unmatched_ledger_items()
+            .into_iter()
+            .cloned()
+            .map(ReconciliationLedgerItem::from)
+            .collect()
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ReconciliationLineStatus {
+    Unmatched,
+    Matched,
+    Exception,
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ReconciliationLine {
+    line_number: u32,
+    booking_date: Date,
+    amount: Decimal,
+    currency: String,
+    reference: Option<String>,
+    description: Option<String>,
+    status: ReconciliationLineStatus,
+    ledger_transaction_id: Option<UUID>,
+    matched_automatically: bool,
+    exception_reason: Option<String>,
+}
+
+impl From<DomainReconciliationLine> for ReconciliationLine {
+    fn from(DomainReconciliationLine { line, state }: DomainReconciliationLine) -> Self {
+        let (status, ledger_transaction_id, matched_automatically, exception_reason) = match state {
+            StatementLineState::Unmatched => {
+                (ReconciliationLineStatus::Unmatched, None, false, None)
+            }
+            StatementLineState::Matched {
+                ledger_transaction_id,
+                automatic,
+            } => (
+                ReconciliationLineStatus::Matched,
+                Some(UUID::from(ledger_transaction_id)),
+                automatic,
+                None,
+            ),
+            StatementLineState::Exception { reason } => (
+                ReconciliationLineStatus::Exception,
+                None,
+                false,
+                Some(reason),
+            ),
+        };
+        Self {
+            line_number: line.line_number,
+            booking_date: line.booking_date.into(),
+            amount: line.amount.into(),
+            currency: line.currency,
+            reference: line.reference,
+            description: line.description,
+            status,
+            ledger_transaction_id,
+            matched_automatically,
+            exception_reason,
+        }
+    }
+}
+
+#[ComplexObject]
+impl ReconciliationLine {
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<LedgerTransaction>> {
+        let Some(ledger_transaction_id) = self.ledger_transaction_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader
+            .load_one(LedgerTransactionId::from(ledger_transaction_id))
+            .await?)
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct ReconciliationLedgerItem {
+    ledger_transaction_id: UUID,
+    effective: Date,
+    amount: Decimal,
+    currency: String,
+    description: Option<String>,
+
+    #[graphql(skip)]
+    item: DomainLedgerItem,
+}
+
+impl From<DomainLedgerItem> for ReconciliationLedgerItem {
+    fn from(item: DomainLedgerItem) -> Self {
+        Self {
+            ledger_transaction_id: UUID::from(item.ledger_transaction_id),
+            effective: item.effective.into(),
+            amount: item.amount.into(),
+            currency: item.currency.clone(),
+            description: item.description.clone(),
+            item,
+        }
+    }
+}
+
+#[ComplexObject]
+impl ReconciliationLedgerItem {
+    async fn ledger_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<LedgerTransaction> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let tx = loader
+            .load_one(self.item.ledger_transaction_id)
+            .await?
+            .expect("ledger transaction not found");
+        Ok(tx)
+    }
+}
+
+#[derive(InputObject)]
+pub struct ReconciliationImportInput {
+    pub ledger_account_id: UUID,
+    pub statement_reference: Option<String>,
+    pub format: StatementFormat,
+    pub file: Upload,
+}
+crate::mutation_payload! { ReconciliationImportPayload, reconciliation: Reconciliation }
+
+#[derive(InputObject)]
+pub struct ReconciliationAutoMatchInput {
+    pub reconciliation_id: UUID,
+}
+crate::mutation_payload! { ReconciliationAutoMatchPayload, reconciliation: Reconciliation }
+
+#[derive(InputObject)]
+pub struct ReconciliationLineMatchInput {
+    pub reconciliation_id: UUID,
+    pub line_number: u32,
+    pub ledger_transaction_id: UUID,
+}
+crate::mutation_payload! { ReconciliationLineMatchPayload, reconciliation: Reconciliation }
+
+#[derive(InputObject)]
+pub struct ReconciliationLineMarkExceptionInput {
+    pub reconciliation_id: UUID,
+    pub line_number: u32,
+    pub reason: String,
+}
+crate::mutation_payload! { ReconciliationLineMarkExceptionPayload, reconciliation: Reconciliation }
+
+#[derive(InputObject)]
+pub struct ReconciliationLineResetInput {
+    pub reconciliation_id: UUID,
+    pub line_number: u32,
+}
+crate::mutation_payload! { ReconciliationLineResetPayload, reconciliation: Reconciliation }
+
+#[derive(InputObject)]
+pub struct ReconciliationCompleteInput {
+    pub reconciliation_id: UUID,
+}
+crate::mutation_payload! { ReconciliationCompletePayload, reconciliation: Reconciliation }
@@ -0,0 +1,129 @@
+use async_graphql::*;
+
+pub use lana_app::accounting::recurring_journal_entry::{
+    RecurrenceFrequency, RecurrenceSchedule, RecurringJournalEntriesByCreatedAtCursor,
+    RecurringJournalEntry as DomainRecurringJournalEntry,
+    RecurringJournalEntryOccurrence as DomainRecurringJournalEntryOccurrence,
+    RecurringJournalEntryStatus,
+};
+
+use crate::graphql::loader::LanaDataLoader;
+use crate::primitives::*;
+
+use super::manual_transaction::{
+    ManualTransaction, ManualTransactionEntry, ManualTransactionEntryInput,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct RecurringJournalEntry {
+    id: ID,
+    recurring_journal_entry_id: UUID,
+    reference: String,
+    description: String,
+    frequency: RecurrenceFrequency,
+    starts_on: Date,
+    next_occurrence: Date,
+    status: RecurringJournalEntryStatus,
+    entries: Vec<ManualTransactionEntry>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub entity: Arc<DomainRecurringJournalEntry>,
+}
+
+impl From<DomainRecurringJournalEntry> for RecurringJournalEntry {
+    fn from(entry: DomainRecurringJournalEntry) -> Self {
+        Self {
+            id: entry.id.to_global_id(),
+            recurring_journal_entry_id: UUID::from(entry.id),
+            reference: entry.reference.clone(),
+            description: entry.description.clone(),
+            frequency: entry.schedule.frequency,
+            starts_on: entry.schedule.starts_on.into(),
+            next_occurrence: entry.next_occurrence.into(),
+            status: entry.status(),
+            entries: entry
+                .entries
+                .iter()
+                .cloned()
+                .map(ManualTransactionEntry::from)
+                .collect(),
+            created_at: entry.created_at().into(),
+            entity: Arc::new(entry),
+        }
+    }
+}
+
+#[ComplexObject]
+impl RecurringJournalEntry {
+    async fn occurrences(&self) -> Vec<RecurringJournalEntryOccurrence> {
+        self.entity
+            .occurrences()
+            .into_iter()
+            .map(RecurringJournalEntryOccurrence::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct RecurringJournalEntryOccurrence {
+    effective: Date,
+    manual_transaction_id: Option<UUID>,
+    skip_reason: Option<String>,
+
+    #[graphql(skip)]
+    occurrence: DomainRecurringJournalEntryOccurrence,
+}
+
+impl From<DomainRecurringJournalEntryOccurrence> for RecurringJournalEntryOccurrence {
+    fn from(occurrence: DomainRecurringJournalEntryOccurrence) -> Self {
+        Self {
+            effective: occurrence.effective.into(),
+            manual_transaction_id: occurrence.manual_transaction_id.map(UUID::from),
+            skip_reason: occurrence.skip_reason.clone(),
+            occurrence,
+        }
+    }
+}
+
+#[ComplexObject]
+impl RecurringJournalEntryOccurrence {
+    async fn manual_transaction(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ManualTransaction>> {
+        let Some(manual_transaction_id) = self.occurrence.manual_transaction_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let tx = loader
+            .load_one(manual_transaction_id)
+            .await?
+            .expect("manual transaction not found");
+        Ok(Some(tx))
+    }
+}
+
+#[derive(InputObject)]
+pub struct RecurringJournalEntryCreateInput {
+    pub description: String,
+    pub reference: Option<String>,
+    pub frequency: RecurrenceFrequency,
+    pub starts_on: Date,
+    pub entries: Vec<ManualTransactionEntryInput>,
+}
+crate::mutation_payload! { RecurringJournalEntryCreatePayload, recurring_journal_entry: RecurringJournalEntry }
+
+#[derive(InputObject)]
+pub struct RecurringJournalEntryPauseInput {
+    pub recurring_journal_entry_id: UUID,
+}
+crate::mutation_payload! { RecurringJournalEntryPausePayload, recurring_journal_entry: RecurringJournalEntry }
+
+#[derive(InputObject)]
+pub struct RecurringJournalEntryResumeInput {
+    pub recurring_journal_entry_id: UUID,
+}
+crate::mutation_payload! { RecurringJournalEntryResumePayload, recurring_journal_entry: RecurringJournalEntry }
@@ -0,0 +1,69 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+pub use lana_app::{
+    accounting::statement_export::{StatementExportFormat, StatementType},
+    document::{Document as DomainDocument, DocumentStatus, GeneratedDocumentDownloadLink},
+};
+use std::sync::Arc;
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct StatementExport {
+    id: ID,
+    document_id: UUID,
+    status: DocumentStatus,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub entity: Arc<DomainDocument>,
+}
+
+impl From<DomainDocument> for StatementExport {
+    fn from(document: DomainDocument) -> Self {
+        Self {
+            id: document.id.to_global_id(),
+            document_id: UUID::from(document.id),
+            status: document.status,
+            created_at: document.created_at().into(),
+            entity: Arc::new(document),
+        }
+    }
+}
+
+#[ComplexObject]
+impl StatementExport {
+    async fn filename(&self) -> &str {
+        &self.entity.filename
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct StatementExportDownloadLink {
+    pub url: String,
+    pub document_id: UUID,
+}
+
+impl From<GeneratedDocumentDownloadLink> for StatementExportDownloadLink {
+    fn from(result: GeneratedDocumentDownloadLink) -> Self {
+        Self {
+            url: result.link,
+            document_id: UUID::from(result.document_id),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct StatementExportCreateInput {
+    pub statement_type: StatementType,
+    pub from: Date,
+    pub until: Date,
+    pub format: StatementExportFormat,
+}
+crate::mutation_payload! { StatementExportCreatePayload, statement_export: StatementExport }
+
+#[derive(InputObject)]
+pub struct StatementExportDownloadLinkGenerateInput {
+    pub document_id: UUID,
+}
+crate::mutation_payload! { StatementExportDownloadLinkGeneratePayload, link: StatementExportDownloadLink }
@@ -3,8 +3,8 @@ use async_graphql::*;
 use crate::primitives::*;
 
 use super::{
-    access::User, approval_rules::*, credit_facility::*, loader::LanaDataLoader, policy::*,
-    withdrawal::*,
+    access::User, accounting::ManualTransaction, approval_rules::*, credit_facility::*,
+    loader::LanaDataLoader, policy::*, transfer::*, withdrawal::*,
 };
 
 pub use lana_app::governance::{
@@ -129,7 +129,8 @@ impl ApprovalProcess {
     async fn target(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcessTarget> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         match self.approval_process_type {
-            ApprovalProcessType::WithdrawalApproval => {
+            ApprovalProcessType::WithdrawalApproval
+            | ApprovalProcessType::WithdrawalOverLimitApproval => {
                 let withdrawal = loader
                     .load_one(
                         self.entity
@@ -141,7 +142,21 @@ impl ApprovalProcess {
                     .expect("withdrawal not found");
                 Ok(ApprovalProcessTarget::Withdrawal(withdrawal))
             }
-            ApprovalProcessType::CreditFacilityApproval => {
+            ApprovalProcessType::TransferApproval => {
+                let transfer = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<TransferId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("transfer not found");
+                Ok(ApprovalProcessTarget::Transfer(transfer))
+            }
+            ApprovalProcessType::CreditFacilityApproval
+            | ApprovalProcessType::CreditFacilityRestructuringApproval
+            | ApprovalProcessType::CreditFacilityRolloverApproval => {
                 let credit_facility = loader
                     .load_one(
                         self.entity
@@ -165,6 +180,32 @@ impl ApprovalProcess {
                     .expect("disbursal not found");
                 Ok(ApprovalProcessTarget::CreditFacilityDisbursal(disbursal))
             }
+            ApprovalProcessType::CollateralReleaseApproval => {
+                let collateral_release = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<CollateralReleaseId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("collateral release not found");
+                Ok(ApprovalProcessTarget::CreditFacilityCollateralRelease(
+                    collateral_release,
+                ))
+            }
+            ApprovalProcessType::ManualTransactionApproval => {
+                let manual_transaction = loader
+                    .load_one(
+                        self.entity
+                            .target_ref()
+                            .parse::<ManualTransactionId>()
+                            .expect("invalid target ref"),
+                    )
+                    .await?
+                    .expect("manual transaction not found");
+                Ok(ApprovalProcessTarget::ManualTransaction(manual_transaction))
+            }
         }
     }
 }
@@ -173,18 +214,38 @@ impl ApprovalProcess {
 #[allow(clippy::enum_variant_names)]
 pub enum ApprovalProcessType {
     WithdrawalApproval,
+    WithdrawalOverLimitApproval,
+    TransferApproval,
     CreditFacilityApproval,
+    CreditFacilityRestructuringApproval,
+    CreditFacilityRolloverApproval,
     DisbursalApproval,
+    CollateralReleaseApproval,
+    ManualTransactionApproval,
 }
 
 impl From<&DomainApprovalProcessType> for ApprovalProcessType {
     fn from(process_type: &DomainApprovalProcessType) -> Self {
         if process_type == &lana_app::governance::APPROVE_WITHDRAWAL_PROCESS {
             Self::WithdrawalApproval
+        } else if process_type == &lana_app::governance::APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS {
+            Self::WithdrawalOverLimitApproval
+        } else if process_type == &lana_app::governance::APPROVE_TRANSFER_PROCESS {
+            Self::TransferApproval
         } else if process_type == &lana_app::governance::APPROVE_CREDIT_FACILITY_PROCESS {
             Self::CreditFacilityApproval
+        } else if process_type
+            == &lana_app::governance::APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS
+        {
+            Self::CreditFacilityRestructuringApproval
+        } else if process_type == &lana_app::governance::APPROVE_CREDIT_FACILITY_ROLLOVER_PROCESS {
+            Self::CreditFacilityRolloverApproval
         } else if process_type == &lana_app::governance::APPROVE_DISBURSAL_PROCESS {
             Self::DisbursalApproval
+        } else if process_type == &lana_app::governance::APPROVE_COLLATERAL_RELEASE_PROCESS {
+            Self::CollateralReleaseApproval
+        } else if process_type == &lana_app::governance::APPROVE_MANUAL_TRANSACTION_PROCESS {
+            Self::ManualTransactionApproval
         } else {
             panic!("Unknown approval process type: {process_type:?}");
         }
@@ -219,8 +280,11 @@ impl ApprovalProcessVoter {
 #[derive(async_graphql::Union)]
 pub(super) enum ApprovalProcessTarget {
     Withdrawal(Withdrawal),
+    Transfer(Transfer),
     CreditFacility(CreditFacility),
     CreditFacilityDisbursal(CreditFacilityDisbursal),
+    CreditFacilityCollateralRelease(CreditFacilityCollateralRelease),
+    ManualTransaction(ManualTransaction),
 }
 
 #[derive(InputObject)]
@@ -15,6 +15,12 @@ pub struct CreditModuleConfig {
     chart_of_account_in_liquidation_parent_code: Option<String>,
     chart_of_account_interest_income_parent_code: Option<String>,
     chart_of_account_fee_income_parent_code: Option<String>,
+    chart_of_account_unapplied_funds_parent_code: Option<String>,
+    chart_of_account_penalty_receivable_parent_code: Option<String>,
+    chart_of_account_penalty_income_parent_code: Option<String>,
+    chart_of_account_collateral_in_liquidation_parent_code: Option<String>,
+    chart_of_account_liquidation_proceeds_parent_code: Option<String>,
+    chart_of_account_liquidation_proceeds_omnibus_parent_code: Option<String>,
 
     chart_of_account_short_term_individual_disbursed_receivable_parent_code: Option<String>,
     chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: Option<String>,
@@ -109,6 +115,30 @@ impl From<DomainChartOfAccountsIntegrationConfig> for CreditModuleConfig {
             chart_of_account_fee_income_parent_code: Some(
                 values.chart_of_account_fee_income_parent_code.to_string(),
             ),
+            chart_of_account_unapplied_funds_parent_code: values
+                .chart_of_account_unapplied_funds_parent_code
+                .as_ref()
+                .map(ToString::to_string),
+            chart_of_account_penalty_receivable_parent_code: values
+                .chart_of_account_penalty_receivable_parent_code
+                .as_ref()
+                .map(ToString::to_string),
+            chart_of_account_penalty_income_parent_code: values
+                .chart_of_account_penalty_income_parent_code
+                .as_ref()
+                .map(ToString::to_string),
+            chart_of_account_collateral_in_liquidation_parent_code: values
+                .chart_of_account_collateral_in_liquidation_parent_code
+                .as_ref()
+                .map(ToString::to_string),
+            chart_of_account_liquidation_proceeds_parent_code: values
+                .chart_of_account_liquidation_proceeds_parent_code
+                .as_ref()
+                .map(ToString::to_string),
+            chart_of_account_liquidation_proceeds_omnibus_parent_code: values
+                .chart_of_account_liquidation_proceeds_omnibus_parent_code
+                .as_ref()
+                .map(ToString::to_string),
 
             chart_of_account_short_term_individual_disbursed_receivable_parent_code: Some(
                 values
@@ -305,6 +335,12 @@ pub struct CreditModuleConfigureInput {
     pub chart_of_account_in_liquidation_parent_code: String,
     pub chart_of_account_interest_income_parent_code: String,
     pub chart_of_account_fee_income_parent_code: String,
+    pub chart_of_account_unapplied_funds_parent_code: Option<String>,
+    pub chart_of_account_penalty_receivable_parent_code: Option<String>,
+    pub chart_of_account_penalty_income_parent_code: Option<String>,
+    pub chart_of_account_collateral_in_liquidation_parent_code: Option<String>,
+    pub chart_of_account_liquidation_proceeds_parent_code: Option<String>,
+    pub chart_of_account_liquidation_proceeds_omnibus_parent_code: Option<String>,
 
     pub chart_of_account_short_term_individual_disbursed_receivable_parent_code: String,
     pub chart_of_account_short_term_government_entity_disbursed_receivable_parent_code: String,
@@ -7,6 +7,7 @@ pub(super) struct CreditFacilityBalance {
     facility_remaining: FacilityRemaining,
     disbursed: Disbursed,
     interest: Interest,
+    penalty: Penalty,
     outstanding: Outstanding,
     outstanding_payable: Outstanding,
     due_outstanding: Outstanding,
@@ -41,6 +42,14 @@ impl From<lana_app::credit::CreditFacilityBalanceSummary> for CreditFacilityBala
                     usd_balance: balance.interest_outstanding_payable(),
                 },
             },
+            penalty: Penalty {
+                total: Total {
+                    usd_balance: balance.penalty_posted(),
+                },
+                outstanding: Outstanding {
+                    usd_balance: balance.penalty_outstanding(),
+                },
+            },
             outstanding: Outstanding {
                 usd_balance: balance.total_outstanding(),
             },
@@ -90,3 +99,9 @@ pub struct Interest {
     pub outstanding: Outstanding,
     pub outstanding_payable: Outstanding,
 }
+
+#[derive(SimpleObject)]
+pub struct Penalty {
+    pub total: Total,
+    pub outstanding: Outstanding,
+}
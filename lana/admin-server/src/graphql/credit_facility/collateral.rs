@@ -1,13 +1,20 @@
 use async_graphql::*;
 
-use crate::primitives::*;
-pub use lana_app::credit::Collateral as DomainCollateral;
+use crate::{graphql::primitives::Decimal, primitives::*};
+pub use lana_app::{credit::Collateral as DomainCollateral, price::CollateralAsset};
 
 #[derive(SimpleObject, Clone)]
 pub struct Collateral {
     id: ID,
     collateral_id: UUID,
     pub(crate) wallet_id: Option<UUID>,
+    pub(crate) asset_positions: Vec<CollateralAssetPosition>,
+}
+
+#[derive(SimpleObject, Clone)]
+pub struct CollateralAssetPosition {
+    asset: CollateralAsset,
+    quantity: Decimal,
 }
 
 impl From<DomainCollateral> for Collateral {
@@ -16,6 +23,14 @@ impl From<DomainCollateral> for Collateral {
             id: collateral.id.to_global_id(),
             collateral_id: collateral.id.into(),
             wallet_id: collateral.custody_wallet_id.map(|id| id.into()),
+            asset_positions: collateral
+                .asset_positions()
+                .into_iter()
+                .map(|(asset, quantity)| CollateralAssetPosition {
+                    asset,
+                    quantity: quantity.into(),
+                })
+                .collect(),
         }
     }
 }
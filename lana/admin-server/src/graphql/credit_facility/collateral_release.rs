@@ -0,0 +1,72 @@
+use async_graphql::*;
+
+use crate::{
+    graphql::{approval_process::*, loader::LanaDataLoader},
+    primitives::*,
+};
+
+pub use lana_app::credit::CollateralRelease as DomainCollateralRelease;
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct CreditFacilityCollateralRelease {
+    id: ID,
+    collateral_release_id: UUID,
+    amount: Satoshis,
+    address: String,
+    status: CollateralReleaseStatus,
+    external_transfer_id: Option<String>,
+    failure_reason: Option<String>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainCollateralRelease>,
+}
+
+impl From<DomainCollateralRelease> for CreditFacilityCollateralRelease {
+    fn from(release: DomainCollateralRelease) -> Self {
+        Self {
+            id: release.id.to_global_id(),
+            collateral_release_id: UUID::from(release.id),
+            amount: release.amount,
+            address: release.address.clone(),
+            status: release.status(),
+            external_transfer_id: release.external_transfer_id().map(str::to_owned),
+            failure_reason: release.failure_reason().map(str::to_owned),
+            created_at: release.created_at().into(),
+            entity: Arc::new(release),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CreditFacilityCollateralRelease {
+    async fn credit_facility(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<super::CreditFacility> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let facility = loader
+            .load_one(self.entity.credit_facility_id)
+            .await?
+            .expect("facility should exist for a collateral release");
+        Ok(facility)
+    }
+
+    async fn approval_process(&self, ctx: &Context<'_>) -> async_graphql::Result<ApprovalProcess> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let process = loader
+            .load_one(self.entity.approval_process_id)
+            .await?
+            .expect("process not found");
+        Ok(process)
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditFacilityCollateralReleaseRequestInput {
+    pub credit_facility_id: UUID,
+    pub amount: Satoshis,
+    pub address: String,
+}
+crate::mutation_payload! { CreditFacilityCollateralReleaseRequestPayload, collateral_release: CreditFacilityCollateralRelease }
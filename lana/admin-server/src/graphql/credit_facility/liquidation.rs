@@ -0,0 +1,67 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::credit::LiquidationProcess as DomainLiquidationProcess;
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct CreditFacilityLiquidation {
+    id: ID,
+    liquidation_process_id: UUID,
+    initial_amount: UsdCents,
+    collateral_reserved: Option<Satoshis>,
+    proceeds: Option<UsdCents>,
+    surplus_returned: UsdCents,
+    completed: bool,
+
+    #[graphql(skip)]
+    pub(crate) entity: Arc<DomainLiquidationProcess>,
+}
+
+impl From<DomainLiquidationProcess> for CreditFacilityLiquidation {
+    fn from(liquidation_process: DomainLiquidationProcess) -> Self {
+        Self {
+            id: liquidation_process.id.to_global_id(),
+            liquidation_process_id: UUID::from(liquidation_process.id),
+            initial_amount: liquidation_process.initial_amount,
+            collateral_reserved: liquidation_process.collateral_reserved(),
+            proceeds: liquidation_process.proceeds(),
+            surplus_returned: liquidation_process.surplus_returned(),
+            completed: liquidation_process.is_completed(),
+            entity: Arc::new(liquidation_process),
+        }
+    }
+}
+
+#[ComplexObject]
+impl CreditFacilityLiquidation {
+    async fn credit_facility(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<super::CreditFacility> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let cf = app
+            .credit()
+            .for_subject(sub)?
+            .find_by_id(self.entity.credit_facility_id)
+            .await?
+            .expect("facility should exist for a liquidation");
+        Ok(super::CreditFacility::from(cf))
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreditFacilityLiquidationCollateralReserveInput {
+    pub liquidation_process_id: UUID,
+    pub collateral: Satoshis,
+}
+crate::mutation_payload! { CreditFacilityLiquidationCollateralReservePayload, liquidation: CreditFacilityLiquidation }
+
+#[derive(InputObject)]
+pub struct CreditFacilityLiquidationSaleRecordInput {
+    pub liquidation_process_id: UUID,
+    pub usd_cents_per_btc: UsdCents,
+}
+crate::mutation_payload! { CreditFacilityLiquidationSaleRecordPayload, liquidation: CreditFacilityLiquidation }
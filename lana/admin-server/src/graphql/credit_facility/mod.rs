@@ -1,9 +1,12 @@
 mod balance;
 mod collateral;
+pub(super) mod collateral_release;
 pub(super) mod disbursal;
 mod error;
 mod history;
+mod liquidation;
 pub(super) mod obligation_installment;
+mod payment_allocation;
 mod repayment;
 
 use async_graphql::*;
@@ -11,8 +14,12 @@ use async_graphql::*;
 use crate::primitives::*;
 
 use super::{
-    approval_process::*, custody::Wallet, customer::*, loader::LanaDataLoader,
-    primitives::SortDirection, terms::*,
+    approval_process::*,
+    custody::Wallet,
+    customer::*,
+    loader::LanaDataLoader,
+    primitives::{Decimal, SortDirection},
+    terms::*,
 };
 pub use lana_app::{
     credit::{
@@ -28,9 +35,12 @@ pub use lana_app::{
 
 pub use balance::*;
 pub use collateral::*;
+pub use collateral_release::*;
 pub use disbursal::*;
 pub use error::*;
 pub use history::*;
+pub use liquidation::*;
+pub use payment_allocation::*;
 pub use repayment::*;
 
 #[derive(SimpleObject, Clone)]
@@ -80,7 +90,19 @@ impl CreditFacility {
     }
 
     async fn credit_facility_terms(&self) -> TermValues {
-        self.entity.terms.into()
+        self.entity.terms.clone().into()
+    }
+
+    async fn unapplied_funds(&self) -> UsdCents {
+        self.entity.unapplied_funds()
+    }
+
+    async fn rolled_over_from_credit_facility_id(&self) -> Option<UUID> {
+        self.entity.rolled_over_from().map(UUID::from)
+    }
+
+    async fn rolled_over_to_credit_facility_id(&self) -> Option<UUID> {
+        self.entity.rolled_over_to().map(UUID::from)
     }
 
     async fn status(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacilityStatus> {
@@ -114,6 +136,49 @@ impl CreditFacility {
         Ok(app.credit().repayment_plan(sub, self.entity.id).await?)
     }
 
+    async fn liquidations(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CreditFacilityLiquidation>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .obligations()
+            .list_liquidation_processes_for_credit_facility(sub, self.entity.id)
+            .await?
+            .into_iter()
+            .map(CreditFacilityLiquidation::from)
+            .collect())
+    }
+
+    async fn collateral_releases(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CreditFacilityCollateralRelease>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .collateral_releases()
+            .list_for_credit_facility(sub, self.entity.id)
+            .await?
+            .into_iter()
+            .map(CreditFacilityCollateralRelease::from)
+            .collect())
+    }
+
+    async fn payment_allocation_preview(
+        &self,
+        ctx: &Context<'_>,
+        amount: UsdCents,
+    ) -> async_graphql::Result<PaymentAllocationPreview> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .credit()
+            .preview_payment_allocation(sub, self.entity.id, amount)
+            .await?
+            .into())
+    }
+
     async fn disbursals(
         &self,
         ctx: &Context<'_>,
@@ -201,6 +266,19 @@ impl CreditFacility {
         Ok(CreditFacilityBalance::from(balance))
     }
 
+    async fn collateral_asset_positions(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<CollateralAssetPosition>> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let collateral = loader
+            .load_one(self.entity.collateral_id)
+            .await?
+            .expect("credit facility has collateral");
+
+        Ok(collateral.asset_positions)
+    }
+
     async fn wallet(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Wallet>> {
         let loader = ctx.data_unchecked::<LanaDataLoader>();
         let collateral = loader
@@ -226,6 +304,23 @@ pub struct CreditFacilityCreateInput {
 }
 crate::mutation_payload! { CreditFacilityCreatePayload, credit_facility: CreditFacility }
 
+#[derive(InputObject)]
+pub struct CreditFacilityRestructureInput {
+    pub credit_facility_id: UUID,
+    pub terms: TermsInput,
+}
+crate::mutation_payload! { CreditFacilityRestructurePayload, credit_facility: CreditFacility }
+
+#[derive(InputObject)]
+pub struct CreditFacilityRolloverInput {
+    pub credit_facility_id: UUID,
+    pub facility: UsdCents,
+    pub terms: TermsInput,
+    #[graphql(default)]
+    pub capitalize_interest: bool,
+}
+crate::mutation_payload! { CreditFacilityRolloverPayload, credit_facility: CreditFacility }
+
 #[derive(InputObject)]
 pub struct CreditFacilityCollateralUpdateInput {
     pub credit_facility_id: UUID,
@@ -234,6 +329,14 @@ pub struct CreditFacilityCollateralUpdateInput {
 }
 crate::mutation_payload! { CreditFacilityCollateralUpdatePayload, credit_facility: CreditFacility }
 
+#[derive(InputObject)]
+pub struct CreditFacilityCollateralAssetUpdateInput {
+    pub credit_facility_id: UUID,
+    pub asset: CollateralAsset,
+    pub quantity: Decimal,
+}
+crate::mutation_payload! { CreditFacilityCollateralAssetUpdatePayload, credit_facility: CreditFacility }
+
 #[derive(InputObject)]
 pub struct CreditFacilityPartialPaymentInput {
     pub credit_facility_id: UUID,
@@ -242,6 +345,21 @@ pub struct CreditFacilityPartialPaymentInput {
 }
 crate::mutation_payload! { CreditFacilityPartialPaymentPayload, credit_facility: CreditFacility }
 
+#[derive(InputObject)]
+pub struct CreditFacilityPrepaymentInput {
+    pub credit_facility_id: UUID,
+    pub amount: UsdCents,
+    pub effective: Date,
+}
+crate::mutation_payload! { CreditFacilityPrepaymentPayload, credit_facility: CreditFacility }
+
+#[derive(InputObject)]
+pub struct CreditFacilityUnappliedFundsRefundInput {
+    pub credit_facility_id: UUID,
+    pub amount: UsdCents,
+}
+crate::mutation_payload! { CreditFacilityUnappliedFundsRefundPayload, credit_facility: CreditFacility }
+
 #[derive(InputObject)]
 pub struct CreditFacilityCompleteInput {
     pub credit_facility_id: UUID,
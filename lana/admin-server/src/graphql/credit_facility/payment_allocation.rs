@@ -0,0 +1,90 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+pub use lana_app::{
+    credit::{
+        ObligationStatus as DomainObligationStatus, ObligationType as DomainObligationType,
+        PaymentAllocationPreview as DomainPaymentAllocationPreview,
+        PaymentAllocationPreviewEntry as DomainPaymentAllocationPreviewEntry,
+    },
+    terms::PaymentAllocationStrategy,
+};
+
+#[derive(async_graphql::Enum, Clone, Copy, PartialEq, Eq)]
+pub enum ObligationType {
+    Disbursal,
+    Interest,
+    Penalty,
+}
+
+impl From<DomainObligationType> for ObligationType {
+    fn from(obligation_type: DomainObligationType) -> Self {
+        match obligation_type {
+            DomainObligationType::Disbursal => Self::Disbursal,
+            DomainObligationType::Interest => Self::Interest,
+            DomainObligationType::Penalty => Self::Penalty,
+        }
+    }
+}
+
+#[derive(async_graphql::Enum, Clone, Copy, PartialEq, Eq)]
+pub enum ObligationStatus {
+    NotYetDue,
+    Due,
+    Overdue,
+    Defaulted,
+    Paid,
+}
+
+impl From<DomainObligationStatus> for ObligationStatus {
+    fn from(status: DomainObligationStatus) -> Self {
+        match status {
+            DomainObligationStatus::NotYetDue => Self::NotYetDue,
+            DomainObligationStatus::Due => Self::Due,
+            DomainObligationStatus::Overdue => Self::Overdue,
+            DomainObligationStatus::Defaulted => Self::Defaulted,
+            DomainObligationStatus::Paid => Self::Paid,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PaymentAllocationPreviewEntry {
+    obligation_id: UUID,
+    obligation_type: ObligationType,
+    status: ObligationStatus,
+    outstanding: UsdCents,
+    allocated: UsdCents,
+}
+
+impl From<DomainPaymentAllocationPreviewEntry> for PaymentAllocationPreviewEntry {
+    fn from(entry: DomainPaymentAllocationPreviewEntry) -> Self {
+        Self {
+            obligation_id: UUID::from(entry.obligation_id),
+            obligation_type: entry.obligation_type.into(),
+            status: entry.status.into(),
+            outstanding: entry.outstanding,
+            allocated: entry.allocated,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PaymentAllocationPreview {
+    strategy: PaymentAllocationStrategy,
+    amount: UsdCents,
+    unallocated: UsdCents,
+    entries: Vec<PaymentAllocationPreviewEntry>,
+}
+
+impl From<DomainPaymentAllocationPreview> for PaymentAllocationPreview {
+    fn from(preview: DomainPaymentAllocationPreview) -> Self {
+        Self {
+            strategy: preview.strategy,
+            amount: preview.amount,
+            unallocated: preview.unallocated,
+            entries: preview.entries.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::primitives::*;
 pub enum CreditFacilityRepaymentType {
     Disbursal,
     Interest,
+    Penalty,
 }
 
 impl From<lana_app::credit::RepaymentType> for CreditFacilityRepaymentType {
@@ -13,6 +14,7 @@ impl From<lana_app::credit::RepaymentType> for CreditFacilityRepaymentType {
         match repayment_type {
             lana_app::credit::RepaymentType::Disbursal => Self::Disbursal,
             lana_app::credit::RepaymentType::Interest => Self::Interest,
+            lana_app::credit::RepaymentType::Penalty => Self::Penalty,
         }
     }
 }
@@ -2,7 +2,7 @@ use async_graphql::*;
 
 use crate::primitives::*;
 
-use super::loader::LanaDataLoader;
+use super::{loader::LanaDataLoader, primitives::minor_units};
 
 pub use super::deposit_account::DepositAccount;
 
@@ -15,6 +15,7 @@ pub struct Deposit {
     deposit_id: UUID,
     account_id: UUID,
     amount: UsdCents,
+    currency: String,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -27,7 +28,8 @@ impl From<DomainDeposit> for Deposit {
             id: deposit.id.to_global_id(),
             deposit_id: UUID::from(deposit.id),
             account_id: UUID::from(deposit.deposit_account_id),
-            amount: deposit.amount,
+            amount: minor_units(&deposit.amount),
+            currency: deposit.amount.currency().to_string(),
             created_at: deposit.created_at().into(),
 
             entity: Arc::new(deposit),
@@ -59,6 +61,7 @@ impl Deposit {
 pub struct DepositRecordInput {
     pub deposit_account_id: UUID,
     pub amount: UsdCents,
+    pub currency: Option<String>,
     pub reference: Option<String>,
 }
 crate::mutation_payload! { DepositRecordPayload, deposit: Deposit }
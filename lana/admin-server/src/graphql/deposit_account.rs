@@ -7,7 +7,10 @@ pub use lana_app::deposit::{
     DepositAccountHistoryEntry as DomainDepositAccountHistoryEntry,
 };
 
-use super::{customer::Customer, deposit::*, deposit_account_history::*, withdrawal::*};
+use super::{
+    customer::Customer, deposit::*, deposit_account_history::*, deposit_product::*, hold::*,
+    loader::LanaDataLoader, primitives::minor_units, standing_order::*, transfer::*, withdrawal::*,
+};
 
 #[derive(SimpleObject, Clone)]
 #[graphql(complex)]
@@ -15,6 +18,7 @@ pub struct DepositAccount {
     id: ID,
     deposit_account_id: UUID,
     customer_id: UUID,
+    currency: String,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -27,6 +31,7 @@ impl From<DomainDepositAccount> for DepositAccount {
             id: account.id.to_global_id(),
             deposit_account_id: account.id.into(),
             customer_id: account.account_holder_id.into(),
+            currency: account.currency.to_string(),
             created_at: account.created_at().into(),
 
             entity: Arc::new(account),
@@ -38,13 +43,19 @@ impl From<DomainDepositAccount> for DepositAccount {
 pub struct DepositAccountBalance {
     settled: UsdCents,
     pending: UsdCents,
+    held: UsdCents,
+    available: UsdCents,
+    currency: String,
 }
 
 impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
     fn from(balance: lana_app::deposit::DepositAccountBalance) -> Self {
         Self {
-            settled: balance.settled,
-            pending: balance.pending,
+            settled: minor_units(&balance.settled),
+            pending: minor_units(&balance.pending),
+            held: minor_units(&balance.held),
+            available: minor_units(&balance.available()),
+            currency: balance.settled.currency().to_string(),
         }
     }
 }
@@ -73,6 +84,49 @@ impl DepositAccount {
         Ok(withdrawals.into_iter().map(Withdrawal::from).collect())
     }
 
+    async fn transfers(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Transfer>> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        let transfers = app
+            .deposits()
+            .list_transfers_for_account(sub, self.entity.id)
+            .await?;
+        Ok(transfers.into_iter().map(Transfer::from).collect())
+    }
+
+    async fn holds(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<Connection<HoldsByCreatedAtCursor, Hold, EmptyFields, EmptyFields>>
+    {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        crate::list_with_cursor!(HoldsByCreatedAtCursor, Hold, ctx, after, first, |query| app
+            .deposits()
+            .list_holds_for_account(sub, self.entity.id, query))
+    }
+
+    async fn standing_orders(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<StandingOrdersByCreatedAtCursor, StandingOrder, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+        crate::list_with_cursor!(
+            StandingOrdersByCreatedAtCursor,
+            StandingOrder,
+            ctx,
+            after,
+            first,
+            |query| app
+                .deposits()
+                .list_standing_orders_for_account(sub, self.entity.id, query)
+        )
+    }
+
     async fn history(
         &self,
         ctx: &Context<'_>,
@@ -133,4 +187,21 @@ impl DepositAccount {
 
         Ok(Customer::from(customer))
     }
+    async fn deposit_product(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<DepositProduct>> {
+        let Some(deposit_product_id) = self.entity.deposit_product_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(deposit_product_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct DepositAccountAssignProductInput {
+    pub deposit_account_id: UUID,
+    pub deposit_product_id: UUID,
 }
+crate::mutation_payload! { DepositAccountAssignProductPayload, account: DepositAccount }
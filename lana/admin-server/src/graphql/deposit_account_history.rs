@@ -8,6 +8,7 @@ use super::{
         obligation_installment::CreditFacilityObligationInstallment,
     },
     deposit::Deposit,
+    transfer::Transfer,
     withdrawal::Withdrawal,
 };
 
@@ -16,8 +17,11 @@ pub enum DepositAccountHistoryEntry {
     Deposit(DepositEntry),
     Withdrawal(WithdrawalEntry),
     CancelledWithdrawal(CancelledWithdrawalEntry),
+    TransferIn(TransferInEntry),
+    TransferOut(TransferOutEntry),
     Disbursal(DisbursalEntry),
     Payment(PaymentEntry),
+    Interest(InterestEntry),
     Unknown(UnknownEntry),
 }
 
@@ -44,6 +48,22 @@ pub struct CancelledWithdrawalEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct TransferInEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct TransferOutEntry {
+    #[graphql(skip)]
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 #[graphql(complex)]
 pub struct DisbursalEntry {
@@ -60,6 +80,12 @@ pub struct PaymentEntry {
     pub recorded_at: Timestamp,
 }
 
+#[derive(SimpleObject)]
+pub struct InterestEntry {
+    pub tx_id: UUID,
+    pub recorded_at: Timestamp,
+}
+
 #[derive(SimpleObject)]
 pub struct UnknownEntry {
     pub tx_id: UUID,
@@ -109,6 +135,37 @@ impl CancelledWithdrawalEntry {
         Ok(Withdrawal::from(withdrawal))
     }
 }
+
+#[ComplexObject]
+impl TransferInEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let transfer = app
+            .deposits()
+            .find_transfer_by_id(sub, self.tx_id)
+            .await?
+            .expect("transfer should exist");
+
+        Ok(Transfer::from(transfer))
+    }
+}
+
+#[ComplexObject]
+impl TransferOutEntry {
+    async fn transfer(&self, ctx: &Context<'_>) -> async_graphql::Result<Transfer> {
+        let (app, sub) = crate::app_and_sub_from_ctx!(ctx);
+
+        let transfer = app
+            .deposits()
+            .find_transfer_by_id(sub, self.tx_id)
+            .await?
+            .expect("transfer should exist");
+
+        Ok(Transfer::from(transfer))
+    }
+}
+
 #[ComplexObject]
 impl DisbursalEntry {
     async fn disbursal(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacilityDisbursal> {
@@ -163,6 +220,18 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferIn(entry) => {
+                Self::TransferIn(TransferInEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
+            lana_app::deposit::DepositAccountHistoryEntry::TransferOut(entry) => {
+                Self::TransferOut(TransferOutEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Disbursal(entry) => {
                 Self::Disbursal(DisbursalEntry {
                     tx_id: UUID::from(entry.tx_id),
@@ -175,6 +244,12 @@ impl From<lana_app::deposit::DepositAccountHistoryEntry> for DepositAccountHisto
                     recorded_at: entry.recorded_at.into(),
                 })
             }
+            lana_app::deposit::DepositAccountHistoryEntry::Interest(entry) => {
+                Self::Interest(InterestEntry {
+                    tx_id: UUID::from(entry.tx_id),
+                    recorded_at: entry.recorded_at.into(),
+                })
+            }
             lana_app::deposit::DepositAccountHistoryEntry::Unknown(entry) => {
                 Self::Unknown(UnknownEntry {
                     tx_id: UUID::from(entry.tx_id),
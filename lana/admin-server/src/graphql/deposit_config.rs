@@ -14,6 +14,8 @@ pub struct DepositModuleConfig {
     chart_of_account_bank_deposit_accounts_parent_code: Option<String>,
     chart_of_account_financial_institution_deposit_accounts_parent_code: Option<String>,
     chart_of_account_non_domiciled_company_deposit_accounts_parent_code: Option<String>,
+    chart_of_account_interest_expense_parent_code: Option<String>,
+    chart_of_account_interest_payable_parent_code: Option<String>,
 
     #[graphql(skip)]
     pub(super) _entity: Arc<DomainChartOfAccountsIntegrationConfig>,
@@ -56,6 +58,14 @@ impl From<DomainChartOfAccountsIntegrationConfig> for DepositModuleConfig {
                     .chart_of_account_non_domiciled_individual_deposit_accounts_parent_code
                     .to_string(),
             ),
+            chart_of_account_interest_expense_parent_code: values
+                .chart_of_account_interest_expense_parent_code
+                .as_ref()
+                .map(ToString::to_string),
+            chart_of_account_interest_payable_parent_code: values
+                .chart_of_account_interest_payable_parent_code
+                .as_ref()
+                .map(ToString::to_string),
 
             _entity: Arc::new(values),
         }
@@ -71,5 +81,7 @@ pub struct DepositModuleConfigureInput {
     pub chart_of_account_bank_deposit_accounts_parent_code: String,
     pub chart_of_account_financial_institution_deposit_accounts_parent_code: String,
     pub chart_of_account_non_domiciled_individual_deposit_accounts_parent_code: String,
+    pub chart_of_account_interest_expense_parent_code: Option<String>,
+    pub chart_of_account_interest_payable_parent_code: Option<String>,
 }
 crate::mutation_payload! { DepositModuleConfigurePayload, deposit_config: DepositModuleConfig }
@@ -0,0 +1,59 @@
+use async_graphql::*;
+use rust_decimal::Decimal;
+
+use crate::primitives::*;
+
+pub use lana_app::deposit::{
+    AnnualRatePct as DomainAnnualRatePct, DepositProduct as DomainDepositProduct,
+    DepositProductsByNameCursor,
+};
+use lana_app::terms::AnnualRatePct;
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct DepositProduct {
+    id: ID,
+    deposit_product_id: UUID,
+    annual_rate: AnnualRatePct,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainDepositProduct>,
+}
+
+impl From<DomainDepositProduct> for DepositProduct {
+    fn from(product: DomainDepositProduct) -> Self {
+        Self {
+            id: product.id.to_global_id(),
+            deposit_product_id: product.id.into(),
+            annual_rate: AnnualRatePct::from(Decimal::from(product.annual_rate)),
+            created_at: product.created_at().into(),
+            entity: Arc::new(product),
+        }
+    }
+}
+
+#[ComplexObject]
+impl DepositProduct {
+    async fn name(&self) -> &str {
+        &self.entity.name
+    }
+}
+
+pub(super) fn domain_annual_rate(rate: AnnualRatePct) -> DomainAnnualRatePct {
+    DomainAnnualRatePct::from(Decimal::from(rate))
+}
+
+#[derive(InputObject)]
+pub struct DepositProductCreateInput {
+    pub name: String,
+    pub annual_rate: AnnualRatePct,
+}
+crate::mutation_payload! { DepositProductCreatePayload, deposit_product: DepositProduct }
+
+#[derive(InputObject)]
+pub struct DepositProductUpdateRateInput {
+    pub deposit_product_id: UUID,
+    pub annual_rate: AnnualRatePct,
+}
+crate::mutation_payload! { DepositProductUpdateRatePayload, deposit_product: DepositProduct }
@@ -6,9 +6,13 @@ use lana_app::{
     access::{error::CoreAccessError, user::error::UserError},
     accounting::{
         Chart, LedgerAccountId, TransactionTemplateId,
+        accounting_period::error::AccountingPeriodError,
         chart_of_accounts::error::ChartOfAccountsError,
         csv::{AccountingCsvDocumentId, error::AccountingCsvExportError},
         ledger_transaction::error::LedgerTransactionError,
+        manual_transaction::error::ManualTransactionError,
+        reconciliation::error::ReconciliationError,
+        recurring_journal_entry::error::RecurringJournalEntryError,
         transaction_templates::error::TransactionTemplateError,
     },
     app::LanaApp,
@@ -23,8 +27,9 @@ use crate::primitives::*;
 
 use super::{
     access::*, accounting::*, approval_process::*, committee::*, credit_facility::*, custody::*,
-    customer::*, deposit::*, deposit_account::*, document::*, policy::*, reports::*,
-    terms_template::*, withdrawal::*,
+    customer::*, deposit::*, deposit_account::*, deposit_product::*, document::*, hold::*,
+    policy::*, reports::*, standing_order::*, terms_template::*, transfer::*, withdrawal::*,
+    withdrawal_limit::*,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -213,6 +218,57 @@ impl Loader<ChartId> for LanaLoader {
     }
 }
 
+impl Loader<AccountingPeriodId> for LanaLoader {
+    type Value = AccountingPeriod;
+    type Error = Arc<AccountingPeriodError>;
+
+    async fn load(
+        &self,
+        keys: &[AccountingPeriodId],
+    ) -> Result<HashMap<AccountingPeriodId, AccountingPeriod>, Self::Error> {
+        self.app
+            .accounting()
+            .accounting_periods()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<RecurringJournalEntryId> for LanaLoader {
+    type Value = RecurringJournalEntry;
+    type Error = Arc<RecurringJournalEntryError>;
+
+    async fn load(
+        &self,
+        keys: &[RecurringJournalEntryId],
+    ) -> Result<HashMap<RecurringJournalEntryId, RecurringJournalEntry>, Self::Error> {
+        self.app
+            .accounting()
+            .recurring_journal_entries()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<ReconciliationId> for LanaLoader {
+    type Value = Reconciliation;
+    type Error = Arc<ReconciliationError>;
+
+    async fn load(
+        &self,
+        keys: &[ReconciliationId],
+    ) -> Result<HashMap<ReconciliationId, Reconciliation>, Self::Error> {
+        self.app
+            .accounting()
+            .reconciliations()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<WithdrawalId> for LanaLoader {
     type Value = Withdrawal;
     type Error = Arc<CoreDepositError>;
@@ -229,6 +285,83 @@ impl Loader<WithdrawalId> for LanaLoader {
     }
 }
 
+impl Loader<TransferId> for LanaLoader {
+    type Value = Transfer;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[TransferId],
+    ) -> Result<HashMap<TransferId, Transfer>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_transfers(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<DepositProductId> for LanaLoader {
+    type Value = DepositProduct;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[DepositProductId],
+    ) -> Result<HashMap<DepositProductId, DepositProduct>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_deposit_products(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<HoldId> for LanaLoader {
+    type Value = Hold;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(&self, keys: &[HoldId]) -> Result<HashMap<HoldId, Hold>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_holds(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<WithdrawalLimitId> for LanaLoader {
+    type Value = WithdrawalLimit;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[WithdrawalLimitId],
+    ) -> Result<HashMap<WithdrawalLimitId, WithdrawalLimit>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_withdrawal_limits(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
+impl Loader<StandingOrderId> for LanaLoader {
+    type Value = StandingOrder;
+    type Error = Arc<CoreDepositError>;
+
+    async fn load(
+        &self,
+        keys: &[StandingOrderId],
+    ) -> Result<HashMap<StandingOrderId, StandingOrder>, Self::Error> {
+        self.app
+            .deposits()
+            .find_all_standing_orders(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<DepositId> for LanaLoader {
     type Value = Deposit;
     type Error = Arc<CoreDepositError>;
@@ -275,6 +408,23 @@ impl Loader<LedgerTransactionId> for LanaLoader {
     }
 }
 
+impl Loader<ManualTransactionId> for LanaLoader {
+    type Value = ManualTransaction;
+    type Error = Arc<ManualTransactionError>;
+
+    async fn load(
+        &self,
+        keys: &[ManualTransactionId],
+    ) -> Result<HashMap<ManualTransactionId, Self::Value>, Self::Error> {
+        self.app
+            .accounting()
+            .manual_transactions()
+            .find_all(keys)
+            .await
+            .map_err(Arc::new)
+    }
+}
+
 impl Loader<TransactionTemplateId> for LanaLoader {
     type Value = TransactionTemplate;
     type Error = Arc<TransactionTemplateError>;
@@ -373,6 +523,23 @@ impl Loader<DisbursalId> for LanaLoader {
     }
 }
 
+impl Loader<CollateralReleaseId> for LanaLoader {
+    type Value = CreditFacilityCollateralRelease;
+    type Error = Arc<lana_app::credit::error::CoreCreditError>;
+
+    async fn load(
+        &self,
+        keys: &[CollateralReleaseId],
+    ) -> Result<HashMap<CollateralReleaseId, CreditFacilityCollateralRelease>, Self::Error> {
+        self.app
+            .credit()
+            .collateral_releases()
+            .find_all(keys)
+            .await
+            .map_err(|e| Arc::new(e.into()))
+    }
+}
+
 impl Loader<LedgerAccountId> for LanaLoader {
     type Value = LedgerAccount;
     type Error = Arc<lana_app::accounting::error::CoreAccountingError>;
@@ -14,7 +14,9 @@ mod deposit;
 mod deposit_account;
 mod deposit_account_history;
 mod deposit_config;
+mod deposit_product;
 mod document;
+mod hold;
 mod loader;
 mod me;
 mod price;
@@ -22,10 +24,13 @@ mod primitives;
 mod profit_and_loss_config;
 mod public_id;
 mod reports;
+mod standing_order;
 mod sumsub;
 mod terms;
 mod terms_template;
+mod transfer;
 mod withdrawal;
+mod withdrawal_limit;
 #[macro_use]
 pub mod macros;
 mod access;
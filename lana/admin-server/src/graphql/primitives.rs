@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::primitives::{Money, UsdCents};
+
 #[derive(async_graphql::Enum, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SortDirection {
     #[default]
@@ -30,3 +32,22 @@ impl From<Decimal> for rust_decimal::Decimal {
         value.0
     }
 }
+
+/// Deposit amounts are exposed in the minor units of their currency.
+pub(super) fn minor_units(money: &Money) -> UsdCents {
+    UsdCents::from(money.to_minor_units().unsigned_abs())
+}
+
+pub(super) fn money_from_minor_units(
+    amount: UsdCents,
+    currency: Option<String>,
+) -> async_graphql::Result<Money> {
+    let currency = match currency {
+        Some(currency) => currency.parse()?,
+        None => return Ok(amount.into()),
+    };
+    Ok(Money::from_minor_units(
+        i64::try_from(amount.into_inner())?,
+        currency,
+    ))
+}
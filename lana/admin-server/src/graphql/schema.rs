@@ -14,9 +14,11 @@ use crate::primitives::*;
 use super::{
     access::*, accounting::*, approval_process::*, audit::*, balance_sheet_config::*, committee::*,
     contract_creation::*, credit_config::*, credit_facility::*, custody::*, customer::*,
-    dashboard::*, deposit::*, deposit_config::*, document::*, loader::*, me::*, policy::*,
-    price::*, profit_and_loss_config::*, public_id::*, reports::*, sumsub::*, terms_template::*,
-    withdrawal::*,
+    dashboard::*, deposit::*, deposit_account::*, deposit_config::*, deposit_product::*,
+    document::*, hold::*, loader::*, me::*, policy::*, price::*,
+    primitives::money_from_minor_units, profit_and_loss_config::*, public_id::*, reports::*,
+    standing_order::*, sumsub::*, terms::collateral_haircuts_from_input, terms_template::*,
+    transfer::*, withdrawal::*, withdrawal_limit::*,
 };
 
 pub struct Query;
@@ -200,6 +202,84 @@ impl Query {
         )
     }
 
+    async fn transfer(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<Transfer>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Transfer, ctx, app.deposits().find_transfer_by_id(sub, id))
+    }
+
+    async fn transfers(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransfersByCreatedAtCursor, Transfer, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            TransfersByCreatedAtCursor,
+            Transfer,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_transfers(sub, query)
+        )
+    }
+
+    async fn hold(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Hold>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(Hold, ctx, app.deposits().find_hold_by_id(sub, id))
+    }
+
+    async fn withdrawal_limit(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<WithdrawalLimit>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            WithdrawalLimit,
+            ctx,
+            app.deposits().find_withdrawal_limit_by_id(sub, id)
+        )
+    }
+
+    async fn withdrawal_limits(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<WithdrawalLimitsByCreatedAtCursor, WithdrawalLimit, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            WithdrawalLimitsByCreatedAtCursor,
+            WithdrawalLimit,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_withdrawal_limits(sub, query)
+        )
+    }
+
+    async fn standing_order(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<StandingOrder>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            StandingOrder,
+            ctx,
+            app.deposits().find_standing_order_by_id(sub, id)
+        )
+    }
+
     async fn deposit(&self, ctx: &Context<'_>, id: UUID) -> async_graphql::Result<Option<Deposit>> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         maybe_fetch_one!(Deposit, ctx, app.deposits().find_deposit_by_id(sub, id))
@@ -236,6 +316,38 @@ impl Query {
         )
     }
 
+    async fn deposit_product(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<DepositProduct>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            DepositProduct,
+            ctx,
+            app.deposits().find_deposit_product_by_id(sub, id)
+        )
+    }
+
+    async fn deposit_products(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<DepositProductsByNameCursor, DepositProduct, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            DepositProductsByNameCursor,
+            DepositProduct,
+            ctx,
+            after,
+            first,
+            |query| app.deposits().list_deposit_products(sub, query)
+        )
+    }
+
     async fn terms_template(
         &self,
         ctx: &Context<'_>,
@@ -581,6 +693,75 @@ impl Query {
         )
     }
 
+    async fn manual_transaction(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<ManualTransaction>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            ManualTransaction,
+            ctx,
+            app.accounting()
+                .manual_transactions()
+                .find_manual_transaction_by_id(sub, id)
+        )
+    }
+
+    async fn manual_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            ctx,
+            after,
+            first,
+            |query| app
+                .accounting()
+                .manual_transactions()
+                .list_manual_transactions(sub, query)
+        )
+    }
+
+    async fn pending_manual_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            ManualTransactionsByCreatedAtCursor,
+            ManualTransaction,
+            ctx,
+            after,
+            first,
+            |query| app
+                .accounting()
+                .manual_transactions()
+                .list_pending_manual_transactions(sub, query)
+        )
+    }
+
     async fn ledger_transactions_for_template_code(
         &self,
         ctx: &Context<'_>,
@@ -656,6 +837,112 @@ impl Query {
         Ok(TrialBalance::from(account_summary))
     }
 
+    async fn accounting_period(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<AccountingPeriod>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            AccountingPeriod,
+            ctx,
+            app.accounting().accounting_periods().find_by_id(sub, id)
+        )
+    }
+
+    async fn accounting_periods(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<AccountingPeriodsByCreatedAtCursor, AccountingPeriod, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            AccountingPeriodsByCreatedAtCursor,
+            AccountingPeriod,
+            ctx,
+            after,
+            first,
+            |query| app.accounting().accounting_periods().list(sub, query)
+        )
+    }
+
+    async fn recurring_journal_entry(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<RecurringJournalEntry>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            RecurringJournalEntry,
+            ctx,
+            app.accounting()
+                .recurring_journal_entries()
+                .find_by_id(sub, id)
+        )
+    }
+
+    async fn recurring_journal_entries(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<
+            RecurringJournalEntriesByCreatedAtCursor,
+            RecurringJournalEntry,
+            EmptyFields,
+            EmptyFields,
+        >,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            RecurringJournalEntriesByCreatedAtCursor,
+            RecurringJournalEntry,
+            ctx,
+            after,
+            first,
+            |query| app
+                .accounting()
+                .recurring_journal_entries()
+                .list(sub, query)
+        )
+    }
+
+    async fn reconciliation(
+        &self,
+        ctx: &Context<'_>,
+        id: UUID,
+    ) -> async_graphql::Result<Option<Reconciliation>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        maybe_fetch_one!(
+            Reconciliation,
+            ctx,
+            app.accounting().reconciliations().find_by_id(sub, id)
+        )
+    }
+
+    async fn reconciliations(
+        &self,
+        ctx: &Context<'_>,
+        first: i32,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<ReconciliationsByCreatedAtCursor, Reconciliation, EmptyFields, EmptyFields>,
+    > {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        list_with_cursor!(
+            ReconciliationsByCreatedAtCursor,
+            Reconciliation,
+            ctx,
+            after,
+            first,
+            |query| app.accounting().reconciliations().list(sub, query)
+        )
+    }
+
     async fn chart_of_accounts(&self, ctx: &Context<'_>) -> async_graphql::Result<ChartOfAccounts> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
         let chart = app
@@ -667,6 +954,19 @@ impl Query {
         Ok(ChartOfAccounts::from(chart))
     }
 
+    async fn chart_of_accounts_csv_export(
+        &self,
+        ctx: &Context<'_>,
+        chart_id: UUID,
+    ) -> async_graphql::Result<String> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        Ok(app
+            .accounting()
+            .chart_of_accounts()
+            .export_to_csv(sub, chart_id)
+            .await?)
+    }
+
     async fn balance_sheet(
         &self,
         ctx: &Context<'_>,
@@ -707,6 +1007,38 @@ impl Query {
         Ok(ProfitAndLossStatement::from(profit_and_loss))
     }
 
+    async fn comparative_balance_sheet(
+        &self,
+        ctx: &Context<'_>,
+        input: ComparativeStatementInput,
+    ) -> async_graphql::Result<ComparativeStatement> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let statement = app
+            .accounting()
+            .comparative_statements()
+            .balance_sheet(sub, BALANCE_SHEET_NAME.to_string(), input.periods()?)
+            .await?;
+        Ok(ComparativeStatement::from(statement))
+    }
+
+    async fn comparative_profit_and_loss_statement(
+        &self,
+        ctx: &Context<'_>,
+        input: ComparativeStatementInput,
+    ) -> async_graphql::Result<ComparativeStatement> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let statement = app
+            .accounting()
+            .comparative_statements()
+            .pl_statement(
+                sub,
+                PROFIT_AND_LOSS_STATEMENT_NAME.to_string(),
+                input.periods()?,
+            )
+            .await?;
+        Ok(ComparativeStatement::from(statement))
+    }
+
     async fn realtime_price(&self, ctx: &Context<'_>) -> async_graphql::Result<RealtimePrice> {
         let app = ctx.data_unchecked::<LanaApp>();
         let usd_cents_per_btc = app.price().usd_cents_per_btc().await?;
@@ -864,6 +1196,20 @@ impl Query {
         Ok(latest)
     }
 
+    async fn statement_exports(
+        &self,
+        ctx: &Context<'_>,
+        statement_type: StatementType,
+    ) -> async_graphql::Result<Vec<StatementExport>> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let exports = app
+            .accounting()
+            .statement_exports()
+            .list_for_statement(sub, statement_name(statement_type))
+            .await?;
+        Ok(exports.into_iter().map(StatementExport::from).collect())
+    }
+
     async fn report_runs(
         &self,
         ctx: &Context<'_>,
@@ -1132,6 +1478,18 @@ impl Mutation {
             .chart_of_accounts_omnibus_parent_code(
                 input.chart_of_accounts_omnibus_parent_code.parse()?,
             )
+            .chart_of_account_interest_expense_parent_code(
+                input
+                    .chart_of_account_interest_expense_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            )
+            .chart_of_account_interest_payable_parent_code(
+                input
+                    .chart_of_account_interest_payable_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            )
             .build()?;
         let config = app
             .deposits()
@@ -1156,7 +1514,7 @@ impl Mutation {
 
         exec_mutation!(
             ManualTransactionExecutePayload,
-            LedgerTransaction,
+            ManualTransaction,
             ctx,
             app.accounting().execute_manual_transaction(
                 sub,
@@ -1169,41 +1527,301 @@ impl Mutation {
         )
     }
 
-    pub async fn deposit_record(
+    pub async fn manual_transaction_reverse(
         &self,
         ctx: &Context<'_>,
-        input: DepositRecordInput,
-    ) -> async_graphql::Result<DepositRecordPayload> {
+        input: ManualTransactionReverseInput,
+    ) -> async_graphql::Result<ManualTransactionReversePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
-
         exec_mutation!(
-            DepositRecordPayload,
-            Deposit,
+            ManualTransactionReversePayload,
+            ManualTransaction,
             ctx,
-            app.deposits().record_deposit(
+            app.accounting().reverse_manual_transaction(
                 sub,
-                input.deposit_account_id,
-                input.amount,
-                input.reference
+                input.manual_transaction_id.into(),
+                input.effective.map(|ts| ts.into_inner())
             )
         )
     }
 
-    pub async fn withdrawal_initiate(
+    pub async fn recurring_journal_entry_create(
         &self,
         ctx: &Context<'_>,
-        input: WithdrawalInitiateInput,
-    ) -> async_graphql::Result<WithdrawalInitiatePayload> {
+        input: RecurringJournalEntryCreateInput,
+    ) -> async_graphql::Result<RecurringJournalEntryCreatePayload> {
         let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut entries = Vec::with_capacity(input.entries.len());
+        for entry in input.entries.into_iter() {
+            entries.push(entry.try_into()?);
+        }
+
         exec_mutation!(
-            WithdrawalInitiatePayload,
-            Withdrawal,
+            RecurringJournalEntryCreatePayload,
+            RecurringJournalEntry,
             ctx,
-            app.deposits().initiate_withdrawal(
+            app.accounting().recurring_journal_entries().create(
                 sub,
-                input.deposit_account_id,
-                input.amount,
-                input.reference
+                CHART_REF.0,
+                input.reference,
+                input.description,
+                RecurrenceSchedule::new(input.frequency, input.starts_on.into_inner()),
+                entries
+            )
+        )
+    }
+
+    pub async fn recurring_journal_entry_pause(
+        &self,
+        ctx: &Context<'_>,
+        input: RecurringJournalEntryPauseInput,
+    ) -> async_graphql::Result<RecurringJournalEntryPausePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            RecurringJournalEntryPausePayload,
+            RecurringJournalEntry,
+            ctx,
+            app.accounting()
+                .recurring_journal_entries()
+                .pause(sub, input.recurring_journal_entry_id)
+        )
+    }
+
+    pub async fn recurring_journal_entry_resume(
+        &self,
+        ctx: &Context<'_>,
+        input: RecurringJournalEntryResumeInput,
+    ) -> async_graphql::Result<RecurringJournalEntryResumePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            RecurringJournalEntryResumePayload,
+            RecurringJournalEntry,
+            ctx,
+            app.accounting()
+                .recurring_journal_entries()
+                .resume(sub, input.recurring_journal_entry_id)
+        )
+    }
+
+    pub async fn reconciliation_import(
+        &self,
+        ctx: &Context<'_>,
+        input: ReconciliationImportInput,
+    ) -> async_graphql::Result<ReconciliationImportPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut file = input.file.value(ctx)?.content;
+        let mut data = String::new();
+        file.read_to_string(&mut data)?;
+        exec_mutation!(
+            ReconciliationImportPayload,
+            Reconciliation,
+            ctx,
+            app.accounting().reconciliations().import_statement(
+                sub,
+                input.ledger_account_id,
+                input.statement_reference,
+                input.format,
+                data
+            )
+        )
+    }
+
+    pub async fn reconciliation_auto_match(
+        &self,
+        ctx: &Context<'_>,
+        input: ReconciliationAutoMatchInput,
+    ) -> async_graphql::Result<ReconciliationAutoMatchPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ReconciliationAutoMatchPayload,
+            Reconciliation,
+            ctx,
+            app.accounting()
+                .reconciliations()
+                .auto_match(sub, input.reconciliation_id)
+        )
+    }
+
+    pub async fn reconciliation_line_match(
+        &self,
+        ctx: &Context<'_>,
+        input: ReconciliationLineMatchInput,
+    ) -> async_graphql::Result<ReconciliationLineMatchPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ReconciliationLineMatchPayload,
+            Reconciliation,
+            ctx,
+            app.accounting().reconciliations().match_line(
+                sub,
+                input.reconciliation_id,
+                input.line_number,
+                input.ledger_transaction_id
+            )
+        )
+    }
+
+    pub async fn reconciliation_line_mark_exception(
+        &self,
+        ctx: &Context<'_>,
+        input: ReconciliationLineMarkExceptionInput,
+    ) -> async_graphql::Result<ReconciliationLineMarkExceptionPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ReconciliationLineMarkExceptionPayload,
+            Reconciliation,
+            ctx,
+            app.accounting().reconciliations().mark_line_as_exception(
+                sub,
+                input.reconciliation_id,
+                input.line_number,
+                input.reason
+            )
+        )
+    }
+
+    pub async fn reconciliation_line_reset(
+        &self,
+        ctx: &Context<'_>,
+        input: ReconciliationLineResetInput,
+    ) -> async_graphql::Result<ReconciliationLineResetPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ReconciliationLineResetPayload,
+            Reconciliation,
+            ctx,
+            app.accounting().reconciliations().reset_line(
+                sub,
+                input.reconciliation_id,
+                input.line_number
+            )
+        )
+    }
+
+    pub async fn reconciliation_complete(
+        &self,
+        ctx: &Context<'_>,
+        input: ReconciliationCompleteInput,
+    ) -> async_graphql::Result<ReconciliationCompletePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ReconciliationCompletePayload,
+            Reconciliation,
+            ctx,
+            app.accounting()
+                .reconciliations()
+                .complete(sub, input.reconciliation_id)
+        )
+    }
+
+    pub async fn accounting_period_create(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodCreateInput,
+    ) -> async_graphql::Result<AccountingPeriodCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            AccountingPeriodCreatePayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting().accounting_periods().create(
+                sub,
+                input.period_type,
+                input.starts_on.into_inner()
+            )
+        )
+    }
+
+    pub async fn accounting_period_soft_close(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodSoftCloseInput,
+    ) -> async_graphql::Result<AccountingPeriodSoftClosePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            AccountingPeriodSoftClosePayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting()
+                .accounting_periods()
+                .soft_close(sub, input.accounting_period_id)
+        )
+    }
+
+    pub async fn accounting_period_reopen(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodReopenInput,
+    ) -> async_graphql::Result<AccountingPeriodReopenPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            AccountingPeriodReopenPayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting()
+                .accounting_periods()
+                .reopen(sub, input.accounting_period_id)
+        )
+    }
+
+    pub async fn accounting_period_close(
+        &self,
+        ctx: &Context<'_>,
+        input: AccountingPeriodCloseInput,
+    ) -> async_graphql::Result<AccountingPeriodClosePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let retained_earnings_code = input
+            .retained_earnings_account_code
+            .map(|code| code.parse())
+            .transpose()?;
+        exec_mutation!(
+            AccountingPeriodClosePayload,
+            AccountingPeriod,
+            ctx,
+            app.accounting().close_accounting_period(
+                sub,
+                CHART_REF.0,
+                PROFIT_AND_LOSS_STATEMENT_NAME,
+                input.accounting_period_id.into(),
+                retained_earnings_code
+            )
+        )
+    }
+
+    pub async fn deposit_record(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositRecordInput,
+    ) -> async_graphql::Result<DepositRecordPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let amount = money_from_minor_units(input.amount, input.currency)?;
+        exec_mutation!(
+            DepositRecordPayload,
+            Deposit,
+            ctx,
+            app.deposits()
+                .record_deposit(sub, input.deposit_account_id, amount, input.reference)
+        )
+    }
+
+    pub async fn withdrawal_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalInitiateInput,
+    ) -> async_graphql::Result<WithdrawalInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let amount = money_from_minor_units(input.amount, input.currency)?;
+        exec_mutation!(
+            WithdrawalInitiatePayload,
+            Withdrawal,
+            ctx,
+            app.deposits().initiate_withdrawal(
+                sub,
+                input.deposit_account_id,
+                amount,
+                input.reference
             )
         )
     }
@@ -1251,6 +1869,202 @@ impl Mutation {
         )
     }
 
+    pub async fn transfer_initiate(
+        &self,
+        ctx: &Context<'_>,
+        input: TransferInitiateInput,
+    ) -> async_graphql::Result<TransferInitiatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let amount = money_from_minor_units(input.amount, input.currency)?;
+        exec_mutation!(
+            TransferInitiatePayload,
+            Transfer,
+            ctx,
+            app.deposits().initiate_transfer(
+                sub,
+                input.from_account_id,
+                input.to_account_id,
+                amount,
+                input.reference
+            )
+        )
+    }
+
+    pub async fn hold_place(
+        &self,
+        ctx: &Context<'_>,
+        input: HoldPlaceInput,
+    ) -> async_graphql::Result<HoldPlacePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let amount = money_from_minor_units(input.amount, input.currency)?;
+        exec_mutation!(
+            HoldPlacePayload,
+            Hold,
+            ctx,
+            app.deposits().place_hold(
+                sub,
+                input.deposit_account_id,
+                amount,
+                input.reason,
+                input.expires_at.map(|t| t.into_inner())
+            )
+        )
+    }
+
+    pub async fn hold_release(
+        &self,
+        ctx: &Context<'_>,
+        input: HoldReleaseInput,
+    ) -> async_graphql::Result<HoldReleasePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            HoldReleasePayload,
+            Hold,
+            ctx,
+            app.deposits().release_hold(sub, input.hold_id)
+        )
+    }
+
+    pub async fn withdrawal_limit_create(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalLimitCreateInput,
+    ) -> async_graphql::Result<WithdrawalLimitCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let amounts = domain_amounts(input.daily, input.weekly, input.monthly, &input.currency)?;
+        let currency = match input.currency {
+            Some(currency) => currency.parse()?,
+            None => lana_app::primitives::CurrencyCode::USD,
+        };
+        exec_mutation!(
+            WithdrawalLimitCreatePayload,
+            WithdrawalLimit,
+            ctx,
+            app.deposits().create_withdrawal_limit(
+                sub,
+                input.scope.into(),
+                currency,
+                amounts,
+                input.breach_action
+            )
+        )
+    }
+
+    pub async fn withdrawal_limit_update(
+        &self,
+        ctx: &Context<'_>,
+        input: WithdrawalLimitUpdateInput,
+    ) -> async_graphql::Result<WithdrawalLimitUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let amounts = domain_amounts(input.daily, input.weekly, input.monthly, &input.currency)?;
+        exec_mutation!(
+            WithdrawalLimitUpdatePayload,
+            WithdrawalLimit,
+            ctx,
+            app.deposits().update_withdrawal_limit(
+                sub,
+                input.withdrawal_limit_id,
+                amounts,
+                input.breach_action
+            )
+        )
+    }
+
+    pub async fn standing_order_create(
+        &self,
+        ctx: &Context<'_>,
+        input: StandingOrderCreateInput,
+    ) -> async_graphql::Result<StandingOrderCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let amount = money_from_minor_units(input.amount, input.currency)?;
+        exec_mutation!(
+            StandingOrderCreatePayload,
+            StandingOrder,
+            ctx,
+            app.deposits().create_standing_order(
+                sub,
+                input.deposit_account_id,
+                amount,
+                input.destination_reference,
+                StandingOrderSchedule::new(
+                    input.frequency,
+                    input.starts_on.into_inner(),
+                    input.ends_on.map(|d| d.into_inner())
+                ),
+                input.max_retries
+            )
+        )
+    }
+
+    pub async fn standing_order_cancel(
+        &self,
+        ctx: &Context<'_>,
+        input: StandingOrderCancelInput,
+    ) -> async_graphql::Result<StandingOrderCancelPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            StandingOrderCancelPayload,
+            StandingOrder,
+            ctx,
+            app.deposits()
+                .cancel_standing_order(sub, input.standing_order_id)
+        )
+    }
+
+    pub async fn deposit_product_create(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductCreateInput,
+    ) -> async_graphql::Result<DepositProductCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositProductCreatePayload,
+            DepositProduct,
+            ctx,
+            app.deposits().create_deposit_product(
+                sub,
+                input.name,
+                domain_annual_rate(input.annual_rate)
+            )
+        )
+    }
+
+    pub async fn deposit_product_update_rate(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositProductUpdateRateInput,
+    ) -> async_graphql::Result<DepositProductUpdateRatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositProductUpdateRatePayload,
+            DepositProduct,
+            ctx,
+            app.deposits().update_deposit_product_annual_rate(
+                sub,
+                input.deposit_product_id,
+                domain_annual_rate(input.annual_rate)
+            )
+        )
+    }
+
+    pub async fn deposit_account_assign_product(
+        &self,
+        ctx: &Context<'_>,
+        input: DepositAccountAssignProductInput,
+    ) -> async_graphql::Result<DepositAccountAssignProductPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            DepositAccountAssignProductPayload,
+            DepositAccount,
+            ctx,
+            app.deposits().assign_deposit_product(
+                sub,
+                input.deposit_account_id,
+                input.deposit_product_id
+            )
+        )
+    }
+
     pub async fn deposit_revert(
         &self,
         ctx: &Context<'_>,
@@ -1285,6 +2099,12 @@ impl Mutation {
             .liquidation_cvl(input.liquidation_cvl)
             .margin_call_cvl(input.margin_call_cvl)
             .initial_cvl(input.initial_cvl)
+            .payment_allocation_strategy(input.payment_allocation_strategy)
+            .principal_repayment(input.principal_repayment)
+            .principal_repayment_interval(input.principal_repayment_interval)
+            .prepayment_fee_rate(input.prepayment_fee_rate)
+            .penalty_rate(input.penalty_rate)
+            .collateral_haircuts(collateral_haircuts_from_input(input.collateral_haircuts))
             .build()?;
 
         exec_mutation!(
@@ -1318,6 +2138,12 @@ impl Mutation {
             .liquidation_cvl(input.liquidation_cvl)
             .margin_call_cvl(input.margin_call_cvl)
             .initial_cvl(input.initial_cvl)
+            .payment_allocation_strategy(input.payment_allocation_strategy)
+            .principal_repayment(input.principal_repayment)
+            .principal_repayment_interval(input.principal_repayment_interval)
+            .prepayment_fee_rate(input.prepayment_fee_rate)
+            .penalty_rate(input.penalty_rate)
+            .collateral_haircuts(collateral_haircuts_from_input(input.collateral_haircuts))
             .build()?;
         exec_mutation!(
             TermsTemplateUpdatePayload,
@@ -1353,6 +2179,12 @@ impl Mutation {
             chart_of_account_in_liquidation_parent_code,
             chart_of_account_interest_income_parent_code,
             chart_of_account_fee_income_parent_code,
+            chart_of_account_unapplied_funds_parent_code,
+            chart_of_account_penalty_receivable_parent_code,
+            chart_of_account_penalty_income_parent_code,
+            chart_of_account_collateral_in_liquidation_parent_code,
+            chart_of_account_liquidation_proceeds_parent_code,
+            chart_of_account_liquidation_proceeds_omnibus_parent_code,
 
             chart_of_account_short_term_individual_disbursed_receivable_parent_code,
             chart_of_account_short_term_government_entity_disbursed_receivable_parent_code,
@@ -1424,6 +2256,36 @@ impl Mutation {
             .chart_of_account_fee_income_parent_code(
                 chart_of_account_fee_income_parent_code.parse()?,
             )
+            .chart_of_account_unapplied_funds_parent_code(
+                chart_of_account_unapplied_funds_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            )
+            .chart_of_account_penalty_receivable_parent_code(
+                chart_of_account_penalty_receivable_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            )
+            .chart_of_account_penalty_income_parent_code(
+                chart_of_account_penalty_income_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            )
+            .chart_of_account_collateral_in_liquidation_parent_code(
+                chart_of_account_collateral_in_liquidation_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            )
+            .chart_of_account_liquidation_proceeds_parent_code(
+                chart_of_account_liquidation_proceeds_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            )
+            .chart_of_account_liquidation_proceeds_omnibus_parent_code(
+                chart_of_account_liquidation_proceeds_omnibus_parent_code
+                    .map(|code| code.parse())
+                    .transpose()?,
+            )
             .chart_of_account_short_term_individual_disbursed_receivable_parent_code(chart_of_account_short_term_individual_disbursed_receivable_parent_code.parse()?)
             .chart_of_account_short_term_government_entity_disbursed_receivable_parent_code(chart_of_account_short_term_government_entity_disbursed_receivable_parent_code.parse()?)
             .chart_of_account_short_term_private_company_disbursed_receivable_parent_code(chart_of_account_short_term_private_company_disbursed_receivable_parent_code.parse()?)
@@ -1501,6 +2363,12 @@ impl Mutation {
             .liquidation_cvl(terms.liquidation_cvl)
             .margin_call_cvl(terms.margin_call_cvl)
             .initial_cvl(terms.initial_cvl)
+            .payment_allocation_strategy(terms.payment_allocation_strategy)
+            .principal_repayment(terms.principal_repayment)
+            .principal_repayment_interval(terms.principal_repayment_interval)
+            .prepayment_fee_rate(terms.prepayment_fee_rate)
+            .penalty_rate(terms.penalty_rate)
+            .collateral_haircuts(collateral_haircuts_from_input(terms.collateral_haircuts))
             .build()?;
 
         exec_mutation!(
@@ -1518,6 +2386,97 @@ impl Mutation {
         )
     }
 
+    pub async fn credit_facility_restructure(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityRestructureInput,
+    ) -> async_graphql::Result<CreditFacilityRestructurePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityRestructureInput {
+            credit_facility_id,
+            terms,
+        } = input;
+
+        let term_values = lana_app::terms::TermValues::builder()
+            .annual_rate(terms.annual_rate)
+            .accrual_interval(terms.accrual_interval)
+            .accrual_cycle_interval(terms.accrual_cycle_interval)
+            .one_time_fee_rate(terms.one_time_fee_rate)
+            .duration(terms.duration)
+            .interest_due_duration_from_accrual(terms.interest_due_duration_from_accrual)
+            .obligation_overdue_duration_from_due(terms.obligation_overdue_duration_from_due)
+            .obligation_liquidation_duration_from_due(
+                terms.obligation_liquidation_duration_from_due,
+            )
+            .liquidation_cvl(terms.liquidation_cvl)
+            .margin_call_cvl(terms.margin_call_cvl)
+            .initial_cvl(terms.initial_cvl)
+            .payment_allocation_strategy(terms.payment_allocation_strategy)
+            .principal_repayment(terms.principal_repayment)
+            .principal_repayment_interval(terms.principal_repayment_interval)
+            .prepayment_fee_rate(terms.prepayment_fee_rate)
+            .penalty_rate(terms.penalty_rate)
+            .collateral_haircuts(collateral_haircuts_from_input(terms.collateral_haircuts))
+            .build()?;
+
+        exec_mutation!(
+            CreditFacilityRestructurePayload,
+            CreditFacility,
+            ctx,
+            app.credit()
+                .restructure_facility(sub, credit_facility_id, term_values)
+        )
+    }
+
+    pub async fn credit_facility_rollover(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityRolloverInput,
+    ) -> async_graphql::Result<CreditFacilityRolloverPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityRolloverInput {
+            credit_facility_id,
+            facility,
+            terms,
+            capitalize_interest,
+        } = input;
+
+        let term_values = lana_app::terms::TermValues::builder()
+            .annual_rate(terms.annual_rate)
+            .accrual_interval(terms.accrual_interval)
+            .accrual_cycle_interval(terms.accrual_cycle_interval)
+            .one_time_fee_rate(terms.one_time_fee_rate)
+            .duration(terms.duration)
+            .interest_due_duration_from_accrual(terms.interest_due_duration_from_accrual)
+            .obligation_overdue_duration_from_due(terms.obligation_overdue_duration_from_due)
+            .obligation_liquidation_duration_from_due(
+                terms.obligation_liquidation_duration_from_due,
+            )
+            .liquidation_cvl(terms.liquidation_cvl)
+            .margin_call_cvl(terms.margin_call_cvl)
+            .initial_cvl(terms.initial_cvl)
+            .payment_allocation_strategy(terms.payment_allocation_strategy)
+            .principal_repayment(terms.principal_repayment)
+            .principal_repayment_interval(terms.principal_repayment_interval)
+            .prepayment_fee_rate(terms.prepayment_fee_rate)
+            .penalty_rate(terms.penalty_rate)
+            .collateral_haircuts(collateral_haircuts_from_input(terms.collateral_haircuts))
+            .build()?;
+
+        exec_mutation!(
+            CreditFacilityRolloverPayload,
+            CreditFacility,
+            ctx,
+            app.credit().rollover_facility(
+                sub,
+                credit_facility_id,
+                facility,
+                term_values,
+                capitalize_interest
+            )
+        )
+    }
+
     pub async fn credit_facility_collateral_update(
         &self,
         ctx: &Context<'_>,
@@ -1538,6 +2497,30 @@ impl Mutation {
         )
     }
 
+    pub async fn credit_facility_collateral_asset_update(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityCollateralAssetUpdateInput,
+    ) -> async_graphql::Result<CreditFacilityCollateralAssetUpdatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityCollateralAssetUpdateInput {
+            credit_facility_id,
+            asset,
+            quantity,
+        } = input;
+        exec_mutation!(
+            CreditFacilityCollateralAssetUpdatePayload,
+            CreditFacility,
+            ctx,
+            app.credit().update_collateral_asset_position(
+                sub,
+                credit_facility_id,
+                asset,
+                quantity.into()
+            )
+        )
+    }
+
     pub async fn credit_facility_partial_payment(
         &self,
         ctx: &Context<'_>,
@@ -1557,6 +2540,78 @@ impl Mutation {
         )
     }
 
+    pub async fn credit_facility_prepayment(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityPrepaymentInput,
+    ) -> async_graphql::Result<CreditFacilityPrepaymentPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityPrepaymentPayload,
+            CreditFacility,
+            ctx,
+            app.credit().record_prepayment(
+                sub,
+                input.credit_facility_id,
+                input.amount,
+                input.effective
+            )
+        )
+    }
+
+    pub async fn credit_facility_unapplied_funds_refund(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityUnappliedFundsRefundInput,
+    ) -> async_graphql::Result<CreditFacilityUnappliedFundsRefundPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            CreditFacilityUnappliedFundsRefundPayload,
+            CreditFacility,
+            ctx,
+            app.credit()
+                .refund_unapplied_funds(sub, input.credit_facility_id, input.amount)
+        )
+    }
+
+    pub async fn credit_facility_liquidation_collateral_reserve(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityLiquidationCollateralReserveInput,
+    ) -> async_graphql::Result<CreditFacilityLiquidationCollateralReservePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let liquidation = app
+            .credit()
+            .reserve_collateral_for_liquidation(
+                sub,
+                LiquidationProcessId::from(input.liquidation_process_id),
+                input.collateral,
+            )
+            .await?;
+        Ok(CreditFacilityLiquidationCollateralReservePayload::from(
+            CreditFacilityLiquidation::from(liquidation),
+        ))
+    }
+
+    pub async fn credit_facility_liquidation_sale_record(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityLiquidationSaleRecordInput,
+    ) -> async_graphql::Result<CreditFacilityLiquidationSaleRecordPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let liquidation = app
+            .credit()
+            .record_liquidation_sale(
+                sub,
+                LiquidationProcessId::from(input.liquidation_process_id),
+                lana_app::primitives::PriceOfOneBTC::new(input.usd_cents_per_btc),
+            )
+            .await?;
+        Ok(CreditFacilityLiquidationSaleRecordPayload::from(
+            CreditFacilityLiquidation::from(liquidation),
+        ))
+    }
+
     pub async fn credit_facility_disbursal_initiate(
         &self,
         ctx: &Context<'_>,
@@ -1572,6 +2627,26 @@ impl Mutation {
         )
     }
 
+    pub async fn credit_facility_collateral_release_request(
+        &self,
+        ctx: &Context<'_>,
+        input: CreditFacilityCollateralReleaseRequestInput,
+    ) -> async_graphql::Result<CreditFacilityCollateralReleaseRequestPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let CreditFacilityCollateralReleaseRequestInput {
+            credit_facility_id,
+            amount,
+            address,
+        } = input;
+        exec_mutation!(
+            CreditFacilityCollateralReleaseRequestPayload,
+            CreditFacilityCollateralRelease,
+            ctx,
+            app.credit()
+                .request_collateral_release(sub, credit_facility_id, amount, address)
+        )
+    }
+
     async fn credit_facility_complete(
         &self,
         ctx: &Context<'_>,
@@ -1777,6 +2852,26 @@ impl Mutation {
         )
     }
 
+    async fn chart_of_accounts_csv_import_dry_run(
+        &self,
+        ctx: &Context<'_>,
+        input: ChartOfAccountsCsvImportInput,
+    ) -> async_graphql::Result<ChartOfAccountsCsvImportDryRunPayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+
+        let mut file = input.file.value(ctx)?.content;
+        let mut data = String::new();
+        file.read_to_string(&mut data)?;
+        let diff = app
+            .accounting()
+            .chart_of_accounts()
+            .import_from_csv_dry_run(sub, input.chart_id, data)
+            .await?;
+        Ok(ChartOfAccountsCsvImportDryRunPayload::from(
+            ChartOfAccountsCsvImportDiff::from(diff),
+        ))
+    }
+
     async fn chart_of_accounts_add_root_node(
         &self,
         ctx: &Context<'_>,
@@ -1819,6 +2914,65 @@ impl Mutation {
         )
     }
 
+    async fn chart_of_accounts_node_rename(
+        &self,
+        ctx: &Context<'_>,
+        input: ChartOfAccountsNodeRenameInput,
+    ) -> async_graphql::Result<ChartOfAccountsNodeRenamePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ChartOfAccountsNodeRenamePayload,
+            ChartOfAccounts,
+            ChartId,
+            ctx,
+            app.accounting().chart_of_accounts().rename_node(
+                sub,
+                input.chart_id,
+                input.code.try_into()?,
+                input.name.parse()?,
+            )
+        )
+    }
+
+    async fn chart_of_accounts_node_deactivate(
+        &self,
+        ctx: &Context<'_>,
+        input: ChartOfAccountsNodeDeactivateInput,
+    ) -> async_graphql::Result<ChartOfAccountsNodeDeactivatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ChartOfAccountsNodeDeactivatePayload,
+            ChartOfAccounts,
+            ChartId,
+            ctx,
+            app.accounting().chart_of_accounts().deactivate_node(
+                sub,
+                input.chart_id,
+                input.code.try_into()?,
+            )
+        )
+    }
+
+    async fn chart_of_accounts_node_move(
+        &self,
+        ctx: &Context<'_>,
+        input: ChartOfAccountsNodeMoveInput,
+    ) -> async_graphql::Result<ChartOfAccountsNodeMovePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        exec_mutation!(
+            ChartOfAccountsNodeMovePayload,
+            ChartOfAccounts,
+            ChartId,
+            ctx,
+            app.accounting().chart_of_accounts().move_node(
+                sub,
+                input.chart_id,
+                input.code.try_into()?,
+                input.new_parent.try_into()?,
+            )
+        )
+    }
+
     async fn balance_sheet_configure(
         &self,
         ctx: &Context<'_>,
@@ -1927,6 +3081,45 @@ impl Mutation {
         Ok(AccountingCsvDownloadLinkGeneratePayload::from(link))
     }
 
+    pub async fn statement_export_create(
+        &self,
+        ctx: &Context<'_>,
+        input: StatementExportCreateInput,
+    ) -> async_graphql::Result<StatementExportCreatePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let document = app
+            .accounting()
+            .statement_exports()
+            .create(
+                sub,
+                input.statement_type,
+                statement_name(input.statement_type),
+                input.from.into_inner(),
+                input.until.into_inner(),
+                input.format,
+            )
+            .await?;
+        Ok(StatementExportCreatePayload::from(StatementExport::from(
+            document,
+        )))
+    }
+
+    pub async fn statement_export_download_link_generate(
+        &self,
+        ctx: &Context<'_>,
+        input: StatementExportDownloadLinkGenerateInput,
+    ) -> async_graphql::Result<StatementExportDownloadLinkGeneratePayload> {
+        let (app, sub) = app_and_sub_from_ctx!(ctx);
+        let result = app
+            .accounting()
+            .statement_exports()
+            .generate_download_link(sub, input.document_id.into())
+            .await?;
+        Ok(StatementExportDownloadLinkGeneratePayload::from(
+            StatementExportDownloadLink::from(result),
+        ))
+    }
+
     pub async fn loan_agreement_generate(
         &self,
         ctx: &Context<'_>,
@@ -1979,3 +3172,12 @@ impl Mutation {
         Ok(ReportFileGenerateDownloadLinkPayload { url })
     }
 }
+
+fn statement_name(statement_type: StatementType) -> String {
+    match statement_type {
+        StatementType::TrialBalance => TRIAL_BALANCE_STATEMENT_NAME,
+        StatementType::BalanceSheet => BALANCE_SHEET_NAME,
+        StatementType::ProfitAndLoss => PROFIT_AND_LOSS_STATEMENT_NAME,
+    }
+    .to_string()
+}
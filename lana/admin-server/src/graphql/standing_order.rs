@@ -0,0 +1,132 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    deposit_account::DepositAccount, loader::LanaDataLoader, primitives::minor_units,
+    withdrawal::Withdrawal,
+};
+
+pub use lana_app::deposit::{
+    StandingOrder as DomainStandingOrder, StandingOrderExecution as DomainStandingOrderExecution,
+    StandingOrderExecutionOutcome, StandingOrderFailureReason, StandingOrderFrequency,
+    StandingOrderSchedule, StandingOrderStatus, StandingOrdersByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct StandingOrder {
+    id: ID,
+    standing_order_id: UUID,
+    deposit_account_id: UUID,
+    amount: UsdCents,
+    currency: String,
+    destination_reference: String,
+    frequency: StandingOrderFrequency,
+    starts_on: Date,
+    ends_on: Option<Date>,
+    max_retries: u32,
+    next_run_date: Option<Date>,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainStandingOrder>,
+}
+
+impl From<DomainStandingOrder> for StandingOrder {
+    fn from(standing_order: DomainStandingOrder) -> Self {
+        Self {
+            id: standing_order.id.to_global_id(),
+            standing_order_id: UUID::from(standing_order.id),
+            deposit_account_id: standing_order.deposit_account_id.into(),
+            amount: minor_units(&standing_order.amount),
+            currency: standing_order.amount.currency().to_string(),
+            destination_reference: standing_order.destination_reference.clone(),
+            frequency: standing_order.schedule.frequency,
+            starts_on: standing_order.schedule.starts_on.into(),
+            ends_on: standing_order.schedule.ends_on.map(Date::from),
+            max_retries: standing_order.max_retries,
+            next_run_date: standing_order.next_run_date.map(Date::from),
+            created_at: standing_order.created_at().into(),
+            entity: Arc::new(standing_order),
+        }
+    }
+}
+
+#[ComplexObject]
+impl StandingOrder {
+    async fn status(&self) -> StandingOrderStatus {
+        self.entity.status()
+    }
+
+    async fn executions(&self) -> Vec<StandingOrderExecution> {
+        self.entity
+            .executions()
+            .into_iter()
+            .map(StandingOrderExecution::from)
+            .collect()
+    }
+
+    async fn account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.deposit_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct StandingOrderExecution {
+    run_date: Date,
+    attempt: u32,
+    outcome: StandingOrderExecutionOutcome,
+    failure_reason: Option<StandingOrderFailureReason>,
+
+    #[graphql(skip)]
+    execution: DomainStandingOrderExecution,
+}
+
+impl From<DomainStandingOrderExecution> for StandingOrderExecution {
+    fn from(execution: DomainStandingOrderExecution) -> Self {
+        Self {
+            run_date: execution.run_date.into(),
+            attempt: execution.attempt,
+            outcome: execution.outcome,
+            failure_reason: execution.failure_reason,
+            execution,
+        }
+    }
+}
+
+#[ComplexObject]
+impl StandingOrderExecution {
+    async fn withdrawal(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Withdrawal>> {
+        let Some(withdrawal_id) = self.execution.withdrawal_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(withdrawal_id).await?)
+    }
+}
+
+#[derive(InputObject)]
+pub struct StandingOrderCreateInput {
+    pub deposit_account_id: UUID,
+    pub amount: UsdCents,
+    pub currency: Option<String>,
+    pub destination_reference: String,
+    pub frequency: StandingOrderFrequency,
+    pub starts_on: Date,
+    pub ends_on: Option<Date>,
+    pub max_retries: Option<u32>,
+}
+crate::mutation_payload! { StandingOrderCreatePayload, standing_order: StandingOrder }
+
+#[derive(InputObject)]
+pub struct StandingOrderCancelInput {
+    pub standing_order_id: UUID,
+}
+crate::mutation_payload! { StandingOrderCancelPayload, standing_order: StandingOrder }
@@ -2,11 +2,14 @@ use async_graphql::*;
 use serde::{Deserialize, Serialize};
 
 pub use lana_app::terms::{
-    AnnualRatePct, CVLPct as DomainCVLPct, FacilityDuration as DomainDuration, InterestInterval,
-    ObligationDuration as DomainObligationDuration, OneTimeFeeRatePct,
-    TermValues as DomainTermValues,
+    AnnualRatePct, CVLPct as DomainCVLPct, CollateralHaircuts as DomainCollateralHaircuts,
+    FacilityDuration as DomainDuration, HaircutPct, InterestInterval,
+    ObligationDuration as DomainObligationDuration, OneTimeFeeRatePct, PaymentAllocationStrategy,
+    PrincipalRepayment, TermValues as DomainTermValues,
 };
 
+use super::credit_facility::CollateralAsset;
+
 #[derive(SimpleObject, Clone)]
 pub struct TermValues {
     annual_rate: AnnualRatePct,
@@ -17,6 +20,12 @@ pub struct TermValues {
     liquidation_cvl: CVLPct,
     margin_call_cvl: CVLPct,
     initial_cvl: CVLPct,
+    payment_allocation_strategy: PaymentAllocationStrategy,
+    principal_repayment: PrincipalRepayment,
+    principal_repayment_interval: InterestInterval,
+    prepayment_fee_rate: Option<OneTimeFeeRatePct>,
+    penalty_rate: Option<AnnualRatePct>,
+    collateral_haircuts: Vec<CollateralHaircut>,
 }
 
 impl From<DomainTermValues> for TermValues {
@@ -30,6 +39,16 @@ impl From<DomainTermValues> for TermValues {
             liquidation_cvl: values.liquidation_cvl.into(),
             margin_call_cvl: values.margin_call_cvl.into(),
             initial_cvl: values.initial_cvl.into(),
+            payment_allocation_strategy: values.payment_allocation_strategy,
+            principal_repayment: values.principal_repayment,
+            principal_repayment_interval: values.principal_repayment_interval,
+            prepayment_fee_rate: values.prepayment_fee_rate,
+            penalty_rate: values.penalty_rate,
+            collateral_haircuts: values
+                .collateral_haircuts
+                .iter()
+                .map(|(asset, haircut)| CollateralHaircut { asset, haircut })
+                .collect(),
         }
     }
 }
@@ -46,6 +65,37 @@ pub struct TermsInput {
     pub margin_call_cvl: CVLPctValue,
     pub initial_cvl: CVLPctValue,
     pub liquidation_cvl: CVLPctValue,
+    #[graphql(default)]
+    pub payment_allocation_strategy: PaymentAllocationStrategy,
+    #[graphql(default)]
+    pub principal_repayment: PrincipalRepayment,
+    #[graphql(default)]
+    pub principal_repayment_interval: InterestInterval,
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
+    pub penalty_rate: Option<AnnualRatePct>,
+    #[graphql(default)]
+    pub collateral_haircuts: Vec<CollateralHaircutInput>,
+}
+
+#[derive(SimpleObject, Clone)]
+pub(super) struct CollateralHaircut {
+    asset: CollateralAsset,
+    haircut: HaircutPct,
+}
+
+#[derive(InputObject)]
+pub struct CollateralHaircutInput {
+    pub asset: CollateralAsset,
+    pub haircut: HaircutPct,
+}
+
+pub(super) fn collateral_haircuts_from_input(
+    input: Vec<CollateralHaircutInput>,
+) -> DomainCollateralHaircuts {
+    input
+        .into_iter()
+        .map(|CollateralHaircutInput { asset, haircut }| (asset, haircut))
+        .collect()
 }
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
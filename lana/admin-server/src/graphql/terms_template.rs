@@ -24,7 +24,7 @@ impl From<DomainTermsTemplate> for TermsTemplate {
             id: terms.id.to_global_id(),
             created_at: terms.created_at().into(),
             terms_id: terms.id.into(),
-            values: terms.values.into(),
+            values: terms.values.clone().into(),
             entity: Arc::new(terms),
         }
     }
@@ -64,6 +64,16 @@ pub(super) struct TermsTemplateCreateInput {
     pub liquidation_cvl: CVLPctValue,
     pub margin_call_cvl: CVLPctValue,
     pub initial_cvl: CVLPctValue,
+    #[graphql(default)]
+    pub payment_allocation_strategy: PaymentAllocationStrategy,
+    #[graphql(default)]
+    pub principal_repayment: PrincipalRepayment,
+    #[graphql(default)]
+    pub principal_repayment_interval: InterestInterval,
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
+    pub penalty_rate: Option<AnnualRatePct>,
+    #[graphql(default)]
+    pub collateral_haircuts: Vec<CollateralHaircutInput>,
 }
 crate::mutation_payload! { TermsTemplateCreatePayload, terms_template: TermsTemplate }
 
@@ -81,5 +91,15 @@ pub(super) struct TermsTemplateUpdateInput {
     pub obligation_liquidation_duration_from_due: DurationInput,
     pub margin_call_cvl: CVLPctValue,
     pub initial_cvl: CVLPctValue,
+    #[graphql(default)]
+    pub payment_allocation_strategy: PaymentAllocationStrategy,
+    #[graphql(default)]
+    pub principal_repayment: PrincipalRepayment,
+    #[graphql(default)]
+    pub principal_repayment_interval: InterestInterval,
+    pub prepayment_fee_rate: Option<OneTimeFeeRatePct>,
+    pub penalty_rate: Option<AnnualRatePct>,
+    #[graphql(default)]
+    pub collateral_haircuts: Vec<CollateralHaircutInput>,
 }
 crate::mutation_payload! { TermsTemplateUpdatePayload, terms_template: TermsTemplate }
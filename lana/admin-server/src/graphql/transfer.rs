@@ -0,0 +1,99 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    approval_process::ApprovalProcess, deposit_account::DepositAccount, loader::LanaDataLoader,
+    primitives::minor_units,
+};
+
+pub use lana_app::deposit::{
+    Transfer as DomainTransfer, TransferStatus, TransfersByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Transfer {
+    id: ID,
+    transfer_id: UUID,
+    from_account_id: UUID,
+    to_account_id: UUID,
+    approval_process_id: Option<UUID>,
+    amount: UsdCents,
+    currency: String,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainTransfer>,
+}
+
+impl From<DomainTransfer> for Transfer {
+    fn from(transfer: DomainTransfer) -> Self {
+        Transfer {
+            id: transfer.id.to_global_id(),
+            created_at: transfer.created_at().into(),
+            transfer_id: UUID::from(transfer.id),
+            from_account_id: transfer.from_account_id.into(),
+            to_account_id: transfer.to_account_id.into(),
+            approval_process_id: transfer.approval_process_id.map(UUID::from),
+            amount: minor_units(&transfer.amount),
+            currency: transfer.amount.currency().to_string(),
+            entity: Arc::new(transfer),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Transfer {
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn status(&self) -> TransferStatus {
+        self.entity.status()
+    }
+
+    async fn failure_reason(&self) -> Option<&str> {
+        self.entity.failure_reason()
+    }
+
+    async fn approval_process(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ApprovalProcess>> {
+        let Some(approval_process_id) = self.entity.approval_process_id else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(approval_process_id).await?)
+    }
+
+    #[graphql(name = "fromAccount")]
+    async fn source_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.from_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+
+    async fn to_account(&self, ctx: &Context<'_>) -> async_graphql::Result<DepositAccount> {
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        let account = loader
+            .load_one(self.entity.to_account_id)
+            .await?
+            .expect("account not found");
+        Ok(account)
+    }
+}
+
+#[derive(InputObject)]
+pub struct TransferInitiateInput {
+    pub from_account_id: UUID,
+    pub to_account_id: UUID,
+    pub amount: UsdCents,
+    pub currency: Option<String>,
+    pub reference: Option<String>,
+}
+crate::mutation_payload! { TransferInitiatePayload, transfer: Transfer }
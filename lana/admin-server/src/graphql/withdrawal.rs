@@ -4,6 +4,7 @@ use crate::primitives::*;
 
 use super::{
     approval_process::ApprovalProcess, deposit_account::DepositAccount, loader::LanaDataLoader,
+    primitives::minor_units,
 };
 
 pub use lana_app::deposit::{
@@ -18,6 +19,7 @@ pub struct Withdrawal {
     account_id: UUID,
     approval_process_id: UUID,
     amount: UsdCents,
+    currency: String,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -32,7 +34,8 @@ impl From<lana_app::deposit::Withdrawal> for Withdrawal {
             account_id: withdraw.deposit_account_id.into(),
             withdrawal_id: UUID::from(withdraw.id),
             approval_process_id: UUID::from(withdraw.approval_process_id),
-            amount: withdraw.amount,
+            amount: minor_units(&withdraw.amount),
+            currency: withdraw.amount.currency().to_string(),
             entity: Arc::new(withdraw),
         }
     }
@@ -77,6 +80,7 @@ impl Withdrawal {
 pub struct WithdrawalInitiateInput {
     pub deposit_account_id: UUID,
     pub amount: UsdCents,
+    pub currency: Option<String>,
     pub reference: Option<String>,
 }
 crate::mutation_payload! { WithdrawalInitiatePayload, withdrawal: Withdrawal }
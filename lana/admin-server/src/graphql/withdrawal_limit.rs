@@ -0,0 +1,126 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::{
+    deposit_account::DepositAccount,
+    loader::LanaDataLoader,
+    primitives::{minor_units, money_from_minor_units},
+};
+
+pub use lana_app::deposit::{
+    DepositAccountType, WithdrawalLimit as DomainWithdrawalLimit, WithdrawalLimitAmounts,
+    WithdrawalLimitBreachAction, WithdrawalLimitScope, WithdrawalLimitsByCreatedAtCursor,
+};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct WithdrawalLimit {
+    id: ID,
+    withdrawal_limit_id: UUID,
+    currency: String,
+    daily: Option<UsdCents>,
+    weekly: Option<UsdCents>,
+    monthly: Option<UsdCents>,
+    breach_action: WithdrawalLimitBreachAction,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainWithdrawalLimit>,
+}
+
+impl From<DomainWithdrawalLimit> for WithdrawalLimit {
+    fn from(limit: DomainWithdrawalLimit) -> Self {
+        Self {
+            id: limit.id.to_global_id(),
+            withdrawal_limit_id: UUID::from(limit.id),
+            currency: limit.currency.to_string(),
+            daily: limit.amounts.daily.as_ref().map(minor_units),
+            weekly: limit.amounts.weekly.as_ref().map(minor_units),
+            monthly: limit.amounts.monthly.as_ref().map(minor_units),
+            breach_action: limit.breach_action,
+            created_at: limit.created_at().into(),
+            entity: Arc::new(limit),
+        }
+    }
+}
+
+#[ComplexObject]
+impl WithdrawalLimit {
+    async fn account_type(&self) -> Option<DepositAccountType> {
+        match self.entity.scope {
+            WithdrawalLimitScope::AccountType { account_type } => Some(account_type),
+            WithdrawalLimitScope::Account { .. } => None,
+        }
+    }
+
+    async fn deposit_account(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<DepositAccount>> {
+        let WithdrawalLimitScope::Account { deposit_account_id } = self.entity.scope else {
+            return Ok(None);
+        };
+        let loader = ctx.data_unchecked::<LanaDataLoader>();
+        Ok(loader.load_one(deposit_account_id).await?)
+    }
+}
+
+pub(super) fn domain_amounts(
+    daily: Option<UsdCents>,
+    weekly: Option<UsdCents>,
+    monthly: Option<UsdCents>,
+    currency: &Option<String>,
+) -> async_graphql::Result<WithdrawalLimitAmounts> {
+    let money = |amount: Option<UsdCents>| {
+        amount
+            .map(|amount| money_from_minor_units(amount, currency.clone()))
+            .transpose()
+    };
+    Ok(WithdrawalLimitAmounts {
+        daily: money(daily)?,
+        weekly: money(weekly)?,
+        monthly: money(monthly)?,
+    })
+}
+
+#[derive(OneofObject)]
+pub enum WithdrawalLimitScopeInput {
+    AccountType(DepositAccountType),
+    DepositAccountId(UUID),
+}
+
+impl From<WithdrawalLimitScopeInput> for WithdrawalLimitScope {
+    fn from(input: WithdrawalLimitScopeInput) -> Self {
+        match input {
+            WithdrawalLimitScopeInput::AccountType(account_type) => {
+                WithdrawalLimitScope::AccountType { account_type }
+            }
+            WithdrawalLimitScopeInput::DepositAccountId(id) => WithdrawalLimitScope::Account {
+                deposit_account_id: id.into(),
+            },
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitCreateInput {
+    pub scope: WithdrawalLimitScopeInput,
+    pub currency: Option<String>,
+    pub daily: Option<UsdCents>,
+    pub weekly: Option<UsdCents>,
+    pub monthly: Option<UsdCents>,
+    pub breach_action: WithdrawalLimitBreachAction,
+}
+crate::mutation_payload! { WithdrawalLimitCreatePayload, withdrawal_limit: WithdrawalLimit }
+
+#[derive(InputObject)]
+pub struct WithdrawalLimitUpdateInput {
+    pub withdrawal_limit_id: UUID,
+    pub currency: Option<String>,
+    pub daily: Option<UsdCents>,
+    pub weekly: Option<UsdCents>,
+    pub monthly: Option<UsdCents>,
+    pub breach_action: WithdrawalLimitBreachAction,
+}
+crate::mutation_payload! { WithdrawalLimitUpdatePayload, withdrawal_limit: WithdrawalLimit }
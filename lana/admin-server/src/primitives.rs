@@ -5,12 +5,14 @@ use serde::{Deserialize, Serialize};
 
 pub use lana_app::{
     primitives::{
-        AccountSpec, ApprovalProcessId, ChartId, CollateralId, CommitteeId, CreditFacilityId,
-        CustodianId, CustomerDocumentId, CustomerId, DepositAccountId, DepositId, DisbursalId,
-        DisbursalStatus, DocumentId, EntryId, LedgerTransactionId, ManualTransactionId,
-        ObligationInstallmentId, PaymentId, PermissionSetId, PolicyId, ReportId, RoleId, Satoshis,
-        SignedSatoshis, SignedUsdCents, Subject, TermsTemplateId, UsdCents, UserId, WalletId,
-        WithdrawalId,
+        AccountSpec, AccountingPeriodId, ApprovalProcessId, ChartId, CollateralId,
+        CollateralReleaseId, CollateralReleaseStatus, CommitteeId, CreditFacilityId, CustodianId,
+        CustomerDocumentId, CustomerId, DepositAccountId, DepositId, DepositProductId, DisbursalId,
+        DisbursalStatus, DocumentId, EntryId, HoldId, LedgerTransactionId, LiquidationProcessId,
+        ManualTransactionId, Money, ObligationInstallmentId, PaymentId, PermissionSetId, PolicyId,
+        ReconciliationId, RecurringJournalEntryId, ReportId, RoleId, Satoshis, SignedSatoshis,
+        SignedUsdCents, StandingOrderId, Subject, TermsTemplateId, TransferId, UsdCents, UserId,
+        WalletId, WithdrawalId, WithdrawalLimitId,
     },
     public_id::PublicId,
     report::ReportRunId,
@@ -116,6 +118,11 @@ impl_to_global_id! {
     PolicyId,
     CommitteeId,
     WithdrawalId,
+    TransferId,
+    DepositProductId,
+    HoldId,
+    WithdrawalLimitId,
+    StandingOrderId,
     DepositId,
     ReportId,
     ReportRunId,
@@ -124,6 +131,11 @@ impl_to_global_id! {
     DepositAccountId,
     LedgerTransactionId,
     ObligationInstallmentId,
+    LiquidationProcessId,
+    CollateralReleaseId,
     PublicId,
-    EntryId
+    EntryId,
+    AccountingPeriodId,
+    RecurringJournalEntryId,
+    ReconciliationId
 }
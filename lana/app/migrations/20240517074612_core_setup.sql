@@ -147,6 +147,87 @@ CREATE TABLE core_withdrawal_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_transfers (
+  id UUID PRIMARY KEY,
+  from_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  to_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_transfers_from_account_id ON core_transfers(from_account_id);
+CREATE INDEX idx_core_transfers_to_account_id ON core_transfers(to_account_id);
+
+CREATE TABLE core_transfer_events (
+  id UUID NOT NULL REFERENCES core_transfers(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_deposit_products (
+  id UUID PRIMARY KEY,
+  name VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_deposit_product_events (
+  id UUID NOT NULL REFERENCES core_deposit_products(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_holds (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_holds_deposit_account_id ON core_holds(deposit_account_id);
+
+CREATE TABLE core_hold_events (
+  id UUID NOT NULL REFERENCES core_holds(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_withdrawal_limits (
+  id UUID PRIMARY KEY,
+  scope_key VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_withdrawal_limit_events (
+  id UUID NOT NULL REFERENCES core_withdrawal_limits(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_standing_orders (
+  id UUID PRIMARY KEY,
+  deposit_account_id UUID NOT NULL REFERENCES core_deposit_accounts(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_standing_orders_deposit_account_id ON core_standing_orders(deposit_account_id);
+
+CREATE TABLE core_standing_order_events (
+  id UUID NOT NULL REFERENCES core_standing_orders(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_terms_templates (
   id UUID PRIMARY KEY,
   name VARCHAR NOT NULL UNIQUE,
@@ -334,6 +415,22 @@ CREATE TABLE core_disbursal_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_collateral_releases (
+  id UUID PRIMARY KEY,
+  credit_facility_id UUID NOT NULL REFERENCES core_credit_facilities(id),
+  approval_process_id UUID NOT NULL REFERENCES core_approval_processes(id),
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_collateral_release_events (
+  id UUID NOT NULL REFERENCES core_collateral_releases(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE core_interest_accrual_cycles (
   id UUID PRIMARY KEY,
   credit_facility_id UUID NOT NULL REFERENCES core_credit_facilities(id),
@@ -452,8 +549,10 @@ CREATE TABLE core_manual_transactions (
   id UUID PRIMARY KEY,
   reference VARCHAR NOT NULL UNIQUE,
   ledger_transaction_id UUID NOT NULL,
+  status VARCHAR NOT NULL,
   created_at TIMESTAMPTZ NOT NULL
 );
+CREATE INDEX idx_core_manual_transactions_status_created_at ON core_manual_transactions(status, created_at);
 
 CREATE TABLE core_manual_transaction_events (
   id UUID NOT NULL REFERENCES core_manual_transactions(id),
@@ -464,6 +563,89 @@ CREATE TABLE core_manual_transaction_events (
   UNIQUE(id, sequence)
 );
 
+CREATE TABLE core_accounting_periods (
+  id UUID PRIMARY KEY,
+  period_type VARCHAR NOT NULL,
+  starts_on DATE NOT NULL,
+  ends_on DATE NOT NULL,
+  status VARCHAR NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(period_type, starts_on)
+);
+CREATE INDEX idx_core_accounting_periods_range ON core_accounting_periods (starts_on, ends_on);
+
+CREATE TABLE core_accounting_period_events (
+  id UUID NOT NULL REFERENCES core_accounting_periods(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_fx_rates (
+  id UUID PRIMARY KEY,
+  currency VARCHAR NOT NULL,
+  rate_date DATE NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(currency, rate_date)
+);
+
+CREATE TABLE core_fx_rate_events (
+  id UUID NOT NULL REFERENCES core_fx_rates(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_fx_revaluations (
+  id UUID PRIMARY KEY,
+  revaluation_date DATE NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_fx_revaluation_events (
+  id UUID NOT NULL REFERENCES core_fx_revaluations(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_recurring_journal_entries (
+  id UUID PRIMARY KEY,
+  reference VARCHAR NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE core_recurring_journal_entry_events (
+  id UUID NOT NULL REFERENCES core_recurring_journal_entries(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
+CREATE TABLE core_reconciliations (
+  id UUID PRIMARY KEY,
+  ledger_account_id UUID NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_core_reconciliations_ledger_account_id_created_at ON core_reconciliations(ledger_account_id, created_at);
+
+CREATE TABLE core_reconciliation_events (
+  id UUID NOT NULL REFERENCES core_reconciliations(id),
+  sequence INT NOT NULL,
+  event_type VARCHAR NOT NULL,
+  event JSONB NOT NULL,
+  recorded_at TIMESTAMPTZ NOT NULL,
+  UNIQUE(id, sequence)
+);
+
 CREATE TABLE jobs (
   id UUID NOT NULL UNIQUE,
   unique_per_type BOOLEAN NOT NULL,
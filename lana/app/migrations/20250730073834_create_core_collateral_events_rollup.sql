@@ -8,9 +8,14 @@ CREATE TABLE core_collateral_events_rollup (
   abs_diff BIGINT,
   account_id UUID,
   action VARCHAR,
+  asset VARCHAR,
   collateral_amount BIGINT,
+  collateral_release_id UUID,
+  counterpart_credit_facility_id UUID,
   credit_facility_id UUID,
   custody_wallet_id UUID,
+  liquidation_process_id UUID,
+  quantity VARCHAR,
 
   -- Collection rollups
   audit_entry_ids BIGINT[],
@@ -37,7 +42,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'updated_via_manual_input', 'updated_via_custodian_sync', 'updated') THEN
+  IF event_type NOT IN ('initialized', 'updated_via_manual_input', 'updated_via_custodian_sync', 'updated_via_liquidation', 'updated_via_rollover', 'updated_via_release', 'asset_position_updated', 'updated') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -52,6 +57,7 @@ BEGIN
     new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
     new_row.account_id := (NEW.event ->> 'account_id')::UUID;
     new_row.action := (NEW.event ->> 'action');
+    new_row.asset := (NEW.event ->> 'asset');
     new_row.audit_entry_ids := CASE
        WHEN NEW.event ? 'audit_entry_ids' THEN
          ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
@@ -59,6 +65,8 @@ BEGIN
      END
 ;
     new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+    new_row.collateral_release_id := (NEW.event ->> 'collateral_release_id')::UUID;
+    new_row.counterpart_credit_facility_id := (NEW.event ->> 'counterpart_credit_facility_id')::UUID;
     new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
     new_row.custody_wallet_id := (NEW.event ->> 'custody_wallet_id')::UUID;
     new_row.ledger_tx_ids := CASE
@@ -67,16 +75,23 @@ BEGIN
        ELSE ARRAY[]::UUID[]
      END
 ;
+    new_row.liquidation_process_id := (NEW.event ->> 'liquidation_process_id')::UUID;
+    new_row.quantity := (NEW.event ->> 'quantity');
   ELSE
     -- Default all fields to current values
     new_row.abs_diff := current_row.abs_diff;
     new_row.account_id := current_row.account_id;
     new_row.action := current_row.action;
+    new_row.asset := current_row.asset;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
     new_row.collateral_amount := current_row.collateral_amount;
+    new_row.collateral_release_id := current_row.collateral_release_id;
+    new_row.counterpart_credit_facility_id := current_row.counterpart_credit_facility_id;
     new_row.credit_facility_id := current_row.credit_facility_id;
     new_row.custody_wallet_id := current_row.custody_wallet_id;
     new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.liquidation_process_id := current_row.liquidation_process_id;
+    new_row.quantity := current_row.quantity;
   END IF;
 
   -- Update only the fields that are modified by the specific event
@@ -94,6 +109,30 @@ BEGIN
       new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
       new_row.action := (NEW.event ->> 'action');
       new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+    WHEN 'updated_via_liquidation' THEN
+      new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
+      new_row.action := (NEW.event ->> 'action');
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+      new_row.liquidation_process_id := (NEW.event ->> 'liquidation_process_id')::UUID;
+    WHEN 'updated_via_rollover' THEN
+      new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
+      new_row.action := (NEW.event ->> 'action');
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+      new_row.counterpart_credit_facility_id := (NEW.event ->> 'counterpart_credit_facility_id')::UUID;
+    WHEN 'updated_via_release' THEN
+      new_row.abs_diff := (NEW.event ->> 'abs_diff')::BIGINT;
+      new_row.action := (NEW.event ->> 'action');
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral_amount := (NEW.event ->> 'collateral_amount')::BIGINT;
+      new_row.collateral_release_id := (NEW.event ->> 'collateral_release_id')::UUID;
+    WHEN 'asset_position_updated' THEN
+      new_row.action := (NEW.event ->> 'action');
+      new_row.asset := (NEW.event ->> 'asset');
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+      new_row.quantity := (NEW.event ->> 'quantity');
     WHEN 'updated' THEN
       new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
   END CASE;
@@ -106,11 +145,16 @@ BEGIN
     abs_diff,
     account_id,
     action,
+    asset,
     audit_entry_ids,
     collateral_amount,
+    collateral_release_id,
+    counterpart_credit_facility_id,
     credit_facility_id,
     custody_wallet_id,
-    ledger_tx_ids
+    ledger_tx_ids,
+    liquidation_process_id,
+    quantity
   )
   VALUES (
     new_row.id,
@@ -120,11 +164,16 @@ BEGIN
     new_row.abs_diff,
     new_row.account_id,
     new_row.action,
+    new_row.asset,
     new_row.audit_entry_ids,
     new_row.collateral_amount,
+    new_row.collateral_release_id,
+    new_row.counterpart_credit_facility_id,
     new_row.credit_facility_id,
     new_row.custody_wallet_id,
-    new_row.ledger_tx_ids
+    new_row.ledger_tx_ids,
+    new_row.liquidation_process_id,
+    new_row.quantity
   );
 
   RETURN NEW;
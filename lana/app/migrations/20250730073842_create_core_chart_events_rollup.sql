@@ -6,8 +6,10 @@ CREATE TABLE core_chart_events_rollup (
   modified_at TIMESTAMPTZ NOT NULL,
   -- Flattened fields from the event JSON
   code JSONB,
+  from_parent JSONB,
   name VARCHAR,
   reference VARCHAR,
+  to_parent JSONB,
 
   -- Collection rollups
   audit_entry_ids BIGINT[],
@@ -36,7 +38,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'node_added', 'manual_transaction_account_added') THEN
+  IF event_type NOT IN ('initialized', 'node_added', 'manual_transaction_account_added', 'node_renamed', 'node_deactivated', 'node_moved') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -55,6 +57,7 @@ BEGIN
      END
 ;
     new_row.code := (NEW.event -> 'code');
+    new_row.from_parent := (NEW.event -> 'from_parent');
     new_row.ledger_account_set_ids := CASE
        WHEN NEW.event ? 'ledger_account_set_ids' THEN
          ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'ledger_account_set_ids'))
@@ -75,15 +78,18 @@ BEGIN
      END
 ;
     new_row.reference := (NEW.event ->> 'reference');
+    new_row.to_parent := (NEW.event -> 'to_parent');
   ELSE
     -- Default all fields to current values
     new_row.audit_entry_ids := current_row.audit_entry_ids;
     new_row.code := current_row.code;
+    new_row.from_parent := current_row.from_parent;
     new_row.ledger_account_set_ids := current_row.ledger_account_set_ids;
     new_row.manual_ledger_account_ids := current_row.manual_ledger_account_ids;
     new_row.name := current_row.name;
     new_row.node_specs := current_row.node_specs;
     new_row.reference := current_row.reference;
+    new_row.to_parent := current_row.to_parent;
   END IF;
 
   -- Update only the fields that are modified by the specific event
@@ -100,6 +106,18 @@ BEGIN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.code := (NEW.event -> 'code');
       new_row.manual_ledger_account_ids := array_append(COALESCE(current_row.manual_ledger_account_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_account_id')::UUID);
+    WHEN 'node_renamed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.code := (NEW.event -> 'code');
+      new_row.name := (NEW.event ->> 'name');
+    WHEN 'node_deactivated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.code := (NEW.event -> 'code');
+    WHEN 'node_moved' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.code := (NEW.event -> 'code');
+      new_row.from_parent := (NEW.event -> 'from_parent');
+      new_row.to_parent := (NEW.event -> 'to_parent');
   END CASE;
 
   INSERT INTO core_chart_events_rollup (
@@ -109,11 +127,13 @@ BEGIN
     modified_at,
     audit_entry_ids,
     code,
+    from_parent,
     ledger_account_set_ids,
     manual_ledger_account_ids,
     name,
     node_specs,
-    reference
+    reference,
+    to_parent
   )
   VALUES (
     new_row.id,
@@ -122,11 +142,13 @@ BEGIN
     new_row.modified_at,
     new_row.audit_entry_ids,
     new_row.code,
+    new_row.from_parent,
     new_row.ledger_account_set_ids,
     new_row.manual_ledger_account_ids,
     new_row.name,
     new_row.node_specs,
-    new_row.reference
+    new_row.reference,
+    new_row.to_parent
   );
 
   RETURN NEW;
@@ -5,12 +5,18 @@ CREATE TABLE core_liquidation_process_events_rollup (
   created_at TIMESTAMPTZ NOT NULL,
   modified_at TIMESTAMPTZ NOT NULL,
   -- Flattened fields from the event JSON
+  amount BIGINT,
+  collateral BIGINT,
   credit_facility_id UUID,
+  deposit_account_id UUID,
   effective VARCHAR,
   in_liquidation_account_id UUID,
   initial_amount BIGINT,
   ledger_tx_id UUID,
   obligation_id UUID,
+  payment_id UUID,
+  price JSONB,
+  proceeds BIGINT,
 
   -- Collection rollups
   audit_entry_ids BIGINT[],
@@ -39,7 +45,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'completed') THEN
+  IF event_type NOT IN ('initialized', 'collateral_reserved', 'collateral_sold', 'proceeds_allocated', 'surplus_returned', 'completed') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -51,29 +57,41 @@ BEGIN
 
   -- Initialize fields with default values if this is a new record
   IF current_row.id IS NULL THEN
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
     new_row.audit_entry_ids := CASE
        WHEN NEW.event ? 'audit_entry_ids' THEN
          ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
        ELSE ARRAY[]::BIGINT[]
      END
 ;
+    new_row.collateral := (NEW.event ->> 'collateral')::BIGINT;
     new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
     new_row.effective := (NEW.event ->> 'effective');
     new_row.in_liquidation_account_id := (NEW.event ->> 'in_liquidation_account_id')::UUID;
     new_row.initial_amount := (NEW.event ->> 'initial_amount')::BIGINT;
     new_row.is_completed := false;
     new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
     new_row.obligation_id := (NEW.event ->> 'obligation_id')::UUID;
+    new_row.payment_id := (NEW.event ->> 'payment_id')::UUID;
+    new_row.price := (NEW.event -> 'price');
+    new_row.proceeds := (NEW.event ->> 'proceeds')::BIGINT;
   ELSE
     -- Default all fields to current values
+    new_row.amount := current_row.amount;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.collateral := current_row.collateral;
     new_row.credit_facility_id := current_row.credit_facility_id;
+    new_row.deposit_account_id := current_row.deposit_account_id;
     new_row.effective := current_row.effective;
     new_row.in_liquidation_account_id := current_row.in_liquidation_account_id;
     new_row.initial_amount := current_row.initial_amount;
     new_row.is_completed := current_row.is_completed;
     new_row.ledger_tx_id := current_row.ledger_tx_id;
     new_row.obligation_id := current_row.obligation_id;
+    new_row.payment_id := current_row.payment_id;
+    new_row.price := current_row.price;
+    new_row.proceeds := current_row.proceeds;
   END IF;
 
   -- Update only the fields that are modified by the specific event
@@ -86,9 +104,33 @@ BEGIN
       new_row.initial_amount := (NEW.event ->> 'initial_amount')::BIGINT;
       new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
       new_row.obligation_id := (NEW.event ->> 'obligation_id')::UUID;
+    WHEN 'collateral_reserved' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral := (NEW.event ->> 'collateral')::BIGINT;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    WHEN 'collateral_sold' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral := (NEW.event ->> 'collateral')::BIGINT;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+      new_row.price := (NEW.event -> 'price');
+      new_row.proceeds := (NEW.event ->> 'proceeds')::BIGINT;
+    WHEN 'proceeds_allocated' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.payment_id := (NEW.event ->> 'payment_id')::UUID;
+    WHEN 'surplus_returned' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
     WHEN 'completed' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.is_completed := true;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
   END CASE;
 
   INSERT INTO core_liquidation_process_events_rollup (
@@ -96,28 +138,40 @@ BEGIN
     version,
     created_at,
     modified_at,
+    amount,
     audit_entry_ids,
+    collateral,
     credit_facility_id,
+    deposit_account_id,
     effective,
     in_liquidation_account_id,
     initial_amount,
     is_completed,
     ledger_tx_id,
-    obligation_id
+    obligation_id,
+    payment_id,
+    price,
+    proceeds
   )
   VALUES (
     new_row.id,
     new_row.version,
     new_row.created_at,
     new_row.modified_at,
+    new_row.amount,
     new_row.audit_entry_ids,
+    new_row.collateral,
     new_row.credit_facility_id,
+    new_row.deposit_account_id,
     new_row.effective,
     new_row.in_liquidation_account_id,
     new_row.initial_amount,
     new_row.is_completed,
     new_row.ledger_tx_id,
-    new_row.obligation_id
+    new_row.obligation_id,
+    new_row.payment_id,
+    new_row.price,
+    new_row.proceeds
   );
 
   RETURN NEW;
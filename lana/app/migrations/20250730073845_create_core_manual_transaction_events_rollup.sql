@@ -5,9 +5,16 @@ CREATE TABLE core_manual_transaction_events_rollup (
   created_at TIMESTAMPTZ NOT NULL,
   modified_at TIMESTAMPTZ NOT NULL,
   -- Flattened fields from the event JSON
+  approval_process_id UUID,
+  approved BOOLEAN,
   description VARCHAR,
+  effective VARCHAR,
+  entries JSONB,
   ledger_transaction_id UUID,
+  reason VARCHAR,
   reference VARCHAR,
+  reversal_of UUID,
+  reversal_transaction_id UUID,
 
   -- Collection rollups
   audit_entry_ids BIGINT[]
@@ -33,7 +40,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized') THEN
+  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'failed', 'reversed') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -45,6 +52,8 @@ BEGIN
 
   -- Initialize fields with default values if this is a new record
   IF current_row.id IS NULL THEN
+    new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+    new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
     new_row.audit_entry_ids := CASE
        WHEN NEW.event ? 'audit_entry_ids' THEN
          ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
@@ -52,23 +61,49 @@ BEGIN
      END
 ;
     new_row.description := (NEW.event ->> 'description');
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.entries := (NEW.event -> 'entries');
     new_row.ledger_transaction_id := (NEW.event ->> 'ledger_transaction_id')::UUID;
+    new_row.reason := (NEW.event ->> 'reason');
     new_row.reference := (NEW.event ->> 'reference');
+    new_row.reversal_of := (NEW.event ->> 'reversal_of')::UUID;
+    new_row.reversal_transaction_id := (NEW.event ->> 'reversal_transaction_id')::UUID;
   ELSE
     -- Default all fields to current values
+    new_row.approval_process_id := current_row.approval_process_id;
+    new_row.approved := current_row.approved;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
     new_row.description := current_row.description;
+    new_row.effective := current_row.effective;
+    new_row.entries := current_row.entries;
     new_row.ledger_transaction_id := current_row.ledger_transaction_id;
+    new_row.reason := current_row.reason;
     new_row.reference := current_row.reference;
+    new_row.reversal_of := current_row.reversal_of;
+    new_row.reversal_transaction_id := current_row.reversal_transaction_id;
   END IF;
 
   -- Update only the fields that are modified by the specific event
   CASE event_type
     WHEN 'initialized' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.description := (NEW.event ->> 'description');
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.entries := (NEW.event -> 'entries');
       new_row.ledger_transaction_id := (NEW.event ->> 'ledger_transaction_id')::UUID;
       new_row.reference := (NEW.event ->> 'reference');
+      new_row.reversal_of := (NEW.event ->> 'reversal_of')::UUID;
+    WHEN 'approval_process_concluded' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'failed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.reason := (NEW.event ->> 'reason');
+    WHEN 'reversed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.reversal_transaction_id := (NEW.event ->> 'reversal_transaction_id')::UUID;
   END CASE;
 
   INSERT INTO core_manual_transaction_events_rollup (
@@ -76,20 +111,34 @@ BEGIN
     version,
     created_at,
     modified_at,
+    approval_process_id,
+    approved,
     audit_entry_ids,
     description,
+    effective,
+    entries,
     ledger_transaction_id,
-    reference
+    reason,
+    reference,
+    reversal_of,
+    reversal_transaction_id
   )
   VALUES (
     new_row.id,
     new_row.version,
     new_row.created_at,
     new_row.modified_at,
+    new_row.approval_process_id,
+    new_row.approved,
     new_row.audit_entry_ids,
     new_row.description,
+    new_row.effective,
+    new_row.entries,
     new_row.ledger_transaction_id,
-    new_row.reference
+    new_row.reason,
+    new_row.reference,
+    new_row.reversal_of,
+    new_row.reversal_transaction_id
   );
 
   RETURN NEW;
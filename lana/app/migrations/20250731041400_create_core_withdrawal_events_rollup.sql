@@ -5,7 +5,7 @@ CREATE TABLE core_withdrawal_events_rollup (
   created_at TIMESTAMPTZ NOT NULL,
   modified_at TIMESTAMPTZ NOT NULL,
   -- Flattened fields from the event JSON
-  amount BIGINT,
+  amount JSONB,
   approval_process_id UUID,
   approved BOOLEAN,
   deposit_account_id UUID,
@@ -54,7 +54,7 @@ BEGIN
 
   -- Initialize fields with default values if this is a new record
   IF current_row.id IS NULL THEN
-    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.amount := (NEW.event -> 'amount');
     new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
     new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
     new_row.audit_entry_ids := CASE
@@ -93,7 +93,7 @@ BEGIN
   -- Update only the fields that are modified by the specific event
   CASE event_type
     WHEN 'initialized' THEN
-      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.amount := (NEW.event -> 'amount');
       new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
@@ -13,10 +13,12 @@ CREATE TABLE core_disbursal_events_rollup (
   due_date TIMESTAMPTZ,
   effective VARCHAR,
   facility_id UUID,
+  installment_obligation_ids JSONB,
   ledger_tx_id UUID,
   liquidation_date TIMESTAMPTZ,
   obligation_id UUID,
   overdue_date TIMESTAMPTZ,
+  principal_installments JSONB,
   public_id VARCHAR,
 
   -- Collection rollups
@@ -74,6 +76,7 @@ BEGIN
     new_row.due_date := (NEW.event ->> 'due_date')::TIMESTAMPTZ;
     new_row.effective := (NEW.event ->> 'effective');
     new_row.facility_id := (NEW.event ->> 'facility_id')::UUID;
+    new_row.installment_obligation_ids := (NEW.event -> 'installment_obligation_ids');
     new_row.is_approval_process_concluded := false;
     new_row.is_cancelled := false;
     new_row.is_settled := false;
@@ -81,6 +84,7 @@ BEGIN
     new_row.liquidation_date := (NEW.event ->> 'liquidation_date')::TIMESTAMPTZ;
     new_row.obligation_id := (NEW.event ->> 'obligation_id')::UUID;
     new_row.overdue_date := (NEW.event ->> 'overdue_date')::TIMESTAMPTZ;
+    new_row.principal_installments := (NEW.event -> 'principal_installments');
     new_row.public_id := (NEW.event ->> 'public_id');
   ELSE
     -- Default all fields to current values
@@ -93,6 +97,7 @@ BEGIN
     new_row.due_date := current_row.due_date;
     new_row.effective := current_row.effective;
     new_row.facility_id := current_row.facility_id;
+    new_row.installment_obligation_ids := current_row.installment_obligation_ids;
     new_row.is_approval_process_concluded := current_row.is_approval_process_concluded;
     new_row.is_cancelled := current_row.is_cancelled;
     new_row.is_settled := current_row.is_settled;
@@ -100,6 +105,7 @@ BEGIN
     new_row.liquidation_date := current_row.liquidation_date;
     new_row.obligation_id := current_row.obligation_id;
     new_row.overdue_date := current_row.overdue_date;
+    new_row.principal_installments := current_row.principal_installments;
     new_row.public_id := current_row.public_id;
   END IF;
 
@@ -115,6 +121,7 @@ BEGIN
       new_row.facility_id := (NEW.event ->> 'facility_id')::UUID;
       new_row.liquidation_date := (NEW.event ->> 'liquidation_date')::TIMESTAMPTZ;
       new_row.overdue_date := (NEW.event ->> 'overdue_date')::TIMESTAMPTZ;
+      new_row.principal_installments := (NEW.event -> 'principal_installments');
       new_row.public_id := (NEW.event ->> 'public_id');
     WHEN 'approval_process_concluded' THEN
       new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
@@ -125,6 +132,7 @@ BEGIN
       new_row.amount := (NEW.event ->> 'amount')::BIGINT;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.effective := (NEW.event ->> 'effective');
+      new_row.installment_obligation_ids := (NEW.event -> 'installment_obligation_ids');
       new_row.is_settled := true;
       new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
       new_row.obligation_id := (NEW.event ->> 'obligation_id')::UUID;
@@ -148,6 +156,7 @@ BEGIN
     due_date,
     effective,
     facility_id,
+    installment_obligation_ids,
     is_approval_process_concluded,
     is_cancelled,
     is_settled,
@@ -155,6 +164,7 @@ BEGIN
     liquidation_date,
     obligation_id,
     overdue_date,
+    principal_installments,
     public_id
   )
   VALUES (
@@ -171,6 +181,7 @@ BEGIN
     new_row.due_date,
     new_row.effective,
     new_row.facility_id,
+    new_row.installment_obligation_ids,
     new_row.is_approval_process_concluded,
     new_row.is_cancelled,
     new_row.is_settled,
@@ -178,6 +189,7 @@ BEGIN
     new_row.liquidation_date,
     new_row.obligation_id,
     new_row.overdue_date,
+    new_row.principal_installments,
     new_row.public_id
   );
 
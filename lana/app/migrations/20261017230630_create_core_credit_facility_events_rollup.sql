@@ -10,17 +10,25 @@ CREATE TABLE core_credit_facility_events_rollup (
   amount BIGINT,
   approval_process_id UUID,
   approved BOOLEAN,
+  capitalize_interest BOOLEAN,
   collateral BIGINT,
   collateral_id UUID,
   collateralization_ratio VARCHAR,
   collateralization_state VARCHAR,
   customer_id UUID,
+  customer_type VARCHAR,
   disbursal_credit_account_id UUID,
+  effective VARCHAR,
+  fee_amount BIGINT,
   interest_accrual_cycle_idx INTEGER,
   interest_period JSONB,
+  matures_at TIMESTAMPTZ,
   outstanding JSONB,
+  payment_id UUID,
   price JSONB,
   public_id VARCHAR,
+  rolled_over_from UUID,
+  rolled_over_to UUID,
   terms JSONB,
 
   -- Collection rollups
@@ -55,7 +63,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'activated', 'interest_accrual_cycle_started', 'interest_accrual_cycle_concluded', 'collateralization_state_changed', 'collateralization_ratio_changed', 'completed') THEN
+  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'activated', 'interest_accrual_cycle_started', 'interest_accrual_cycle_concluded', 'collateralization_state_changed', 'collateralization_ratio_changed', 'prepayment_recorded', 'unapplied_funds_recorded', 'unapplied_funds_applied', 'unapplied_funds_refunded', 'penalty_accrued', 'terms_modification_requested', 'terms_modified', 'terms_modification_denied', 'rollover_requested', 'rollover_denied', 'rolled_over_from', 'interest_capitalized', 'rolled_over', 'completed') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -72,6 +80,7 @@ BEGIN
     new_row.amount := (NEW.event ->> 'amount')::BIGINT;
     new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
     new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+    new_row.capitalize_interest := (NEW.event ->> 'capitalize_interest')::BOOLEAN;
     new_row.audit_entry_ids := CASE
        WHEN NEW.event ? 'audit_entry_ids' THEN
          ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
@@ -83,7 +92,10 @@ BEGIN
     new_row.collateralization_ratio := (NEW.event ->> 'collateralization_ratio');
     new_row.collateralization_state := (NEW.event ->> 'collateralization_state');
     new_row.customer_id := (NEW.event ->> 'customer_id')::UUID;
+    new_row.customer_type := (NEW.event ->> 'customer_type');
     new_row.disbursal_credit_account_id := (NEW.event ->> 'disbursal_credit_account_id')::UUID;
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.fee_amount := (NEW.event ->> 'fee_amount')::BIGINT;
     new_row.interest_accrual_cycle_idx := (NEW.event ->> 'interest_accrual_cycle_idx')::INTEGER;
     new_row.interest_accrual_ids := CASE
        WHEN NEW.event ? 'interest_accrual_ids' THEN
@@ -101,6 +113,7 @@ BEGIN
        ELSE ARRAY[]::UUID[]
      END
 ;
+    new_row.matures_at := (NEW.event ->> 'matures_at')::TIMESTAMPTZ;
     new_row.obligation_ids := CASE
        WHEN NEW.event ? 'obligation_ids' THEN
          ARRAY(SELECT value::text::UUID FROM jsonb_array_elements_text(NEW.event -> 'obligation_ids'))
@@ -108,8 +121,11 @@ BEGIN
      END
 ;
     new_row.outstanding := (NEW.event -> 'outstanding');
+    new_row.payment_id := (NEW.event ->> 'payment_id')::UUID;
     new_row.price := (NEW.event -> 'price');
     new_row.public_id := (NEW.event ->> 'public_id');
+    new_row.rolled_over_from := (NEW.event ->> 'rolled_over_from')::UUID;
+    new_row.rolled_over_to := (NEW.event ->> 'rolled_over_to')::UUID;
     new_row.terms := (NEW.event -> 'terms');
   ELSE
     -- Default all fields to current values
@@ -118,13 +134,17 @@ BEGIN
     new_row.amount := current_row.amount;
     new_row.approval_process_id := current_row.approval_process_id;
     new_row.approved := current_row.approved;
+    new_row.capitalize_interest := current_row.capitalize_interest;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
     new_row.collateral := current_row.collateral;
     new_row.collateral_id := current_row.collateral_id;
     new_row.collateralization_ratio := current_row.collateralization_ratio;
     new_row.collateralization_state := current_row.collateralization_state;
     new_row.customer_id := current_row.customer_id;
+    new_row.customer_type := current_row.customer_type;
     new_row.disbursal_credit_account_id := current_row.disbursal_credit_account_id;
+    new_row.effective := current_row.effective;
+    new_row.fee_amount := current_row.fee_amount;
     new_row.interest_accrual_cycle_idx := current_row.interest_accrual_cycle_idx;
     new_row.interest_accrual_ids := current_row.interest_accrual_ids;
     new_row.interest_period := current_row.interest_period;
@@ -132,10 +152,14 @@ BEGIN
     new_row.is_approval_process_concluded := current_row.is_approval_process_concluded;
     new_row.is_completed := current_row.is_completed;
     new_row.ledger_tx_ids := current_row.ledger_tx_ids;
+    new_row.matures_at := current_row.matures_at;
     new_row.obligation_ids := current_row.obligation_ids;
     new_row.outstanding := current_row.outstanding;
+    new_row.payment_id := current_row.payment_id;
     new_row.price := current_row.price;
     new_row.public_id := current_row.public_id;
+    new_row.rolled_over_from := current_row.rolled_over_from;
+    new_row.rolled_over_to := current_row.rolled_over_to;
     new_row.terms := current_row.terms;
   END IF;
 
@@ -181,6 +205,69 @@ BEGIN
     WHEN 'collateralization_ratio_changed' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.collateralization_ratio := (NEW.event ->> 'collateralization_ratio');
+    WHEN 'prepayment_recorded' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.fee_amount := (NEW.event ->> 'fee_amount')::BIGINT;
+      new_row.payment_id := (NEW.event ->> 'payment_id')::UUID;
+    WHEN 'unapplied_funds_recorded' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.payment_id := (NEW.event ->> 'payment_id')::UUID;
+    WHEN 'unapplied_funds_applied' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.payment_id := (NEW.event ->> 'payment_id')::UUID;
+    WHEN 'unapplied_funds_refunded' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+    WHEN 'penalty_accrued' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+    WHEN 'terms_modification_requested' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.terms := (NEW.event -> 'terms');
+    WHEN 'terms_modified' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.matures_at := (NEW.event ->> 'matures_at')::TIMESTAMPTZ;
+      new_row.terms := (NEW.event -> 'terms');
+    WHEN 'terms_modification_denied' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'rollover_requested' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.capitalize_interest := (NEW.event ->> 'capitalize_interest')::BOOLEAN;
+      new_row.customer_type := (NEW.event ->> 'customer_type');
+      new_row.terms := (NEW.event -> 'terms');
+    WHEN 'rollover_denied' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'rolled_over_from' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral := (NEW.event ->> 'collateral')::BIGINT;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.rolled_over_from := (NEW.event ->> 'rolled_over_from')::UUID;
+    WHEN 'interest_capitalized' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.ledger_tx_ids := array_append(COALESCE(current_row.ledger_tx_ids, ARRAY[]::UUID[]), (NEW.event ->> 'ledger_tx_id')::UUID);
+    WHEN 'rolled_over' THEN
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral := (NEW.event ->> 'collateral')::BIGINT;
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.rolled_over_to := (NEW.event ->> 'rolled_over_to')::UUID;
     WHEN 'completed' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.is_completed := true;
@@ -196,13 +283,17 @@ BEGIN
     amount,
     approval_process_id,
     approved,
+    capitalize_interest,
     audit_entry_ids,
     collateral,
     collateral_id,
     collateralization_ratio,
     collateralization_state,
     customer_id,
+    customer_type,
     disbursal_credit_account_id,
+    effective,
+    fee_amount,
     interest_accrual_cycle_idx,
     interest_accrual_ids,
     interest_period,
@@ -210,10 +301,14 @@ BEGIN
     is_approval_process_concluded,
     is_completed,
     ledger_tx_ids,
+    matures_at,
     obligation_ids,
     outstanding,
+    payment_id,
     price,
     public_id,
+    rolled_over_from,
+    rolled_over_to,
     terms
   )
   VALUES (
@@ -226,13 +321,17 @@ BEGIN
     new_row.amount,
     new_row.approval_process_id,
     new_row.approved,
+    new_row.capitalize_interest,
     new_row.audit_entry_ids,
     new_row.collateral,
     new_row.collateral_id,
     new_row.collateralization_ratio,
     new_row.collateralization_state,
     new_row.customer_id,
+    new_row.customer_type,
     new_row.disbursal_credit_account_id,
+    new_row.effective,
+    new_row.fee_amount,
     new_row.interest_accrual_cycle_idx,
     new_row.interest_accrual_ids,
     new_row.interest_period,
@@ -240,10 +339,14 @@ BEGIN
     new_row.is_approval_process_concluded,
     new_row.is_completed,
     new_row.ledger_tx_ids,
+    new_row.matures_at,
     new_row.obligation_ids,
     new_row.outstanding,
+    new_row.payment_id,
     new_row.price,
     new_row.public_id,
+    new_row.rolled_over_from,
+    new_row.rolled_over_to,
     new_row.terms
   );
 
@@ -0,0 +1,180 @@
+-- Auto-generated rollup table for CollateralReleaseEvent
+CREATE TABLE core_collateral_release_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  address VARCHAR,
+  amount BIGINT,
+  approval_process_id UUID,
+  approved BOOLEAN,
+  collateral_id UUID,
+  credit_facility_id UUID,
+  effective VARCHAR,
+  external_transfer_id VARCHAR,
+  ledger_tx_id UUID,
+  reason VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+
+  -- Toggle fields
+  is_approval_process_concluded BOOLEAN DEFAULT false,
+  is_executed BOOLEAN DEFAULT false,
+  is_failed BOOLEAN DEFAULT false,
+  is_send_requested BOOLEAN DEFAULT false
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for CollateralReleaseEvent
+CREATE OR REPLACE FUNCTION core_collateral_release_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_collateral_release_events_rollup%ROWTYPE;
+  new_row core_collateral_release_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_collateral_release_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'approval_process_concluded', 'send_requested', 'executed', 'failed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.address := (NEW.event ->> 'address');
+    new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+    new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+    new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.collateral_id := (NEW.event ->> 'collateral_id')::UUID;
+    new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.external_transfer_id := (NEW.event ->> 'external_transfer_id');
+    new_row.is_approval_process_concluded := false;
+    new_row.is_executed := false;
+    new_row.is_failed := false;
+    new_row.is_send_requested := false;
+    new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    new_row.reason := (NEW.event ->> 'reason');
+  ELSE
+    -- Default all fields to current values
+    new_row.address := current_row.address;
+    new_row.amount := current_row.amount;
+    new_row.approval_process_id := current_row.approval_process_id;
+    new_row.approved := current_row.approved;
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.collateral_id := current_row.collateral_id;
+    new_row.credit_facility_id := current_row.credit_facility_id;
+    new_row.effective := current_row.effective;
+    new_row.external_transfer_id := current_row.external_transfer_id;
+    new_row.is_approval_process_concluded := current_row.is_approval_process_concluded;
+    new_row.is_executed := current_row.is_executed;
+    new_row.is_failed := current_row.is_failed;
+    new_row.is_send_requested := current_row.is_send_requested;
+    new_row.ledger_tx_id := current_row.ledger_tx_id;
+    new_row.reason := current_row.reason;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.address := (NEW.event ->> 'address');
+      new_row.amount := (NEW.event ->> 'amount')::BIGINT;
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.collateral_id := (NEW.event ->> 'collateral_id')::UUID;
+      new_row.credit_facility_id := (NEW.event ->> 'credit_facility_id')::UUID;
+    WHEN 'approval_process_concluded' THEN
+      new_row.approval_process_id := (NEW.event ->> 'approval_process_id')::UUID;
+      new_row.approved := (NEW.event ->> 'approved')::BOOLEAN;
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_approval_process_concluded := true;
+    WHEN 'send_requested' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_send_requested := true;
+    WHEN 'executed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.external_transfer_id := (NEW.event ->> 'external_transfer_id');
+      new_row.is_executed := true;
+      new_row.ledger_tx_id := (NEW.event ->> 'ledger_tx_id')::UUID;
+    WHEN 'failed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_failed := true;
+      new_row.reason := (NEW.event ->> 'reason');
+  END CASE;
+
+  INSERT INTO core_collateral_release_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    address,
+    amount,
+    approval_process_id,
+    approved,
+    audit_entry_ids,
+    collateral_id,
+    credit_facility_id,
+    effective,
+    external_transfer_id,
+    is_approval_process_concluded,
+    is_executed,
+    is_failed,
+    is_send_requested,
+    ledger_tx_id,
+    reason
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.address,
+    new_row.amount,
+    new_row.approval_process_id,
+    new_row.approved,
+    new_row.audit_entry_ids,
+    new_row.collateral_id,
+    new_row.credit_facility_id,
+    new_row.effective,
+    new_row.external_transfer_id,
+    new_row.is_approval_process_concluded,
+    new_row.is_executed,
+    new_row.is_failed,
+    new_row.is_send_requested,
+    new_row.ledger_tx_id,
+    new_row.reason
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for CollateralReleaseEvent
+CREATE TRIGGER core_collateral_release_events_rollup_trigger
+  AFTER INSERT ON core_collateral_release_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_collateral_release_events_rollup_trigger();
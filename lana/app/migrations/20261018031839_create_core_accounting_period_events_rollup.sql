@@ -0,0 +1,115 @@
+-- Auto-generated rollup table for AccountingPeriodEvent
+CREATE TABLE core_accounting_period_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  closing_transaction_id UUID,
+  ends_on VARCHAR,
+  period_type VARCHAR,
+  starts_on VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for AccountingPeriodEvent
+CREATE OR REPLACE FUNCTION core_accounting_period_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_accounting_period_events_rollup%ROWTYPE;
+  new_row core_accounting_period_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_accounting_period_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'soft_closed', 'reopened', 'closed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.closing_transaction_id := (NEW.event ->> 'closing_transaction_id')::UUID;
+    new_row.ends_on := (NEW.event ->> 'ends_on');
+    new_row.period_type := (NEW.event ->> 'period_type');
+    new_row.starts_on := (NEW.event ->> 'starts_on');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.closing_transaction_id := current_row.closing_transaction_id;
+    new_row.ends_on := current_row.ends_on;
+    new_row.period_type := current_row.period_type;
+    new_row.starts_on := current_row.starts_on;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.ends_on := (NEW.event ->> 'ends_on');
+      new_row.period_type := (NEW.event ->> 'period_type');
+      new_row.starts_on := (NEW.event ->> 'starts_on');
+    WHEN 'soft_closed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'reopened' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'closed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.closing_transaction_id := (NEW.event ->> 'closing_transaction_id')::UUID;
+  END CASE;
+
+  INSERT INTO core_accounting_period_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    closing_transaction_id,
+    ends_on,
+    period_type,
+    starts_on
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.closing_transaction_id,
+    new_row.ends_on,
+    new_row.period_type,
+    new_row.starts_on
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for AccountingPeriodEvent
+CREATE TRIGGER core_accounting_period_events_rollup_trigger
+  AFTER INSERT ON core_accounting_period_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_accounting_period_events_rollup_trigger();
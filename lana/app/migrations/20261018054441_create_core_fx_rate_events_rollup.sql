@@ -0,0 +1,106 @@
+-- Auto-generated rollup table for FxRateEvent
+CREATE TABLE core_fx_rate_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  currency VARCHAR,
+  rate VARCHAR,
+  rate_date VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for FxRateEvent
+CREATE OR REPLACE FUNCTION core_fx_rate_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_fx_rate_events_rollup%ROWTYPE;
+  new_row core_fx_rate_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_fx_rate_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'rate_updated') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.currency := (NEW.event ->> 'currency');
+    new_row.rate := (NEW.event ->> 'rate');
+    new_row.rate_date := (NEW.event ->> 'rate_date');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.currency := current_row.currency;
+    new_row.rate := current_row.rate;
+    new_row.rate_date := current_row.rate_date;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.currency := (NEW.event ->> 'currency');
+      new_row.rate := (NEW.event ->> 'rate');
+      new_row.rate_date := (NEW.event ->> 'rate_date');
+    WHEN 'rate_updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.rate := (NEW.event ->> 'rate');
+  END CASE;
+
+  INSERT INTO core_fx_rate_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    currency,
+    rate,
+    rate_date
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.currency,
+    new_row.rate,
+    new_row.rate_date
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for FxRateEvent
+CREATE TRIGGER core_fx_rate_events_rollup_trigger
+  AFTER INSERT ON core_fx_rate_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_fx_rate_events_rollup_trigger();
@@ -0,0 +1,109 @@
+-- Auto-generated rollup table for FxRevaluationEvent
+CREATE TABLE core_fx_revaluation_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  lines JSONB,
+  manual_transaction_id UUID,
+  previous_revaluation_date VARCHAR,
+  revaluation_date VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for FxRevaluationEvent
+CREATE OR REPLACE FUNCTION core_fx_revaluation_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_fx_revaluation_events_rollup%ROWTYPE;
+  new_row core_fx_revaluation_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_fx_revaluation_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.lines := (NEW.event -> 'lines');
+    new_row.manual_transaction_id := (NEW.event ->> 'manual_transaction_id')::UUID;
+    new_row.previous_revaluation_date := (NEW.event ->> 'previous_revaluation_date');
+    new_row.revaluation_date := (NEW.event ->> 'revaluation_date');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.lines := current_row.lines;
+    new_row.manual_transaction_id := current_row.manual_transaction_id;
+    new_row.previous_revaluation_date := current_row.previous_revaluation_date;
+    new_row.revaluation_date := current_row.revaluation_date;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.lines := (NEW.event -> 'lines');
+      new_row.manual_transaction_id := (NEW.event ->> 'manual_transaction_id')::UUID;
+      new_row.previous_revaluation_date := (NEW.event ->> 'previous_revaluation_date');
+      new_row.revaluation_date := (NEW.event ->> 'revaluation_date');
+  END CASE;
+
+  INSERT INTO core_fx_revaluation_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    lines,
+    manual_transaction_id,
+    previous_revaluation_date,
+    revaluation_date
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.lines,
+    new_row.manual_transaction_id,
+    new_row.previous_revaluation_date,
+    new_row.revaluation_date
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for FxRevaluationEvent
+CREATE TRIGGER core_fx_revaluation_events_rollup_trigger
+  AFTER INSERT ON core_fx_revaluation_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_fx_revaluation_events_rollup_trigger();
@@ -0,0 +1,135 @@
+-- Auto-generated rollup table for RecurringJournalEntryEvent
+CREATE TABLE core_recurring_journal_entry_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  description VARCHAR,
+  effective VARCHAR,
+  entries JSONB,
+  manual_transaction_id UUID,
+  next_occurrence VARCHAR,
+  reference VARCHAR,
+  schedule JSONB,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for RecurringJournalEntryEvent
+CREATE OR REPLACE FUNCTION core_recurring_journal_entry_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_recurring_journal_entry_events_rollup%ROWTYPE;
+  new_row core_recurring_journal_entry_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_recurring_journal_entry_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'occurrence_posted', 'paused', 'resumed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.description := (NEW.event ->> 'description');
+    new_row.effective := (NEW.event ->> 'effective');
+    new_row.entries := (NEW.event -> 'entries');
+    new_row.manual_transaction_id := (NEW.event ->> 'manual_transaction_id')::UUID;
+    new_row.next_occurrence := (NEW.event ->> 'next_occurrence');
+    new_row.reference := (NEW.event ->> 'reference');
+    new_row.schedule := (NEW.event -> 'schedule');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.description := current_row.description;
+    new_row.effective := current_row.effective;
+    new_row.entries := current_row.entries;
+    new_row.manual_transaction_id := current_row.manual_transaction_id;
+    new_row.next_occurrence := current_row.next_occurrence;
+    new_row.reference := current_row.reference;
+    new_row.schedule := current_row.schedule;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.description := (NEW.event ->> 'description');
+      new_row.entries := (NEW.event -> 'entries');
+      new_row.next_occurrence := (NEW.event ->> 'next_occurrence');
+      new_row.reference := (NEW.event ->> 'reference');
+      new_row.schedule := (NEW.event -> 'schedule');
+    WHEN 'occurrence_posted' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.effective := (NEW.event ->> 'effective');
+      new_row.manual_transaction_id := (NEW.event ->> 'manual_transaction_id')::UUID;
+      new_row.next_occurrence := (NEW.event ->> 'next_occurrence');
+    WHEN 'paused' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+    WHEN 'resumed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.next_occurrence := (NEW.event ->> 'next_occurrence');
+  END CASE;
+
+  INSERT INTO core_recurring_journal_entry_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    description,
+    effective,
+    entries,
+    manual_transaction_id,
+    next_occurrence,
+    reference,
+    schedule
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.description,
+    new_row.effective,
+    new_row.entries,
+    new_row.manual_transaction_id,
+    new_row.next_occurrence,
+    new_row.reference,
+    new_row.schedule
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for RecurringJournalEntryEvent
+CREATE TRIGGER core_recurring_journal_entry_events_rollup_trigger
+  AFTER INSERT ON core_recurring_journal_entry_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_recurring_journal_entry_events_rollup_trigger();
@@ -0,0 +1,159 @@
+-- Auto-generated rollup table for ReconciliationEvent
+CREATE TABLE core_reconciliation_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  format VARCHAR,
+  ledger_account_id UUID,
+  ledger_items JSONB,
+  ledger_transaction_id UUID,
+  line_number INTEGER,
+  lines JSONB,
+  matches JSONB,
+  reason VARCHAR,
+  statement_reference VARCHAR,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+
+  -- Toggle fields
+  is_completed BOOLEAN DEFAULT false
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for ReconciliationEvent
+CREATE OR REPLACE FUNCTION core_reconciliation_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_reconciliation_events_rollup%ROWTYPE;
+  new_row core_reconciliation_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_reconciliation_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'auto_matched', 'line_matched', 'line_marked_as_exception', 'line_reset', 'completed') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.format := (NEW.event ->> 'format');
+    new_row.is_completed := false;
+    new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
+    new_row.ledger_items := (NEW.event -> 'ledger_items');
+    new_row.ledger_transaction_id := (NEW.event ->> 'ledger_transaction_id')::UUID;
+    new_row.line_number := (NEW.event ->> 'line_number')::INTEGER;
+    new_row.lines := (NEW.event -> 'lines');
+    new_row.matches := (NEW.event -> 'matches');
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.statement_reference := (NEW.event ->> 'statement_reference');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.format := current_row.format;
+    new_row.is_completed := current_row.is_completed;
+    new_row.ledger_account_id := current_row.ledger_account_id;
+    new_row.ledger_items := current_row.ledger_items;
+    new_row.ledger_transaction_id := current_row.ledger_transaction_id;
+    new_row.line_number := current_row.line_number;
+    new_row.lines := current_row.lines;
+    new_row.matches := current_row.matches;
+    new_row.reason := current_row.reason;
+    new_row.statement_reference := current_row.statement_reference;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.format := (NEW.event ->> 'format');
+      new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
+      new_row.ledger_items := (NEW.event -> 'ledger_items');
+      new_row.lines := (NEW.event -> 'lines');
+      new_row.statement_reference := (NEW.event ->> 'statement_reference');
+    WHEN 'auto_matched' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.matches := (NEW.event -> 'matches');
+    WHEN 'line_matched' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.ledger_transaction_id := (NEW.event ->> 'ledger_transaction_id')::UUID;
+      new_row.line_number := (NEW.event ->> 'line_number')::INTEGER;
+    WHEN 'line_marked_as_exception' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.line_number := (NEW.event ->> 'line_number')::INTEGER;
+      new_row.reason := (NEW.event ->> 'reason');
+    WHEN 'line_reset' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.line_number := (NEW.event ->> 'line_number')::INTEGER;
+    WHEN 'completed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_completed := true;
+  END CASE;
+
+  INSERT INTO core_reconciliation_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    format,
+    is_completed,
+    ledger_account_id,
+    ledger_items,
+    ledger_transaction_id,
+    line_number,
+    lines,
+    matches,
+    reason,
+    statement_reference
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.format,
+    new_row.is_completed,
+    new_row.ledger_account_id,
+    new_row.ledger_items,
+    new_row.ledger_transaction_id,
+    new_row.line_number,
+    new_row.lines,
+    new_row.matches,
+    new_row.reason,
+    new_row.statement_reference
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for ReconciliationEvent
+CREATE TRIGGER core_reconciliation_events_rollup_trigger
+  AFTER INSERT ON core_reconciliation_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_reconciliation_events_rollup_trigger();
@@ -6,7 +6,11 @@ CREATE TABLE core_deposit_account_events_rollup (
   modified_at TIMESTAMPTZ NOT NULL,
   -- Flattened fields from the event JSON
   account_holder_id UUID,
+  account_type VARCHAR,
+  currency VARCHAR,
+  deposit_product_id UUID,
   description VARCHAR,
+  interest_payable_account_id UUID,
   ledger_account_id UUID,
   name VARCHAR,
   public_id VARCHAR,
@@ -37,7 +41,7 @@ BEGIN
   END IF;
 
   -- Validate event type is known
-  IF event_type NOT IN ('initialized', 'account_status_updated') THEN
+  IF event_type NOT IN ('initialized', 'account_status_updated', 'deposit_product_assigned') THEN
     RAISE EXCEPTION 'Unknown event type: %', event_type;
   END IF;
 
@@ -49,14 +53,18 @@ BEGIN
 
   -- Initialize fields with default values if this is a new record
   IF current_row.id IS NULL THEN
-    new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
     new_row.audit_entry_ids := CASE
        WHEN NEW.event ? 'audit_entry_ids' THEN
          ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
        ELSE ARRAY[]::BIGINT[]
      END
 ;
+    new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+    new_row.account_type := (NEW.event ->> 'account_type');
+    new_row.currency := (NEW.event ->> 'currency');
+    new_row.deposit_product_id := (NEW.event ->> 'deposit_product_id')::UUID;
     new_row.description := (NEW.event ->> 'description');
+    new_row.interest_payable_account_id := (NEW.event ->> 'interest_payable_account_id')::UUID;
     new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
     new_row.name := (NEW.event ->> 'name');
     new_row.public_id := (NEW.event ->> 'public_id');
@@ -64,9 +72,13 @@ BEGIN
     new_row.status := (NEW.event ->> 'status');
   ELSE
     -- Default all fields to current values
-    new_row.account_holder_id := current_row.account_holder_id;
     new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.account_holder_id := current_row.account_holder_id;
+    new_row.account_type := current_row.account_type;
+    new_row.currency := current_row.currency;
+    new_row.deposit_product_id := current_row.deposit_product_id;
     new_row.description := current_row.description;
+    new_row.interest_payable_account_id := current_row.interest_payable_account_id;
     new_row.ledger_account_id := current_row.ledger_account_id;
     new_row.name := current_row.name;
     new_row.public_id := current_row.public_id;
@@ -77,8 +89,10 @@ BEGIN
   -- Update only the fields that are modified by the specific event
   CASE event_type
     WHEN 'initialized' THEN
-      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.account_holder_id := (NEW.event ->> 'account_holder_id')::UUID;
+      new_row.account_type := (NEW.event ->> 'account_type');
+      new_row.currency := (NEW.event ->> 'currency');
       new_row.description := (NEW.event ->> 'description');
       new_row.ledger_account_id := (NEW.event ->> 'ledger_account_id')::UUID;
       new_row.name := (NEW.event ->> 'name');
@@ -88,6 +102,10 @@ BEGIN
     WHEN 'account_status_updated' THEN
       new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
       new_row.status := (NEW.event ->> 'status');
+    WHEN 'deposit_product_assigned' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.deposit_product_id := (NEW.event ->> 'deposit_product_id')::UUID;
+      new_row.interest_payable_account_id := (NEW.event ->> 'interest_payable_account_id')::UUID;
   END CASE;
 
   INSERT INTO core_deposit_account_events_rollup (
@@ -95,9 +113,13 @@ BEGIN
     version,
     created_at,
     modified_at,
-    account_holder_id,
     audit_entry_ids,
+    account_holder_id,
+    account_type,
+    currency,
+    deposit_product_id,
     description,
+    interest_payable_account_id,
     ledger_account_id,
     name,
     public_id,
@@ -109,9 +131,13 @@ BEGIN
     new_row.version,
     new_row.created_at,
     new_row.modified_at,
-    new_row.account_holder_id,
     new_row.audit_entry_ids,
+    new_row.account_holder_id,
+    new_row.account_type,
+    new_row.currency,
+    new_row.deposit_product_id,
     new_row.description,
+    new_row.interest_payable_account_id,
     new_row.ledger_account_id,
     new_row.name,
     new_row.public_id,
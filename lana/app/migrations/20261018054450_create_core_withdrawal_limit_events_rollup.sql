@@ -0,0 +1,113 @@
+-- Auto-generated rollup table for WithdrawalLimitEvent
+CREATE TABLE core_withdrawal_limit_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  amounts JSONB,
+  breach_action VARCHAR,
+  currency VARCHAR,
+  scope JSONB,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[]
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for WithdrawalLimitEvent
+CREATE OR REPLACE FUNCTION core_withdrawal_limit_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_withdrawal_limit_events_rollup%ROWTYPE;
+  new_row core_withdrawal_limit_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_withdrawal_limit_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'updated') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.amounts := (NEW.event -> 'amounts');
+    new_row.breach_action := (NEW.event ->> 'breach_action');
+    new_row.currency := (NEW.event ->> 'currency');
+    new_row.scope := (NEW.event -> 'scope');
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.amounts := current_row.amounts;
+    new_row.breach_action := current_row.breach_action;
+    new_row.currency := current_row.currency;
+    new_row.scope := current_row.scope;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.amounts := (NEW.event -> 'amounts');
+      new_row.breach_action := (NEW.event ->> 'breach_action');
+      new_row.currency := (NEW.event ->> 'currency');
+      new_row.scope := (NEW.event -> 'scope');
+    WHEN 'updated' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.amounts := (NEW.event -> 'amounts');
+      new_row.breach_action := (NEW.event ->> 'breach_action');
+  END CASE;
+
+  INSERT INTO core_withdrawal_limit_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    amounts,
+    breach_action,
+    currency,
+    scope
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.amounts,
+    new_row.breach_action,
+    new_row.currency,
+    new_row.scope
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for WithdrawalLimitEvent
+CREATE TRIGGER core_withdrawal_limit_events_rollup_trigger
+  AFTER INSERT ON core_withdrawal_limit_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_withdrawal_limit_events_rollup_trigger();
@@ -0,0 +1,174 @@
+-- Auto-generated rollup table for StandingOrderEvent
+CREATE TABLE core_standing_order_events_rollup (
+  id UUID NOT NULL,
+  version INT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  modified_at TIMESTAMPTZ NOT NULL,
+  -- Flattened fields from the event JSON
+  amount JSONB,
+  attempt INTEGER,
+  deposit_account_id UUID,
+  destination_reference VARCHAR,
+  max_retries INTEGER,
+  next_run_date VARCHAR,
+  reason VARCHAR,
+  retry_on VARCHAR,
+  run_date VARCHAR,
+  schedule JSONB,
+  withdrawal_id UUID,
+
+  -- Collection rollups
+  audit_entry_ids BIGINT[],
+
+  -- Toggle fields
+  is_cancelled BOOLEAN DEFAULT false
+,
+  PRIMARY KEY (id, version)
+);
+
+-- Auto-generated trigger function for StandingOrderEvent
+CREATE OR REPLACE FUNCTION core_standing_order_events_rollup_trigger()
+RETURNS TRIGGER AS $$
+DECLARE
+  event_type TEXT;
+  current_row core_standing_order_events_rollup%ROWTYPE;
+  new_row core_standing_order_events_rollup%ROWTYPE;
+BEGIN
+  event_type := NEW.event_type;
+
+  -- Load the previous version if this isn't the first event
+  IF NEW.sequence > 1 THEN
+    SELECT * INTO current_row
+    FROM core_standing_order_events_rollup
+    WHERE id = NEW.id AND version = NEW.sequence - 1;
+  END IF;
+
+  -- Validate event type is known
+  IF event_type NOT IN ('initialized', 'executed', 'execution_failed', 'skipped', 'cancelled') THEN
+    RAISE EXCEPTION 'Unknown event type: %', event_type;
+  END IF;
+
+  -- Construct the new row based on event type
+  new_row.id := NEW.id;
+  new_row.version := NEW.sequence;
+  new_row.created_at := COALESCE(current_row.created_at, NEW.recorded_at);
+  new_row.modified_at := NEW.recorded_at;
+
+  -- Initialize fields with default values if this is a new record
+  IF current_row.id IS NULL THEN
+    new_row.audit_entry_ids := CASE
+       WHEN NEW.event ? 'audit_entry_ids' THEN
+         ARRAY(SELECT value::text::BIGINT FROM jsonb_array_elements_text(NEW.event -> 'audit_entry_ids'))
+       ELSE ARRAY[]::BIGINT[]
+     END
+;
+    new_row.amount := (NEW.event -> 'amount');
+    new_row.attempt := (NEW.event ->> 'attempt')::INTEGER;
+    new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+    new_row.destination_reference := (NEW.event ->> 'destination_reference');
+    new_row.is_cancelled := false;
+    new_row.max_retries := (NEW.event ->> 'max_retries')::INTEGER;
+    new_row.next_run_date := (NEW.event ->> 'next_run_date');
+    new_row.reason := (NEW.event ->> 'reason');
+    new_row.retry_on := (NEW.event ->> 'retry_on');
+    new_row.run_date := (NEW.event ->> 'run_date');
+    new_row.schedule := (NEW.event -> 'schedule');
+    new_row.withdrawal_id := (NEW.event ->> 'withdrawal_id')::UUID;
+  ELSE
+    -- Default all fields to current values
+    new_row.audit_entry_ids := current_row.audit_entry_ids;
+    new_row.amount := current_row.amount;
+    new_row.attempt := current_row.attempt;
+    new_row.deposit_account_id := current_row.deposit_account_id;
+    new_row.destination_reference := current_row.destination_reference;
+    new_row.is_cancelled := current_row.is_cancelled;
+    new_row.max_retries := current_row.max_retries;
+    new_row.next_run_date := current_row.next_run_date;
+    new_row.reason := current_row.reason;
+    new_row.retry_on := current_row.retry_on;
+    new_row.run_date := current_row.run_date;
+    new_row.schedule := current_row.schedule;
+    new_row.withdrawal_id := current_row.withdrawal_id;
+  END IF;
+
+  -- Update only the fields that are modified by the specific event
+  CASE event_type
+    WHEN 'initialized' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.amount := (NEW.event -> 'amount');
+      new_row.deposit_account_id := (NEW.event ->> 'deposit_account_id')::UUID;
+      new_row.destination_reference := (NEW.event ->> 'destination_reference');
+      new_row.max_retries := (NEW.event ->> 'max_retries')::INTEGER;
+      new_row.next_run_date := (NEW.event ->> 'next_run_date');
+      new_row.schedule := (NEW.event -> 'schedule');
+    WHEN 'executed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.attempt := (NEW.event ->> 'attempt')::INTEGER;
+      new_row.next_run_date := (NEW.event ->> 'next_run_date');
+      new_row.run_date := (NEW.event ->> 'run_date');
+      new_row.withdrawal_id := (NEW.event ->> 'withdrawal_id')::UUID;
+    WHEN 'execution_failed' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.attempt := (NEW.event ->> 'attempt')::INTEGER;
+      new_row.reason := (NEW.event ->> 'reason');
+      new_row.retry_on := (NEW.event ->> 'retry_on');
+      new_row.run_date := (NEW.event ->> 'run_date');
+    WHEN 'skipped' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.attempt := (NEW.event ->> 'attempt')::INTEGER;
+      new_row.next_run_date := (NEW.event ->> 'next_run_date');
+      new_row.reason := (NEW.event ->> 'reason');
+      new_row.run_date := (NEW.event ->> 'run_date');
+    WHEN 'cancelled' THEN
+      new_row.audit_entry_ids := array_append(COALESCE(current_row.audit_entry_ids, ARRAY[]::BIGINT[]), (NEW.event -> 'audit_info' ->> 'audit_entry_id')::BIGINT);
+      new_row.is_cancelled := true;
+  END CASE;
+
+  INSERT INTO core_standing_order_events_rollup (
+    id,
+    version,
+    created_at,
+    modified_at,
+    audit_entry_ids,
+    amount,
+    attempt,
+    deposit_account_id,
+    destination_reference,
+    is_cancelled,
+    max_retries,
+    next_run_date,
+    reason,
+    retry_on,
+    run_date,
+    schedule,
+    withdrawal_id
+  )
+  VALUES (
+    new_row.id,
+    new_row.version,
+    new_row.created_at,
+    new_row.modified_at,
+    new_row.audit_entry_ids,
+    new_row.amount,
+    new_row.attempt,
+    new_row.deposit_account_id,
+    new_row.destination_reference,
+    new_row.is_cancelled,
+    new_row.max_retries,
+    new_row.next_run_date,
+    new_row.reason,
+    new_row.retry_on,
+    new_row.run_date,
+    new_row.schedule,
+    new_row.withdrawal_id
+  );
+
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Auto-generated trigger for StandingOrderEvent
+CREATE TRIGGER core_standing_order_events_rollup_trigger
+  AFTER INSERT ON core_standing_order_events
+  FOR EACH ROW
+  EXECUTE FUNCTION core_standing_order_events_rollup_trigger();
@@ -60,6 +60,7 @@ impl ChartsInit {
             deposit,
             accounting.balance_sheets(),
             accounting.profit_and_loss(),
+            accounting.fx_revaluations(),
             accounting_init_config,
         )
         .await
@@ -1,14 +1,17 @@
 use std::path::PathBuf;
 
 use crate::{
-    accounting::ChartId,
+    accounting::{ChartId, FxRevaluations},
     accounting_init::{constants::*, *},
 };
 
 use rbac_types::Subject;
 
-use super::module_config::{balance_sheet::*, credit::*, deposit::*, profit_and_loss::*};
+use super::module_config::{
+    balance_sheet::*, credit::*, deposit::*, fx_revaluation::*, profit_and_loss::*,
+};
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn init(
     chart_of_accounts: &ChartOfAccounts,
     trial_balances: &TrialBalances,
@@ -16,6 +19,7 @@ pub(crate) async fn init(
     deposit: &Deposits,
     balance_sheet: &BalanceSheets,
     profit_and_loss: &ProfitAndLossStatements,
+    fx_revaluations: &FxRevaluations,
     accounting_init_config: AccountingInitConfig,
 ) -> Result<(), AccountingInitError> {
     let chart_id = create_chart_of_accounts(chart_of_accounts).await?;
@@ -28,6 +32,7 @@ pub(crate) async fn init(
             deposit,
             balance_sheet,
             profit_and_loss,
+            fx_revaluations,
             chart_id,
             path,
             accounting_init_config,
@@ -62,6 +67,7 @@ async fn seed_chart_of_accounts(
     deposit: &Deposits,
     balance_sheet: &BalanceSheets,
     profit_and_loss: &ProfitAndLossStatements,
+    fx_revaluations: &FxRevaluations,
     chart_id: ChartId,
     chart_of_accounts_seed_path: PathBuf,
     accounting_init_config: AccountingInitConfig,
@@ -71,6 +77,7 @@ async fn seed_chart_of_accounts(
         deposit_config_path,
         balance_sheet_config_path,
         profit_and_loss_config_path,
+        fx_revaluation_config_path,
 
         chart_of_accounts_seed_path: _,
     } = accounting_init_config;
@@ -123,5 +130,9 @@ async fn seed_chart_of_accounts(
             });
     }
 
+    if let Some(config_path) = fx_revaluation_config_path {
+        fx_revaluation_module_configure(fx_revaluations, &chart, config_path).await?;
+    }
+
     Ok(())
 }
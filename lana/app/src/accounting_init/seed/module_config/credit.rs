@@ -20,6 +20,18 @@ struct CreditConfigData {
     in_liquidation_parent_code: String,
     interest_income_parent_code: String,
     fee_income_parent_code: String,
+    #[serde(default)]
+    unapplied_funds_parent_code: Option<String>,
+    #[serde(default)]
+    penalty_receivable_parent_code: Option<String>,
+    #[serde(default)]
+    penalty_income_parent_code: Option<String>,
+    #[serde(default)]
+    collateral_in_liquidation_parent_code: Option<String>,
+    #[serde(default)]
+    liquidation_proceeds_parent_code: Option<String>,
+    #[serde(default)]
+    liquidation_proceeds_omnibus_parent_code: Option<String>,
     short_term_individual_interest_receivable_parent_code: String,
     short_term_government_entity_interest_receivable_parent_code: String,
     short_term_private_company_interest_receivable_parent_code: String,
@@ -72,6 +84,12 @@ pub(in crate::accounting_init::seed) async fn credit_module_configure(
         in_liquidation_parent_code,
         interest_income_parent_code,
         fee_income_parent_code,
+        unapplied_funds_parent_code,
+        penalty_receivable_parent_code,
+        penalty_income_parent_code,
+        collateral_in_liquidation_parent_code,
+        liquidation_proceeds_parent_code,
+        liquidation_proceeds_omnibus_parent_code,
         short_term_individual_interest_receivable_parent_code,
         short_term_government_entity_interest_receivable_parent_code,
         short_term_private_company_interest_receivable_parent_code,
@@ -121,6 +139,36 @@ pub(in crate::accounting_init::seed) async fn credit_module_configure(
         .chart_of_account_in_liquidation_parent_code(in_liquidation_parent_code.parse()?)
         .chart_of_account_interest_income_parent_code(interest_income_parent_code.parse()?)
         .chart_of_account_fee_income_parent_code(fee_income_parent_code.parse()?)
+        .chart_of_account_unapplied_funds_parent_code(
+            unapplied_funds_parent_code
+                .map(|code| code.parse())
+                .transpose()?,
+        )
+        .chart_of_account_penalty_receivable_parent_code(
+            penalty_receivable_parent_code
+                .map(|code| code.parse())
+                .transpose()?,
+        )
+        .chart_of_account_penalty_income_parent_code(
+            penalty_income_parent_code
+                .map(|code| code.parse())
+                .transpose()?,
+        )
+        .chart_of_account_collateral_in_liquidation_parent_code(
+            collateral_in_liquidation_parent_code
+                .map(|code| code.parse())
+                .transpose()?,
+        )
+        .chart_of_account_liquidation_proceeds_parent_code(
+            liquidation_proceeds_parent_code
+                .map(|code| code.parse())
+                .transpose()?,
+        )
+        .chart_of_account_liquidation_proceeds_omnibus_parent_code(
+            liquidation_proceeds_omnibus_parent_code
+                .map(|code| code.parse())
+                .transpose()?,
+        )
         .chart_of_account_short_term_individual_interest_receivable_parent_code(
             short_term_individual_interest_receivable_parent_code.parse()?,
         )
@@ -19,6 +19,8 @@ struct DepositConfigData {
     bank_deposit_accounts_parent_code: String,
     financial_institution_deposit_accounts_parent_code: String,
     non_domiciled_individual_deposit_accounts_parent_code: String,
+    interest_expense_parent_code: String,
+    interest_payable_parent_code: String,
 }
 
 pub(in crate::accounting_init::seed) async fn deposit_module_configure(
@@ -35,6 +37,8 @@ pub(in crate::accounting_init::seed) async fn deposit_module_configure(
         bank_deposit_accounts_parent_code,
         financial_institution_deposit_accounts_parent_code,
         non_domiciled_individual_deposit_accounts_parent_code,
+        interest_expense_parent_code,
+        interest_payable_parent_code,
     } = serde_json::from_str(&data)?;
 
     let config_values = ChartOfAccountsIntegrationConfig::builder()
@@ -58,6 +62,8 @@ pub(in crate::accounting_init::seed) async fn deposit_module_configure(
         .chart_of_account_non_domiciled_individual_deposit_accounts_parent_code(
             non_domiciled_individual_deposit_accounts_parent_code.parse()?,
         )
+        .chart_of_account_interest_expense_parent_code(Some(interest_expense_parent_code.parse()?))
+        .chart_of_account_interest_payable_parent_code(Some(interest_payable_parent_code.parse()?))
         .build()?;
 
     match deposit
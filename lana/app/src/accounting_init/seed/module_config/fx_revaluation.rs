@@ -0,0 +1,46 @@
+use std::{fs, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::{
+    accounting::{Chart, FxRevaluationConfig, FxRevaluations},
+    accounting_init::AccountingInitError,
+};
+
+use rbac_types::Subject;
+
+#[derive(Deserialize)]
+struct FxRevaluationConfigData {
+    revalued_account_codes: Vec<String>,
+    unrealized_gain_code: String,
+    unrealized_loss_code: String,
+}
+
+pub(in crate::accounting_init::seed) async fn fx_revaluation_module_configure(
+    fx_revaluations: &FxRevaluations,
+    chart: &Chart,
+    config_path: PathBuf,
+) -> Result<(), AccountingInitError> {
+    let data = fs::read_to_string(config_path)?;
+    let FxRevaluationConfigData {
+        revalued_account_codes,
+        unrealized_gain_code,
+        unrealized_loss_code,
+    } = serde_json::from_str(&data)?;
+
+    let config = FxRevaluationConfig {
+        chart_of_accounts_ref: chart.reference.clone(),
+        revalued_account_codes: revalued_account_codes
+            .iter()
+            .map(|code| code.parse())
+            .collect::<Result<_, _>>()?,
+        unrealized_gain_account_code: unrealized_gain_code.parse()?,
+        unrealized_loss_account_code: unrealized_loss_code.parse()?,
+    };
+
+    fx_revaluations
+        .schedule_monthly(&Subject::System, config)
+        .await?;
+
+    Ok(())
+}
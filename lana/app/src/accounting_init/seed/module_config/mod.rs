@@ -1,4 +1,5 @@
 pub(super) mod balance_sheet;
 pub(super) mod credit;
 pub(super) mod deposit;
+pub(super) mod fx_revaluation;
 pub(super) mod profit_and_loss;
@@ -4,9 +4,9 @@ use std::path::PathBuf;
 
 use crate::{
     access::config::AccessConfig, applicant::SumsubConfig, credit::CreditConfig,
-    custody::CustodyConfig, customer_sync::CustomerSyncConfig, deposit_sync::DepositSyncConfig,
-    job::JobsConfig, notification::NotificationConfig, report::ReportConfig,
-    storage::config::StorageConfig, user_onboarding::UserOnboardingConfig,
+    custody::CustodyConfig, customer_sync::CustomerSyncConfig, deposit::DepositConfig,
+    deposit_sync::DepositSyncConfig, job::JobsConfig, notification::NotificationConfig,
+    report::ReportConfig, storage::config::StorageConfig, user_onboarding::UserOnboardingConfig,
 };
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -26,6 +26,8 @@ pub struct AppConfig {
     #[serde(default)]
     pub customer_sync: CustomerSyncConfig,
     #[serde(default)]
+    pub deposit: DepositConfig,
+    #[serde(default)]
     pub deposit_sync: DepositSyncConfig,
     #[serde(default)]
     pub accounting_init: AccountingInitConfig,
@@ -49,4 +51,6 @@ pub struct AccountingInitConfig {
     pub balance_sheet_config_path: Option<PathBuf>,
     #[serde(default)]
     pub profit_and_loss_config_path: Option<PathBuf>,
+    #[serde(default)]
+    pub fx_revaluation_config_path: Option<PathBuf>,
 }
@@ -42,6 +42,8 @@ pub enum ApplicationError {
     DashboardError(#[from] dashboard::error::DashboardError),
     #[error("ApplicationError - CalaInit: {0}")]
     CalaError(#[from] cala_ledger::error::LedgerError),
+    #[error("ApplicationError - CoreAccountingError: {0}")]
+    CoreAccountingError(#[from] core_accounting::error::CoreAccountingError),
     #[error("ApplicationError - ChartOfAccountsError: {0}")]
     ChartOfAccountsError(#[from] core_accounting::chart_of_accounts::error::ChartOfAccountsError),
     #[error("ApplicationError - DepositError: {0}")]
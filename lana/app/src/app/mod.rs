@@ -102,14 +102,17 @@ impl LanaApp {
             .expect("cala config");
         let cala = cala_ledger::CalaLedger::init(cala_config).await?;
         let journal_init = JournalInit::journal(&cala).await?;
-        let accounting = Accounting::new(
+        let accounting = Accounting::init(
             &pool,
             &authz,
+            &outbox,
+            &governance,
             &cala,
             journal_init.journal_id,
             documents.clone(),
             &jobs,
-        );
+        )
+        .await?;
 
         StatementsInit::statements(&accounting).await?;
 
@@ -122,6 +125,7 @@ impl LanaApp {
         );
         let deposits = Deposits::init(
             &pool,
+            config.deposit,
             &authz,
             &outbox,
             &governance,
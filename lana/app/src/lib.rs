@@ -88,9 +88,15 @@ pub mod governance {
     use crate::authorization::Authorization;
     use lana_events::LanaEvent;
     pub type Governance = governance::Governance<Authorization, LanaEvent>;
+    pub use crate::credit::APPROVE_COLLATERAL_RELEASE_PROCESS;
     pub use crate::credit::APPROVE_CREDIT_FACILITY_PROCESS;
+    pub use crate::credit::APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS;
+    pub use crate::credit::APPROVE_CREDIT_FACILITY_ROLLOVER_PROCESS;
     pub use crate::credit::APPROVE_DISBURSAL_PROCESS;
-    pub use core_deposit::APPROVE_WITHDRAWAL_PROCESS;
+    pub use core_accounting::APPROVE_MANUAL_TRANSACTION_PROCESS;
+    pub use core_deposit::{
+        APPROVE_TRANSFER_PROCESS, APPROVE_WITHDRAWAL_OVER_LIMIT_PROCESS, APPROVE_WITHDRAWAL_PROCESS,
+    };
     pub use governance::{
         ApprovalProcess, ApprovalProcessStatus, ApprovalProcessType, ApprovalRules, Committee,
         CommitteeId, Policy, approval_process_cursor, committee_cursor, error, policy_cursor,
@@ -110,10 +116,18 @@ pub mod audit {
 
 pub mod deposit {
     pub use core_deposit::{
-        ChartOfAccountsIntegrationConfig, CoreDepositEvent, Deposit, DepositAccount,
-        DepositAccountBalance, DepositAccountHistoryCursor, DepositAccountHistoryEntry, DepositId,
-        DepositStatus, DepositsByCreatedAtCursor, Withdrawal, WithdrawalId, WithdrawalStatus,
-        WithdrawalsByCreatedAtCursor, error,
+        AnnualRatePct, ChartOfAccountsIntegrationConfig, CoreDepositEvent, Deposit, DepositAccount,
+        DepositAccountBalance, DepositAccountHistoryCursor, DepositAccountHistoryEntry,
+        DepositAccountType, DepositConfig, DepositId, DepositProduct, DepositProductId,
+        DepositProductsByNameCursor, DepositStatus, DepositsByCreatedAtCursor, Hold, HoldId,
+        HoldStatus, HoldsByCreatedAtCursor, StandingOrder, StandingOrderExecution,
+        StandingOrderExecutionOutcome, StandingOrderFailureReason, StandingOrderFrequency,
+        StandingOrderId, StandingOrderSchedule, StandingOrderStatus,
+        StandingOrdersByCreatedAtCursor, Transfer, TransferId, TransferStatus,
+        TransfersByCreatedAtCursor, Withdrawal, WithdrawalId, WithdrawalLimit,
+        WithdrawalLimitAmounts, WithdrawalLimitBreachAction, WithdrawalLimitId,
+        WithdrawalLimitInterval, WithdrawalLimitScope, WithdrawalLimitsByCreatedAtCursor,
+        WithdrawalStatus, WithdrawalsByCreatedAtCursor, error,
     };
 
     pub type Deposits =
@@ -122,15 +136,27 @@ pub mod deposit {
 
 pub mod accounting {
     pub use core_accounting::{
-        AccountCode, AccountCodeSection, AccountingCsvId, CalaAccountBalance, CalaAccountId,
-        ChartId, LedgerAccountId, TransactionTemplateId, chart_of_accounts, csv, error, journal,
-        ledger_account, ledger_transaction, manual_transaction, transaction_templates,
+        AccountCode, AccountCodeSection, AccountingCsvId, AccountingPeriodId, CalaAccountBalance,
+        CalaAccountId, ChartId, CurrencyCode, FxRate, FxRateId, FxRatesByRateDateCursor,
+        FxRevaluation, FxRevaluationConfig, FxRevaluationId, FxRevaluationLine,
+        FxRevaluationsByRevaluationDateCursor, LedgerAccountId, ReconciliationId,
+        RecurrenceFrequency, RecurrenceSchedule, RecurringJournalEntryId, TransactionTemplateId,
+        accounting_period, chart_of_accounts, comparative_statement, csv, error, fx_rate,
+        fx_revaluation, journal, ledger_account, ledger_transaction, manual_transaction,
+        reconciliation, recurring_journal_entry, statement_export, transaction_templates,
         {Chart, tree},
     };
 
-    pub type Accounting = core_accounting::CoreAccounting<crate::authorization::Authorization>;
+    pub type Accounting = core_accounting::CoreAccounting<
+        crate::authorization::Authorization,
+        lana_events::LanaEvent,
+    >;
     pub type ChartOfAccounts =
         core_accounting::ChartOfAccounts<crate::authorization::Authorization>;
+    pub type FxRevaluations = core_accounting::FxRevaluations<
+        crate::authorization::Authorization,
+        lana_events::LanaEvent,
+    >;
 }
 
 pub mod profit_and_loss {
@@ -157,15 +183,19 @@ pub mod custody {
 
 pub mod credit {
     pub use core_credit::{
-        APPROVE_CREDIT_FACILITY_PROCESS, APPROVE_DISBURSAL_PROCESS,
-        ChartOfAccountsIntegrationConfig, Collateral, CollateralUpdated, CollateralizationUpdated,
-        CoreCreditEvent, CreditConfig, CreditFacilitiesCursor, CreditFacilitiesFilter,
-        CreditFacilitiesSortBy, CreditFacility, CreditFacilityApproved,
+        APPROVE_COLLATERAL_RELEASE_PROCESS, APPROVE_CREDIT_FACILITY_PROCESS,
+        APPROVE_CREDIT_FACILITY_RESTRUCTURING_PROCESS, APPROVE_CREDIT_FACILITY_ROLLOVER_PROCESS,
+        APPROVE_DISBURSAL_PROCESS, ChartOfAccountsIntegrationConfig, Collateral,
+        CollateralAssetUpdated, CollateralRelease, CollateralReleaseUpdated, CollateralUpdated,
+        CollateralizationUpdated, CoreCreditEvent, CreditConfig, CreditFacilitiesCursor,
+        CreditFacilitiesFilter, CreditFacilitiesSortBy, CreditFacility, CreditFacilityApproved,
         CreditFacilityBalanceSummary, CreditFacilityHistoryEntry, CreditFacilityRepaymentPlanEntry,
         CreditFacilityStatus, Disbursal, DisbursalExecuted, DisbursalStatus, DisbursalsCursor,
         DisbursalsFilter, DisbursalsSortBy, IncrementalPayment, InterestAccrualsPosted,
-        ListDirection, ObligationInstallment, ObligationMovedToLiquidation, Payment,
-        RepaymentStatus, RepaymentType, Sort, TermsTemplate, error, terms_template_error,
+        LiquidationProcess, ListDirection, ObligationInstallment, ObligationMovedToLiquidation,
+        ObligationStatus, ObligationType, Payment, PaymentAllocationPreview,
+        PaymentAllocationPreviewEntry, PenaltyAccrued, PrepaymentRecorded, RepaymentStatus,
+        RepaymentType, Sort, TermsTemplate, error, terms_template_error,
     };
 
     pub type Credit =
@@ -174,8 +204,9 @@ pub mod credit {
 
 pub mod terms {
     pub use core_credit::{
-        AnnualRatePct, CVLPct, CollateralizationState, FacilityDuration, InterestInterval,
-        ObligationDuration, OneTimeFeeRatePct, TermValues,
+        AnnualRatePct, CVLPct, CollateralHaircuts, CollateralizationState, FacilityDuration,
+        HaircutPct, InterestInterval, ObligationDuration, OneTimeFeeRatePct,
+        PaymentAllocationStrategy, PrincipalRepayment, TermValues,
     };
 }
 
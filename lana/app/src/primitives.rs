@@ -1,14 +1,19 @@
 pub use core_access::{PermissionSetId, RoleId, UserId};
 pub use core_accounting::{
-    AccountSpec, BalanceRange, Chart, ChartId, LedgerTransactionId, ManualTransactionId,
+    AccountSpec, AccountingPeriodId, BalanceRange, Chart, ChartId, LedgerTransactionId,
+    ManualTransactionId, ReconciliationId, RecurringJournalEntryId,
 };
 pub use core_credit::{
-    CollateralAction, CollateralId, CreditFacilityId, CreditFacilityStatus, DisbursalId,
-    DisbursalStatus, ObligationInstallmentId, PaymentId, TermsTemplateId,
+    CollateralAction, CollateralId, CollateralReleaseId, CollateralReleaseStatus, CreditFacilityId,
+    CreditFacilityStatus, DisbursalId, DisbursalStatus, LiquidationProcessId,
+    ObligationInstallmentId, PaymentId, TermsTemplateId,
 };
 pub use core_custody::{CustodianId, WalletId};
 pub use core_customer::{CustomerDocumentId, CustomerId};
-pub use core_deposit::{DepositAccountHolderId, DepositAccountId, DepositId, WithdrawalId};
+pub use core_deposit::{
+    DepositAccountHolderId, DepositAccountId, DepositId, DepositProductId, HoldId, StandingOrderId,
+    TransferId, WithdrawalId, WithdrawalLimitId,
+};
 pub use core_money::*;
 pub use core_price::PriceOfOneBTC;
 pub use core_report::ReportId;
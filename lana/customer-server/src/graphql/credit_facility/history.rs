@@ -1,17 +1,28 @@
 use async_graphql::*;
 
-use crate::primitives::*;
-pub use lana_app::primitives::CollateralAction;
+use crate::{graphql::terms::TermValues, primitives::*};
+
+pub use lana_app::{
+    price::CollateralAsset,
+    primitives::{CollateralAction, CollateralReleaseStatus},
+};
 
 #[derive(async_graphql::Union)]
 pub enum CreditFacilityHistoryEntry {
     Payment(CreditFacilityIncrementalPayment),
+    Prepayment(CreditFacilityPrepaymentRecorded),
     Collateral(CreditFacilityCollateralUpdated),
+    CollateralAsset(CreditFacilityCollateralAssetUpdated),
+    CollateralRelease(CreditFacilityCollateralReleaseUpdated),
     Approved(CreditFacilityApproved),
     Collateralization(CreditFacilityCollateralizationUpdated),
     Disbursal(CreditFacilityDisbursalExecuted),
     Interest(CreditFacilityInterestAccrued),
+    Penalty(CreditFacilityPenaltyAccrued),
     ReservedForLiquidation(CreditFacilityLiquidationAmountReserved),
+    RolledOver(CreditFacilityRolledOver),
+    RolledOverFrom(CreditFacilityRolledOverFrom),
+    TermsModified(CreditFacilityTermsModified),
 }
 
 #[derive(SimpleObject)]
@@ -22,6 +33,15 @@ pub struct CreditFacilityIncrementalPayment {
     pub tx_id: UUID,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityPrepaymentRecorded {
+    pub cents: UsdCents,
+    pub fee_cents: UsdCents,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+    pub tx_id: UUID,
+}
+
 #[derive(SimpleObject)]
 pub struct CreditFacilityCollateralUpdated {
     pub satoshis: Satoshis,
@@ -31,6 +51,26 @@ pub struct CreditFacilityCollateralUpdated {
     pub tx_id: UUID,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityCollateralAssetUpdated {
+    pub asset: CollateralAsset,
+    pub quantity: Decimal,
+    pub abs_diff: Decimal,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+    pub action: CollateralAction,
+    pub tx_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct CreditFacilityCollateralReleaseUpdated {
+    pub collateral_release_id: UUID,
+    pub satoshis: Satoshis,
+    pub status: CollateralReleaseStatus,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+}
+
 #[derive(SimpleObject)]
 pub struct CreditFacilityApproved {
     pub cents: UsdCents,
@@ -67,6 +107,14 @@ pub struct CreditFacilityInterestAccrued {
     pub days: u32,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityPenaltyAccrued {
+    pub cents: UsdCents,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+    pub tx_id: UUID,
+}
+
 #[derive(SimpleObject)]
 pub struct CreditFacilityLiquidationAmountReserved {
     pub cents: UsdCents,
@@ -75,15 +123,53 @@ pub struct CreditFacilityLiquidationAmountReserved {
     pub tx_id: UUID,
 }
 
+#[derive(SimpleObject)]
+pub struct CreditFacilityRolledOver {
+    pub cents: UsdCents,
+    pub collateral: Satoshis,
+    pub rolled_over_to_credit_facility_id: UUID,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+    pub tx_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct CreditFacilityRolledOverFrom {
+    pub cents: UsdCents,
+    pub collateral: Satoshis,
+    pub rolled_over_from_credit_facility_id: UUID,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+    pub tx_id: UUID,
+}
+
+#[derive(SimpleObject)]
+pub struct CreditFacilityTermsModified {
+    pub previous_terms: TermValues,
+    pub terms: TermValues,
+    pub matures_at: Timestamp,
+    pub recorded_at: Timestamp,
+    pub effective: Date,
+}
+
 impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistoryEntry {
     fn from(transaction: lana_app::credit::CreditFacilityHistoryEntry) -> Self {
         match transaction {
             lana_app::credit::CreditFacilityHistoryEntry::Payment(payment) => {
                 CreditFacilityHistoryEntry::Payment(payment.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::Prepayment(prepayment) => {
+                CreditFacilityHistoryEntry::Prepayment(prepayment.into())
+            }
             lana_app::credit::CreditFacilityHistoryEntry::Collateral(collateral) => {
                 CreditFacilityHistoryEntry::Collateral(collateral.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::CollateralAsset(collateral) => {
+                CreditFacilityHistoryEntry::CollateralAsset(collateral.into())
+            }
+            lana_app::credit::CreditFacilityHistoryEntry::CollateralRelease(release) => {
+                CreditFacilityHistoryEntry::CollateralRelease(release.into())
+            }
             lana_app::credit::CreditFacilityHistoryEntry::Approved(approved) => {
                 CreditFacilityHistoryEntry::Approved(approved.into())
             }
@@ -96,9 +182,41 @@ impl From<lana_app::credit::CreditFacilityHistoryEntry> for CreditFacilityHistor
             lana_app::credit::CreditFacilityHistoryEntry::Interest(interest) => {
                 CreditFacilityHistoryEntry::Interest(interest.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::Penalty(penalty) => {
+                CreditFacilityHistoryEntry::Penalty(penalty.into())
+            }
             lana_app::credit::CreditFacilityHistoryEntry::ReservedForLiquidation(liquidation) => {
                 CreditFacilityHistoryEntry::ReservedForLiquidation(liquidation.into())
             }
+            lana_app::credit::CreditFacilityHistoryEntry::RolledOver(rollover) => {
+                CreditFacilityHistoryEntry::RolledOver(CreditFacilityRolledOver {
+                    cents: rollover.cents,
+                    collateral: rollover.collateral,
+                    rolled_over_to_credit_facility_id: UUID::from(rollover.credit_facility_id),
+                    recorded_at: rollover.recorded_at.into(),
+                    effective: rollover.effective.into(),
+                    tx_id: UUID::from(rollover.tx_id),
+                })
+            }
+            lana_app::credit::CreditFacilityHistoryEntry::RolledOverFrom(rollover) => {
+                CreditFacilityHistoryEntry::RolledOverFrom(CreditFacilityRolledOverFrom {
+                    cents: rollover.cents,
+                    collateral: rollover.collateral,
+                    rolled_over_from_credit_facility_id: UUID::from(rollover.credit_facility_id),
+                    recorded_at: rollover.recorded_at.into(),
+                    effective: rollover.effective.into(),
+                    tx_id: UUID::from(rollover.tx_id),
+                })
+            }
+            lana_app::credit::CreditFacilityHistoryEntry::TermsModified(modification) => {
+                CreditFacilityHistoryEntry::TermsModified(CreditFacilityTermsModified {
+                    previous_terms: modification.previous_terms.into(),
+                    terms: modification.terms.into(),
+                    matures_at: modification.matures_at.into(),
+                    recorded_at: modification.recorded_at.into(),
+                    effective: modification.effective.into(),
+                })
+            }
         }
     }
 }
@@ -114,6 +232,18 @@ impl From<lana_app::credit::IncrementalPayment> for CreditFacilityIncrementalPay
     }
 }
 
+impl From<lana_app::credit::PrepaymentRecorded> for CreditFacilityPrepaymentRecorded {
+    fn from(prepayment: lana_app::credit::PrepaymentRecorded) -> Self {
+        Self {
+            cents: prepayment.cents,
+            fee_cents: prepayment.fee_cents,
+            recorded_at: prepayment.recorded_at.into(),
+            effective: prepayment.effective.into(),
+            tx_id: UUID::from(prepayment.payment_id),
+        }
+    }
+}
+
 impl From<lana_app::credit::CollateralUpdated> for CreditFacilityCollateralUpdated {
     fn from(collateral: lana_app::credit::CollateralUpdated) -> Self {
         Self {
@@ -126,6 +256,32 @@ impl From<lana_app::credit::CollateralUpdated> for CreditFacilityCollateralUpdat
     }
 }
 
+impl From<lana_app::credit::CollateralAssetUpdated> for CreditFacilityCollateralAssetUpdated {
+    fn from(collateral: lana_app::credit::CollateralAssetUpdated) -> Self {
+        Self {
+            asset: collateral.asset,
+            quantity: collateral.quantity.into(),
+            abs_diff: collateral.abs_diff.into(),
+            recorded_at: collateral.recorded_at.into(),
+            effective: collateral.effective.into(),
+            action: collateral.action,
+            tx_id: UUID::from(collateral.tx_id),
+        }
+    }
+}
+
+impl From<lana_app::credit::CollateralReleaseUpdated> for CreditFacilityCollateralReleaseUpdated {
+    fn from(release: lana_app::credit::CollateralReleaseUpdated) -> Self {
+        Self {
+            collateral_release_id: UUID::from(release.collateral_release_id),
+            satoshis: release.satoshis,
+            status: release.status,
+            recorded_at: release.recorded_at.into(),
+            effective: release.effective.into(),
+        }
+    }
+}
+
 impl From<lana_app::credit::CreditFacilityApproved> for CreditFacilityApproved {
     fn from(origination: lana_app::credit::CreditFacilityApproved) -> Self {
         Self {
@@ -174,6 +330,17 @@ impl From<lana_app::credit::InterestAccrualsPosted> for CreditFacilityInterestAc
     }
 }
 
+impl From<lana_app::credit::PenaltyAccrued> for CreditFacilityPenaltyAccrued {
+    fn from(penalty: lana_app::credit::PenaltyAccrued) -> Self {
+        Self {
+            cents: penalty.cents,
+            recorded_at: penalty.recorded_at.into(),
+            effective: penalty.effective.into(),
+            tx_id: UUID::from(penalty.tx_id),
+        }
+    }
+}
+
 impl From<lana_app::credit::ObligationMovedToLiquidation>
     for CreditFacilityLiquidationAmountReserved
 {
@@ -59,7 +59,7 @@ impl From<DomainCreditFacility> for CreditFacility {
 #[ComplexObject]
 impl CreditFacility {
     async fn credit_facility_terms(&self) -> TermValues {
-        self.entity.terms.into()
+        self.entity.terms.clone().into()
     }
 
     async fn balance(&self, ctx: &Context<'_>) -> async_graphql::Result<CreditFacilityBalance> {
@@ -11,6 +11,7 @@ pub struct Deposit {
     deposit_id: UUID,
     account_id: UUID,
     amount: UsdCents,
+    currency: String,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -23,7 +24,8 @@ impl From<DomainDeposit> for Deposit {
             id: deposit.id.to_global_id(),
             deposit_id: UUID::from(deposit.id),
             account_id: UUID::from(deposit.deposit_account_id),
-            amount: deposit.amount,
+            amount: minor_units(&deposit.amount),
+            currency: deposit.amount.currency().to_string(),
             created_at: deposit.created_at().into(),
 
             entity: Arc::new(deposit),
@@ -41,3 +43,8 @@ impl Deposit {
         self.entity.status()
     }
 }
+
+/// Deposit amounts are exposed in the minor units of their currency.
+pub(super) fn minor_units(money: &Money) -> UsdCents {
+    UsdCents::from(money.to_minor_units().unsigned_abs())
+}
@@ -15,6 +15,7 @@ pub struct DepositAccount {
     id: ID,
     deposit_account_id: UUID,
     customer_id: UUID,
+    currency: String,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -27,6 +28,7 @@ impl From<DomainDepositAccount> for DepositAccount {
             id: account.id.to_global_id(),
             deposit_account_id: account.id.into(),
             customer_id: account.account_holder_id.into(),
+            currency: account.currency.to_string(),
             created_at: account.created_at().into(),
 
             entity: Arc::new(account),
@@ -38,13 +40,19 @@ impl From<DomainDepositAccount> for DepositAccount {
 pub struct DepositAccountBalance {
     settled: UsdCents,
     pending: UsdCents,
+    held: UsdCents,
+    available: UsdCents,
+    currency: String,
 }
 
 impl From<lana_app::deposit::DepositAccountBalance> for DepositAccountBalance {
     fn from(balance: lana_app::deposit::DepositAccountBalance) -> Self {
         Self {
-            settled: balance.settled,
-            pending: balance.pending,
+            settled: minor_units(&balance.settled),
+            pending: minor_units(&balance.pending),
+            held: minor_units(&balance.held),
+            available: minor_units(&balance.available()),
+            currency: balance.settled.currency().to_string(),
         }
     }
 }
@@ -9,6 +9,7 @@ mod me;
 mod price;
 mod schema;
 mod terms;
+mod transfer;
 mod withdrawal;
 
 use async_graphql::*;
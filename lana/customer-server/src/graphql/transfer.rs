@@ -0,0 +1,48 @@
+use async_graphql::*;
+
+use crate::primitives::*;
+
+use super::deposit::minor_units;
+
+pub use lana_app::deposit::{Transfer as DomainTransfer, TransferStatus};
+
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Transfer {
+    id: ID,
+    transfer_id: UUID,
+    from_account_id: UUID,
+    to_account_id: UUID,
+    amount: UsdCents,
+    currency: String,
+    created_at: Timestamp,
+
+    #[graphql(skip)]
+    pub(super) entity: Arc<DomainTransfer>,
+}
+
+impl From<DomainTransfer> for Transfer {
+    fn from(transfer: DomainTransfer) -> Self {
+        Transfer {
+            id: transfer.id.to_global_id(),
+            created_at: transfer.created_at().into(),
+            transfer_id: UUID::from(transfer.id),
+            from_account_id: transfer.from_account_id.into(),
+            to_account_id: transfer.to_account_id.into(),
+            amount: minor_units(&transfer.amount),
+            currency: transfer.amount.currency().to_string(),
+            entity: Arc::new(transfer),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Transfer {
+    async fn reference(&self) -> &str {
+        &self.entity.reference
+    }
+
+    async fn status(&self) -> TransferStatus {
+        self.entity.status()
+    }
+}
@@ -2,6 +2,8 @@ use async_graphql::*;
 
 use crate::primitives::*;
 
+use super::deposit::minor_units;
+
 pub use lana_app::deposit::{Withdrawal as DomainWithdrawal, WithdrawalStatus};
 
 #[derive(SimpleObject, Clone)]
@@ -11,6 +13,7 @@ pub struct Withdrawal {
     withdrawal_id: UUID,
     account_id: UUID,
     amount: UsdCents,
+    currency: String,
     created_at: Timestamp,
 
     #[graphql(skip)]
@@ -24,7 +27,8 @@ impl From<lana_app::deposit::Withdrawal> for Withdrawal {
             created_at: withdraw.created_at().into(),
             account_id: withdraw.deposit_account_id.into(),
             withdrawal_id: UUID::from(withdraw.id),
-            amount: withdraw.amount,
+            amount: minor_units(&withdraw.amount),
+            currency: withdraw.amount.currency().to_string(),
             entity: Arc::new(withdraw),
         }
     }
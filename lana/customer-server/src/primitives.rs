@@ -6,8 +6,8 @@ pub use std::sync::Arc;
 pub use lana_app::{
     primitives::{
         CreditFacilityId, CreditFacilityStatus, CustomerId, DepositAccountId, DepositId,
-        DisbursalId, DisbursalStatus, ObligationInstallmentId, Satoshis, Subject, UsdCents,
-        WithdrawalId,
+        DisbursalId, DisbursalStatus, Money, ObligationInstallmentId, Satoshis, Subject,
+        TransferId, UsdCents, WithdrawalId,
     },
     terms::CollateralizationState,
 };
@@ -58,6 +58,16 @@ impl Date {
     }
 }
 
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Decimal(rust_decimal::Decimal);
+scalar!(Decimal);
+impl From<rust_decimal::Decimal> for Decimal {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self(value)
+    }
+}
+
 pub trait ToGlobalId {
     fn to_global_id(&self) -> async_graphql::types::ID;
 }
@@ -79,6 +89,7 @@ impl_to_global_id! {
     DepositAccountId,
     DepositId,
     WithdrawalId,
+    TransferId,
     CreditFacilityId,
     DisbursalId,
     ObligationInstallmentId
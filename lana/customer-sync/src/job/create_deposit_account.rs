@@ -6,8 +6,8 @@ use audit::{AuditSvc, SystemSubject};
 use authz::PermissionCheck;
 use core_customer::{CoreCustomerAction, CoreCustomerEvent, CustomerObject};
 use core_deposit::{
-    CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, GovernanceAction,
-    GovernanceObject,
+    CoreDeposit, CoreDepositAction, CoreDepositEvent, CoreDepositObject, CurrencyCode,
+    GovernanceAction, GovernanceObject,
 };
 use governance::GovernanceEvent;
 use outbox::{Outbox, OutboxEventMarker, PersistentOutboxEvent};
@@ -217,6 +217,7 @@ where
                     id,
                     active,
                     customer_type,
+                    CurrencyCode::USD,
                 )
                 .await
             {
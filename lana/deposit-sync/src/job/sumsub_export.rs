@@ -204,15 +204,15 @@ where
                         .find_account_by_id_without_audit(*deposit_account_id)
                         .await?;
                     message.inject_trace_parent();
-                    let amount_usd: f64 = amount.to_usd().try_into()?;
+                    let amount_value: f64 = amount.amount().try_into()?;
                     self.sumsub_client
                         .submit_finance_transaction(
                             account.account_holder_id,
                             id.to_string(),
                             "Deposit",
                             &SumsubTransactionDirection::In.to_string(),
-                            amount_usd,
-                            "USD",
+                            amount_value,
+                            amount.currency().as_str(),
                         )
                         .await?;
                 }
@@ -226,15 +226,15 @@ where
                         .find_account_by_id_without_audit(*deposit_account_id)
                         .await?;
                     message.inject_trace_parent();
-                    let amount_usd: f64 = amount.to_usd().try_into()?;
+                    let amount_value: f64 = amount.amount().try_into()?;
                     self.sumsub_client
                         .submit_finance_transaction(
                             account.account_holder_id,
                             id.to_string(),
                             "Withdrawal",
                             &SumsubTransactionDirection::Out.to_string(),
-                            amount_usd,
-                            "USD",
+                            amount_value,
+                            amount.currency().as_str(),
                         )
                         .await?;
                 }
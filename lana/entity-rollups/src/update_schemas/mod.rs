@@ -4,15 +4,21 @@ mod migration;
 use colored::*;
 
 use core_access::event_schema::{PermissionSetEvent, RoleEvent, UserEvent};
-use core_accounting::event_schema::{ChartEvent, ManualTransactionEvent};
+use core_accounting::event_schema::{
+    AccountingPeriodEvent, ChartEvent, FxRateEvent, FxRevaluationEvent, ManualTransactionEvent,
+    ReconciliationEvent, RecurringJournalEntryEvent,
+};
 use core_credit::event_schema::{
-    CollateralEvent, CreditFacilityEvent, DisbursalEvent, InterestAccrualCycleEvent,
-    LiquidationProcessEvent, ObligationEvent, ObligationInstallmentEvent, PaymentEvent,
-    TermsTemplateEvent,
+    CollateralEvent, CollateralReleaseEvent, CreditFacilityEvent, DisbursalEvent,
+    InterestAccrualCycleEvent, LiquidationProcessEvent, ObligationEvent,
+    ObligationInstallmentEvent, PaymentEvent, TermsTemplateEvent,
 };
 use core_custody::event_schema::CustodianEvent;
 use core_customer::event_schema::CustomerEvent;
-use core_deposit::event_schema::{DepositAccountEvent, DepositEvent, WithdrawalEvent};
+use core_deposit::event_schema::{
+    DepositAccountEvent, DepositEvent, DepositProductEvent, HoldEvent, StandingOrderEvent,
+    TransferEvent, WithdrawalEvent, WithdrawalLimitEvent,
+};
 use document_storage::event_schema::DocumentEvent;
 use governance::event_schema::{ApprovalProcessEvent, CommitteeEvent, PolicyEvent};
 use schemars::schema_for;
@@ -225,6 +231,39 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(WithdrawalEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "TransferEvent",
+            filename: "transfer_event_schema.json",
+            toggle_events: vec!["ApprovalProcessConcluded", "Completed", "Failed"],
+            generate_schema: || serde_json::to_value(schema_for!(TransferEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "DepositProductEvent",
+            filename: "deposit_product_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(DepositProductEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "HoldEvent",
+            filename: "hold_event_schema.json",
+            toggle_events: vec!["Released", "Expired"],
+            generate_schema: || serde_json::to_value(schema_for!(HoldEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "WithdrawalLimitEvent",
+            filename: "withdrawal_limit_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(WithdrawalLimitEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "StandingOrderEvent",
+            filename: "standing_order_event_schema.json",
+            toggle_events: vec!["Cancelled"],
+            generate_schema: || serde_json::to_value(schema_for!(StandingOrderEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "CustodianEvent",
             filename: "custodian_event_schema.json",
@@ -274,6 +313,13 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(CreditFacilityEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "CollateralReleaseEvent",
+            filename: "collateral_release_event_schema.json",
+            toggle_events: vec!["ApprovalProcessConcluded", "Executed", "Failed"],
+            generate_schema: || serde_json::to_value(schema_for!(CollateralReleaseEvent)).unwrap(),
+            ..Default::default()
+        },
         SchemaInfo {
             name: "DisbursalEvent",
             filename: "disbursal_event_schema.json",
@@ -398,6 +444,39 @@ pub fn update_schemas(
             generate_schema: || serde_json::to_value(schema_for!(ManualTransactionEvent)).unwrap(),
             ..Default::default()
         },
+        SchemaInfo {
+            name: "AccountingPeriodEvent",
+            filename: "accounting_period_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(AccountingPeriodEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "FxRateEvent",
+            filename: "fx_rate_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(FxRateEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "FxRevaluationEvent",
+            filename: "fx_revaluation_event_schema.json",
+            generate_schema: || serde_json::to_value(schema_for!(FxRevaluationEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "ReconciliationEvent",
+            filename: "reconciliation_event_schema.json",
+            toggle_events: vec!["Completed"],
+            generate_schema: || serde_json::to_value(schema_for!(ReconciliationEvent)).unwrap(),
+            ..Default::default()
+        },
+        SchemaInfo {
+            name: "RecurringJournalEntryEvent",
+            filename: "recurring_journal_entry_event_schema.json",
+            generate_schema: || {
+                serde_json::to_value(schema_for!(RecurringJournalEntryEvent)).unwrap()
+            },
+            ..Default::default()
+        },
     ];
 
     // First, detect which schemas have changed
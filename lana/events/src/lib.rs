@@ -12,6 +12,7 @@ pub use core_report::CoreReportEvent;
 pub use governance::GovernanceEvent;
 pub use outbox::OutboxEventMarker;
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "module")]
 pub enum LanaEvent {
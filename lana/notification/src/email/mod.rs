@@ -75,6 +75,7 @@ impl EmailNotification {
             payment_type: match obligation.obligation_type {
                 ObligationType::Disbursal => "Principal Repayment".to_string(),
                 ObligationType::Interest => "Interest Payment".to_string(),
+                ObligationType::Penalty => "Penalty Payment".to_string(),
             },
             original_amount: obligation.initial_amount,
             outstanding_amount: *amount,
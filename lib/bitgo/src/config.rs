@@ -17,6 +17,9 @@ pub struct BitgoDirectoryConfig {
     pub testing_url: Url,
     #[serde(default = "default_production_url")]
     pub production_url: Url,
+    /// BitGo Express instance that signs outgoing transfers with the wallet passphrase.
+    #[serde(default = "default_express_url")]
+    pub express_url: Url,
 }
 
 impl Default for BitgoDirectoryConfig {
@@ -24,6 +27,7 @@ impl Default for BitgoDirectoryConfig {
         Self {
             testing_url: default_testing_url(),
             production_url: default_production_url(),
+            express_url: default_express_url(),
         }
     }
 }
@@ -35,3 +39,7 @@ fn default_testing_url() -> Url {
 fn default_production_url() -> Url {
     "https://app.bitgo.com/".parse().expect("valid URL")
 }
+
+fn default_express_url() -> Url {
+    "http://localhost:3080/".parse().expect("valid URL")
+}
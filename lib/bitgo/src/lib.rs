@@ -22,6 +22,7 @@ pub struct BitgoClient {
     http_client: Client,
     long_lived_token: String,
     endpoint: Url,
+    express_endpoint: Url,
     passphrase: String,
     enterprise_id: String,
     coin: String,
@@ -51,6 +52,7 @@ impl BitgoClient {
             http_client: Client::new(),
             long_lived_token: config.long_lived_token,
             endpoint,
+            express_endpoint: directory_config.express_url,
             passphrase: config.passphrase,
             enterprise_id: config.enterprise_id,
             coin: coin.to_owned(),
@@ -199,6 +201,62 @@ impl BitgoClient {
             .await?;
         Ok(serde_json::from_value(response)?)
     }
+
+    #[tracing::instrument(
+        name = "bitgo.get_transfer_by_sequence_id",
+        skip(self),
+        fields(response, url),
+        err
+    )]
+    pub async fn get_transfer_by_sequence_id(
+        &self,
+        wallet_id: &str,
+        sequence_id: &str,
+    ) -> Result<Transfer, BitgoError> {
+        // https://developers.bitgo.com/api/v2.wallet.gettransferbysequenceid
+
+        let response = self
+            .get(self.url_with_coin(&format!(
+                "wallet/{wallet_id}/transfer/sequenceId/{sequence_id}"
+            )))
+            .await?;
+        Ok(serde_json::from_value(response)?)
+    }
+
+    #[tracing::instrument(name = "bitgo.send_coins", skip(self), fields(response, url), err)]
+    pub async fn send_coins(
+        &self,
+        wallet_id: &str,
+        address: &str,
+        amount: u64,
+        sequence_id: &str,
+    ) -> Result<Transfer, BitgoError> {
+        // https://developers.bitgo.com/api/express.wallet.sendcoins
+        //
+        // BitGo rejects a second transfer with the same `sequence_id`, so a retry
+        // after a lost response looks the first one up instead of sending again.
+
+        let response: Result<SendCoinsResponse, _> = self
+            .post(
+                self.express_url_with_coin(&format!("wallet/{wallet_id}/sendcoins")),
+                &SendCoins {
+                    address,
+                    amount: amount.to_string(),
+                    wallet_passphrase: &self.passphrase,
+                    sequence_id,
+                },
+            )
+            .await;
+
+        match response {
+            Ok(response) => Ok(response.transfer),
+            Err(e @ BitgoError::BitgoError { .. }) => self
+                .get_transfer_by_sequence_id(wallet_id, sequence_id)
+                .await
+                .map_err(|_| e),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 impl BitgoClient {
@@ -274,4 +332,12 @@ impl BitgoClient {
             .join(path)
             .expect("valid URL")
     }
+
+    fn express_url_with_coin(&self, path: &str) -> Url {
+        self.express_endpoint
+            .join(&format!("api/v2/{}/", self.coin))
+            .expect("valid URL")
+            .join(path)
+            .expect("valid URL")
+    }
 }
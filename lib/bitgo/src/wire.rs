@@ -73,6 +73,7 @@ pub struct Address {
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Transfer {
+    pub id: String,
     pub wallet: String,
     pub txid: String,
     pub confirmations: u32,
@@ -104,6 +105,21 @@ pub enum TransferState {
     Unconfirmed,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendCoins<'a> {
+    pub address: &'a str,
+    pub amount: String,
+    pub wallet_passphrase: &'a str,
+    pub sequence_id: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendCoinsResponse {
+    pub transfer: Transfer,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateKey {
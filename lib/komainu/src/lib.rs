@@ -20,14 +20,15 @@ use reqwest::{
     Client, Method, RequestBuilder, Url,
     header::{CONTENT_TYPE, HeaderValue},
 };
+use rust_decimal::Decimal;
 use serde::{Serialize, de::DeserializeOwned};
 use sha2::{Digest as _, Sha256};
 use tokio::sync::Mutex;
 
 pub use config::{KomainuConfig, KomainuDirectoryConfig, KomainuSecretKey};
 pub use error::KomainuError;
+use wire::{CreateTransaction, Fallible, GetToken, GetTokenResponse, Many};
 pub use wire::{EntityType, EventType, Notification, Request, Transaction, Wallet};
-use wire::{Fallible, GetToken, GetTokenResponse, Many};
 
 #[derive(Clone)]
 pub struct KomainuClient {
@@ -124,6 +125,40 @@ impl KomainuClient {
         self.get_many("v1/custody/transactions").await
     }
 
+    /// Requests an outgoing transaction, which is executed once approved in Komainu.
+    /// A transaction already carrying `external_reference` is returned instead of
+    /// requesting a second one.
+    #[tracing::instrument(name = "komainu.create_transaction", skip(self))]
+    pub async fn create_transaction(
+        &self,
+        wallet_id: &str,
+        receiver_address: &str,
+        amount: Decimal,
+        external_reference: &str,
+    ) -> Result<String, KomainuError> {
+        if let Some(existing) = self
+            .list_transactions()
+            .await?
+            .into_iter()
+            .find(|tx| tx.wallet_id == wallet_id && tx.external_reference == external_reference)
+        {
+            return Ok(existing.id);
+        }
+
+        let request: Request = self
+            .post(
+                "v1/custody/transactions",
+                CreateTransaction {
+                    wallet_id,
+                    receiver_address,
+                    amount,
+                    external_reference,
+                },
+            )
+            .await?;
+        Ok(request.id)
+    }
+
     #[tracing::instrument(name = "komainu.get_wallet", skip(self))]
     pub async fn get_wallet(&self, id: &str) -> Result<Wallet, KomainuError> {
         self.get_one(&format!("v1/custody/wallets/{id}")).await
@@ -184,6 +219,33 @@ impl KomainuClient {
         }
     }
 
+    async fn post<T: Serialize, D: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        payload: T,
+    ) -> Result<D, KomainuError> {
+        let response = self
+            .request(Method::POST, endpoint, None, Some(payload))
+            .await?
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        match response {
+            Fallible::Error {
+                error_code,
+                errors,
+                status,
+            } => Err(KomainuError::KomainuError {
+                error_code,
+                errors,
+                status,
+            }),
+            Fallible::Ok(res) => Ok(res),
+        }
+    }
+
     async fn request<T: Serialize>(
         &self,
         method: Method,
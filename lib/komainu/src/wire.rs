@@ -74,6 +74,14 @@ pub struct Transaction {
     pub account: String,
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTransaction<'a> {
+    pub wallet_id: &'a str,
+    pub receiver_address: &'a str,
+    pub amount: Decimal,
+    pub external_reference: &'a str,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct WalletBalance {
     pub total: Decimal,
@@ -6,6 +6,10 @@ pub enum RenderingError {
     Render(#[from] handlebars::RenderError),
     #[error("PDF generation error: {0}")]
     PdfGeneration(String),
+    #[error("XLSX generation error: {0}")]
+    XlsxGeneration(String),
+    #[error("XLSX error: {0}")]
+    Xlsx(#[from] rust_xlsxwriter::XlsxError),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
     #[error("Invalid template data: {0}")]
@@ -4,10 +4,12 @@
 pub mod error;
 pub mod pdf;
 pub mod template;
+pub mod xlsx;
 
 pub use error::RenderingError;
 pub use pdf::PdfGenerator;
 pub use template::TemplateRenderer;
+pub use xlsx::{XlsxCell, XlsxGenerator};
 
 /// Main rendering service that combines template processing and PDF generation
 #[derive(Clone)]
@@ -0,0 +1,122 @@
+use rust_xlsxwriter::Workbook;
+
+use crate::error::RenderingError;
+
+/// A single cell of a spreadsheet row
+#[derive(Debug, Clone)]
+pub enum XlsxCell {
+    Text(String),
+    /// Plain decimal representation of a number, e.g. `-1234.56`
+    Number(String),
+    Empty,
+}
+
+/// XLSX generator writing a single-sheet workbook
+#[derive(Clone)]
+pub struct XlsxGenerator;
+
+impl XlsxGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Generate a workbook with one sheet holding `rows`
+    pub fn generate_xlsx(
+        &self,
+        sheet_name: &str,
+        rows: &[Vec<XlsxCell>],
+    ) -> Result<Vec<u8>, RenderingError> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        // Sheet names are limited to 31 characters
+        let sheet_name: String = sheet_name.chars().take(31).collect();
+        worksheet.set_name(sheet_name)?;
+
+        for (row_idx, row) in rows.iter().enumerate() {
+            let row_idx = u32::try_from(row_idx)
+                .map_err(|_| RenderingError::XlsxGeneration("too many rows".to_string()))?;
+            for (col_idx, cell) in row.iter().enumerate() {
+                let col_idx = u16::try_from(col_idx)
+                    .map_err(|_| RenderingError::XlsxGeneration("too many columns".to_string()))?;
+                match cell {
+                    XlsxCell::Text(text) => {
+                        worksheet.write_string(row_idx, col_idx, text)?;
+                    }
+                    XlsxCell::Number(number) => {
+                        let number: f64 = number.parse().map_err(|_| {
+                            RenderingError::XlsxGeneration(format!("invalid number: {number}"))
+                        })?;
+                        worksheet.write_number(row_idx, col_idx, number)?;
+                    }
+                    XlsxCell::Empty => {}
+                }
+            }
+        }
+
+        Ok(workbook.save_to_buffer()?)
+    }
+}
+
+impl Default for XlsxGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use calamine::{Data, Reader, Xlsx};
+
+    use super::*;
+
+    #[test]
+    fn generates_readable_workbook() -> Result<(), RenderingError> {
+        let rows = vec![
+            vec![
+                XlsxCell::Text("Code".to_string()),
+                XlsxCell::Text("Balance".to_string()),
+            ],
+            vec![
+                XlsxCell::Text("11 <Assets> & Co".to_string()),
+                XlsxCell::Number("-12.50".to_string()),
+            ],
+            vec![XlsxCell::Empty, XlsxCell::Number("3".to_string())],
+        ];
+        let bytes =
+            XlsxGenerator::new().generate_xlsx("Trial Balance Report For The Whole Year", &rows)?;
+
+        let mut workbook = Xlsx::new(Cursor::new(bytes)).expect("readable workbook");
+        assert_eq!(
+            workbook.sheet_names(),
+            vec!["Trial Balance Report For The Wh".to_string()]
+        );
+        let range = workbook
+            .worksheet_range_at(0)
+            .expect("one sheet")
+            .expect("readable sheet");
+        assert_eq!(range.get_size(), (3, 2));
+        assert_eq!(
+            range.get_value((0, 0)),
+            Some(&Data::String("Code".to_string()))
+        );
+        assert_eq!(
+            range.get_value((1, 0)),
+            Some(&Data::String("11 <Assets> & Co".to_string()))
+        );
+        assert_eq!(range.get_value((1, 1)), Some(&Data::Float(-12.5)));
+        assert_eq!(range.get_value((2, 0)), Some(&Data::Empty));
+        assert_eq!(range.get_value((2, 1)), Some(&Data::Float(3.0)));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_numbers() {
+        let rows = vec![vec![XlsxCell::Number("12,50".to_string())]];
+        assert!(matches!(
+            XlsxGenerator::new().generate_xlsx("Sheet", &rows),
+            Err(RenderingError::XlsxGeneration(_))
+        ));
+    }
+}
@@ -22,7 +22,12 @@ with source as (
         withdrawal_id,
         deposit_account_id,
 
-        cast(amount as numeric) / {{ var('cents_per_usd') }} as amount_usd,
+        cast(json_value(amount, "$.amount") as numeric) as amount,
+        json_value(amount, "$.currency") as currency,
+        case
+            when json_value(amount, "$.currency") = 'USD'
+                then cast(json_value(amount, "$.amount") as numeric)
+        end as amount_usd,
         approved,
         is_approval_process_concluded,
         is_confirmed,